@@ -0,0 +1,214 @@
+/*!
+A conformance check for custom [`Axis`] implementations.
+
+The [`Axis`] docs list the arithmetic rules an implementation has to follow,
+but most of the contract is implicit in how the functions of this crate use it
+(`TAU` being twice `PI`, [`sin_cos`](Axis::sin_cos) agreeing with [`sin`](Axis::sin)
+and [`cos`](Axis::cos), [`ERROR`](Axis::ERROR) being a small positive step, ...).
+[`check_axis_conformance`] runs all of those identities on a type,
+so anyone implementing [`Axis`] for a fixed point, `f16` or decimal type
+can check it from their own tests:
+
+```
+use quaternion_traits::axis_tests::check_axis_conformance;
+
+check_axis_conformance::<f32>(0.0001);
+check_axis_conformance::<f64>(0.000_000_001);
+```
+
+The crate runs it on [f32], [f64] and (with the `std` feature) on
+[`Std<f32>`](crate::structs::Std) and [`Std<f64>`](crate::structs::Std).
+
+Nothing here needs `std`, failures are reported with [`core::panic!`](crate::core::panic).
+ */
+
+use crate::Axis;
+
+/// Inputs used for the identities that take any finite number.
+const ANY: [f64; 9] = [-3.0, -1.5, -0.75, -0.1, 0.0, 0.1, 0.75, 1.5, 3.0];
+/// Inputs used for the identities that only take positive numbers.
+const POSITIVE: [f64; 7] = [0.01, 0.1, 0.5, 1.0, 2.0, 7.5, 42.0];
+/// Inputs used for the inverse trigonometric identities.
+const UNIT_RANGE: [f64; 7] = [-1.0, -0.75, -0.1, 0.0, 0.1, 0.75, 1.0];
+
+/// Checks if `value` is within `tolerance` of `expected`,
+/// the tolerance is scaled by the size of `expected` when it's larger than one.
+#[inline]
+fn near<A: Axis>(value: A, expected: A, tolerance: A) -> bool {
+    (value - expected).abs() <= tolerance * expected.abs().max(A::ONE)
+}
+
+/// Panics with `identity` and `input` if `value` isn't near `expected`.
+#[inline]
+#[track_caller]
+fn check<A: Axis>(value: A, expected: A, tolerance: A, identity: &str, input: f64) {
+    if !near(value, expected, tolerance) {
+        crate::core::panic!("Axis conformance failed: `{identity}` does not hold for x = {input}");
+    }
+}
+
+/// Panics with `rule` if `holds` is false.
+#[inline]
+#[track_caller]
+fn require(holds: bool, rule: &str) {
+    if !holds {
+        crate::core::panic!("Axis conformance failed: `{rule}` does not hold");
+    }
+}
+
+/**
+Runs every identity the crate expects an [`Axis`] type to follow
+and panics with a descriptive message on the first one that fails.
+
+Approximate identities pass if both sides are within `tolerance`
+(scaled by the size of the expected value when it's above one),
+exact ones (the constants, [`from_u8`](Axis::from_u8) and
+[`abs`](Axis::abs)/[`min`](Axis::min)/[`max`](Axis::max)) have to hold with `==`.
+
+Checked, in order:
+- the constants: `ZERO < ERROR < ONE`, `ONE + ERROR > ONE`, `ZERO < EPS_ARITH <= EPS_TRIG < ONE`, `-ZERO == ZERO`, `NAN.is_nan()`
+- `TAU / 2 ≈ from_f64(PI)`, `sin(TAU / 4) ≈ 1`, `cos(TAU / 2) ≈ -1`
+- `from_f64` keeps exact values: `from_f64(0.5) + from_f64(0.5) == ONE`, `from_f64(-x) == -from_f64(x)`
+- `from_u8(n) == from_f64(n)` and `from_u8(n) + ONE == from_u8(n + 1)` for all of `0..=255`
+- `from_f32(x) == from_f64(x)`, and `from_i32(n)`, `from_u32(n)` and `from_usize(n)` equal `from_f64(n)`
+  for the integers in `-1024..=1024` (positive ones for the unsigned ones)
+  and the powers of two up to `2^24`, which every float can hold exactly
+- `abs`, `min` and `max`
+- `mul_add(x, y, z) ≈ x * y + z`
+- `sin_cos(x) ≈ (sin(x), cos(x))` and `sin² + cos² ≈ 1`
+- `asin(sin x) ≈ x` and `acos(cos x) ≈ x` (in their ranges)
+- `cosh² - sinh² ≈ 1`
+- `atan2` returns the angle of every quadrant and axis
+- `sqrt(x)² ≈ x`, `pow(x, 2) ≈ x * x` and `pow(x, 0.5) ≈ sqrt(x)`
+- `exp(ln x) ≈ x`, `ln(exp x) ≈ x`, `exp(0) == 1` and `ln(1) ≈ 0`
+
+# Panics
+
+Panics if any of the identities above doesn't hold.
+
+# Example
+```should_panic
+use quaternion_traits::axis_tests::check_axis_conformance;
+
+// no float is precise enough for this
+check_axis_conformance::<f32>(0.0);
+```
+ */
+pub fn check_axis_conformance<A: Axis>(tolerance: A) {
+    let two: A = A::ONE + A::ONE;
+    let pi: A = A::TAU / two;
+
+    // constants
+    require(A::ZERO < A::ERROR, "ZERO < ERROR");
+    require(A::ERROR < A::ONE, "ERROR < ONE");
+    require(A::ONE + A::ERROR > A::ONE, "ONE + ERROR > ONE");
+    require(A::ZERO < A::EPS_ARITH, "ZERO < EPS_ARITH");
+    require(A::EPS_ARITH <= A::EPS_TRIG, "EPS_ARITH <= EPS_TRIG");
+    require(A::EPS_TRIG < A::ONE, "EPS_TRIG < ONE");
+    require(-A::ZERO == A::ZERO, "-ZERO == ZERO");
+    require(A::ONE * A::ONE == A::ONE, "ONE * ONE == ONE");
+    require(A::NAN.is_nan(), "NAN.is_nan()");
+    require(!A::ONE.is_nan() && !A::ZERO.is_nan(), "!ONE.is_nan() && !ZERO.is_nan()");
+
+    // TAU and PI
+    check(pi, A::from_f64(crate::core::f64::consts::PI), tolerance, "TAU / 2 ≈ PI", 0.0);
+    check((pi / two).sin(), A::ONE, tolerance, "sin(TAU / 4) ≈ 1", 0.0);
+    check(pi.cos(), -A::ONE, tolerance, "cos(TAU / 2) ≈ -1", 0.0);
+    check(A::TAU.cos(), A::ONE, tolerance, "cos(TAU) ≈ 1", 0.0);
+
+    // from_f64 and from_u8
+    let half: A = A::from_f64(0.5);
+    require(half + half == A::ONE, "from_f64(0.5) + from_f64(0.5) == ONE");
+    require(A::from_f64(1.0) == A::ONE && A::from_f64(0.0) == A::ZERO, "from_f64(1.0) == ONE && from_f64(0.0) == ZERO");
+    for x in ANY {
+        if A::from_f64(-x) != -A::from_f64(x) {
+            crate::core::panic!("Axis conformance failed: `from_f64(-x) == -from_f64(x)` does not hold for x = {x}");
+        }
+    }
+    let mut previous: A = A::from_u8(0);
+    require(previous == A::ZERO, "from_u8(0) == ZERO");
+    for n in 1..=255_u8 {
+        let current: A = A::from_u8(n);
+        if current != A::from_f64(n as f64) {
+            crate::core::panic!("Axis conformance failed: `from_u8(n) == from_f64(n)` does not hold for n = {n}");
+        }
+        if previous + A::ONE != current {
+            crate::core::panic!("Axis conformance failed: `from_u8(n - 1) + ONE == from_u8(n)` does not hold for n = {n}");
+        }
+        previous = current;
+    }
+
+    for x in ANY {
+        let x: f32 = x as f32;
+        if A::from_f32(x) != A::from_f64(x as f64) {
+            crate::core::panic!("Axis conformance failed: `from_f32(x) == from_f64(x)` does not hold for x = {x}");
+        }
+    }
+    let check_int = |n: i32| {
+        let expected: A = A::from_f64(n as f64);
+        if A::from_i32(n) != expected || A::from_i32(-n) != -expected {
+            crate::core::panic!("Axis conformance failed: `from_i32(n) == from_f64(n)` does not hold for n = ±{n}");
+        }
+        if n >= 0 && (A::from_u32(n as u32) != expected || A::from_usize(n as usize) != expected) {
+            crate::core::panic!("Axis conformance failed: `from_u32(n) == from_usize(n) == from_f64(n)` does not hold for n = {n}");
+        }
+    };
+    for n in -1024..=1024 {
+        check_int(n);
+    }
+    for power in 0..=24 {
+        check_int(1 << power);
+    }
+
+    // abs, min and max
+    require(two.abs() == two && (-two).abs() == two, "abs(±2) == 2");
+    require(A::ONE.min(two) == A::ONE && two.min(A::ONE) == A::ONE, "min(1, 2) == 1");
+    require(A::ONE.max(two) == two && two.max(A::ONE) == two, "max(1, 2) == 2");
+
+    for x in ANY {
+        let a: A = A::from_f64(x);
+
+        check(a.mul_add(two, half), a * two + half, tolerance, "mul_add(x, 2, 0.5) ≈ x * 2 + 0.5", x);
+
+        let (sin, cos) = a.sin_cos();
+        check(sin, a.sin(), tolerance, "sin_cos(x).0 ≈ sin(x)", x);
+        check(cos, a.cos(), tolerance, "sin_cos(x).1 ≈ cos(x)", x);
+        check(sin * sin + cos * cos, A::ONE, tolerance, "sin(x)² + cos(x)² ≈ 1", x);
+
+        let (sinh, cosh) = (a.sinh(), a.cosh());
+        check(cosh * cosh - sinh * sinh, A::ONE, tolerance, "cosh(x)² - sinh(x)² ≈ 1", x);
+
+        check(a.exp().ln(), a, tolerance, "ln(exp(x)) ≈ x", x);
+    }
+
+    for x in UNIT_RANGE {
+        // `x` times a quarter turn is in the range of `asin`, shifting it by a quarter turn puts it in the range of `acos`
+        let angle: A = A::from_f64(x) * pi / two;
+        check(angle.sin().asin(), angle, tolerance, "asin(sin(x * TAU / 4)) ≈ x * TAU / 4", x);
+        check((angle + pi / two).cos().acos(), angle + pi / two, tolerance, "acos(cos((x + 1) * TAU / 4)) ≈ (x + 1) * TAU / 4", x);
+    }
+
+    // atan2 quadrants
+    let eighth: A = A::TAU / A::from_f64(8.0);
+    let three: A = two + A::ONE;
+    check(A::ZERO.atan2(A::ONE), A::ZERO, tolerance, "atan2(0, 1) ≈ 0", 0.0);
+    check(A::ONE.atan2(A::ONE), eighth, tolerance, "atan2(1, 1) ≈ TAU / 8", 0.0);
+    check(A::ONE.atan2(A::ZERO), two * eighth, tolerance, "atan2(1, 0) ≈ TAU / 4", 0.0);
+    check(A::ONE.atan2(-A::ONE), three * eighth, tolerance, "atan2(1, -1) ≈ 3 TAU / 8", 0.0);
+    check((-A::ONE).atan2(-A::ONE), -three * eighth, tolerance, "atan2(-1, -1) ≈ -3 TAU / 8", 0.0);
+    check((-A::ONE).atan2(A::ZERO), -two * eighth, tolerance, "atan2(-1, 0) ≈ -TAU / 4", 0.0);
+    check((-A::ONE).atan2(A::ONE), -eighth, tolerance, "atan2(-1, 1) ≈ -TAU / 8", 0.0);
+
+    // sqrt, pow, exp and ln
+    require(A::ONE.sqrt() == A::ONE && A::ZERO.sqrt() == A::ZERO, "sqrt(1) == 1 && sqrt(0) == 0");
+    require(A::ZERO.exp() == A::ONE, "exp(0) == 1");
+    check(A::ONE.ln(), A::ZERO, tolerance, "ln(1) ≈ 0", 1.0);
+    for x in POSITIVE {
+        let a: A = A::from_f64(x);
+        let sqrt: A = a.sqrt();
+        check(sqrt * sqrt, a, tolerance, "sqrt(x)² ≈ x", x);
+        check(a.pow(two), a * a, tolerance, "pow(x, 2) ≈ x * x", x);
+        check(a.pow(half), sqrt, tolerance, "pow(x, 0.5) ≈ sqrt(x)", x);
+        check(a.ln().exp(), a, tolerance, "exp(ln(x)) ≈ x", x);
+    }
+}
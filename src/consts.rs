@@ -0,0 +1,90 @@
+/*!
+The numeric constants used inside the crate's functions, with where their values come from.
+
+Each one is an [`f64`] constant, with a function that converts it into any [`Axis`]
+through [`from_f64`](Axis::from_f64), which is what the functions of the crate call.
+The conversion of a literal doesn't go through any platform math,
+so the values are the same everywhere (also with the `deterministic` feature).
+
+| constant                 | value              | source                                                                  |
+|--------------------------|--------------------|-------------------------------------------------------------------------|
+| [`HALF`]                 | `0.5`              | exact                                                                   |
+| [`PI`]                   | `π`                | [`f64::consts::PI`](crate::core::f64::consts::PI)                       |
+| [`FRAC_PI_2`]            | `π/2`              | [`f64::consts::FRAC_PI_2`](crate::core::f64::consts::FRAC_PI_2)         |
+| [`FRAC_1_SQRT_2`]        | `1/√2`             | [`f64::consts::FRAC_1_SQRT_2`](crate::core::f64::consts::FRAC_1_SQRT_2) |
+| [`EULER_MASCHERONI`]     | `γ ≈ 0.5772156649` | [OEIS A001620](https://oeis.org/A001620)                                |
+| [`SLERP_LERP_THRESHOLD`] | `1 - 2⁻¹⁶`         | `1 - ERROR` of [f32] and [f64]                                          |
+
+# Example
+```
+use quaternion_traits::consts;
+
+assert_eq!( consts::half::<f32>(), 0.5 );
+assert_eq!( consts::euler_mascheroni::<f64>(), consts::EULER_MASCHERONI );
+assert_eq!( consts::euler_mascheroni::<f32>(), consts::EULER_MASCHERONI as f32 );
+```
+ */
+
+use crate::Axis;
+
+/// One half, for halving angles (a rotation by `θ` has a quaternion made of `sin(θ/2)` and `cos(θ/2)`).
+pub const HALF: f64 = 0.5;
+
+/// Archimedes' constant (`π`), a half turn in radians.
+pub const PI: f64 = crate::core::f64::consts::PI;
+
+/// `π/2`, a quarter turn in radians (the pitch at the poles).
+pub const FRAC_PI_2: f64 = crate::core::f64::consts::FRAC_PI_2;
+
+/// `1/√2`, the components of a quarter turn around an axis.
+pub const FRAC_1_SQRT_2: f64 = crate::core::f64::consts::FRAC_1_SQRT_2;
+
+/// The Euler–Mascheroni constant (`γ`), used by [`lngamma`](crate::quat::lngamma).
+/// 
+/// `0.57721566490153286060651209008240243104215933593992...`
+/// ([OEIS A001620](https://oeis.org/A001620)), rounded to the nearest [`f64`].
+pub const EULER_MASCHERONI: f64 = 0.577_215_664_901_532_9;
+
+/// The dot product of two unit quaternions above which slerp falls back to
+/// normalized linear interpolation (the angle is too small for `sin` of it to divide by).
+/// 
+/// This is `1 - ERROR` for [f32] and [f64], other [`Axis`] types use their own [`ERROR`](Axis::ERROR)
+/// (`1 - EPSILON` for [Std](crate::structs::Std)), see [`slerp_lerp_threshold`].
+pub const SLERP_LERP_THRESHOLD: f64 = 1.0 - 1.0 / 65536.0;
+
+/// [`HALF`] as `Num`.
+#[inline]
+pub fn half<Num: Axis>() -> Num {
+    Num::from_f64(HALF)
+}
+
+/// [`PI`] as `Num`.
+#[inline]
+pub fn pi<Num: Axis>() -> Num {
+    Num::from_f64(PI)
+}
+
+/// [`FRAC_PI_2`] as `Num`.
+#[inline]
+pub fn frac_pi_2<Num: Axis>() -> Num {
+    Num::from_f64(FRAC_PI_2)
+}
+
+/// [`FRAC_1_SQRT_2`] as `Num`.
+#[inline]
+pub fn frac_1_sqrt_2<Num: Axis>() -> Num {
+    Num::from_f64(FRAC_1_SQRT_2)
+}
+
+/// [`EULER_MASCHERONI`] as `Num`.
+#[inline]
+pub fn euler_mascheroni<Num: Axis>() -> Num {
+    Num::from_f64(EULER_MASCHERONI)
+}
+
+/// The slerp threshold for `Num`: `1 - ERROR`
+/// (equal to [`SLERP_LERP_THRESHOLD`] for [f32] and [f64], [Std](crate::structs::Std) gives `1 - EPSILON`).
+#[inline]
+pub fn slerp_lerp_threshold<Num: Axis>() -> Num {
+    Num::ONE - Num::ERROR
+}
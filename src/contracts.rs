@@ -0,0 +1,468 @@
+/*!
+The numeric promises of the crate, as functions that check them.
+
+Every contract runs the real functions of [`quat`] on the fixed [`SAMPLES`]
+(and [`POINTS`] for the rotations) and gives back a [`ContractViolation`]
+for the first sample the promise doesn't hold on.
+The crate's own tests run all of them, so a change in behaviour has to change a contract here
+instead of silently shifting results.
+Downstream code can run them too, to check that an upgrade
+(or a custom [`Axis`] type) still behaves the way it relies on.
+
+The contracts ending in `_exact` promise bit for bit results and take no tolerance,
+the ones ending in `_within` take the largest allowed error.
+Errors are absolute for unit quaternions and relative to the magnitude of the expected result otherwise,
+and rotations are compared up to the sign of the quaternion.
+
+[`check_all`] runs every contract with the tolerances from the crate documentation
+([`EPS_ARITH`](Axis::EPS_ARITH) for plain arithmetic, [`EPS_TRIG`](Axis::EPS_TRIG) for the rest).
+
+# Example
+```
+use quaternion_traits::contracts;
+
+assert_eq!( contracts::check_all::<f32>(), Ok(()) );
+assert_eq!( contracts::mul_is_associative_within::<f64>(1e-12), Ok(()) );
+
+// nothing is associative within a negative tolerance
+let violation = contracts::mul_is_associative_within::<f64>(-1.0).unwrap_err();
+assert_eq!( violation.contract, "mul_is_associative_within" );
+assert_eq!( violation.sample, 0 );
+```
+ */
+
+use crate::{
+    Axis,
+    Scalar,
+};
+use crate::quat;
+use crate::core::result::Result;
+
+type Q<Num> = [Num; 4];
+
+/// The quaternions the contracts are checked on.
+/// 
+/// A mix of unit and non-unit quaternions, with some components `0`,
+/// contracts that need unit quaternions normalize them first.
+pub const SAMPLES: [[f64; 4]; 8] = [
+    [1.0, 2.0, 3.0, 4.0],
+    [0.5, -1.5, 0.25, 2.0],
+    [-2.0, 0.0, 1.0, -0.5],
+    [0.0, 0.0, 0.0, 1.0],
+    [3.0, 0.1, -0.2, 0.3],
+    [-0.75, 0.5, -0.25, 0.125],
+    [0.1, 2.5, -1.5, 0.0],
+    [1.0, 0.0, 0.0, 0.0],
+];
+
+/// The points the rotation contracts rotate.
+pub const POINTS: [[f64; 3]; 4] = [
+    [1.0, 0.0, 0.0],
+    [0.0, -2.0, 0.5],
+    [3.0, 1.0, -4.0],
+    [-0.25, 0.75, 0.5],
+];
+
+/// The interpolation amounts the slerp contracts use.
+pub const AMOUNTS: [f64; 5] = [0.0, 0.1, 0.5, 0.75, 1.0];
+
+/// The details of a contract that didn't hold.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContractViolation<Num> {
+    /// The name of the contract function.
+    pub contract: &'static str,
+    /// The index of the first sample in [`SAMPLES`] it didn't hold on.
+    pub sample: usize,
+    /// The error of the result (NaN if the result was NaN).
+    pub error: Num,
+    /// The largest allowed error (`0` for the exact contracts).
+    pub tolerance: Num,
+}
+
+impl<Num: crate::core::fmt::Display> crate::core::fmt::Display for ContractViolation<Num> {
+    fn fmt(&self, f: &mut crate::core::fmt::Formatter<'_>) -> crate::core::fmt::Result {
+        crate::core::write!(
+            f,
+            "contract {} broken on sample {} (error {}, tolerance {})",
+            self.contract, self.sample, self.error, self.tolerance,
+        )
+    }
+}
+
+impl<Num: crate::core::fmt::Debug + crate::core::fmt::Display> crate::core::error::Error for ContractViolation<Num> {}
+
+/// Passes if `error` is at most `tolerance` (so a NaN error never passes).
+#[inline]
+fn check<Num: Axis>(contract: &'static str, sample: usize, error: Num, tolerance: Num) -> Result<(), ContractViolation<Num>> {
+    if error <= tolerance {
+        Result::Ok(())
+    } else {
+        Result::Err(ContractViolation { contract, sample, error, tolerance })
+    }
+}
+
+/// Passes if the two quaternions are exactly the same.
+#[inline]
+fn check_exact<Num: Axis>(contract: &'static str, sample: usize, result: Q<Num>, expected: Q<Num>) -> Result<(), ContractViolation<Num>> {
+    if result == expected {
+        Result::Ok(())
+    } else {
+        Result::Err(ContractViolation {
+            contract,
+            sample,
+            error: quat::dist_euclid(result, expected),
+            tolerance: Num::ZERO,
+        })
+    }
+}
+
+#[inline]
+fn sample<Num: Axis>(index: usize) -> Q<Num> {
+    SAMPLES[index].map(Num::from_f64)
+}
+
+#[inline]
+fn unit_sample<Num: Axis>(index: usize) -> Q<Num> {
+    quat::normalize(sample::<Num>(index))
+}
+
+#[cfg(feature = "rotation")]
+#[inline]
+fn point<Num: Axis>(index: usize) -> [Num; 3] {
+    POINTS[index].map(Num::from_f64)
+}
+
+/// The distance between two quaternions, relative to the magnitude of `expected`.
+#[inline]
+fn relative_error<Num: Axis>(result: Q<Num>, expected: Q<Num>) -> Num {
+    quat::dist_euclid::<Num, Num>(result, expected) / quat::abs::<Num, Num>(expected).max(Num::ONE)
+}
+
+/// The distance between two unit quaternions, up to their sign.
+#[cfg(any(feature = "rotation", feature = "matrix"))]
+#[inline]
+fn rotation_error<Num: Axis>(result: Q<Num>, expected: Q<Num>) -> Num {
+    quat::dist_euclid::<Num, Num>(result, expected)
+        .min(quat::dist_euclid::<Num, Num>(result, quat::neg::<Num, Q<Num>>(expected)))
+}
+
+/// The distance between two points, relative to the length of `expected`.
+#[cfg(feature = "rotation")]
+#[inline]
+fn point_error<Num: Axis>(result: [Num; 3], expected: [Num; 3]) -> Num {
+    let difference: [Num; 3] = [result[0] - expected[0], result[1] - expected[1], result[2] - expected[2]];
+    let length = |v: [Num; 3]| (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    length(difference) / length(expected).max(Num::ONE)
+}
+
+/// The angle between two unit quaternions on the unit sphere of `R⁴`
+/// (half the angle of the rotation between them, if they are in the same hemisphere).
+#[cfg(feature = "math_fns")]
+#[inline]
+fn sphere_angle<Num: Axis>(from: Q<Num>, to: Q<Num>) -> Num {
+    let two: Num = Num::from_f64(2.0);
+    two * Num::atan2(
+        quat::dist_euclid::<Num, Num>(&from, &to),
+        quat::abs::<Num, Num>(quat::add::<Num, Q<Num>>(from, to)),
+    )
+}
+
+/// Multiplying by the identity (on either side) gives back the exact same quaternion.
+pub fn mul_identity_exact<Num: Axis>() -> Result<(), ContractViolation<Num>> {
+    let identity: Q<Num> = [Num::ONE, Num::ZERO, Num::ZERO, Num::ZERO];
+    for index in 0..SAMPLES.len() {
+        let q: Q<Num> = sample(index);
+        check_exact("mul_identity_exact", index, quat::mul(identity, q), q)?;
+        check_exact("mul_identity_exact", index, quat::mul(q, identity), q)?;
+    }
+    Result::Ok(())
+}
+
+/// `(a * b) * c` and `a * (b * c)` are the same, relative to `|a| |b| |c|`.
+pub fn mul_is_associative_within<Num: Axis>(tolerance: impl Scalar<Num>) -> Result<(), ContractViolation<Num>> {
+    let tolerance: Num = tolerance.scalar();
+    for index in 0..SAMPLES.len() {
+        let a: Q<Num> = sample(index);
+        let b: Q<Num> = sample((index + 1) % SAMPLES.len());
+        let c: Q<Num> = sample((index + 2) % SAMPLES.len());
+        let left: Q<Num> = quat::mul(quat::mul::<Num, Q<Num>>(a, b), c);
+        let right: Q<Num> = quat::mul(a, quat::mul::<Num, Q<Num>>(b, c));
+        let scale: Num = quat::abs::<Num, Num>(a) * quat::abs::<Num, Num>(b) * quat::abs::<Num, Num>(c);
+        check("mul_is_associative_within", index, quat::dist_euclid::<Num, Num>(left, right) / scale, tolerance)?;
+    }
+    Result::Ok(())
+}
+
+/// `|a * b|` is `|a| |b|`, relative to `|a| |b|`.
+pub fn mul_abs_is_multiplicative_within<Num: Axis>(tolerance: impl Scalar<Num>) -> Result<(), ContractViolation<Num>> {
+    let tolerance: Num = tolerance.scalar();
+    for index in 0..SAMPLES.len() {
+        let a: Q<Num> = sample(index);
+        let b: Q<Num> = sample((index + 3) % SAMPLES.len());
+        let expected: Num = quat::abs::<Num, Num>(a) * quat::abs::<Num, Num>(b);
+        let result: Num = quat::abs(quat::mul::<Num, Q<Num>>(a, b));
+        check("mul_abs_is_multiplicative_within", index, (result - expected).abs() / expected, tolerance)?;
+    }
+    Result::Ok(())
+}
+
+/// `conj(a * b)` is `conj(b) * conj(a)`.
+pub fn conj_reverses_mul_within<Num: Axis>(tolerance: impl Scalar<Num>) -> Result<(), ContractViolation<Num>> {
+    let tolerance: Num = tolerance.scalar();
+    for index in 0..SAMPLES.len() {
+        let a: Q<Num> = sample(index);
+        let b: Q<Num> = sample((index + 5) % SAMPLES.len());
+        let result: Q<Num> = quat::conj(quat::mul::<Num, Q<Num>>(a, b));
+        let expected: Q<Num> = quat::mul(quat::conj::<Num, Q<Num>>(b), quat::conj::<Num, Q<Num>>(a));
+        check("conj_reverses_mul_within", index, relative_error(result, expected), tolerance)?;
+    }
+    Result::Ok(())
+}
+
+/// `q * inv(q)` and `inv(q) * q` are both the identity.
+pub fn inv_is_mul_inverse_within<Num: Axis>(tolerance: impl Scalar<Num>) -> Result<(), ContractViolation<Num>> {
+    let tolerance: Num = tolerance.scalar();
+    let identity: Q<Num> = [Num::ONE, Num::ZERO, Num::ZERO, Num::ZERO];
+    for index in 0..SAMPLES.len() {
+        let q: Q<Num> = sample(index);
+        let inverse: Q<Num> = quat::inv(q);
+        check("inv_is_mul_inverse_within", index, quat::dist_euclid(quat::mul::<Num, Q<Num>>(q, inverse), identity), tolerance)?;
+        check("inv_is_mul_inverse_within", index, quat::dist_euclid(quat::mul::<Num, Q<Num>>(inverse, q), identity), tolerance)?;
+    }
+    Result::Ok(())
+}
+
+/// `inv(inv(q))` is `q`.
+pub fn inv_is_involution_within<Num: Axis>(tolerance: impl Scalar<Num>) -> Result<(), ContractViolation<Num>> {
+    let tolerance: Num = tolerance.scalar();
+    for index in 0..SAMPLES.len() {
+        let q: Q<Num> = sample(index);
+        let result: Q<Num> = quat::inv(quat::inv::<Num, Q<Num>>(q));
+        check("inv_is_involution_within", index, relative_error(result, q), tolerance)?;
+    }
+    Result::Ok(())
+}
+
+/// [`normalize`](quat::normalize) gives a quaternion with an absolute value of `1`.
+pub fn normalize_is_unit_within<Num: Axis>(tolerance: impl Scalar<Num>) -> Result<(), ContractViolation<Num>> {
+    let tolerance: Num = tolerance.scalar();
+    for index in 0..SAMPLES.len() {
+        let error: Num = (quat::abs::<Num, Num>(unit_sample::<Num>(index)) - Num::ONE).abs();
+        check("normalize_is_unit_within", index, error, tolerance)?;
+    }
+    Result::Ok(())
+}
+
+/// [`exp`](quat::exp) of `0` is exactly the identity.
+#[cfg(feature = "math_fns")]
+pub fn exp_of_origin_is_identity_exact<Num: Axis>() -> Result<(), ContractViolation<Num>> {
+    let origin: Q<Num> = [Num::ZERO; 4];
+    check_exact("exp_of_origin_is_identity_exact", 0, quat::exp(origin), [Num::ONE, Num::ZERO, Num::ZERO, Num::ZERO])
+}
+
+/// `exp(ln(q))` is `q`.
+#[cfg(feature = "math_fns")]
+pub fn exp_ln_roundtrip_within<Num: Axis>(tolerance: impl Scalar<Num>) -> Result<(), ContractViolation<Num>> {
+    let tolerance: Num = tolerance.scalar();
+    for index in 0..SAMPLES.len() {
+        let q: Q<Num> = sample(index);
+        let result: Q<Num> = quat::exp(quat::ln::<Num, Q<Num>>(q));
+        check("exp_ln_roundtrip_within", index, relative_error(result, q), tolerance)?;
+    }
+    Result::Ok(())
+}
+
+/// `ln(exp(q))` is `q`, for quaternions with a vector part shorter then `π`
+/// (the samples are scaled to an absolute value of `1.5`).
+#[cfg(feature = "math_fns")]
+pub fn ln_exp_roundtrip_within<Num: Axis>(tolerance: impl Scalar<Num>) -> Result<(), ContractViolation<Num>> {
+    let tolerance: Num = tolerance.scalar();
+    for index in 0..SAMPLES.len() {
+        let q: Q<Num> = quat::scale(unit_sample::<Num>(index), Num::from_f64(1.5));
+        let result: Q<Num> = quat::ln(quat::exp::<Num, Q<Num>>(q));
+        check("ln_exp_roundtrip_within", index, relative_error(result, q), tolerance)?;
+    }
+    Result::Ok(())
+}
+
+/// [`slerp_eased`](quat::slerp_eased) gives back exactly `from` at `0`
+/// and exactly `to` at `1` (its negative if they are in opposite hemispheres, for the shorter path).
+/// 
+/// This is a promise of the endpoint shortcut of [`slerp_eased`](quat::slerp_eased) only,
+/// the interpolation itself ([`slerp_unchecked`](quat::slerp_unchecked) and [`slerp_checked`](quat::slerp_checked))
+/// only gets there up to rounding, its endpoints are covered by [`slerp_constant_speed_within`].
+#[cfg(feature = "math_fns")]
+pub fn slerp_eased_endpoints_exact<Num: Axis>() -> Result<(), ContractViolation<Num>> {
+    let linear = |at: Num| at;
+    for index in 0..SAMPLES.len() {
+        let from: Q<Num> = unit_sample(index);
+        let to: Q<Num> = unit_sample((index + 1) % SAMPLES.len());
+        let end: Q<Num> = if quat::dot::<Num, Num>(from, to) < Num::ZERO { quat::neg(to) } else { to };
+        check_exact("slerp_eased_endpoints_exact", index, quat::slerp_eased(from, to, Num::ZERO, linear), from)?;
+        check_exact("slerp_eased_endpoints_exact", index, quat::slerp_eased(from, to, Num::ONE, linear), end)?;
+    }
+    Result::Ok(())
+}
+
+/// [`slerp_checked`](quat::slerp_checked) of unit quaternions stays a unit quaternion.
+#[cfg(feature = "math_fns")]
+pub fn slerp_stays_unit_within<Num: Axis>(tolerance: impl Scalar<Num>) -> Result<(), ContractViolation<Num>> {
+    let tolerance: Num = tolerance.scalar();
+    for index in 0..SAMPLES.len() {
+        let from: Q<Num> = unit_sample(index);
+        let to: Q<Num> = unit_sample((index + 2) % SAMPLES.len());
+        for at in AMOUNTS {
+            let error: Num = match quat::slerp_checked::<Num, Q<Num>>(from, to, Num::from_f64(at)) {
+                crate::core::option::Option::Some(result) => (quat::abs::<Num, Num>(result) - Num::ONE).abs(),
+                crate::core::option::Option::None => Num::NAN,
+            };
+            check("slerp_stays_unit_within", index, error, tolerance)?;
+        }
+    }
+    Result::Ok(())
+}
+
+/// [`slerp_checked`](quat::slerp_checked) moves at a constant angular speed:
+/// after `at` of the way it has covered `at` of the angle (in radians on the unit sphere of `R⁴`).
+#[cfg(feature = "math_fns")]
+pub fn slerp_constant_speed_within<Num: Axis>(tolerance: impl Scalar<Num>) -> Result<(), ContractViolation<Num>> {
+    let tolerance: Num = tolerance.scalar();
+    for index in 0..SAMPLES.len() {
+        let from: Q<Num> = unit_sample(index);
+        let to: Q<Num> = quat::align_to(unit_sample::<Num>((index + 3) % SAMPLES.len()), from);
+        let total: Num = sphere_angle(from, to);
+        for at in AMOUNTS {
+            let at: Num = Num::from_f64(at);
+            let error: Num = match quat::slerp_checked::<Num, Q<Num>>(from, to, at) {
+                crate::core::option::Option::Some(result) => (sphere_angle(from, result) - at * total).abs(),
+                crate::core::option::Option::None => Num::NAN,
+            };
+            check("slerp_constant_speed_within", index, error, tolerance)?;
+        }
+    }
+    Result::Ok(())
+}
+
+/// [`point_rotation`](quat::point_rotation) doesn't change the length of the point,
+/// relative to that length.
+#[cfg(feature = "rotation")]
+pub fn rotation_preserves_length_within<Num: Axis>(tolerance: impl Scalar<Num>) -> Result<(), ContractViolation<Num>> {
+    let tolerance: Num = tolerance.scalar();
+    let length = |v: [Num; 3]| (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    for index in 0..SAMPLES.len() {
+        for p in 0..POINTS.len() {
+            let point: [Num; 3] = point::<Num>(p);
+            let rotated: [Num; 3] = quat::point_rotation(sample::<Num>(index), point);
+            let error: Num = (length(rotated) - length(point)).abs() / length(point);
+            check("rotation_preserves_length_within", index, error, tolerance)?;
+        }
+    }
+    Result::Ok(())
+}
+
+/// Rotating by `a * b` is the same as rotating by `b`, then by `a`
+/// (relative to the length of the point).
+#[cfg(feature = "rotation")]
+pub fn rotation_composes_within<Num: Axis>(tolerance: impl Scalar<Num>) -> Result<(), ContractViolation<Num>> {
+    let tolerance: Num = tolerance.scalar();
+    for index in 0..SAMPLES.len() {
+        let a: Q<Num> = unit_sample(index);
+        let b: Q<Num> = unit_sample((index + 4) % SAMPLES.len());
+        let both: Q<Num> = quat::mul(a, b);
+        for p in 0..POINTS.len() {
+            let point: [Num; 3] = point::<Num>(p);
+            let result: [Num; 3] = quat::point_rotation(both, point);
+            let expected: [Num; 3] = quat::point_rotation(a, quat::point_rotation::<Num, [Num; 3]>(b, point));
+            check("rotation_composes_within", index, point_error(result, expected), tolerance)?;
+        }
+    }
+    Result::Ok(())
+}
+
+/// [`from_axis_angle`](quat::from_axis_angle) of [`to_axis_angle`](quat::to_axis_angle)
+/// gives back the same rotation.
+/// 
+/// The identity has no axis, for it [`to_axis_angle`](quat::to_axis_angle) has to give
+/// a zero axis and an angle of `0` instead (which [`from_axis_angle`](quat::from_axis_angle) can't take back).
+#[cfg(feature = "rotation")]
+pub fn axis_angle_roundtrip_within<Num: Axis>(tolerance: impl Scalar<Num>) -> Result<(), ContractViolation<Num>> {
+    let tolerance: Num = tolerance.scalar();
+    for index in 0..SAMPLES.len() {
+        let q: Q<Num> = unit_sample(index);
+        let (axis, angle) = quat::to_axis_angle::<Num, [Num; 3], Num>(q);
+        let error: Num = if q[1] == Num::ZERO && q[2] == Num::ZERO && q[3] == Num::ZERO {
+            angle.abs() + axis[0].abs() + axis[1].abs() + axis[2].abs()
+        } else {
+            rotation_error(quat::from_axis_angle(axis, angle), q)
+        };
+        check("axis_angle_roundtrip_within", index, error, tolerance)?;
+    }
+    Result::Ok(())
+}
+
+/// [`from_rotation`](quat::from_rotation) of [`to_rotation`](quat::to_rotation)
+/// gives back the same rotation.
+#[cfg(feature = "rotation")]
+pub fn euler_roundtrip_within<Num: Axis>(tolerance: impl Scalar<Num>) -> Result<(), ContractViolation<Num>> {
+    let tolerance: Num = tolerance.scalar();
+    for index in 0..SAMPLES.len() {
+        let q: Q<Num> = unit_sample(index);
+        let result: Q<Num> = quat::from_rotation(quat::to_rotation::<Num, [Num; 3]>(q));
+        check("euler_roundtrip_within", index, rotation_error(result, q), tolerance)?;
+    }
+    Result::Ok(())
+}
+
+/// [`from_matrix_3`](quat::from_matrix_3) of [`to_matrix_3`](quat::to_matrix_3)
+/// gives back the same rotation.
+#[cfg(feature = "matrix")]
+pub fn matrix_roundtrip_within<Num: Axis>(tolerance: impl Scalar<Num>) -> Result<(), ContractViolation<Num>> {
+    let tolerance: Num = tolerance.scalar();
+    for index in 0..SAMPLES.len() {
+        let q: Q<Num> = unit_sample(index);
+        let matrix: [[Num; 3]; 3] = quat::to_matrix_3::<Num, Num, [[Num; 3]; 3]>(q);
+        let result: Q<Num> = quat::from_matrix_3::<Num, Num, Q<Num>>(matrix);
+        check("matrix_roundtrip_within", index, rotation_error(result, q), tolerance)?;
+    }
+    Result::Ok(())
+}
+
+/// Runs every contract, with [`EPS_ARITH`](Axis::EPS_ARITH) as the tolerance for plain arithmetic
+/// and [`EPS_TRIG`](Axis::EPS_TRIG) for everything that goes through square roots or trigonometry.
+/// 
+/// Gives back the first violation.
+pub fn check_all<Num: Axis>() -> Result<(), ContractViolation<Num>> {
+    let arith: Num = Num::EPS_ARITH;
+    let trig: Num = Num::EPS_TRIG;
+
+    mul_identity_exact::<Num>()?;
+    mul_is_associative_within::<Num>(arith)?;
+    mul_abs_is_multiplicative_within::<Num>(trig)?;
+    conj_reverses_mul_within::<Num>(arith)?;
+    inv_is_mul_inverse_within::<Num>(arith)?;
+    inv_is_involution_within::<Num>(arith)?;
+    normalize_is_unit_within::<Num>(trig)?;
+
+    #[cfg(feature = "math_fns")]
+    {
+        exp_of_origin_is_identity_exact::<Num>()?;
+        exp_ln_roundtrip_within::<Num>(trig)?;
+        ln_exp_roundtrip_within::<Num>(trig)?;
+        slerp_eased_endpoints_exact::<Num>()?;
+        slerp_stays_unit_within::<Num>(trig)?;
+        slerp_constant_speed_within::<Num>(trig)?;
+    }
+
+    #[cfg(feature = "rotation")]
+    {
+        rotation_preserves_length_within::<Num>(trig)?;
+        rotation_composes_within::<Num>(trig)?;
+        axis_angle_roundtrip_within::<Num>(trig)?;
+        euler_roundtrip_within::<Num>(trig)?;
+    }
+
+    #[cfg(feature = "matrix")]
+    matrix_roundtrip_within::<Num>(trig)?;
+
+    Result::Ok(())
+}
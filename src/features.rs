@@ -0,0 +1,144 @@
+/*!
+The feature flags this crate was compiled with.
+
+Every constant here is `true` when its feature is enabled, so downstream code
+can check them with a plain `if` (the branch that can't be taken gets compiled out).
+[`FEATURES`] and [`summary`] list every enabled feature for diagnostics and logging.
+
+Meta features (`full` and `num`) aren't listed, only the features they enable.
+
+# Example
+```
+use quaternion_traits::features;
+
+if features::MATRIX {
+    // picks a branch only, calling items of the `matrix` feature (like `to_matrix_3`) still needs a `#[cfg]`
+    println!("matrices available");
+} else {
+    println!("no matrices, features: {}", features::summary());
+}
+
+assert_eq!( features::FEATURES.contains(&"matrix"), features::MATRIX );
+```
+ */
+
+use crate::core::str::from_utf8;
+use crate::core::result::Result;
+
+/// If the `std` feature is enabled.
+pub const STD: bool = cfg!(feature = "std");
+/// If the `deterministic` feature is enabled.
+pub const DETERMINISTIC: bool = cfg!(feature = "deterministic");
+/// If the `alloc` feature is enabled.
+pub const ALLOC: bool = cfg!(feature = "alloc");
+/// If the `qol_fns` feature is enabled.
+pub const QOL_FNS: bool = cfg!(feature = "qol_fns");
+/// If the `math_fns` feature is enabled.
+pub const MATH_FNS: bool = cfg!(feature = "math_fns");
+/// If the `trigonometry` feature is enabled.
+pub const TRIGONOMETRY: bool = cfg!(feature = "trigonometry");
+/// If the `rotation` feature is enabled.
+pub const ROTATION: bool = cfg!(feature = "rotation");
+/// If the `matrix` feature is enabled.
+pub const MATRIX: bool = cfg!(feature = "matrix");
+/// If the `display` feature is enabled.
+pub const DISPLAY: bool = cfg!(feature = "display");
+/// If the `unstable` feature is enabled.
+pub const UNSTABLE: bool = cfg!(feature = "unstable");
+
+macro_rules! enabled_features {
+    ( $( $feature:literal ),* $(,)? ) => {
+        &[ $( #[cfg(feature = $feature)] $feature, )* ]
+    };
+}
+
+/// The names of every enabled feature (in the order of the crate's feature list).
+pub const FEATURES: &[&str] = enabled_features![
+    "std",
+    "std_axis_for_primitives",
+    "deterministic",
+    "alloc",
+    "qol_fns",
+    "math_fns",
+    "trigonometry",
+    "rotation",
+    "matrix",
+    "display",
+    "compact_float",
+    "test_helpers",
+    "test_harness",
+    "panic_free_check",
+    "unstable",
+    "f16",
+    "f128",
+    "portable_simd",
+    "num-traits",
+    "num-complex",
+    "num-rational",
+    "num-bigint",
+    "serde",
+    "rkyv",
+    "bevy_math",
+    "rayon",
+    "ndarray",
+];
+
+const SEPARATOR: &[u8] = b", ";
+
+const SUMMARY_LEN: usize = {
+    let mut len: usize = 0;
+    let mut n: usize = 0;
+    while n < FEATURES.len() {
+        if n > 0 {
+            len += SEPARATOR.len();
+        }
+        len += FEATURES[n].len();
+        n += 1;
+    }
+    len
+};
+
+const SUMMARY_BYTES: [u8; SUMMARY_LEN] = {
+    let mut bytes: [u8; SUMMARY_LEN] = [0; SUMMARY_LEN];
+    let mut at: usize = 0;
+    let mut n: usize = 0;
+    while n < FEATURES.len() {
+        if n > 0 {
+            let mut m: usize = 0;
+            while m < SEPARATOR.len() {
+                bytes[at] = SEPARATOR[m];
+                at += 1;
+                m += 1;
+            }
+        }
+        let feature: &[u8] = FEATURES[n].as_bytes();
+        let mut m: usize = 0;
+        while m < feature.len() {
+            bytes[at] = feature[m];
+            at += 1;
+            m += 1;
+        }
+        n += 1;
+    }
+    bytes
+};
+
+const SUMMARY: &str = match from_utf8(&SUMMARY_BYTES) {
+    Result::Ok(summary) => summary,
+    Result::Err(_) => "",
+};
+
+/// Every enabled feature in one line, separated by `", "` (empty if there are none).
+/// 
+/// Built at compile time from [`FEATURES`].
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::features;
+/// 
+/// assert_eq!( features::summary(), features::FEATURES.join(", ") );
+/// ```
+#[inline]
+pub const fn summary() -> &'static str {
+    SUMMARY
+}
@@ -20,19 +20,29 @@ and [RotationType](https://docs.rs/quaternion-core/latest/quaternion_core/enum.R
 - [num-complex](https://crates.io/crates/num-complex) (feature `num-complex`)
 - [num-rational](https://crates.io/crates/num-rational) (feature `num-rational`)
 - [num-bigint](https://crates.io/crates/num-bigint) (feature `num-bigint`)
+- [bevy_math](https://crates.io/crates/bevy_math) (feature `bevy_math`)
+  (No `Rotation` impls, the roll, pitch and yaw used here are bevy's
+  [`EulerRot::ZYX`](https://docs.rs/bevy_math/latest/bevy_math/enum.EulerRot.html) angles in reverse order,
+  for the other orders convert through bevy's `Quat`)
 
 # Details
 
 ## Implementation
 
-Currently the quaternion traits are implemented only in core rust and num,
-but it's planned to add (though optional dependencies) these traits to crates like
-[bevy](https://crates.io/crates/bevy)
-and the other quaternion crates out there.
+Currently the quaternion traits are implemented in core rust, num and
+[bevy](https://crates.io/crates/bevy) (through `bevy_math`),
+but it's planned to add (though optional dependencies) these traits to
+the other quaternion crates out there.
 
 Due to how the traits are implemented this crate is naturaly usable with the
 [quaternion](https://crates.io/crates/quaternion) crate. So for any crates that use
 this crate this dependency is (hopefully) frictionless.
+Its `(w, [x, y, z])` tuples can be given to any function here directly
+and [Quat](structs::Quat) converts to and from them with [From](crate::core::convert::From).
+The only difference found is the argument order of [`rotate_vector`](quat::rotate_vector)
+(the vector comes first here). [`quat::rotate_vector`](quat::rotate_vector) gives the same result
+as the `quaternion` crate's since version `3.0.0` of this crate, before that it gave
+`2 * vector - rotated` (a breaking change, see its docs for how to migrate).
 
 If possible this crate should implement functions for every quaternion use.
 And it should implement every function that a crate it's comapatble with has.
@@ -40,14 +50,12 @@ And it should implement every function that a crate it's comapatble with has.
 This crate also currently has these pure maths functions for quaternions (excluding common ones):
 [`exp`](quat::exp), [`ln`](quat::ln), [`sqrt`](quat::sqrt), [`sin`](quat::sin),
 [`sinh`](quat::sinh), [`sec`](quat::sec), [`cos`](quat::cos), [`cosh`](quat::cosh),
-[`csc`](quat::csc), [`sin_cos`](quat::sin_cos), [`tan`](quat::tan), [`tanh`](quat::tanh),
+[`csc`](quat::csc), [`sin_cos`](quat::sin_cos), [`sinh_cosh`](quat::sinh_cosh), [`tan`](quat::tan), [`tanh`](quat::tanh),
 [`cot`](quat::cot), [`coth`](quat::coth), [`asin`](quat::asin), [`asinh`](quat::asinh),
 [`asec`](quat::asec), [`acos`](quat::acos), [`acosh`](quat::acosh), [`acsc`](quat::acsc),
 [`atan`](quat::atan), [`atanh`](quat::atanh), [`acot`](quat::acot), [`acoth`](quat::acoth).
 
 This crate provides currently an unstable form of these functions:
-- [`rem`](quat::rem) (the equasion used uses gaussian intigers, but untill a paper showing this is the convention
-or that another method is prefered this will remain as unstable)
 - [`pow`](quat::pow_q) (the equasion used seams to not be fully agreed on though so it's at risk of change if
   another equasion comes out that is guaranteed to be correct)
 - [`log`](quat::log) (simple [`ln`](quat::ln) division only works for complex quaternions that include the real axis and
@@ -56,17 +64,38 @@ or that another method is prefered this will remain as unstable)
 
 List of features:
 - `std`: (enabled by default) Adds `alloc` feature, adds [Std](structs::Std) struct.
+- `std_axis_for_primitives`: Adds `std` feature, makes the [Axis] implementations of
+  [f32] and [f64] use the std methods instead of the [libm](https://docs.rs/libm/latest/libm/) ones
+  (the same ones used by [Std](structs::Std)).
+- `deterministic`: Makes the [Axis] implementations of [f32], [f64] and [Std](structs::Std)
+  always use the [libm](https://docs.rs/libm/latest/libm/) methods (even with `std_axis_for_primitives`),
+  so the same inputs give bit-identical results on every platform (eg: lock-step multiplayer simulations).
+  Assumes IEEE 754 float arithmetic, which excludes the x87 unit of 32 bit x86 without SSE2.
 - `alloc`: Adds [Quaternion], [Vector], [Complex] and [Scalar] implementations for
-Box, Arc, Rc and Cow, adds the [`to_string`](quat::to_string) function.
+  Box, Arc, Rc and Cow, adds the [`to_string`](quat::to_string) function and the [QuatSpline](structs::QuatSpline) struct.
 - `full`: (enabled by default) Enables all stable feature flags that don't have any dependencies
 (`qol_fns`, `math_fns`, `trigonometry`, `rotation`, `matrix`, `display`).
 - `qol_fns`: Adds quality of life functions and methods. (eg: [`add_scalar`](quat::add_scalar))
 - `math_fns`: Adds pure math focused functions. (eg: [`sqrt`](quat::sqrt))
 - `trigonometry`: Adds trigonomentric functions. (eg: [`sin`](quat::sin))
 - `rotation`: Adds rotation arithmatic functions. (eg: [`rotation_from_to`](quat::rotation_from_to))
+  the [`WorldFrame`](structs::WorldFrame) and [`LocalFrame`](structs::LocalFrame) wrappers
+  and the [`Similarity`](structs::Similarity) struct.
 - `matrix`: Adds matrix arithmatic functions. (eg: [`to_matrix_3`](quat::to_matrix_3))
+  and the [`RowMajor`](structs::RowMajor) and [`ColMajor`](structs::ColMajor) adapters.
 - `display`: Adds [str] and [String](crate::alloc::string::String) functions. (eg: [`display`](quat::display))
+- `compact_float`: Makes [`display`](quat::display) show [f32] and [f64] numbers using a small
+  formatter of this crate instead of the float formatting of `core` (which is quite large on embedded targets).
+- `test_helpers`: Adds the `assert_quat_near!` and `assert_same_rotation!` macros
+  (and the `test_helpers` module they use) for testing code that uses quaternions.
+- `test_harness`: Adds the `axis_tests` module, which checks that a custom [Axis]
+  implementation follows the contract this crate relies on.
 - `unstable`: Enables items that may change functionality or may be removed entirely.
+- `panic_free_check`: Makes every function in [quat] and [unit] that is meant to never panic
+  fail to link if the compiler can't prove that it doesn't panic (using [no-panic](https://crates.io/crates/no-panic)).
+  Only useful for testing this crate, run `cargo test --profile panic-free-check --features panic_free_check --test panic_free`.
+
+Which features the crate was compiled with can be checked at runtime with the [features] module.
 
 List of dependency features:
 - `num`: Adds all the num traits `num-traits`, `num-complex`, `num-rational`, `num-bigint`.
@@ -77,10 +106,48 @@ List of dependency features:
 - `serde`: Adds [Serialize](https://docs.rs/serde/latest/serde/trait.Serialize.html)
 and [Deserialize](https://docs.rs/serde/latest/serde/trait.Deserialize.html) implementation
 for [Std](structs::Std).
+- `rkyv`: Adds [rkyv](https://docs.rs/rkyv/latest/rkyv/) `Archive`, `Serialize` and `Deserialize` implementations
+  for [Quat](structs::Quat), [Std](structs::Std) and [UnitQuat](structs::UnitQuat)
+  (the archived ones are quaternions too, and archived [UnitQuat](structs::UnitQuat)s are checked to be normalized when validated).
+- `rayon`: Adds `std` feature, adds the `quat::par` module with versions of the slice functions
+  (eg: [`normalize_slice`](quat::normalize_slice)) that split the work across the [rayon](https://docs.rs/rayon/latest/rayon/) thread pool.
+- `ndarray`: Adds `alloc` feature, implements [Quaternion] and [Vector] for one dimensional
+  [ndarray](https://docs.rs/ndarray/latest/ndarray/) arrays and views (panicking when the length is wrong),
+  their constructors for `Array1`, and adds `quat::from_array2_rows` to read the rows of an `Array2` in bulk.
+
+## Tolerances
+
+Approximate comparisons use one of the two tolerances of [Axis]:
+- [`EPS_ARITH`](traits::Axis::EPS_ARITH) (defaults to [`ERROR`](traits::Axis::ERROR), `4 * ERROR` for [Std](structs::Std)) for results of plain arithmetic:
+  [`is_near`](quat::is_near), the length ratio of [`is_close`](quat::is_close),
+  [`are_nearly_mul_commutative`](quat::are_nearly_mul_commutative) and `assert_quat_near!`.
+- [`EPS_TRIG`](traits::Axis::EPS_TRIG) (`8 * ERROR` for [f32], [f64] and [Std](structs::Std)) for results that went
+  through trigonometric functions or square roots: every unit quaternion check
+  ([`is_normalized`](quat::is_normalized), [`UnitQuat::new`](structs::UnitQuat::new),
+  [`new_unit_quat`](traits::UnitQuaternionConstructor::new_unit_quat), [`to_unit_quat_checked`](quat::to_unit_quat_checked),
+  [`slerp_checked`](quat::slerp_checked), [`from_axis_angle_checked`](quat::from_axis_angle_checked), ...),
+  the angle part of [`is_close`](quat::is_close) and `assert_same_rotation!`.
+
+The [contracts] module checks the promises made with these tolerances
+([`contracts::check_all`] runs all of them).
+
+[`ERROR`](traits::Axis::ERROR) itself stays the general precision step
+(picking numerically stable branches, snapping to the poles, step sizes).
+
+Coming from the single `ERROR`:
+- Looser: the unit quaternion checks went from `|abs² - 1| < ERROR²` (which normalized [f32] quaternions often failed)
+  to `|abs² - 1| < EPS_TRIG`, the angle part of [`is_close`](quat::is_close) and the default tolerance
+  of `assert_same_rotation!` are `8` times larger.
+- Stricter: [`from_axis_angle_checked`](quat::from_axis_angle_checked) now also rejects axes that are too short
+  (only too long ones were rejected before).
+- The same: [`is_near`](quat::is_near), the length ratio of [`is_close`](quat::is_close),
+  [`are_nearly_mul_commutative`](quat::are_nearly_mul_commutative) and `assert_quat_near!`.
+- Custom [Axis] types get `EPS_ARITH = EPS_TRIG = ERROR` by default,
+  so for them only the unit quaternion checks change (from `ERROR²` to `ERROR`).
 
 ## Versions
 
-Current version **0.2.0.1**
+Current version **0.3.0.0** (`3.0.0` on crates.io)
 
 This crate uses the *HUMAN.MAJOR.MINOR.PATCH* version format.
 And version *1.0.0.0* would be the first true release.
@@ -139,6 +206,21 @@ extern crate num_integer;
 #[cfg(feature = "serde")]
 extern crate serde;
 
+#[cfg(feature = "rkyv")]
+extern crate rkyv;
+
+#[cfg(feature = "bevy_math")]
+extern crate bevy_math;
+
+#[cfg(feature = "rayon")]
+extern crate rayon;
+
+#[cfg(feature = "ndarray")]
+extern crate ndarray;
+
+#[cfg(feature = "panic_free_check")]
+extern crate no_panic;
+
 extern crate core;
 extern crate libm;
 // extern crate thiserror as err;
@@ -151,6 +233,7 @@ pub use traits::{
     Quaternion,
     QuaternionConstructor,
     QuaternionConsts,
+    QuaternionFloatConsts,
     QuaternionMethods,
 
     UnitQuaternion,
@@ -190,4 +273,16 @@ pub mod unit;
 
 pub mod structs;
 
+pub mod features;
+
+pub mod contracts;
+
+pub mod consts;
+
+#[cfg(feature = "test_helpers")]
+pub mod test_helpers;
+
+#[cfg(feature = "test_harness")]
+pub mod axis_tests;
+
 
@@ -35,6 +35,7 @@ use crate::{
     Scalar,
     ScalarConstructor,
 };
+use crate::traits::AxisBytes;
 
 #[cfg(feature = "rotation")]
 use crate::{
@@ -67,9 +68,24 @@ mod rotation_ops;
 #[cfg(feature = "rotation")]
 pub use rotation_ops::*;
 
+#[cfg(feature = "rotation")]
+pub mod rotation_strategies;
+
+pub mod blas;
+
+pub mod ease;
+
+pub mod vec;
+
 mod iterator_ops;
 pub use iterator_ops::*;
 
+mod batch;
+pub use batch::*;
+
+#[cfg(feature = "rayon")]
+pub mod par;
+
 mod conversions;
 pub use conversions::*;
 
@@ -0,0 +1,333 @@
+// Functions that work on four `f32` quaternions at once,
+// and functions that work on whole slices of quaternions.
+//
+// The quaternions are given as `[[f32; 4]; 4]` (one `[r, i, j, k]` per quaternion),
+// get transposed so each axis of all four quaternions sits next to each other
+// and then every operation is done on all four lanes at once without branching.
+// This lets the autovectorizer use full width operations even
+// without the `portable_simd` feature.
+//
+// The slice functions are plain loops over the elements
+// (each one is the same as calling the single quaternion function on it),
+// the `par` module has the same functions split across threads.
+// With the `ndarray` feature the rows of a 2D array can be read out in bulk.
+
+use crate::Axis;
+#[cfg(feature = "math_fns")]
+use crate::Scalar;
+#[cfg(feature = "rotation")]
+use crate::Quaternion;
+use crate::core::iter::Iterator;
+use crate::core::array::from_fn;
+
+/// Turns four `[r, i, j, k]` quaternions into `[[r; 4], [i; 4], [j; 4], [k; 4]]` (and back).
+#[inline(always)]
+fn transpose(quats: &[[f32; 4]; 4]) -> [[f32; 4]; 4] {
+    [
+        [quats[0][0], quats[1][0], quats[2][0], quats[3][0]],
+        [quats[0][1], quats[1][1], quats[2][1], quats[3][1]],
+        [quats[0][2], quats[1][2], quats[2][2], quats[3][2]],
+        [quats[0][3], quats[1][3], quats[2][3], quats[3][3]],
+    ]
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Multiplies four pairs of quaternions at once.
+/// 
+/// Same as calling [`mul`](super::mul) on each pair.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{mul4, mul};
+/// 
+/// let left: [[f32; 4]; 4] = [
+///     [1.0, 2.0, 3.0, 4.0],
+///     [0.0, 1.0, 0.0, 0.0],
+///     [-1.5, 0.5, 2.0, -3.0],
+///     [0.1, 0.2, 0.3, 0.4],
+/// ];
+/// let right: [[f32; 4]; 4] = [
+///     [4.0, 3.0, 2.0, 1.0],
+///     [0.0, 0.0, 1.0, 0.0],
+///     [2.5, -1.0, 0.0, 7.0],
+///     [0.4, 0.3, 0.2, 0.1],
+/// ];
+/// 
+/// let result: [[f32; 4]; 4] = mul4(&left, &right);
+/// 
+/// for n in 0..4 {
+///     assert_eq!( result[n], mul::<f32, [f32; 4]>(left[n], right[n]) );
+/// }
+/// ```
+pub fn mul4(left: &[[f32; 4]; 4], right: &[[f32; 4]; 4]) -> [[f32; 4]; 4] {
+    let [lr, li, lj, lk] = transpose(left);
+    let [rr, ri, rj, rk] = transpose(right);
+    transpose(&[
+        from_fn(|n| lr[n] * rr[n] - li[n] * ri[n] - lj[n] * rj[n] - lk[n] * rk[n]),
+        from_fn(|n| lr[n] * ri[n] + li[n] * rr[n] + lj[n] * rk[n] - lk[n] * rj[n]),
+        from_fn(|n| lr[n] * rj[n] - li[n] * rk[n] + lj[n] * rr[n] + lk[n] * ri[n]),
+        from_fn(|n| lr[n] * rk[n] + li[n] * rj[n] - lj[n] * ri[n] + lk[n] * rr[n]),
+    ])
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gets the dot products of four pairs of quaternions at once.
+/// 
+/// Same as calling [`dot`](super::dot) on each pair.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{dot4, dot};
+/// 
+/// let left: [[f32; 4]; 4] = [
+///     [1.0, 2.0, 3.0, 4.0],
+///     [0.0, 1.0, 0.0, 0.0],
+///     [-1.5, 0.5, 2.0, -3.0],
+///     [0.1, 0.2, 0.3, 0.4],
+/// ];
+/// let right: [[f32; 4]; 4] = [
+///     [4.0, 3.0, 2.0, 1.0],
+///     [0.0, 0.0, 1.0, 0.0],
+///     [2.5, -1.0, 0.0, 7.0],
+///     [0.4, 0.3, 0.2, 0.1],
+/// ];
+/// 
+/// let result: [f32; 4] = dot4(&left, &right);
+/// 
+/// for n in 0..4 {
+///     assert_eq!( result[n], dot::<f32, f32>(left[n], right[n]) );
+/// }
+/// ```
+pub fn dot4(left: &[[f32; 4]; 4], right: &[[f32; 4]; 4]) -> [f32; 4] {
+    let [lr, li, lj, lk] = transpose(left);
+    let [rr, ri, rj, rk] = transpose(right);
+    from_fn(|n| lr[n] * rr[n] + li[n] * ri[n] + lj[n] * rj[n] + lk[n] * rk[n])
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Normalizes four quaternions at once.
+/// 
+/// Same as calling [`normalize`](super::normalize) on each quaternion,
+/// so the origin quaternion stays the origin
+/// and quaternions with a subnormal axis get rescaled first (on the slower path).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{normalize4, normalize};
+/// 
+/// let quats: [[f32; 4]; 4] = [
+///     [1.0, 2.0, 3.0, 4.0],
+///     [0.0, 0.0, 0.0, 0.0],
+///     [-1.5, 0.5, 2.0, -3.0],
+///     [1e-38, 0.0, 0.0, 0.0],
+/// ];
+/// 
+/// let result: [[f32; 4]; 4] = normalize4(&quats);
+/// 
+/// for n in 0..4 {
+///     assert_eq!( result[n], normalize::<f32, [f32; 4]>(quats[n]) );
+/// }
+/// ```
+pub fn normalize4(quats: &[[f32; 4]; 4]) -> [[f32; 4]; 4] {
+    let [r, i, j, k] = transpose(quats);
+    let is_origin: [bool; 4] = from_fn(|n| (r[n] == 0.0) & (i[n] == 0.0) & (j[n] == 0.0) & (k[n] == 0.0));
+    let length: [f32; 4] = from_fn(|n| 1.0 / Axis::sqrt(r[n] * r[n] + i[n] * i[n] + j[n] * j[n] + k[n] * k[n]));
+    let scaled = |axis: [f32; 4]| -> [f32; 4] {
+        from_fn(|n| if is_origin[n] { 0.0 } else { axis[n] * length[n] })
+    };
+    let mut out: [[f32; 4]; 4] = transpose(&[scaled(r), scaled(i), scaled(j), scaled(k)]);
+    // the squares of subnormal axes underflow, those lanes need the rescaling of `normalize`
+    for n in 0..4 {
+        if super::count_subnormals::<f32>(quats[n]) > 0 {
+            out[n] = super::normalize::<f32, [f32; 4]>(quats[n]);
+        }
+    }
+    out
+}
+
+/// Panics with the name of the function if the two inputs and the output have different lengths.
+#[inline]
+#[track_caller]
+pub(crate) fn check_lengths(function: &str, first: usize, second: usize, out: usize) {
+    if first != second || first != out {
+        crate::core::panic!(
+            "Mismatched slice lengths in {}! Got {} and {} input quaternions, but the output holds {}",
+            function, first, second, out,
+        );
+    }
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Normalizes every quaternion of a slice in place.
+/// 
+/// Same as calling [`normalize`](super::normalize) on each quaternion.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{normalize_slice, normalize};
+/// 
+/// let original: [[f32; 4]; 3] = [[1.0, 2.0, 3.0, 4.0], [0.0; 4], [0.0, -2.5, 0.0, 0.0]];
+/// let mut quats: [[f32; 4]; 3] = original;
+/// 
+/// normalize_slice::<f32>(&mut quats);
+/// 
+/// for n in 0..3 {
+///     assert_eq!( quats[n], normalize::<f32, [f32; 4]>(original[n]) );
+/// }
+/// ```
+pub fn normalize_slice<Num>(quats: &mut [[Num; 4]])
+where 
+    Num: Axis,
+{
+    for quat in quats.iter_mut() {
+        *quat = super::normalize(*quat);
+    }
+}
+
+#[inline]
+/// Multiplies every pair of quaternions of two slices, writing the products into a third slice.
+/// 
+/// Same as calling [`mul`](super::mul) on each pair.
+/// 
+/// # Panics
+/// Panics if the three slices have different lengths.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{mul_slices, mul};
+/// 
+/// let left: [[f32; 4]; 2] = [[1.0, 2.0, 3.0, 4.0], [0.0, 1.0, 0.0, 0.0]];
+/// let right: [[f32; 4]; 2] = [[4.0, 3.0, 2.0, 1.0], [0.0, 0.0, 1.0, 0.0]];
+/// let mut out: [[f32; 4]; 2] = [[0.0; 4]; 2];
+/// 
+/// mul_slices::<f32>(&left, &right, &mut out);
+/// 
+/// for n in 0..2 {
+///     assert_eq!( out[n], mul::<f32, [f32; 4]>(left[n], right[n]) );
+/// }
+/// ```
+pub fn mul_slices<Num>(left: &[[Num; 4]], right: &[[Num; 4]], out: &mut [[Num; 4]])
+where 
+    Num: Axis,
+{
+    check_lengths("mul_slices", left.len(), right.len(), out.len());
+    for ((left, right), out) in left.iter().zip(right.iter()).zip(out.iter_mut()) {
+        *out = super::mul(left, right);
+    }
+}
+
+#[inline]
+#[cfg(feature = "math_fns")]
+/// Spherically interpolates every pair of unit quaternions of two slices by the same amount,
+/// writing the results into a third slice.
+/// 
+/// Same as calling [`slerp_unchecked`](super::slerp_unchecked) on each pair,
+/// so every quaternion must be a unit quaternion.
+/// 
+/// # Panics
+/// Panics if the three slices have different lengths.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{slerp_slices, slerp_unchecked};
+/// 
+/// let from: [[f32; 4]; 2] = [[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0]];
+/// let to: [[f32; 4]; 2] = [[0.0, 0.0, 1.0, 0.0], [0.6, 0.0, 0.0, 0.8]];
+/// let mut out: [[f32; 4]; 2] = [[0.0; 4]; 2];
+/// 
+/// slerp_slices::<f32>(&from, &to, 0.25, &mut out);
+/// 
+/// for n in 0..2 {
+///     assert_eq!( out[n], slerp_unchecked::<f32, [f32; 4]>(from[n], to[n], 0.25) );
+/// }
+/// ```
+pub fn slerp_slices<Num>(from: &[[Num; 4]], to: &[[Num; 4]], at: impl Scalar<Num>, out: &mut [[Num; 4]])
+where 
+    Num: Axis,
+{
+    check_lengths("slerp_slices", from.len(), to.len(), out.len());
+    let at: Num = at.scalar();
+    for ((from, to), out) in from.iter().zip(to.iter()).zip(out.iter_mut()) {
+        *out = super::slerp_unchecked(from, to, at);
+    }
+}
+
+#[inline]
+#[cfg(feature = "rotation")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Rotates every point of a slice in place by the same rotation.
+/// 
+/// Same as calling [`point_rotation`](super::point_rotation) on each point,
+/// but the rotation is only normalized once.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{rotate_points, point_rotation};
+/// 
+/// let rotation: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+/// let original: [[f32; 3]; 2] = [[1.0, 0.0, 0.0], [-2.0, 0.5, 3.0]];
+/// let mut points: [[f32; 3]; 2] = original;
+/// 
+/// rotate_points::<f32>(rotation, &mut points);
+/// 
+/// for n in 0..2 {
+///     assert_eq!( points[n], point_rotation::<f32, [f32; 3]>(rotation, original[n]) );
+/// }
+/// ```
+pub fn rotate_points<Num>(rotation: impl Quaternion<Num>, points: &mut [[Num; 3]])
+where 
+    Num: Axis,
+{
+    let rotation: [Num; 4] = super::normalize(rotation);
+    rotate_points_unchecked(rotation, points);
+}
+
+#[inline]
+#[cfg(feature = "rotation")]
+/// [`rotate_points`] for an already normalized rotation.
+pub(crate) fn rotate_points_unchecked<Num>(rotation: [Num; 4], points: &mut [[Num; 3]])
+where 
+    Num: Axis,
+{
+    for point in points.iter_mut() {
+        *point = super::point_rotation_unchecked(rotation, *point);
+    }
+}
+
+#[inline]
+#[cfg(feature = "ndarray")]
+/// Reads every row of a 2D array with 4 columns as a `[r, i, j, k]` quaternion.
+/// 
+/// The rows themselves can be used as quaternions too
+/// (`arr.row(n)` is a [`Quaternion`](crate::Quaternion)), this copies them out in bulk.
+/// 
+/// # Panics
+/// Panics if the array doesn't have exactly 4 columns.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::from_array2_rows;
+/// use ndarray::array;
+/// 
+/// let arr = array![[1.0, 2.0, 3.0, 4.0], [0.5, 0.0, -1.0, 2.0]];
+/// let quats: Vec<[f64; 4]> = from_array2_rows::<f64>(&arr).collect();
+/// 
+/// assert_eq!( quats, [[1.0, 2.0, 3.0, 4.0], [0.5, 0.0, -1.0, 2.0]] );
+/// ```
+pub fn from_array2_rows<Num>(arr: &crate::ndarray::Array2<Num>) -> impl Iterator<Item = [Num; 4]> + '_
+where 
+    Num: Axis,
+{
+    if arr.ncols() != 4 {
+        crate::core::panic!(
+            "Wrong number of columns in from_array2_rows! Got {}, but quaternions need 4",
+            arr.ncols(),
+        );
+    }
+    crate::core::iter::IntoIterator::into_iter(arr.rows())
+        .map(|row| [row[0], row[1], row[2], row[3]])
+}
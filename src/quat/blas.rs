@@ -0,0 +1,127 @@
+/*!
+The quaternions as flat `R⁴` vectors, with the names used by BLAS.
+
+Numerical optimization code (conjugate gradient, Gauss–Newton, ...)
+usually treats the parameters as plain vectors,
+these are the vector space operations under the names that code uses,
+so porting it is a matter of replacing the calls.
+
+| BLAS    | here                         | same as                              |
+|---------|------------------------------|--------------------------------------|
+| `axpy`  | [`axpy`]                     | `add(scale(x, alpha), y)`            |
+| `dot`   | [`dot`]                      | [`quat::dot`](super::dot)            |
+| `nrm2`  | [`norm2`]                    | [`quat::abs`](super::abs)            |
+| `copy`  | [`copy_into`]                | `*dst = convert_quat(src)`           |
+| `swap`  | [`swap`]                     | `core::mem::swap` for any two types  |
+
+Everything here is elementwise on the 4 components, none of it uses quaternion multiplication.
+ */
+
+use super::*;
+
+pub use super::dot;
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Calculates `alpha * x + y`.
+/// 
+/// The inputs are all read before the output is made, so `x` and `y` can be the same
+/// quaternion (`axpy(alpha, &x, &x)` is `(alpha + 1) * x`)
+/// and the result can be assigned back into either of them.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::blas::axpy;
+/// 
+/// let x: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+/// let mut y: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+/// 
+/// y = axpy::<f32, [f32; 4]>(2.0, x, y);
+/// assert_eq!( y, [3.0, 5.0, 7.0, 9.0] );
+/// 
+/// // aliasing is fine
+/// assert_eq!( axpy::<f32, [f32; 4]>(2.0, &x, &x), [3.0, 6.0, 9.0, 12.0] );
+/// ```
+pub fn axpy<Num, Out>(alpha: impl Scalar<Num>, x: impl Quaternion<Num>, y: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let alpha: Num = alpha.scalar();
+    Out::new_quat(
+        alpha * x.r() + y.r(),
+        alpha * x.i() + y.i(),
+        alpha * x.j() + y.j(),
+        alpha * x.k() + y.k(),
+    )
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Calculates the euclidean norm of a quaternion as a vector of `R⁴`.
+/// 
+/// Same as [`abs`].
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::blas::norm2;
+/// 
+/// assert_eq!( norm2::<f32, f32>([1.0, 2.0, 2.0, 4.0]), 5.0 );
+/// ```
+pub fn norm2<Num, Out>(x: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: ScalarConstructor<Num>,
+{
+    abs(x)
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Writes the components of `src` into `dst`, converting them into the type of `dst`.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::blas::copy_into;
+/// 
+/// let src: (f32, [f32; 3]) = (1.0, [2.0, 3.0, 4.0]);
+/// let mut dst: [f32; 4] = [0.0; 4];
+/// 
+/// copy_into::<f32, [f32; 4]>(src, &mut dst);
+/// assert_eq!( dst, [1.0, 2.0, 3.0, 4.0] );
+/// ```
+pub fn copy_into<Num, Dst>(src: impl Quaternion<Num>, dst: &mut Dst)
+where 
+    Num: Axis,
+    Dst: QuaternionConstructor<Num>,
+{
+    *dst = Dst::from_quat(src);
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Swaps the components of two quaternions, which can be of different types.
+/// 
+/// For two quaternions of the same type this is the same as [`swap`](crate::core::mem::swap).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::blas::swap;
+/// 
+/// let mut a: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+/// let mut b: (f32, [f32; 3]) = (5.0, [6.0, 7.0, 8.0]);
+/// 
+/// swap::<f32, _, _>(&mut a, &mut b);
+/// assert_eq!( a, [5.0, 6.0, 7.0, 8.0] );
+/// assert_eq!( b, (1.0, [2.0, 3.0, 4.0]) );
+/// ```
+pub fn swap<Num, A, B>(a: &mut A, b: &mut B)
+where 
+    Num: Axis,
+    A: Quaternion<Num> + QuaternionConstructor<Num>,
+    B: Quaternion<Num> + QuaternionConstructor<Num>,
+{
+    let old_a: Q<Num> = (a.r(), [a.i(), a.j(), a.k()]);
+    *a = A::from_quat(&*b);
+    *b = B::from_quat(old_a);
+}
@@ -2,7 +2,7 @@
 use super::*;
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Constructs a quaternion representation from another one.
 /// 
 /// # Example
@@ -32,7 +32,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Changes the inner type used by the quaternion.
 pub fn convert_num<Num, To, Out>(from: impl Quaternion<Num>) -> Out
 where 
@@ -49,7 +49,220 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Turns a quaternion of [f32]s into one of [f64]s.
+/// 
+/// This is lossless, every [f32] is exactly representable as a [f64].
+/// Same as `convert_num::<f32, f64, Out>`, but without spelling out the types.
+/// 
+/// The other way around is [`narrow`].
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::widen;
+/// 
+/// assert_eq!( widen::<[f64; 4]>([0.1_f32, 1.0, -2.5, 1e30]), [0.1_f32 as f64, 1.0, -2.5, 1e30_f32 as f64] );
+/// ```
+pub fn widen<Out>(quaternion: impl Quaternion<f32>) -> Out
+where 
+    Out: QuaternionConstructor<f64>,
+{
+    convert_num::<f32, f64, Out>(quaternion)
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Turns a quaternion of [f64]s into one of [f32]s.
+/// 
+/// This is lossy: every component is rounded to the nearest [f32]
+/// (values too large for [f32] become infinite, and too small ones zero).
+/// Same as `convert_num::<f64, f32, Out>`.
+/// 
+/// The other way around is [`widen`].
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::narrow;
+/// 
+/// assert_eq!( narrow::<[f32; 4]>([0.1_f64, 1.0, 1e300, 1e-300]), [0.1_f32, 1.0, f32::INFINITY, 0.0] );
+/// ```
+pub fn narrow<Out>(quaternion: impl Quaternion<f64>) -> Out
+where 
+    Out: QuaternionConstructor<f32>,
+{
+    convert_num::<f64, f32, Out>(quaternion)
+}
+
+#[inline]
+/// Changes the inner type used by every quaternion of a slice, writing them into another slice.
+/// 
+/// Same as calling [`convert_num`] on each quaternion,
+/// but written as a plain loop over the two slices so the casts can be vectorized.
+/// 
+/// # Panics
+/// Panics if the two slices have different lengths.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::convert_num_slice;
+/// 
+/// let wide: [[f64; 4]; 2] = [[1.0, 2.0, 3.0, 4.0], [0.1, -0.2, 1e300, -1e-300]];
+/// let mut narrow: [[f32; 4]; 2] = [[0.0; 4]; 2];
+/// 
+/// convert_num_slice::<f64, f32>(&wide, &mut narrow);
+/// assert_eq!( narrow, [[1.0, 2.0, 3.0, 4.0], [0.1, -0.2, f32::INFINITY, -0.0]] );
+/// ```
+pub fn convert_num_slice<Num, To>(src: &[[Num; 4]], dst: &mut [[To; 4]])
+where 
+    Num: Axis,
+    To: Axis + ScalarConstructor<Num>,
+{
+    if src.len() != dst.len() {
+        crate::core::panic!(
+            "Mismatched slice lengths in convert_num_slice! Got {} source and {} destination quaternions",
+            src.len(), dst.len(),
+        );
+    }
+    use crate::core::iter::Iterator;
+    for (from, to) in src.iter().zip(dst.iter_mut()) {
+        *to = [
+            ScalarConstructor::new_scalar(from[0]),
+            ScalarConstructor::new_scalar(from[1]),
+            ScalarConstructor::new_scalar(from[2]),
+            ScalarConstructor::new_scalar(from[3]),
+        ];
+    }
+}
+
+#[inline]
+#[cfg(feature = "alloc")]
+/// Changes the inner type used by every quaternion of a slice, collecting them into a new [`Vec`](crate::alloc::vec::Vec).
+/// 
+/// Check [`convert_num_slice`] for more info.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::convert_num_vec;
+/// 
+/// let narrow: [[f32; 4]; 2] = [[1.0, 2.0, 3.0, 4.0], [0.1, -0.2, f32::MAX, f32::MIN_POSITIVE]];
+/// let wide: Vec<[f64; 4]> = convert_num_vec::<f32, f64>(&narrow);
+/// 
+/// assert_eq!( wide, [[1.0, 2.0, 3.0, 4.0], [0.1_f32 as f64, -0.2_f32 as f64, f32::MAX as f64, f32::MIN_POSITIVE as f64]] );
+/// ```
+pub fn convert_num_vec<Num, To>(src: &[[Num; 4]]) -> crate::alloc::vec::Vec<[To; 4]>
+where 
+    Num: Axis,
+    To: Axis + ScalarConstructor<Num>,
+{
+    use crate::core::iter::Iterator;
+    src.iter()
+        .map(|from: &[Num; 4]| [
+            ScalarConstructor::new_scalar(from[0]),
+            ScalarConstructor::new_scalar(from[1]),
+            ScalarConstructor::new_scalar(from[2]),
+            ScalarConstructor::new_scalar(from[3]),
+        ])
+        .collect()
+}
+
+/// Writes the numbers of a quaternion (`r`, `i`, `j`, `k`) one after the other,
+/// each of them with the given byte order.
+#[inline]
+fn write_bytes<Num: AxisBytes>(quaternion: impl Quaternion<Num>, to_bytes: impl crate::core::ops::Fn(Num) -> Num::Bytes) -> Num::QuatBytes {
+    use crate::core::convert::{AsRef, AsMut};
+    use crate::core::iter::Iterator;
+    let size: usize = crate::core::mem::size_of::<Num::Bytes>();
+    let parts: [Num; 4] = [quaternion.r(), quaternion.i(), quaternion.j(), quaternion.k()];
+    let mut bytes: Num::QuatBytes = crate::core::default::Default::default();
+    for (n, part) in crate::core::iter::IntoIterator::into_iter(parts).enumerate() {
+        bytes.as_mut()[n * size..(n + 1) * size].copy_from_slice(to_bytes(part).as_ref());
+    }
+    bytes
+}
+
+/// Reads the numbers of a quaternion written by [`write_bytes`].
+#[inline]
+fn read_bytes<Num: AxisBytes, Out: QuaternionConstructor<Num>>(bytes: Num::QuatBytes, from_bytes: impl crate::core::ops::Fn(Num::Bytes) -> Num) -> Out {
+    use crate::core::convert::{AsRef, AsMut};
+    let size: usize = crate::core::mem::size_of::<Num::Bytes>();
+    let part = |n: usize| -> Num {
+        let mut part: Num::Bytes = crate::core::default::Default::default();
+        part.as_mut().copy_from_slice(&bytes.as_ref()[n * size..(n + 1) * size]);
+        from_bytes(part)
+    };
+    Out::new_quat(part(0), part(1), part(2), part(3))
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gets the little endian bytes of a quaternion.
+/// 
+/// The wire format is `r`, `i`, `j` and `k` one after the other, each in the
+/// little endian byte order of it's IEEE 754 bit pattern ([`AxisBytes::to_le_bytes`]),
+/// so `16` bytes for [f32] and `32` for [f64].
+/// It doesn't depend on the platform and NaN payloads are kept bit for bit.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{to_le_bytes, from_le_bytes};
+/// 
+/// let bytes: [u8; 16] = to_le_bytes::<f32>([1.0, 0.0, -2.0, 0.5]);
+/// assert_eq!( bytes[0..4], [0x00, 0x00, 0x80, 0x3F] );
+/// 
+/// assert_eq!( from_le_bytes::<f32, [f32; 4]>(bytes), [1.0, 0.0, -2.0, 0.5] );
+/// ```
+pub fn to_le_bytes<Num: AxisBytes>(quaternion: impl Quaternion<Num>) -> Num::QuatBytes {
+    write_bytes(quaternion, Num::to_le_bytes)
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gets the big endian bytes of a quaternion.
+/// 
+/// Same as [`to_le_bytes`] but every number is in big endian byte order
+/// (the numbers themselves are still in the `r`, `i`, `j`, `k` order).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{to_be_bytes, from_be_bytes};
+/// 
+/// let bytes: [u8; 16] = to_be_bytes::<f32>([1.0, 0.0, -2.0, 0.5]);
+/// assert_eq!( bytes[0..4], [0x3F, 0x80, 0x00, 0x00] );
+/// 
+/// assert_eq!( from_be_bytes::<f32, [f32; 4]>(bytes), [1.0, 0.0, -2.0, 0.5] );
+/// ```
+pub fn to_be_bytes<Num: AxisBytes>(quaternion: impl Quaternion<Num>) -> Num::QuatBytes {
+    write_bytes(quaternion, Num::to_be_bytes)
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Reads a quaternion from the little endian bytes written by [`to_le_bytes`].
+/// 
+/// NaN payloads are kept bit for bit.
+pub fn from_le_bytes<Num, Out>(bytes: Num::QuatBytes) -> Out
+where 
+    Num: AxisBytes,
+    Out: QuaternionConstructor<Num>,
+{
+    read_bytes(bytes, Num::from_le_bytes)
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Reads a quaternion from the big endian bytes written by [`to_be_bytes`].
+/// 
+/// NaN payloads are kept bit for bit.
+pub fn from_be_bytes<Num, Out>(bytes: Num::QuatBytes) -> Out
+where 
+    Num: AxisBytes,
+    Out: QuaternionConstructor<Num>,
+{
+    read_bytes(bytes, Num::from_be_bytes)
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Constructs a quaternion from a unit quaternion.
 /// 
 /// # Example
@@ -78,7 +291,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Constructs a quaternion from a vector representation.
 /// 
 /// # Example
@@ -104,7 +317,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Constructs a quaternion from a complex number representation.
 /// 
 /// # Example
@@ -130,7 +343,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Constructs a quaternion from a scalar value.
 /// 
 /// # Example
@@ -155,12 +368,46 @@ where
     )
 }
 
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Constructs a quaternion with all four components equal to `value`.
+/// 
+/// Handy for uniform bounds in componentwise comparisons and for starting accumulators.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::splat;
+/// 
+/// assert_eq!( splat::<f32, [f32; 4]>(0.5), [0.5, 0.5, 0.5, 0.5] );
+/// ```
+pub fn splat<Num, Out>(value: impl Scalar<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let value: Num = value.scalar();
+    Out::new_quat(value, value, value, value)
+}
+
 // TODO add is_near
 
 #[cfg(feature = "rotation")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Constructs a quaternion from a rotation.
 /// 
+/// The rotation is the yaw around Z, then the pitch around Y and then the roll around X
+/// (in the rotated frame), so the result is `rotation_z(yaw) * rotation_y(pitch) * rotation_x(roll)`
+/// and [`to_rotation`] gives the angles back.
+/// 
+/// The angles go straight into [`sin_cos`](Axis::sin_cos), so for very large angles
+/// (many full turns) the result is only as good as the range reduction of `Num`,
+/// see [`from_rotation_wrapped`] for a version that reduces them first.
+/// 
+/// # Breaking change
+/// Before version `3.0.0` two of the signs were flipped, which gave
+/// a quaternion that wasn't unit and wasn't any rotation of the angles whenever
+/// more than one of them wasn't zero. With a single angle the result is the same as before.
+/// 
 /// # Example
 /// ```
 /// use quaternion_traits::quat::{from_rotation, is_near};
@@ -176,19 +423,88 @@ where
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    let (sin_r, cos_r) = Num::sin_cos(rotation.roll() * Num::from_f64(0.5));
-    let (sin_p, cos_p) = Num::sin_cos(rotation.pitch() * Num::from_f64(0.5));
-    let (sin_y, cos_y) = Num::sin_cos(rotation.yaw() * Num::from_f64(0.5));
+    let (sin_r, cos_r) = Num::sin_cos(rotation.roll() * crate::consts::half::<Num>());
+    let (sin_p, cos_p) = Num::sin_cos(rotation.pitch() * crate::consts::half::<Num>());
+    let (sin_y, cos_y) = Num::sin_cos(rotation.yaw() * crate::consts::half::<Num>());
     QuaternionConstructor::new_quat(
         cos_r * cos_p * cos_y + sin_r * sin_p * sin_y,
-        sin_r * cos_p * cos_y + cos_r * sin_p * sin_y,
+        sin_r * cos_p * cos_y - cos_r * sin_p * sin_y,
         cos_r * sin_p * cos_y + sin_r * cos_p * sin_y,
-        cos_r * cos_p * sin_y + sin_r * sin_p * cos_y,
+        cos_r * cos_p * sin_y - sin_r * sin_p * cos_y,
     )
 }
 
+#[cfg(feature = "rotation")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Constructs a quaternion from a rotation,
+/// or gives back [`None`](Option::None) if any of the angles is NaN or infinite.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{from_rotation, from_rotation_checked};
+/// 
+/// assert_eq!(
+///     from_rotation_checked::<f32, [f32; 4]>([0.5, 1.0, -2.0]),
+///     Some(from_rotation::<f32, [f32; 4]>([0.5, 1.0, -2.0])),
+/// );
+/// assert_eq!( from_rotation_checked::<f32, [f32; 4]>([0.5, f32::NAN, -2.0]), None );
+/// assert_eq!( from_rotation_checked::<f32, [f32; 4]>([f32::INFINITY, 1.0, -2.0]), None );
+/// ```
+pub fn from_rotation_checked<Num, Out>(rotation: impl Rotation<Num>) -> Option<Out>
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let is_finite = |angle: Num| !crate::core::matches!(
+        angle.classify(),
+        crate::core::num::FpCategory::Nan | crate::core::num::FpCategory::Infinite
+    );
+    let (roll, pitch, yaw) = (rotation.roll(), rotation.pitch(), rotation.yaw());
+    if is_finite(roll) && is_finite(pitch) && is_finite(yaw) {
+        Option::Some(from_rotation([roll, pitch, yaw]))
+    } else {
+        Option::None
+    }
+}
+
+#[cfg(feature = "rotation")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Constructs a quaternion from a rotation,
+/// reducing each angle modulo [`TAU`](Axis::TAU) before halving it.
+/// 
+/// The rotation is the same as the one of [`from_rotation`],
+/// but `sin_cos` only ever gets angles in `(-π, π)`,
+/// so it doesn't depend on how well `Num` handles large arguments.
+/// The quaternion can be the negative of the one [`from_rotation`] gives,
+/// since taking away a full turn flips the sign of the half angle.
+/// 
+/// This can't bring back precision that the angle itself doesn't have:
+/// an `f32` angle of a thousand turns is only precise to about `0.0002`.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{from_rotation, from_rotation_wrapped, is_near};
+/// # use core::f64::consts::PI;
+/// 
+/// let spun: [f64; 4] = from_rotation_wrapped::<f64, [f64; 4]>([1000.0 * PI + 0.1, 0.0, 0.0]);
+/// let plain: [f64; 4] = from_rotation::<f64, [f64; 4]>([0.1, 0.0, 0.0]);
+/// 
+/// assert!( is_near::<f64>(spun, plain) );
+/// ```
+pub fn from_rotation_wrapped<Num, Out>(rotation: impl Rotation<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    from_rotation([
+        rotation.roll() % Num::TAU,
+        rotation.pitch() % Num::TAU,
+        rotation.yaw() % Num::TAU,
+    ])
+}
+
 #[cfg(feature = "math_fns")] 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Calculates a quaternion using the given polar form.
 /// 
 /// Returns [`None`](Option::None) if the absolute value of `unit_vec`
@@ -198,7 +514,7 @@ where
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    if (unit_vec.x() * unit_vec.x() + unit_vec.y() * unit_vec.y() + unit_vec.z() * unit_vec.z() - Num::ONE).abs() >= Num::ERROR * Num::ERROR {
+    if (unit_vec.x() * unit_vec.x() + unit_vec.y() * unit_vec.y() + unit_vec.z() * unit_vec.z() - Num::ONE).abs() >= Num::EPS_TRIG {
         return Option::None;
     }
     let (sin, cos) = angle.scalar().sin_cos();
@@ -211,7 +527,7 @@ where
 }
 
 #[cfg(feature = "math_fns")] 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Calculates a quaternion using the given polar form.
 /// 
 /// # Safety
@@ -278,6 +594,7 @@ where
 /// );
 /// ```
 #[cfg(feature = "matrix")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn from_matrix_2<Num, Elem, Out>(matrix: impl Matrix<Elem, 2>) -> Option<Out>
 where 
     Num: Axis,
@@ -334,6 +651,7 @@ where
 /// );
 /// ```
 #[cfg(feature = "matrix")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn from_matrix_2_unchecked<Num, Elem, Out>(matrix: impl Matrix<Elem, 2>) -> Out
 where 
     Num: Axis,
@@ -354,7 +672,7 @@ where
 /// a quaternion, this one uses 4 formulas and choses one based on
 /// the inputs, for the most general use case.
 #[cfg(feature = "matrix")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn from_matrix_3<Num, Elem, Out>(matrix: impl Matrix<Elem, 3>) -> Out
 where 
     Num: Axis,
@@ -388,8 +706,8 @@ where
         return Out::new_quat(
             (matrix.get_unchecked(1, 2).scalar() - matrix.get_unchecked(2, 1).scalar()) / (largest * two),
             largest / two,
-            (matrix.get_unchecked(0, 1).scalar() - matrix.get_unchecked(1, 0).scalar()) / (largest * two),
-            (matrix.get_unchecked(2, 0).scalar() - matrix.get_unchecked(0, 2).scalar()) / (largest * two),
+            (matrix.get_unchecked(0, 1).scalar() + matrix.get_unchecked(1, 0).scalar()) / (largest * two),
+            (matrix.get_unchecked(2, 0).scalar() + matrix.get_unchecked(0, 2).scalar()) / (largest * two),
         )
     }
 
@@ -397,9 +715,9 @@ where
         largest = (largest + Num::ONE).sqrt();
         return Out::new_quat(
             (matrix.get_unchecked(2, 0).scalar() - matrix.get_unchecked(0, 2).scalar()) / (largest * two),
-            (matrix.get_unchecked(0, 1).scalar() - matrix.get_unchecked(1, 0).scalar()) / (largest * two),
+            (matrix.get_unchecked(0, 1).scalar() + matrix.get_unchecked(1, 0).scalar()) / (largest * two),
             largest / two,
-            (matrix.get_unchecked(1, 2).scalar() - matrix.get_unchecked(2, 1).scalar()) / (largest * two),
+            (matrix.get_unchecked(1, 2).scalar() + matrix.get_unchecked(2, 1).scalar()) / (largest * two),
         )
     }
 
@@ -407,8 +725,8 @@ where
     largest = (largest + Num::ONE).sqrt();
     return Out::new_quat(
         (matrix.get_unchecked(0, 1).scalar() - matrix.get_unchecked(1, 0).scalar()) / (largest * two),
-        (matrix.get_unchecked(2, 0).scalar() - matrix.get_unchecked(0, 2).scalar()) / (largest * two),
-        (matrix.get_unchecked(1, 2).scalar() - matrix.get_unchecked(2, 1).scalar()) / (largest * two),
+        (matrix.get_unchecked(2, 0).scalar() + matrix.get_unchecked(0, 2).scalar()) / (largest * two),
+        (matrix.get_unchecked(1, 2).scalar() + matrix.get_unchecked(2, 1).scalar()) / (largest * two),
         largest / two,
     )
     
@@ -422,7 +740,7 @@ where
 /// of this matrix instead.
 #[inline]
 #[cfg(feature = "matrix")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn from_matrix_4<Num, Elem, Out>(matrix: impl Matrix<Elem, 4>) -> Out
 where 
     Num: Axis,
@@ -437,8 +755,257 @@ where
     ])
 }
 
+/// Cosntructs a quaternion from a 3x3 matrix (DCM) given as a row-major slice.
+/// 
+/// For data that doesn't have its size known at compile time (like from a file),
+/// gives back [`None`](Option::None) if the slice doesn't have exactly `9` elements,
+/// otherwise it's the same as [`from_matrix_3`].
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{from_matrix_3_slice, from_matrix_3};
+/// 
+/// let data: Vec<f64> = vec![
+///     0.0, 1.0, 0.0,
+///     -1.0, 0.0, 0.0,
+///     0.0, 0.0, 1.0,
+/// ];
+/// 
+/// assert_eq!(
+///     from_matrix_3_slice::<f64, [f64; 4]>(&data),
+///     Some(from_matrix_3::<f64, f64, [f64; 4]>([[0.0, 1.0, 0.0], [-1.0, 0.0, 0.0], [0.0, 0.0, 1.0]])),
+/// );
+/// assert_eq!( from_matrix_3_slice::<f64, [f64; 4]>(&data[..8]), None );
+/// ```
+#[inline]
+#[cfg(feature = "matrix")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn from_matrix_3_slice<Num, Out>(data: &[impl Scalar<Num>]) -> Option<Out>
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let data: &[_; 9] = crate::core::convert::TryInto::try_into(data).ok()?;
+    Option::Some(from_matrix_3::<Num, Num, Out>(data.each_ref().map(|elem| elem.scalar())))
+}
+
+/// Cosntructs a quaternion from a 4x4 matrix given as a row-major slice.
+/// 
+/// Gives back [`None`](Option::None) if the slice doesn't have exactly `16` elements,
+/// otherwise it's the same as [`from_matrix_4`].
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::from_matrix_4_slice;
+/// 
+/// // a rotation around the Z axis and a translation
+/// let data: [f32; 16] = [
+///     0.0, 1.0, 0.0, 0.0,
+///     -1.0, 0.0, 0.0, 0.0,
+///     0.0, 0.0, 1.0, 0.0,
+///     5.0, 6.0, 7.0, 1.0,
+/// ];
+/// 
+/// let quat: Option<[f32; 4]> = from_matrix_4_slice::<f32, [f32; 4]>(&data);
+/// assert!( quat.is_some() );
+/// assert_eq!( from_matrix_4_slice::<f32, [f32; 4]>(&data[..9]), None );
+/// ```
+#[inline]
+#[cfg(feature = "matrix")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn from_matrix_4_slice<Num, Out>(data: &[impl Scalar<Num>]) -> Option<Out>
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let data: &[_; 16] = crate::core::convert::TryInto::try_into(data).ok()?;
+    Option::Some(from_matrix_4::<Num, Num, Out>(data.each_ref().map(|elem| elem.scalar())))
+}
+
+/// Turns a 3x3 matrix that is *almost* a rotation matrix (DCM)
+/// into a proper one using modified Gram–Schmidt.
+/// 
+/// Matrices that come from sensors or got interpolated tend to
+/// drift away from being orthonormal, and [`from_matrix_3`]
+/// will then give back a quaternion that isn't a unit quaternion.
+/// 
+/// # Bias
+/// This method does not treat all rows the same:
+/// - The first row is trusted the most, it only gets normalized.
+/// - The second row only has the part along the first row removed
+///   before being normalized.
+/// - The third row is thrown away and recomputed as the cross product
+///   of the first two, so the result always has a determinant of `+1`.
+/// 
+/// So any error in the first row is kept in the output,
+/// if you know which row is the most reliable put it first.
+/// 
+/// If the first two rows are parallel (or one of them is zero)
+/// the output will contain NaN's or infinities.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::orthonormalize_matrix_3;
+/// 
+/// let drifted: [[f32; 3]; 3] = [
+///     [ 1.001,  0.001, -0.001],
+///     [-0.001,  0.001,  0.999],
+///     [ 0.001, -1.001,  0.001],
+/// ];
+/// 
+/// let fixed: [[f32; 3]; 3] = orthonormalize_matrix_3::<f32, f32, [[f32; 3]; 3]>(drifted);
+/// 
+/// for a in 0..3 {
+///     for b in 0..3 {
+///         let dot: f32 = fixed[a][0] * fixed[b][0]
+///                      + fixed[a][1] * fixed[b][1]
+///                      + fixed[a][2] * fixed[b][2];
+///         let expected: f32 = if a == b { 1.0 } else { 0.0 };
+///         assert!( (dot - expected).abs() < 0.00001 );
+///     }
+/// }
+/// ```
+#[cfg(feature = "matrix")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn orthonormalize_matrix_3<Num, Elem, Out>(matrix: impl Matrix<Elem, 3>) -> Out
+where 
+    Num: Axis,
+    Elem: Scalar<Num>,
+    Out: MatrixConstructor<Num, 3>,
+{
+    let row = |row: usize| -> [Num; 3] {[
+        matrix.get_unchecked(row, 0).scalar(),
+        matrix.get_unchecked(row, 1).scalar(),
+        matrix.get_unchecked(row, 2).scalar(),
+    ]};
+
+    let x: [Num; 3] = row(0);
+    let x_len: Num = (x[0]*x[0] + x[1]*x[1] + x[2]*x[2]).sqrt();
+    let x: [Num; 3] = [x[0] / x_len, x[1] / x_len, x[2] / x_len];
+
+    let y: [Num; 3] = row(1);
+    let along_x: Num = y[0]*x[0] + y[1]*x[1] + y[2]*x[2];
+    let y: [Num; 3] = [
+        y[0] - along_x * x[0],
+        y[1] - along_x * x[1],
+        y[2] - along_x * x[2],
+    ];
+    let y_len: Num = (y[0]*y[0] + y[1]*y[1] + y[2]*y[2]).sqrt();
+    let y: [Num; 3] = [y[0] / y_len, y[1] / y_len, y[2] / y_len];
+
+    let z: [Num; 3] = [
+        x[1]*y[2] - x[2]*y[1],
+        x[2]*y[0] - x[0]*y[2],
+        x[0]*y[1] - x[1]*y[0],
+    ];
+
+    Out::new_matrix([x, y, z])
+}
+
+/// Cosntructs a quaternion from a 3x3 matrix (DCM) that
+/// might have drifted away from being a rotation matrix.
+/// 
+/// This first runs the matrix through [`orthonormalize_matrix_3`]
+/// (check it for the bias of the method) and then through [`from_matrix_3`],
+/// so the output is a unit quaternion as long as the first
+/// two rows of the matrix are not parallel.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{from_matrix_3_orthonormalized, abs, dot};
+/// 
+/// // a 90 degree rotation around the i axis
+/// let clean: [f32; 4] = [
+///     core::f32::consts::FRAC_1_SQRT_2,
+///     core::f32::consts::FRAC_1_SQRT_2,
+///     0.0,
+///     0.0,
+/// ];
+/// 
+/// // the matrix of `clean` with every entry moved by 0.001
+/// let drifted: [[f32; 3]; 3] = [
+///     [ 1.001,  0.001, -0.001],
+///     [-0.001,  0.001,  0.999],
+///     [ 0.001, -1.001,  0.001],
+/// ];
+/// 
+/// let q: [f32; 4] = from_matrix_3_orthonormalized::<f32, f32, [f32; 4]>(drifted);
+/// 
+/// assert!( (abs::<f32, f32>(q) - 1.0).abs() < 0.00001 );
+/// // cos(angle / 2) of the angle inbetween the two rotations
+/// assert!( dot::<f32, f32>(q, clean).abs() > 0.99999 );
+/// ```
+#[inline]
+#[cfg(feature = "matrix")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn from_matrix_3_orthonormalized<Num, Elem, Out>(matrix: impl Matrix<Elem, 3>) -> Out
+where 
+    Num: Axis,
+    Elem: Scalar<Num>,
+    Out: QuaternionConstructor<Num>,
+{
+    from_matrix_3::<Num, Num, Out>(
+        orthonormalize_matrix_3::<Num, Elem, [[Num; 3]; 3]>(matrix)
+    )
+}
+
+/// Constructs the quaternion of a frame from it's three (approximately orthonormal) basis vectors,
+/// aka the columns of it's rotation matrix.
+/// 
+/// The vectors go through the same path as [`from_matrix_3_orthonormalized`],
+/// so `x` is trusted the most, `y` only has it's part along `x` removed and
+/// `z` is only used for checking the handedness.
+/// 
+/// Returns [`None`](Option::None) if `x` and `y` are (within [`Num::ERROR`](Axis::ERROR) of) parallel
+/// or one of them is zero, or if `z` points away from `x × y` (a left handed frame isn't a rotation).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{frame_from_columns, from_axis_angle, rotate_vector, dot};
+/// 
+/// let quat: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([1.0, -1.0, 2.0], 2.0);
+/// let x: [f32; 3] = rotate_vector::<f32, [f32; 3]>([1.0, 0.0, 0.0], quat);
+/// let y: [f32; 3] = rotate_vector::<f32, [f32; 3]>([0.0, 1.0, 0.0], quat);
+/// let z: [f32; 3] = rotate_vector::<f32, [f32; 3]>([0.0, 0.0, 1.0], quat);
+/// 
+/// let back: [f32; 4] = frame_from_columns::<f32, [f32; 4]>(x, y, z).unwrap();
+/// // `q` and `-q` are the same rotation
+/// assert!( dot::<f32, f32>(back, quat).abs() > 0.99999 );
+/// 
+/// assert_eq!( frame_from_columns::<f32, [f32; 4]>(x, x, z), None );
+/// assert_eq!( frame_from_columns::<f32, [f32; 4]>(x, y, [-z[0], -z[1], -z[2]]), None );
+/// ```
+#[cfg(feature = "matrix")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn frame_from_columns<Num, Out>(x: impl Vector<Num>, y: impl Vector<Num>, z: impl Vector<Num>) -> Option<Out>
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let cross: [Num; 3] = [
+        x.y() * y.z() - x.z() * y.y(),
+        x.z() * y.x() - x.x() * y.z(),
+        x.x() * y.y() - x.y() * y.x(),
+    ];
+    let cross_len: Num = (cross[0] * cross[0] + cross[1] * cross[1] + cross[2] * cross[2]).sqrt();
+    let x_len: Num = (x.x() * x.x() + x.y() * x.y() + x.z() * x.z()).sqrt();
+    let y_len: Num = (y.x() * y.x() + y.y() * y.y() + y.z() * y.z()).sqrt();
+
+    let independent: bool = cross_len > Num::ERROR * x_len * y_len;
+    let right_handed: bool = cross[0] * z.x() + cross[1] * z.y() + cross[2] * z.z() > Num::ZERO;
+    if !(independent && right_handed) {
+        return Option::None;
+    }
+    // the rows of the matrix here are the columns of the rotation matrix
+    Option::Some(from_matrix_3_orthonormalized::<Num, Num, Out>([
+        [x.x(), x.y(), x.z()],
+        [y.x(), y.y(), y.z()],
+        [z.x(), z.y(), z.z()],
+    ]))
+}
+
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Constructs a unit quaternion from a normal quaternion by normalizing the quaternion.
 pub fn to_unit_quat<Num, Out>(quat: impl Quaternion<Num>) -> Out
 where 
@@ -452,7 +1019,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Constructs a unit quaternion from a normal quaternion.
 /// 
 /// Returns [`None`](Option::None) if the quaternion is an invalid unit quaternion.
@@ -465,7 +1032,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Constructs a unit quaternion from a normal quaternion
 /// without checking if it's a valid unit quaternion.
 pub unsafe fn to_unit_quat_unchecked<Num, Out>(quat: impl Quaternion<Num>) -> Out
@@ -479,7 +1046,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Constructs a complex number representation from a quaternion.
 /// 
 /// # Example
@@ -504,7 +1071,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Constructs a complex number representation from a quaternion.
 /// 
 /// # Example
@@ -528,7 +1095,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Constructs a scalar value from a quaternion.
 /// 
 /// # Example
@@ -550,10 +1117,63 @@ where
     )
 }
 
+/// Tells if (and which) pole a quaternion was at when it got turned into a rotation.
+/// 
+/// Given back by [`to_rotation_ext`].
+#[cfg(feature = "rotation")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GimbalLock {
+    /// The pitch is not near `±π/2`, all three angles are meaningful.
+    #[default]
+    None,
+    /// The pitch is near `π/2`, roll and yaw rotate around the same axis
+    /// so the whole rotation is put in the roll and the yaw is zero.
+    North,
+    /// The pitch is near `-π/2`, roll and yaw rotate around the same axis
+    /// so the whole rotation is put in the roll and the yaw is zero.
+    South,
+}
+
+/// The range the roll and yaw angles are given back in by [`to_rotation_ext`].
+/// 
+/// The pitch is always in `[-π/2, π/2]`.
+#[cfg(feature = "rotation")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AngleRange {
+    /// Angles are in `(-π, π]`.
+    #[default]
+    Signed,
+    /// Angles are in `[0, 2π)`.
+    Unsigned,
+}
+
+#[cfg(feature = "rotation")]
+impl AngleRange {
+    /// Wraps an angle in `[-2π, 2π]` into this range.
+    #[inline]
+    fn wrap<Num: Axis>(self, angle: Num) -> Num {
+        let pi: Num = Num::TAU / Num::from_f64(2.0);
+        match self {
+            AngleRange::Signed if angle > pi => angle - Num::TAU,
+            AngleRange::Signed if angle <= -pi => angle + Num::TAU,
+            AngleRange::Unsigned if angle < Num::ZERO => angle + Num::TAU,
+            AngleRange::Unsigned if angle >= Num::TAU => angle - Num::TAU,
+            _ => angle,
+        }
+    }
+}
+
+#[inline]
 #[cfg(feature = "rotation")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Constructs a rotation representation from a quaternion.
 /// 
+/// Roll and yaw are in `(-π, π]`, pitch is in `[-π/2, π/2]`.
+/// Near the poles (gimbal lock) the yaw is zero and the whole rotation is put in the roll,
+/// use [`to_rotation_ext`] to know when that happens or to get the angles in `[0, 2π)`.
+/// 
+/// The origin has no rotation, it gives back NaN angles.
+/// 
 /// # Example
 /// ```
 /// use quaternion_traits::quat::to_rotation;
@@ -569,38 +1189,156 @@ where
     Num: Axis,
     Out: RotationConstructor<Num>
 {
-    let quat: Q<Num> = normalize(quaternion);
+    to_rotation_ext::<Num, Out>(quaternion, AngleRange::Signed).0
+}
 
-    let two = Num::from_f64(2.0);
+#[cfg(feature = "rotation")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Constructs a rotation representation from a quaternion,
+/// with the roll and yaw in the given `range` and
+/// whether the quaternion was at one of the poles.
+/// 
+/// If the pitch is within [`Num::ERROR`](Axis::ERROR) (on the sine) of `±π/2`
+/// the roll and yaw rotate around the same axis, so the yaw is set to zero,
+/// the whole rotation is put in the roll and the pitch is exactly `±π/2`.
+/// That is reported with [`GimbalLock::North`] (positive pitch) or [`GimbalLock::South`] (negative pitch),
+/// otherwise it's [`GimbalLock::None`].
+/// 
+/// [`to_rotation`] is the same as this with [`AngleRange::Signed`] and without the flag.
+/// The origin gives back NaN angles (and [`GimbalLock::None`]).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{to_rotation_ext, from_rotation, GimbalLock, AngleRange};
+/// use core::f32::consts::{PI, FRAC_PI_2};
+/// 
+/// let quat: [f32; 4] = from_rotation::<f32, [f32; 4]>([-FRAC_PI_2, 0.25, -3.0]);
+/// 
+/// let (signed, lock) = to_rotation_ext::<f32, [f32; 3]>(quat, AngleRange::Signed);
+/// assert_eq!( lock, GimbalLock::None );
+/// assert!( (signed[0] + FRAC_PI_2).abs() < 0.0001 && (signed[2] + 3.0).abs() < 0.0001 );
+/// 
+/// let (unsigned, _) = to_rotation_ext::<f32, [f32; 3]>(quat, AngleRange::Unsigned);
+/// assert!( (unsigned[0] - 3.0 * FRAC_PI_2).abs() < 0.0001 && (unsigned[2] - (2.0 * PI - 3.0)).abs() < 0.0001 );
+/// 
+/// // pitched straight up
+/// let quat: [f32; 4] = from_rotation::<f32, [f32; 4]>([0.5, FRAC_PI_2, 0.0]);
+/// let (rotation, lock) = to_rotation_ext::<f32, [f32; 3]>(quat, AngleRange::Signed);
+/// assert_eq!( lock, GimbalLock::North );
+/// assert_eq!( (rotation[1], rotation[2]), (FRAC_PI_2, 0.0) );
+/// ```
+pub fn to_rotation_ext<Num, Out>(quaternion: impl Quaternion<Num>, range: AngleRange) -> (Out, GimbalLock)
+where 
+    Num: Axis,
+    Out: RotationConstructor<Num>
+{
+    let quat: Q<Num> = normalize_unchecked(quaternion);
+    let (peach, lock) = pitch_sine(&quat);
+    (RotationConstructor::new_rotation(
+        roll_of(&quat, lock, range),
+        pitch_of(peach, lock),
+        yaw_of(&quat, lock, range),
+    ), lock)
+}
+
+/// The sine of the pitch of a unit quaternion and the pole it's locked at (if any).
+#[inline]
+#[cfg(feature = "rotation")]
+fn pitch_sine<Num: Axis>(quat: &Q<Num>) -> (Num, GimbalLock) {
     // here I misspelled 'pitch' but it's funny so I kept it
-    let peach = two * (quat.r() * quat.j() - quat.i() * quat.k());
+    let peach = Num::from_f64(2.0) * (quat.r() * quat.j() - quat.i() * quat.k());
+    let lock = if peach > Num::ONE - Num::ERROR {
+        GimbalLock::North
+    } else if peach < Num::ERROR - Num::ONE {
+        GimbalLock::South
+    } else {
+        GimbalLock::None
+    };
+    (peach, lock)
+}
 
-    if peach > Num::ONE - Num::ERROR {
-        return RotationConstructor::new_rotation(
-            two * Num::atan2(quat.i(), quat.r()),
-            Num::from_f64(crate::core::f64::consts::FRAC_PI_2),
-            Num::ZERO,
-        )
+/// The roll of a unit quaternion, at the poles the whole rotation is put in it.
+#[inline]
+#[cfg(feature = "rotation")]
+fn roll_of<Num: Axis>(quat: &Q<Num>, lock: GimbalLock, range: AngleRange) -> Num {
+    let two = Num::from_f64(2.0);
+    range.wrap(match lock {
+        GimbalLock::None => Num::atan2(
+            two * (quat.r() * quat.i() + quat.j() * quat.k()),
+            Num::ONE - two * ( quat.i() * quat.i() + quat.j() * quat.j())
+        ),
+        GimbalLock::North | GimbalLock::South => two * Num::atan2(quat.i(), quat.r()),
+    })
+}
+
+/// The pitch from its sine, exactly `±π/2` at the poles.
+#[inline]
+#[cfg(feature = "rotation")]
+fn pitch_of<Num: Axis>(peach: Num, lock: GimbalLock) -> Num {
+    match lock {
+        GimbalLock::None => Num::asin(peach),
+        GimbalLock::North => crate::consts::frac_pi_2::<Num>(),
+        GimbalLock::South => -crate::consts::frac_pi_2::<Num>(),
     }
+}
 
-    if peach < Num::ERROR - Num::ONE {
-        return RotationConstructor::new_rotation(
-            -two * Num::atan2(quat.i(), quat.r()),
-            Num::from_f64(-crate::core::f64::consts::FRAC_PI_2),
-            Num::ZERO,
-        )
+/// The yaw of a unit quaternion, zero at the poles.
+#[inline]
+#[cfg(feature = "rotation")]
+fn yaw_of<Num: Axis>(quat: &Q<Num>, lock: GimbalLock, range: AngleRange) -> Num {
+    let two = Num::from_f64(2.0);
+    match lock {
+        GimbalLock::None => range.wrap(Num::atan2(
+            two * (quat.r() * quat.k() + quat.i() * quat.j()),
+            Num::ONE - two * ( quat.j() * quat.j() + quat.k() * quat.k() )
+        )),
+        GimbalLock::North | GimbalLock::South => Num::ZERO,
     }
+}
 
-    let roll: Num = Num::atan2(
-        two * (quat.r() * quat.i() + quat.j() * quat.k()),
-        Num::ONE - two * ( quat.i() * quat.i() + quat.j() * quat.j())
-    );
-    let pitch: Num = Num::asin(peach);
-    let yaw: Num = Num::atan2(
-        two * (quat.r() * quat.k() + quat.i() * quat.j()),
-        Num::ONE - two * ( quat.j() * quat.j() + quat.k() * quat.k() )
-    );
-    RotationConstructor::new_rotation(roll, pitch, yaw)
+/// Gets the roll, pitch and yaw angles of a quaternion in one go.
+/// 
+/// Same as [`to_rotation`] (including at the poles) but gives back a tuple,
+/// and cheaper then calling [`to_roll`], [`to_pitch`] and [`to_yaw`] one after the other.
+/// 
+/// The origin has no rotation, it gives back NaN angles.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{to_roll_pitch_yaw, from_rotation};
+/// 
+/// let quat: [f32; 4] = from_rotation::<f32, [f32; 4]>([0.5, -0.25, 2.0]);
+/// let (roll, pitch, yaw) = to_roll_pitch_yaw::<f32>(quat);
+/// 
+/// assert!( (roll - 0.5).abs() < 0.0001 );
+/// assert!( (pitch + 0.25).abs() < 0.0001 );
+/// assert!( (yaw - 2.0).abs() < 0.0001 );
+/// ```
+#[cfg(feature = "rotation")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn to_roll_pitch_yaw<Num: Axis>(quaternion: impl Quaternion<Num>) -> (Num, Num, Num) {
+    let quat: Q<Num> = normalize_unchecked(quaternion);
+    let (peach, lock) = pitch_sine(&quat);
+    (
+        roll_of(&quat, lock, AngleRange::Signed),
+        pitch_of(peach, lock),
+        yaw_of(&quat, lock, AngleRange::Signed),
+    )
+}
+
+/// Gets the roll, pitch and yaw angles of a unit quaternion in one go.
+/// 
+/// Same as [`to_roll_pitch_yaw`] but doesn't normalize the quaternion.
+#[cfg(feature = "rotation")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn to_roll_pitch_yaw_unit<Num: Axis>(unit_quat: impl UnitQuaternion<Num>) -> (Num, Num, Num) {
+    let quat: Q<Num> = Q::<Num>::from_quat(unit_quat);
+    let (peach, lock) = pitch_sine(&quat);
+    (
+        roll_of(&quat, lock, AngleRange::Signed),
+        pitch_of(peach, lock),
+        yaw_of(&quat, lock, AngleRange::Signed),
+    )
 }
 
 /// Turns this quaternion into a 2x2 Matrix
@@ -627,7 +1365,7 @@ where
 /// ```
 #[inline]
 #[cfg(feature = "matrix")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn to_matrix_2<Num, Complex, Matrix>(quaternion: impl Quaternion<Num>) -> Matrix
 where 
     Num: Axis,
@@ -648,34 +1386,272 @@ where
     )
 }
 
+/// The ways [`to_matrix_2_with`] and [`from_matrix_2_with`] lay out a quaternion
+/// `a + bi + cj + dk` as a 2x2 matrix.
+/// 
+/// | form           | matrix                                   | mapping                                        |
+/// |----------------|------------------------------------------|------------------------------------------------|
+/// | [`Standard`]   | `[[a+bi, c+di], [-c+di, a-bi]]`          | same as [`to_matrix_2`]                        |
+/// | [`Conjugate`]  | `[[a-bi, c-di], [-c-di, a+bi]]`          | every element of `Standard` conjugated         |
+/// | [`PauliBasis`] | `[a, d, c, b]`                           | `Standard = a·I + d·iσx + c·iσy + b·iσz`       |
+/// 
+/// `σx`, `σy` and `σz` are the [Pauli matrices](https://en.wikipedia.org/wiki/Pauli_matrices),
+/// so the `Standard` form sends `i` to `iσz`, `j` to `iσy` and `k` to `iσx`.
+/// All three have a determinant of [`abs_squared`](super::abs_squared) of the quaternion.
+/// 
+/// [`Standard`]: Matrix2Form::Standard
+/// [`Conjugate`]: Matrix2Form::Conjugate
+/// [`PauliBasis`]: Matrix2Form::PauliBasis
+#[cfg(feature = "matrix")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Matrix2Form {
+    /// The complex matrix `[[a+bi, c+di], [-c+di, a-bi]]`.
+    #[default]
+    Standard,
+    /// The complex matrix `[[a-bi, c-di], [-c-di, a+bi]]`.
+    Conjugate,
+    /// The real coefficients `[a, d, c, b]` of `I`, `iσx`, `iσy` and `iσz`
+    /// that add up to the `Standard` matrix.
+    PauliBasis,
+}
+
+/// A 2x2 matrix form of a quaternion, given back by [`to_matrix_2_with`].
+/// 
+/// The [`Standard`](Matrix2Form::Standard) and [`Conjugate`](Matrix2Form::Conjugate) forms
+/// are complex matrices (of `(real, imaginary)` pairs),
+/// the [`PauliBasis`](Matrix2Form::PauliBasis) form is four real coefficients.
+#[cfg(feature = "matrix")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Matrix2<Num> {
+    /// A complex matrix, made by the `Standard` and `Conjugate` forms.
+    Complex([[(Num, Num); 2]; 2]),
+    /// The coefficients of `I`, `iσx`, `iσy` and `iσz`, made by the `PauliBasis` form.
+    Pauli([Num; 4]),
+}
+
+#[cfg(feature = "matrix")]
+impl<Num: Axis> Matrix2<Num> {
+    /// Gets the complex matrix, adding up the Pauli matrices for [`Matrix2::Pauli`].
+    #[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+    pub fn to_complex(self) -> [[(Num, Num); 2]; 2] {
+        match self {
+            Matrix2::Complex(matrix) => matrix,
+            Matrix2::Pauli([w, x, y, z]) => [
+                [(w, z), (y, x)],
+                [(-y, x), (w, -z)],
+            ],
+        }
+    }
+
+    /// Calculates the (complex) determinant of the matrix, as `(real, imaginary)`.
+    #[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+    pub fn determinant(self) -> (Num, Num) {
+        let [[(ar, ai), (br, bi)], [(cr, ci), (dr, di)]] = self.to_complex();
+        (
+            (ar * dr - ai * di) - (br * cr - bi * ci),
+            (ar * di + ai * dr) - (br * ci + bi * cr),
+        )
+    }
+}
+
+/// Turns this quaternion into a 2x2 matrix in the given [`Matrix2Form`].
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{to_matrix_2_with, Matrix2, Matrix2Form};
+/// 
+/// let quat: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+/// 
+/// assert_eq!(
+///     to_matrix_2_with::<f32>(quat, Matrix2Form::Conjugate),
+///     Matrix2::Complex([
+///         [(1.0, -2.0), (3.0, -4.0)],
+///         [(-3.0, -4.0), (1.0, 2.0)],
+///     ])
+/// );
+/// assert_eq!(
+///     to_matrix_2_with::<f32>(quat, Matrix2Form::PauliBasis),
+///     Matrix2::Pauli([1.0, 4.0, 3.0, 2.0])
+/// );
+/// ```
+#[inline]
+#[cfg(feature = "matrix")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn to_matrix_2_with<Num>(quaternion: impl Quaternion<Num>, form: Matrix2Form) -> Matrix2<Num>
+where 
+    Num: Axis,
+{
+    let (r, i, j, k) = (quaternion.r(), quaternion.i(), quaternion.j(), quaternion.k());
+    match form {
+        Matrix2Form::Standard => Matrix2::Complex(to_matrix_2::<Num, (Num, Num), _>((r, [i, j, k]))),
+        Matrix2Form::Conjugate => Matrix2::Complex([
+            [(r, -i), (j, -k)],
+            [(-j, -k), (r, i)],
+        ]),
+        Matrix2Form::PauliBasis => Matrix2::Pauli([r, k, j, i]),
+    }
+}
+
+/// Constructs a quaternion from a 2x2 matrix in the given [`Matrix2Form`].
+/// 
+/// Gives back [`None`](Option::None) if the matrix isn't a valid representation of a quaternion
+/// in that form (a complex matrix for `PauliBasis` or coefficients for the complex forms
+/// are never valid). The Pauli coefficients are always valid.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{from_matrix_2_with, to_matrix_2_with, Matrix2, Matrix2Form};
+/// 
+/// let quat: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+/// let pauli: Matrix2<f32> = to_matrix_2_with::<f32>(quat, Matrix2Form::PauliBasis);
+/// 
+/// assert_eq!( from_matrix_2_with::<f32, [f32; 4]>(pauli, Matrix2Form::PauliBasis), Some(quat) );
+/// assert_eq!( from_matrix_2_with::<f32, [f32; 4]>(pauli, Matrix2Form::Standard), None );
+/// ```
+#[cfg(feature = "matrix")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn from_matrix_2_with<Num, Out>(matrix: Matrix2<Num>, form: Matrix2Form) -> Option<Out>
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    match (matrix, form) {
+        (Matrix2::Complex(matrix), Matrix2Form::Standard) => from_matrix_2::<Num, (Num, Num), Out>(matrix),
+        // the conjugated matrix has the same structure, only the signs of the imaginary parts change
+        (Matrix2::Complex(matrix), Matrix2Form::Conjugate) => from_matrix_2::<Num, (Num, Num), Q<Num>>(matrix)
+            .map(|(r, [i, j, k])| Out::new_quat(r, -i, j, -k)),
+        (Matrix2::Pauli([w, x, y, z]), Matrix2Form::PauliBasis) => Option::Some( Out::new_quat(w, z, y, x) ),
+        _ => Option::None,
+    }
+}
+
 /// Turns this quaternion into a 3x3 Matrix. (DCM)
 #[cfg(feature = "matrix")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn to_matrix_3<Num, Elem, Out>(quaternion: impl Quaternion<Num>) -> Out
 where 
     Num: Axis,
     Elem: ScalarConstructor<Num>,
     Out: MatrixConstructor<Elem, 3>,
 {
+    Out::new_matrix(matrix_3_rows(quaternion).map(|row: [Num; 3]| row.map(Elem::new_scalar)))
+}
+
+/// The rows of the 3x3 matrix (DCM) of a quaternion, the body of [`to_matrix_3`]
+/// (also used transposed by `rotation_strategies::rotation_matrix`).
+#[inline]
+#[cfg(any(feature = "matrix", feature = "rotation"))]
+pub(crate) fn matrix_3_rows<Num: Axis>(quaternion: impl Quaternion<Num>) -> [[Num; 3]; 3] {
     let q = quaternion;
     let two = Num::from_f64(2.0);
-    Out::new_matrix([
+    [
         [
-            Elem::new_scalar(q.r()*q.r() + q.i()*q.i() - q.j()*q.j() - q.k()*q.k()),
-            Elem::new_scalar(two * ( q.i()*q.j() + q.r()*q.k() )),
-            Elem::new_scalar(two * ( q.i()*q.j() - q.r()*q.k() )),
+            q.r()*q.r() + q.i()*q.i() - q.j()*q.j() - q.k()*q.k(),
+            two * ( q.i()*q.j() + q.r()*q.k() ),
+            two * ( q.i()*q.k() - q.r()*q.j() ),
         ],
         [
-            Elem::new_scalar(two * ( q.i()*q.j() - q.r()*q.k() )),
-            Elem::new_scalar(q.r()*q.r() - q.i()*q.i() + q.j()*q.j() - q.k()*q.k()),
-            Elem::new_scalar(two * ( q.j()*q.k() + q.r()*q.i() )),
+            two * ( q.i()*q.j() - q.r()*q.k() ),
+            q.r()*q.r() - q.i()*q.i() + q.j()*q.j() - q.k()*q.k(),
+            two * ( q.j()*q.k() + q.r()*q.i() ),
         ],
         [
-            Elem::new_scalar(two * ( q.i()*q.k() + q.r()*q.j() )),
-            Elem::new_scalar(two * ( q.j()*q.k() - q.r()*q.i() )),
-            Elem::new_scalar(q.r()*q.r() - q.i()*q.i() - q.j()*q.j() + q.k()*q.k()),
+            two * ( q.i()*q.k() + q.r()*q.j() ),
+            two * ( q.j()*q.k() - q.r()*q.i() ),
+            q.r()*q.r() - q.i()*q.i() - q.j()*q.j() + q.k()*q.k(),
         ],
-    ])
+    ]
+}
+
+/// Writes the 3x3 matrix (DCM) of this quaternion into a slice, row-major.
+/// 
+/// Gives back a [`WrongLengthError`](crate::traits::WrongLengthError)
+/// (and leaves the slice untouched) if the slice doesn't have exactly `9` elements,
+/// otherwise it writes the same as [`to_matrix_3`].
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{to_matrix_3_slice, to_matrix_3};
+/// use quaternion_traits::traits::WrongLengthError;
+/// 
+/// let quat: [f32; 4] = [0.5, 0.5, -0.5, 0.5];
+/// let mut out: Vec<f32> = vec![0.0; 9];
+/// 
+/// assert_eq!( to_matrix_3_slice::<f32>(quat, &mut out), Ok(()) );
+/// assert_eq!( out[..], to_matrix_3::<f32, f32, [f32; 9]>(quat) );
+/// 
+/// let mut short: [f32; 4] = [0.0; 4];
+/// assert_eq!( to_matrix_3_slice::<f32>(quat, &mut short), Err(WrongLengthError { expected: 9, found: 4 }) );
+/// ```
+#[inline]
+#[cfg(feature = "matrix")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn to_matrix_3_slice<Num>(quaternion: impl Quaternion<Num>, out: &mut [Num]) -> crate::core::result::Result<(), crate::traits::WrongLengthError>
+where 
+    Num: Axis,
+{
+    let found: usize = out.len();
+    let out: &mut [Num; 9] = crate::core::convert::TryInto::try_into(out).map_err(|_| crate::traits::WrongLengthError { expected: 9, found })?;
+    *out = to_matrix_3::<Num, Num, [Num; 9]>(quaternion);
+    crate::core::result::Result::Ok(())
+}
+
+/// Turns euler angles into a 3x3 matrix. (DCM)
+/// 
+/// This is exactly [`to_matrix_3`] of [`from_rotation`],
+/// the quaternion inbetween is picked by this function.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{rotation_to_matrix_3, to_matrix_3, from_rotation};
+/// 
+/// let rotation: [f32; 3] = [0.3, -0.8, 2.0];
+/// 
+/// let direct: [[f32; 3]; 3] = rotation_to_matrix_3::<f32, f32, [[f32; 3]; 3]>(rotation);
+/// let two_steps: [[f32; 3]; 3] = to_matrix_3::<f32, f32, [[f32; 3]; 3]>(from_rotation::<f32, [f32; 4]>(rotation));
+/// 
+/// assert_eq!( direct, two_steps );
+/// ```
+#[inline]
+#[cfg(all(feature = "rotation", feature = "matrix"))]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn rotation_to_matrix_3<Num, Elem, Out>(rotation: impl Rotation<Num>) -> Out
+where 
+    Num: Axis,
+    Elem: ScalarConstructor<Num>,
+    Out: MatrixConstructor<Elem, 3>,
+{
+    to_matrix_3::<Num, Elem, Out>(from_rotation::<Num, Q<Num>>(rotation))
+}
+
+/// Turns a 3x3 matrix (DCM) into euler angles.
+/// 
+/// This is exactly [`to_rotation`] of [`from_matrix_3`],
+/// the quaternion inbetween is picked by this function.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{matrix_3_to_rotation, rotation_to_matrix_3};
+/// 
+/// let rotation: [f32; 3] = [0.3, -0.8, 2.0];
+/// let matrix: [[f32; 3]; 3] = rotation_to_matrix_3::<f32, f32, [[f32; 3]; 3]>(rotation);
+/// 
+/// let back: [f32; 3] = matrix_3_to_rotation::<f32, f32, [f32; 3]>(matrix);
+/// 
+/// for n in 0..3 {
+///     assert!( (back[n] - rotation[n]).abs() < 0.0001 );
+/// }
+/// ```
+#[inline]
+#[cfg(all(feature = "rotation", feature = "matrix"))]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn matrix_3_to_rotation<Num, Elem, Out>(matrix: impl Matrix<Elem, 3>) -> Out
+where 
+    Num: Axis,
+    Elem: Scalar<Num>,
+    Out: RotationConstructor<Num>,
+{
+    to_rotation::<Num, Out>(from_matrix_3::<Num, Elem, Q<Num>>(matrix))
 }
 
 /// Turns this quaternion into a 4x4 Matrix.
@@ -719,7 +1695,7 @@ where
 /// ```
 #[inline]
 #[cfg(feature = "matrix")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn to_matrix_4<Num, Elem, Out>(quaternion: impl Quaternion<Num>) -> Out
 where 
     Num: Axis,
@@ -755,7 +1731,7 @@ where
 }
 
 #[cfg(feature = "math_fns")] 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Gets the polar form of a quaternion.
 /// 
 /// The values are given in this order (`abs`, `angle`, `unit_vec`).
@@ -796,37 +1772,33 @@ where
 /// 
 /// Equivalent to getting the roll value after a `to_rotation` call,
 /// this function is faster due to ignoring calculations for pitch and yaw.
+/// 
+/// The origin has no rotation, it gives back NaN.
 #[cfg(feature = "rotation")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn to_roll<Num, Angle>(quaternion: impl Quaternion<Num>) -> Angle
 where 
     Num: Axis,
     Angle: ScalarConstructor<Num>,
 {
-    
-    let quat: Q<Num> = normalize(quaternion);
-
-    let two = Num::from_f64(2.0);
-    // here I misspelled 'pitch' but it's funny so I kept it
-    let peach = two * (quat.r() * quat.j() - quat.i() * quat.k());
-
-    if peach > Num::ONE - Num::ERROR {
-        return ScalarConstructor::new_scalar(
-            two * Num::atan2(quat.i(), quat.r())
-        )
-    }
-
-    if peach < Num::ERROR - Num::ONE {
-        return ScalarConstructor::new_scalar(
-            -two * Num::atan2(quat.i(), quat.r())
-        )
-    }
+    let quat: Q<Num> = normalize_unchecked(quaternion);
+    let (_, lock) = pitch_sine(&quat);
+    ScalarConstructor::new_scalar(roll_of(&quat, lock, AngleRange::Signed))
+}
 
-    ScalarConstructor::new_scalar(
-            Num::atan2(
-            two * (quat.r() * quat.i() + quat.j() * quat.k()),
-            Num::ONE - two * ( quat.i() * quat.i() + quat.j() * quat.j())
-        )
-    )
+/// Gets the roll angle of a unit quaternion.
+/// 
+/// Same as [`to_roll`] but doesn't normalize the quaternion.
+#[cfg(feature = "rotation")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn to_roll_unit<Num, Angle>(unit_quat: impl UnitQuaternion<Num>) -> Angle
+where 
+    Num: Axis,
+    Angle: ScalarConstructor<Num>,
+{
+    let quat: Q<Num> = Q::<Num>::from_quat(unit_quat);
+    let (_, lock) = pitch_sine(&quat);
+    ScalarConstructor::new_scalar(roll_of(&quat, lock, AngleRange::Signed))
 }
 
 /// Gets the pitch angle of a quaternion.
@@ -836,32 +1808,33 @@ where
 /// 
 /// Equivalent to getting the pitch value after a `to_rotation` call,
 /// this function is faster due to ignoring calculations for roll and yaw.
+/// 
+/// The origin has no rotation, it gives back NaN.
 #[cfg(feature = "rotation")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn to_pitch<Num, Angle>(quaternion: impl Quaternion<Num>) -> Angle
 where 
     Num: Axis,
     Angle: ScalarConstructor<Num>,
 {
-    
-    let quat: Q<Num> = normalize(quaternion);
-
-    let two = Num::from_f64(2.0);
-    // here I misspelled 'pitch' but it's funny so I kept it
-    let peach = two * (quat.r() * quat.j() - quat.i() * quat.k());
-
-    if peach > Num::ONE - Num::ERROR {
-        return ScalarConstructor::new_scalar(
-            Num::from_f64(crate::core::f64::consts::FRAC_PI_2),
-        )
-    }
-
-    if peach < Num::ERROR - Num::ONE {
-        return ScalarConstructor::new_scalar(
-            Num::from_f64(-crate::core::f64::consts::FRAC_PI_2),
-        )
-    }
+    let quat: Q<Num> = normalize_unchecked(quaternion);
+    let (peach, lock) = pitch_sine(&quat);
+    ScalarConstructor::new_scalar(pitch_of(peach, lock))
+}
 
-    ScalarConstructor::new_scalar(peach.asin())
+/// Gets the pitch angle of a unit quaternion.
+/// 
+/// Same as [`to_pitch`] but doesn't normalize the quaternion.
+#[cfg(feature = "rotation")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn to_pitch_unit<Num, Angle>(unit_quat: impl UnitQuaternion<Num>) -> Angle
+where 
+    Num: Axis,
+    Angle: ScalarConstructor<Num>,
+{
+    let quat: Q<Num> = Q::<Num>::from_quat(unit_quat);
+    let (peach, lock) = pitch_sine(&quat);
+    ScalarConstructor::new_scalar(pitch_of(peach, lock))
 }
 
 /// Gets the yaw angle of a quaternion.
@@ -871,43 +1844,46 @@ where
 /// 
 /// Equivalent to getting the yaw value after a `to_rotation` call,
 /// this function is faster due to ignoring calculations for roll and pitch.
+/// 
+/// The origin has no rotation, it gives back NaN.
 #[cfg(feature = "rotation")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn to_yaw<Num, Angle>(quaternion: impl Quaternion<Num>) -> Angle
 where 
     Num: Axis,
     Angle: ScalarConstructor<Num>,
 {
-    
-    let quat: Q<Num> = normalize(quaternion);
-
-    let two = Num::from_f64(2.0);
-    // here I misspelled 'pitch' but it's funny so I kept it
-    let peach = two * (quat.r() * quat.j() - quat.i() * quat.k());
-
-    if peach > Num::ONE - Num::ERROR
-    || peach < Num::ERROR - Num::ONE
-    {
-        return ScalarConstructor::new_scalar(Num::ZERO);
-    }
+    let quat: Q<Num> = normalize_unchecked(quaternion);
+    let (_, lock) = pitch_sine(&quat);
+    ScalarConstructor::new_scalar(yaw_of(&quat, lock, AngleRange::Signed))
+}
 
-    ScalarConstructor::new_scalar(
-        Num::atan2(
-            two * (quat.r() * quat.k() + quat.i() * quat.j()),
-            Num::ONE - two * ( quat.j() * quat.j() + quat.k() * quat.k() )
-        )
-    )
+/// Gets the yaw angle of a unit quaternion.
+/// 
+/// Same as [`to_yaw`] but doesn't normalize the quaternion.
+#[cfg(feature = "rotation")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn to_yaw_unit<Num, Angle>(unit_quat: impl UnitQuaternion<Num>) -> Angle
+where 
+    Num: Axis,
+    Angle: ScalarConstructor<Num>,
+{
+    let quat: Q<Num> = Q::<Num>::from_quat(unit_quat);
+    let (_, lock) = pitch_sine(&quat);
+    ScalarConstructor::new_scalar(yaw_of(&quat, lock, AngleRange::Signed))
 }
 
 /// Constructs a quaternion from a roll angle.
 /// 
 /// Equivalent to [`from_rotation`], but cheaper.
 #[cfg(feature = "rotation")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn from_roll<Num, Out>(roll: impl Scalar<Num>) -> Out
 where 
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    let (sin, cos) = Num::sin_cos(roll.scalar() * Num::from_f64(0.5));
+    let (sin, cos) = Num::sin_cos(roll.scalar() * crate::consts::half::<Num>());
     QuaternionConstructor::new_quat(
         sin,
         cos,
@@ -920,12 +1896,13 @@ where
 /// 
 /// Equivalent to [`from_rotation`], but cheaper.
 #[cfg(feature = "rotation")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn from_pitch<Num, Out>(pitch: impl Scalar<Num>) -> Out
 where 
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    let (sin, cos) = Num::sin_cos(pitch.scalar() * Num::from_f64(0.5));
+    let (sin, cos) = Num::sin_cos(pitch.scalar() * crate::consts::half::<Num>());
     QuaternionConstructor::new_quat(
         sin,
         Num::ZERO,
@@ -938,12 +1915,13 @@ where
 /// 
 /// Equivalent to [`from_rotation`], but cheaper.
 #[cfg(feature = "rotation")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn from_yaw<Num, Out>(yaw: impl Scalar<Num>) -> Out
 where 
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    let (sin, cos) = Num::sin_cos(yaw.scalar() * Num::from_f64(0.5));
+    let (sin, cos) = Num::sin_cos(yaw.scalar() * crate::consts::half::<Num>());
     QuaternionConstructor::new_quat(
         sin,
         Num::ZERO,
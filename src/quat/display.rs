@@ -1,9 +1,8 @@
 
 use crate::{Axis, Quaternion, QuaternionConstructor};
-use crate::structs::QuaternionFormat;
+use crate::structs::{QuaternionFormat, InlineString, BufferTooSmall};
 use crate::core::result::Result;
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
 /// Writes a quaternion representation to a formatter/string.
 /// 
 /// This function gives each number in order.
@@ -90,20 +89,30 @@ pub fn display<Num: Axis + crate::core::fmt::Display>(
 ) -> crate::core::fmt::Result {
     use crate::core::write;
 
+    /// Shows a number using [`Axis::write_axis`].
+    struct Shown<Num>(Num);
+
+    impl<Num: Axis + crate::core::fmt::Display> crate::core::fmt::Display for Shown<Num> {
+        #[inline]
+        fn fmt(&self, f: &mut crate::core::fmt::Formatter<'_>) -> crate::core::fmt::Result {
+            self.0.write_axis(f)
+        }
+    }
+
     #[inline]
     fn write_first<Num: Axis + crate::core::fmt::Display, const AXIS: char>(target: &mut impl crate::core::fmt::Write, num: Num, format: crate::structs::QuaternionFormat) -> crate::core::fmt::Result {
         if (num != Num::ONE && num != -Num::ONE) || format.show_1s {
             if num < Num::ZERO {
                 if format.add_spacing_for_first {
-                    write!(target, "- {}{AXIS}", -num)
+                    write!(target, "- {}{AXIS}", Shown(-num))
                 } else {
-                    write!(target, "{}{AXIS}", num)
+                    write!(target, "{}{AXIS}", Shown(num))
                 }
             } else {
                 match (format.explicit_plus_sign, format.add_spacing_for_first) {
-                    (false, _) => write!(target, "{}{AXIS}", num),
-                    (true, false) => write!(target, "+{}{AXIS}", num),
-                    (true, true) => write!(target, "+ {}{AXIS}", num),
+                    (false, _) => write!(target, "{}{AXIS}", Shown(num)),
+                    (true, false) => write!(target, "+{}{AXIS}", Shown(num)),
+                    (true, true) => write!(target, "+ {}{AXIS}", Shown(num)),
                 }
             }
         } else if num == Num::ONE {
@@ -126,9 +135,9 @@ pub fn display<Num: Axis + crate::core::fmt::Display>(
         if num > Num::ZERO {
             if num != Num::ONE || format.show_1s {
                 if format.remove_spacing {
-                    write!(target, "+{}{AXIS}", num)
+                    write!(target, "+{}{AXIS}", Shown(num))
                 } else {
-                    write!(target, " + {}{AXIS}", num)
+                    write!(target, " + {}{AXIS}", Shown(num))
                 }
             } else {
                 if format.remove_spacing {
@@ -140,9 +149,9 @@ pub fn display<Num: Axis + crate::core::fmt::Display>(
         } else if num < Num::ZERO {
             if num != -Num::ONE || format.show_1s {
                 if format.remove_spacing {
-                    write!(target, "{}{AXIS}", -num)
+                    write!(target, "{}{AXIS}", Shown(-num))
                 } else {
-                    write!(target, " - {}{AXIS}", -num)
+                    write!(target, " - {}{AXIS}", Shown(-num))
                 }
             } else {
                 if format.remove_spacing {
@@ -165,15 +174,15 @@ pub fn display<Num: Axis + crate::core::fmt::Display>(
             write_first::<Num, 'r'>(target, quaternion.r(), format)?;
         } else if quaternion.r() < Num::ZERO {
             if format.add_spacing_for_first {
-                write!(target, "- {}", -quaternion.r())?;
+                write!(target, "- {}", Shown(-quaternion.r()))?;
             } else {
-                write!(target, "{}", quaternion.r())?;
+                write!(target, "{}", Shown(quaternion.r()))?;
             }
         } else {
             match (format.explicit_plus_sign, format.add_spacing_for_first) {
-                (false, _) => write!(target, "{}", quaternion.r()),
-                (true, false) => write!(target, "+{}", quaternion.r()),
-                (true, true) => write!(target, "+ {}", quaternion.r()),
+                (false, _) => write!(target, "{}", Shown(quaternion.r())),
+                (true, false) => write!(target, "+{}", Shown(quaternion.r())),
+                (true, true) => write!(target, "+ {}", Shown(quaternion.r())),
             }?;
         }
 
@@ -207,12 +216,11 @@ pub fn display<Num: Axis + crate::core::fmt::Display>(
         return write_first::<Num, 'k'>(target, quaternion.i(), format);
     }
 
-    write!(target, "{}", Num::ZERO)
+    write!(target, "{}", Shown(Num::ZERO))
 }
 
 /// Alias for `display(target, quaternion, QuaternionFormat::DEFAULT)`
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
 pub fn default_display<Num: Axis + crate::core::fmt::Display>(
     target: &mut impl crate::core::fmt::Write,
     quaternion: impl Quaternion<Num>,
@@ -224,7 +232,6 @@ pub fn default_display<Num: Axis + crate::core::fmt::Display>(
 use crate::alloc::string::String;
 
 #[cfg(feature = "alloc")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
 /// Turns a quaternion representation into a [`String`].
 /// 
 /// # Example
@@ -249,7 +256,6 @@ pub fn to_string<Num: Axis + crate::core::fmt::Display>(quaternion: impl Quatern
 
 #[inline]
 #[cfg(feature = "alloc")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
 /// Alias for `to_string(_, QuaternionFormat::DEFAULT)`.
 /// 
 /// # Example
@@ -269,6 +275,48 @@ pub fn to_default_string<Num: Axis + crate::core::fmt::Display>(quaternion: impl
     to_string::<Num>(quaternion, QuaternionFormat::DEFAULT)
 }
 
+/// Turns a quaternion representation into an [`InlineString`],
+/// a string of at most `N` bytes that doesn't need `alloc`.
+/// 
+/// Gives back [`BufferTooSmall`] (with the length the whole string needs)
+/// if it doesn't fit in `N` bytes.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::to_inline_string;
+/// use quaternion_traits::structs::{BufferTooSmall, InlineString, QuaternionFormat as QF};
+/// 
+/// let quat: [f32; 4] = [0.0, 1.0, -2.0, 0.0];
+/// 
+/// let string: InlineString<16> = to_inline_string::<f32, 16>(quat, QF::DEFAULT).unwrap();
+/// assert_eq!( string, "i - 2j" );
+/// 
+/// assert_eq!(
+///     to_inline_string::<f32, 4>(quat, QF::DEFAULT),
+///     Err(BufferTooSmall { capacity: 4, needed: 6 }),
+/// );
+/// ```
+pub fn to_inline_string<Num: Axis + crate::core::fmt::Display, const N: usize>(quaternion: impl Quaternion<Num>, format: QuaternionFormat) -> Result<InlineString<N>, BufferTooSmall> {
+    /// Only counts the bytes written to it.
+    struct Counter(usize);
+
+    impl crate::core::fmt::Write for Counter {
+        #[inline]
+        fn write_str(&mut self, string: &str) -> crate::core::fmt::Result {
+            self.0 += string.len();
+            crate::core::fmt::Result::Ok(())
+        }
+    }
+
+    let mut string: InlineString<N> = InlineString::new();
+    if display(&mut string, &quaternion, format).is_ok() {
+        return Result::Ok(string);
+    }
+    let mut counter: Counter = Counter(0);
+    let _ = display(&mut counter, quaternion, format);
+    Result::Err(BufferTooSmall { capacity: N, needed: counter.0 })
+}
+
 use crate::core::str::FromStr;
 
 /// Parses a [`str`] into a quaternion representation.
@@ -0,0 +1,76 @@
+/*!
+Easing curves for the interpolation parameter, meant for [`slerp_eased`](super::slerp_eased).
+
+Every curve here maps `0` to `0` and `1` to `1` (exactly),
+so the interpolation still starts at `from` and ends at `to`.
+They are plain `fn(Num) -> Num`, so they can be given directly
+(`ease::smoothstep::<f32>`) or wrapped in closures.
+
+Inputs outside `[0, 1]` aren't clamped here, [`slerp_eased`](super::slerp_eased) clamps before easing.
+
+# Example
+```
+use quaternion_traits::quat::ease;
+
+assert_eq!( ease::smoothstep::<f32>(0.5), 0.5 );
+assert_eq!( ease::smootherstep::<f32>(1.0), 1.0 );
+assert_eq!( ease::ease_in_out_cubic::<f32>(0.25), 0.0625 );
+```
+ */
+
+use crate::Axis;
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// The cubic `3t² - 2t³`, which has a derivative of `0` at both ends.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::ease::smoothstep;
+/// 
+/// assert_eq!( smoothstep::<f64>(0.0), 0.0 );
+/// assert_eq!( smoothstep::<f64>(0.25), 0.15625 );
+/// assert_eq!( smoothstep::<f64>(1.0), 1.0 );
+/// ```
+pub fn smoothstep<Num: Axis>(at: Num) -> Num {
+    at * at * (Num::from_f64(3.0) - Num::from_f64(2.0) * at)
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// The quintic `6t⁵ - 15t⁴ + 10t³`, which has a first and second derivative of `0` at both ends.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::ease::smootherstep;
+/// 
+/// assert_eq!( smootherstep::<f64>(0.0), 0.0 );
+/// assert_eq!( smootherstep::<f64>(0.5), 0.5 );
+/// assert_eq!( smootherstep::<f64>(1.0), 1.0 );
+/// ```
+pub fn smootherstep<Num: Axis>(at: Num) -> Num {
+    at * at * at * (at * (Num::from_f64(6.0) * at - Num::from_f64(15.0)) + Num::from_f64(10.0))
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Accelerates with `4t³` in the first half and decelerates symmetrically in the second half.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::ease::ease_in_out_cubic;
+/// 
+/// assert_eq!( ease_in_out_cubic::<f64>(0.0), 0.0 );
+/// assert_eq!( ease_in_out_cubic::<f64>(0.5), 0.5 );
+/// assert_eq!( ease_in_out_cubic::<f64>(0.75), 0.9375 );
+/// assert_eq!( ease_in_out_cubic::<f64>(1.0), 1.0 );
+/// ```
+pub fn ease_in_out_cubic<Num: Axis>(at: Num) -> Num {
+    let half: Num = crate::consts::half::<Num>();
+    if at < half {
+        Num::from_f64(4.0) * at * at * at
+    } else {
+        let left: Num = Num::ONE - at;
+        Num::ONE - Num::from_f64(4.0) * left * left * left
+    }
+}
@@ -3,7 +3,7 @@
 use super::*;
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Constructs the origin quaternion. (Aditive identity)
 /// 
 /// # Example
@@ -23,7 +23,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Constructs the positive real unit quaternion. (Multiplicative identity)
 /// 
 /// # Example
@@ -43,7 +43,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Constructs a quaternion that has all axies set to [`Num::NAN`s](Axis::NAN).
 /// 
 /// # Example
@@ -76,6 +76,7 @@ where
 /// 
 /// assert_eq!( quat, [1.0, 0.0, 0.0, 0.0] );
 /// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn unit_r<Num, Out>() -> Out
 where
     Num: Axis,
@@ -95,6 +96,7 @@ where
 /// 
 /// assert_eq!( quat, [0.0, 1.0, 0.0, 0.0] );
 /// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn unit_i<Num, Out>() -> Out
 where
     Num: Axis,
@@ -114,6 +116,7 @@ where
 /// 
 /// assert_eq!( quat, [0.0, 0.0, 1.0, 0.0] );
 /// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn unit_j<Num, Out>() -> Out
 where
     Num: Axis,
@@ -133,6 +136,7 @@ where
 /// 
 /// assert_eq!( quat, [0.0, 0.0, 0.0, 1.0] );
 /// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn unit_k<Num, Out>() -> Out
 where
     Num: Axis,
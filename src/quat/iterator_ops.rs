@@ -5,11 +5,18 @@ use crate::core::iter::{
     IntoIterator,
 };
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Adds all the quaternions in an iterator.
 /// 
 /// Returns the origin quaternion if the iterator is empty.
 /// 
+/// Since references to quaternions are quaternions as well
+/// this also works on iterators of references, without cloning anything.
+/// 
+/// [`Sum`](crate::core::iter::Sum) can't be implemented by this crate for arrays or tuples
+/// (neather the trait nor the types are from this crate), so `iter.sum::<[f32; 4]>()` doesn't work,
+/// use this function or sum into a [`Quat`](crate::structs::Quat) instead.
+/// 
 /// # Example
 /// ```
 /// use quaternion_traits::quat::{sum, add};
@@ -20,8 +27,10 @@ use crate::core::iter::{
 /// 
 /// let normal: [f32; 4] = add::<f32, [f32; 4]>(&add::<f32, [f32; 4]>(&a, &b), &c);
 /// let iter: [f32; 4] = sum::<f32, [f32; 4]>( [a, b, c] );
+/// let by_ref: [f32; 4] = sum::<f32, [f32; 4]>( [a, b, c].iter() );
 /// 
 /// assert_eq!(normal, iter);
+/// assert_eq!(normal, by_ref);
 /// ```
 pub fn sum<Num, Out>(iter: impl IntoIterator<Item: Quaternion<Num>>) -> Out
 where 
@@ -35,12 +44,44 @@ where
     Out::from_quat(sum)
 }
 
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Adds all the quaternions in an iterator of [f32] quaternions, adding them up as [f64]s.
+/// 
+/// Same as [`sum`], but every quaternion is [widened](widen) before it's added
+/// and the result is [narrowed](narrow) at the end, so the rounding of adding many
+/// (especially small) quaternions builds up in [f64] instead of [f32].
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{sum, sum_widened};
+/// 
+/// let tiny: [f32; 4] = [0.1, 0.0, 0.0, 0.0];
+/// 
+/// let plain: [f32; 4] = sum::<f32, [f32; 4]>( core::iter::repeat(tiny).take(100_000) );
+/// let widened: [f32; 4] = sum_widened::<[f32; 4]>( core::iter::repeat(tiny).take(100_000) );
+/// 
+/// assert_eq!( widened[0], 10_000.0 );
+/// assert!( (plain[0] - 10_000.0).abs() > 1.0 );
+/// ```
+pub fn sum_widened<Out>(iter: impl IntoIterator<Item: Quaternion<f32>>) -> Out
+where 
+    Out: QuaternionConstructor<f32>,
+{
+    let mut sum: Q<f64> = (0.0, [0.0; 3]);
+    for quaternion in iter {
+        sum = add::<f64, Q<f64>>(sum, widen::<Q<f64>>(quaternion));
+    }
+    narrow(sum)
+}
+
 // const PRODUCT_MARGIN: usize = 0xFFFFFFF;
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Multiplies all the quaternions in an iterator.
 /// 
 /// Returns the identity quaternion if the iterator is empty.
 /// 
+/// Works on iterators of references too, check [`sum`] for more info.
+/// 
 /// # Example
 /// ```
 /// use quaternion_traits::quat::{product, mul};
@@ -82,3 +123,430 @@ where
     // }
     Out::from_quat(product)
 }
+
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Makes the signs of the quaternions in an iterator continuous.
+/// 
+/// Each quaternion is ran through [`enforce_continuity`] with
+/// the previous output, so the first one is left as is and every
+/// quaternion after it gets flipped if it's on the other side
+/// of the hyper-sphere compared to the one before it.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::continuous;
+/// 
+/// let alternating: [[f32; 4]; 4] = [
+///     [ 1.0,  0.0, 0.0, 0.0],
+///     [-1.0, -0.0, 0.0, 0.0],
+///     [ 1.0,  0.0, 0.0, 0.0],
+///     [-1.0, -0.0, 0.0, 0.0],
+/// ];
+/// 
+/// for quat in continuous::<f32, [f32; 4]>(alternating) {
+///     assert_eq!( quat, [1.0, 0.0, 0.0, 0.0] );
+/// }
+/// 
+/// // a genuinely rotating sequence is left unchanged
+/// let rotating: [[f32; 4]; 4] = [
+///     [1.0, 0.0, 0.0, 0.0],
+///     [0.8, 0.6, 0.0, 0.0],
+///     [0.0, 1.0, 0.0, 0.0],
+///     [-0.6, 0.8, 0.0, 0.0],
+/// ];
+/// 
+/// let continuous_rotating: Vec<[f32; 4]> = continuous::<f32, [f32; 4]>(rotating).collect();
+/// assert_eq!( continuous_rotating, rotating );
+/// ```
+pub fn continuous<Num, Out>(iter: impl IntoIterator<Item: Quaternion<Num>>) -> impl Iterator<Item = Out>
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let mut previous: Option<Q<Num>> = Option::None;
+    iter.into_iter().map(move |quaternion| {
+        let current: Q<Num> = match previous {
+            Option::Some(previous) => enforce_continuity(previous, quaternion),
+            Option::None => Q::<Num>::from_quat(quaternion),
+        };
+        previous = Option::Some(current);
+        Out::from_quat(current)
+    })
+}
+
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gets the weighted average of the rotations in an iterator
+/// using the method from Markley et al. (also known as Davenport's q-method).
+/// 
+/// This builds the 4x4 matrix `Σ wᵢ qᵢ qᵢᵀ` and gives back the
+/// eigenvector of it's largest eigenvalue as a unit quaternion
+/// (with a non-negative real part).
+/// Unlike adding the quaternions up and normalizing them, this doesn't
+/// care about the signs of the quaternions (`q` and `-q` count as the same
+/// rotation), so it stays accurate for rotations that are spread out widely.
+/// 
+/// The quaternions are paired up with the weights, and if one of the iterators
+/// is longer then the other the extra items are ignored.
+/// The weights should not be negative and the quaternions should be normalized.
+/// 
+/// The eigenvector is found using power iteration (no external linear algebra is used).
+/// The matrix is first squared 8 times, then the iteration runs until no component
+/// changes by more then [`Num::ERROR`](Axis::ERROR), capped at 32 iterations.
+/// 
+/// Returns a [`NaN`](nan) quaternion if the iterator is empty or the weights add up to zero.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{mean_markley, continuous, sum, normalize, dot};
+/// 
+/// // for rotations that are close together it agrees with the normalized sum
+/// let tight: [[f32; 4]; 3] = [
+///     [0.9950042, 0.0998334, 0.0, 0.0],
+///     [0.9987503, 0.0, 0.0499792, 0.0],
+///     [0.9987503, 0.0, 0.0, -0.0499792],
+/// ];
+/// let markley: [f32; 4] = mean_markley::<f32, [f32; 4]>(tight, [1.0; 3]);
+/// let simple: [f32; 4] = normalize::<f32, [f32; 4]>(sum::<f32, [f32; 4]>(tight));
+/// assert!( dot::<f32, f32>(markley, simple) > 0.99999 );
+/// 
+/// // rotations around the i axis, with the half angles:
+/// let half_angles: [f32; 5] = [0.0, 1.2, 2.4, 0.0, 0.0];
+/// let wide: [[f32; 4]; 5] = half_angles.map(|h| [h.cos(), h.sin(), 0.0, 0.0]);
+/// 
+/// let markley: [f32; 4] = mean_markley::<f32, [f32; 4]>(wide, [1.0; 5]);
+/// // the sign flips pile up along the sequence
+/// let simple: [f32; 4] = normalize::<f32, [f32; 4]>(
+///     sum::<f32, [f32; 4]>(continuous::<f32, [f32; 4]>(wide))
+/// );
+/// 
+/// // distance inbetween 2 half angles, ignoring the sign of the quaternion
+/// let dist = |a: f32, b: f32| {
+///     let d = (a - b).rem_euclid(core::f32::consts::PI);
+///     d.min(core::f32::consts::PI - d)
+/// };
+/// // the karcher mean found by brute force
+/// let karcher: f32 = (0..10_000)
+///     .map(|n| n as f32 * core::f32::consts::PI / 10_000.0)
+///     .min_by(|&a, &b| {
+///         let cost = |m: f32| half_angles.iter().map(|&h| dist(m, h).powi(2)).sum::<f32>();
+///         cost(a).total_cmp(&cost(b))
+///     })
+///     .unwrap();
+/// 
+/// let markley_error: f32 = dist(markley[1].atan2(markley[0]), karcher);
+/// let simple_error: f32 = dist(simple[1].atan2(simple[0]), karcher);
+/// assert!( markley_error < simple_error );
+/// ```
+pub fn mean_markley<Num, Out>(
+    iter: impl IntoIterator<Item: Quaternion<Num>>,
+    weights: impl IntoIterator<Item: Scalar<Num>>,
+) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let mut matrix: [[Num; 4]; 4] = [[Num::ZERO; 4]; 4];
+    for (quaternion, weight) in Iterator::zip(iter.into_iter(), weights) {
+        let q: [Num; 4] = [quaternion.r(), quaternion.i(), quaternion.j(), quaternion.k()];
+        let weight: Num = weight.scalar();
+        for (row, q_row) in matrix.iter_mut().zip(q) {
+            for (elem, q_col) in row.iter_mut().zip(q) {
+                *elem = *elem + weight * q_row * q_col;
+            }
+        }
+    }
+
+    match largest_eigenvector_4(matrix) {
+        Option::Some([r, i, j, k]) if r < Num::ZERO => Out::new_quat(-r, -i, -j, -k),
+        Option::Some([r, i, j, k]) => Out::new_quat(r, i, j, k),
+        Option::None => nan(),
+    }
+}
+
+/// Finds the weighted average rotation of an iterator of [f32] quaternions, working in [f64]s.
+/// 
+/// Same as [`mean_markley`], but the quaternions and weights are [widened](widen),
+/// the matrix is added up and solved in [f64] and only the result is [narrowed](narrow).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{mean_markley, mean_markley_widened};
+/// 
+/// let quats: [[f32; 4]; 3] = [[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [1.0, 0.0, 0.0, 0.0]];
+/// 
+/// let widened: [f32; 4] = mean_markley_widened::<[f32; 4]>(quats, [1.0_f32; 3]);
+/// let plain: [f32; 4] = mean_markley::<f32, [f32; 4]>(quats, [1.0_f32; 3]);
+/// 
+/// assert!( (widened[0] - 1.0).abs() < 1e-6 );
+/// assert!( (widened[0] - plain[0]).abs() < 1e-6 );
+/// ```
+pub fn mean_markley_widened<Out>(
+    iter: impl IntoIterator<Item: Quaternion<f32>>,
+    weights: impl IntoIterator<Item: Scalar<f32>>,
+) -> Out
+where 
+    Out: QuaternionConstructor<f32>,
+{
+    let mean: Q<f64> = mean_markley::<f64, Q<f64>>(
+        iter.into_iter().map(widen::<Q<f64>>),
+        weights.into_iter().map(|weight| weight.scalar() as f64),
+    );
+    narrow(mean)
+}
+
+/// Finds the rotation that best turns the first vector of every pair into the second one
+/// ([Wahba's problem](https://en.wikipedia.org/wiki/Wahba%27s_problem),
+/// the rotation part of the [Kabsch algorithm](https://en.wikipedia.org/wiki/Kabsch_algorithm)).
+/// 
+/// The result `q` minimizes the sum of `|to - point_rotation(q, from)|²` over the pairs
+/// (so longer vectors weigh more, normalize them first for equal weights),
+/// for point clouds the pairs are the points relative to the centroid of their cloud.
+/// 
+/// Uses [Davenport's q-method](https://ntrs.nasa.gov/citations/19690018880):
+/// the pairs are added up into the attitude profile matrix `B = Σ to fromᵀ`,
+/// which gives the symmetric 4x4 matrix `K`, and the result is the eigenvector of its largest eigenvalue,
+/// found the same way as in [`mean_markley`] (after shifting `K` by `Σ |from| |to|` so it's positive semi-definite).
+/// The result is normalized with a positive real part.
+/// 
+/// Returns [`None`](Option::None) if the rotation isn't determined by the pairs:
+/// if there are fewer then two pairs without a zero vector, or if all the first vectors are
+/// [parallel](are_parallel) (any turn around their axis fits as well).
+/// 
+/// # Example
+/// ```
+/// # #[cfg(feature = "rotation")] {
+/// use quaternion_traits::quat::{kabsch, from_axis_angle, point_rotation, is_near_by};
+/// 
+/// let rotation: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([1.0, 2.0, 3.0], 0.8);
+/// let points: [[f32; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 1.0, 1.0]];
+/// let pairs = points.map(|point| (point, point_rotation::<f32, [f32; 3]>(rotation, point)));
+/// 
+/// let found: [f32; 4] = kabsch::<f32, [f32; 4]>(pairs).unwrap();
+/// assert!( is_near_by::<f32>(found, rotation, 0.0001) );
+/// 
+/// // a single direction leaves the turn around it free
+/// assert_eq!( kabsch::<f32, [f32; 4]>([([1.0, 0.0, 0.0], [0.0, 1.0, 0.0]); 3]), None );
+/// # }
+/// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn kabsch<Num, Out>(pairs: impl IntoIterator<Item = (impl Vector<Num>, impl Vector<Num>)>) -> Option<Out>
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let mut b: [[Num; 3]; 3] = [[Num::ZERO; 3]; 3];
+    let mut shift: Num = Num::ZERO;
+    let mut first: Option<[Num; 3]> = Option::None;
+    let mut spanning: bool = false;
+    for (from, to) in pairs {
+        let from: [Num; 3] = [from.x(), from.y(), from.z()];
+        let to: [Num; 3] = [to.x(), to.y(), to.z()];
+        let from_length: Num = (from[0] * from[0] + from[1] * from[1] + from[2] * from[2]).sqrt();
+        let to_length: Num = (to[0] * to[0] + to[1] * to[1] + to[2] * to[2]).sqrt();
+        if from_length == Num::ZERO || to_length == Num::ZERO {
+            continue;
+        }
+        match first {
+            Option::None => first = Option::Some(from),
+            Option::Some(first) => spanning = spanning || !are_parallel(first, from),
+        }
+        for (row, to) in b.iter_mut().zip(to) {
+            for (elem, from) in row.iter_mut().zip(from) {
+                *elem = *elem + to * from;
+            }
+        }
+        shift = shift + from_length * to_length;
+    }
+    if !spanning {
+        return Option::None;
+    }
+
+    let sigma: Num = b[0][0] + b[1][1] + b[2][2];
+    let z: [Num; 3] = [b[2][1] - b[1][2], b[0][2] - b[2][0], b[1][0] - b[0][1]];
+    let mut k: [[Num; 4]; 4] = [
+        [sigma, z[0], z[1], z[2]],
+        [z[0], Num::ZERO, Num::ZERO, Num::ZERO],
+        [z[1], Num::ZERO, Num::ZERO, Num::ZERO],
+        [z[2], Num::ZERO, Num::ZERO, Num::ZERO],
+    ];
+    for row in 0..3 {
+        for col in 0..3 {
+            k[row + 1][col + 1] = b[row][col] + b[col][row];
+        }
+        k[row + 1][row + 1] = k[row + 1][row + 1] - sigma;
+    }
+    for (n, row) in k.iter_mut().enumerate() {
+        row[n] = row[n] + shift;
+    }
+
+    match largest_eigenvector_4(k) {
+        Option::Some([r, i, j, k]) if r < Num::ZERO => Option::Some(Out::new_quat(-r, -i, -j, -k)),
+        Option::Some([r, i, j, k]) => Option::Some(Out::new_quat(r, i, j, k)),
+        Option::None => Option::None,
+    }
+}
+
+/// Finds the (normalized) eigenvector of the largest eigenvalue
+/// of a symmetric positive semi-definite 4x4 matrix.
+/// 
+/// Returns [`None`](Option::None) if the trace of the matrix is not positive.
+fn largest_eigenvector_4<Num: Axis>(matrix: [[Num; 4]; 4]) -> Option<[Num; 4]> {
+    const SQUARINGS: usize = 8;
+    const MAX_ITERATIONS: usize = 32;
+
+    let mul = |matrix: &[[Num; 4]; 4], vector: [Num; 4]| -> [Num; 4] {
+        matrix.map(|row| row[0]*vector[0] + row[1]*vector[1] + row[2]*vector[2] + row[3]*vector[3])
+    };
+    let normalize = |vector: [Num; 4]| -> [Num; 4] {
+        let len: Num = (vector[0]*vector[0] + vector[1]*vector[1] + vector[2]*vector[2] + vector[3]*vector[3]).sqrt();
+        vector.map(|elem| elem / len)
+    };
+
+    // Squaring the matrix squares the ratio inbetween the eigenvalues,
+    // so after a few squarings it's (close to) just the largest eigenvalue's part.
+    let mut squared: [[Num; 4]; 4] = matrix;
+    for _ in 0..SQUARINGS {
+        let trace: Num = squared[0][0] + squared[1][1] + squared[2][2] + squared[3][3];
+        if trace <= Num::ZERO || trace.is_nan() {
+            return Option::None;
+        }
+        let scaled: [[Num; 4]; 4] = squared.map(|row| row.map(|elem| elem / trace));
+        // symmetric, so the rows are also the columns
+        squared = scaled.map(|row| mul(&scaled, row));
+    }
+
+    // the column with the largest diagonal can't be orthogonal to the eigenvector we want
+    let mut largest: usize = 0;
+    for n in 1..4 {
+        if squared[n][n] > squared[largest][largest] {
+            largest = n;
+        }
+    }
+    let mut vector: [Num; 4] = normalize(squared[largest]);
+
+    for _ in 0..MAX_ITERATIONS {
+        let next: [Num; 4] = normalize(mul(&matrix, vector));
+        let done: bool = (next[0] - vector[0]).abs() <= Num::ERROR
+                      && (next[1] - vector[1]).abs() <= Num::ERROR
+                      && (next[2] - vector[2]).abs() <= Num::ERROR
+                      && (next[3] - vector[3]).abs() <= Num::ERROR;
+        vector = next;
+        if done {
+            break;
+        }
+    }
+
+    if vector[0].is_nan() { Option::None } else { Option::Some(vector) }
+}
+
+/// Blends rotations with barycentric weights using their
+/// [Karcher mean](https://en.wikipedia.org/wiki/Fr%C3%A9chet_mean)
+/// (the rotation with the smallest weighted sum of squared angles to all of them).
+/// 
+/// Starting at the rotation with the largest weight, every iteration takes the weighted average of the
+/// logarithms of the rotations relative to the current estimate and moves the estimate by it,
+/// for at most 16 iterations or until the step is smaller then [`Num::ERROR`](Axis::ERROR).
+/// For two rotations this is the same as [`slerp_unchecked`] and for more it's the natural
+/// extension of it, `q` and `-q` count as the same rotation.
+/// 
+/// This is heavier than the flat weighted mean ([`mean_markley`] or normalizing the weighted sum),
+/// which only matches the Karcher mean for rotations that are close together,
+/// and it drifts off the more the rotations are spread out.
+/// 
+/// Returns [`None`](Option::None) if the slices are empty, have different lengths
+/// or the weights don't add up to [`Num::ONE`](Axis::ONE) (within [`Num::ERROR`](Axis::ERROR)).
+/// The quaternions should be normalized and the weights should not be negative.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{blend_barycentric, is_near};
+/// 
+/// let a: [f32; 4] = [1.0, 0.0, 0.0, 0.0];
+/// let b: [f32; 4] = [0.0, 1.0, 0.0, 0.0];
+/// 
+/// // the same as `slerp_unchecked(a, b, 0.25)`, a quarter of the way from `a` to `b`
+/// let blended: [f32; 4] = blend_barycentric::<f32, [f32; 4]>(&[a, b], &[0.75_f32, 0.25]).unwrap();
+/// let angle: f32 = 0.25 * core::f32::consts::FRAC_PI_2;
+/// assert!( is_near::<f32>(blended, [angle.cos(), angle.sin(), 0.0, 0.0]) );
+/// 
+/// assert!( blend_barycentric::<f32, [f32; 4]>(&[a, b], &[1.0_f32]).is_none() );
+/// assert!( blend_barycentric::<f32, [f32; 4]>(&[a, b], &[0.5_f32, 0.6]).is_none() );
+/// ```
+pub fn blend_barycentric<Num, Out>(quats: &[impl Quaternion<Num>], weights: &[impl Scalar<Num>]) -> Option<Out>
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    blend_barycentric_n::<Num, Out, 16>(quats, weights)
+}
+
+/// Blends rotations with barycentric weights using their Karcher mean,
+/// doing at most `MAX_ITERATIONS` iterations.
+/// 
+/// Same as [`blend_barycentric`] (which does at most 16 iterations),
+/// but the cap on the work can be lowered to make the time it takes predictable
+/// or raised for rotations that are spread out a lot.
+/// It still stops early once the step is smaller then [`Num::ERROR`](Axis::ERROR).
+/// With zero iterations the rotation with the largest weight is given back (normalized).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{blend_barycentric, blend_barycentric_n};
+/// 
+/// let quats: [[f32; 4]; 3] = [[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.6, 0.0, 0.8, 0.0]];
+/// let weights: [f32; 3] = [0.5, 0.25, 0.25];
+/// 
+/// let capped: [f32; 4] = blend_barycentric_n::<f32, [f32; 4], 0>(&quats, &weights).unwrap();
+/// assert_eq!( capped, quats[0] );
+/// 
+/// assert_eq!(
+///     blend_barycentric_n::<f32, [f32; 4], 16>(&quats, &weights),
+///     blend_barycentric::<f32, [f32; 4]>(&quats, &weights),
+/// );
+/// ```
+pub fn blend_barycentric_n<Num, Out, const MAX_ITERATIONS: usize>(quats: &[impl Quaternion<Num>], weights: &[impl Scalar<Num>]) -> Option<Out>
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    if quats.is_empty() || quats.len() != weights.len() {
+        return Option::None;
+    }
+    let mut total: Num = Num::ZERO;
+    let mut start: usize = 0;
+    for (index, weight) in weights.iter().enumerate() {
+        total = total + weight.scalar();
+        if weight.scalar() > weights[start].scalar() {
+            start = index;
+        }
+    }
+    if (total - Num::ONE).abs() > Num::ERROR || total.is_nan() {
+        return Option::None;
+    }
+
+    let mut mean: Q<Num> = normalize(&quats[start]);
+    for _ in 0..MAX_ITERATIONS {
+        let mut step: [Num; 3] = [Num::ZERO; 3];
+        for (quaternion, weight) in Iterator::zip(quats.iter(), weights.iter()) {
+            let mut relative: Q<Num> = mul(conj::<Num, Q<Num>>(mean), quaternion);
+            if relative.r() < Num::ZERO {
+                relative = neg(relative);
+            }
+            let (r, [i, j, k]) = relative;
+            let sin: Num = (i*i + j*j + k*k).sqrt();
+            // half angle divided by it's sine, close to one for small angles
+            let factor: Num = if sin < Num::ERROR { Num::ONE } else { Num::atan2(sin, r) / sin };
+            let weight: Num = weight.scalar() * factor;
+            step = [step[0] + weight * i, step[1] + weight * j, step[2] + weight * k];
+        }
+
+        let angle: Num = (step[0]*step[0] + step[1]*step[1] + step[2]*step[2]).sqrt();
+        let (sin, cos) = angle.sin_cos();
+        let factor: Num = if angle < Num::ERROR { Num::ONE } else { sin / angle };
+        mean = normalize(mul::<Num, Q<Num>>(mean, (cos, step.map(|axis| axis * factor))));
+        if angle < Num::ERROR {
+            break;
+        }
+    }
+    Option::Some(Out::from_quat(mean))
+}
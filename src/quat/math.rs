@@ -14,7 +14,7 @@ use super::*;
 /// assert_eq!( result, [5.0, 5.0, 5.0, 0.0] );
 /// ```
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn add<Num, Out>(left: impl Quaternion<Num>, right: impl Quaternion<Num>) -> Out
 where 
     Num: Axis,
@@ -41,7 +41,7 @@ where
 /// ```
 #[inline]
 #[cfg(feature = "qol_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn add_scalar<Num, Out>(quaternion: impl Quaternion<Num>, scalar: impl Scalar<Num>) -> Out
 where 
     Num: Axis,
@@ -68,7 +68,7 @@ where
 /// ```
 #[inline]
 #[cfg(feature = "qol_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn add_complex<Num, Out>(quaternion: impl Quaternion<Num>, complex: impl Complex<Num>) -> Out
 where 
     Num: Axis,
@@ -95,7 +95,7 @@ where
 /// ```
 #[inline]
 #[cfg(feature = "qol_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn add_vector<Num, Out>(quaternion: impl Quaternion<Num>, vector: impl Vector<Num>) -> Out
 where 
     Num: Axis,
@@ -110,7 +110,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Subtracts a quaternion from another one.
 /// 
 /// # Example
@@ -138,7 +138,7 @@ where
 
 #[inline]
 #[cfg(feature = "qol_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Subtracts a scalar value from a quaternion.
 /// 
 /// # Example
@@ -165,7 +165,7 @@ where
 
 #[inline]
 #[cfg(feature = "qol_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Subtracts a complex number from a quaternion.
 /// 
 /// # Example
@@ -192,7 +192,7 @@ where
 
 #[inline]
 #[cfg(feature = "qol_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Subtracts a vector from a quaternion.
 /// 
 /// # Example
@@ -218,7 +218,94 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg(feature = "qol_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Subtracts a quaternion from a scalar value.
+/// 
+/// This is the reverse of [`sub_scalar`], computing `scalar - quaternion`.
+/// 
+/// # Example
+/// ```rust
+/// use quaternion_traits::quat::sub_from_scalar;
+/// 
+/// let quat: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+/// let result: [f32; 4] = sub_from_scalar::<f32, _>(2.0, quat);
+/// 
+/// assert_eq!( result, [1.0, -2.0, -3.0, -4.0] );
+/// ```
+pub fn sub_from_scalar<Num, Out>(scalar: impl Scalar<Num>, quaternion: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    Out::new_quat(
+        scalar.scalar() - quaternion.r(), 
+        -quaternion.i(), 
+        -quaternion.j(), 
+        -quaternion.k(),
+    )
+}
+
+#[inline]
+#[cfg(feature = "qol_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Subtracts a quaternion from a complex number.
+/// 
+/// This is the reverse of [`sub_complex`], computing `complex - quaternion`.
+/// 
+/// # Example
+/// ```rust
+/// use quaternion_traits::quat::sub_from_complex;
+/// 
+/// let quat: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+/// let result: [f32; 4] = sub_from_complex::<f32, _>((2.0, -2.0), quat);
+/// 
+/// assert_eq!( result, [1.0, -4.0, -3.0, -4.0] );
+/// ```
+pub fn sub_from_complex<Num, Out>(complex: impl Complex<Num>, quaternion: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    Out::new_quat(
+        complex.real() - quaternion.r(), 
+        complex.imaginary() - quaternion.i(), 
+        -quaternion.j(), 
+        -quaternion.k(),
+    )
+}
+
+#[inline]
+#[cfg(feature = "qol_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Subtracts a quaternion from a vector.
+/// 
+/// This is the reverse of [`sub_vector`], computing `vector - quaternion`.
+/// 
+/// # Example
+/// ```rust
+/// use quaternion_traits::quat::sub_from_vector;
+/// 
+/// let quat: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+/// let result: [f32; 4] = sub_from_vector::<f32, _>((1.5, 2.5, 3.5), quat);
+/// 
+/// assert_eq!( result, [-1.0, -0.5, -0.5, -0.5] );
+/// ```
+pub fn sub_from_vector<Num, Out>(vector: impl Vector<Num>, quaternion: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    Out::new_quat(
+        -quaternion.r(), 
+        vector.x() - quaternion.i(), 
+        vector.y() - quaternion.j(), 
+        vector.z() - quaternion.k(),
+    )
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Multiplies a quaternion to another one.
 /// 
 /// Quaternion multiplication formula:
@@ -270,6 +357,7 @@ where
 ///     mul::<f32, [f32; 4]>(quat, (complex, (), ()))
 /// );
 /// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn mul_complex<Num, Out>(left: impl Quaternion<Num>, right: impl Complex<Num>) -> Out
 where 
     Num: Axis,
@@ -299,6 +387,7 @@ where
 ///     mul::<f32, [f32; 4]>(quat, ((), vector))
 /// );
 /// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn mul_vector<Num, Out>(left: impl Quaternion<Num>, right: impl Vector<Num>) -> Out
 where 
     Num: Axis,
@@ -313,7 +402,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Multiplies two quaternions in reversed order.
 /// 
 /// Quaternions are neather comutative nor anti-comutative for multiplication,
@@ -340,7 +429,7 @@ where
 
 #[inline]
 #[cfg(feature = "qol_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Multiplies a complex number with a quaternion.
 /// 
 /// # Example
@@ -372,7 +461,7 @@ where
 
 #[inline]
 #[cfg(feature = "qol_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Multiplies a vector with a quaternion.
 /// 
 /// # Example
@@ -403,11 +492,11 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Divides a quaternion by another one.
 /// 
 /// Is equivalent to multiplying a quaternion with
-/// another one's inverse.
+/// another one's inverse on the right, aka [`solve_right(right, left)`](solve_right).
 /// 
 /// ```
 /// use quaternion_traits::quat::{div, mul, inv};
@@ -425,7 +514,15 @@ where
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    mul::<Num, Out>(left, inv::<Num, Q<Num>>(right))
+    // `mul(left, inv(right))` written out (the origin gives NaN too, `0 * ∞`)
+    let inv: Num = Num::ONE / abs_squared::<Num, Num>(&right);
+    let (r, i, j, k) = (right.r() * inv, -right.i() * inv, -right.j() * inv, -right.k() * inv);
+    Out::new_quat(
+        left.r() * r - left.i() * i - left.j() * j - left.k() * k,
+        left.r() * i + left.i() * r + left.j() * k - left.k() * j,
+        left.r() * j - left.i() * k + left.j() * r + left.k() * i,
+        left.r() * k + left.i() * j - left.j() * i + left.k() * r,
+    )
 }
 
 /// Divides a quaternion by another one in reversed order.
@@ -434,32 +531,444 @@ where
 /// anti-commutative and since division is just multiplying by the inverse
 /// 
 /// `div(q1, q2) = q1 * inv(q2)` 
-/// `div_reversed(q1, q2) = inv(q2) * q1` 
+/// `div_reversed(q1, q2) = inv(q1) * q2` 
+/// 
+/// Aka `div(q1, q2) = solve_right(q2, q1)` and `div_reversed(q1, q2) = solve_left(q1, q2)`,
+/// prefer [`solve_left`] and [`solve_right`] since their names say which equation is solved.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{div_reversed, solve_left};
+/// 
+/// let a: [f32; 4] = [1.0, 2.0, 0.0, 3.0];
+/// let b: [f32; 4] = [3.0, 1.0, 4.0, 0.0];
+/// 
+/// assert_eq!(
+///     div_reversed::<f32, [f32; 4]>(a, b),
+///     solve_left::<f32, [f32; 4]>(a, b)
+/// );
+/// ```
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn div_reversed<Num, Out>(left: impl Quaternion<Num>, right: impl Quaternion<Num>) -> Out
 where 
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    mul::<Num, Out>(inv::<Num, Q<Num>>(left), &right)
+    solve_left::<Num, Out>(left, right)
 }
 
-/// Calculates the modulus of a quaternion to another quaternion.
-#[cfg(feature = "unstable")]
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Solves `a * x = b` for `x`.
+/// 
+/// Is equivalent to `mul(inv(a), b)` (the inverse is on the left).
+/// 
+/// If `a` is the [origin](origin) the result is [NaN](Axis::NAN),
+/// use [`solve_left_checked`] to get [`None`](Option::None) instead.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{solve_left, mul, is_near};
+/// 
+/// let a: [f32; 4] = [1.0, 2.0, 0.0, 3.0];
+/// let b: [f32; 4] = [3.0, 1.0, 4.0, 0.0];
+/// let x: [f32; 4] = solve_left::<f32, [f32; 4]>(a, b);
+/// 
+/// assert!( is_near::<f32>(mul::<f32, [f32; 4]>(a, x), b) );
+/// ```
+pub fn solve_left<Num, Out>(a: impl Quaternion<Num>, b: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    mul::<Num, Out>(inv::<Num, Q<Num>>(a), b)
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Solves `x * a = b` for `x`.
+/// 
+/// Is equivalent to `mul(b, inv(a))` (the inverse is on the right)
+/// and to [`div(b, a)`](div).
+/// 
+/// If `a` is the [origin](origin) the result is [NaN](Axis::NAN),
+/// use [`solve_right_checked`] to get [`None`](Option::None) instead.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{solve_right, mul, is_near};
+/// 
+/// let a: [f32; 4] = [1.0, 2.0, 0.0, 3.0];
+/// let b: [f32; 4] = [3.0, 1.0, 4.0, 0.0];
+/// let x: [f32; 4] = solve_right::<f32, [f32; 4]>(a, b);
+/// 
+/// assert!( is_near::<f32>(mul::<f32, [f32; 4]>(x, a), b) );
+/// ```
+pub fn solve_right<Num, Out>(a: impl Quaternion<Num>, b: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    mul::<Num, Out>(b, inv::<Num, Q<Num>>(a))
+}
+
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gets the inverse of `a` only if it exists and is finite.
+fn inv_checked<Num>(a: impl Quaternion<Num>) -> Option<Q<Num>>
+where 
+    Num: Axis,
+{
+    let norm: Num = abs_squared(&a);
+    let scale: Num = Num::ONE / norm;
+    // `x * 0` is zero only for finite values
+    if norm == Num::ZERO || norm * Num::ZERO != Num::ZERO || scale * Num::ZERO != Num::ZERO {
+        return Option::None;
+    }
+    Option::Some(( a.r() * scale, [-a.i() * scale, -a.j() * scale, -a.k() * scale] ))
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Solves `a * x = b` for `x`.
+/// 
+/// Returns [`None`](Option::None) if `a` has no finite inverse
+/// (it's the origin, is NaN or infinite, or its absolute value under/overflows).
+/// 
+/// Check [`solve_left`] for more info.
+pub fn solve_left_checked<Num, Out>(a: impl Quaternion<Num>, b: impl Quaternion<Num>) -> Option<Out>
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    inv_checked::<Num>(a).map(|inv: Q<Num>| mul::<Num, Out>(inv, b))
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Solves `x * a = b` for `x`.
+/// 
+/// Returns [`None`](Option::None) if `a` has no finite inverse
+/// (it's the origin, is NaN or infinite, or its absolute value under/overflows).
+/// 
+/// Check [`solve_right`] for more info.
+pub fn solve_right_checked<Num, Out>(a: impl Quaternion<Num>, b: impl Quaternion<Num>) -> Option<Out>
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    inv_checked::<Num>(a).map(|inv: Q<Num>| mul::<Num, Out>(b, inv))
+}
+
+/// Truncates each component of a quaternion towards zero.
+#[inline]
+fn trunc<Num: Axis>(quaternion: impl Quaternion<Num>) -> Q<Num> {
+    (
+        quaternion.r() - quaternion.r() % Num::ONE,
+        [
+            quaternion.i() - quaternion.i() % Num::ONE,
+            quaternion.j() - quaternion.j() % Num::ONE,
+            quaternion.k() - quaternion.k() % Num::ONE,
+        ]
+    )
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Divides a quaternion by a modulus and gives back the quotient and the remainder.
+/// 
+/// The quotient is [`solve_left(modulus, quaternion)`](solve_left) with each component
+/// truncated towards zero (like integer division) and the remainder is
+/// `quaternion - modulus * quotient`, so `quaternion = modulus * quotient + remainder`.
+/// 
+/// For a real modulus this is the same as `/` and `%` on each component.
+/// 
+/// If the modulus is the [origin](origin) both are [NaN](Axis::NAN),
+/// use [`rem_checked`] to get [`None`](Option::None) instead.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{div_rem, add, mul};
+/// 
+/// let quat: [f32; 4] = [7.0, -5.0, 2.5, 9.0];
+/// 
+/// let (quotient, remainder): ([f32; 4], [f32; 4]) = div_rem::<f32, [f32; 4], [f32; 4]>(quat, [2.0, 0.0, 0.0, 0.0]);
+/// assert_eq!( quotient, [3.0, -2.0, 1.0, 4.0] );
+/// assert_eq!( remainder, [1.0, -1.0, 0.5, 1.0] );
+/// 
+/// let modulus: [f32; 4] = [1.0, 2.0, -1.0, 0.0];
+/// let (quotient, remainder): ([f32; 4], [f32; 4]) = div_rem::<f32, [f32; 4], [f32; 4]>(quat, modulus);
+/// assert_eq!( add::<f32, [f32; 4]>(mul::<f32, [f32; 4]>(modulus, quotient), remainder), quat );
+/// ```
+pub fn div_rem<Num, Quotient, Remainder>(quaternion: impl Quaternion<Num>, modulus: impl Quaternion<Num>) -> (Quotient, Remainder)
+where 
+    Num: Axis,
+    Quotient: QuaternionConstructor<Num>,
+    Remainder: QuaternionConstructor<Num>,
+{
+    let quotient: Q<Num> = trunc(solve_left::<Num, Q<Num>>(&modulus, &quaternion));
+    (
+        Quotient::from_quat(quotient),
+        sub(quaternion, mul::<Num, Q<Num>>(modulus, quotient)),
+    )
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Calculates the remainder of dividing a quaternion by a modulus.
+/// 
+/// It's the remainder given back by [`div_rem`],
+/// aka `quaternion - modulus * trunc(solve_left(modulus, quaternion))`.
+/// 
+/// If the modulus is the [origin](origin) the result is [NaN](Axis::NAN),
+/// use [`rem_checked`] to get [`None`](Option::None) instead.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::rem;
+/// 
+/// let quat: [f32; 4] = [7.0, -5.0, 2.5, 9.0];
+/// 
+/// assert_eq!( rem::<f32, [f32; 4]>(quat, [2.0, 0.0, 0.0, 0.0]), [1.0, -1.0, 0.5, 1.0] );
+/// ```
 pub fn rem<Num, Out>(quaternion: impl Quaternion<Num>, modulus: impl Quaternion<Num>) -> Out
 where 
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    let inv = inv::<Num, Q<Num>>(&quaternion);
-    let gaussian: Q<Num> = (
-        inv.r() - inv.r() % Num::ONE,
+    div_rem::<Num, Q<Num>, Out>(quaternion, modulus).1
+}
+
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Calculates the remainder of dividing a quaternion by a modulus.
+/// 
+/// Returns [`None`](Option::None) if the modulus has no finite inverse
+/// (it's the origin, is NaN or infinite, or its absolute value under/overflows).
+/// 
+/// Check [`rem`] for more info.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::rem_checked;
+/// 
+/// let quat: [f32; 4] = [7.0, -5.0, 2.5, 9.0];
+/// 
+/// assert_eq!( rem_checked::<f32, [f32; 4]>(quat, [2.0, 0.0, 0.0, 0.0]), Some([1.0, -1.0, 0.5, 1.0]) );
+/// assert_eq!( rem_checked::<f32, [f32; 4]>(quat, [0.0; 4]), None );
+/// ```
+pub fn rem_checked<Num, Out>(quaternion: impl Quaternion<Num>, modulus: impl Quaternion<Num>) -> Option<Out>
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let inv: Q<Num> = inv_checked::<Num>(&modulus)?;
+    let quotient: Q<Num> = trunc(mul::<Num, Q<Num>>(inv, &quaternion));
+    Option::Some(sub(quaternion, mul::<Num, Q<Num>>(modulus, quotient)))
+}
+
+#[inline]
+#[cfg(feature = "math_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Calculates the remainder of dividing a quaternion by a modulus
+/// with the quotient rounded to the nearest Hurwitz quaternion.
+/// 
+/// The remainder is `quaternion - modulus * round_hurwitz(solve_left(modulus, quaternion))`,
+/// which is as small as a remainder can get:
+/// `abs_squared(remainder) <= abs_squared(modulus) / 2`.
+/// This is the division step of the euclidean algorithm for Hurwitz quaternions.
+/// 
+/// If the modulus is the [origin](origin) the result is [NaN](Axis::NAN).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{rem_euclid, abs_squared};
+/// 
+/// let quat: [f32; 4] = [7.0, -5.0, 2.0, 9.0];
+/// let modulus: [f32; 4] = [1.0, 2.0, -1.0, 3.0];
+/// 
+/// let remainder: [f32; 4] = rem_euclid::<f32, [f32; 4]>(quat, modulus);
+/// assert!( abs_squared::<f32, f32>(remainder) <= abs_squared::<f32, f32>(modulus) / 2.0 );
+/// ```
+pub fn rem_euclid<Num, Out>(quaternion: impl Quaternion<Num>, modulus: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let quotient: Q<Num> = round_hurwitz(solve_left::<Num, Q<Num>>(&modulus, &quaternion));
+    sub(quaternion, mul::<Num, Q<Num>>(modulus, quotient))
+}
+
+/// Rounds a number to the nearest integer, with ties rounding away from zero.
+#[inline]
+#[cfg(feature = "math_fns")]
+fn round_nearest<Num: Axis>(num: Num) -> Num {
+    let half: Num = crate::consts::half::<Num>();
+    let truncated: Num = num - num % Num::ONE;
+    let fraction: Num = num - truncated;
+    if fraction >= half {
+        truncated + Num::ONE
+    } else if fraction <= -half {
+        truncated - Num::ONE
+    } else {
+        truncated
+    }
+}
+
+#[inline]
+#[cfg(feature = "math_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Rounds a quaternion to the nearest Lipschitz quaternion
+/// (a quaternion who's components are all integers).
+/// 
+/// Each component is rounded on it's own, ties (`x.5`) get rounded away from zero.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::round_lipschitz;
+/// 
+/// let quat: [f32; 4] = [1.2, -2.7, 0.5, -3.5];
+/// 
+/// assert_eq!( round_lipschitz::<f32, [f32; 4]>(quat), [1.0, -3.0, 1.0, -4.0] );
+/// ```
+pub fn round_lipschitz<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    Out::new_quat(
+        round_nearest(quaternion.r()),
+        round_nearest(quaternion.i()),
+        round_nearest(quaternion.j()),
+        round_nearest(quaternion.k()),
+    )
+}
+
+#[cfg(feature = "math_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Rounds a quaternion to the nearest Hurwitz quaternion
+/// (a quaternion who's components are eather all integers or all half-integers).
+/// 
+/// This gets the nearest quaternion with all integer components ([`round_lipschitz`])
+/// and the nearest one with all half-integer components, and gives back
+/// whichever one is closer to the quaternion.
+/// If they are both as close then the integer one is given back.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{round_hurwitz, round_lipschitz, dist_euclid};
+/// 
+/// // closer to the half-integers
+/// let quat: [f32; 4] = [0.4, -0.6, 1.45, -2.55];
+/// assert_eq!( round_hurwitz::<f32, [f32; 4]>(quat), [0.5, -0.5, 1.5, -2.5] );
+/// 
+/// // closer to the integers
+/// let quat: [f32; 4] = [0.9, -1.2, 3.0, -0.1];
+/// assert_eq!( round_hurwitz::<f32, [f32; 4]>(quat), [1.0, -1.0, 3.0, 0.0] );
+/// 
+/// // a tie inbetween the two goes to the integers
+/// let quat: [f32; 4] = [0.25, 0.25, 0.25, 0.25];
+/// assert_eq!( round_hurwitz::<f32, [f32; 4]>(quat), [0.0, 0.0, 0.0, 0.0] );
+/// 
+/// // never further away then the nearest Lipschitz quaternion
+/// for quat in [[0.3, -0.7, 2.5, -1.5], [-0.49, 0.51, 0.5, -0.5], [10.2, -3.4, 0.6, 7.5]] {
+///     let hurwitz: [f32; 4] = round_hurwitz::<f32, [f32; 4]>(quat);
+///     let lipschitz: [f32; 4] = round_lipschitz::<f32, [f32; 4]>(quat);
+///     assert!( dist_euclid::<f32, f32>(quat, hurwitz) <= dist_euclid::<f32, f32>(quat, lipschitz) );
+/// }
+/// ```
+pub fn round_hurwitz<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let half: Num = crate::consts::half::<Num>();
+    let integer: Q<Num> = round_lipschitz(&quaternion);
+    let half_integer: Q<Num> = (
+        round_nearest(quaternion.r() - half) + half,
         [
-            inv.i() - inv.i() % Num::ONE,
-            inv.j() - inv.j() % Num::ONE,
-            inv.k() - inv.k() % Num::ONE,
+            round_nearest(quaternion.i() - half) + half,
+            round_nearest(quaternion.j() - half) + half,
+            round_nearest(quaternion.k() - half) + half,
         ]
     );
-    sub(quaternion, mul::<Num, Q<Num>>(modulus, gaussian))
+    if abs_squared::<Num, Num>(sub::<Num, Q<Num>>(&quaternion, half_integer))
+     < abs_squared::<Num, Num>(sub::<Num, Q<Num>>(&quaternion, integer)) {
+        Out::from_quat(half_integer)
+    } else {
+        Out::from_quat(integer)
+    }
+}
+
+#[inline]
+#[cfg(feature = "math_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gets the norm of a quaternion as it's used in number theory,
+/// aka the sum of the squares of it's four components (`r² + i² + j² + k²`).
+/// 
+/// Same as [`abs_squared`], but for a Lipschitz or Hurwitz quaternion it's an integer
+/// and it's multiplicative (`N(a * b) = N(a) * N(b)`), which is
+/// [Euler's four-square identity](https://en.wikipedia.org/wiki/Euler%27s_four-square_identity).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{four_square_norm, mul};
+/// 
+/// let a: [f32; 4] = [1.0, 2.0, -1.0, 3.0];
+/// let b: [f32; 4] = [0.0, -4.0, 2.0, 1.0];
+/// 
+/// assert_eq!( four_square_norm::<f32, u32>(a), 15 );
+/// assert_eq!( four_square_norm::<f32, u32>(b), 21 );
+/// assert_eq!( four_square_norm::<f32, u32>(mul::<f32, [f32; 4]>(a, b)), 15 * 21 );
+/// ```
+pub fn four_square_norm<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: ScalarConstructor<Num>,
+{
+    abs_squared(quaternion)
+}
+
+#[cfg(feature = "math_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gets the 8 associates of a quaternion,
+/// aka the quaternion multiplied (from the left) by each Lipschitz unit.
+/// 
+/// They are given back in the order `q`, `-q`, `i q`, `-i q`, `j q`, `-j q`, `k q`, `-k q`.
+/// 
+/// All of them have the same [`four_square_norm`] as the quaternion,
+/// and for the associates of `1` they are the Lipschitz units themselves.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{associates, mul};
+/// 
+/// let quat: [f32; 4] = [1.0, 2.0, -1.0, 3.0];
+/// let units: [[f32; 4]; 8] = associates::<f32, [f32; 4]>([1.0, 0.0, 0.0, 0.0]);
+/// 
+/// assert_eq!( units[4], [0.0, 0.0, 1.0, 0.0] );
+/// for (associate, unit) in associates::<f32, [f32; 4]>(quat).into_iter().zip(units) {
+///     assert_eq!( associate, mul::<f32, [f32; 4]>(unit, quat) );
+/// }
+/// ```
+pub fn associates<Num, Out>(quaternion: impl Quaternion<Num>) -> [Out; 8]
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let (r, i, j, k) = (quaternion.r(), quaternion.i(), quaternion.j(), quaternion.k());
+    [
+        Out::new_quat(r, i, j, k),
+        Out::new_quat(-r, -i, -j, -k),
+        // i q
+        Out::new_quat(-i, r, -k, j),
+        Out::new_quat(i, -r, k, -j),
+        // j q
+        Out::new_quat(-j, k, r, -i),
+        Out::new_quat(j, -k, -r, i),
+        // k q
+        Out::new_quat(-k, -j, i, r),
+        Out::new_quat(k, j, -i, -r),
+    ]
 }
 
 /// Multiplies two quaternions and then adds the addend.
@@ -486,7 +995,7 @@ where
 /// ```
 #[inline]
 #[cfg(feature = "qol_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn mul_add<Num, Out>(quaternion: impl Quaternion<Num>, factor: impl Quaternion<Num>, addend: impl Quaternion<Num>) -> Out
 where 
     Num: Axis,
@@ -520,7 +1029,7 @@ where
 /// ```
 #[inline]
 #[cfg(feature = "qol_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn mul_reversed_add<Num, Out>(quaternion: impl Quaternion<Num>, factor: impl Quaternion<Num>, addend: impl Quaternion<Num>) -> Out
 where 
     Num: Axis,
@@ -530,7 +1039,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Gets the negative of this quaternion.
 /// 
 /// # Example
@@ -556,7 +1065,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Gets the conjugate of this quaternion.
 /// 
 /// # Example
@@ -582,7 +1091,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Scales a quaternion.
 /// 
 /// Equivalent to multiplying a quaternion by a scalar quaternion.
@@ -610,7 +1119,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Scales a quaternion by the inverse of the scalar.
 /// 
 /// Equivalent to dividing a quaternion by a scalar quaternion.
@@ -642,25 +1151,34 @@ where
 /// 
 /// Uses the shortest path inbetween the two quaternions.
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn lerp<Num, Out>(from: impl Quaternion<Num>, to: impl Quaternion<Num>, at: impl Scalar<Num>) -> Out
 where 
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    add(
-        if dot::<Num, Num>(&from, &to) < Num::ZERO {
-            scale::<Num, Q<Num>>(add::<Num, Q<Num>>(to, &from), at)
-        } else {
-            scale::<Num, Q<Num>>(sub::<Num, Q<Num>>(to, &from), at)
-        },
-        from,
-    )
+    let at: Num = at.scalar();
+    if dot::<Num, Num>(&from, &to) < Num::ZERO {
+        let at: Num = -at;
+        Out::new_quat(
+            (to.r() + from.r()) * at + from.r(),
+            (to.i() + from.i()) * at + from.i(),
+            (to.j() + from.j()) * at + from.j(),
+            (to.k() + from.k()) * at + from.k(),
+        )
+    } else {
+        Out::new_quat(
+            (to.r() - from.r()) * at + from.r(),
+            (to.i() - from.i()) * at + from.i(),
+            (to.j() - from.j()) * at + from.j(),
+            (to.k() - from.k()) * at + from.k(),
+        )
+    }
 }
 
 #[inline]
 #[cfg(feature = "math_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Spherical liniar interpolation for unit quaternions.
 /// 
 /// Uses the shortest path inbetween the two unit
@@ -672,8 +1190,8 @@ where
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    if (abs_squared::<Num, Num>(&from) - Num::ONE).abs() < Num::ERROR * Num::ERROR
-    && (abs_squared::<Num, Num>( &to ) - Num::ONE).abs() < Num::ERROR * Num::ERROR
+    if (abs_squared::<Num, Num>(&from) - Num::ONE).abs() < Num::EPS_TRIG
+    && (abs_squared::<Num, Num>( &to ) - Num::ONE).abs() < Num::EPS_TRIG
     { Option::Some(slerp_unchecked(from, to, at)) }
     else { Option::None }
 }
@@ -685,7 +1203,7 @@ where
 /// 
 /// The two quaternions must be unit quaternions (have an absolite value of [`Num::ONE`](Axis::ONE)).
 #[cfg(feature = "math_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn slerp_unchecked<Num, Out>(from: impl Quaternion<Num>, to: impl Quaternion<Num>, at: impl Scalar<Num>) -> Out
 where 
     Num: Axis,
@@ -700,53 +1218,197 @@ where
         convert_quat(to)
     };
 
-    if dot > Num::ONE - Num::ERROR { // for ERROR = 0.0005 => Aprox. Err < 0.017%
-        return add(
-            scale::<Num, Q<Num>>(add::<Num, Q<Num>>(to, &from), at),
-            from
-        );
+    let at: Num = at.scalar();
+
+    // close enough for the liniar interpolation, which is never the origin here
+    if dot > crate::consts::slerp_lerp_threshold::<Num>() {
+        let (r, [i, j, k]) = to;
+        return normalize_unchecked((
+            (r - from.r()) * at + from.r(),
+            [(i - from.i()) * at + from.i(), (j - from.j()) * at + from.j(), (k - from.k()) * at + from.k()],
+        ));
     }
 
     let angle = dot.acos();
-    let transition_angle = at.scalar() * angle;
+    let transition_angle = at * angle;
 
     let sin_1 = (angle - transition_angle).sin();
     let sin_2 = transition_angle.sin();
 
     let coeficient = Num::ONE / (Num::ONE - dot*dot).sqrt();
+    let (from_factor, to_factor) = (coeficient * sin_1, coeficient * sin_2);
+    let (r, [i, j, k]) = to;
+
+    Out::new_quat(
+        from.r() * from_factor + r * to_factor,
+        from.i() * from_factor + i * to_factor,
+        from.j() * from_factor + j * to_factor,
+        from.k() * from_factor + k * to_factor,
+    )
+}
+
+/// Spherical liniar interpolation for unit quaternions that also extrapolates.
+/// 
+/// Any real `at` is allowed: `at = 0` gives `from`, `at = 1` gives `to`
+/// and values outside `[0, 1]` keep moving along the same great circle,
+/// so `at = 2` is `mul(mul(to, conj(from)), to)` and `at = -1` is `mul(mul(from, conj(to)), from)`.
+/// 
+/// Unlike [`slerp_unchecked`] this never flips `to` onto the shortest path
+/// (that would make the result jump when the dot product changes sign),
+/// so give it `to` or `neg(to)` yourself if you want the shortest path.
+/// 
+/// The result goes around the great circle, when `at * angle` passes
+/// `π` (where `angle = acos(dot(from, to))`) it reaches `neg(from)`,
+/// which is the same rotation as `from`, and at `2π` it is back at `from`.
+/// So as a rotation the result repeats every `π / angle` steps of `at`.
+/// 
+/// When `from` and `to` are (almost) the same the result is the normalized
+/// liniar extrapolation, when they are (almost) opposite the great circle isn't unique
+/// and the one through `mul(from, [0, 1, 0, 0])` (around the local x axis) is used.
+/// 
+/// The two quaternions must be unit quaternions (have an absolite value of [`Num::ONE`](Axis::ONE)).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{slerp_extrapolate, from_axis_angle, is_near};
+/// # use core::f32::consts::PI;
+/// 
+/// let from: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 0.0);
+/// let to: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], PI / 4.0);
+/// 
+/// let past: [f32; 4] = slerp_extrapolate::<f32, [f32; 4]>(from, to, 3.0);
+/// assert!( is_near::<f32>(past, from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 3.0 * PI / 4.0)) );
+/// 
+/// let before: [f32; 4] = slerp_extrapolate::<f32, [f32; 4]>(from, to, -0.5);
+/// assert!( is_near::<f32>(before, from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], -PI / 8.0)) );
+/// ```
+#[cfg(feature = "math_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn slerp_extrapolate<Num, Out>(from: impl Quaternion<Num>, to: impl Quaternion<Num>, at: impl Scalar<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let at: Num = at.scalar();
+    let dot: Num = dot::<Num, Num>(&from, &to);
+    // the part of `to` perpendicular to `from`, its length is the sine of the angle
+    let perpendicular: Q<Num> = sub(&to, scale::<Num, Q<Num>>(&from, dot));
+    let sin: Num = abs(perpendicular);
+
+    if sin < Num::ERROR {
+        if dot > Num::ZERO {
+            let linear: Q<Num> = add(scale::<Num, Q<Num>>(sub::<Num, Q<Num>>(to, &from), at), &from);
+            return normalize_unchecked(linear);
+        }
+        let (sin, cos) = (at * Num::TAU / Num::from_f64(2.0)).sin_cos();
+        return add(
+            scale::<Num, Q<Num>>(&from, cos),
+            scale::<Num, Q<Num>>(mul::<Num, Q<Num>>(&from, (Num::ZERO, [Num::ONE, Num::ZERO, Num::ZERO])), sin),
+        );
+    }
 
+    let (sin_at, cos_at) = (at * Num::atan2(sin, dot)).sin_cos();
     add(
-        scale::<Num, Q<Num>>(from, coeficient * sin_1),
-        scale::<Num, Q<Num>>(to, coeficient * sin_2),
+        scale::<Num, Q<Num>>(from, cos_at),
+        scale::<Num, Q<Num>>(perpendicular, sin_at / sin),
     )
 }
 
-/// Gets the distance inbetween the coordenates of two quaternions.
+#[cfg(feature = "math_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Spherical liniar interpolation for unit quaternions, with `at` passed through an easing function.
+/// 
+/// `at` is clamped to `[0, 1]` first, then given to `ease`,
+/// and the result is used as the `at` of [`slerp_unchecked`] (so the shortest path is used).
+/// The [`ease`](crate::quat::ease) module has some common easing functions.
+/// 
+/// When the eased value is exactly `0` the result is exactly `from`,
+/// when it's exactly `1` the result is exactly `to` (or `neg(to)` if that one is on the shortest path),
+/// so any easing function with `ease(0) = 0` and `ease(1) = 1` keeps the endpoints exact.
+/// Eased values outside of `[0, 1]` (for curves that overshoot) keep going along the same great circle.
+/// 
+/// The two quaternions must be unit quaternions (have an absolite value of [`Num::ONE`](Axis::ONE)).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{slerp_eased, slerp_unchecked, from_axis_angle, ease};
+/// 
+/// let from: [f32; 4] = [1.0, 0.0, 0.0, 0.0];
+/// let to: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([0.0, 1.0, 0.0], 1.0);
+/// 
+/// assert_eq!( slerp_eased::<f32, [f32; 4]>(from, to, 0.0, ease::smoothstep), from );
+/// assert_eq!( slerp_eased::<f32, [f32; 4]>(from, to, 1.0, ease::smoothstep), to );
+/// assert_eq!( slerp_eased::<f32, [f32; 4]>(from, to, 2.0, ease::smoothstep), to );
+/// assert_eq!(
+///     slerp_eased::<f32, [f32; 4]>(from, to, 0.25, |at: f32| at * at),
+///     slerp_unchecked::<f32, [f32; 4]>(from, to, 0.0625),
+/// );
+/// ```
+pub fn slerp_eased<Num, Out>(from: impl Quaternion<Num>, to: impl Quaternion<Num>, at: impl Scalar<Num>, ease: impl crate::core::ops::Fn(Num) -> Num) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let at: Num = ease(at.scalar().max(Num::ZERO).min(Num::ONE));
+    if at == Num::ZERO {
+        return convert_quat(from);
+    }
+    if at == Num::ONE {
+        return if dot::<Num, Num>(&from, &to) < Num::ZERO { neg(to) } else { convert_quat(to) };
+    }
+    slerp_unchecked(from, to, at)
+}
+
+/// Gets the distance inbetween the coordenates of two quaternions.
+/// 
+/// Equivalent to getting the absolute value of 
+/// 
+/// ```
+/// use quaternion_traits::quat::dist_euclid;
+/// 
+/// let a: [f32; 4] = [5.0, 0.0, 1.0, 3.0];
+/// let b: [f32; 4] = [2.0, 0.0, 5.0, 3.0];
+/// 
+/// assert_eq!( dist_euclid::<f32, f32>(a, b), 5.0 );
+/// assert_eq!( dist_euclid::<f32, f32>(a, a), 0.0 );
+/// ```
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn dist_euclid<Num, Out>(from: impl Quaternion<Num>, to: impl Quaternion<Num>) -> Out
+where
+    Num: Axis,
+    Out: ScalarConstructor<Num>,
+{
+    abs(sub::<Num, Q<Num>>(from, to))
+}
+
+/// Gets the squared distance inbetween the coordenates of two quaternions.
 /// 
-/// Equivalent to getting the absolute value of 
+/// Cheaper then [`dist_euclid`] because it does not use [`sqrt`](Axis::sqrt),
+/// this is what [`is_near`] and [`is_near_by`] compare against.
 /// 
 /// ```
-/// use quaternion_traits::quat::dist_euclid;
+/// use quaternion_traits::quat::dist_euclid_squared;
 /// 
 /// let a: [f32; 4] = [5.0, 0.0, 1.0, 3.0];
 /// let b: [f32; 4] = [2.0, 0.0, 5.0, 3.0];
 /// 
-/// assert_eq!( dist_euclid::<f32, f32>(a, b), 5.0 );
-/// assert_eq!( dist_euclid::<f32, f32>(a, a), 0.0 );
+/// assert_eq!( dist_euclid_squared::<f32, f32>(a, b), 25.0 );
+/// assert_eq!( dist_euclid_squared::<f32, f32>(a, a), 0.0 );
 /// ```
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
-pub fn dist_euclid<Num, Out>(from: impl Quaternion<Num>, to: impl Quaternion<Num>) -> Out
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn dist_euclid_squared<Num, Out>(from: impl Quaternion<Num>, to: impl Quaternion<Num>) -> Out
 where
     Num: Axis,
     Out: ScalarConstructor<Num>,
 {
-    abs(sub::<Num, Q<Num>>(from, to))
+    abs_squared(sub::<Num, Q<Num>>(from, to))
 }
 
 /// Calculates the cosine distance between two quaternions.
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn dist_cosine<Num, Out>(from: impl Quaternion<Num>, to: impl Quaternion<Num>) -> Out
 where 
     Num: Axis,
@@ -757,7 +1419,7 @@ where
 
 /// Calculates the geodesic distance between two quaternions.
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn dist_geodesic<Num, Out>(from: impl Quaternion<Num>, to: impl Quaternion<Num>) -> Out
 where
     Num: Axis,
@@ -766,18 +1428,36 @@ where
     Out::new_scalar((dot::<Num, Num>(from, to) - Num::ONE).acos())
 }
 
-/// Calculates the angle between two quaternions.
+/// Calculates the angle between two quaternions, from `0` to `π`.
 /// 
 /// This does NOT use the [`angle`] function, and the two give diferent results.
+/// 
+/// Uses `2·atan2(|a - b|, |a + b|)` of the normalized quaternions,
+/// which (unlike `acos` of the normalized dot product, see [`angle_between_cos`])
+/// stays accurate for angles near `0` and near `π`.
+/// 
+/// If one of the quaternions is the origin the result is NaN.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::angle_between;
+/// # use core::f32::consts::FRAC_PI_2;
+/// 
+/// assert!( (angle_between::<f32, f32>([2.0, 0.0, 0.0, 0.0], [0.0, 0.0, 3.0, 0.0]) - FRAC_PI_2).abs() < 0.00001 );
+/// assert_eq!( angle_between::<f32, f32>([1.0, 0.0, 0.0, 0.0], [1.0, 1e-5, 0.0, 0.0]), 1e-5 );
+/// ```
 #[inline]
 #[cfg(feature = "rotation")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn angle_between<Num, Out>(from: impl Quaternion<Num>, to: impl Quaternion<Num>) -> Out
 where 
     Num: Axis,
     Out: ScalarConstructor<Num>,
 {
-    Out::new_scalar( ( dot::<Num, Num>(&from, &to) / (abs_squared::<Num, Num>(from) * abs_squared(to)).sqrt() ).acos() )
+    let from: Q<Num> = unscale(&from, abs::<Num, Num>(&from));
+    let to: Q<Num> = unscale(&to, abs::<Num, Num>(&to));
+    let two: Num = Num::from_f64(2.0);
+    Out::new_scalar( two * abs::<Num, Num>(sub::<Num, Q<Num>>(&from, &to)).atan2(abs(add::<Num, Q<Num>>(from, to))) )
 }
 
 /// Calculates the cosine of the angle between two quaternions.
@@ -785,7 +1465,7 @@ where
 /// This does NOT use the [`angle`] function, and the two give diferent results.
 #[inline]
 #[cfg(feature = "rotation")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn angle_between_cos<Num, Out>(from: impl Quaternion<Num>, to: impl Quaternion<Num>) -> Out
 where 
     Num: Axis,
@@ -795,7 +1475,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Normalizes a quaternion.
 /// 
 /// The normal of a quaternion always has the same "direction"
@@ -804,7 +1484,7 @@ where
 /// If the quaternion is the origin it returns the origin.
 /// 
 /// If you know you have a non-zero quaternion then you
-/// can skip the if check by using `unscale(q, abs::<Num, Num>(q))` or `scale(q, Num::ONE / abs(q))`
+/// can skip the if check by using [`normalize_unchecked`].
 /// 
 /// # Example
 /// ```
@@ -821,6 +1501,48 @@ where
     Out: QuaternionConstructor<Num>,
 {
     if eq(&quaternion, ()) { return origin() }
+    normalize_unchecked(quaternion)
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Normalizes a quaternion that isn't the origin.
+/// 
+/// Same as [`normalize`] but without checking for the origin,
+/// the origin gives back a [`NaN`](nan) quaternion (nothing worse,
+/// so this isn't `unsafe`).
+/// 
+/// If any axis is subnormal the quaternion is divided by it's largest axis first,
+/// so `1 / abs` can't overflow.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{normalize_unchecked, normalize, is_nan};
+/// 
+/// let quat: [f32; 4] = [1.0, -2.0, 0.5, 3.0];
+/// 
+/// assert_eq!( normalize_unchecked::<f32, [f32; 4]>(quat), normalize::<f32, [f32; 4]>(quat) );
+/// assert!( is_nan::<f32>(normalize_unchecked::<f32, [f32; 4]>([0.0_f32; 4])) );
+/// ```
+pub fn normalize_unchecked<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    if count_subnormals(&quaternion) > 0 {
+        let largest: Num = quaternion.r().abs()
+            .max(quaternion.i().abs())
+            .max(quaternion.j().abs())
+            .max(quaternion.k().abs());
+        let scaled: [Num; 4] = [
+            quaternion.r() / largest,
+            quaternion.i() / largest,
+            quaternion.j() / largest,
+            quaternion.k() / largest,
+        ];
+        let length: Num = Num::ONE / abs(&scaled);
+        return Out::new_quat(scaled[0] * length, scaled[1] * length, scaled[2] * length, scaled[3] * length);
+    }
     let length: Num = Num::ONE / abs(&quaternion);
     Out::new_quat(
         quaternion.r() * length,
@@ -831,7 +1553,53 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Normalizes a quaternion using [`Axis::inv_sqrt_fast`] instead of a square root and a division.
+/// 
+/// For [f32] (and [`Std<f32>`](crate::structs::Std)) the result has a relative error
+/// of at most `1e-6` and isn't bit-identical to [`normalize`],
+/// for types that don't override [`inv_sqrt_fast`](Axis::inv_sqrt_fast) it's just [`normalize`].
+/// 
+/// The bit trick only pays off on targets without a fast hardware square root and division
+/// (like many embedded ones), on current desktop cpus [`normalize`] is usually just as fast or faster,
+/// so measure before switching.
+/// 
+/// Quaternions with a squared absolute value above `2^120` or below `2^-120`
+/// are first divided by their largest component so that the squared absolute value
+/// doesn't overflow or lose its precision.
+/// 
+/// If the quaternion is the origin it returns the origin.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{normalize_fast, normalize, dist_euclid};
+/// 
+/// let quat: [f32; 4] = [1.0, -2.0, 3.0, 0.5];
+/// let fast: [f32; 4] = normalize_fast::<f32, [f32; 4]>(quat);
+/// 
+/// assert!( dist_euclid::<f32, f32>(fast, normalize::<f32, [f32; 4]>(quat)) < 0.000001 );
+/// ```
+pub fn normalize_fast<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let squared: Num = abs_squared(&quaternion);
+    // 2^-120 and 2^120
+    if squared < Num::from_f64(7.52316384526264e-37) || squared > Num::from_f64(1.329227995784916e36) {
+        if eq(&quaternion, ()) { return origin() }
+        let largest: Num = quaternion.r().abs()
+            .max(quaternion.i().abs())
+            .max(quaternion.j().abs())
+            .max(quaternion.k().abs());
+        let quaternion: Q<Num> = unscale(quaternion, largest);
+        return scale(quaternion, abs_squared::<Num, Num>(quaternion).inv_sqrt_fast());
+    }
+    scale(quaternion, squared.inv_sqrt_fast())
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Gets the absolute value of a quaternion. (Also knows as it's "length")
 /// 
 /// # Example
@@ -842,11 +1610,24 @@ where
 /// 
 /// assert_eq!( abs::<f32, f32>(&quat), 10.0 );
 /// ```
+/// 
+/// If any axis is subnormal (so it's square would underflow to zero)
+/// the quaternion is rescaled first, see [`count_subnormals`].
+/// ```
+/// use quaternion_traits::quat::abs;
+/// 
+/// let tiny: [f32; 4] = [3e-40, 0.0, 4e-40, 0.0];
+/// 
+/// assert!( (abs::<f32, f32>(tiny) - 5e-40).abs() <= 1e-44 );
+/// ```
 pub fn abs<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
 where 
     Num: Axis,
     Out: ScalarConstructor<Num>,
 {
+    if count_subnormals(&quaternion) > 0 {
+        return Out::new_scalar(abs_rescaled(quaternion));
+    }
     Out::new_scalar( Num::sqrt(
         quaternion.r() * quaternion.r()
         + quaternion.i() * quaternion.i()
@@ -855,8 +1636,26 @@ where
     ) )
 }
 
+/// The absolute value of a quaternion calculated after dividing it by it's largest axis,
+/// so the squares can't underflow.
+#[inline]
+fn abs_rescaled<Num: Axis>(quaternion: impl Quaternion<Num>) -> Num {
+    let largest: Num = quaternion.r().abs()
+        .max(quaternion.i().abs())
+        .max(quaternion.j().abs())
+        .max(quaternion.k().abs());
+    if largest == Num::ZERO || largest.classify() == crate::core::num::FpCategory::Infinite {
+        return largest;
+    }
+    // divided one by one, `1 / largest` can overflow
+    abs_squared::<Num, Num>((
+        quaternion.r() / largest,
+        [quaternion.i() / largest, quaternion.j() / largest, quaternion.k() / largest],
+    )).sqrt() * largest
+}
+
 // TODO test this
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Gets the absolute value of a quaternion close to the origin.
 /// 
 /// Calculates the squared absolute value of the quaternion multiplied by [`Num::ERROR`](Axis::ERROR) to the -2 power.
@@ -913,6 +1712,10 @@ where
     Num: Axis,
     Out: ScalarConstructor<Num>,
 {
+    // the factor isn't enough for subnormal axis
+    if count_subnormals(&quaternion) > 0 {
+        return Out::new_scalar(abs_rescaled(quaternion));
+    }
     let factor = Num::ONE / Num::ERROR / Num::ERROR;
     Out::new_scalar( Num::sqrt(
           quaternion.r() * factor * quaternion.r()
@@ -923,7 +1726,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Gets the squared absolute value of a quaternion. (Also knows as it's squared "length")
 /// 
 /// # Example
@@ -948,7 +1751,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Calculates the angle of a quaternion's polar form.
 /// 
 /// Note: This isn't named arg because it does not represent the
@@ -962,7 +1765,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Calculates the cosine of the angle of a quaternion's polar form.
 /// 
 /// Note: This isn't named arg_cos because it does not use the
@@ -975,7 +1778,7 @@ where
     Out::new_scalar(quaternion.r() / abs::<Num, Num>(quaternion))
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Gets the inverse quaternion of a quaternion.
 /// 
 /// # Example
@@ -1009,9 +1812,11 @@ where
 }
 
 #[cfg(any(feature = "math_fns", feature = "trigonometry"))]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Gets the natural logarithm of a quaternion.
 /// 
+/// The logarithm of a negative real number is `ln(|r|) + πi`, same as for complex numbers.
+/// 
 /// # Example
 /// ```
 /// use quaternion_traits::quat::{ln, exp, is_near};
@@ -1028,8 +1833,18 @@ where
     Out: QuaternionConstructor<Num>,
 {
     let absolute: Num = abs(&quaternion);
-    let factor = (quaternion.r() / absolute).acos()
-                    / (quaternion.i()*quaternion.i() + quaternion.j()*quaternion.j() + quaternion.k()*quaternion.k()).sqrt();
+    let len: Num = (quaternion.i()*quaternion.i() + quaternion.j()*quaternion.j() + quaternion.k()*quaternion.k()).sqrt();
+    if len == Num::ZERO {
+        // a real number, the negative ones get the angle on the `i` axis (like complex numbers)
+        return if quaternion.r() < Num::ZERO {
+            new_quat(absolute.ln(), Num::TAU / Num::from_f64(2.0), Num::ZERO, Num::ZERO)
+        } else {
+            new_quat(absolute.ln(), Num::ZERO, Num::ZERO, Num::ZERO)
+        }
+    }
+    // `atan2` keeps the angle when the vector part is tiny next to the real part
+    // (`acos(r / |q|)` rounds it to zero)
+    let factor = len.atan2(quaternion.r()) / len;
     new_quat(
         absolute.ln(),
         quaternion.i() * factor, 
@@ -1039,7 +1854,7 @@ where
 }
 
 #[cfg(any(feature = "math_fns", feature = "trigonometry"))]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Raises the number e to a quaternion power.
 /// 
 /// e ≈ 2.71828...
@@ -1067,7 +1882,8 @@ where
     let len = (quaternion.i()*quaternion.i() + quaternion.j()*quaternion.j() + quaternion.k()*quaternion.k()).sqrt();
     let (sin, cos) = len.sin_cos();
     let r_exp = quaternion.r().exp();
-    let factor = sin * r_exp / len;
+    // `sin(len) / len` goes to one as `len` goes to zero
+    let factor = if len == Num::ZERO { r_exp } else { sin * r_exp / len };
     new_quat(
         cos * r_exp,
         quaternion.i() * factor,
@@ -1079,7 +1895,7 @@ where
 #[inline]
 #[cfg(feature = "unstable")]
 #[cfg(feature = "math_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Gets the logarithm of a quaternion with a quaternion base.
 /// 
 /// # Example
@@ -1101,7 +1917,7 @@ where
 }
 
 // #[cfg(feature = "math_fns")]
-// #[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+// #[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 // /// Calculates the log of a quaternion with a scalar base.
 // /// 
 // /// # Example
@@ -1123,7 +1939,7 @@ where
 // }
 
 #[cfg(feature = "math_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Calculates the square root of a quaternion.
 /// 
 /// This uses a diferent algorthm from [`pow_f`].
@@ -1157,16 +1973,257 @@ where
     }
     let r: Num = quaternion.r();
     let unit = normalize::<Num, Q<Num>>(vector_part::<Num, Q<Num>>(&quaternion)).1;
+    let vector_len: Num = abs::<Num, Num>(vector_part::<Num, Q<Num>>(&quaternion));
+    let abs: Num = abs::<Num, Num>(&quaternion);
+    // `abs - r` (or `abs + r`) cancels when the real part dominates,
+    // so only the larger part takes the root and the other is derived from it
+    let (real_part, unreal_part): (Num, Num) = if r >= Num::ZERO {
+        let real_part: Num = Num::sqrt( (abs + r) * crate::consts::half::<Num>() );
+        (real_part, vector_len / (real_part + real_part))
+    } else {
+        let unreal_part: Num = Num::sqrt( (abs - r) * crate::consts::half::<Num>() );
+        (vector_len / (unreal_part + unreal_part), unreal_part)
+    };
+    Out::new_quat (
+        real_part,
+        unit[0] * unreal_part,
+        unit[1] * unreal_part,
+        unit[2] * unreal_part,
+    )
+}
+
+#[cfg(feature = "math_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Calculates the square root of a quaternion without underflowing for tiny inputs.
+/// 
+/// [`sqrt`] squares the components of the quaternion to get it's [absolute value](abs),
+/// so for very small quaternions that squared value underflows and the result is wrong.
+/// This first scales the quaternion by `4^-e`, where `e` is the base four exponent of its largest component
+/// (so that component ends up inbetween one and four), takes the square root the same way [`sqrt`] does
+/// (with the vector part scaled again before being normalized) and then
+/// scales the result back by `2^e`.
+/// 
+/// The scaling factors are powers of two, so for binary floating point numbers
+/// the scaling itself is exact.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{sqrt, sqrt_scaled, square, is_near};
+/// 
+/// let smol: [f32; 4] = [1e-21, 3e-21, 9e-21, 3e-21];
+/// 
+/// let sqrt: [f32; 4] = sqrt::<f32, [f32; 4]>(smol);
+/// assert!( !is_near::<f32>(
+///     square::<f32, [f32; 4]>(sqrt).map(|num| num * 1e21),
+///     smol.map(|num| num * 1e21),
+/// ) );
+/// 
+/// let sqrt: [f32; 4] = sqrt_scaled::<f32, [f32; 4]>(smol);
+/// assert!( is_near::<f32>(
+///     square::<f32, [f32; 4]>(sqrt).map(|num| num * 1e21),
+///     smol.map(|num| num * 1e21),
+/// ) );
+/// ```
+pub fn sqrt_scaled<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let (factor, root_factor) = power_scale::<Num>(
+        quaternion.r().abs()
+            .max(quaternion.i().abs())
+            .max(quaternion.j().abs())
+            .max(quaternion.k().abs())
+    );
+    let quaternion: Q<Num> = scale(quaternion, factor);
+    if is_scalar(quaternion) {
+        return scale(sqrt::<Num, Q<Num>>(quaternion), root_factor)
+    }
+
+    // The vector part can still be much smaller than the whole quaternion,
+    // so it gets it's own scaling before being normalized.
+    let (vector_factor, _) = power_scale::<Num>(
+        quaternion.i().abs()
+            .max(quaternion.j().abs())
+            .max(quaternion.k().abs())
+    );
+    let r: Num = quaternion.r();
+    let vector: Q<Num> = scale::<Num, Q<Num>>(vector_part::<Num, Q<Num>>(&quaternion), vector_factor);
+    let unit = normalize::<Num, Q<Num>>(vector).1;
+    let vector_len: Num = abs::<Num, Num>(vector) / vector_factor;
     let abs: Num = abs::<Num, Num>(&quaternion);
-    let unreal_part: Num = Num::sqrt( (abs - r) * Num::from_f64(0.5) );
+    // like in `sqrt` only the larger part takes the root
+    let (real_part, unreal_part): (Num, Num) = if r >= Num::ZERO {
+        let real_part: Num = Num::sqrt( (abs + r) * crate::consts::half::<Num>() );
+        (real_part, vector_len / (real_part + real_part))
+    } else {
+        let unreal_part: Num = Num::sqrt( (abs - r) * crate::consts::half::<Num>() );
+        (vector_len / (unreal_part + unreal_part), unreal_part)
+    };
+    let unreal_part: Num = unreal_part * root_factor;
     Out::new_quat (
-        Num::sqrt( (abs + r) * Num::from_f64(0.5) ),
+        real_part * root_factor,
         unit[0] * unreal_part,
         unit[1] * unreal_part,
         unit[2] * unreal_part,
     )
 }
 
+/// Finds the factor that brings `largest` close to one and the square root of the inverse of that factor.
+/// 
+/// For the base four exponent `e` of `largest` (so `4^e <= largest < 4^(e + 1)`)
+/// the factor is `4^-e` and its root is `2^e`, both built by squaring two,
+/// so for binary floating point numbers scaling by them is exact.
+/// If `4^-e` isn't finite (for the smallest subnormals) the largest finite power of four is used.
+#[cfg(feature = "math_fns")]
+fn power_scale<Num: Axis>(largest: Num) -> (Num, Num) {
+    use crate::core::num::FpCategory;
+    let is_finite = |num: Num| !crate::core::matches!(num.classify(), FpCategory::Infinite | FpCategory::Nan);
+    let mut factor: Num = Num::ONE;
+    let mut root_factor: Num = Num::ONE;
+    if largest == Num::ZERO || !is_finite(largest) { return (factor, root_factor) }
+
+    // `2^(2^n)` for every `n` where `4^(2^n)` is still finite
+    let mut roots: [Num; 16] = [Num::ONE; 16];
+    let mut len: usize = 0;
+    let mut root: Num = Num::from_f64(2.0);
+    while len < roots.len() && is_finite(root * root) {
+        roots[len] = root;
+        len += 1;
+        root = root * root;
+    }
+
+    // the exponent is found bit by bit, largest bit first
+    for n in crate::core::iter::Iterator::rev(0..len) {
+        let root: Num = roots[n];
+        let square: Num = root * root;
+        while largest * factor >= square {
+            factor = factor / square;
+            root_factor = root_factor * root;
+        }
+        while largest * factor * square < Num::ONE && is_finite(factor * square) {
+            factor = factor * square;
+            root_factor = root_factor / root;
+        }
+    }
+    (factor, root_factor)
+}
+
+#[cfg(feature = "math_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Refines a guess of the square root of a quaternion.
+/// 
+/// Does two steps of Heron's (Newton's) method, `x = (x + q * inv(x)) / 2`,
+/// starting from `initial_guess`.
+/// Every step roughly doubles the number of correct digits of the guess,
+/// so starting from [`sqrt_scaled`] (or [`sqrt`]) this gets the last bits of precision.
+/// 
+/// The guess should be close to the wanted root,
+/// otherwise this can converge to a diffrent root or not at all.
+/// 
+/// If the guess is the origin the result is NaN.
+/// 
+/// See also [`square_add`] and [`sym_mul`] for the other building blocks of Newton style iterations.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{sqrt_newton, sqrt_scaled, mul, is_near};
+/// 
+/// let quat: [f32; 4] = [1.2, 3.4, 5.6, 7.8];
+/// let guess: [f32; 4] = sqrt_scaled::<f32, [f32; 4]>(quat);
+/// 
+/// let sqrt: [f32; 4] = sqrt_newton::<f32, [f32; 4]>(quat, guess);
+/// 
+/// assert!( is_near::<f32>(
+///     mul::<f32, [f32; 4]>(sqrt, sqrt),
+///     quat
+/// ) );
+/// ```
+pub fn sqrt_newton<Num, Out>(quaternion: impl Quaternion<Num>, initial_guess: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let half: Num = crate::consts::half::<Num>();
+    let mut guess: Q<Num> = Q::from_quat(initial_guess);
+    for _ in 0..2 {
+        guess = scale(
+            add::<Num, Q<Num>>(&guess, mul::<Num, Q<Num>>(&quaternion, inv::<Num, Q<Num>>(&guess))),
+            half,
+        );
+    }
+    Out::from_quat(guess)
+}
+
+#[cfg(feature = "math_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Creates the unit pure quaternion pointing in the direction given by spherical angles.
+/// 
+/// `polar` is the angle from the `k` axis and `azimuth` the angle around it (starting from the `i` axis),
+/// so the result is `sin(polar) cos(azimuth) i + sin(polar) sin(azimuth) j + cos(polar) k`.
+/// 
+/// The unit pure quaternions are exactly the square roots of `-1`
+/// (see [`is_root_of_neg_one`]), so this can reach every one of them.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{unit_vector_from_angles, square, is_near};
+/// 
+/// let root: [f32; 4] = unit_vector_from_angles::<f32, [f32; 4]>(0.7, 2.1);
+/// 
+/// assert!( is_near::<f32>(square::<f32, [f32; 4]>(root), [-1.0, 0.0, 0.0, 0.0]) );
+/// ```
+pub fn unit_vector_from_angles<Num, Out>(polar: impl Scalar<Num>, azimuth: impl Scalar<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let (polar_sin, polar_cos): (Num, Num) = polar.scalar().sin_cos();
+    let (azimuth_sin, azimuth_cos): (Num, Num) = azimuth.scalar().sin_cos();
+    Out::new_quat(
+        Num::ZERO,
+        polar_sin * azimuth_cos,
+        polar_sin * azimuth_sin,
+        polar_cos,
+    )
+}
+
+#[cfg(feature = "math_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gets the point number `index` of `total` points spread evenly over the unit sphere.
+/// 
+/// Uses the Fibonacci (golden angle) spiral: the points are at evenly spaced heights
+/// and every one is turned by the golden angle from the previous one.
+/// It's deterministic and low-discrepancy, so it gives well spread
+/// rotation axes (or [roots of `-1`](is_root_of_neg_one)) for tests and Monte-Carlo code.
+/// The closest two of the `total` points are about `3 / sqrt(total)` apart.
+/// 
+/// The point is calculated in [f64] and then converted.
+/// If `index` isn't less then `total` the result is NaN.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::fibonacci_sphere_point;
+/// 
+/// for index in 0..100 {
+///     let point: [f32; 3] = fibonacci_sphere_point::<f32>(index, 100);
+///     let length: f32 = (point[0] * point[0] + point[1] * point[1] + point[2] * point[2]).sqrt();
+///     assert!( (length - 1.0).abs() < 0.00001 );
+/// }
+/// ```
+pub fn fibonacci_sphere_point<Num: Axis>(index: usize, total: usize) -> [Num; 3] {
+    // `π (3 - √5)`
+    const GOLDEN_ANGLE: f64 = 2.399_963_229_728_653;
+    let height: f64 = 1.0 - (2.0 * index as f64 + 1.0) / total as f64;
+    let radius: f64 = crate::libm::sqrt(1.0 - height * height);
+    let (sin, cos): (f64, f64) = crate::libm::sincos((index as f64 * GOLDEN_ANGLE) % crate::core::f64::consts::TAU);
+    [
+        Num::from_f64(radius * cos),
+        Num::from_f64(radius * sin),
+        Num::from_f64(height),
+    ]
+}
+
 /// Calculares the square of a quaternion.
 /// 
 /// Equivalent to `mul(q, q)`
@@ -1184,6 +2241,7 @@ where
 /// ```
 #[inline]
 #[cfg(any(feature = "qol_fns",feature = "math_fns"))]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn square<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
 where 
     Num: Axis,
@@ -1197,8 +2255,89 @@ where
     )
 }
 
+/// Calculates the square of a quaternion and then adds the addend.
+/// 
+/// Equivalent to `add(square(q), c)`, but in one pass.
+/// 
+/// Intended for iterations like `q = q² + c`
+/// (eg: escape time fractals or Newton's method for the roots of `q² + c`,
+/// like [`sqrt_newton`]).
+/// 
+/// # Example
+/// ```
+/// # use quaternion_traits::quat::{square, add, is_near};
+/// use quaternion_traits::quat::square_add;
+/// 
+/// let quat: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+/// let addend: [f32; 4] = [0.5, -0.5, 0.25, -0.25];
+/// 
+/// assert!( is_near::<f32>(
+///     square_add::<f32, [f32; 4]>(quat, addend),
+///     add::<f32, [f32; 4]>(square::<f32, [f32; 4]>(quat), addend)
+/// ) );
+/// ```
+#[inline]
+#[cfg(any(feature = "qol_fns",feature = "math_fns"))]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn square_add<Num, Out>(quaternion: impl Quaternion<Num>, addend: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let (r, i, j, k) = (quaternion.r(), quaternion.i(), quaternion.j(), quaternion.k());
+    let double_r: Num = r + r;
+    Out::new_quat(
+        r * r - i * i - j * j - k * k + addend.r(),
+        double_r * i + addend.i(),
+        double_r * j + addend.j(),
+        double_r * k + addend.k(),
+    )
+}
+
+/// Calculates the symmetrized product of two quaternions, `(a·q + q·a) / 2`.
+/// 
+/// The cross products of the two multiplications cancel out,
+/// so this is calculated directly from the components
+/// with half the multiplications of a single [`mul`].
+/// Unlike [`mul`] this is commutative.
+/// 
+/// Intended for Newton style iterations, where the derivative of `q²`
+/// in the direction `a` is `a·q + q·a` (eg: [`sqrt_newton`]).
+/// 
+/// # Example
+/// ```
+/// # use quaternion_traits::quat::{mul, add, scale, is_near};
+/// use quaternion_traits::quat::sym_mul;
+/// 
+/// let a: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+/// let q: [f32; 4] = [8.7, 6.5, 4.3, 2.1];
+/// 
+/// let composed: [f32; 4] = scale::<f32, [f32; 4]>(
+///     add::<f32, [f32; 4]>(mul::<f32, [f32; 4]>(a, q), mul::<f32, [f32; 4]>(q, a)),
+///     0.5,
+/// );
+/// 
+/// assert!( is_near::<f32>(sym_mul::<f32, [f32; 4]>(a, q), composed) );
+/// assert_eq!( sym_mul::<f32, [f32; 4]>(a, q), sym_mul::<f32, [f32; 4]>(q, a) );
+/// ```
+#[inline]
+#[cfg(any(feature = "qol_fns",feature = "math_fns"))]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn sym_mul<Num, Out>(left: impl Quaternion<Num>, right: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    Out::new_quat(
+        left.r() * right.r() - left.i() * right.i() - left.j() * right.j() - left.k() * right.k(),
+        left.r() * right.i() + left.i() * right.r(),
+        left.r() * right.j() + left.j() * right.r(),
+        left.r() * right.k() + left.k() * right.r(),
+    )
+}
+
 #[cfg(feature = "math_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Raises a quaternion to an integer power.
 /// 
 /// This is evaluated by repeated multiplication.
@@ -1224,7 +2363,7 @@ where
 }
 
 #[cfg(feature = "math_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Raises a quaternion to a positive integer power.
 /// 
 /// This is evaluated by repeated multiplication.
@@ -1245,11 +2384,21 @@ where
 }
 
 #[cfg(feature = "math_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Raises a quaternion to a scalar power.
 /// 
 /// Doesn't use eather `exp(ln(base) * exp)` or `exp(exp * ln(base))`.
 /// 
+/// # Edge cases
+/// - An exponent of zero gives exactly [`identity`] (except for the origin).
+/// - The origin raised to a positive exponent gives the origin,
+///   to a zero, negative or NaN exponent it gives [`nan`] (same as the unstable `pow_q`).
+/// - Positive real bases give a real result.
+/// - Negative real bases with an integer exponent give a real result.
+/// - Negative real bases with any other exponent have infinitely many roots
+///   (any unit vector squares to `-1`), this gives the principal root along
+///   the `i` axis, the same one as for complex numbers.
+/// 
 /// # Example
 /// ```
 /// # use quaternion_traits::quat::{mul, is_near};
@@ -1267,14 +2416,52 @@ where
 /// 
 /// let quat_2: [f32; 4] = pow_f::<f32, _>(quat, 2.5);
 /// ```
+/// 
+/// Negative real bases.
+/// ```
+/// use quaternion_traits::quat::{pow_f, is_near};
+/// 
+/// assert_eq!( pow_f::<f32, [f32; 4]>([-2.0, 0.0, 0.0, 0.0], 3.0), [-8.0, 0.0, 0.0, 0.0] );
+/// assert_eq!( pow_f::<f32, [f32; 4]>([-2.0, 0.0, 0.0, 0.0], -2.0), [0.25, 0.0, 0.0, 0.0] );
+/// 
+/// // 2 * (cos(π/3) + i sin(π/3)), not -2
+/// assert!( is_near::<f32>(
+///     pow_f::<f32, [f32; 4]>([-8.0, 0.0, 0.0, 0.0], 1.0 / 3.0),
+///     [1.0, 1.7320508, 0.0, 0.0],
+/// ) );
+/// ```
 pub fn pow_f<Num, Out>(base: impl Quaternion<Num>, exp: impl Scalar<Num>) -> Out
 where 
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
+    let exp: Num = exp.scalar();
+    if eq(&base, ()) {
+        if exp > Num::ZERO {
+            return origin();
+        }
+        return nan();
+    }
+    if exp == Num::ZERO {
+        return identity();
+    }
+    if base.i() == Num::ZERO && base.j() == Num::ZERO && base.k() == Num::ZERO {
+        if base.r() > Num::ZERO {
+            return Out::new_quat(base.r().pow(exp), Num::ZERO, Num::ZERO, Num::ZERO);
+        }
+        let abs: Num = -base.r();
+        if exp % Num::ONE == Num::ZERO {
+            let pow: Num = abs.pow(exp);
+            let is_odd: bool = exp % Num::from_u8(2) != Num::ZERO;
+            return Out::new_quat(if is_odd { -pow } else { pow }, Num::ZERO, Num::ZERO, Num::ZERO);
+        }
+        let (sin, cos) = (exp * Num::TAU / Num::from_u8(2)).sin_cos();
+        let abs: Num = abs.pow(exp);
+        return Out::new_quat(cos * abs, sin * abs, Num::ZERO, Num::ZERO);
+    }
     let abs: Num = abs(&base);
     let angle = (base.r() / abs).acos();
-    let (sin, cos) = (exp.scalar() * angle).sin_cos();
+    let (sin, cos) = (exp * angle).sin_cos();
     let factor = sin / (base.i()*base.i() + base.j()*base.j() + base.k()*base.k()).sqrt();
     scale(
         [
@@ -1283,13 +2470,13 @@ where
             base.j() * factor,
             base.k() * factor,
         ],
-        abs.pow(exp.scalar()) // replaces one use of sqrt with one div
+        abs.pow(exp) // replaces one use of sqrt with one div
     )
 }
 
 #[cfg(feature = "unstable")]
 #[cfg(feature = "math_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Raises a quaternion to a quaternion power.
 /// 
 /// Used this paper as a refrence:
@@ -1311,26 +2498,46 @@ where
     crate::quat::exp(&mul::<Num, Q<Num>>(&ln::<Num, Q<Num>>(base), &exp))
 }
 
-#[cfg(feature = "math_fns")]
-#[inline(always)]
-fn γ<Num: Axis>() -> Num {
-    let γ_limit = Num::from_f64(2000.0);
-    let mut at = Num::ZERO;
-    let mut result = -γ_limit * γ_limit.ln();
-    for _ in 1..2000 {
-        at = at + Num::ONE;
-        result = result + Num::ONE / at;
-    }
-    result
-}
-
 #[cfg(feature = "math_fns")]
 const LNGAMMA_REPEATS: u16 = 2000;
 /// Calculates the natural logarithm of the gamma function with a quaternion input.
 /// 
 /// Equivalent to `ln(gamma(q))` (assuming infinite precision + infinite loops).
+/// 
+/// Sums the first 2000 terms of the series, same as [`lngamma_n::<_, _, 2000>`](lngamma_n).
 #[cfg(feature = "math_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn lngamma<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    lngamma_n::<Num, Out, LNGAMMA_REPEATS>(quaternion)
+}
+
+/// Calculates the natural logarithm of the gamma function with a quaternion input,
+/// summing `TERMS` terms of the series.
+/// 
+/// Uses the series `ln(gamma(q)) = -γq - ln(q) + Σ (q/n - ln(1 + q/n))`,
+/// the error of cutting it off after `TERMS` terms is about `|q|² / (2 * TERMS)`,
+/// so fewer terms cap the work (eg: on embedded targets) and more terms give more accuracy.
+/// [`lngamma`] uses 2000 terms.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::lngamma_n;
+/// 
+/// // ln(gamma(3)) = ln(2)
+/// let rough: [f64; 4] = lngamma_n::<f64, [f64; 4], 100>([3.0, 0.0, 0.0, 0.0]);
+/// let fine: [f64; 4] = lngamma_n::<f64, [f64; 4], 10000>([3.0, 0.0, 0.0, 0.0]);
+/// 
+/// let ln_2: f64 = core::f64::consts::LN_2;
+/// assert!( (fine[0] - ln_2).abs() < (rough[0] - ln_2).abs() );
+/// assert!( (fine[0] - ln_2).abs() < 0.001 );
+/// ```
+#[cfg(feature = "math_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn lngamma_n<Num, Out, const TERMS: u16>(quaternion: impl Quaternion<Num>) -> Out
 where 
     Num: Axis,
     Out: QuaternionConstructor<Num>,
@@ -1340,12 +2547,10 @@ where
     // quaternion and a real and teh rest are additions/subtractions which are commutative
     // and since all functions involved are included
     // I assumed that this funtion can be expanded to the quaternion set without change.
-    let mut result: Q<Num> = sub(scale::<Num, Q<Num>>(&quaternion, -γ::<Num>()), ln::<Num, Q<Num>>(&quaternion));
-    let mut at: Num = Num::ZERO;
+    let mut result: Q<Num> = sub(scale::<Num, Q<Num>>(&quaternion, -crate::consts::euler_mascheroni::<Num>()), ln::<Num, Q<Num>>(&quaternion));
     let mut fraction: Q<Num>;
-    for _ in 0..LNGAMMA_REPEATS {
-        at = at + Num::ONE;
-        fraction = unscale(&quaternion, at);
+    for at in 1..=TERMS {
+        fraction = unscale(&quaternion, Num::from_u32(at as u32));
         result = add(
             result,
             sub::<Num, Q<Num>>(
@@ -1368,6 +2573,7 @@ where
 /// if you need the naturla logarigthm of the gamma function
 /// use that function directly.
 #[cfg(feature = "math_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn gamma<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
 where 
     Num: Axis,
@@ -1377,7 +2583,7 @@ where
     exp(lngamma::<Num, Q<Num>>(quaternion))
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Gets the dot product of two quaternions.
 /// 
 /// Fun fact: the dot product of a quaternion with it'self returns the squared absolute value :)
@@ -1405,6 +2611,41 @@ where
     )
 }
 
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gives back eather `current` or `-current`, whichever one
+/// has a non-negative dot product with `previous`.
+/// 
+/// `q` and `-q` represent the same rotation, so this doesn't change the rotation,
+/// it just stops the sign from flipping in a sequence of rotations
+/// (like the ones you get from solvers frame by frame),
+/// which would break things like finite differencing or compression.
+/// 
+/// For doing this over an entire sequence check [`continuous`].
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::enforce_continuity;
+/// 
+/// let previous: [f32; 4] = [1.0, 0.0, 0.0, 0.0];
+/// let same_side: [f32; 4] = [0.6, 0.8, 0.0, 0.0];
+/// let flipped: [f32; 4] = [-0.6, -0.8, 0.0, 0.0];
+/// 
+/// assert_eq!( enforce_continuity::<f32, [f32; 4]>(previous, same_side), [0.6, 0.8, 0.0, 0.0] );
+/// assert_eq!( enforce_continuity::<f32, [f32; 4]>(previous, flipped), [0.6, 0.8, 0.0, 0.0] );
+/// ```
+pub fn enforce_continuity<Num, Out>(previous: impl Quaternion<Num>, current: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    if dot::<Num, Num>(previous, &current) < Num::ZERO {
+        neg(current)
+    } else {
+        Out::from_quat(current)
+    }
+}
+
 /// Gives the hadamard product of two quaternions.
 /// 
 /// It uses the following formula:
@@ -1435,6 +2676,7 @@ where
 /// )
 /// ```
 #[cfg(feature = "math_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn hadamard<Num, Out>(left: impl Quaternion<Num>, right: impl Quaternion<Num>) -> Out
 where 
     Num: Axis,
@@ -3,7 +3,7 @@
 use super::*;
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Constructs a new general quaternion representation.
 /// 
 /// ```
@@ -24,7 +24,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Gets the vector part of a quaternion.
 /// 
 /// # Example
@@ -52,7 +52,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Gets the complex part of a quaternion.
 /// 
 /// # Example
@@ -80,7 +80,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Gets the scalar part of a quaternion.
 /// 
 /// # Example
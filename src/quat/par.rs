@@ -0,0 +1,168 @@
+/*!
+The slice functions of [`quat`](super), split across the [rayon](https://docs.rs/rayon/latest/rayon/) thread pool.
+
+Every function here has the same name, arguments and panics as the sequential one,
+the slices get cut into chunks of [`MIN_CHUNK_LEN`] quaternions
+and each chunk is given to the sequential function on some thread of the pool.
+Slices that aren't longer than one chunk are done on the current thread,
+so there is no overhead from the thread pool for small inputs.
+
+Every output only depends on the inputs at the same index,
+so the results are exactly the same as the sequential ones
+no matter how many threads there are or how the work gets split.
+Reductions where the result depends on the order of the additions
+(like the compensated [`Quats::reduce_sum`](crate::structs::Quats::reduce_sum)) don't have a version here.
+
+| here                    | sequential                                  |
+|-------------------------|---------------------------------------------|
+| [`normalize_slice`]     | [`quat::normalize_slice`](super::normalize_slice) |
+| [`mul_slices`]          | [`quat::mul_slices`](super::mul_slices)     |
+| [`slerp_slices`]        | [`quat::slerp_slices`](super::slerp_slices) |
+| [`rotate_points`]       | [`quat::rotate_points`](super::rotate_points) |
+ */
+
+use crate::Axis;
+#[cfg(feature = "math_fns")]
+use crate::Scalar;
+#[cfg(feature = "rotation")]
+use crate::Quaternion;
+use crate::core::marker::{Send, Sync};
+use crate::rayon::prelude::*;
+
+/// The number of quaternions (or points) each thread gets at a time.
+/// 
+/// Slices up to this length are done on the current thread.
+pub const MIN_CHUNK_LEN: usize = 4096;
+
+#[inline]
+/// Normalizes every quaternion of a slice in place, across the thread pool.
+/// 
+/// Same result as [`quat::normalize_slice`](super::normalize_slice).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{self, par};
+/// 
+/// let original: Vec<[f32; 4]> = (0..10_000).map(|n| [n as f32, 1.0, -2.0, 0.5]).collect();
+/// let mut sequential: Vec<[f32; 4]> = original.clone();
+/// let mut parallel: Vec<[f32; 4]> = original.clone();
+/// 
+/// quat::normalize_slice::<f32>(&mut sequential);
+/// par::normalize_slice::<f32>(&mut parallel);
+/// assert_eq!( parallel, sequential );
+/// ```
+pub fn normalize_slice<Num>(quats: &mut [[Num; 4]])
+where 
+    Num: Axis + Send + Sync,
+{
+    if quats.len() <= MIN_CHUNK_LEN {
+        return super::normalize_slice(quats);
+    }
+    quats.par_chunks_mut(MIN_CHUNK_LEN).for_each(super::normalize_slice);
+}
+
+#[inline]
+/// Multiplies every pair of quaternions of two slices, writing the products into a third slice,
+/// across the thread pool.
+/// 
+/// Same result as [`quat::mul_slices`](super::mul_slices).
+/// 
+/// # Panics
+/// Panics if the three slices have different lengths.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{self, par};
+/// 
+/// let left: Vec<[f32; 4]> = (0..10_000).map(|n| [n as f32, 1.0, -2.0, 0.5]).collect();
+/// let right: Vec<[f32; 4]> = (0..10_000).map(|n| [0.5, n as f32, 3.0, -1.0]).collect();
+/// let mut sequential: Vec<[f32; 4]> = vec![[0.0; 4]; 10_000];
+/// let mut parallel: Vec<[f32; 4]> = vec![[0.0; 4]; 10_000];
+/// 
+/// quat::mul_slices::<f32>(&left, &right, &mut sequential);
+/// par::mul_slices::<f32>(&left, &right, &mut parallel);
+/// assert_eq!( parallel, sequential );
+/// ```
+pub fn mul_slices<Num>(left: &[[Num; 4]], right: &[[Num; 4]], out: &mut [[Num; 4]])
+where 
+    Num: Axis + Send + Sync,
+{
+    super::batch::check_lengths("mul_slices", left.len(), right.len(), out.len());
+    if out.len() <= MIN_CHUNK_LEN {
+        return super::mul_slices(left, right, out);
+    }
+    left.par_chunks(MIN_CHUNK_LEN)
+        .zip(right.par_chunks(MIN_CHUNK_LEN))
+        .zip(out.par_chunks_mut(MIN_CHUNK_LEN))
+        .for_each(|((left, right), out)| super::mul_slices(left, right, out));
+}
+
+#[inline]
+#[cfg(feature = "math_fns")]
+/// Spherically interpolates every pair of unit quaternions of two slices by the same amount,
+/// writing the results into a third slice, across the thread pool.
+/// 
+/// Same result as [`quat::slerp_slices`](super::slerp_slices),
+/// so every quaternion must be a unit quaternion.
+/// 
+/// # Panics
+/// Panics if the three slices have different lengths.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{self, par};
+/// 
+/// let from: Vec<[f32; 4]> = (0..10_000).map(|n| quat::normalize::<f32, [f32; 4]>([n as f32, 1.0, -2.0, 0.5])).collect();
+/// let to: Vec<[f32; 4]> = (0..10_000).map(|n| quat::normalize::<f32, [f32; 4]>([0.5, n as f32, 3.0, -1.0])).collect();
+/// let mut sequential: Vec<[f32; 4]> = vec![[0.0; 4]; 10_000];
+/// let mut parallel: Vec<[f32; 4]> = vec![[0.0; 4]; 10_000];
+/// 
+/// quat::slerp_slices::<f32>(&from, &to, 0.3, &mut sequential);
+/// par::slerp_slices::<f32>(&from, &to, 0.3, &mut parallel);
+/// assert_eq!( parallel, sequential );
+/// ```
+pub fn slerp_slices<Num>(from: &[[Num; 4]], to: &[[Num; 4]], at: impl Scalar<Num>, out: &mut [[Num; 4]])
+where 
+    Num: Axis + Send + Sync,
+{
+    super::batch::check_lengths("slerp_slices", from.len(), to.len(), out.len());
+    let at: Num = at.scalar();
+    if out.len() <= MIN_CHUNK_LEN {
+        return super::slerp_slices(from, to, at, out);
+    }
+    from.par_chunks(MIN_CHUNK_LEN)
+        .zip(to.par_chunks(MIN_CHUNK_LEN))
+        .zip(out.par_chunks_mut(MIN_CHUNK_LEN))
+        .for_each(|((from, to), out)| super::slerp_slices(from, to, at, out));
+}
+
+#[inline]
+#[cfg(feature = "rotation")]
+/// Rotates every point of a slice in place by the same rotation, across the thread pool.
+/// 
+/// Same result as [`quat::rotate_points`](super::rotate_points).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{self, par};
+/// 
+/// let rotation: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+/// let original: Vec<[f32; 3]> = (0..10_000).map(|n| [n as f32, 1.0, -2.0]).collect();
+/// let mut sequential: Vec<[f32; 3]> = original.clone();
+/// let mut parallel: Vec<[f32; 3]> = original.clone();
+/// 
+/// quat::rotate_points::<f32>(rotation, &mut sequential);
+/// par::rotate_points::<f32>(rotation, &mut parallel);
+/// assert_eq!( parallel, sequential );
+/// ```
+pub fn rotate_points<Num>(rotation: impl Quaternion<Num>, points: &mut [[Num; 3]])
+where 
+    Num: Axis + Send + Sync,
+{
+    let rotation: [Num; 4] = super::normalize(rotation);
+    if points.len() <= MIN_CHUNK_LEN {
+        return super::batch::rotate_points_unchecked(rotation, points);
+    }
+    points.par_chunks_mut(MIN_CHUNK_LEN)
+        .for_each(|points| super::batch::rotate_points_unchecked(rotation, points));
+}
@@ -2,7 +2,7 @@
 use super::*;
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Checks if two types represent the same quaternion.
 /// 
 /// # Example
@@ -22,7 +22,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Checks to see if this quaternion is only a scalar value.
 /// 
 /// # Example
@@ -45,7 +45,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Checks to see if this quaternion is a complex value.
 /// 
 /// Note: this checks if [`q.j()`](Quaternion::j) and
@@ -70,7 +70,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Checks to see if this quaternion is a vector value. (or aure quaternion)
 /// 
 /// Note: this checks if [`q.r()`](Quaternion::r) returns [`Num::ZERO`](Axis::ZERO).
@@ -120,6 +120,7 @@ where
 ///     )
 /// );
 /// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn is_nan<Num>(quaternion: impl Quaternion<Num>) -> bool
 where 
     Num: Axis,
@@ -127,6 +128,62 @@ where
     quaternion.r().is_nan() || quaternion.i().is_nan() || quaternion.j().is_nan() || quaternion.k().is_nan()
 }
 
+/// Gets what kind of number (zero, subnormal, normal, infinite or NaN)
+/// every axis of a quaternion is, in the `r`, `i`, `j`, `k` order.
+/// 
+/// Uses [`Axis::classify`].
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::classify;
+/// use core::num::FpCategory;
+/// 
+/// assert_eq!(
+///     classify::<f32>([1.0, 0.0, 1e-40, f32::INFINITY]),
+///     [FpCategory::Normal, FpCategory::Zero, FpCategory::Subnormal, FpCategory::Infinite],
+/// );
+/// ```
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn classify<Num>(quaternion: impl Quaternion<Num>) -> [crate::core::num::FpCategory; 4]
+where 
+    Num: Axis,
+{
+    [
+        quaternion.r().classify(),
+        quaternion.i().classify(),
+        quaternion.j().classify(),
+        quaternion.k().classify(),
+    ]
+}
+
+/// Counts how many axis of a quaternion are subnormal.
+/// 
+/// Squaring a subnormal number underflows to zero, so functions like [`abs`]
+/// use this to know when they have to rescale the quaternion first.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::count_subnormals;
+/// 
+/// assert_eq!( count_subnormals::<f32>([1.0, 1e-40, -1e-39, 0.0]), 2 );
+/// assert_eq!( count_subnormals::<f32>([1.0, 1e-30, 0.0, f32::NAN]), 0 );
+/// ```
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn count_subnormals<Num>(quaternion: impl Quaternion<Num>) -> u8
+where 
+    Num: Axis,
+{
+    let mut count: u8 = 0;
+    for category in classify(quaternion) {
+        if category == crate::core::num::FpCategory::Subnormal {
+            count += 1;
+        }
+    }
+    count
+}
+
 /// Checks if all axis of a quaternion is a [`Num::NAN`](Axis::NAN) value.
 /// 
 /// # Example
@@ -155,6 +212,7 @@ where
 ///     )
 /// );
 /// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn is_all_nan<Num>(quaternion: impl Quaternion<Num>) -> bool
 where 
     Num: Axis,
@@ -164,7 +222,7 @@ where
 
 #[inline]
 #[cfg(any(feature = "qol_fns", feature = "rotation"))]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Checks to see if this quaternion is on a axis plane.
 /// 
 /// Checks if at most 2 of the quaternion feilds are non-zero.
@@ -190,7 +248,7 @@ where
 }
 
 // #[inline]
-// #[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+// #[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 // /// Checks if two quaternions are on the same paralel to an axis plane plane.
 // /// 
 // /// Equivalent to checking if the two vectors subtracted 
@@ -212,11 +270,11 @@ where
 // }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
-/// Checks if the distance between two quaternions is less then [`Num::ERROR`](Axis::ERROR).
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Checks if the distance between two quaternions is less then [`Num::EPS_ARITH`](Axis::EPS_ARITH).
 /// 
 /// Note: This function does not use [`sqrt`](Axis::sqrt),
-/// instead it uses the square of [`Num::ERROR`](Axis::ERROR).
+/// instead it uses the square of [`Num::EPS_ARITH`](Axis::EPS_ARITH).
 /// 
 /// # Example
 /// ```
@@ -231,14 +289,18 @@ pub fn is_near<Num>(left: impl Quaternion<Num>, right: impl Quaternion<Num>) ->
 where
     Num: Axis,
 {
-    abs_squared::<Num, Num>(&sub::<Num, Q<Num>>(left, right)) < Num::ERROR * Num::ERROR
+    dist_euclid_squared::<Num, Num>(left, right) < Num::EPS_ARITH * Num::EPS_ARITH
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Checks if the distance between two quaternions is less then `error`.
 /// 
-/// If [`error.scalar()`](Scalar::scalar) evaluates to a non_
+/// If [`error.scalar()`](Scalar::scalar) evaluates to a non-positive number or NaN
+/// then no distance can be less then it, so this always returns `false`.
+/// 
+/// Note: Like [`is_near`] this compares the [squared distance](dist_euclid_squared)
+/// with the square of `error` instead of using [`sqrt`](Axis::sqrt).
 /// 
 /// # Example
 /// ```
@@ -248,21 +310,121 @@ where
 /// let b: [f32; 4] = [0.5, 0.0, 0.0, 0.0];
 /// 
 /// assert!( is_near_by::<f32>(a, b, 1) );
+/// assert!( !is_near_by::<f32>(a, b, -1) );
+/// assert!( !is_near_by::<f32>(a, a, f32::NAN) );
 /// ```
 pub fn is_near_by<Num>(left: impl Quaternion<Num>, right: impl Quaternion<Num>, error: impl Scalar<Num>) -> bool
 where
     Num: Axis,
 {
-    abs_squared::<Num, Num>(&sub::<Num, Q<Num>>(left, right)) < error.scalar() * error.scalar()
+    let error: Num = error.scalar();
+    // NaN fails the first comparison, a negative error fails it before it gets squared
+    error > Num::ZERO && dist_euclid_squared::<Num, Num>(left, right) < error * error
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg(feature = "math_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Checks if a quaternion is a Lipschitz quaternion (all of it's components are integers)
+/// by checking if it's distance from [the nearest one](round_lipschitz) is less then `error`.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::is_lipschitz;
+/// 
+/// assert!( is_lipschitz::<f32>([1.0, -2.0, 0.0, 7.0], 0.0001) );
+/// assert!( is_lipschitz::<f32>([1.00001, -2.0, 0.0, 7.0], 0.0001) );
+/// assert!( !is_lipschitz::<f32>([0.5, 0.5, 0.5, 0.5], 0.0001) );
+/// ```
+pub fn is_lipschitz<Num>(quaternion: impl Quaternion<Num>, error: impl Scalar<Num>) -> bool
+where
+    Num: Axis,
+{
+    is_near_by(&quaternion, round_lipschitz::<Num, Q<Num>>(&quaternion), error)
+}
+
+#[inline]
+#[cfg(feature = "math_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Checks if a quaternion is a Hurwitz quaternion (it's components are eather all integers or all half-integers)
+/// by checking if it's distance from [the nearest one](round_hurwitz) is less then `error`.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::is_hurwitz;
+/// 
+/// assert!( is_hurwitz::<f32>([1.0, -2.0, 0.0, 7.0], 0.0001) );
+/// assert!( is_hurwitz::<f32>([0.5, -1.5, 2.5, -0.5], 0.0001) );
+/// assert!( !is_hurwitz::<f32>([0.5, 1.0, 0.5, 0.5], 0.0001) );
+/// ```
+pub fn is_hurwitz<Num>(quaternion: impl Quaternion<Num>, error: impl Scalar<Num>) -> bool
+where
+    Num: Axis,
+{
+    is_near_by(&quaternion, round_hurwitz::<Num, Q<Num>>(&quaternion), error)
+}
+
+#[inline]
+#[cfg(feature = "math_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Checks if a quaternion is a unit of the Lipschitz quaternions
+/// (`±1`, `±i`, `±j` or `±k`), aka it has integer components
+/// and a [`four_square_norm`] of one.
+/// 
+/// Both are checked with a margin of [`Num::ERROR`](Axis::ERROR).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::is_unit_lipschitz;
+/// 
+/// assert!( is_unit_lipschitz::<f32>([0.0, 0.0, -1.0, 0.0]) );
+/// assert!( !is_unit_lipschitz::<f32>([0.5, 0.5, 0.5, 0.5]) );
+/// assert!( !is_unit_lipschitz::<f32>([1.0, 1.0, 0.0, 0.0]) );
+/// assert!( !is_unit_lipschitz::<f32>([0.0, 0.0, 0.0, 0.0]) );
+/// ```
+pub fn is_unit_lipschitz<Num>(quaternion: impl Quaternion<Num>) -> bool
+where
+    Num: Axis,
+{
+    is_lipschitz(&quaternion, Num::ERROR)
+    && (four_square_norm::<Num, Num>(&quaternion) - Num::ONE).abs() < Num::ERROR
+}
+
+#[inline]
+#[cfg(feature = "math_fns")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Checks if the square of a quaternion is `-1`,
+/// aka it's a pure quaternion (no real part) of length one.
+/// 
+/// The real part is checked with a margin of [`Num::EPS_ARITH`](Axis::EPS_ARITH)
+/// and the squared length like [`is_normalized`] (with [`Num::EPS_TRIG`](Axis::EPS_TRIG)).
+/// 
+/// See [`unit_vector_from_angles`] and [`fibonacci_sphere_point`] for making them.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::is_root_of_neg_one;
+/// 
+/// assert!( is_root_of_neg_one::<f32>([0.0, 1.0, 0.0, 0.0]) );
+/// assert!( is_root_of_neg_one::<f32>([0.0, 0.6, 0.0, -0.8]) );
+/// assert!( !is_root_of_neg_one::<f32>([0.0, 1.0, 1.0, 0.0]) );
+/// assert!( !is_root_of_neg_one::<f32>([1.0, 0.0, 0.0, 0.0]) );
+/// ```
+pub fn is_root_of_neg_one<Num>(quaternion: impl Quaternion<Num>) -> bool
+where
+    Num: Axis,
+{
+    quaternion.r().abs() < Num::EPS_ARITH
+    && (abs_squared::<Num, Num>(&quaternion) - Num::ONE).abs() < Num::EPS_TRIG
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Checks if the ratio inbetween the abs of two quaternions is small enough
 /// 
 /// Checks if the ratio inbetween the absolute values of two quaternions
-/// is strictly inbetween `Num::ONE - Num::ERROR` and `Num::ONE + Num::ERROR`
-/// AND that the distance inbetween the angle
+/// is strictly inbetween `Num::ONE - Num::EPS_ARITH` and `Num::ONE + Num::EPS_ARITH`
+/// AND that the distance inbetween the angles is less then [`Num::EPS_TRIG`](Axis::EPS_TRIG)
 /// 
 /// If eather quaternion is the origin, then [`is_near`] is used because
 /// otherwise the algorithm used always give out false, even if they are equal.
@@ -305,13 +467,13 @@ where
         return is_near(left, ());
     }
     ( ( abs_squared::<Num, Num>(&left) / abs_squared::<Num, Num>(&right) ).sqrt() - Num::ONE ).abs()
-    < Num::ERROR
+    < Num::EPS_ARITH
     &&
-    ((angle::<Num, Num>(left) - angle::<Num, Num>(right)).abs() < Num::ERROR)
+    ((angle::<Num, Num>(left) - angle::<Num, Num>(right)).abs() < Num::EPS_TRIG)
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Checks if the ratio inbetween the abs of two quaternions is small enough
 /// 
 /// Checks if the ratio inbetween the absolute values of two quaternions
@@ -320,6 +482,9 @@ where
 /// 
 /// [`is_close_by`] and [`is_near_by`] will not always give the same results.
 /// 
+/// Just like [`is_near_by`], if [`error.scalar()`](Scalar::scalar) evaluates
+/// to a non-positive number or NaN this always returns `false`.
+/// 
 /// # Example
 /// ```
 /// use quaternion_traits::quat::is_close_by;
@@ -333,6 +498,8 @@ pub fn is_close_by<Num>(left: impl Quaternion<Num>, right: impl Quaternion<Num>,
 where
     Num: Axis
 {
+    let error: Num = error.scalar();
+    if error.is_nan() || error <= Num::ZERO { return false }
     if eq(&left, ()) {
         return is_near_by(right, (), error);
     }
@@ -340,28 +507,29 @@ where
         return is_near_by(left, (), error);
     }
     ( ( abs_squared::<Num, Num>(&left) / abs_squared::<Num, Num>(&right) ).sqrt() - Num::ONE ).abs()
-    < error.scalar()
+    < error
     &&
-    (angle::<Num, Num>(left) - angle::<Num, Num>(right)).abs() < error.scalar()
+    (angle::<Num, Num>(left) - angle::<Num, Num>(right)).abs() < error
 }
 
 #[inline]
 #[cfg(any(feature = "qol_fns", feature = "rotation"))]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Checks if a quaternion is a unit quaternion.
 /// 
-/// It checks if the quaternion's absolute value falls in the range
-/// (Num::ONE - Num::ERROR, Num::ONE + Num::ERROR)
+/// It checks if the quaternion's squared absolute value falls in the range
+/// (Num::ONE - Num::EPS_TRIG, Num::ONE + Num::EPS_TRIG),
+/// the same check every unit quaternion constructor does.
 pub fn is_normalized<Num>(quaternion: impl Quaternion<Num>) -> bool
 where 
     Num: Axis,
 {
-    (abs_squared::<Num, Num>(quaternion) - Num::ONE).abs() < Num::ERROR * Num::ERROR
+    (abs_squared::<Num, Num>(quaternion) - Num::ONE).abs() < Num::EPS_TRIG
 }
 
 #[inline]
 #[cfg(feature = "qol_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Checks if `eq(mul(q, p), mul(p, q))` returns `true`.
 /// 
 /// Faster then performing the multiplication twice.
@@ -436,7 +604,7 @@ where
 
 #[inline]
 #[cfg(feature = "qol_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Checks if `ia_near(mul(q, p), mul(p, q))` returns `true`.
 /// 
 /// Faster then performing the multiplication twice.
@@ -490,5 +658,226 @@ where
     let y = left.i() * right.k() - left.k() * right.i();
     let z = left.j() * right.i() - left.i() * right.j();
     let abs_squared = x * x + y * y + z * z;
-    abs_squared + abs_squared + abs_squared + abs_squared < Num::ERROR * Num::ERROR
+    abs_squared + abs_squared + abs_squared + abs_squared < Num::EPS_ARITH * Num::EPS_ARITH
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gets the absolute value of the dot product of two quaternions.
+/// 
+/// `q` and `-q` represent the same rotation, so this (and not the signed [`dot`])
+/// is what tells how similar two rotations are:
+/// for unit quaternions it's `1` for the same rotation and `0` for rotations
+/// half a turn apart, no matter the signs the quaternions were given with.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{abs_dot, dot};
+/// 
+/// let a: [f32; 4] = [0.6, 0.8, 0.0, 0.0];
+/// let b: [f32; 4] = [-0.6, -0.8, 0.0, 0.0];
+/// 
+/// assert_eq!( dot::<f32, f32>(a, b), -1.0 );
+/// assert_eq!( abs_dot::<f32, f32>(a, b), 1.0 );
+/// ```
+pub fn abs_dot<Num, Out>(left: impl Quaternion<Num>, right: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: ScalarConstructor<Num>,
+{
+    Out::new_scalar(dot::<Num, Num>(left, right).abs())
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gives back eather `quaternion` or `-quaternion`, whichever one
+/// has a non-negative dot product with `reference`.
+/// 
+/// Both represent the same rotation, so this only picks the one on the same
+/// hemisphere as `reference`, after which blending the two (like with [`lerp`]
+/// or [`slerp_extrapolate`](crate::quat::slerp_extrapolate)) takes the shortest path.
+/// 
+/// Same as [`enforce_continuity`] with the arguments swapped.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::align_to;
+/// 
+/// let reference: [f32; 4] = [1.0, 0.0, 0.0, 0.0];
+/// 
+/// assert_eq!( align_to::<f32, [f32; 4]>([-0.6, 0.0, -0.8, 0.0], reference), [0.6, 0.0, 0.8, 0.0] );
+/// assert_eq!( align_to::<f32, [f32; 4]>([0.6, 0.0, -0.8, 0.0], reference), [0.6, 0.0, -0.8, 0.0] );
+/// ```
+pub fn align_to<Num, Out>(quaternion: impl Quaternion<Num>, reference: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    enforce_continuity(reference, quaternion)
+}
+
+/// Scales a vector so its largest component is `±1`, so squaring
+/// the components can't overflow or underflow (the zero vector stays zero).
+#[inline]
+fn rescaled<Num: Axis>(vector: impl Vector<Num>) -> [Num; 3] {
+    let largest: Num = vector.x().abs().max(vector.y().abs()).max(vector.z().abs());
+    if largest == Num::ZERO {
+        return [Num::ZERO; 3];
+    }
+    [vector.x() / largest, vector.y() / largest, vector.z() / largest]
+}
+
+/// Gets the cross product, the dot product and the product
+/// of the squared lengths of two rescaled vectors.
+#[inline]
+fn cross_dot_lengths<Num: Axis>(left: impl Vector<Num>, right: impl Vector<Num>) -> ([Num; 3], Num, Num) {
+    let [lx, ly, lz] = rescaled(left);
+    let [rx, ry, rz] = rescaled(right);
+    (
+        [ly * rz - lz * ry, lz * rx - lx * rz, lx * ry - ly * rx],
+        lx * rx + ly * ry + lz * rz,
+        (lx * lx + ly * ly + lz * lz) * (rx * rx + ry * ry + rz * rz),
+    )
+}
+
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Checks if two vectors are parallel (pointing the same or the opposite way).
+/// 
+/// The check is relative, the sine of the angle between them has to be less then
+/// [`Num::EPS_ARITH`](Axis::EPS_ARITH), so it works the same at any magnitude
+/// (the vectors are rescaled first, so even `1e20` long [f32] vectors don't overflow).
+/// 
+/// The zero vector is parallel to every vector.
+/// 
+/// For the vector parts of quaternions check [`vectors_parallel`].
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::are_parallel;
+/// 
+/// assert!( are_parallel::<f32>([1.0, 2.0, 3.0], [-2e20, -4e20, -6e20]) );
+/// assert!( are_parallel::<f32>([1e-20, 0.0, 0.0], [1.0, 1e-9, 0.0]) );
+/// assert!( !are_parallel::<f32>([1.0, 0.0, 0.0], [1.0, 0.001, 0.0]) );
+/// ```
+pub fn are_parallel<Num>(left: impl Vector<Num>, right: impl Vector<Num>) -> bool
+where 
+    Num: Axis,
+{
+    let ([x, y, z], _, lengths) = cross_dot_lengths(left, right);
+    x * x + y * y + z * z <= Num::EPS_ARITH * Num::EPS_ARITH * lengths
+}
+
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Checks if two vectors are perpendicular.
+/// 
+/// The check is relative, the cosine of the angle between them has to be less then
+/// [`Num::EPS_ARITH`](Axis::EPS_ARITH), so it works the same at any magnitude.
+/// 
+/// The zero vector is perpendicular to every vector.
+/// 
+/// For the vector parts of quaternions check [`vectors_perpendicular`].
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::are_perpendicular;
+/// 
+/// assert!( are_perpendicular::<f32>([1e20, 0.0, 0.0], [0.0, 3e-20, 1e-20]) );
+/// assert!( !are_perpendicular::<f32>([1.0, 0.0, 0.0], [0.001, 1.0, 0.0]) );
+/// ```
+pub fn are_perpendicular<Num>(left: impl Vector<Num>, right: impl Vector<Num>) -> bool
+where 
+    Num: Axis,
+{
+    let (_, dot, lengths) = cross_dot_lengths(left, right);
+    dot * dot <= Num::EPS_ARITH * Num::EPS_ARITH * lengths
+}
+
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gets the angle between two vectors, from `0` to `π`.
+/// 
+/// Uses `atan2(|a × b|, a · b)`, which (unlike `acos` of the dot product)
+/// stays accurate for vectors that are almost parallel or almost opposite,
+/// alike [`angle_between`] for quaternions.
+/// 
+/// If one of the vectors is the zero vector the result is NaN.
+/// 
+/// For the vector parts of quaternions check [`vector_angle_between`].
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::angle_between_vectors;
+/// # use core::f32::consts::FRAC_PI_2;
+/// 
+/// assert!( (angle_between_vectors::<f32, f32>([2.0, 0.0, 0.0], [0.0, 0.0, 5.0]) - FRAC_PI_2).abs() < 0.00001 );
+/// assert!( angle_between_vectors::<f32, f32>([1.0, 0.0, 0.0], [0.0, 0.0, 0.0]).is_nan() );
+/// ```
+pub fn angle_between_vectors<Num, Out>(left: impl Vector<Num>, right: impl Vector<Num>) -> Out
+where 
+    Num: Axis,
+    Out: ScalarConstructor<Num>,
+{
+    let ([x, y, z], dot, lengths) = cross_dot_lengths(left, right);
+    if lengths == Num::ZERO {
+        return Out::new_scalar(Num::NAN);
+    }
+    Out::new_scalar((x * x + y * y + z * z).sqrt().atan2(dot))
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Checks if the vector parts of two quaternions are parallel.
+/// 
+/// Check [`are_parallel`] for more info.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::vectors_parallel;
+/// 
+/// assert!( vectors_parallel::<f32>([5.0, 1.0, 2.0, 3.0], [-1.0, 0.5, 1.0, 1.5]) );
+/// ```
+pub fn vectors_parallel<Num>(left: impl Quaternion<Num>, right: impl Quaternion<Num>) -> bool
+where 
+    Num: Axis,
+{
+    are_parallel(to_vector::<Num, [Num; 3]>(left), to_vector::<Num, [Num; 3]>(right))
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Checks if the vector parts of two quaternions are perpendicular.
+/// 
+/// Check [`are_perpendicular`] for more info.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::vectors_perpendicular;
+/// 
+/// assert!( vectors_perpendicular::<f32>([5.0, 1.0, 0.0, 0.0], [5.0, 0.0, 1.0, 1.0]) );
+/// ```
+pub fn vectors_perpendicular<Num>(left: impl Quaternion<Num>, right: impl Quaternion<Num>) -> bool
+where 
+    Num: Axis,
+{
+    are_perpendicular(to_vector::<Num, [Num; 3]>(left), to_vector::<Num, [Num; 3]>(right))
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gets the angle between the vector parts of two quaternions, from `0` to `π`.
+/// 
+/// Check [`angle_between_vectors`] for more info.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::vector_angle_between;
+/// # use core::f32::consts::PI;
+/// 
+/// assert!( (vector_angle_between::<f32, f32>([1.0, 1.0, 0.0, 0.0], [2.0, -3.0, 0.0, 0.0]) - PI).abs() < 0.00001 );
+/// ```
+pub fn vector_angle_between<Num, Out>(left: impl Quaternion<Num>, right: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: ScalarConstructor<Num>,
+{
+    angle_between_vectors(to_vector::<Num, [Num; 3]>(left), to_vector::<Num, [Num; 3]>(right))
 }
@@ -1,8 +1,35 @@
-
 use super::*;
 
 // Thanks to quaternion crate for formula.
 /// Gives the vector rotated by the given quaternion
+/// 
+/// Same as [`quaternion::rotate_vector`](https://docs.rs/quaternion/latest/quaternion/fn.rotate_vector.html),
+/// but with the arguments the other way around (the vector comes first).
+/// If you want the quaternion first use [`point_rotation_unchecked`],
+/// which gives the same result.
+/// 
+/// The quaternion should be a unit quaternion.
+/// 
+/// # Breaking change
+/// Before version `3.0.0` this took its cross products the wrong way around
+/// and gave `2 * vector - rotated` instead of the rotated vector
+/// (a quarter turn around Z took `[1, 0, 0]` to `[2, -1, 0]`), so it wasn't a rotation at all.
+/// Code that worked around that by rotating the vector some other way
+/// can call this directly now, code that relied on the old value gets it back
+/// with `2 * vector - rotate_vector(vector, quaternion)`.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{rotate_vector, from_axis_angle};
+/// 
+/// let quat: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], core::f32::consts::FRAC_PI_2);
+/// let rotated: [f32; 3] = rotate_vector::<f32, [f32; 3]>([1.0, 0.0, 0.0], quat);
+/// 
+/// assert!( rotated[0].abs() < 0.00001 );
+/// assert!( (rotated[1] - 1.0).abs() < 0.00001 );
+/// assert!( rotated[2].abs() < 0.00001 );
+/// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn rotate_vector<Num, Out>(vector: impl Vector<Num>, quaternion: impl Quaternion<Num>) -> Out
 where 
     Num: Axis,
@@ -10,19 +37,200 @@ where
 {
     let two = Num::from_f64(2.0);
     let cross: [Num; 3] = [
-        two * (vector.y() * quaternion.k() - vector.z() * quaternion.j()),
-        two * (vector.z() * quaternion.i() - vector.x() * quaternion.k()),
-        two * (vector.x() * quaternion.j() - vector.y() * quaternion.i()),
+        two * (quaternion.j() * vector.z() - quaternion.k() * vector.y()),
+        two * (quaternion.k() * vector.x() - quaternion.i() * vector.z()),
+        two * (quaternion.i() * vector.y() - quaternion.j() * vector.x()),
     ];
     Out::new_vector(
-        vector.x() + cross[0] * quaternion.r() + quaternion.j() * cross[2] - quaternion.k() * cross[1],
-        vector.y() + cross[1] * quaternion.r() + quaternion.k() * cross[0] - quaternion.i() * cross[2],
-        vector.z() + cross[2] * quaternion.r() + quaternion.i() * cross[1] - quaternion.j() * cross[0],
+        vector.x() + cross[0] * quaternion.r() + (quaternion.j() * cross[2] - quaternion.k() * cross[1]),
+        vector.y() + cross[1] * quaternion.r() + (quaternion.k() * cross[0] - quaternion.i() * cross[2]),
+        vector.z() + cross[2] * quaternion.r() + (quaternion.i() * cross[1] - quaternion.j() * cross[0]),
+    )
+}
+
+/// Rotates a vector by a quaternion that doesn't have to be normalized,
+/// without dividing or taking any square roots.
+/// 
+/// Gives back the rotated vector multiplied by [`abs_squared`] of the quaternion
+/// (`q * v * conj(q)`) together with that factor, the caller should divide the vector by it.
+/// Since only additions and multiplications are used the result is exact whenever
+/// the axis type can hold the intermediate values exactly
+/// (eg: integer valued floats that stay under `2^24` for [f32]),
+/// which allows exact rotations by quaternions like `(1, 1, 0, 0)`.
+/// 
+/// For the origin quaternion both the vector and the factor are zero.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::rotate_vector_scaled;
+/// 
+/// // a quarter turn around the x axis, with abs_squared of 2
+/// let (scaled, factor): ([f32; 3], f32) = rotate_vector_scaled::<f32, [f32; 3]>([1.0, 2.0, 3.0], [1.0, 1.0, 0.0, 0.0]);
+/// 
+/// assert_eq!( factor, 2.0 );
+/// assert_eq!( scaled, [2.0, -6.0, 4.0] );
+/// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn rotate_vector_scaled<Num, Out>(vector: impl Vector<Num>, quaternion: impl Quaternion<Num>) -> (Out, Num)
+where 
+    Num: Axis,
+    Out: VectorConstructor<Num>,
+{
+    let (r, i, j, k) = (quaternion.r(), quaternion.i(), quaternion.j(), quaternion.k());
+    let (x, y, z) = (vector.x(), vector.y(), vector.z());
+    let two = Num::from_f64(2.0);
+    // (r² - |u|²) v + 2 (u · v) u + 2 r (u × v)
+    let vector_squared: Num = i * i + j * j + k * k;
+    let diagonal: Num = r * r - vector_squared;
+    let dot: Num = two * (i * x + j * y + k * z);
+    let r_twice: Num = two * r;
+    (
+        Out::new_vector(
+            diagonal * x + dot * i + r_twice * (j * z - k * y),
+            diagonal * y + dot * j + r_twice * (k * x - i * z),
+            diagonal * z + dot * k + r_twice * (i * y - j * x),
+        ),
+        r * r + vector_squared,
+    )
+}
+
+/// Rotates a vector by a quaternion that doesn't have to be normalized,
+/// dividing only once at the end.
+/// 
+/// This is [`rotate_vector_scaled`] with the vector divided by the factor,
+/// so no square roots are taken and the only rounding is from that
+/// last division (none if it's exact, like dividing by `2` or `4`).
+/// 
+/// The origin quaternion gives a [`NaN`](Axis::NAN) vector.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::rotate_vector_exact;
+/// 
+/// // a third of a turn around the (1, 1, 1) axis
+/// let rotated: [f32; 3] = rotate_vector_exact::<f32, [f32; 3]>([0.5, 2.0, -3.0], [1.0, 1.0, 1.0, 1.0]);
+/// 
+/// assert_eq!( rotated, [-3.0, 0.5, 2.0] );
+/// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn rotate_vector_exact<Num, Out>(vector: impl Vector<Num>, quaternion: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: VectorConstructor<Num>,
+{
+    let (scaled, factor) = rotate_vector_scaled::<Num, [Num; 3]>(vector, quaternion);
+    if factor == Num::ZERO {
+        return Out::new_vector(Num::NAN, Num::NAN, Num::NAN);
+    }
+    Out::new_vector(scaled[0] / factor, scaled[1] / factor, scaled[2] / factor)
+}
+
+/// Constructs the quaternion that reflects vectors across the plane with the given normal.
+/// 
+/// This is the normal itself turned into a pure unit quaternion `n`, the reflection is
+/// the sandwich `n * v * n` (without conjugating, unlike a rotation).
+/// Reflecting a vector directly is cheaper with [`reflect_vector`].
+/// 
+/// Two reflections one after the other are a rotation (around the line where the two planes meet,
+/// by twice the angle inbetween them), which is what [`rotation_from_two_reflections`] constructs.
+/// 
+/// The zero vector gives back the origin quaternion.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{reflection_across_plane, mul, to_vector};
+/// 
+/// let reflection: [f32; 4] = reflection_across_plane::<f32, [f32; 4]>([0.0, 0.0, 2.0]);
+/// assert_eq!( reflection, [0.0, 0.0, 0.0, 1.0] );
+/// 
+/// // mirrored across the xy plane
+/// let vector: [f32; 4] = [0.0, 1.0, 2.0, 3.0];
+/// let sandwich: [f32; 4] = mul::<f32, [f32; 4]>(mul::<f32, [f32; 4]>(reflection, vector), reflection);
+/// assert_eq!( to_vector::<f32, [f32; 3]>(sandwich), [1.0, 2.0, -3.0] );
+/// ```
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn reflection_across_plane<Num, Out>(normal: impl Vector<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    normalize((Num::ZERO, [normal.x(), normal.y(), normal.z()]))
+}
+
+/// Reflects a vector across the plane with the given normal.
+/// 
+/// Same as the sandwich with [`reflection_across_plane`], but uses the closed form
+/// `v - 2 (v · n̂) n̂` which is cheaper. The normal doesn't have to be normalized.
+/// 
+/// The zero normal gives a [`NaN`](Axis::NAN) vector.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::reflect_vector;
+/// 
+/// let reflected: [f32; 3] = reflect_vector::<f32, [f32; 3]>([1.0, 2.0, 3.0], [1.0, 1.0, 0.0]);
+/// assert_eq!( reflected, [-2.0, -1.0, 3.0] );
+/// ```
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn reflect_vector<Num, Out>(vector: impl Vector<Num>, normal: impl Vector<Num>) -> Out
+where 
+    Num: Axis,
+    Out: VectorConstructor<Num>,
+{
+    let (x, y, z) = (normal.x(), normal.y(), normal.z());
+    let factor: Num = Num::from_f64(2.0) * (vector.x() * x + vector.y() * y + vector.z() * z) / (x * x + y * y + z * z);
+    Out::new_vector(
+        vector.x() - factor * x,
+        vector.y() - factor * y,
+        vector.z() - factor * z,
+    )
+}
+
+/// Constructs the rotation that is the same as reflecting across the plane with the `first` normal
+/// and then across the plane with the `second` normal.
+/// 
+/// This is the product of the two [reflections](reflection_across_plane) `n2 * n1`,
+/// a rotation around `n1 × n2` by twice the angle inbetween the normals.
+/// 
+/// If either normal is the zero vector the origin quaternion is given back.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{rotation_from_two_reflections, rotate_vector, reflect_vector};
+/// 
+/// let first: [f32; 3] = [1.0, 0.0, 0.0];
+/// let second: [f32; 3] = [1.0, 1.0, 0.0];
+/// // the normals are 45° apart, so this is a quarter turn around the z axis
+/// let rotation: [f32; 4] = rotation_from_two_reflections::<f32, [f32; 4]>(first, second);
+/// 
+/// let vector: [f32; 3] = [1.0, 2.0, 3.0];
+/// let rotated: [f32; 3] = rotate_vector::<f32, [f32; 3]>(vector, rotation);
+/// let reflected: [f32; 3] = reflect_vector::<f32, [f32; 3]>(reflect_vector::<f32, [f32; 3]>(vector, first), second);
+/// 
+/// for n in 0..3 {
+///     assert!( (rotated[n] - reflected[n]).abs() < 0.0001 );
+/// }
+/// assert!( (rotated[0] + 2.0).abs() < 0.0001 );
+/// assert!( (rotated[1] - 1.0).abs() < 0.0001 );
+/// ```
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn rotation_from_two_reflections<Num, Out>(first: impl Vector<Num>, second: impl Vector<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    mul(
+        reflection_across_plane::<Num, Q<Num>>(second),
+        reflection_across_plane::<Num, Q<Num>>(first),
     )
 }
 
 // Thanks to quaternion crate for formula.
 /// Constructs a quaternion representing the rotation inbetween two vectors.
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn rotation_from_to<Num, Out>(from: impl Vector<Num>, to: impl Vector<Num>) -> Out
 where 
     Num: Axis,
@@ -55,24 +263,10 @@ where
         return identity();
     }
 
-    // from and to are anti-parallel
+    // from and to are anti-parallel, any axis perpendicular to from works
     if dot < Num::ERROR - Num::ONE {
-        let mut axis: [Num; 3] = if from[2] != Num::ZERO && from[1] != Num::ZERO {
-            [
-                Num::ZERO,
-                -from[2],
-                from[1],
-            ]
-        } else {
-            [
-                Num::ZERO,
-                Num::ZERO,
-                -from[0],
-            ]
-        };
-        len = Num::ONE / (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
-        axis = [axis[0] * len, axis[1] * len, axis[2] * len];
-        return from_axis_angle_unchecked(axis, Num::from_f64(crate::core::f64::consts::PI));
+        let (axis, _) = super::vec::orthonormal_basis::<Num>(from);
+        return from_axis_angle_unchecked(axis, crate::consts::pi::<Num>());
     }
     
     let quat: Q<Num> = (
@@ -83,7 +277,7 @@ where
             from[0] * to[1] - from[1] * to[0],
         ],
     );
-    unscale(quat, abs::<Num, Num>(quat)) // same as using `normalize` but skips the if check
+    normalize_unchecked(quat)
 }
 
 /// Constructs a quaternion from a given axis vector and a given angle.
@@ -123,12 +317,13 @@ where
 ///     [SQRT_2 / 2.0, 0.0, SQRT_2, 0.0]
 /// )
 /// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn from_axis_angle_unchecked<Num, Out>(axis: impl Vector<Num>, angle: impl Scalar<Num>) -> Out
 where 
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    let (sin, cos) = (angle.scalar() * Num::from_f64(0.5)).sin_cos();
+    let (sin, cos) = (angle.scalar() * crate::consts::half::<Num>()).sin_cos();
     Out::new_quat(
         cos,
         axis.x() * sin,
@@ -174,12 +369,13 @@ where
 ///     None
 /// )
 /// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn from_axis_angle_checked<Num, Out>(axis: impl Vector<Num>, angle: impl Scalar<Num>) -> Option<Out>
 where 
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    if axis.x()*axis.x() + axis.y()*axis.y() + axis.z()*axis.z() - Num::ONE < Num::ERROR * Num::ERROR {
+    if (axis.x()*axis.x() + axis.y()*axis.y() + axis.z()*axis.z() - Num::ONE).abs() < Num::EPS_TRIG {
         Option::Some(from_axis_angle_unchecked(axis, angle))
     } else {
         Option::None
@@ -190,6 +386,9 @@ where
 /// 
 /// Returns a unit quaternion no matter if the axis vector is normalized it'self or not.
 /// 
+/// The angle is in radians, pass in [`Degrees`](crate::structs::Degrees) for degrees
+/// (or use [`from_axis_angle_typed`] to make the unit required).
+/// 
 /// # Example
 /// Normalized
 /// ```
@@ -222,12 +421,13 @@ where
 ///     [SQRT_2 / 2.0, 0.0, SQRT_2 / 2.0, 0.0]
 /// )
 /// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn from_axis_angle<Num, Out>(axis: impl Vector<Num>, angle: impl Scalar<Num>) -> Out
 where 
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    let (sin, cos) = (angle.scalar() * Num::from_f64(0.5)).sin_cos();
+    let (sin, cos) = (angle.scalar() * crate::consts::half::<Num>()).sin_cos();
     let scalar = sin / (axis.x()*axis.x() + axis.y()*axis.y() + axis.z()*axis.z()).sqrt();
     Out::new_quat(
         cos,
@@ -237,170 +437,904 @@ where
     )
 }
 
-/// Gets a quaternion's axis and angle.
+/// Constructs a unit quaternion from a given axis vector and an angle that has to say its unit.
 /// 
-/// Alike [`to_polar_form`] but ignores the absolute value of the quaternion.
-pub fn to_axis_angle<Num, Vector, Scalar>(quaternion: impl Quaternion<Num>) -> (Vector, Scalar)
+/// Same as [`from_axis_angle`] but a plain number doesn't work as the angle,
+/// it has to be [`Radians`](crate::structs::Radians) or [`Degrees`](crate::structs::Degrees).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{from_axis_angle_typed, from_axis_angle};
+/// use quaternion_traits::structs::{Radians, Degrees};
+/// use core::f32::consts::PI;
+/// 
+/// let typed: [f32; 4] = from_axis_angle_typed::<f32, [f32; 4]>([1.0, 0.0, 0.0], Degrees(180.0));
+/// let plain: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([1.0, 0.0, 0.0], PI);
+/// 
+/// assert_eq!( typed, from_axis_angle_typed::<f32, [f32; 4]>([1.0, 0.0, 0.0], Radians(PI)) );
+/// for n in 0..4 {
+///     assert!( (typed[n] - plain[n]).abs() < 0.00001 );
+/// }
+/// ```
+/// 
+/// ```compile_fail
+/// use quaternion_traits::quat::from_axis_angle_typed;
+/// 
+/// // which unit is this in?
+/// let quat: [f32; 4] = from_axis_angle_typed::<f32, [f32; 4]>([1.0, 0.0, 0.0], 180.0_f32);
+/// ```
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn from_axis_angle_typed<Num, Out>(axis: impl Vector<Num>, angle: impl crate::core::convert::Into<crate::structs::Radians<Num>>) -> Out
 where 
     Num: Axis,
-    Vector: crate::VectorConstructor<Num>,
-    Scalar: crate::ScalarConstructor<Num>,
+    Out: QuaternionConstructor<Num>,
 {
-    if quaternion.i() == Num::ZERO || quaternion.j() == Num::ZERO || quaternion.k() == Num::ZERO {
-        return (Vector::new_vector(Num::ZERO, Num::ZERO, Num::ZERO), Scalar::new_scalar(Num::ZERO));
-    }
-    let vec_abs = (quaternion.i()*quaternion.i() + quaternion.j()*quaternion.j() + quaternion.k()*quaternion.k()).sqrt();
-    let vec_inv_abs = Num::ONE / vec_abs;
-    let angle = vec_abs.min(Num::ONE).asin();
-    (
-        Vector::new_vector(quaternion.i() * vec_inv_abs, quaternion.j() * vec_inv_abs, quaternion.k() * vec_inv_abs),
-        Scalar::new_scalar( if quaternion.r() >= Num::ZERO {angle + angle} else {-(angle + angle)} )
-    )
+    from_axis_angle(axis, angle.into())
 }
 
-// TODO check `rotate_from_to_shortest` from quaternion_core
-// TODO check `point_rotation` from quaternion_core
-
-/// Point rotation by a quaternion. (Frame fixed)
+/// Constructs a unit quaternion from a chain of axis-angle rotations.
 /// 
-/// Given formula:
-/// `q v *q` where `abs(q) == 1`
+/// The first item in the chain is applied first, meaning that
+/// the quaternions are multiplied from right to left:
 /// 
-/// Normalizes the quaternion before operating.
+///     # "
+///     [(a1, t1), (a2, t2), ..., (an, tn)]
+///         => qn * ... * q2 * q1
+///     # ";
 /// 
-/// If you want to go around this then use:
-/// [`point_rotation_checked`] or [`point_rotation_unchecked`].
+/// where each `q` is given by [`from_axis_angle`].
+/// 
+/// This is usefull for things like the forward kinematics of a robot arm.
+/// The product gets renormalized every 16 rotations (and at the end)
+/// so long chains don't drift away from being unit quaternions.
+/// 
+/// Returns the identity quaternion if the chain is empty.
 /// 
 /// # Example
 /// ```
+/// use quaternion_traits::quat::{compose_axis_angles, point_rotation, from_rotation};
 /// # use core::f32::consts::PI;
-/// use quaternion_traits::traits::Axis;
-/// use quaternion_traits::quat::{point_rotation, mul, conj, from_axis_angle};
 /// 
-/// let vector: [f32; 3] = [1.0, 0.5, -8.0];
-/// let quat: [f32; 4] = from_axis_angle::<f32, _>([0.2, 1.0, -2.0], PI);
+/// let (yaw, pitch, roll): (f32, f32, f32) = (PI / 3.0, PI / 5.0, -PI / 7.0);
 /// 
-/// let result: [f32; 3] = point_rotation::<f32, _>(quat, vector);
+/// // ZYX (yaw, pitch, roll) from the view of the rotating body,
+/// // which means the vector first gets rotated around the x axis.
+/// let quat: [f32; 4] = compose_axis_angles::<f32, [f32; 4]>([
+///     ([1.0, 0.0, 0.0], roll),
+///     ([0.0, 1.0, 0.0], pitch),
+///     ([0.0, 0.0, 1.0], yaw),
+/// ]);
 /// 
-/// // Equivalent but more expensive
-/// let check: [f32; 3] = mul::<f32, (f32, [f32; 3])>(
-///     mul::<f32, [f32; 4]>(
-///         quat,
-///         (0.0, vector)
-///     ),
-///     conj::<f32, [f32; 4]>(quat),
-/// ).1;
+/// let vector: [f32; 3] = [1.0, 2.0, 3.0];
+/// let result: [f32; 3] = point_rotation::<f32, [f32; 3]>(quat, vector);
 /// 
-/// assert!( (result[0] - check[0]).abs() < <f32 as Axis>::ERROR );
-/// assert!( (result[1] - check[1]).abs() < <f32 as Axis>::ERROR );
-/// assert!( (result[2] - check[2]).abs() < <f32 as Axis>::ERROR );
-/// ```
+/// // the same as rotating the vector in steps
+/// let check: [f32; 3] = [1.0, 2.0, 3.0];
+/// let check: [f32; 3] = point_rotation::<f32, [f32; 3]>([(roll / 2.0).cos(), (roll / 2.0).sin(), 0.0, 0.0], check);
+/// let check: [f32; 3] = point_rotation::<f32, [f32; 3]>([(pitch / 2.0).cos(), 0.0, (pitch / 2.0).sin(), 0.0], check);
+/// let check: [f32; 3] = point_rotation::<f32, [f32; 3]>([(yaw / 2.0).cos(), 0.0, 0.0, (yaw / 2.0).sin()], check);
 /// 
-// Example gotten from https://docs.rs/quaternion-core/latest/quaternion_core/fn.point_rotation.html
-pub fn point_rotation<Num, Out>(quaternion: impl Quaternion<Num>, vector: impl Vector<Num>) -> Out
+/// for n in 0..3 {
+///     assert!( (result[n] - check[n]).abs() < 0.0001 );
+/// }
+/// 
+/// // and the same as the crate's own ZYX (yaw, pitch, roll) conversion
+/// let zyx: [f32; 4] = from_rotation::<f32, [f32; 4]>([roll, pitch, yaw]);
+/// for n in 0..4 {
+///     assert!( (quat[n] - zyx[n]).abs() < 0.0001 );
+/// }
+/// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn compose_axis_angles<Num, Out>(chain: impl crate::core::iter::IntoIterator<Item = (impl Vector<Num>, impl Scalar<Num>)>) -> Out
 where 
     Num: Axis,
-    Out: VectorConstructor<Num>,
+    Out: QuaternionConstructor<Num>,
 {
-    point_rotation_unchecked(normalize::<Num, Q<Num>>(quaternion), vector)
+    const RENORMALIZE_EVERY: usize = 16;
+
+    let mut product: Q<Num> = identity::<Num, Q<Num>>();
+    for (n, (axis, angle)) in crate::core::iter::Iterator::enumerate(chain.into_iter()) {
+        product = mul(from_axis_angle::<Num, Q<Num>>(axis, angle), product);
+        if n % RENORMALIZE_EVERY == RENORMALIZE_EVERY - 1 {
+            product = normalize(product);
+        }
+    }
+    normalize(product)
 }
 
-/// Checked point rotation by a quaternion. (Frame fixed)
+#[inline]
+/// Applies a rotation around the x (`i`) axis on top of the rotation of the quaternion.
 /// 
-/// Checks if the quaternion is normalized before operating.
-/// Returns [`None`](Option::None) if it's not normalized.
-pub fn point_rotation_checked<Num, Out>(quaternion: impl Quaternion<Num>, vector: impl Vector<Num>) -> Option<Out>
+/// Does the same thing as `mul(from_axis_angle([1, 0, 0], angle), quaternion)`
+/// (so the new rotation is applied after the one of the quaternion)
+/// without constructing the axis vector or the intermediate quaternion.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{rotate_x, mul, from_axis_angle};
+/// 
+/// let quat: [f32; 4] = [0.5, 0.5, -0.5, 0.5];
+/// 
+/// let rotated: [f32; 4] = rotate_x::<f32, [f32; 4]>(quat, 1.0);
+/// let check: [f32; 4] = mul::<f32, [f32; 4]>(from_axis_angle::<f32, [f32; 4]>([1.0, 0.0, 0.0], 1.0), quat);
+/// 
+/// for n in 0..4 {
+///     assert!( (rotated[n] - check[n]).abs() < 0.00001 );
+/// }
+/// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn rotate_x<Num, Out>(quaternion: impl Quaternion<Num>, angle: impl Scalar<Num>) -> Out
 where 
     Num: Axis,
-    Out: VectorConstructor<Num>,
+    Out: QuaternionConstructor<Num>,
 {
-    if is_normalized::<Num>(&quaternion) {
-        Option::Some(point_rotation_unchecked(quaternion, vector))
-    } else {
-        Option::None
-    }
+    let (sin, cos) = (angle.scalar() * crate::consts::half::<Num>()).sin_cos();
+    Out::new_quat(
+        cos * quaternion.r() - sin * quaternion.i(),
+        cos * quaternion.i() + sin * quaternion.r(),
+        cos * quaternion.j() - sin * quaternion.k(),
+        cos * quaternion.k() + sin * quaternion.j(),
+    )
 }
 
-/// Unchecked point rotation by a quaternion. (Frame fixed)
+#[inline]
+/// Applies a rotation around the y (`j`) axis on top of the rotation of the quaternion.
 /// 
-/// Performs the operation no matter what.
-/// For non-normalized quaternions the output is
-/// (determenistic) undefined behaviour.
-pub fn point_rotation_unchecked<Num, Out>(quaternion: impl Quaternion<Num>, vector: impl Vector<Num>) -> Out
+/// Does the same thing as `mul(from_axis_angle([0, 1, 0], angle), quaternion)`
+/// (so the new rotation is applied after the one of the quaternion)
+/// without constructing the axis vector or the intermediate quaternion.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{rotate_y, mul, from_axis_angle};
+/// 
+/// let quat: [f32; 4] = [0.5, 0.5, -0.5, 0.5];
+/// 
+/// let rotated: [f32; 4] = rotate_y::<f32, [f32; 4]>(quat, 1.0);
+/// let check: [f32; 4] = mul::<f32, [f32; 4]>(from_axis_angle::<f32, [f32; 4]>([0.0, 1.0, 0.0], 1.0), quat);
+/// 
+/// for n in 0..4 {
+///     assert!( (rotated[n] - check[n]).abs() < 0.00001 );
+/// }
+/// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn rotate_y<Num, Out>(quaternion: impl Quaternion<Num>, angle: impl Scalar<Num>) -> Out
 where 
     Num: Axis,
-    Out: VectorConstructor<Num>,
+    Out: QuaternionConstructor<Num>,
 {
-    let temp: [Num; 3] = [
-        quaternion.r() * vector.x() + quaternion.j() * vector.z() - quaternion.k() * vector.y(),
-        quaternion.r() * vector.y() + quaternion.k() * vector.x() - quaternion.i() * vector.z(),
-        quaternion.r() * vector.z() + quaternion.i() * vector.y() - quaternion.j() * vector.x(),
-    ];
-    let two = Num::from_f64(2.0);
-    Out::new_vector(
-        vector.x() + (quaternion.j() * temp[2] - quaternion.k() * temp[1]) * two,
-        vector.y() + (quaternion.k() * temp[0] - quaternion.i() * temp[2]) * two,
-        vector.z() + (quaternion.i() * temp[1] - quaternion.j() * temp[0]) * two,
+    let (sin, cos) = (angle.scalar() * crate::consts::half::<Num>()).sin_cos();
+    Out::new_quat(
+        cos * quaternion.r() - sin * quaternion.j(),
+        cos * quaternion.i() + sin * quaternion.k(),
+        cos * quaternion.j() + sin * quaternion.r(),
+        cos * quaternion.k() - sin * quaternion.i(),
     )
 }
 
-/// Frame rotation by a quaternion. (Point fixed)
-/// 
-/// Given formula:
-/// `*q v q` where `abs(q) == 1`
+#[inline]
+/// Applies a rotation around the z (`k`) axis on top of the rotation of the quaternion.
 /// 
-/// Normalizes the quaternion before operating.
-/// 
-/// If you want to go around this then use:
-/// [`frame_rotation_checked`] or [`frame_rotation_unchecked`].
+/// Does the same thing as `mul(from_axis_angle([0, 0, 1], angle), quaternion)`
+/// (so the new rotation is applied after the one of the quaternion)
+/// without constructing the axis vector or the intermediate quaternion.
 /// 
 /// # Example
 /// ```
-/// # use core::f32::consts::PI;
-/// use quaternion_traits::{quat::{mul, conj, from_axis_angle}, traits::Axis};
-/// use quaternion_traits::quat::point_rotation;
+/// use quaternion_traits::quat::{rotate_z, mul, from_axis_angle};
 /// 
-/// let vector: [f32; 3] = [1.0, 0.5, -8.0];
-/// let quat: [f32; 4] = from_axis_angle::<f32, _>([0.2, 1.0, -2.0], PI);
+/// let quat: [f32; 4] = [0.5, 0.5, -0.5, 0.5];
 /// 
-/// let result: [f32; 3] = point_rotation::<f32, _>(quat, vector);
+/// let rotated: [f32; 4] = rotate_z::<f32, [f32; 4]>(quat, 1.0);
+/// let check: [f32; 4] = mul::<f32, [f32; 4]>(from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 1.0), quat);
 /// 
-/// // Equivalent but more expensive
-/// let check: [f32; 3] = mul::<f32, (f32, [f32; 3])>(
-///     mul::<f32, [f32; 4]>(
-///         conj::<f32, [f32; 4]>(quat),
-///         (0.0, vector)
-///     ),
-///     quat,
-/// ).1;
+/// for n in 0..4 {
+///     assert!( (rotated[n] - check[n]).abs() < 0.00001 );
+/// }
+/// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn rotate_z<Num, Out>(quaternion: impl Quaternion<Num>, angle: impl Scalar<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let (sin, cos) = (angle.scalar() * crate::consts::half::<Num>()).sin_cos();
+    Out::new_quat(
+        cos * quaternion.r() - sin * quaternion.k(),
+        cos * quaternion.i() - sin * quaternion.j(),
+        cos * quaternion.j() + sin * quaternion.i(),
+        cos * quaternion.k() + sin * quaternion.r(),
+    )
+}
+
+/// Gets the rotation around the same axis by twice the angle.
 /// 
-/// assert!( (result[0] - check[0]).abs() < <f32 as Axis>::ERROR );
-/// assert!( (result[1] - check[1]).abs() < <f32 as Axis>::ERROR );
-/// assert!( (result[2] - check[2]).abs() < <f32 as Axis>::ERROR );
+/// For unit quaternions this is the same as [`square`](super::square) of the quaternion,
+/// but computed as `2r q - |q|²` (the real part of `q²` is `r² - |v|² = 2r² - |q|²`),
+/// without a full multiplication.
+/// For other quaternions the result has a length of `|q|²` (and still the doubled rotation),
+/// normalize it if needed.
+/// 
+/// Undoes [`half`].
+/// 
+/// # Example
 /// ```
+/// use quaternion_traits::quat::{double, from_axis_angle, is_near_by};
 /// 
-// Example gotten from https://docs.rs/quaternion-core/latest/quaternion_core/fn.frame_rotation.html
-pub fn frame_rotation<Num, Out>(quaternion: impl Quaternion<Num>, vector: impl Vector<Num>) -> Out
+/// let quarter: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 0.25);
+/// let half: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 0.5);
+/// 
+/// assert!( is_near_by::<f32>(double::<f32, [f32; 4]>(quarter), half, 0.00001) );
+/// ```
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn double<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
 where 
     Num: Axis,
-    Out: VectorConstructor<Num>,
+    Out: QuaternionConstructor<Num>,
 {
-    frame_rotation_unchecked(normalize::<Num, Q<Num>>(quaternion), vector)
+    let two_r: Num = Num::from_f64(2.0) * quaternion.r();
+    Out::new_quat(
+        two_r * quaternion.r() - abs_squared::<Num, Num>(&quaternion),
+        two_r * quaternion.i(),
+        two_r * quaternion.j(),
+        two_r * quaternion.k(),
+    )
 }
 
-/// Checked frame rotation by a quaternion. (Point fixed)
+/// Gets the rotation around the same axis by half the angle, as a unit quaternion.
 /// 
-/// Checks if the quaternion is normalized before operating.
-/// Returns [`None`](Option::None) if it's not normalized.
-pub fn frame_rotation_checked<Num, Out>(quaternion: impl Quaternion<Num>, vector: impl Vector<Num>) -> Option<Out>
+/// This is the square root of the normalized quaternion (`normalize(q + |q|)`),
+/// with a non-negative real part, so [`double`] of it gives back the normalized `q`
+/// (not `-q`, which is the same rotation but halves into a different one).
+/// 
+/// When the real part is negative `r + |q|` is computed as `|v|² / (|q| - r)`,
+/// which doesn't lose precision to cancellation near `q = -|q|`.
+/// At `q = -|q|` exactly (a full turn, which has no axis, so only when `i == j == k == 0`)
+/// every unit vector is a square root, this gives back the one around the x axis, `[0, 1, 0, 0]`.
+/// A vector part that's merely tiny next to `r` still gives its own axis.
+/// The origin gives back the origin.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{half, double, from_axis_angle, is_near_by};
+/// 
+/// let turn: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([1.0, 2.0, 3.0], 2.5);
+/// let halved: [f32; 4] = half::<f32, [f32; 4]>(turn);
+/// 
+/// assert!( is_near_by::<f32>(halved, from_axis_angle::<f32, [f32; 4]>([1.0, 2.0, 3.0], 1.25), 0.00001) );
+/// assert!( is_near_by::<f32>(double::<f32, [f32; 4]>(halved), turn, 0.00001) );
+/// 
+/// assert_eq!( half::<f32, [f32; 4]>([1.0, 0.0, 0.0, 0.0]), [1.0, 0.0, 0.0, 0.0] );
+/// assert_eq!( half::<f32, [f32; 4]>([-1.0, 0.0, 0.0, 0.0]), [0.0, 1.0, 0.0, 0.0] );
+/// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn half<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
 where 
     Num: Axis,
-    Out: VectorConstructor<Num>,
+    Out: QuaternionConstructor<Num>,
 {
-    if is_normalized(&quaternion) {
-        Option::Some(frame_rotation_unchecked(quaternion, vector))
-    } else {
-        Option::None
+    let r: Num = quaternion.r();
+    if quaternion.i() == Num::ZERO && quaternion.j() == Num::ZERO && quaternion.k() == Num::ZERO && r <= Num::ZERO {
+        return if r == Num::ZERO { origin() } else { Out::new_quat(Num::ZERO, Num::ONE, Num::ZERO, Num::ZERO) };
+    }
+    // divided by the largest axis (only of the vector part when `r` is negative, as then it carries the result)
+    // so the squares of a vector part that's tiny next to `r` don't underflow
+    let largest: Num = quaternion.i().abs().max(quaternion.j().abs()).max(quaternion.k().abs());
+    let largest: Num = if r >= Num::ZERO { largest.max(r) } else { largest };
+    let r: Num = r / largest;
+    let vector: [Num; 3] = [quaternion.i() / largest, quaternion.j() / largest, quaternion.k() / largest];
+    let vector_squared: Num = vector[0] * vector[0] + vector[1] * vector[1] + vector[2] * vector[2];
+    let length: Num = (r * r + vector_squared).sqrt();
+    let real: Num = if r >= Num::ZERO { r + length } else { vector_squared / (length - r) };
+    normalize((real, vector))
+}
+
+/// Turns a camera by a yaw around the world z axis and a pitch around its own y axis.
+/// 
+/// The axes are the ones of [`to_roll_pitch_yaw`]: the yaw is applied around the fixed z axis
+/// (on the left, alike [`rotate_z`]) and the pitch around the camera's y axis
+/// (on the right, the camera's right axis when x is its forward axis).
+/// So a camera that starts without roll never gets any: with `current` at yaw `ψ` and pitch `θ`,
+/// the result is at yaw `ψ + yaw_delta` and pitch `θ + pitch_delta`.
+/// 
+/// The pitch isn't limited, past `±π/2` the camera goes over the top.
+/// For a limited pitch use [`orbit`].
+/// The result is normalized.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{turntable, to_pitch, to_yaw};
+/// 
+/// let mut camera: [f32; 4] = [1.0, 0.0, 0.0, 0.0];
+/// for _ in 0..10 {
+///     camera = turntable::<f32, [f32; 4]>(camera, 0.1, 0.05);
+/// }
+/// 
+/// assert!( (to_yaw::<f32, f32>(camera) - 1.0).abs() < 0.0001 );
+/// assert!( (to_pitch::<f32, f32>(camera) - 0.5).abs() < 0.0001 );
+/// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn turntable<Num, Out>(current: impl Quaternion<Num>, yaw_delta: impl Scalar<Num>, pitch_delta: impl Scalar<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let yawed: Q<Num> = rotate_z(current, yaw_delta);
+    let pitch: Q<Num> = from_axis_angle_unchecked([Num::ZERO, Num::ONE, Num::ZERO], pitch_delta);
+    normalize(mul::<Num, Q<Num>>(yawed, pitch))
+}
+
+/// Same as [`turntable`], but keeps the pitch (as [`to_pitch`] gives it) inside `pitch_limits`.
+/// 
+/// `pitch_limits` is `(lowest, highest)` and should be inside `[-π/2, π/2]`.
+/// Only the part of `pitch_delta` that fits is applied, the yaw is never limited.
+/// A camera that starts outside the limits is moved onto the nearest one.
+/// 
+/// Expects `current` to have no roll (which [`orbit`] and [`turntable`] never add),
+/// with roll the pitch around the camera's y axis doesn't add up to the pitch of [`to_pitch`].
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{orbit, to_pitch};
+/// 
+/// let mut camera: [f32; 4] = [1.0, 0.0, 0.0, 0.0];
+/// for _ in 0..10 {
+///     camera = orbit::<f32, [f32; 4]>(camera, 0.1, 0.2, (-1.0, 1.0));
+/// }
+/// 
+/// assert!( (to_pitch::<f32, f32>(camera) - 1.0).abs() < 0.0001 );
+/// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn orbit<Num, Out>(current: impl Quaternion<Num>, yaw_delta: impl Scalar<Num>, pitch_delta: impl Scalar<Num>, pitch_limits: (Num, Num)) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let pitch: Num = to_pitch(&current);
+    let (lowest, highest) = pitch_limits;
+    let target: Num = (pitch + pitch_delta.scalar()).max(lowest).min(highest);
+    turntable(current, yaw_delta, target - pitch)
+}
+
+/// Integrates a body frame angular rate (like the one a gyroscope gives) onto an orientation.
+/// 
+/// The rate is in radians per second around the axes of the body (the rotated frame),
+/// and `dt` is the time step in seconds (a [`Duration`](crate::core::time::Duration) works too).
+/// The step rotation `from_axis_angle(body_rate, abs(body_rate) * dt)`
+/// is multiplied on the right (`orientation * step`) and the result is normalized.
+/// 
+/// If the rate or `dt` is zero the orientation is given back unchanged (and isn't normalized).
+/// 
+/// For a rate in the world frame use [`integrate_gyro_world`].
+/// 
+/// For a constant rate the steps commute, so integrating `n` steps only differs from
+/// `mul(orientation, from_axis_angle(body_rate, abs(body_rate) * n * dt))` by rounding
+/// (about `n` times [`f32::EPSILON`] for [f32]).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{integrate_gyro, from_axis_angle, mul, is_near_by};
+/// 
+/// let start: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([1.0, 0.0, 0.0], 0.5);
+/// 
+/// // turning around the body's z axis at 1 rad/s for 1 second, in 100 steps
+/// let mut orientation: [f32; 4] = start;
+/// for _ in 0..100 {
+///     orientation = integrate_gyro::<f32, [f32; 4]>(orientation, [0.0, 0.0, 1.0], 0.01);
+/// }
+/// 
+/// let expected: [f32; 4] = mul::<f32, [f32; 4]>(start, from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 1.0));
+/// assert!( is_near_by::<f32>(orientation, expected, 0.0001) );
+/// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn integrate_gyro<Num, Out>(orientation: impl Quaternion<Num>, body_rate: impl Vector<Num>, dt: impl Scalar<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    match gyro_step::<Num>(body_rate, dt) {
+        Option::Some(step) => normalize(mul::<Num, Q<Num>>(orientation, step)),
+        Option::None => Out::from_quat(orientation),
+    }
+}
+
+/// Integrates a world frame angular rate onto an orientation.
+/// 
+/// Same as [`integrate_gyro`] but the rate is around the axes of the world (the fixed frame),
+/// so the step rotation is multiplied on the left (`step * orientation`).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{integrate_gyro, integrate_gyro_world, conj, point_rotation};
+/// 
+/// let orientation: [f32; 4] = [0.8, 0.0, 0.6, 0.0];
+/// let world_rate: [f32; 3] = [0.0, 0.0, 2.0];
+/// // the same rate seen from the body
+/// let body_rate: [f32; 3] = point_rotation::<f32, [f32; 3]>(conj::<f32, [f32; 4]>(orientation), world_rate);
+/// 
+/// let world: [f32; 4] = integrate_gyro_world::<f32, [f32; 4]>(orientation, world_rate, 0.1);
+/// let body: [f32; 4] = integrate_gyro::<f32, [f32; 4]>(orientation, body_rate, 0.1);
+/// 
+/// for n in 0..4 {
+///     assert!( (world[n] - body[n]).abs() < 0.00001 );
+/// }
+/// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn integrate_gyro_world<Num, Out>(orientation: impl Quaternion<Num>, world_rate: impl Vector<Num>, dt: impl Scalar<Num>) -> Out
+where 
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    match gyro_step::<Num>(world_rate, dt) {
+        Option::Some(step) => normalize(mul::<Num, Q<Num>>(step, orientation)),
+        Option::None => Out::from_quat(orientation),
+    }
+}
+
+/// The rotation of a rate over `dt`, or [`None`](Option::None) if it doesn't rotate.
+#[inline]
+fn gyro_step<Num: Axis>(rate: impl Vector<Num>, dt: impl Scalar<Num>) -> Option<Q<Num>> {
+    let dt: Num = dt.scalar();
+    let speed: Num = (rate.x() * rate.x() + rate.y() * rate.y() + rate.z() * rate.z()).sqrt();
+    if speed == Num::ZERO || dt == Num::ZERO {
+        return Option::None;
+    }
+    let (sin, cos) = (speed * dt * crate::consts::half::<Num>()).sin_cos();
+    let scale: Num = sin / speed;
+    Option::Some((cos, [rate.x() * scale, rate.y() * scale, rate.z() * scale]))
+}
+
+/// Turns a change in euler angles over a timestep into the constant
+/// angular velocity (in radians per unit of time) that does the same rotation in that time.
+/// 
+/// The rotation is turned into a quaternion with [`from_rotation`] and then into
+/// it's rotation vector (the axis times the angle, the logarithm of the rotation) which is divided by `dt`.
+/// This is exact and not the small angle approximation `[roll, pitch, yaw] / dt`,
+/// which is only right to first order and gets worse the larger the rotation is.
+/// [`integrate_gyro`] with the result and the same `dt` gives back the same rotation.
+/// 
+/// The angle is taken in `(-π, π]` (see [`to_axis_angle`]), so the rotation vector is at most half a turn long
+/// and the shortest rate is picked (a rotation by more then half a turn can't be told apart from going the other way).
+/// A `dt` of zero gives back infinite (or NaN) rates.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{rotation_to_rate, integrate_gyro, from_rotation, is_near_by};
+/// 
+/// let rotation: [f32; 3] = [0.4, -0.3, 1.2];
+/// let rate: [f32; 3] = rotation_to_rate::<f32, [f32; 3]>(rotation, 0.5);
+/// 
+/// let integrated: [f32; 4] = integrate_gyro::<f32, [f32; 4]>([1.0, 0.0, 0.0, 0.0], rate, 0.5);
+/// assert!( is_near_by::<f32>(integrated, from_rotation::<f32, [f32; 4]>(rotation), 0.0001) );
+/// ```
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn rotation_to_rate<Num, Out>(rotation: impl Rotation<Num>, dt: impl Scalar<Num>) -> Out
+where 
+    Num: Axis,
+    Out: VectorConstructor<Num>,
+{
+    let (axis, angle) = to_axis_angle::<Num, [Num; 3], Num>(from_rotation::<Num, Q<Num>>(rotation));
+    let scale: Num = angle / dt.scalar();
+    Out::new_vector(axis[0] * scale, axis[1] * scale, axis[2] * scale)
+}
+
+/// Turns a constant angular velocity (in radians per unit of time) kept for `dt`
+/// into the change in euler angles it makes.
+/// 
+/// The inverse of [`rotation_to_rate`], this is exact as well
+/// (the same rotation as [`integrate_gyro`] gives, turned into euler angles with [`to_rotation`]).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::rate_to_rotation;
+/// 
+/// // turning around the z axis at 2 radians a second for a quarter second
+/// let rotation: [f32; 3] = rate_to_rotation::<f32, [f32; 3]>([0.0, 0.0, 2.0], 0.25);
+/// 
+/// assert!( rotation[0].abs() < 0.00001 );
+/// assert!( rotation[1].abs() < 0.00001 );
+/// assert!( (rotation[2] - 0.5).abs() < 0.00001 );
+/// ```
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn rate_to_rotation<Num, Out>(rate: impl Vector<Num>, dt: impl Scalar<Num>) -> Out
+where 
+    Num: Axis,
+    Out: RotationConstructor<Num>,
+{
+    match gyro_step::<Num>(rate, dt) {
+        Option::Some(step) => to_rotation(step),
+        Option::None => Out::new_rotation(Num::ZERO, Num::ZERO, Num::ZERO),
+    }
+}
+
+/// Gets a quaternion's axis and angle.
+/// 
+/// Alike [`to_polar_form`] but ignores the absolute value of the quaternion.
+/// 
+/// The angle is in `(-π, π]`, this is [`to_axis_angle_wrapped`] with [`AngleWrap::SignedPi`].
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn to_axis_angle<Num, Vector, Scalar>(quaternion: impl Quaternion<Num>) -> (Vector, Scalar)
+where 
+    Num: Axis,
+    Vector: crate::VectorConstructor<Num>,
+    Scalar: crate::ScalarConstructor<Num>,
+{
+    to_axis_angle_wrapped(quaternion, AngleWrap::SignedPi)
+}
+
+/// The range the angle is given back in by [`to_axis_angle_wrapped`].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub enum AngleWrap<Num> {
+    /// Angles are in `(-π, π]`.
+    #[default]
+    SignedPi,
+    /// Angles are in `[0, 2π)`.
+    ZeroTau,
+    /// The angle that is the closest to the given previous angle,
+    /// so it can go past `±π` and keep going (for following a joint that keeps turning).
+    /// 
+    /// The previous angle should be the last one given back for the same axis.
+    Unwrapped(Num),
+}
+
+/// Gets a quaternion's axis and angle with the angle in the range picked by `wrap`.
+/// 
+/// The angle is `2 * atan2(abs(vector_part), real_part)`
+/// so the quaternion doesn't have to be normalized.
+/// 
+/// If the vector part is zero the axis is zero as well and the angle is `0`
+/// (or the multiple of `2π` closest to the previous angle for [`AngleWrap::Unwrapped`]).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{to_axis_angle_wrapped, from_axis_angle, AngleWrap};
+/// use core::f32::consts::PI;
+/// 
+/// let quat: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 1.5 * PI);
+/// 
+/// let (_, signed) = to_axis_angle_wrapped::<f32, [f32; 3], f32>(quat, AngleWrap::SignedPi);
+/// let (_, unsigned) = to_axis_angle_wrapped::<f32, [f32; 3], f32>(quat, AngleWrap::ZeroTau);
+/// let (axis, unwrapped) = to_axis_angle_wrapped::<f32, [f32; 3], f32>(quat, AngleWrap::Unwrapped(3.5 * PI));
+/// 
+/// assert!( (axis[2] - 1.0).abs() < 0.0001 );
+/// assert!( (signed + 0.5 * PI).abs() < 0.0001 );
+/// assert!( (unsigned - 1.5 * PI).abs() < 0.0001 );
+/// assert!( (unwrapped - 3.5 * PI).abs() < 0.0001 );
+/// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn to_axis_angle_wrapped<Num, Vector, Scalar>(quaternion: impl Quaternion<Num>, wrap: AngleWrap<Num>) -> (Vector, Scalar)
+where 
+    Num: Axis,
+    Vector: crate::VectorConstructor<Num>,
+    Scalar: crate::ScalarConstructor<Num>,
+{
+    let pi: Num = Num::TAU / Num::from_f64(2.0);
+    let vec_abs = (quaternion.i()*quaternion.i() + quaternion.j()*quaternion.j() + quaternion.k()*quaternion.k()).sqrt();
+    let (axis, angle) = if vec_abs == Num::ZERO {
+        (Vector::new_vector(Num::ZERO, Num::ZERO, Num::ZERO), Num::ZERO)
+    } else {
+        let vec_inv_abs = Num::ONE / vec_abs;
+        (
+            Vector::new_vector(quaternion.i() * vec_inv_abs, quaternion.j() * vec_inv_abs, quaternion.k() * vec_inv_abs),
+            // in `[0, 2π]`
+            Num::from_f64(2.0) * Num::atan2(vec_abs, quaternion.r()),
+        )
+    };
+    let angle: Num = match wrap {
+        AngleWrap::SignedPi if angle > pi => angle - Num::TAU,
+        AngleWrap::SignedPi => angle,
+        AngleWrap::ZeroTau if angle >= Num::TAU => angle - Num::TAU,
+        AngleWrap::ZeroTau => angle,
+        AngleWrap::Unwrapped(previous) => {
+            // the difference moved into `(-π, π]`
+            let mut difference: Num = (angle - previous) % Num::TAU;
+            if difference > pi {
+                difference = difference - Num::TAU;
+            } else if difference <= -pi {
+                difference = difference + Num::TAU;
+            }
+            previous + difference
+        },
+    };
+    (axis, Scalar::new_scalar(angle))
+}
+
+/// The quaternion of an axis and an angle, the identity for a zero axis (instead of NaN).
+#[inline]
+fn axis_angle_or_identity<Num: Axis>(axis: &impl Vector<Num>, angle: impl Scalar<Num>) -> Q<Num> {
+    if axis.x() == Num::ZERO && axis.y() == Num::ZERO && axis.z() == Num::ZERO {
+        return identity::<Num, Q<Num>>();
+    }
+    from_axis_angle(axis, angle)
+}
+
+/// Turns the interpolated quaternion of two axis angle keyframes back into an axis and an angle.
+/// 
+/// The axis is made to point to the same side as the axis of the first keyframe that isn't
+/// the identity rotation, and if the result is the identity rotation it is that axis.
+fn interpolated_axis_angle<Num: Axis>(
+    axis_a: impl Vector<Num>, 
+    from: Q<Num>, 
+    axis_b: impl Vector<Num>, 
+    to: Q<Num>, 
+    result: Q<Num>,
+) -> ([Num; 3], Num) {
+    let is_identity = |quat: &Q<Num>| quat.1 == [Num::ZERO; 3];
+    let [a, b]: [[Num; 3]; 2] = [
+        [axis_a.x(), axis_a.y(), axis_a.z()], 
+        [axis_b.x(), axis_b.y(), axis_b.z()],
+    ];
+    let reference: [Num; 3] = if !is_identity(&from) || (is_identity(&to) && a != [Num::ZERO; 3]) { a } else { b };
+    let length: Num = (reference[0] * reference[0] + reference[1] * reference[1] + reference[2] * reference[2]).sqrt();
+    let reference: [Num; 3] = if length == Num::ZERO {
+        reference
+    } else {
+        [reference[0] / length, reference[1] / length, reference[2] / length]
+    };
+
+    let (_, [i, j, k]) = result;
+    let result: Q<Num> = if i * reference[0] + j * reference[1] + k * reference[2] < Num::ZERO { neg(result) } else { result };
+    let (axis, angle): ([Num; 3], Num) = to_axis_angle(result);
+    if axis == [Num::ZERO; 3] {
+        (reference, angle)
+    } else {
+        (axis, angle)
+    }
+}
+
+/// Spherical liniar interpolation inbetween two rotations given as an axis and an angle,
+/// giving back an axis and an angle.
+/// 
+/// Same as turning both into quaternions with [`from_axis_angle`],
+/// interpolating them with [`slerp_unchecked`] (so the shortest path is used)
+/// and turning the result back with [`to_axis_angle`] (so the angle is in `(-π, π]`),
+/// except that the axis is flipped (and the angle negated) to point to the same side as the given axes.
+/// 
+/// An endpoint that doesn't rotate (an angle of `0` or a zero axis) has no axis of its own,
+/// so when the result doesn't rotate either the axis of the other endpoint is given back
+/// (instead of a zero axis, or NaN for a zero axis).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::slerp_axis_angle;
+/// use core::f32::consts::PI;
+/// 
+/// let (axis, angle) = slerp_axis_angle::<f32>([0.0, 0.0, 2.0], PI / 2.0, [0.0, 0.0, 1.0], PI / 4.0, 0.5);
+/// assert_eq!( axis, [0.0, 0.0, 1.0] );
+/// assert!( (angle - 3.0 * PI / 8.0).abs() < 0.0001 );
+/// 
+/// // the identity keeps the axis of the other endpoint
+/// let (axis, angle) = slerp_axis_angle::<f32>([0.0; 3], 0.0, [1.0, 0.0, 0.0], 1.0, 0.0);
+/// assert_eq!( (axis, angle), ([1.0, 0.0, 0.0], 0.0) );
+/// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn slerp_axis_angle<Num>(
+    axis_a: impl Vector<Num>, 
+    angle_a: impl Scalar<Num>, 
+    axis_b: impl Vector<Num>, 
+    angle_b: impl Scalar<Num>, 
+    at: impl Scalar<Num>,
+) -> ([Num; 3], Num)
+where 
+    Num: Axis,
+{
+    let from: Q<Num> = axis_angle_or_identity(&axis_a, angle_a);
+    let to: Q<Num> = axis_angle_or_identity(&axis_b, angle_b);
+    interpolated_axis_angle(axis_a, from, axis_b, to, slerp_unchecked(from, to, at))
+}
+
+/// Normalized liniar interpolation inbetween two rotations given as an axis and an angle,
+/// giving back an axis and an angle.
+/// 
+/// Cheaper then [`slerp_axis_angle`] but doesn't move at a constant speed,
+/// everything else is the same.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::nlerp_axis_angle;
+/// 
+/// let (axis, angle) = nlerp_axis_angle::<f32>([0.0, 1.0, 0.0], 0.5, [0.0, 1.0, 0.0], 1.5, 0.5);
+/// assert!( (axis[1] - 1.0).abs() < 0.0001 );
+/// assert!( (angle - 1.0).abs() < 0.0001 );
+/// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn nlerp_axis_angle<Num>(
+    axis_a: impl Vector<Num>, 
+    angle_a: impl Scalar<Num>, 
+    axis_b: impl Vector<Num>, 
+    angle_b: impl Scalar<Num>, 
+    at: impl Scalar<Num>,
+) -> ([Num; 3], Num)
+where 
+    Num: Axis,
+{
+    let from: Q<Num> = axis_angle_or_identity(&axis_a, angle_a);
+    let to: Q<Num> = axis_angle_or_identity(&axis_b, angle_b);
+    interpolated_axis_angle(axis_a, from, axis_b, to, normalize(lerp::<Num, Q<Num>>(from, to, at)))
+}
+
+// TODO check `rotate_from_to_shortest` from quaternion_core
+// TODO check `point_rotation` from quaternion_core
+
+/// Point rotation by a quaternion. (Frame fixed)
+/// 
+/// Given formula:
+/// `q v *q` where `abs(q) == 1`
+/// 
+/// Normalizes the quaternion before operating.
+/// 
+/// If you want to go around this then use:
+/// [`point_rotation_checked`] or [`point_rotation_unchecked`].
+/// 
+/// # Example
+/// ```
+/// # use core::f32::consts::PI;
+/// use quaternion_traits::traits::Axis;
+/// use quaternion_traits::quat::{point_rotation, mul, conj, from_axis_angle};
+/// 
+/// let vector: [f32; 3] = [1.0, 0.5, -8.0];
+/// let quat: [f32; 4] = from_axis_angle::<f32, _>([0.2, 1.0, -2.0], PI);
+/// 
+/// let result: [f32; 3] = point_rotation::<f32, _>(quat, vector);
+/// 
+/// // Equivalent but more expensive
+/// let check: [f32; 3] = mul::<f32, (f32, [f32; 3])>(
+///     mul::<f32, [f32; 4]>(
+///         quat,
+///         (0.0, vector)
+///     ),
+///     conj::<f32, [f32; 4]>(quat),
+/// ).1;
+/// 
+/// assert!( (result[0] - check[0]).abs() < <f32 as Axis>::ERROR );
+/// assert!( (result[1] - check[1]).abs() < <f32 as Axis>::ERROR );
+/// assert!( (result[2] - check[2]).abs() < <f32 as Axis>::ERROR );
+/// ```
+/// 
+// Example gotten from https://docs.rs/quaternion-core/latest/quaternion_core/fn.point_rotation.html
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn point_rotation<Num, Out>(quaternion: impl Quaternion<Num>, vector: impl Vector<Num>) -> Out
+where 
+    Num: Axis,
+    Out: VectorConstructor<Num>,
+{
+    point_rotation_unchecked(normalize::<Num, Q<Num>>(quaternion), vector)
+}
+
+/// Checked point rotation by a quaternion. (Frame fixed)
+/// 
+/// Checks if the quaternion is normalized before operating.
+/// Returns [`None`](Option::None) if it's not normalized.
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn point_rotation_checked<Num, Out>(quaternion: impl Quaternion<Num>, vector: impl Vector<Num>) -> Option<Out>
+where 
+    Num: Axis,
+    Out: VectorConstructor<Num>,
+{
+    if is_normalized::<Num>(&quaternion) {
+        Option::Some(point_rotation_unchecked(quaternion, vector))
+    } else {
+        Option::None
+    }
+}
+
+/// Unchecked point rotation by a quaternion. (Frame fixed)
+/// 
+/// Performs the operation no matter what.
+/// For non-normalized quaternions the output is
+/// (determenistic) undefined behaviour.
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn point_rotation_unchecked<Num, Out>(quaternion: impl Quaternion<Num>, vector: impl Vector<Num>) -> Out
+where 
+    Num: Axis,
+    Out: VectorConstructor<Num>,
+{
+    let temp: [Num; 3] = [
+        quaternion.r() * vector.x() + quaternion.j() * vector.z() - quaternion.k() * vector.y(),
+        quaternion.r() * vector.y() + quaternion.k() * vector.x() - quaternion.i() * vector.z(),
+        quaternion.r() * vector.z() + quaternion.i() * vector.y() - quaternion.j() * vector.x(),
+    ];
+    let two = Num::from_f64(2.0);
+    Out::new_vector(
+        vector.x() + (quaternion.j() * temp[2] - quaternion.k() * temp[1]) * two,
+        vector.y() + (quaternion.k() * temp[0] - quaternion.i() * temp[2]) * two,
+        vector.z() + (quaternion.i() * temp[1] - quaternion.j() * temp[0]) * two,
+    )
+}
+
+/// Point rotation of a point in homogeneous coordinates.
+/// 
+/// Rotates x, y and z (as [`point_rotation`] does) and keeps w,
+/// which is the right rotation for every `w`: a point with `w == 2`
+/// ends up standing for the same 3D point as the rotated point with `w == 1` would,
+/// and directions (`w == 0`) are rotated like vectors.
+/// 
+/// Normalizes the quaternion before operating.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::rotate_homogeneous;
+/// use quaternion_traits::structs::Homogeneous;
+/// 
+/// let half_turn: [f32; 4] = [0.0, 0.0, 0.0, 1.0];
+/// 
+/// assert_eq!( rotate_homogeneous::<f32>(half_turn, Homogeneous([2.0, 4.0, 6.0, 2.0])), Homogeneous([-2.0, -4.0, 6.0, 2.0]) );
+/// assert_eq!( rotate_homogeneous::<f32>(half_turn, Homogeneous([1.0, 0.0, 0.0, 0.0])), Homogeneous([-1.0, 0.0, 0.0, 0.0]) );
+/// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn rotate_homogeneous<Num>(quaternion: impl Quaternion<Num>, point: crate::structs::Homogeneous<Num>) -> crate::structs::Homogeneous<Num>
+where 
+    Num: Axis,
+{
+    let [x, y, z, w] = point.0;
+    let [x, y, z]: [Num; 3] = point_rotation(quaternion, [x, y, z]);
+    crate::structs::Homogeneous([x, y, z, w])
+}
+
+/// Frame rotation by a quaternion. (Point fixed)
+/// 
+/// Given formula:
+/// `*q v q` where `abs(q) == 1`
+/// 
+/// Normalizes the quaternion before operating.
+/// 
+/// If you want to go around this then use:
+/// [`frame_rotation_checked`] or [`frame_rotation_unchecked`].
+/// 
+/// # Example
+/// ```
+/// # use core::f32::consts::PI;
+/// use quaternion_traits::{quat::{mul, conj, from_axis_angle}, traits::Axis};
+/// use quaternion_traits::quat::point_rotation;
+/// 
+/// let vector: [f32; 3] = [1.0, 0.5, -8.0];
+/// let quat: [f32; 4] = from_axis_angle::<f32, _>([0.2, 1.0, -2.0], PI);
+/// 
+/// let result: [f32; 3] = point_rotation::<f32, _>(quat, vector);
+/// 
+/// // Equivalent but more expensive
+/// let check: [f32; 3] = mul::<f32, (f32, [f32; 3])>(
+///     mul::<f32, [f32; 4]>(
+///         conj::<f32, [f32; 4]>(quat),
+///         (0.0, vector)
+///     ),
+///     quat,
+/// ).1;
+/// 
+/// assert!( (result[0] - check[0]).abs() < <f32 as Axis>::ERROR );
+/// assert!( (result[1] - check[1]).abs() < <f32 as Axis>::ERROR );
+/// assert!( (result[2] - check[2]).abs() < <f32 as Axis>::ERROR );
+/// ```
+/// 
+// Example gotten from https://docs.rs/quaternion-core/latest/quaternion_core/fn.frame_rotation.html
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn frame_rotation<Num, Out>(quaternion: impl Quaternion<Num>, vector: impl Vector<Num>) -> Out
+where 
+    Num: Axis,
+    Out: VectorConstructor<Num>,
+{
+    frame_rotation_unchecked(normalize::<Num, Q<Num>>(quaternion), vector)
+}
+
+/// Checked frame rotation by a quaternion. (Point fixed)
+/// 
+/// Checks if the quaternion is normalized before operating.
+/// Returns [`None`](Option::None) if it's not normalized.
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn frame_rotation_checked<Num, Out>(quaternion: impl Quaternion<Num>, vector: impl Vector<Num>) -> Option<Out>
+where 
+    Num: Axis,
+    Out: VectorConstructor<Num>,
+{
+    if is_normalized(&quaternion) {
+        Option::Some(frame_rotation_unchecked(quaternion, vector))
+    } else {
+        Option::None
     }
 }
 
@@ -409,6 +1343,7 @@ where
 /// Performs the operation no matter what.
 /// For non-normalized quaternions the output is
 /// (determenistic) undefined behaviour.
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn frame_rotation_unchecked<Num, Out>(quaternion: impl Quaternion<Num>, vector: impl Vector<Num>) -> Out
 where 
     Num: Axis,
@@ -426,3 +1361,715 @@ where
         vector.z() + (temp[0] * quaternion.j() - temp[1] * quaternion.i()) * two,
     )
 }
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Rotates, scales and then translates a vector (a similarity transform).
+/// 
+/// Given formula:
+/// `s (q v *q) + t` where `abs(q) == 1`
+/// 
+/// Normalizes the quaternion before operating (same as [`point_rotation`]).
+/// 
+/// For keeping the three parts together use [`Similarity`](crate::structs::Similarity).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{similarity_transform, from_axis_angle};
+/// 
+/// let rotation: [f32; 4] = from_axis_angle::<f32, _>([0.0, 0.0, 1.0], core::f32::consts::FRAC_PI_2);
+/// let result: [f32; 3] = similarity_transform::<f32, _>([1.0, 0.0, 0.0], rotation, 2.0, [0.0, 0.0, 5.0]);
+/// 
+/// assert!( result[0].abs() < 0.00001 );
+/// assert!( (result[1] - 2.0).abs() < 0.00001 );
+/// assert!( (result[2] - 5.0).abs() < 0.00001 );
+/// ```
+pub fn similarity_transform<Num, Out>(
+    vector: impl Vector<Num>,
+    rotation: impl Quaternion<Num>,
+    scale: impl Scalar<Num>,
+    translation: impl Vector<Num>,
+) -> Out
+where 
+    Num: Axis,
+    Out: VectorConstructor<Num>,
+{
+    let rotated: [Num; 3] = point_rotation(rotation, vector);
+    let scale: Num = scale.scalar();
+    Out::new_vector(
+        rotated[0] * scale + translation.x(),
+        rotated[1] * scale + translation.y(),
+        rotated[2] * scale + translation.z(),
+    )
+}
+
+#[cfg(feature = "alloc")]
+/// Finds the angular velocities of a track of timestamped orientations.
+/// 
+/// Clears `out` and fills it with one angular velocity for each sample
+/// (the axis of rotation scaled by the speed in radians per unit of time),
+/// in the same frame as the samples rotate points in
+/// (same as [`QuatSpline::velocity`](crate::structs::QuatSpline::velocity)).
+/// 
+/// Uses central differences of the log map (`2 ln(q[n + 1] *q[n - 1]) / (t[n + 1] - t[n - 1])`),
+/// and forward/backward differences for the first/last sample.
+/// Each pair of samples is put on the same hemisphere first (check [`enforce_continuity`]),
+/// so samples with flipped signs don't show up as a spin.
+/// 
+/// If `window` is bigger then one the velocities are then smoothed
+/// using a centered moving average of `window` samples
+/// (fewer at the ends of the track).
+/// 
+/// The times should be strictly increasing, the orientations don't need to be normalized.
+/// A single sample has no velocity.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{angular_velocity_track, from_axis_angle};
+/// 
+/// // spinning around z at 2 radians per second
+/// let samples: Vec<(f32, [f32; 4])> = (0..10)
+///     .map(|n| n as f32 * 0.1)
+///     .map(|time| (time, from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 2.0 * time)))
+///     .collect();
+/// 
+/// let mut velocities: Vec<[f32; 3]> = Vec::new();
+/// angular_velocity_track::<f32>(&samples, 1, &mut velocities);
+/// 
+/// assert_eq!( velocities.len(), samples.len() );
+/// for [x, y, z] in velocities {
+///     assert!( x.abs() < 0.0001 && y.abs() < 0.0001 && (z - 2.0).abs() < 0.0001 );
+/// }
+/// ```
+pub fn angular_velocity_track<Num>(samples: &[(Num, [Num; 4])], window: usize, out: &mut crate::alloc::vec::Vec<[Num; 3]>)
+where 
+    Num: Axis,
+{
+    use crate::core::iter::Iterator;
+
+    /// `2 ln(after *before) / (end - start)`
+    fn difference<Num: Axis>((start, before): (Num, [Num; 4]), (end, after): (Num, [Num; 4])) -> [Num; 3] {
+        let after: [Num; 4] = enforce_continuity(before, after);
+        let [r, i, j, k]: [Num; 4] = mul(after, conj::<Num, [Num; 4]>(before));
+        let length: Num = (i * i + j * j + k * k).sqrt();
+        if length == Num::ZERO {
+            return [Num::ZERO; 3];
+        }
+        let factor: Num = Num::from_f64(2.0) * length.atan2(r) / (length * (end - start));
+        [i * factor, j * factor, k * factor]
+    }
+
+    out.clear();
+    let len: usize = samples.len();
+    if len < 2 {
+        out.resize(len, [Num::ZERO; 3]);
+        return;
+    }
+
+    out.push(difference(samples[0], samples[1]));
+    for at in 1..len - 1 {
+        out.push(difference(samples[at - 1], samples[at + 1]));
+    }
+    out.push(difference(samples[len - 2], samples[len - 1]));
+
+    if window > 1 {
+        let raw: crate::alloc::vec::Vec<[Num; 3]> = out.to_vec();
+        for (at, smoothed) in out.iter_mut().enumerate() {
+            let start: usize = at.saturating_sub((window - 1) / 2);
+            let end: usize = crate::core::cmp::min(at + window / 2 + 1, len);
+            let mut sum: [Num; 3] = [Num::ZERO; 3];
+            for velocity in &raw[start..end] {
+                sum = [sum[0] + velocity[0], sum[1] + velocity[1], sum[2] + velocity[2]];
+            }
+            let count: Num = Num::from_usize(end - start);
+            *smoothed = [sum[0] / count, sum[1] / count, sum[2] / count];
+        }
+    }
+}
+
+/// The angle of the rotation that turns `from` into `to`, in `[0, π]`.
+/// 
+/// Doesn't care about the signs or the lengths of the quaternions.
+#[inline]
+fn rotation_distance<Num: Axis>(from: impl Quaternion<Num>, to: impl Quaternion<Num>) -> Num {
+    let (r, [i, j, k]): Q<Num> = mul(conj::<Num, Q<Num>>(from), to);
+    Num::from_f64(2.0) * Num::atan2((i*i + j*j + k*k).sqrt(), r.abs())
+}
+
+/// Fills `out` with the angular distance of every pair of rotations in `set`.
+/// 
+/// The distance is the angle (in radians, in `[0, π]`) of the rotation that turns
+/// one into the other, `q` and `-q` count as the same rotation
+/// and the quaternions don't need to be normalized.
+/// 
+/// Only the upper triangle of the distance matrix is written (it's symmetric and the diagonal is zero),
+/// row after row: `(0, 1), (0, 2), ..., (0, n - 1), (1, 2), ..., (n - 2, n - 1)`,
+/// so `out` has to hold `n * (n - 1) / 2` distances for `n` rotations
+/// and the distance of `set[a]` and `set[b]` (with `a < b`) is at
+/// `out[a * n - a * (a + 1) / 2 + (b - a - 1)]`.
+/// 
+/// # Panics
+/// Panics if `out` doesn't hold exactly `set.len() * (set.len() - 1) / 2` distances.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{pairwise_angular_distances, from_axis_angle};
+/// 
+/// let set: [[f32; 4]; 3] = [
+///     from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 0.0),
+///     from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 0.5),
+///     from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 2.0),
+/// ];
+/// let mut distances: [f32; 3] = [0.0; 3];
+/// pairwise_angular_distances::<f32>(&set, &mut distances);
+/// 
+/// // (0, 1), (0, 2), (1, 2)
+/// for (distance, expected) in distances.into_iter().zip([0.5, 2.0, 1.5]) {
+///     assert!( (distance - expected).abs() < 0.0001 );
+/// }
+/// ```
+pub fn pairwise_angular_distances<Num>(set: &[[Num; 4]], out: &mut [Num])
+where 
+    Num: Axis,
+{
+    let pairs: usize = set.len() * set.len().saturating_sub(1) / 2;
+    if out.len() != pairs {
+        crate::core::panic!(
+            "Mismatched slice lengths in pairwise_angular_distances! Got {} quaternions needing {} distances, but the output holds {}",
+            set.len(), pairs, out.len(),
+        );
+    }
+    use crate::core::iter::Iterator;
+    let mut index: usize = 0;
+    for (row, from) in set.iter().enumerate() {
+        for to in &set[row + 1..] {
+            out[index] = rotation_distance(from, to);
+            index += 1;
+        }
+    }
+}
+
+/// Finds the rotation in `set` closest to `query`,
+/// returning it's index and angular distance.
+/// 
+/// Uses the same distance as [`pairwise_angular_distances`]
+/// (so `q` and `-q` are the same rotation), on a tie the first one is returned.
+/// 
+/// Returns [`None`](Option::None) if `set` is empty.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{nearest, from_axis_angle, neg};
+/// 
+/// let set: [[f32; 4]; 3] = [
+///     from_axis_angle::<f32, [f32; 4]>([1.0, 0.0, 0.0], 1.0),
+///     from_axis_angle::<f32, [f32; 4]>([0.0, 1.0, 0.0], 1.0),
+///     from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 1.0),
+/// ];
+/// 
+/// let query: [f32; 4] = neg::<f32, [f32; 4]>(from_axis_angle::<f32, [f32; 4]>([0.0, 0.1, 1.0], 1.0));
+/// let (index, distance) = nearest::<f32>(query, &set).unwrap();
+/// assert_eq!( index, 2 );
+/// assert!( distance < 0.1 );
+/// 
+/// assert_eq!( nearest::<f32>(query, &[]), None );
+/// ```
+pub fn nearest<Num>(query: impl Quaternion<Num>, set: &[[Num; 4]]) -> Option<(usize, Num)>
+where 
+    Num: Axis,
+{
+    use crate::core::iter::Iterator;
+    let mut closest: Option<(usize, Num)> = Option::None;
+    for (index, quaternion) in set.iter().enumerate() {
+        let distance: Num = rotation_distance(&query, quaternion);
+        match closest {
+            Option::Some((_, best)) if distance >= best || distance.is_nan() => {},
+            _ => closest = Option::Some((index, distance)),
+        }
+    }
+    closest
+}
+
+/// The index of the largest component of a quaternion (the first one on a tie)
+/// and whether it's negative.
+#[inline]
+fn largest_component<Num: Axis>(quaternion: &[Num; 4]) -> (usize, bool) {
+    let mut largest: usize = 0;
+    for axis in 1..4 {
+        if quaternion[axis].abs() > quaternion[largest].abs() {
+            largest = axis;
+        }
+    }
+    (largest, quaternion[largest] < Num::ZERO)
+}
+
+/// Flips the sign of a quaternion so its largest component (the first one on a tie) is positive,
+/// the same sign [`rotation_key`] picks.
+#[inline]
+#[cfg(feature = "alloc")]
+fn canonical_sign<Num: Axis>(quaternion: [Num; 4]) -> [Num; 4] {
+    if largest_component(&quaternion).1 {
+        neg(quaternion)
+    } else {
+        quaternion
+    }
+}
+
+/// Compares the components one after the other,
+/// a NaN goes after every number (and is the same as any other NaN).
+#[cfg(feature = "alloc")]
+fn cmp_components<Num: Axis>(a: &[Num; 4], b: &[Num; 4]) -> crate::core::cmp::Ordering {
+    use crate::core::cmp::Ordering;
+    for n in 0..4 {
+        let order: Ordering = match (a[n].is_nan(), b[n].is_nan()) {
+            (false, false) => a[n].partial_cmp(&b[n]).unwrap_or(Ordering::Equal),
+            (a, b) => crate::core::cmp::Ord::cmp(&a, &b),
+        };
+        if order != Ordering::Equal {
+            return order;
+        }
+    }
+    Ordering::Equal
+}
+
+#[cfg(feature = "alloc")]
+/// Removes the rotations of `set` that are (about) the same as another one,
+/// returning how many were removed.
+/// 
+/// Every quaternion's sign is picked first so that its largest component is positive
+/// (so `q` and `-q` are the same), then the set is sorted by [`rotation_key_default`]
+/// and a rotation is removed if its angular distance (same as [`pairwise_angular_distances`])
+/// to the last rotation that was kept is smaller then `angular_tolerance`.
+/// The kept quaternions are otherwise left as they are (they aren't normalized).
+/// 
+/// Rotations with the same key are sorted by their (sign picked) components,
+/// so out of near-duplicates with the same key the one with the smallest components
+/// (compared one after the other, starting with the real part) is the one that's kept.
+/// The order of the result is the order of the keys, not the original one.
+/// 
+/// The keys are quantized (check the collisions of [`rotation_key`]),
+/// so two rotations closer then the tolerance can still be on different sides of a cell border.
+/// Then they are sorted by their cells instead of their original order
+/// and other rotations can end up inbetween them, so not every near-duplicate
+/// is guaranteed to be found.
+/// Exact duplicates (up to sign) always have the same key and components,
+/// so they end up next to each other and are always found.
+/// 
+/// The origin and quaternions with a NaN don't have a rotation, they are always kept
+/// (and skipped over when looking for the last kept rotation).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{dedup_rotations, from_axis_angle, neg};
+/// 
+/// let a: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 1.0);
+/// let b: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([1.0, 0.0, 0.0], 1.0);
+/// 
+/// let mut set: Vec<[f32; 4]> = vec![a, b, neg::<f32, [f32; 4]>(a), a];
+/// 
+/// assert_eq!( dedup_rotations::<f32>(&mut set, 0.001), 2 );
+/// assert_eq!( set.len(), 2 );
+/// ```
+pub fn dedup_rotations<Num>(set: &mut crate::alloc::vec::Vec<[Num; 4]>, angular_tolerance: Num) -> usize
+where 
+    Num: Axis,
+{
+    for quaternion in set.iter_mut() {
+        *quaternion = canonical_sign(*quaternion);
+    }
+    set.sort_by(|a: &[Num; 4], b: &[Num; 4]| {
+        crate::core::cmp::Ord::cmp(&rotation_key_default(a), &rotation_key_default(b))
+            .then_with(|| cmp_components(a, b))
+    });
+
+    let len: usize = set.len();
+    let mut kept: usize = 0;
+    // the last kept quaternion that has a rotation
+    let mut previous: Option<[Num; 4]> = Option::None;
+    for at in 0..len {
+        let current: [Num; 4] = set[at];
+        let length: Num = abs_squared(current);
+        // the origin is zero distance from everything, and a NaN from nothing
+        if length == Num::ZERO || length.is_nan() {
+            set[kept] = current;
+            kept += 1;
+            continue;
+        }
+        match previous {
+            Option::Some(previous) if rotation_distance(previous, current) < angular_tolerance => {},
+            _ => {
+                set[kept] = current;
+                kept += 1;
+                previous = Option::Some(current);
+            },
+        }
+    }
+    set.truncate(kept);
+    len - kept
+}
+
+#[cfg(feature = "alloc")]
+/// Sorts `set` by the angular distance of each rotation from `reference`, closest first.
+/// 
+/// Uses the same distance as [`pairwise_angular_distances`]
+/// (so `q` and `-q` are the same rotation), the quaternions themselves aren't changed.
+/// 
+/// The sort is stable (rotations at the same distance keep their order)
+/// and quaternions with a NaN (or the origin) go to the end.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{sort_by_angle_from, from_axis_angle};
+/// 
+/// let mut set: [[f32; 4]; 3] = [
+///     from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 2.0),
+///     from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 0.5),
+///     from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], -1.0),
+/// ];
+/// 
+/// sort_by_angle_from::<f32>(&mut set, [1.0, 0.0, 0.0, 0.0]);
+/// assert!( set[0][3] > 0.0 && set[0][3] < 0.3 );
+/// assert!( set[1][3] < 0.0 );
+/// assert!( set[2][3] > 0.8 );
+/// ```
+pub fn sort_by_angle_from<Num>(set: &mut [[Num; 4]], reference: impl Quaternion<Num>)
+where 
+    Num: Axis,
+{
+    use crate::core::cmp::Ordering;
+    set.sort_by(|a: &[Num; 4], b: &[Num; 4]| {
+        let a: Num = rotation_distance(&reference, a);
+        let b: Num = rotation_distance(&reference, b);
+        match (a.is_nan(), b.is_nan()) {
+            (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+            (a, b) => crate::core::cmp::Ord::cmp(&a, &b),
+        }
+    });
+}
+
+/// The amount of cells per axis [`rotation_key_default`] uses.
+/// 
+/// A cell is about `0.04°` of rotation wide, coarse enough to merge rotations
+/// that only differ by [f32] rounding and fine enough to keep visibly different ones apart.
+pub const DEFAULT_ROTATION_KEY_CELLS: u32 = 4096;
+
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gets a hashable key of the rotation of a quaternion, quantized to a grid of cells.
+/// 
+/// The quaternion is normalized and its sign is picked so that its largest component is positive
+/// (so `q` and `-q` give the same key), then the other three components
+/// (which are always inbetween `±1/√2`, the "smallest three" representation)
+/// are each put into one of `cells_per_axis` equal cells.
+/// The key is the index of the largest component (2 bits) and the three cells (20 bits each).
+/// 
+/// # Collisions
+/// - Rotations in the same cell always get the same key, that's the point.
+///   A cell is `√2 / cells_per_axis` wide on each axis,
+///   which is at most about `2√6 / cells_per_axis` radians of rotation across.
+/// - Rotations in different cells always get different keys
+///   (the packing doesn't lose anything), so there are no other collisions.
+/// - Two rotations that are very close can still be on different sides of a cell border
+///   (or have a different largest component, when two components are almost equal),
+///   so a different key doesn't mean the rotations are far apart.
+///   For deduplicating within a tolerance also look up the neighbouring cells,
+///   or compare the angles between the rotations after grouping by key.
+/// 
+/// `cells_per_axis` is clamped into `1..=2^20`.
+/// The origin and quaternions with a NaN don't have a rotation,
+/// they still get a key but it's also the key of some real rotations.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{rotation_key, from_axis_angle, neg};
+/// 
+/// let a: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 1.0);
+/// let b: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 1.0001);
+/// let c: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 1.1);
+/// 
+/// assert_eq!( rotation_key::<f32>(a, 1000), rotation_key::<f32>(neg::<f32, [f32; 4]>(a), 1000) );
+/// assert_eq!( rotation_key::<f32>(a, 1000), rotation_key::<f32>(b, 1000) );
+/// assert_ne!( rotation_key::<f32>(a, 1000), rotation_key::<f32>(c, 1000) );
+/// ```
+pub fn rotation_key<Num>(quaternion: impl Quaternion<Num>, cells_per_axis: u32) -> u64
+where 
+    Num: Axis,
+{
+    let cells: u32 = crate::core::cmp::Ord::clamp(cells_per_axis, 1, 1 << 20);
+    let q: [Num; 4] = normalize(quaternion);
+
+    let (largest, is_negative) = largest_component(&q);
+    let sign: Num = if is_negative { -Num::ONE } else { Num::ONE };
+
+    let half_width: Num = crate::consts::frac_1_sqrt_2::<Num>();
+    let scale: Num = Num::from_u32(cells) / (half_width + half_width);
+    let mut key: u64 = largest as u64;
+    for (axis, &component) in crate::core::iter::Iterator::enumerate(q.iter()) {
+        if axis != largest {
+            let cell: u32 = cell_of((component * sign + half_width) * scale, cells);
+            key = (key << 20) | cell as u64;
+        }
+    }
+    key
+}
+
+/// Gets the cell `value` is in, the largest of `0..cells` that isn't above it
+/// (so negative values and NaN are in the first cell).
+/// 
+/// Binary searches with [`Axis::from_u32`] so it works for any [`Axis`].
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+fn cell_of<Num: Axis>(value: Num, cells: u32) -> u32 {
+    let (mut low, mut high): (u32, u32) = (0, cells - 1);
+    while low < high {
+        let middle: u32 = low + (high - low).div_ceil(2);
+        if Num::from_u32(middle) <= value {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+    low
+}
+
+/// Gets a hashable key of the rotation of a quaternion, using [`DEFAULT_ROTATION_KEY_CELLS`] cells per axis.
+/// 
+/// Check [`rotation_key`] for more info.
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn rotation_key_default<Num>(quaternion: impl Quaternion<Num>) -> u64
+where 
+    Num: Axis,
+{
+    rotation_key(quaternion, DEFAULT_ROTATION_KEY_CELLS)
+}
+
+/// Gets how far the frame of a quaternion is from being orthonormal.
+/// 
+/// The basis vectors are rotated with [`rotate_vector`] and this gives
+/// the largest absolute difference inbetween their Gram matrix
+/// (the dot products of every pair of them) and the identity matrix.
+/// 
+/// For unit quaternions this is around [`Num::ERROR`](Axis::ERROR) or less,
+/// it's useful for checking how far a quaternion drifted after many compositions.
+/// For a quaternion of length `s` this grows about linearly with `s² - 1`
+/// (except for the identity rotation, which stays orthogonal at any length).
+/// 
+/// If the quaternion has a NaN the result is NaN.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{frame_orthogonality_error, from_axis_angle, scale};
+/// 
+/// let unit: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([1.0, 2.0, 3.0], 0.8);
+/// assert!( frame_orthogonality_error::<f32, f32>(unit) < 0.00001 );
+/// 
+/// let drifted: [f32; 4] = scale::<f32, [f32; 4]>(unit, 1.01);
+/// assert!( frame_orthogonality_error::<f32, f32>(drifted) > 0.001 );
+/// ```
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn frame_orthogonality_error<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Out: ScalarConstructor<Num>,
+{
+    let frame: [[Num; 3]; 3] = [
+        rotate_vector([Num::ONE, Num::ZERO, Num::ZERO], &quaternion),
+        rotate_vector([Num::ZERO, Num::ONE, Num::ZERO], &quaternion),
+        rotate_vector([Num::ZERO, Num::ZERO, Num::ONE], &quaternion),
+    ];
+    let mut error: Num = Num::ZERO;
+    for a in 0..3 {
+        for b in a..3 {
+            let dot: Num = frame[a][0] * frame[b][0] + frame[a][1] * frame[b][1] + frame[a][2] * frame[b][2];
+            let expected: Num = if a == b { Num::ONE } else { Num::ZERO };
+            let deviation: Num = (dot - expected).abs();
+            if deviation > error || deviation.is_nan() {
+                error = deviation;
+            }
+        }
+    }
+    Out::new_scalar(error)
+}
+
+/// Gets the angle (in radians, in `[0, π]`) of the rotation between
+/// the rotations of two 3x3 rotation matrices (DCMs).
+/// 
+/// Both matrices are turned into quaternions with [`from_matrix_3`] and compared with the
+/// same distance as [`pairwise_angular_distances`], so the sign of the quaternions
+/// doesn't matter and neither does the matrices being a bit off from orthonormal.
+/// Both matrices have to use the same convention (rows or columns).
+/// 
+/// If either matrix has a NaN the result is NaN.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{rotation_angle_between_matrices, to_matrix_3, from_axis_angle};
+/// 
+/// let from: [[f32; 3]; 3] = to_matrix_3::<f32, f32, [[f32; 3]; 3]>(from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 0.5));
+/// let to: [[f32; 3]; 3] = to_matrix_3::<f32, f32, [[f32; 3]; 3]>(from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 0.75));
+/// 
+/// let angle: f32 = rotation_angle_between_matrices::<f32, f32, f32>(from, to);
+/// assert!( (angle - 0.25).abs() < 0.0001 );
+/// ```
+#[inline]
+#[cfg(feature = "matrix")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn rotation_angle_between_matrices<Num, Elem, Out>(from: impl Matrix<Elem, 3>, to: impl Matrix<Elem, 3>) -> Out
+where 
+    Num: Axis,
+    Elem: Scalar<Num>,
+    Out: ScalarConstructor<Num>,
+{
+    Out::new_scalar(rotation_distance(
+        from_matrix_3::<Num, Elem, Q<Num>>(from),
+        from_matrix_3::<Num, Elem, Q<Num>>(to),
+    ))
+}
+
+/// Checks if two 3x3 rotation matrices (DCMs) encode the same rotation,
+/// up to an angle of `tolerance` (in radians).
+/// 
+/// Comparing the elements of the matrices doesn't work well for this,
+/// small rounding errors (like the ones from a round trip through a quaternion)
+/// can move single elements quite a bit while barely changing the rotation.
+/// This compares the angle from [`rotation_angle_between_matrices`] instead.
+/// 
+/// If either matrix has a NaN this is `false`.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{same_rotation_matrix, to_matrix_3, from_axis_angle};
+/// 
+/// let matrix: [[f32; 3]; 3] = to_matrix_3::<f32, f32, [[f32; 3]; 3]>(from_axis_angle::<f32, [f32; 4]>([1.0, 2.0, 3.0], 1.0));
+/// let mut nudged: [[f32; 3]; 3] = matrix;
+/// nudged[0][1] += 0.0005;
+/// 
+/// assert!( same_rotation_matrix::<f32, f32>(matrix, nudged, 0.01) );
+/// assert!( !same_rotation_matrix::<f32, f32>(matrix, [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]], 0.01) );
+/// ```
+#[inline]
+#[cfg(feature = "matrix")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn same_rotation_matrix<Num, Elem>(from: impl Matrix<Elem, 3>, to: impl Matrix<Elem, 3>, tolerance: impl Scalar<Num>) -> bool
+where 
+    Num: Axis,
+    Elem: Scalar<Num>,
+{
+    rotation_angle_between_matrices::<Num, Elem, Num>(from, to) <= tolerance.scalar()
+}
+
+/// Gets the small adjoint `ad(v)` of a tangent vector (a rotation vector / angular rate),
+/// which is the skew-symmetric cross product matrix of the vector.
+/// 
+/// Multiplying the matrix by another vector `w` (as a column) gives `v × w`,
+/// which is the Lie bracket of the two tangent vectors.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::ad;
+/// 
+/// let matrix: [[f32; 3]; 3] = ad::<f32, f32, [[f32; 3]; 3]>([1.0, 2.0, 3.0]);
+/// 
+/// assert_eq!( matrix, [
+///     [ 0.0, -3.0,  2.0],
+///     [ 3.0,  0.0, -1.0],
+///     [-2.0,  1.0,  0.0],
+/// ] );
+/// ```
+#[inline]
+#[cfg(feature = "matrix")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn ad<Num, Elem, Out>(vector: impl Vector<Num>) -> Out
+where 
+    Num: Axis,
+    Elem: ScalarConstructor<Num>,
+    Out: MatrixConstructor<Elem, 3>,
+{
+    Out::new_matrix([
+        [Elem::new_scalar(Num::ZERO), Elem::new_scalar(-vector.z()), Elem::new_scalar(vector.y())],
+        [Elem::new_scalar(vector.z()), Elem::new_scalar(Num::ZERO), Elem::new_scalar(-vector.x())],
+        [Elem::new_scalar(-vector.y()), Elem::new_scalar(vector.x()), Elem::new_scalar(Num::ZERO)],
+    ])
+}
+
+/// Gets the big adjoint `Ad(q)` of a unit quaternion, which is the matrix
+/// that rotates tangent vectors the same way [`rotate_vector`] does.
+/// 
+/// Multiplying the matrix by a vector (as a column) is the same as rotating it by the quaternion.
+/// This is the transpose of the DCM given by [`to_matrix_3`].
+/// 
+/// The quaternion should be a unit quaternion.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{Ad, from_axis_angle};
+/// 
+/// let quat: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], core::f32::consts::FRAC_PI_2);
+/// let matrix: [[f32; 3]; 3] = Ad::<f32, f32, [[f32; 3]; 3]>(quat);
+/// 
+/// // the x axis is turned into the y axis
+/// assert!( matrix[0][0].abs() < 0.00001 );
+/// assert!( (matrix[1][0] - 1.0).abs() < 0.00001 );
+/// assert!( matrix[2][0].abs() < 0.00001 );
+/// ```
+#[inline]
+#[allow(non_snake_case)]
+#[cfg(feature = "matrix")]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn Ad<Num, Elem, Out>(quaternion: impl Quaternion<Num>) -> Out
+where 
+    Num: Axis,
+    Elem: ScalarConstructor<Num>,
+    Out: MatrixConstructor<Elem, 3>,
+{
+    let q = quaternion;
+    let two = Num::from_f64(2.0);
+    Out::new_matrix([
+        [
+            Elem::new_scalar(q.r()*q.r() + q.i()*q.i() - q.j()*q.j() - q.k()*q.k()),
+            Elem::new_scalar(two * ( q.i()*q.j() - q.r()*q.k() )),
+            Elem::new_scalar(two * ( q.i()*q.k() + q.r()*q.j() )),
+        ],
+        [
+            Elem::new_scalar(two * ( q.i()*q.j() + q.r()*q.k() )),
+            Elem::new_scalar(q.r()*q.r() - q.i()*q.i() + q.j()*q.j() - q.k()*q.k()),
+            Elem::new_scalar(two * ( q.j()*q.k() - q.r()*q.i() )),
+        ],
+        [
+            Elem::new_scalar(two * ( q.i()*q.k() - q.r()*q.j() )),
+            Elem::new_scalar(two * ( q.j()*q.k() + q.r()*q.i() )),
+            Elem::new_scalar(q.r()*q.r() - q.i()*q.i() - q.j()*q.j() + q.k()*q.k()),
+        ],
+    ])
+}
+
+/// Composes two small rotations given as rotation vectors (axis times angle)
+/// with the second order Baker–Campbell–Hausdorff approximation `v + w + ½ v × w`.
+/// 
+/// The result approximates the rotation vector of applying `w` first and then `v`
+/// (the quaternion product `exp(v/2) * exp(w/2)`), with an error of third order
+/// in the size of the two vectors.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::baker_campbell_hausdorff_2;
+/// 
+/// let composed: [f32; 3] = baker_campbell_hausdorff_2::<f32, [f32; 3]>([0.02, 0.0, 0.0], [0.0, 0.04, 0.0]);
+/// 
+/// assert!( (composed[0] - 0.02).abs() < 0.000001 );
+/// assert!( (composed[1] - 0.04).abs() < 0.000001 );
+/// assert!( (composed[2] - 0.0004).abs() < 0.000001 );
+/// ```
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+pub fn baker_campbell_hausdorff_2<Num, Out>(v: impl Vector<Num>, w: impl Vector<Num>) -> Out
+where 
+    Num: Axis,
+    Out: VectorConstructor<Num>,
+{
+    let half = crate::consts::half::<Num>();
+    Out::new_vector(
+        v.x() + w.x() + half * (v.y() * w.z() - v.z() * w.y()),
+        v.y() + w.y() + half * (v.z() * w.x() - v.x() * w.z()),
+        v.z() + w.z() + half * (v.x() * w.y() - v.y() * w.x()),
+    )
+}
@@ -1,7 +1,97 @@
 
 use super::*;
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+/// Applies a function of the complex plane along the quaternion's own imaginary axis.
+/// 
+/// The quaternion `r + v` is read as the complex number `r + |v| i`,
+/// `function` gets its real and imaginary parts and gives back the ones of the result,
+/// and the imaginary part of the result is put back along `v / |v|`.
+/// 
+/// This is how every quaternion function that's a power series with real coefficients
+/// (like the trigonometric ones) is calculated.
+/// 
+/// On the real axis (`|v| == 0`) the result is real,
+/// so those give back exactly what the real function gives back.
+#[inline]
+fn lift_complex<Num, Out>(quaternion: impl Quaternion<Num>, function: impl crate::core::ops::FnOnce(Num, Num) -> (Num, Num)) -> Out
+where
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    let len = (quaternion.i()*quaternion.i() + quaternion.j()*quaternion.j() + quaternion.k()*quaternion.k()).sqrt();
+    let (real, imaginary) = function(quaternion.r(), len);
+    let factor = if len == Num::ZERO { Num::ZERO } else { imaginary / len };
+    Out::new_quat(
+        real,
+        quaternion.i() * factor,
+        quaternion.j() * factor,
+        quaternion.k() * factor,
+    )
+}
+
+/// Same as [`lift_complex`] for a function giving back two complex numbers,
+/// so the length of the vector part is only calculated once.
+#[inline]
+fn lift_complex_pair<Num, OutA, OutB>(
+    quaternion: impl Quaternion<Num>,
+    function: impl crate::core::ops::FnOnce(Num, Num) -> ((Num, Num), (Num, Num)),
+) -> (OutA, OutB)
+where
+    Num: Axis,
+    OutA: QuaternionConstructor<Num>,
+    OutB: QuaternionConstructor<Num>,
+{
+    let len = (quaternion.i()*quaternion.i() + quaternion.j()*quaternion.j() + quaternion.k()*quaternion.k()).sqrt();
+    let ((real_a, imaginary_a), (real_b, imaginary_b)) = function(quaternion.r(), len);
+    let (factor_a, factor_b) = if len == Num::ZERO {
+        (Num::ZERO, Num::ZERO)
+    } else {
+        (imaginary_a / len, imaginary_b / len)
+    };
+    (
+        OutA::new_quat(real_a, quaternion.i() * factor_a, quaternion.j() * factor_a, quaternion.k() * factor_a),
+        OutB::new_quat(real_b, quaternion.i() * factor_b, quaternion.j() * factor_b, quaternion.k() * factor_b),
+    )
+}
+
+/// Divides two complex numbers (given as real and imaginary parts).
+/// 
+/// Dividing by a real number divides both parts by it,
+/// so a division by real zero gives back infinities instead of NaN.
+#[inline]
+fn complex_div<Num: Axis>((a, b): (Num, Num), (c, d): (Num, Num)) -> (Num, Num) {
+    if d == Num::ZERO {
+        return (a / c, b / c);
+    }
+    let abs_squared = c * c + d * d;
+    ((a * c + b * d) / abs_squared, (b * c - a * d) / abs_squared)
+}
+
+#[inline]
+fn complex_sin<Num: Axis>(x: Num, y: Num) -> (Num, Num) {
+    let (sin, cos) = x.sin_cos();
+    (sin * y.cosh(), cos * y.sinh())
+}
+
+#[inline]
+fn complex_cos<Num: Axis>(x: Num, y: Num) -> (Num, Num) {
+    let (sin, cos) = x.sin_cos();
+    (cos * y.cosh(), -sin * y.sinh())
+}
+
+#[inline]
+fn complex_sinh<Num: Axis>(x: Num, y: Num) -> (Num, Num) {
+    let (sin, cos) = y.sin_cos();
+    (x.sinh() * cos, x.cosh() * sin)
+}
+
+#[inline]
+fn complex_cosh<Num: Axis>(x: Num, y: Num) -> (Num, Num) {
+    let (sin, cos) = y.sin_cos();
+    (x.cosh() * cos, x.sinh() * sin)
+}
+
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Calculates the sinus of a quaternion.
 pub fn sin<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
 where
@@ -9,179 +99,236 @@ where
     Out: QuaternionConstructor<Num>,
 {
     // refrence: https://math.stackexchange.com/questions/1499095/how-to-calculate-sin-cos-tan-of-a-quaternion
-    let abs_vec = Num::sqrt(quaternion.i()*quaternion.i() + quaternion.j()*quaternion.j() + quaternion.k()*quaternion.k());
-    let vec_scalar = quaternion.r().cos() * abs_vec.sinh() / abs_vec;
-    Out::new_quat(
-        quaternion.r().sin() * abs_vec.cosh(), 
-        quaternion.i() * vec_scalar, 
-        quaternion.j() * vec_scalar, 
-        quaternion.k() * vec_scalar,
-    )
+    lift_complex(quaternion, complex_sin)
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Calculates the hyperbolic sinus of a quaternion.
 pub fn sinh<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
 where
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    let exp = exp::<Num, Q<Num>>(quaternion);
-    scale(sub::<Num, Q<Num>>(&exp, inv::<Num, Q<Num>>(&exp)), Num::from_f64(0.5))
+    lift_complex(quaternion, complex_sinh)
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Calculates the secant of a quaternion.
+/// 
+/// The secant of a real quaternion is exactly `1 / cos(r)`
+/// (so the secant of the origin is exactly the identity).
 pub fn sec<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
 where
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    inv(&cos::<Num, Q<Num>>(quaternion))
+    lift_complex(quaternion, |x, y| complex_div((Num::ONE, Num::ZERO), complex_cos(x, y)))
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
-/// Calculates the secant of a quaternion.
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Calculates the hyperbolic secant of a quaternion.
+/// 
+/// The hyperbolic secant of a real quaternion is exactly `1 / cosh(r)`
+/// (so the hyperbolic secant of the origin is exactly the identity).
 pub fn sech<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
 where
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    inv(&cosh::<Num, Q<Num>>(quaternion))
+    lift_complex(quaternion, |x, y| complex_div((Num::ONE, Num::ZERO), complex_cosh(x, y)))
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
-/// Calculates the  cosinus of a quaternion.
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Calculates the cosinus of a quaternion.
 pub fn cos<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
 where
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
     // refrence: https://math.stackexchange.com/questions/1499095/how-to-calculate-sin-cos-tan-of-a-quaternion
-    // If you find a paper on this please add it here (or modify the code + add it here if it uses a diferent equasion)
-    let abs_vec = Num::sqrt(quaternion.i()*quaternion.i() + quaternion.j()*quaternion.j() + quaternion.k()*quaternion.k());
-    let vec_scalar = - quaternion.r().sin() * abs_vec.sinh() / abs_vec;
-    Out::new_quat(
-        quaternion.r().cos() * abs_vec.cosh(), 
-        quaternion.i() * vec_scalar, 
-        quaternion.j() * vec_scalar, 
-        quaternion.k() * vec_scalar,
-    )
+    lift_complex(quaternion, complex_cos)
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Calculates the hyperbolic cosinus of a quaternion.
 pub fn cosh<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
 where
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    let exp = exp::<Num, Q<Num>>(quaternion);
-    scale(&add::<Num, Q<Num>>(&exp, &inv::<Num, Q<Num>>(&exp)), Num::from_f64(0.5))
+    lift_complex(quaternion, complex_cosh)
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Calculates the cosecant of a quaternion.
+/// 
+/// The cosecant of a real quaternion is exactly `1 / sin(r)`
+/// (so the cosecant of the origin is infinite instead of NaN).
 pub fn csc<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
 where
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    inv(&sin::<Num, Q<Num>>(quaternion))
+    lift_complex(quaternion, |x, y| complex_div((Num::ONE, Num::ZERO), complex_sin(x, y)))
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
-/// Calculates the secant of a quaternion.
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Calculates the hyperbolic cosecant of a quaternion.
+/// 
+/// The hyperbolic cosecant of a real quaternion is exactly `1 / sinh(r)`.
 pub fn csch<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
 where
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    inv(&sinh::<Num, Q<Num>>(quaternion))
+    lift_complex(quaternion, |x, y| complex_div((Num::ONE, Num::ZERO), complex_sinh(x, y)))
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Calculates the sinus and cosinus of a quaternion at once.
+/// 
+/// Gives back exactly the same as [`sin`] and [`cos`], but the length of the vector part,
+/// the sinus and cosinus of the real part and the hyperbolic sinus and cosinus
+/// of the length are only calculated once for both.
+/// 
+/// To get them as different types use [`sin_cos_split`].
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{sin_cos, sin, cos};
+/// 
+/// let quat: [f32; 4] = [0.5, 1.0, -0.25, 0.75];
+/// let (sin_q, cos_q): ([f32; 4], [f32; 4]) = sin_cos::<f32, [f32; 4]>(quat);
+/// 
+/// assert_eq!( sin_q, sin::<f32, [f32; 4]>(quat) );
+/// assert_eq!( cos_q, cos::<f32, [f32; 4]>(quat) );
+/// ```
 pub fn sin_cos<Num, Out>(quaternion: impl Quaternion<Num>) -> (Out, Out)
 where
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    // refrence: https://math.stackexchange.com/questions/1499095/how-to-calculate-sin-cos-tan-of-a-quaternion
-    let abs_vec = Num::sqrt(quaternion.i()*quaternion.i() + quaternion.j()*quaternion.j() + quaternion.k()*quaternion.k());
-    let vec_scalar = abs_vec.sinh() / abs_vec;
-    let vec_scalar_cos = quaternion.r().cos() * vec_scalar;
-    let vec_scalar_sin = quaternion.r().sin() * - vec_scalar;
-    let abs_vec_cosh = abs_vec.cosh();
-    (
-        Out::new_quat(
-            quaternion.r().sin() * abs_vec_cosh, 
-            quaternion.i() * vec_scalar_sin, 
-            quaternion.j() * vec_scalar_sin, 
-            quaternion.k() * vec_scalar_sin,
-        ),
-        Out::new_quat(
-            quaternion.r().cos() * abs_vec_cosh, 
-            quaternion.i() * vec_scalar_cos, 
-            quaternion.j() * vec_scalar_cos, 
-            quaternion.k() * vec_scalar_cos,
-        ),
-    )
+    sin_cos_split::<Num, Out, Out>(quaternion)
+}
+
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Same as [`sin_cos`], but the sinus and cosinus can be given back as different types.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{sin_cos_split, sin, cos};
+/// 
+/// let quat: [f32; 4] = [0.5, 1.0, -0.25, 0.75];
+/// let (sin_q, cos_q): ([f32; 4], (f32, [f32; 3])) = sin_cos_split::<f32, [f32; 4], (f32, [f32; 3])>(quat);
+/// 
+/// assert_eq!( sin_q, sin::<f32, [f32; 4]>(quat) );
+/// assert_eq!( cos_q, cos::<f32, (f32, [f32; 3])>(quat) );
+/// ```
+pub fn sin_cos_split<Num, OutS, OutC>(quaternion: impl Quaternion<Num>) -> (OutS, OutC)
+where
+    Num: Axis,
+    OutS: QuaternionConstructor<Num>,
+    OutC: QuaternionConstructor<Num>,
+{
+    lift_complex_pair(quaternion, |x, y| {
+        let (sin, cos) = x.sin_cos();
+        let (sinh, cosh) = (y.sinh(), y.cosh());
+        ((sin * cosh, cos * sinh), (cos * cosh, -sin * sinh))
+    })
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Calculates the hyperbolic sinus and cosinus of a quaternion at once.
+/// 
+/// Gives back exactly the same as [`sinh`] and [`cosh`], sharing the work like [`sin_cos`] does.
+/// 
+/// To get them as different types use [`sinh_cosh_split`].
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{sinh_cosh, sinh, cosh};
+/// 
+/// let quat: [f32; 4] = [0.5, 1.0, -0.25, 0.75];
+/// let (sinh_q, cosh_q): ([f32; 4], [f32; 4]) = sinh_cosh::<f32, [f32; 4]>(quat);
+/// 
+/// assert_eq!( sinh_q, sinh::<f32, [f32; 4]>(quat) );
+/// assert_eq!( cosh_q, cosh::<f32, [f32; 4]>(quat) );
+/// ```
+pub fn sinh_cosh<Num, Out>(quaternion: impl Quaternion<Num>) -> (Out, Out)
+where
+    Num: Axis,
+    Out: QuaternionConstructor<Num>,
+{
+    sinh_cosh_split::<Num, Out, Out>(quaternion)
+}
+
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Same as [`sinh_cosh`], but the hyperbolic sinus and cosinus can be given back as different types.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{sinh_cosh_split, sinh, cosh};
+/// 
+/// let quat: [f32; 4] = [0.5, 1.0, -0.25, 0.75];
+/// let (sinh_q, cosh_q): ((f32, [f32; 3]), [f32; 4]) = sinh_cosh_split::<f32, (f32, [f32; 3]), [f32; 4]>(quat);
+/// 
+/// assert_eq!( sinh_q, sinh::<f32, (f32, [f32; 3])>(quat) );
+/// assert_eq!( cosh_q, cosh::<f32, [f32; 4]>(quat) );
+/// ```
+pub fn sinh_cosh_split<Num, OutS, OutC>(quaternion: impl Quaternion<Num>) -> (OutS, OutC)
+where
+    Num: Axis,
+    OutS: QuaternionConstructor<Num>,
+    OutC: QuaternionConstructor<Num>,
+{
+    lift_complex_pair(quaternion, |x, y| {
+        let (sin, cos) = y.sin_cos();
+        let (sinh, cosh) = (x.sinh(), x.cosh());
+        ((sinh * cos, cosh * sin), (cosh * cos, sinh * sin))
+    })
+}
+
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Calculates the tangent of a quaternion
 pub fn tan<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
 where
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    let (sin, cos) = sin_cos::<Num, Q<Num>>(quaternion);
-    div(&sin, &cos)
+    lift_complex(quaternion, |x, y| complex_div(complex_sin(x, y), complex_cos(x, y)))
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
-/// Calculates the sinus of a quaternion.
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Calculates the hyperbolic tangent of a quaternion.
 pub fn tanh<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
 where
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    let exp = exp::<Num, Q<Num>>(quaternion);
-    let inv = inv::<Num, Q<Num>>(&exp);
-    div(
-        &sub::<Num, Q<Num>>(&exp, &inv),
-        &add::<Num, Q<Num>>(&exp, &inv),
-    )
+    lift_complex(quaternion, |x, y| complex_div(complex_sinh(x, y), complex_cosh(x, y)))
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Calculates the cotangent of a quaternion
 pub fn cot<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
 where
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    let (sin, cos) = sin_cos::<Num, Q<Num>>(quaternion);
-    div(&cos, &sin)
+    lift_complex(quaternion, |x, y| complex_div(complex_cos(x, y), complex_sin(x, y)))
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
-/// Calculates the sinus of a quaternion.
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Calculates the hyperbolic cotangent of a quaternion.
 pub fn coth<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
 where
     Num: Axis,
     Out: QuaternionConstructor<Num>,
 {
-    let exp = exp::<Num, Q<Num>>(quaternion);
-    let inv = inv::<Num, Q<Num>>(&exp);
-    div(
-        &add::<Num, Q<Num>>(&exp, &inv),
-        &sub::<Num, Q<Num>>(&exp, &inv),
-    )
+    lift_complex(quaternion, |x, y| complex_div(complex_cosh(x, y), complex_sinh(x, y)))
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 #[inline]
 /// Calculates the arcsinus of a quaternion.
 pub fn asin<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
@@ -201,7 +348,7 @@ where
     )
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 #[inline]
 /// Calculates the arccosinus of a quaternion.
 pub fn acos<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
@@ -224,7 +371,7 @@ where
     )
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 #[inline]
 /// Calculates the arctangent of a quaternion.
 pub fn atan<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
@@ -241,7 +388,7 @@ where
     )
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 #[inline]
 /// Calculates the arccotangent of a quaternion.
 pub fn acot<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
@@ -258,7 +405,7 @@ where
     )
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 #[inline]
 /// Calculates the arcsecant of a quaternion.
 pub fn asec<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
@@ -269,7 +416,7 @@ where
     acos(inv::<Num, Q<Num>>(quaternion))
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 #[inline]
 /// Calculates the arccosecant of a quaternion.
 pub fn acsc<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
@@ -280,7 +427,7 @@ where
     asin(inv::<Num, Q<Num>>(quaternion))
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 #[inline]
 /// Calculates the inverse hyperbolic sinus of a quaternion.
 pub fn asinh<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
@@ -297,7 +444,7 @@ where
     ))
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 #[inline]
 /// Calculates the inverse hyperbolic cosinus of a quaternion.
 pub fn acosh<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
@@ -314,7 +461,7 @@ where
     ))
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 #[inline]
 /// Calculates the inverse hyperbolic tangent of a quaternion.
 pub fn atanh<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
@@ -327,11 +474,11 @@ where
             add::<Num, Q<Num>>(identity::<Num, Q<Num>>(), &quaternion),
             sub::<Num, Q<Num>>(identity::<Num, Q<Num>>(), &quaternion),
         )), 
-        Num::from_f64(0.5),
+        crate::consts::half::<Num>(),
     )
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 #[inline]
 /// Calculates the inverse hyperbolic cotangent of a quaternion.
 pub fn acoth<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
@@ -344,11 +491,11 @@ where
             add::<Num, Q<Num>>(&quaternion, identity::<Num, Q<Num>>()),
             sub::<Num, Q<Num>>(&quaternion, identity::<Num, Q<Num>>()),
         )), 
-        Num::from_f64(0.5),
+        crate::consts::half::<Num>(),
     )
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 #[inline]
 /// Calculates the inverse hyperbolic cosecant of a quaternion.
 pub fn acsch<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
@@ -359,7 +506,7 @@ where
     asinh(inv::<Num, Q<Num>>(quaternion))
 }
 
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 #[inline]
 /// Calculates the inverse hyperbolic secant of a quaternion.
 pub fn asech<Num, Out>(quaternion: impl Quaternion<Num>) -> Out
@@ -0,0 +1,312 @@
+/*!
+Component and projection functions for 3D vectors, alike the ones for quaternions.
+
+Every function takes any [`Vector`] and (where it gives back a vector)
+constructs any [`VectorConstructor`].
+
+| function                                        | gives back                                    |
+|-------------------------------------------------|-----------------------------------------------|
+| [`splat`]                                       | a vector with every component the same        |
+| [`with_x`], [`with_y`], [`with_z`]              | the vector with one component replaced        |
+| [`abs_components`]                              | the absolute value of every component         |
+| [`max_component`], [`min_component`]            | the largest/smallest component and its index  |
+| [`project_onto`], [`reject_from`]               | the parts along and perpendicular to a vector |
+| [`orthonormal_basis`]                           | two unit vectors perpendicular to a vector    |
+| [`are_parallel`], [`are_perpendicular`]         | if two vectors are parallel/perpendicular     |
+| [`angle_between`]                               | the angle between two vectors                 |
+ */
+
+use super::*;
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Constructs a vector with all three components equal to `value`.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::vec::splat;
+/// 
+/// assert_eq!( splat::<f32, [f32; 3]>(2.0), [2.0, 2.0, 2.0] );
+/// ```
+pub fn splat<Num, Out>(value: impl Scalar<Num>) -> Out
+where 
+    Num: Axis,
+    Out: VectorConstructor<Num>,
+{
+    let value: Num = value.scalar();
+    Out::new_vector(value, value, value)
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gives back the vector with its x component replaced by `x`.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::vec::with_x;
+/// 
+/// assert_eq!( with_x::<f32, [f32; 3]>([1.0, 2.0, 3.0], 5.0), [5.0, 2.0, 3.0] );
+/// ```
+pub fn with_x<Num, Out>(vector: impl Vector<Num>, x: impl Scalar<Num>) -> Out
+where 
+    Num: Axis,
+    Out: VectorConstructor<Num>,
+{
+    Out::new_vector(x.scalar(), vector.y(), vector.z())
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gives back the vector with its y component replaced by `y`.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::vec::with_y;
+/// 
+/// assert_eq!( with_y::<f32, [f32; 3]>([1.0, 2.0, 3.0], 5.0), [1.0, 5.0, 3.0] );
+/// ```
+pub fn with_y<Num, Out>(vector: impl Vector<Num>, y: impl Scalar<Num>) -> Out
+where 
+    Num: Axis,
+    Out: VectorConstructor<Num>,
+{
+    Out::new_vector(vector.x(), y.scalar(), vector.z())
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gives back the vector with its z component replaced by `z`.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::vec::with_z;
+/// 
+/// assert_eq!( with_z::<f32, [f32; 3]>([1.0, 2.0, 3.0], 5.0), [1.0, 2.0, 5.0] );
+/// ```
+pub fn with_z<Num, Out>(vector: impl Vector<Num>, z: impl Scalar<Num>) -> Out
+where 
+    Num: Axis,
+    Out: VectorConstructor<Num>,
+{
+    Out::new_vector(vector.x(), vector.y(), z.scalar())
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Calculates the absolute value of every component.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::vec::abs_components;
+/// 
+/// assert_eq!( abs_components::<f32, [f32; 3]>([-1.0, 2.0, -3.0]), [1.0, 2.0, 3.0] );
+/// ```
+pub fn abs_components<Num, Out>(vector: impl Vector<Num>) -> Out
+where 
+    Num: Axis,
+    Out: VectorConstructor<Num>,
+{
+    Out::new_vector(vector.x().abs(), vector.y().abs(), vector.z().abs())
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gets the largest component and its index (`0` for x, `1` for y, `2` for z).
+/// 
+/// On a tie the lowest index is given back.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::vec::max_component;
+/// 
+/// assert_eq!( max_component::<f32>([1.0, 3.0, -5.0]), (3.0, 1) );
+/// assert_eq!( max_component::<f32>([2.0, 2.0, 2.0]), (2.0, 0) );
+/// ```
+pub fn max_component<Num>(vector: impl Vector<Num>) -> (Num, usize)
+where 
+    Num: Axis,
+{
+    let mut max: (Num, usize) = (vector.x(), 0);
+    if vector.y() > max.0 {
+        max = (vector.y(), 1);
+    }
+    if vector.z() > max.0 {
+        max = (vector.z(), 2);
+    }
+    max
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gets the smallest component and its index (`0` for x, `1` for y, `2` for z).
+/// 
+/// On a tie the lowest index is given back.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::vec::min_component;
+/// 
+/// assert_eq!( min_component::<f32>([1.0, 3.0, -5.0]), (-5.0, 2) );
+/// assert_eq!( min_component::<f32>([2.0, 2.0, 2.0]), (2.0, 0) );
+/// ```
+pub fn min_component<Num>(vector: impl Vector<Num>) -> (Num, usize)
+where 
+    Num: Axis,
+{
+    let mut min: (Num, usize) = (vector.x(), 0);
+    if vector.y() < min.0 {
+        min = (vector.y(), 1);
+    }
+    if vector.z() < min.0 {
+        min = (vector.z(), 2);
+    }
+    min
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Calculates the part of `vector` that points along `onto`.
+/// 
+/// Projecting onto the zero vector gives back the zero vector
+/// (the zero vector is perpendicular to everything, see [`are_perpendicular`](super::are_perpendicular)).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::vec::project_onto;
+/// 
+/// assert_eq!( project_onto::<f32, [f32; 3]>([1.0, 2.0, 3.0], [0.0, 5.0, 0.0]), [0.0, 2.0, 0.0] );
+/// assert_eq!( project_onto::<f32, [f32; 3]>([1.0, 2.0, 3.0], [0.0, 0.0, 0.0]), [0.0, 0.0, 0.0] );
+/// ```
+pub fn project_onto<Num, Out>(vector: impl Vector<Num>, onto: impl Vector<Num>) -> Out
+where 
+    Num: Axis,
+    Out: VectorConstructor<Num>,
+{
+    let length_squared: Num = onto.x() * onto.x() + onto.y() * onto.y() + onto.z() * onto.z();
+    if length_squared == Num::ZERO {
+        return Out::new_vector(Num::ZERO, Num::ZERO, Num::ZERO);
+    }
+    let scale: Num = (vector.x() * onto.x() + vector.y() * onto.y() + vector.z() * onto.z()) / length_squared;
+    Out::new_vector(onto.x() * scale, onto.y() * scale, onto.z() * scale)
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Calculates the part of `vector` that is perpendicular to `from`
+/// (`vector - project_onto(vector, from)`).
+/// 
+/// Rejecting from the zero vector gives back `vector` itself.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::vec::reject_from;
+/// 
+/// assert_eq!( reject_from::<f32, [f32; 3]>([1.0, 2.0, 3.0], [0.0, 5.0, 0.0]), [1.0, 0.0, 3.0] );
+/// ```
+pub fn reject_from<Num, Out>(vector: impl Vector<Num>, from: impl Vector<Num>) -> Out
+where 
+    Num: Axis,
+    Out: VectorConstructor<Num>,
+{
+    let along: [Num; 3] = project_onto(&vector, from);
+    Out::new_vector(vector.x() - along[0], vector.y() - along[1], vector.z() - along[2])
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gets two unit vectors that are perpendicular to `vector` and to each other.
+/// 
+/// Together with the normalized `vector` they form a right handed basis
+/// (the cross product of the first and the second is the normalized `vector`).
+/// `vector` doesn't have to be normalized, the zero vector gives back the x and y axes.
+/// 
+/// Uses the construction from
+/// [Building an Orthonormal Basis, Revisited](https://graphics.pixar.com/library/OrthonormalB/paper.pdf)
+/// (Duff et al.), which stays accurate for every direction, including the axes.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::vec::orthonormal_basis;
+/// 
+/// let (first, second) = orthonormal_basis::<f32>([0.0, 0.0, 2.0]);
+/// 
+/// assert_eq!( first, [1.0, 0.0, 0.0] );
+/// assert_eq!( second, [0.0, 1.0, 0.0] );
+/// ```
+pub fn orthonormal_basis<Num>(vector: impl Vector<Num>) -> ([Num; 3], [Num; 3])
+where 
+    Num: Axis,
+{
+    let length: Num = (vector.x() * vector.x() + vector.y() * vector.y() + vector.z() * vector.z()).sqrt();
+    if length == Num::ZERO {
+        return ([Num::ONE, Num::ZERO, Num::ZERO], [Num::ZERO, Num::ONE, Num::ZERO]);
+    }
+    let [x, y, z]: [Num; 3] = [vector.x() / length, vector.y() / length, vector.z() / length];
+
+    let sign: Num = if z < Num::ZERO { -Num::ONE } else { Num::ONE };
+    let a: Num = -Num::ONE / (sign + z);
+    let b: Num = x * y * a;
+    (
+        [Num::ONE + sign * x * x * a, sign * b, -sign * x],
+        [b, sign + y * y * a, -y],
+    )
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Checks if two vectors are parallel (pointing the same or the opposite way).
+/// 
+/// Check [`quat::are_parallel`](super::are_parallel) for more info.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::vec::are_parallel;
+/// 
+/// assert!( are_parallel::<f32>([1.0, 2.0, 3.0], [-2.0, -4.0, -6.0]) );
+/// ```
+pub fn are_parallel<Num>(left: impl Vector<Num>, right: impl Vector<Num>) -> bool
+where 
+    Num: Axis,
+{
+    super::are_parallel(left, right)
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Checks if two vectors are perpendicular.
+/// 
+/// Check [`quat::are_perpendicular`](super::are_perpendicular) for more info.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::vec::are_perpendicular;
+/// 
+/// assert!( are_perpendicular::<f32>([1.0, 0.0, 0.0], [0.0, 3.0, 1.0]) );
+/// ```
+pub fn are_perpendicular<Num>(left: impl Vector<Num>, right: impl Vector<Num>) -> bool
+where 
+    Num: Axis,
+{
+    super::are_perpendicular(left, right)
+}
+
+#[inline]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
+/// Gets the angle between two vectors, from `0` to `π`.
+/// 
+/// Check [`quat::angle_between_vectors`](super::angle_between_vectors) for more info.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::vec::angle_between;
+/// # use core::f32::consts::FRAC_PI_2;
+/// 
+/// assert!( (angle_between::<f32, f32>([2.0, 0.0, 0.0], [0.0, 0.0, 5.0]) - FRAC_PI_2).abs() < 0.00001 );
+/// ```
+pub fn angle_between<Num, Out>(left: impl Vector<Num>, right: impl Vector<Num>) -> Out
+where 
+    Num: Axis,
+    Out: ScalarConstructor<Num>,
+{
+    super::angle_between_vectors(left, right)
+}
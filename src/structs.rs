@@ -8,12 +8,61 @@ pub use quat_struct::*;
 mod unit_struct;
 pub use unit_struct::*;
 
+mod angle_struct;
+pub use angle_struct::*;
+
+mod real_struct;
+pub use real_struct::*;
+
+mod quats_struct;
+pub use quats_struct::*;
+
+#[cfg(feature = "rotation")]
+mod homogeneous;
+#[cfg(feature = "rotation")]
+pub use homogeneous::*;
+
 #[cfg(feature = "std")]
 mod std_struct;
 #[cfg(feature = "std")]
 pub use std_struct::*;
 
+#[cfg(feature = "alloc")]
+mod quat_spline;
+#[cfg(feature = "alloc")]
+pub use quat_spline::*;
+
+#[cfg(feature = "matrix")]
+mod matrix_major;
+#[cfg(feature = "matrix")]
+pub use matrix_major::*;
+
+#[cfg(feature = "matrix")]
+mod matrix_adapters;
+#[cfg(feature = "matrix")]
+pub use matrix_adapters::*;
+
+#[cfg(feature = "rotation")]
+mod frames;
+#[cfg(feature = "rotation")]
+pub use frames::*;
+
+#[cfg(feature = "rotation")]
+mod cube_rotation;
+#[cfg(feature = "rotation")]
+pub use cube_rotation::*;
+
+#[cfg(feature = "rotation")]
+mod similarity;
+#[cfg(feature = "rotation")]
+pub use similarity::*;
+
 #[cfg(feature = "display")]
 mod quaternion_formatter;
 #[cfg(feature = "display")]
 pub use quaternion_formatter::*;
+
+#[cfg(feature = "display")]
+mod inline_string;
+#[cfg(feature = "display")]
+pub use inline_string::*;
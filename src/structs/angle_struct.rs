@@ -0,0 +1,177 @@
+use crate::Axis;
+use crate::Scalar;
+use crate::ScalarConstructor;
+use crate::core::ops::{Add, Sub, Mul, Div, Neg};
+
+/**
+An angle in radians.
+
+Every function that takes an angle as an [`impl Scalar`](Scalar) (like
+[`from_axis_angle`](crate::quat::from_axis_angle) or [`rotate_x`](crate::quat::rotate_x))
+accepts it (and [`Degrees`]), so the unit can be seen at the call site.
+
+# Example
+```
+# #[cfg(feature = "rotation")] {
+use quaternion_traits::quat::from_axis_angle;
+use quaternion_traits::structs::{Radians, Degrees};
+use core::f32::consts::FRAC_PI_2;
+
+let from_radians: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([0.0, 1.0, 0.0], Radians(FRAC_PI_2));
+let from_degrees: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([0.0, 1.0, 0.0], Degrees(90.0));
+
+for n in 0..4 {
+    assert!( (from_radians[n] - from_degrees[n]).abs() < 0.00001 );
+}
+# }
+```
+*/
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Radians<Num>(pub Num);
+
+/**
+An angle in degrees.
+
+It's turned into radians every time it's read as a [`Scalar`],
+see [`Radians`] for how it's used.
+
+# Example
+```
+use quaternion_traits::structs::{Radians, Degrees};
+use core::f32::consts::PI;
+
+let half_turn: Radians<f32> = Degrees(180.0).into();
+assert_eq!( half_turn, Radians(PI) );
+```
+*/
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Degrees<Num>(pub Num);
+
+impl<Num: Axis> Radians<Num> {
+    /// Creates a new angle in radians.
+    #[inline]
+    pub const fn new(angle: Num) -> Self {
+        Radians(angle)
+    }
+
+    /// Gets the angle in radians.
+    #[inline]
+    pub const fn get(self) -> Num {
+        self.0
+    }
+
+    /// Gets the angle in degrees.
+    #[inline]
+    pub fn to_degrees(self) -> Degrees<Num> {
+        Degrees(self.0 * Num::from_f64(360.0) / Num::TAU)
+    }
+}
+
+impl<Num: Axis> Degrees<Num> {
+    /// Creates a new angle in degrees.
+    #[inline]
+    pub const fn new(angle: Num) -> Self {
+        Degrees(angle)
+    }
+
+    /// Gets the angle in degrees.
+    #[inline]
+    pub const fn get(self) -> Num {
+        self.0
+    }
+
+    /// Gets the angle in radians.
+    #[inline]
+    pub fn to_radians(self) -> Radians<Num> {
+        Radians(self.0 * Num::TAU / Num::from_f64(360.0))
+    }
+}
+
+impl<Num: Axis> crate::core::convert::From<Degrees<Num>> for Radians<Num> {
+    #[inline]
+    fn from(angle: Degrees<Num>) -> Self {
+        angle.to_radians()
+    }
+}
+
+impl<Num: Axis> crate::core::convert::From<Radians<Num>> for Degrees<Num> {
+    #[inline]
+    fn from(angle: Radians<Num>) -> Self {
+        angle.to_degrees()
+    }
+}
+
+impl<Num: Axis> Scalar<Num> for Radians<Num> {
+    #[inline] fn scalar(&self) -> Num { self.0 }
+}
+
+impl<Num: Axis> Scalar<Num> for &Radians<Num> {
+    #[inline] fn scalar(&self) -> Num { self.0 }
+}
+
+impl<Num: Axis> Scalar<Num> for Degrees<Num> {
+    #[inline] fn scalar(&self) -> Num { self.to_radians().0 }
+}
+
+impl<Num: Axis> Scalar<Num> for &Degrees<Num> {
+    #[inline] fn scalar(&self) -> Num { self.to_radians().0 }
+}
+
+impl<Num: Axis> ScalarConstructor<Num> for Radians<Num> {
+    #[inline] fn new_scalar(axis: Num) -> Self { Radians(axis) }
+}
+
+impl<Num: Axis> ScalarConstructor<Num> for Degrees<Num> {
+    #[inline] fn new_scalar(axis: Num) -> Self { Radians(axis).to_degrees() }
+}
+
+macro_rules! impl_angle_ops {
+    ( $( $angle:ident ),+ ) => { $(
+        impl<Num: Axis> Add for $angle<Num> {
+            type Output = Self;
+            #[inline] fn add(self, other: Self) -> Self {
+                $angle(self.0 + other.0)
+            }
+        }
+
+        impl<Num: Axis> Sub for $angle<Num> {
+            type Output = Self;
+            #[inline] fn sub(self, other: Self) -> Self {
+                $angle(self.0 - other.0)
+            }
+        }
+
+        impl<Num: Axis> Neg for $angle<Num> {
+            type Output = Self;
+            #[inline] fn neg(self) -> Self {
+                $angle(-self.0)
+            }
+        }
+
+        impl<Num: Axis> Mul<Num> for $angle<Num> {
+            type Output = Self;
+            #[inline] fn mul(self, factor: Num) -> Self {
+                $angle(self.0 * factor)
+            }
+        }
+
+        impl<Num: Axis> Div<Num> for $angle<Num> {
+            type Output = Self;
+            #[inline] fn div(self, divisor: Num) -> Self {
+                $angle(self.0 / divisor)
+            }
+        }
+
+        /// How many times the other angle fits in this one.
+        impl<Num: Axis> Div for $angle<Num> {
+            type Output = Num;
+            #[inline] fn div(self, other: Self) -> Num {
+                self.0 / other.0
+            }
+        }
+    )+ };
+}
+
+impl_angle_ops!{ Radians, Degrees }
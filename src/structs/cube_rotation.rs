@@ -0,0 +1,239 @@
+use crate::{
+    Axis,
+    Quaternion,
+    UnitQuaternion,
+    QuaternionConstructor,
+    Scalar,
+};
+use crate::quat;
+use crate::core::option::Option;
+use crate::core::ops::Mul;
+
+const HALF: f64 = 0.5;
+const ROOT: f64 = crate::core::f64::consts::FRAC_1_SQRT_2;
+
+/// The quaternions of the rotations of the cube, in the order of [`CubeRotation::ALL`].
+const QUATS: [[f64; 4]; 24] = [
+    // the identity
+    [1.0, 0.0, 0.0, 0.0],
+    // half turns around the x, y and z axes
+    [0.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0],
+    [0.0, 0.0, 0.0, 1.0],
+    // quarter turns around the +x, -x, +y, -y, +z and -z axes
+    [ROOT, ROOT, 0.0, 0.0],
+    [ROOT, -ROOT, 0.0, 0.0],
+    [ROOT, 0.0, ROOT, 0.0],
+    [ROOT, 0.0, -ROOT, 0.0],
+    [ROOT, 0.0, 0.0, ROOT],
+    [ROOT, 0.0, 0.0, -ROOT],
+    // half turns around the diagonals of the faces
+    [0.0, ROOT, ROOT, 0.0],
+    [0.0, ROOT, -ROOT, 0.0],
+    [0.0, ROOT, 0.0, ROOT],
+    [0.0, ROOT, 0.0, -ROOT],
+    [0.0, 0.0, ROOT, ROOT],
+    [0.0, 0.0, ROOT, -ROOT],
+    // third turns around the diagonals of the cube
+    [HALF, HALF, HALF, HALF],
+    [HALF, HALF, HALF, -HALF],
+    [HALF, HALF, -HALF, HALF],
+    [HALF, HALF, -HALF, -HALF],
+    [HALF, -HALF, HALF, HALF],
+    [HALF, -HALF, HALF, -HALF],
+    [HALF, -HALF, -HALF, HALF],
+    [HALF, -HALF, -HALF, -HALF],
+];
+
+/// Finds the index of `q` (or `-q`) in [`QUATS`].
+const fn find(q: [f64; 4]) -> u8 {
+    let mut index: usize = 0;
+    while index < 24 {
+        let other: [f64; 4] = QUATS[index];
+        let dot: f64 = q[0] * other[0] + q[1] * other[1] + q[2] * other[2] + q[3] * other[3];
+        // two different rotations of the cube have a dot product of at most `1/√2`
+        if dot.abs() > 0.9 {
+            return index as u8;
+        }
+        index += 1;
+    }
+    crate::core::panic!("the rotations of the cube aren't closed")
+}
+
+/// `COMPOSE[a][b]` is the index of the rotation of `QUATS[a] * QUATS[b]`.
+const COMPOSE: [[u8; 24]; 24] = {
+    let mut table: [[u8; 24]; 24] = [[0; 24]; 24];
+    let mut a: usize = 0;
+    while a < 24 {
+        let [lr, li, lj, lk] = QUATS[a];
+        let mut b: usize = 0;
+        while b < 24 {
+            let [rr, ri, rj, rk] = QUATS[b];
+            table[a][b] = find([
+                lr * rr - li * ri - lj * rj - lk * rk,
+                lr * ri + li * rr + lj * rk - lk * rj,
+                lr * rj - li * rk + lj * rr + lk * ri,
+                lr * rk + li * rj - lj * ri + lk * rr,
+            ]);
+            b += 1;
+        }
+        a += 1;
+    }
+    table
+};
+
+/// `INVERSE[a]` is the index of the rotation of the conjugate of `QUATS[a]`.
+const INVERSE: [u8; 24] = {
+    let mut table: [u8; 24] = [0; 24];
+    let mut a: usize = 0;
+    while a < 24 {
+        let [r, i, j, k] = QUATS[a];
+        table[a] = find([r, -i, -j, -k]);
+        a += 1;
+    }
+    table
+};
+
+/**
+One of the 24 rotations that map an axis aligned cube onto itself
+(the orientations of a block in a voxel grid).
+
+Composing and inverting them are lookups in tables calculated at compile time,
+so they are exact and never drift.
+The quaternion of each one has components that are `0`, `±1/2`, `±1/√2` or `±1`
+(made with [`from_f64`](Axis::from_f64), so as exact as `Num` allows),
+with the first non-zero component positive.
+
+It implements [`Quaternion`] (and [`UnitQuaternion`]),
+so it can be given to any [`quat`](crate::quat) function.
+
+The rotations are indexed in this order:
+- `0`: the identity
+- `1..=3`: half turns around the x, y and z axes
+- `4..=9`: quarter turns around the +x, -x, +y, -y, +z and -z axes
+- `10..=15`: half turns around the diagonals of the faces
+- `16..=23`: third turns around the diagonals of the cube
+
+# Example
+```
+use quaternion_traits::structs::CubeRotation;
+use quaternion_traits::quat::{from_axis_angle, point_rotation};
+
+let turn: CubeRotation = CubeRotation::from_quat::<f32>(
+    from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], core::f32::consts::FRAC_PI_2)
+).unwrap();
+
+assert_eq!( turn * turn * turn * turn, CubeRotation::IDENTITY );
+assert_eq!( turn * turn.inverse(), CubeRotation::IDENTITY );
+assert_eq!( turn.to_quat::<f64, [f64; 4]>(), [core::f64::consts::FRAC_1_SQRT_2, 0.0, 0.0, core::f64::consts::FRAC_1_SQRT_2] );
+
+let x: [f32; 3] = point_rotation::<f32, [f32; 3]>(turn, [1.0, 0.0, 0.0]);
+assert!( x[0].abs() < 0.00001 && (x[1] - 1.0).abs() < 0.00001 && x[2].abs() < 0.00001 );
+
+// not a rotation of the cube
+assert_eq!( CubeRotation::from_quat::<f32>(from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 0.5)), None );
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+pub struct CubeRotation(u8);
+
+impl CubeRotation {
+    /// The rotation that doesn't rotate anything.
+    pub const IDENTITY: Self = CubeRotation(0);
+
+    /// All 24 rotations of the cube, in the order of their indices.
+    pub const ALL: [Self; 24] = {
+        let mut all: [Self; 24] = [CubeRotation(0); 24];
+        let mut index: u8 = 0;
+        while index < 24 {
+            all[index as usize] = CubeRotation(index);
+            index += 1;
+        }
+        all
+    };
+
+    /// Gets the rotation with the given index, or [`None`](Option::None) if it isn't smaller then `24`.
+    #[inline]
+    pub const fn new(index: u8) -> Option<Self> {
+        if index < 24 {
+            Option::Some(CubeRotation(index))
+        } else {
+            Option::None
+        }
+    }
+
+    /// The index of this rotation, always smaller then `24`.
+    #[inline]
+    pub const fn index(self) -> u8 {
+        self.0
+    }
+
+    /// Gets the rotation that undoes this one.
+    #[inline]
+    pub const fn inverse(self) -> Self {
+        CubeRotation(INVERSE[self.0 as usize])
+    }
+
+    /// Composes two rotations, like [`mul`](quat::mul) of their quaternions
+    /// (so `other` is applied first, then `self`).
+    /// 
+    /// Same as `self * other`, but usable in constants.
+    #[inline]
+    pub const fn compose(self, other: Self) -> Self {
+        CubeRotation(COMPOSE[self.0 as usize][other.0 as usize])
+    }
+
+    /// Gets the quaternion of this rotation.
+    #[inline]
+    pub fn to_quat<Num: Axis, Out: QuaternionConstructor<Num>>(self) -> Out {
+        Out::from_quat(self)
+    }
+
+    /// Snaps a unit quaternion to the rotation of the cube it represents.
+    /// 
+    /// Gives back [`None`](Option::None) if the quaternion isn't near
+    /// (by [`Num::EPS_TRIG`](Axis::EPS_TRIG)) the quaternion of any rotation of the cube
+    /// or its negative.
+    #[inline]
+    pub fn from_quat<Num: Axis>(quaternion: impl Quaternion<Num>) -> Option<Self> {
+        Self::from_quat_by(quaternion, Num::EPS_TRIG)
+    }
+
+    /// Snaps a unit quaternion to the rotation of the cube it represents,
+    /// if it's within `tolerance` of it (or of its negative).
+    pub fn from_quat_by<Num: Axis>(quaternion: impl Quaternion<Num>, tolerance: impl Scalar<Num>) -> Option<Self> {
+        let mut best: Self = CubeRotation(0);
+        let mut best_dot: Num = quat::abs_dot(&quaternion, best);
+        for rotation in &Self::ALL[1..] {
+            let dot: Num = quat::abs_dot(&quaternion, rotation);
+            if dot > best_dot {
+                best = *rotation;
+                best_dot = dot;
+            }
+        }
+        let snapped: [Num; 4] = quat::align_to(best, &quaternion);
+        if quat::is_near_by(&quaternion, snapped, tolerance) {
+            Option::Some(best)
+        } else {
+            Option::None
+        }
+    }
+}
+
+impl Mul for CubeRotation {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.compose(other)
+    }
+}
+
+impl<Num: Axis> Quaternion<Num> for CubeRotation {
+    #[inline] fn r(&self) -> Num { Num::from_f64(QUATS[self.0 as usize][0]) }
+    #[inline] fn i(&self) -> Num { Num::from_f64(QUATS[self.0 as usize][1]) }
+    #[inline] fn j(&self) -> Num { Num::from_f64(QUATS[self.0 as usize][2]) }
+    #[inline] fn k(&self) -> Num { Num::from_f64(QUATS[self.0 as usize][3]) }
+}
+
+impl<Num: Axis> UnitQuaternion<Num> for CubeRotation {}
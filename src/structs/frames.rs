@@ -0,0 +1,158 @@
+use crate::{
+    Axis,
+    Quaternion,
+    UnitQuaternion,
+    QuaternionConstructor,
+};
+use crate::quat;
+
+/**
+Marks a rotation as an orientation in the world frame,
+aka it turns vectors from the local frame of an object into the world frame.
+
+Used with [`LocalFrame`] and the [`compose_world`], [`compose_local`] and [`local_between`]
+functions so that multiplying rotations in the wrong order doesn't compile.
+
+It implements [`Quaternion`] (and [`UnitQuaternion`]) by reading the wrapped value,
+so it can still be given to any [`quat`](crate::quat) function.
+
+# Example
+```
+use quaternion_traits::quat::{from_axis_angle, point_rotation};
+use quaternion_traits::structs::{WorldFrame, LocalFrame, compose_world};
+
+// an object turned a quarter turn around the world z axis
+let orientation = WorldFrame(from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], core::f32::consts::FRAC_PI_2));
+// pitching the object around its own x axis
+let pitch = LocalFrame(from_axis_angle::<f32, [f32; 4]>([1.0, 0.0, 0.0], core::f32::consts::FRAC_PI_2));
+
+let new_orientation: WorldFrame<[f32; 4]> = compose_world::<f32, _, _, _>(orientation, pitch);
+
+// the pitch turns the object's own z axis onto its own -y axis,
+// which after the quarter turn is the world x axis
+let up: [f32; 3] = point_rotation::<f32, [f32; 3]>(new_orientation, [0.0, 0.0, 1.0]);
+assert!( (up[0] - 1.0).abs() < 0.00001 && up[1].abs() < 0.00001 && up[2].abs() < 0.00001 );
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct WorldFrame<Q>(pub Q);
+
+/**
+Marks a rotation as being expressed in the local frame of an object,
+aka it rotates around the object's own axes.
+
+Check [`WorldFrame`] for more info.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LocalFrame<Q>(pub Q);
+
+impl<Num: Axis, Q: Quaternion<Num>> Quaternion<Num> for WorldFrame<Q> {
+    #[inline(always)] fn r(&self) -> Num { self.0.r() }
+    #[inline(always)] fn i(&self) -> Num { self.0.i() }
+    #[inline(always)] fn j(&self) -> Num { self.0.j() }
+    #[inline(always)] fn k(&self) -> Num { self.0.k() }
+}
+
+impl<Num: Axis, Q: Quaternion<Num>> Quaternion<Num> for LocalFrame<Q> {
+    #[inline(always)] fn r(&self) -> Num { self.0.r() }
+    #[inline(always)] fn i(&self) -> Num { self.0.i() }
+    #[inline(always)] fn j(&self) -> Num { self.0.j() }
+    #[inline(always)] fn k(&self) -> Num { self.0.k() }
+}
+
+impl<Num: Axis, U: UnitQuaternion<Num>> UnitQuaternion<Num> for WorldFrame<U> {}
+
+impl<Num: Axis, U: UnitQuaternion<Num>> UnitQuaternion<Num> for LocalFrame<U> {}
+
+/// Applies a rotation around the local axes of an object to its world orientation.
+/// 
+/// Is equivalent to `mul(world, local)`.
+/// 
+/// The arguments can't be swapped by mistake:
+/// ```compile_fail,E0308
+/// use quaternion_traits::structs::{WorldFrame, LocalFrame, compose_world};
+/// 
+/// let world = WorldFrame([1.0_f32, 0.0, 0.0, 0.0]);
+/// let local = LocalFrame([0.0_f32, 1.0, 0.0, 0.0]);
+/// 
+/// let wrong: WorldFrame<[f32; 4]> = compose_world::<f32, _, _, _>(local, world);
+/// ```
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::mul;
+/// use quaternion_traits::structs::{WorldFrame, LocalFrame, compose_world};
+/// 
+/// let world: [f32; 4] = [0.5, 0.5, 0.5, 0.5];
+/// let local: [f32; 4] = [0.0, 1.0, 0.0, 0.0];
+/// 
+/// let composed: WorldFrame<[f32; 4]> = compose_world::<f32, _, _, _>(WorldFrame(world), LocalFrame(local));
+/// assert_eq!( composed.0, mul::<f32, [f32; 4]>(world, local) );
+/// ```
+#[inline]
+pub fn compose_world<Num, World, Local, Out>(world: WorldFrame<World>, local: LocalFrame<Local>) -> WorldFrame<Out>
+where 
+    Num: Axis,
+    World: Quaternion<Num>,
+    Local: Quaternion<Num>,
+    Out: QuaternionConstructor<Num>,
+{
+    WorldFrame(quat::mul::<Num, Out>(world.0, local.0))
+}
+
+/// Chains two rotations around local axes,
+/// the second one is around the axes of the object after the first one was applied.
+/// 
+/// Is equivalent to `mul(first, second)`, so for any world orientation
+/// `compose_world(compose_world(world, first), second) = compose_world(world, compose_local(first, second))`.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::is_near;
+/// use quaternion_traits::structs::{WorldFrame, LocalFrame, compose_world, compose_local};
+/// 
+/// let world = WorldFrame([0.5_f32, 0.5, 0.5, 0.5]);
+/// let first = LocalFrame([0.0_f32, 1.0, 0.0, 0.0]);
+/// let second = LocalFrame([0.6_f32, 0.0, 0.8, 0.0]);
+/// 
+/// let one_by_one: WorldFrame<[f32; 4]> = compose_world::<f32, _, _, _>(compose_world::<f32, _, _, [f32; 4]>(world, first), second);
+/// let chained: WorldFrame<[f32; 4]> = compose_world::<f32, _, _, _>(world, compose_local::<f32, _, _, [f32; 4]>(first, second));
+/// assert!( is_near::<f32>(one_by_one, chained) );
+/// ```
+#[inline]
+pub fn compose_local<Num, First, Second, Out>(first: LocalFrame<First>, second: LocalFrame<Second>) -> LocalFrame<Out>
+where 
+    Num: Axis,
+    First: Quaternion<Num>,
+    Second: Quaternion<Num>,
+    Out: QuaternionConstructor<Num>,
+{
+    LocalFrame(quat::mul::<Num, Out>(first.0, second.0))
+}
+
+/// Gets the rotation around the local axes of `from` that turns it into `to`,
+/// aka the `local` for which `compose_world(from, local) = to`.
+/// 
+/// Is equivalent to [`solve_left(from, to)`](crate::quat::solve_left).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::is_near;
+/// use quaternion_traits::structs::{WorldFrame, LocalFrame, compose_world, local_between};
+/// 
+/// let from = WorldFrame([0.5_f32, 0.5, 0.5, 0.5]);
+/// let to = WorldFrame([0.0_f32, 0.6, 0.0, 0.8]);
+/// 
+/// let local: LocalFrame<[f32; 4]> = local_between::<f32, _, _, _>(from, to);
+/// assert!( is_near::<f32>(compose_world::<f32, _, _, [f32; 4]>(from, local), to) );
+/// ```
+#[inline]
+pub fn local_between<Num, From, To, Out>(from: WorldFrame<From>, to: WorldFrame<To>) -> LocalFrame<Out>
+where 
+    Num: Axis,
+    From: Quaternion<Num>,
+    To: Quaternion<Num>,
+    Out: QuaternionConstructor<Num>,
+{
+    LocalFrame(quat::solve_left::<Num, Out>(from.0, to.0))
+}
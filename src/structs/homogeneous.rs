@@ -0,0 +1,85 @@
+use crate::{
+    Axis,
+    Vector,
+    VectorConstructor,
+};
+
+/**
+A point in homogeneous coordinates (`[x, y, z, w]`), the way graphics pipelines pass them around.
+
+As a [`Vector`] it reads as the 3D point it stands for: x, y and z are divided by w
+on every access, so a point with `w == 2` acts like the one with half the coordinates.
+A direction (`w == 0`) has no 3D point, reading one gives infinities
+(NaN for the components that are `0` as well).
+To rotate directions too use [`rotate_homogeneous`](crate::quat::rotate_homogeneous),
+which doesn't divide.
+
+Constructing one (through [`VectorConstructor`]) gives back a point with `w == 1`,
+and the default is the origin (`[0, 0, 0, 1]`).
+
+# Example
+```
+use quaternion_traits::quat::point_rotation;
+use quaternion_traits::structs::Homogeneous;
+
+let point: Homogeneous<f32> = Homogeneous([2.0, 4.0, 6.0, 2.0]);
+let half_turn: [f32; 4] = [0.0, 0.0, 0.0, 1.0];
+
+let rotated: Homogeneous<f32> = point_rotation::<f32, Homogeneous<f32>>(half_turn, point);
+assert_eq!( rotated, Homogeneous([-1.0, -2.0, 3.0, 1.0]) );
+
+let direction: Homogeneous<f32> = Homogeneous([1.0, 0.0, 0.0, 0.0]);
+assert!( quaternion_traits::traits::Vector::<f32>::x(&direction).is_infinite() );
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Homogeneous<Num>(pub [Num; 4]);
+
+impl<Num: Axis> Homogeneous<Num> {
+    /// Creates a new homogeneous point.
+    #[inline]
+    pub const fn new(x: Num, y: Num, z: Num, w: Num) -> Self {
+        Homogeneous([x, y, z, w])
+    }
+
+    /// Gets the w coordinate.
+    #[inline]
+    pub const fn w(&self) -> Num {
+        self.0[3]
+    }
+
+    /// Gets the homogeneous coordinates, without dividing them by w.
+    #[inline]
+    pub const fn get(self) -> [Num; 4] {
+        self.0
+    }
+}
+
+impl<Num: Axis> crate::core::default::Default for Homogeneous<Num> {
+    /// The origin, with `w == 1`.
+    #[inline]
+    fn default() -> Self {
+        Homogeneous([Num::ZERO, Num::ZERO, Num::ZERO, Num::ONE])
+    }
+}
+
+impl<Num: Axis> crate::core::convert::From<[Num; 4]> for Homogeneous<Num> {
+    #[inline]
+    fn from(coordinates: [Num; 4]) -> Self {
+        Homogeneous(coordinates)
+    }
+}
+
+impl<Num: Axis> Vector<Num> for Homogeneous<Num> {
+    #[inline] fn x(&self) -> Num { self.0[0] / self.0[3] }
+    #[inline] fn y(&self) -> Num { self.0[1] / self.0[3] }
+    #[inline] fn z(&self) -> Num { self.0[2] / self.0[3] }
+}
+
+impl<Num: Axis> VectorConstructor<Num> for Homogeneous<Num> {
+    /// Constructs the point with `w == 1`.
+    #[inline]
+    fn new_vector(x: Num, y: Num, z: Num) -> Self {
+        Homogeneous([x, y, z, Num::ONE])
+    }
+}
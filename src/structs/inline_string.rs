@@ -0,0 +1,167 @@
+use crate::core::fmt;
+use crate::core::ops::Deref;
+use crate::core::result::Result;
+use crate::core::str::from_utf8;
+
+/**
+A string of at most `N` bytes stored inline (no allocation), for showing quaternions without `alloc`.
+
+Made by [`to_inline_string`](crate::quat::to_inline_string),
+or written into through [`fmt::Write`] like a [`String`](https://doc.rust-lang.org/std/string/struct.String.html).
+
+It's always valid UTF-8: only whole `&str`s are ever added,
+and one that doesn't fit in the space left is rejected without adding any of it
+(so a multi-byte character is never cut in half).
+
+# Example
+```
+use quaternion_traits::quat::to_inline_string;
+use quaternion_traits::structs::{InlineString, QuaternionFormat as QF};
+
+let string: InlineString<32> = to_inline_string::<f32, 32>([1.0, -2.0, 0.0, 0.5], QF::DEFAULT).unwrap();
+
+assert_eq!( string, "1 - 2i + 0.5k" );
+assert_eq!( string.len(), 13 );
+assert!( string.starts_with("1 -") );
+```
+*/
+#[derive(Clone, Copy)]
+pub struct InlineString<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> InlineString<N> {
+    /// An empty string.
+    pub const EMPTY: Self = InlineString { bytes: [0; N], len: 0 };
+
+    /// Creates an empty string.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::EMPTY
+    }
+
+    /// The most bytes the string can hold.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Gets the string as a [`str`].
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // only whole `str`s are ever copied in, so this is never an error
+        from_utf8(&self.bytes[..self.len]).unwrap_or("")
+    }
+
+    /// Adds `string` to the end, or gives back [`BufferTooSmall`]
+    /// (without adding anything) if it doesn't fit.
+    pub fn push_str(&mut self, string: &str) -> Result<(), BufferTooSmall> {
+        let end: usize = self.len + string.len();
+        if end > N {
+            return Result::Err(BufferTooSmall { capacity: N, needed: end });
+        }
+        self.bytes[self.len..end].copy_from_slice(string.as_bytes());
+        self.len = end;
+        Result::Ok(())
+    }
+
+    /// Empties the string.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> crate::core::default::Default for InlineString<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl<const N: usize> Deref for InlineString<N> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> crate::core::convert::AsRef<str> for InlineString<N> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> fmt::Write for InlineString<N> {
+    #[inline]
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.push_str(string).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const N: usize> fmt::Display for InlineString<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Debug for InlineString<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize, const M: usize> crate::core::cmp::PartialEq<InlineString<M>> for InlineString<N> {
+    #[inline]
+    fn eq(&self, other: &InlineString<M>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> crate::core::cmp::Eq for InlineString<N> {}
+
+impl<const N: usize> crate::core::cmp::PartialEq<str> for InlineString<N> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> crate::core::cmp::PartialEq<&str> for InlineString<N> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> crate::core::hash::Hash for InlineString<N> {
+    #[inline]
+    fn hash<H: crate::core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+/// The error given back when a string doesn't fit in an [`InlineString`].
+/// 
+/// Given back by [`InlineString::push_str`] and [`to_inline_string`](crate::quat::to_inline_string).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BufferTooSmall {
+    /// The most bytes the string can hold.
+    pub capacity: usize,
+    /// The bytes the whole string would have needed.
+    pub needed: usize,
+}
+
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::core::write!(f, "the string needs {} bytes, but only {} fit in the buffer", self.needed, self.capacity)
+    }
+}
+
+impl crate::core::error::Error for BufferTooSmall {}
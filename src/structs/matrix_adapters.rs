@@ -0,0 +1,173 @@
+use crate::core::option::Option;
+use crate::core::marker::Copy;
+use crate::core::ops::Fn;
+use crate::core::fmt;
+use crate::Matrix;
+
+/**
+A matrix computed element by element by a function of the `(row, col)` position.
+
+Lets procedurally built matrices (or ones stored in some other layout)
+be given directly to matrix functions without building an array first.
+
+The function is only called with positions inside the matrix by
+the functions of this crate, [`get`](Matrix::get) gives back
+[`None`](Option::None) for positions outside of it without calling the function.
+
+# Example
+```
+use quaternion_traits::quat::from_matrix_3;
+use quaternion_traits::structs::FnMatrix;
+
+let table: [[f32; 3]; 3] = [
+    [0.0, 1.0, 0.0],
+    [-1.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0],
+];
+
+assert_eq!(
+    from_matrix_3::<f32, f32, [f32; 4]>(FnMatrix::new(|row, col| table[row][col])),
+    from_matrix_3::<f32, f32, [f32; 4]>(table),
+);
+
+// the identity matrix without storing it
+let identity = FnMatrix::new(|row, col| if row == col { 1.0_f32 } else { 0.0 });
+assert_eq!( from_matrix_3::<f32, f32, [f32; 4]>(identity), [1.0, 0.0, 0.0, 0.0] );
+```
+*/
+#[derive(Clone, Copy)]
+pub struct FnMatrix<F>(pub F);
+
+impl<F> FnMatrix<F> {
+    /// Wraps a function of the `(row, col)` position.
+    /// 
+    /// Same as `FnMatrix(function)`, but lets closures be written without annotating the arguments.
+    #[inline]
+    pub const fn new<T>(function: F) -> Self
+    where F: Fn(usize, usize) -> T
+    {
+        FnMatrix(function)
+    }
+}
+
+impl<F> fmt::Debug for FnMatrix<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FnMatrix").finish_non_exhaustive()
+    }
+}
+
+impl<T, F: Fn(usize, usize) -> T, const N: usize> Matrix<T, N> for FnMatrix<F> {
+    #[inline]
+    fn get_unchecked( &self, row: usize, col: usize ) -> T {
+        (self.0)(row, col)
+    }
+}
+
+/**
+A NxN matrix borrowed from a flat row-major slice, with `stride` elements inbetween
+the starts of two rows.
+
+Useful for reading a NxN block out of a larger matrix, eg: the top left 3x3 rotation
+part of a row-major 4x4 transform is `MatrixView::<f32, 3>::new(&transform, 4)`,
+and a block starting at another column is a view of the slice starting there.
+
+# Example
+```
+use quaternion_traits::quat::from_matrix_3;
+use quaternion_traits::structs::MatrixView;
+use quaternion_traits::traits::Matrix;
+
+// a 3x4 matrix, rotation and translation
+let transform: [f32; 12] = [
+    0.0, 1.0, 0.0, 5.0,
+    -1.0, 0.0, 0.0, 6.0,
+    0.0, 0.0, 1.0, 7.0,
+];
+
+let rotation: MatrixView<f32, 3> = MatrixView::new(&transform, 4).unwrap();
+assert_eq!( rotation.get(1, 0), Some(-1.0) );
+assert_eq!( rotation.get(0, 3), None );
+
+assert_eq!(
+    from_matrix_3::<f32, f32, [f32; 4]>(rotation),
+    from_matrix_3::<f32, f32, [f32; 4]>([[0.0, 1.0, 0.0], [-1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]),
+);
+
+// the slice is too short for 3 rows 4 apart
+assert!( MatrixView::<f32, 3>::new(&transform[..10], 4).is_none() );
+```
+*/
+#[derive(Clone, Copy)]
+pub struct MatrixView<'a, T, const N: usize> {
+    data: &'a [T],
+    stride: usize,
+}
+
+impl<'a, T, const N: usize> MatrixView<'a, T, N> {
+    /// Creates a view of a NxN matrix in a row-major slice with `stride` elements per row.
+    /// 
+    /// Returns [`None`](Option::None) if the stride is smaller then `N`
+    /// (the rows would overlap) or if the slice is too short to hold all `N` rows.
+    pub fn new(data: &'a [T], stride: usize) -> Option<Self> {
+        if stride < N {
+            return Option::None;
+        }
+        let needed: usize = match N {
+            0 => 0,
+            _ => stride.checked_mul(N - 1)?.checked_add(N)?,
+        };
+        if data.len() < needed {
+            return Option::None;
+        }
+        Option::Some(MatrixView { data, stride })
+    }
+
+    /// Creates a view of a NxN matrix stored without any gaps (`N * N` elements, row after row).
+    /// 
+    /// Returns [`None`](Option::None) if the slice is shorter then `N * N`.
+    #[inline]
+    pub fn contiguous(data: &'a [T]) -> Option<Self> {
+        Self::new(data, N)
+    }
+
+    /// The number of elements inbetween the starts of two rows.
+    #[inline]
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Gets a row of the matrix as a slice,
+    /// or [`None`](Option::None) if `row` isn't smaller then `N`.
+    #[inline]
+    pub fn row(&self, row: usize) -> Option<&'a [T]> {
+        if row < N {
+            let start: usize = row * self.stride;
+            self.data.get(start..start + N)
+        } else {
+            Option::None
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for MatrixView<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        for row in 0..N {
+            if let Option::Some(row) = self.row(row) {
+                list.entry(&row);
+            }
+        }
+        list.finish()
+    }
+}
+
+impl<T: Copy, const N: usize> Matrix<T, N> for MatrixView<'_, T, N> {
+    #[inline]
+    fn get_unchecked( &self, row: usize, col: usize ) -> T {
+        // with a stride the flat index of an out of bounds position can be in the slice
+        if row >= N || col >= N {
+            crate::core::panic!("Out of index operation! Got ({row}, {col}), accepting at most ({}, {})", N.saturating_sub(1), N.saturating_sub(1))
+        }
+        self.data[row * self.stride + col]
+    }
+}
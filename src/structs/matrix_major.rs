@@ -0,0 +1,114 @@
+use crate::core::option::Option;
+use crate::core::marker::Copy;
+use crate::core::array::from_fn;
+use crate::{
+    Matrix,
+    MatrixConstructor,
+};
+
+/**
+Marks a matrix as being stored row after row.
+
+All the matrix types in this crate are already read this way
+(`matrix[row][col]`, and `[T; N * N]` arrays are stored row after row),
+so this wrapper does nothing except make the convention visible at the call site.
+
+# Example
+```
+use quaternion_traits::quat::from_matrix_4;
+use quaternion_traits::structs::RowMajor;
+
+let buffer: [f32; 16] = [
+    1.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 1.0, 0.0,
+    0.0, -1.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 1.0,
+];
+
+assert_eq!(
+    from_matrix_4::<f32, f32, [f32; 4]>(RowMajor(buffer)),
+    from_matrix_4::<f32, f32, [f32; 4]>(buffer),
+);
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RowMajor<M>(pub M);
+
+/**
+Marks a matrix as being stored column after column.
+
+Reading `ColMajor(matrix)` at (row, col) reads `matrix` at (col, row),
+and constructing a `ColMajor<M>` transposes the matrix before giving it to `M`,
+so column-major buffers (eg: the `[f32; 16]` ones used by OpenGL) can be
+given directly to matrix functions.
+
+# Example
+```
+use quaternion_traits::quat::from_matrix_4;
+use quaternion_traits::structs::{ColMajor, RowMajor};
+
+let buffer: [f32; 16] = [
+    1.0, 0.0, 0.0, 0.0,
+    0.0, 0.0, 1.0, 0.0,
+    0.0, -1.0, 0.0, 0.0,
+    0.0, 0.0, 0.0, 1.0,
+];
+
+let row: [f32; 4] = from_matrix_4::<f32, f32, [f32; 4]>(RowMajor(buffer));
+let col: [f32; 4] = from_matrix_4::<f32, f32, [f32; 4]>(ColMajor(buffer));
+
+// reading the transpose of a rotation gives back the inverse rotation
+assert_eq!( col, [row[0], -row[1], -row[2], -row[3]] );
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ColMajor<M>(pub M);
+
+impl<T, M: Matrix<T, N>, const N: usize> Matrix<T, N> for RowMajor<M> {
+    #[inline]
+    fn get_unchecked( &self, row: usize, col: usize ) -> T {
+        self.0.get_unchecked(row, col)
+    }
+
+    #[inline]
+    fn get( &self, row: usize, col: usize ) -> Option<T> {
+        self.0.get(row, col)
+    }
+
+    #[inline]
+    fn to_array( &self ) -> [[T; N]; N] {
+        self.0.to_array()
+    }
+}
+
+impl<T, M: MatrixConstructor<T, N>, const N: usize> MatrixConstructor<T, N> for RowMajor<M> {
+    #[inline]
+    fn new_matrix(matrix: [[T; N]; N]) -> Self {
+        RowMajor(M::new_matrix(matrix))
+    }
+}
+
+impl<T: Copy, M: Matrix<T, N>, const N: usize> Matrix<T, N> for ColMajor<M> {
+    #[inline]
+    fn get_unchecked( &self, row: usize, col: usize ) -> T {
+        self.0.get_unchecked(col, row)
+    }
+
+    #[inline]
+    fn get( &self, row: usize, col: usize ) -> Option<T> {
+        self.0.get(col, row)
+    }
+
+    #[inline]
+    fn to_array( &self ) -> [[T; N]; N] {
+        let matrix: [[T; N]; N] = self.0.to_array();
+        from_fn(|row| from_fn(|col| matrix[col][row]))
+    }
+}
+
+impl<T: Copy, M: MatrixConstructor<T, N>, const N: usize> MatrixConstructor<T, N> for ColMajor<M> {
+    #[inline]
+    fn new_matrix(matrix: [[T; N]; N]) -> Self {
+        ColMajor(M::new_matrix(from_fn(|row| from_fn(|col| matrix[col][row]))))
+    }
+}
@@ -0,0 +1,290 @@
+use crate::alloc::vec::Vec;
+use crate::core::iter::IntoIterator;
+use crate::Axis;
+use crate::Quaternion;
+use crate::Scalar;
+use crate::quat;
+
+/// How a [`QuatSpline`] behaves for times outside of its keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SplineEnds {
+    /// Times before the first key give the first key
+    /// and times after the last key give the last key.
+    #[default]
+    Clamp,
+    /// The keys repeat every `last time - first time`.
+    ///
+    /// The last key is treated as the same frame as the first one
+    /// (so it should be the same rotation), it only marks where the loop ends.
+    Loop,
+}
+
+/**
+A rotation track made out of `(time, quaternion)` keys.
+
+The keys are kept sorted by time and are interpolated using
+[squad](https://www.geometrictools.com/Documentation/Quaternions.pdf)
+with Catmull–Rom like control points, so the track passes through every key
+and has a continuous angular velocity when the keys are evenly spaced in time.
+
+Keys are normalized when inserted and their signs get flipped (which doesn't change the rotation)
+so that each key is on the same hemisphere as the one before it,
+this way sampling inbetween `q` and `-q` stays at `q` instead of going all the way around.
+
+# Example
+```
+# #[cfg(feature = "rotation")] {
+use quaternion_traits::quat::{from_axis_angle, is_near_by};
+use quaternion_traits::structs::{QuatSpline, SplineEnds};
+
+let mut spline: QuatSpline<f32> = QuatSpline::new(SplineEnds::Clamp);
+for key in [0, 3, 1, 2] {
+    spline.insert(key as f32, from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], key as f32));
+}
+
+let halfway: [f32; 4] = from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], 1.5);
+assert!( is_near_by::<f32>(spline.sample(1.5), halfway, 0.0001) );
+
+let velocity: [f32; 3] = spline.velocity(1.5);
+assert!( (velocity[2] - 1.0).abs() < 0.001 );
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuatSpline<Num: Axis> {
+    keys: Vec<(Num, [Num; 4])>,
+    ends: SplineEnds,
+}
+
+impl<Num: Axis> QuatSpline<Num> {
+    /// Creates a new spline with no keys.
+    #[inline]
+    pub const fn new(ends: SplineEnds) -> Self {
+        QuatSpline { keys: Vec::new(), ends }
+    }
+
+    /// Creates a new spline from `(time, quaternion)` keys, they don't need to be sorted.
+    #[inline]
+    pub fn from_keys<Time, Key>(ends: SplineEnds, keys: impl IntoIterator<Item = (Time, Key)>) -> Self
+    where
+        Time: Scalar<Num>,
+        Key: Quaternion<Num>,
+    {
+        let mut spline: Self = QuatSpline::new(ends);
+        for (time, key) in keys {
+            spline.insert(time, key);
+        }
+        spline
+    }
+
+    /// Adds a key to the spline, replacing the key that is at the exact same time if there is one.
+    ///
+    /// The key gets normalized and (if needed) negated to be on the same hemisphere as the key before it,
+    /// the keys after it get negated as needed as well.
+    ///
+    /// The time should not be NaN.
+    pub fn insert(&mut self, time: impl Scalar<Num>, quaternion: impl Quaternion<Num>) {
+        let time: Num = time.scalar();
+        let key: [Num; 4] = quat::normalize(quaternion);
+        let index: usize = self.keys.partition_point(|(key_time, _)| *key_time <= time);
+        let index: usize = if index > 0 && self.keys[index - 1].0 == time {
+            self.keys[index - 1].1 = key;
+            index - 1
+        } else {
+            self.keys.insert(index, (time, key));
+            index
+        };
+        for at in crate::core::cmp::max(index, 1)..self.keys.len() {
+            self.keys[at].1 = quat::enforce_continuity(self.keys[at - 1].1, self.keys[at].1);
+        }
+    }
+
+    /// The keys of this spline sorted by time.
+    #[inline]
+    pub fn keys(&self) -> &[(Num, [Num; 4])] {
+        &self.keys
+    }
+
+    /// The amount of keys in this spline.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Checks if this spline has no keys.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// How this spline behaves outside of its keys.
+    #[inline]
+    pub fn ends(&self) -> SplineEnds {
+        self.ends
+    }
+
+    /// Changes how this spline behaves outside of its keys.
+    #[inline]
+    pub fn set_ends(&mut self, ends: SplineEnds) {
+        self.ends = ends;
+    }
+
+    /// Gets the rotation at the given time.
+    ///
+    /// Sampling exactly at the time of a key gives back that key.
+    /// If the spline has no keys this gives back a quaternion of NaN's.
+    pub fn sample(&self, time: impl Scalar<Num>) -> [Num; 4] {
+        let (index, at) = match self.segment(time.scalar()) {
+            crate::core::option::Option::Some(segment) => segment,
+            crate::core::option::Option::None => return [Num::NAN; 4],
+        };
+        if self.keys.len() == 1 {
+            return self.keys[0].1;
+        }
+        let index: isize = index as isize;
+
+        let q1: [Num; 4] = self.key(index);
+        let q2: [Num; 4] = quat::enforce_continuity(q1, self.key(index + 1));
+        let q0: [Num; 4] = quat::enforce_continuity(q1, self.key(index - 1));
+        let q3: [Num; 4] = quat::enforce_continuity(q2, self.key(index + 2));
+
+        let s1: [Num; 4] = control_point(q0, q1, q2);
+        let s2: [Num; 4] = control_point(q1, q2, q3);
+
+        let two: Num = Num::from_u8(2);
+        slerp(
+            slerp(q1, q2, at),
+            slerp(s1, s2, at),
+            two * at * (Num::ONE - at),
+        )
+    }
+
+    /// Gets the angular velocity at the given time
+    /// (the axis of rotation scaled by the speed in radians per unit of time).
+    ///
+    /// The velocity is given in the same frame as the samples rotate points in,
+    /// so that `dq/dt = ½ · ω · q`.
+    ///
+    /// This is found numerically, using the log map of the rotation inbetween two
+    /// samples very close to the given time.
+    /// When clamping, times outside of the keys have no velocity.
+    pub fn velocity(&self, time: impl Scalar<Num>) -> [Num; 3] {
+        let time: Num = time.scalar();
+        let zero: [Num; 3] = [Num::ZERO; 3];
+        let (index, _) = match self.segment(time) {
+            crate::core::option::Option::Some(segment) => segment,
+            crate::core::option::Option::None => return [Num::NAN; 3],
+        };
+        if self.keys.len() == 1 {
+            return zero;
+        }
+
+        let step: Num = (self.keys[index + 1].0 - self.keys[index].0) * Num::ERROR.sqrt();
+        let (mut step_before, mut step_after) = (time - step, time + step);
+        if self.ends == SplineEnds::Clamp {
+            let (start, end) = (self.keys[0].0, self.keys[self.keys.len() - 1].0);
+            if step_before < start { step_before = start }
+            if step_after > end { step_after = end }
+            if step_after <= step_before {
+                return zero;
+            }
+        }
+
+        let before: [Num; 4] = self.sample(step_before);
+        // the samples can have opposite signs across the end of a loop
+        let after: [Num; 4] = quat::enforce_continuity(before, self.sample(step_after));
+        let difference: [Num; 4] = quat::mul(after, quat::conj::<Num, [Num; 4]>(before));
+        let scale: Num = Num::from_u8(2) / (step_after - step_before);
+        let [i, j, k] = log_unit(difference);
+        [i * scale, j * scale, k * scale]
+    }
+
+    /// Gets the index of the key at the start of the segment
+    /// that contains the given time and how far along that segment the time is (from 0 to 1).
+    fn segment(&self, time: Num) -> crate::core::option::Option<(usize, Num)> {
+        use crate::core::option::Option::{Some, None};
+
+        let len: usize = self.keys.len();
+        if len == 0 {
+            return None;
+        }
+        if len == 1 {
+            return Some((0, Num::ZERO));
+        }
+        let (start, end) = (self.keys[0].0, self.keys[len - 1].0);
+
+        let time: Num = match self.ends {
+            SplineEnds::Clamp => {
+                if time <= start { return Some((0, Num::ZERO)) }
+                if time >= end { return Some((len - 2, Num::ONE)) }
+                time
+            },
+            SplineEnds::Loop => {
+                let period: Num = end - start;
+                let mut offset: Num = (time - start) % period;
+                if offset < Num::ZERO { offset = offset + period }
+                if offset >= period || offset.is_nan() { offset = Num::ZERO }
+                start + offset
+            },
+        };
+
+        let index: usize = self.keys.partition_point(|(key_time, _)| *key_time <= time);
+        let index: usize = if index == 0 { 0 } else if index >= len { len - 2 } else { index - 1 };
+        let (from, to) = (self.keys[index].0, self.keys[index + 1].0);
+        Some((index, (time - from) / (to - from)))
+    }
+
+    /// Gets the key at the given index, handling indexes outside of the keys based on [`SplineEnds`].
+    fn key(&self, index: isize) -> [Num; 4] {
+        let len: isize = self.keys.len() as isize;
+        let index: isize = match self.ends {
+            SplineEnds::Clamp => if index < 0 { 0 } else if index >= len { len - 1 } else { index },
+            // the last key is the same frame as the first one
+            SplineEnds::Loop => if index < 0 { index + len - 1 } else if index >= len { index - len + 1 } else { index },
+        };
+        self.keys[index as usize].1
+    }
+}
+
+/// The squad control point of `current` with the Catmull–Rom like tangent
+/// going from `previous` to `next`.
+fn control_point<Num: Axis>(previous: [Num; 4], current: [Num; 4], next: [Num; 4]) -> [Num; 4] {
+    let inverse: [Num; 4] = quat::conj(current);
+    let [ni, nj, nk] = log_unit(quat::mul::<Num, [Num; 4]>(inverse, next));
+    let [pi, pj, pk] = log_unit(quat::mul::<Num, [Num; 4]>(inverse, previous));
+    let quarter: Num = Num::ONE / Num::from_u8(4);
+    quat::mul(current, exp_unit([
+        -(ni + pi) * quarter,
+        -(nj + pj) * quarter,
+        -(nk + pk) * quarter,
+    ]))
+}
+
+/// Spherical interpolation that doesn't take the shortest path,
+/// squad needs the path inbetween its control points as is.
+fn slerp<Num: Axis>(from: [Num; 4], to: [Num; 4], at: Num) -> [Num; 4] {
+    let [i, j, k] = log_unit(quat::mul::<Num, [Num; 4]>(quat::conj::<Num, [Num; 4]>(from), to));
+    quat::mul(from, exp_unit([i * at, j * at, k * at]))
+}
+
+/// The log map of a unit quaternion (half the angle times the axis).
+fn log_unit<Num: Axis>(quaternion: [Num; 4]) -> [Num; 3] {
+    let [r, i, j, k] = quaternion;
+    let length: Num = (i * i + j * j + k * k).sqrt();
+    if length == Num::ZERO {
+        return [Num::ZERO; 3];
+    }
+    let factor: Num = length.atan2(r) / length;
+    [i * factor, j * factor, k * factor]
+}
+
+/// The inverse of [`log_unit`].
+fn exp_unit<Num: Axis>([i, j, k]: [Num; 3]) -> [Num; 4] {
+    let angle: Num = (i * i + j * j + k * k).sqrt();
+    if angle == Num::ZERO {
+        return [Num::ONE, Num::ZERO, Num::ZERO, Num::ZERO];
+    }
+    let (sin, cos) = angle.sin_cos();
+    let factor: Num = sin / angle;
+    [cos, i * factor, j * factor, k * factor]
+}
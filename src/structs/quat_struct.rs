@@ -4,11 +4,16 @@ use crate::Quaternion;
 use crate::QuaternionConsts;
 use crate::QuaternionConstructor;
 use crate::QuaternionMethods;
+use crate::Scalar;
+use crate::Vector;
 use crate::quat;
 #[cfg(feature = "std")]
 use crate::structs::Std;
 #[allow(unused_imports)]
 use crate::core::option::Option;
+// the `rkyv` derives use prelude names
+#[cfg(feature = "rkyv")]
+use crate::core::marker::Sized;
 
 #[allow(unused_imports)]
 use crate::core::ops::{
@@ -38,6 +43,7 @@ If the `std` feature is enabled the default is set to `Std<f32>`, otherwise it's
 #[repr(transparent)]
 #[cfg(not(feature = "std"))]
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "rkyv", derive(crate::rkyv::Archive, crate::rkyv::Serialize, crate::rkyv::Deserialize))]
 pub struct Quat<Num: Axis = f32, T = (Num, [Num; 3])> {
     /// The quaternion held by this struct.
     pub quat: T,
@@ -61,6 +67,7 @@ If the `std` feature is enabled the default is set to `Std<f32>`, otherwise it's
 #[repr(transparent)]
 #[cfg(feature = "std")]
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "rkyv", derive(crate::rkyv::Archive, crate::rkyv::Serialize, crate::rkyv::Deserialize))]
 pub struct Quat<Num: Axis = crate::structs::Std<f32>, T = (Num, [Num; 3])> {
     /// The quaternion held by this struct.
     pub quat: T,
@@ -143,6 +150,15 @@ impl<Num: Axis, T> Quat<Num, T> {
     {
         self.quat.clone()
     }
+
+    #[inline]
+    /// Gets the wrapped quaternion as a `(w, [x, y, z])` tuple,
+    /// the representation used by the [quaternion](https://crates.io/crates/quaternion) crate.
+    pub fn to_wxyz_tuple(&self) -> (Num, [Num; 3])
+    where T: Quaternion<Num>
+    {
+        (self.quat.r(), [self.quat.i(), self.quat.j(), self.quat.k()])
+    }
 }
 
 impl<Num: Axis, T: Quaternion<Num>> Quaternion<Num> for Quat<Num, T> {
@@ -168,6 +184,8 @@ impl<Num: Axis, T: QuaternionConsts<Num>> QuaternionConsts<Num> for Quat<Num, T>
     const UNIT_K: Self = Quat::new(T::UNIT_K);
 }
 
+impl<Num: Axis, T: QuaternionConstructor<Num>> crate::QuaternionFloatConsts<Num> for Quat<Num, T> {}
+
 impl<Num: Axis, T> crate::core::ops::Deref for Quat<Num, T> {
     type Target = T;
     #[inline]
@@ -176,6 +194,45 @@ impl<Num: Axis, T> crate::core::ops::Deref for Quat<Num, T> {
     }
 }
 
+impl<Num: Axis, T> crate::core::convert::AsRef<T> for Quat<Num, T> {
+    #[inline] fn as_ref(&self) -> &T {
+        &self.quat
+    }
+}
+
+impl<Num: Axis, T> crate::core::convert::AsMut<T> for Quat<Num, T> {
+    #[inline] fn as_mut(&mut self) -> &mut T {
+        &mut self.quat
+    }
+}
+
+/// The comparisons of [`Quat`] go through the [`Quaternion`] values,
+/// so they only agree with the ones of `T` if `T` compares its values the same way
+/// (which is the case for every quaternion type of `core`).
+impl<Num: Axis, T> crate::core::borrow::Borrow<T> for Quat<Num, T> {
+    #[inline] fn borrow(&self) -> &T {
+        &self.quat
+    }
+}
+
+impl<Num: Axis, T> crate::core::borrow::BorrowMut<T> for Quat<Num, T> {
+    #[inline] fn borrow_mut(&mut self) -> &mut T {
+        &mut self.quat
+    }
+}
+
+impl<Num: Axis, T: QuaternionConstructor<Num>> crate::core::convert::From<(Num, [Num; 3])> for Quat<Num, T> {
+    #[inline] fn from(quat: (Num, [Num; 3])) -> Self {
+        Quat::new(T::from_quat(quat))
+    }
+}
+
+impl<Num: Axis, T: Quaternion<Num>> crate::core::convert::From<Quat<Num, T>> for (Num, [Num; 3]) {
+    #[inline] fn from(quat: Quat<Num, T>) -> Self {
+        quat.to_wxyz_tuple()
+    }
+}
+
 impl<Num: Axis, T: crate::core::default::Default> crate::core::default::Default for Quat<Num, T> {
     #[inline] fn default() -> Self {
         Quat::new(T::default())
@@ -196,6 +253,61 @@ impl<Num: Axis, T: Quaternion<Num>, Other: Quaternion<Num>> crate::core::cmp::Pa
 }
 impl<Num: Axis + crate::core::cmp::Eq, T: Quaternion<Num> + crate::core::cmp::Eq> crate::core::cmp::Eq for Quat<Num, T> { }
 
+macro_rules! impl_reverse_eq_for_quat {
+    ( $( [ $( $generic:ident: $bound:ident ),+ ] $other:ty; )+ ) => { $(
+        impl<Num: Axis, T: Quaternion<Num>, $( $generic: $bound<Num> ),+> crate::core::cmp::PartialEq<Quat<Num, T>> for $other {
+            #[inline] fn eq(&self, other: &Quat<Num, T>) -> bool {
+                quat::eq(self, other)
+            }
+        }
+    )+ };
+}
+
+impl_reverse_eq_for_quat!{
+    [S: Scalar] [S; 4];
+    [S: Scalar, V: Vector] (S, V);
+    [R: Scalar, I: Scalar, J: Scalar, K: Scalar] (R, I, J, K);
+}
+
+/// Compares the real parts first, then the `i`, `j` and `k` ones.
+/// 
+/// The order itself is arbitrary (there is no natural order for quaternions),
+/// but it's total for quaternions without `NaN`s, so it can be used for sorting.
+/// There is no `Ord` implementation, as the float [`Axis`] types aren't `Ord` either.
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::structs::Quat;
+/// 
+/// let mut quats: [Quat<f32, [f32; 4]>; 3] = [
+///     Quat::new([1.0, 0.0, 0.0, 0.0]),
+///     Quat::new([0.0, 5.0, 0.0, 0.0]),
+///     Quat::new([0.0, 1.0, 2.0, 0.0]),
+/// ];
+/// quats.sort_by(|a, b| a.partial_cmp(b).unwrap());
+/// 
+/// assert_eq!( quats[0], [0.0, 1.0, 2.0, 0.0] );
+/// assert_eq!( quats[2], [1.0, 0.0, 0.0, 0.0] );
+/// ```
+impl<Num: Axis, T: Quaternion<Num>, Other: Quaternion<Num>> crate::core::cmp::PartialOrd<Other> for Quat<Num, T> {
+    fn partial_cmp(&self, other: &Other) -> Option<crate::core::cmp::Ordering> {
+        use crate::core::cmp::Ordering;
+        let pairs: [(Num, Num); 4] = [
+            (self.r(), other.r()),
+            (self.i(), other.i()),
+            (self.j(), other.j()),
+            (self.k(), other.k()),
+        ];
+        for (left, right) in pairs {
+            match left.partial_cmp(&right)? {
+                Ordering::Equal => {},
+                ordering => return Option::Some(ordering),
+            }
+        }
+        Option::Some(Ordering::Equal)
+    }
+}
+
 impl<Num: Axis, T: QuaternionConstructor<Num>, Q: Quaternion<Num>> crate::core::iter::Sum<Q> for Quat<Num, T> {
     fn sum<I: crate::core::iter::Iterator<Item = Q>>(iter: I) -> Self {
         quat::sum(iter)
@@ -322,7 +434,6 @@ impl_basic_ops_for_quat!{
     using = div;
 }
 
-#[cfg(feature = "unstable")]
 impl_basic_ops_for_quat!{
     impl = Rem;
     func = rem;
@@ -452,6 +563,29 @@ impl<Num: Axis, T: QuaternionConsts<Num> + QuaternionMethods<Num>> ConstOne for
     const ONE: Self = Self::IDENTITY;
 }
 
+#[cfg(feature = "num-traits")]
+impl<Num: Axis, T: QuaternionConstructor<Num>> crate::num_traits::FloatConst for Quat<Num, T> {
+    #[inline] fn E() -> Self { <Self as crate::QuaternionFloatConsts<Num>>::E() }
+    #[inline] fn FRAC_1_PI() -> Self { <Self as crate::QuaternionFloatConsts<Num>>::FRAC_1_PI() }
+    #[inline] fn FRAC_1_SQRT_2() -> Self { <Self as crate::QuaternionFloatConsts<Num>>::FRAC_1_SQRT_2() }
+    #[inline] fn FRAC_2_PI() -> Self { <Self as crate::QuaternionFloatConsts<Num>>::FRAC_2_PI() }
+    #[inline] fn FRAC_2_SQRT_PI() -> Self { <Self as crate::QuaternionFloatConsts<Num>>::FRAC_2_SQRT_PI() }
+    #[inline] fn FRAC_PI_2() -> Self { <Self as crate::QuaternionFloatConsts<Num>>::FRAC_PI_2() }
+    #[inline] fn FRAC_PI_3() -> Self { <Self as crate::QuaternionFloatConsts<Num>>::FRAC_PI_3() }
+    #[inline] fn FRAC_PI_4() -> Self { <Self as crate::QuaternionFloatConsts<Num>>::FRAC_PI_4() }
+    #[inline] fn FRAC_PI_6() -> Self { <Self as crate::QuaternionFloatConsts<Num>>::FRAC_PI_6() }
+    #[inline] fn FRAC_PI_8() -> Self { <Self as crate::QuaternionFloatConsts<Num>>::FRAC_PI_8() }
+    #[inline] fn LN_10() -> Self { <Self as crate::QuaternionFloatConsts<Num>>::LN_10() }
+    #[inline] fn LN_2() -> Self { <Self as crate::QuaternionFloatConsts<Num>>::LN_2() }
+    #[inline] fn LOG10_E() -> Self { <Self as crate::QuaternionFloatConsts<Num>>::LOG10_E() }
+    #[inline] fn LOG2_E() -> Self { <Self as crate::QuaternionFloatConsts<Num>>::LOG2_E() }
+    #[inline] fn PI() -> Self { <Self as crate::QuaternionFloatConsts<Num>>::PI() }
+    #[inline] fn SQRT_2() -> Self { <Self as crate::QuaternionFloatConsts<Num>>::SQRT_2() }
+    #[inline] fn TAU() -> Self { <Self as crate::QuaternionFloatConsts<Num>>::TAU() }
+    #[inline] fn LOG10_2() -> Self { <Self as crate::QuaternionFloatConsts<Num>>::LOG10_2() }
+    #[inline] fn LOG2_10() -> Self { <Self as crate::QuaternionFloatConsts<Num>>::LOG2_10() }
+}
+
 #[cfg(feature = "num-traits")]
 #[cfg(feature = "unstable")]
 impl<Num: Axis + Number, T: QuaternionMethods<Num>> Number for Quat<Num, T> {
@@ -632,6 +766,21 @@ impl<Num: Axis, T: QuaternionMethods<Num>> crate::num_traits::Inv for &mut Quat<
     }
 }
 
+/// An archived quaternion can be read in place if the archived `T` is a quaternion
+/// (like the archived arrays and tuples of [f32] and [f64]).
+#[cfg(feature = "rkyv")]
+impl<Num, T> Quaternion<Num> for ArchivedQuat<Num, T>
+where
+    Num: Axis,
+    T: crate::rkyv::Archive,
+    crate::rkyv::Archived<T>: Quaternion<Num>,
+{
+    #[inline(always)] fn r(&self) -> Num { self.quat.r() }
+    #[inline(always)] fn i(&self) -> Num { self.quat.i() }
+    #[inline(always)] fn j(&self) -> Num { self.quat.j() }
+    #[inline(always)] fn k(&self) -> Num { self.quat.k() }
+}
+
 #[cfg(feature = "serde")]
 #[cfg(feature = "unstable")]
 use crate::serde::{
@@ -670,6 +819,10 @@ impl<Num: Axis, T: crate::UnitQuaternionConstructor<Num>> crate::UnitQuaternionC
         Option::Some(Quat::new(T::new_unit_quat(r, i, j, k)?))
     }
 
+    #[inline] fn new_unit_quat_or_err(r: Num, i: Num, j: Num, k: Num) -> crate::core::result::Result<Self, crate::traits::NotUnitError<Num>> {
+        crate::core::result::Result::Ok(Quat::new(T::new_unit_quat_or_err(r, i, j, k)?))
+    }
+
     #[inline] unsafe fn new_unit_quat_unchecked(r: Num, i: Num, j: Num, k: Num) -> Self {
         Quat::new( unsafe { T::new_unit_quat_unchecked(r, i, j, k) } )
     }
@@ -717,9 +870,18 @@ mod quat_struct_methods_impl {
                 fn div_reversed(self, other: impl Quaternion<Num>) -> Self;
 
                 #[expr(Quat::new($))]
-                #[cfg(feature = "unstable")]
+                fn solve_left(self, other: impl Quaternion<Num>) -> Self;
+
+                #[expr(Quat::new($))]
+                fn solve_right(self, other: impl Quaternion<Num>) -> Self;
+
+                #[expr(Quat::new($))]
                 fn rem(self, modulus: impl Quaternion<Num>) -> Self;
 
+                #[expr(Quat::new($))]
+                #[cfg(feature = "math_fns")]
+                fn rem_euclid(self, modulus: impl Quaternion<Num>) -> Self;
+
                 #[expr(Quat::new($))]
                 #[cfg(feature = "qol_fns")]
                 fn mul_add(self, factor: impl Quaternion<Num>, addend: impl Quaternion<Num>) -> Self;
@@ -733,6 +895,22 @@ mod quat_struct_methods_impl {
                 #[cfg(feature = "math_fns")]
                 fn sqrt(self) -> Self;
 
+                #[expr(Quat::new($))]
+                #[cfg(feature = "math_fns")]
+                fn sqrt_scaled(self) -> Self;
+
+                #[expr(Quat::new($))]
+                #[cfg(feature = "math_fns")]
+                fn sqrt_newton(self, initial_guess: impl Quaternion<Num>) -> Self;
+
+                #[expr(Quat::new($))]
+                #[cfg(feature = "math_fns")]
+                fn round_lipschitz(self) -> Self;
+
+                #[expr(Quat::new($))]
+                #[cfg(feature = "math_fns")]
+                fn round_hurwitz(self) -> Self;
+
                 #[expr(Quat::new($))]
                 fn neg(self) -> Self;
 
@@ -749,6 +927,14 @@ mod quat_struct_methods_impl {
                 #[cfg(any(feature = "math_fns", feature = "qol_fns"))]
                 fn square(self) -> Self;
 
+                #[expr(Quat::new($))]
+                #[cfg(any(feature = "math_fns", feature = "qol_fns"))]
+                fn square_add(self, addend: impl Quaternion<Num>) -> Self;
+
+                #[expr(Quat::new($))]
+                #[cfg(any(feature = "math_fns", feature = "qol_fns"))]
+                fn sym_mul(self, other: impl Quaternion<Num>) -> Self;
+
                 #[expr(Quat::new($))]
                 #[cfg(feature = "math_fns")]
                 fn exp(self) -> Self;
@@ -889,6 +1075,9 @@ mod quat_struct_methods_impl {
 
                 fn dot(self, other: impl Quaternion<Num>) -> Num;
 
+                #[expr(Quat::new($))]
+                fn aligned_with(self, other: impl Quaternion<Num>) -> Self;
+
 
                 fn is_scalar(self) -> bool;
 
@@ -904,12 +1093,19 @@ mod quat_struct_methods_impl {
 
                 fn is_near_by(self, other: impl Quaternion<Num>, error: impl Scalar<Num>) -> bool;
 
+                #[cfg(feature = "math_fns")]
+                fn is_lipschitz(self, error: impl Scalar<Num>) -> bool;
+
+                #[cfg(feature = "math_fns")]
+                fn is_hurwitz(self, error: impl Scalar<Num>) -> bool;
+
                 fn is_close(self, other: impl Quaternion<Num>) -> bool;
 
                 fn is_close_by(self, other: impl Quaternion<Num>, error: impl Scalar<Num>) -> bool;
                 
 
                 fn dist_euclid(self, other: impl Quaternion<Num>) -> Num;
+                fn dist_euclid_squared(self, other: impl Quaternion<Num>) -> Num;
                 fn dist_cosine(self, other: impl Quaternion<Num>) -> Num;
 
 
@@ -922,6 +1118,18 @@ mod quat_struct_methods_impl {
                 #[cfg(feature = "rotation")]
                 fn to_rotation<R: RotationConstructor<Num> >(self) -> R;
 
+                #[expr(Quat::new($))]
+                #[cfg(feature = "rotation")]
+                fn rotate_x(self, angle: impl Scalar<Num>) -> Self;
+
+                #[expr(Quat::new($))]
+                #[cfg(feature = "rotation")]
+                fn rotate_y(self, angle: impl Scalar<Num>) -> Self;
+
+                #[expr(Quat::new($))]
+                #[cfg(feature = "rotation")]
+                fn rotate_z(self, angle: impl Scalar<Num>) -> Self;
+
                 // #[inline]
                 #[cfg(feature = "math_fns")]
                 fn to_polar_form<Abs, Angle, UnitVec>(self) -> (Abs, Angle, UnitVec)
@@ -970,6 +1178,10 @@ mod quat_struct_methods_impl {
                 #[cfg(feature = "matrix")]
                 fn from_matrix_3<Elem: Scalar<Num>>(matrix: impl Matrix<Elem, 3>) -> Self;
                 
+                #[expr(Quat::new($))]
+                #[cfg(feature = "matrix")]
+                fn from_matrix_3_orthonormalized<Elem: Scalar<Num>>(matrix: impl Matrix<Elem, 3>) -> Self;
+                
                 #[expr(Quat::new($))]
                 #[cfg(feature = "matrix")]
                 fn from_matrix_4<Elem: Scalar<Num>>(matrix: impl Matrix<Elem, 4>) -> Self;
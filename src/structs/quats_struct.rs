@@ -0,0 +1,288 @@
+use crate::{
+    Axis,
+    Quaternion,
+    QuaternionConstructor,
+    Scalar,
+};
+use crate::quat;
+use crate::core::array::from_fn;
+use crate::core::ops::{FnMut, Index, IndexMut, Add, Mul};
+
+/**
+A fixed size batch of `N` quaternions stored on the stack.
+
+Sits between a single quaternion and heap allocated collections,
+so it works without the `alloc` feature.
+The elementwise methods are plain loops over the whole array
+without early returns, so the compiler can autovectorize them.
+
+This is a batch and not a quaternion, so it doesn't implement [`Quaternion`],
+use [`reduce_sum`](Quats::reduce_sum) or [`reduce_product`](Quats::reduce_product)
+to turn it into one.
+
+# Example
+```
+use quaternion_traits::structs::Quats;
+
+let left: Quats<f32, 2> = Quats::from([[1.0, 2.0, 3.0, 4.0], [0.0, 1.0, 0.0, 0.0]]);
+let right: Quats<f32, 2> = Quats::from([[0.5, 0.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0]]);
+
+let product: Quats<f32, 2> = left.mul(right);
+assert_eq!( product[0], [0.5, 1.0, 1.5, 2.0] );
+assert_eq!( product[1], [0.0, 0.0, 0.0, 1.0] );
+
+let sum: [f32; 4] = product.reduce_sum();
+assert_eq!( sum, [0.5, 1.0, 1.5, 3.0] );
+```
+*/
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quats<Num: Axis, const N: usize>(pub [[Num; 4]; N]);
+
+impl<Num: Axis, const N: usize> Quats<Num, N> {
+    /// Creates a new batch from `N` quaternions.
+    #[inline]
+    pub fn new(quats: [impl Quaternion<Num>; N]) -> Self {
+        Quats(quats.map(quat::convert_quat))
+    }
+
+    /// The quaternions held by this batch.
+    #[inline]
+    pub fn get(self) -> [[Num; 4]; N] {
+        self.0
+    }
+
+    /// Iterates over the quaternions in this batch.
+    #[inline]
+    pub fn iter(&self) -> crate::core::slice::Iter<'_, [Num; 4]> {
+        self.0.iter()
+    }
+
+    /// Iterates mutably over the quaternions in this batch.
+    #[inline]
+    pub fn iter_mut(&mut self) -> crate::core::slice::IterMut<'_, [Num; 4]> {
+        self.0.iter_mut()
+    }
+
+    /// Applies a function to every quaternion in the batch.
+    #[inline]
+    pub fn map<Q: Quaternion<Num>>(self, mut function: impl FnMut([Num; 4]) -> Q) -> Self {
+        Quats(self.0.map(|q| quat::convert_quat(function(q))))
+    }
+
+    /// Adds the quaternions of two batches pairwise.
+    /// 
+    /// Same as calling [`add`](quat::add) on each pair, also available as `+`.
+    #[inline]
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, other: Self) -> Self {
+        Quats(from_fn(|n| from_fn(|axis| self.0[n][axis] + other.0[n][axis])))
+    }
+
+    /// Multiplies the quaternions of two batches pairwise.
+    /// 
+    /// Same as calling [`mul`](quat::mul) on each pair, also available as `*`.
+    #[inline]
+    #[allow(clippy::should_implement_trait)]
+    pub fn mul(self, other: Self) -> Self {
+        Quats(from_fn(|n| {
+            let [lr, li, lj, lk] = self.0[n];
+            let [rr, ri, rj, rk] = other.0[n];
+            [
+                lr * rr - li * ri - lj * rj - lk * rk,
+                lr * ri + li * rr + lj * rk - lk * rj,
+                lr * rj - li * rk + lj * rr + lk * ri,
+                lr * rk + li * rj - lj * ri + lk * rr,
+            ]
+        }))
+    }
+
+    /// Scales every quaternion in the batch by the same factor.
+    /// 
+    /// Same as calling [`scale`](quat::scale) on each quaternion.
+    #[inline]
+    pub fn scale(self, factor: impl Scalar<Num>) -> Self {
+        let factor: Num = factor.scalar();
+        Quats(from_fn(|n| from_fn(|axis| self.0[n][axis] * factor)))
+    }
+
+    /// Normalizes every quaternion in the batch.
+    /// 
+    /// Same as calling [`normalize`](quat::normalize) on each quaternion,
+    /// so origin quaternions stay the origin.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        let squared: [Num; N] = from_fn(|n| {
+            let [r, i, j, k] = self.0[n];
+            r * r + i * i + j * j + k * k
+        });
+        let length: [Num; N] = from_fn(|n| Num::ONE / squared[n].sqrt());
+        let mut out: [[Num; 4]; N] = from_fn(|n| from_fn(|axis| self.0[n][axis] * length[n]));
+        // the origin and subnormal axes need the slower path
+        for n in 0..N {
+            if squared[n] == Num::ZERO || quat::count_subnormals(self.0[n]) > 0 {
+                out[n] = quat::normalize(self.0[n]);
+            }
+        }
+        Quats(out)
+    }
+
+    /// Adds all the quaternions in the batch together.
+    /// 
+    /// Uses compensated (Kahan–Babuška–Neumaier) summation on every axis,
+    /// so the rounding error doesn't grow with `N` like it does for [`sum`](quat::sum).
+    /// 
+    /// Returns the origin quaternion if the batch is empty.
+    /// 
+    /// # Example
+    /// ```
+    /// use quaternion_traits::structs::Quats;
+    /// 
+    /// let batch: Quats<f32, 3> = Quats::from([
+    ///     [1.0e8, 0.0, 0.0, 0.0],
+    ///     [1.0, 0.0, 0.0, 1.0],
+    ///     [-1.0e8, 0.0, 0.0, 0.0],
+    /// ]);
+    /// 
+    /// assert_eq!( batch.reduce_sum::<[f32; 4]>(), [1.0, 0.0, 0.0, 1.0] );
+    /// ```
+    pub fn reduce_sum<Out: QuaternionConstructor<Num>>(&self) -> Out {
+        let mut sum: [Num; 4] = [Num::ZERO; 4];
+        let mut compensation: [Num; 4] = [Num::ZERO; 4];
+        for q in &self.0 {
+            for axis in 0..4 {
+                let next: Num = sum[axis] + q[axis];
+                compensation[axis] = compensation[axis] + if sum[axis].abs() >= q[axis].abs() {
+                    (sum[axis] - next) + q[axis]
+                } else {
+                    (q[axis] - next) + sum[axis]
+                };
+                sum[axis] = next;
+            }
+        }
+        Out::new_quat(
+            sum[0] + compensation[0],
+            sum[1] + compensation[1],
+            sum[2] + compensation[2],
+            sum[3] + compensation[3],
+        )
+    }
+
+    /// Multiplies all the quaternions in the batch together, in order.
+    /// 
+    /// Neighbouring quaternions are multiplied in a balanced tree
+    /// (`((q0 * q1) * (q2 * q3)) * ...`), so every quaternion only
+    /// goes through about `log2(N)` multiplications instead of `N`,
+    /// which keeps the rounding error down compared to [`product`](quat::product).
+    /// 
+    /// Returns the identity quaternion if the batch is empty.
+    /// 
+    /// # Example
+    /// ```
+    /// use quaternion_traits::structs::Quats;
+    /// use quaternion_traits::quat::product;
+    /// 
+    /// let batch: Quats<f32, 3> = Quats::from([
+    ///     [0.0, 1.0, 0.0, 0.0],
+    ///     [0.0, 0.0, 1.0, 0.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ]);
+    /// 
+    /// assert_eq!( batch.reduce_product::<[f32; 4]>(), [-1.0, 0.0, 0.0, 0.0] );
+    /// assert_eq!( batch.reduce_product::<[f32; 4]>(), product::<f32, [f32; 4]>(batch) );
+    /// ```
+    pub fn reduce_product<Out: QuaternionConstructor<Num>>(&self) -> Out {
+        if N == 0 {
+            return quat::identity();
+        }
+        let mut buffer: [[Num; 4]; N] = self.0;
+        let mut width: usize = 1;
+        while width < N {
+            let mut n: usize = 0;
+            while n + width < N {
+                buffer[n] = quat::mul(buffer[n], buffer[n + width]);
+                n += width << 1;
+            }
+            width <<= 1;
+        }
+        Out::from_quat(buffer[0])
+    }
+}
+
+impl<Num: Axis, const N: usize> Add for Quats<Num, N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Quats::add(self, other)
+    }
+}
+
+impl<Num: Axis, const N: usize> Mul for Quats<Num, N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Quats::mul(self, other)
+    }
+}
+
+impl<Num: Axis, const N: usize> Index<usize> for Quats<Num, N> {
+    type Output = [Num; 4];
+
+    #[inline]
+    fn index(&self, index: usize) -> &[Num; 4] {
+        &self.0[index]
+    }
+}
+
+impl<Num: Axis, const N: usize> IndexMut<usize> for Quats<Num, N> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut [Num; 4] {
+        &mut self.0[index]
+    }
+}
+
+impl<Num: Axis, const N: usize> crate::core::convert::From<[[Num; 4]; N]> for Quats<Num, N> {
+    #[inline]
+    fn from(quats: [[Num; 4]; N]) -> Self {
+        Quats(quats)
+    }
+}
+
+impl<Num: Axis, const N: usize> crate::core::convert::From<Quats<Num, N>> for [[Num; 4]; N] {
+    #[inline]
+    fn from(quats: Quats<Num, N>) -> Self {
+        quats.0
+    }
+}
+
+impl<Num: Axis, const N: usize> crate::core::iter::IntoIterator for Quats<Num, N> {
+    type Item = [Num; 4];
+    type IntoIter = crate::core::array::IntoIter<[Num; 4], N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        crate::core::iter::IntoIterator::into_iter(self.0)
+    }
+}
+
+impl<'a, Num: Axis, const N: usize> crate::core::iter::IntoIterator for &'a Quats<Num, N> {
+    type Item = &'a [Num; 4];
+    type IntoIter = crate::core::slice::Iter<'a, [Num; 4]>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, Num: Axis, const N: usize> crate::core::iter::IntoIterator for &'a mut Quats<Num, N> {
+    type Item = &'a mut [Num; 4];
+    type IntoIter = crate::core::slice::IterMut<'a, [Num; 4]>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{
+    Axis,
+    Quaternion,
+    QuaternionConstructor,
+    QuaternionConsts,
+    QuaternionMethods,
+};
+
+/**
+A real number used as a quaternion (with all the imaginary parts `0`).
+
+A plain number can't be a [`Quaternion`] itself
+(the impls would overlap with the ones of the quaternion types that are numbers as well,
+like [`Std`](crate::structs::Std)), so it has to be wrapped in this.
+
+Constructing a `Real` from a quaternion only keeps the real part,
+the imaginary parts are thrown away (so [`UNIT_I`](QuaternionConsts::UNIT_I),
+[`UNIT_J`](QuaternionConsts::UNIT_J) and [`UNIT_K`](QuaternionConsts::UNIT_K) are all `0`).
+
+# Example
+```
+use quaternion_traits::quat;
+use quaternion_traits::structs::Real;
+
+let q: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+
+assert_eq!( quat::add::<f32, [f32; 4]>(q, Real(2.0)), [3.0, 2.0, 3.0, 4.0] );
+assert_eq!( quat::mul::<f32, [f32; 4]>(Real(2.0), q), [2.0, 4.0, 6.0, 8.0] );
+
+// only the real part is kept
+assert_eq!( quat::mul::<f32, Real<f32>>(q, q), Real(-28.0) );
+```
+*/
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Real<Num>(pub Num);
+
+impl<Num: Axis> Real<Num> {
+    /// Creates a new real number.
+    #[inline]
+    pub const fn new(real: Num) -> Self {
+        Real(real)
+    }
+
+    /// Gets the real number.
+    #[inline]
+    pub const fn get(self) -> Num {
+        self.0
+    }
+}
+
+impl<Num: Axis> crate::core::convert::From<Num> for Real<Num> {
+    #[inline]
+    fn from(real: Num) -> Self {
+        Real(real)
+    }
+}
+
+impl<Num: Axis> Quaternion<Num> for Real<Num> {
+    #[inline(always)] fn r(&self) -> Num { self.0 }
+    #[inline(always)] fn i(&self) -> Num { Num::ZERO }
+    #[inline(always)] fn j(&self) -> Num { Num::ZERO }
+    #[inline(always)] fn k(&self) -> Num { Num::ZERO }
+}
+
+impl<Num: Axis> QuaternionConstructor<Num> for Real<Num> {
+    /// Only keeps the real part.
+    #[inline(always)]
+    fn new_quat(r: Num, _: Num, _: Num, _: Num) -> Self {
+        Real(r)
+    }
+}
+
+impl<Num: Axis> QuaternionConsts<Num> for Real<Num> {
+    const ORIGIN: Self = Real(Num::ZERO);
+    const IDENTITY: Self = Real(Num::ONE);
+    const NAN: Self = Real(Num::NAN);
+    const UNIT_I: Self = Real(Num::ZERO);
+    const UNIT_J: Self = Real(Num::ZERO);
+    const UNIT_K: Self = Real(Num::ZERO);
+}
+
+impl<Num: Axis> QuaternionMethods<Num> for Real<Num> {}
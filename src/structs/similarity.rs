@@ -0,0 +1,132 @@
+use crate::{
+    Axis,
+    Quaternion,
+    Scalar,
+    Vector,
+    VectorConstructor,
+};
+use crate::quat;
+
+/**
+A rotation, a uniform scale and a translation applied one after the other
+(a [similarity transform](https://en.wikipedia.org/wiki/Similarity_(geometry))).
+
+Transforming a point `p` gives `scale * (rotation p *rotation) + translation`,
+same as [`similarity_transform`](crate::quat::similarity_transform).
+
+The rotation is kept normalized.
+
+# Example
+```
+use quaternion_traits::quat::from_axis_angle;
+use quaternion_traits::structs::Similarity;
+
+let parent: Similarity<f32> = Similarity::new(
+    from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], core::f32::consts::FRAC_PI_2),
+    2.0,
+    [1.0, 0.0, 0.0],
+);
+let child: Similarity<f32> = Similarity::new([1.0, 0.0, 0.0, 0.0], 3.0, [0.0, 1.0, 0.0]);
+
+let point: [f32; 3] = [1.0, 2.0, 3.0];
+let composed: [f32; 3] = parent.compose(child).transform_point(point);
+let one_by_one: [f32; 3] = parent.transform_point(child.transform_point::<[f32; 3]>(point));
+for axis in 0..3 {
+    assert!( (composed[axis] - one_by_one[axis]).abs() < 0.0001 );
+}
+
+let back: [f32; 3] = parent.inverse().transform_point(parent.transform_point::<[f32; 3]>(point));
+for axis in 0..3 {
+    assert!( (back[axis] - point[axis]).abs() < 0.0001 );
+}
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Similarity<Num: Axis> {
+    rotation: [Num; 4],
+    scale: Num,
+    translation: [Num; 3],
+}
+
+impl<Num: Axis> Similarity<Num> {
+    /// The similarity that doesn't change any point.
+    pub const IDENTITY: Self = Similarity {
+        rotation: [Num::ONE, Num::ZERO, Num::ZERO, Num::ZERO],
+        scale: Num::ONE,
+        translation: [Num::ZERO; 3],
+    };
+
+    /// Creates a new similarity, the rotation gets normalized.
+    #[inline]
+    pub fn new(rotation: impl Quaternion<Num>, scale: impl Scalar<Num>, translation: impl Vector<Num>) -> Self {
+        Similarity {
+            rotation: quat::normalize(rotation),
+            scale: scale.scalar(),
+            translation: [translation.x(), translation.y(), translation.z()],
+        }
+    }
+
+    /// The (normalized) rotation of this similarity.
+    #[inline]
+    pub fn rotation(&self) -> [Num; 4] {
+        self.rotation
+    }
+
+    /// The scale of this similarity.
+    #[inline]
+    pub fn scale(&self) -> Num {
+        self.scale
+    }
+
+    /// The translation of this similarity.
+    #[inline]
+    pub fn translation(&self) -> [Num; 3] {
+        self.translation
+    }
+
+    /// Rotates, scales and then translates a point.
+    #[inline]
+    pub fn transform_point<Out: VectorConstructor<Num>>(&self, point: impl Vector<Num>) -> Out {
+        let rotated: [Num; 3] = quat::point_rotation_unchecked(self.rotation, point);
+        Out::new_vector(
+            rotated[0] * self.scale + self.translation[0],
+            rotated[1] * self.scale + self.translation[1],
+            rotated[2] * self.scale + self.translation[2],
+        )
+    }
+
+    /// Combines two similarities into one that first applies `other` and then `self`,
+    /// aka `self.compose(other).transform_point(p) == self.transform_point(other.transform_point(p))`.
+    /// 
+    /// The same order as multiplying rotations (`mul(self, other)`).
+    pub fn compose(self, other: Self) -> Self {
+        let translation: [Num; 3] = self.transform_point(other.translation);
+        Similarity {
+            rotation: quat::normalize(quat::mul::<Num, [Num; 4]>(self.rotation, other.rotation)),
+            scale: self.scale * other.scale,
+            translation,
+        }
+    }
+
+    /// Gets the similarity that undoes this one,
+    /// aka `self.inverse().transform_point(self.transform_point(p)) == p`.
+    /// 
+    /// The point is first moved back by the translation, then scaled by `1 / scale`
+    /// and then rotated by the inverse rotation.
+    /// If the scale is zero the result contains infinities and NaN's.
+    pub fn inverse(self) -> Self {
+        let rotation: [Num; 4] = quat::conj(self.rotation);
+        let scale: Num = Num::ONE / self.scale;
+        let rotated: [Num; 3] = quat::point_rotation_unchecked(rotation, self.translation);
+        Similarity {
+            rotation,
+            scale,
+            translation: [-rotated[0] * scale, -rotated[1] * scale, -rotated[2] * scale],
+        }
+    }
+}
+
+impl<Num: Axis> crate::core::default::Default for Similarity<Num> {
+    #[inline]
+    fn default() -> Self { Similarity::IDENTITY }
+}
@@ -5,6 +5,7 @@ use crate::Quaternion;
 use crate::QuaternionConstructor;
 use crate::Scalar;
 use crate::ScalarConstructor;
+use crate::ScalarConsts;
 use crate::core::num::{
     NonZero,
     Wrapping,
@@ -13,15 +14,27 @@ use crate::core::num::{
 
 use crate::core::ops::{Add, Sub, Mul, Div, Neg, Rem};
 
+#[cfg(not(feature = "deterministic"))]
 use crate::std;
+// the `rkyv` derives use prelude names
+#[cfg(feature = "rkyv")]
+use crate::core::marker::Sized;
 
 /**
 Wrapper that changes the [`Axis`] methods from the [libm](https://docs.rs/libm/latest/libm/)
 ones to the [std](https://doc.rust-lang.org/std/index.html) ones.
+
+If you want [f32] and [f64] to use the std ones as well
+without wrapping them, enable the `std_axis_for_primitives` feature.
+
+With the `deterministic` feature the [`Axis`] methods of `Std<f32>` and `Std<f64>`
+are the libm ones of [f32] and [f64] instead,
+since the std ones can give different results on different platforms.
 */
 #[repr(transparent)]
 #[allow(private_bounds)]
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "rkyv", derive(crate::rkyv::Archive, crate::rkyv::Serialize, crate::rkyv::Deserialize))]
 pub struct Std<T>(pub T);
 
 /// A type alias for [`Std<f32>`](Std).
@@ -162,6 +175,30 @@ macro_rules! impl_scalar_for_std {
             }
         }
 
+        impl Scalar<Std<$for>> for crate::core::time::Duration {
+            #[inline] fn scalar( &self ) -> Std<$for> {
+                Std(self.scalar())
+            }
+        }
+
+        impl Scalar<Std<$for>> for &crate::core::time::Duration {
+            #[inline] fn scalar( &self ) -> Std<$for> {
+                Std(self.scalar())
+            }
+        }
+
+        impl ScalarConstructor<Std<$for>> for crate::core::time::Duration {
+            #[inline] fn new_scalar( axis: Std<$for> ) -> Self {
+                Self::new_scalar(axis.0)
+            }
+        }
+
+        impl ScalarConsts<Std<$for>> for crate::core::time::Duration {
+            const ZERO: Self = <Self as ScalarConsts<$for>>::ZERO;
+            const ONE: Self = <Self as ScalarConsts<$for>>::ONE;
+            const NAN: Self = <Self as ScalarConsts<$for>>::NAN;
+        }
+
         $(
             impl Scalar<Std<$for>> for $impl {
                 #[inline] fn scalar( &self ) -> Std<$for> {
@@ -211,6 +248,18 @@ macro_rules! impl_scalar_for_std {
                 }
             }
 
+            impl Scalar<Std<$for>> for crate::core::option::Option<NonZero<$impl>> {
+                #[inline] fn scalar( &self ) -> Std<$for> {
+                    Std(self.scalar())
+                }
+            }
+
+            impl Scalar<Std<$for>> for &crate::core::option::Option<NonZero<$impl>> {
+                #[inline] fn scalar( &self ) -> Std<$for> {
+                    Std(self.scalar())
+                }
+            }
+
             impl ScalarConstructor<Std<$for>> for crate::core::option::Option<NonZero<$impl>> {
                 #[inline] fn new_scalar( axis: Std<$for> ) -> Self {
                     Self::new_scalar(axis.0)
@@ -228,6 +277,24 @@ macro_rules! impl_scalar_for_std {
                     Self::new_scalar(axis.0)
                 }
             }
+
+            impl ScalarConsts<Std<$for>> for crate::core::option::Option<NonZero<$impl>> {
+                const ZERO: Self = <Self as ScalarConsts<$for>>::ZERO;
+                const ONE: Self = <Self as ScalarConsts<$for>>::ONE;
+                const NAN: Self = <Self as ScalarConsts<$for>>::NAN;
+            }
+
+            impl ScalarConsts<Std<$for>> for Saturating<$impl> {
+                const ZERO: Self = <Self as ScalarConsts<$for>>::ZERO;
+                const ONE: Self = <Self as ScalarConsts<$for>>::ONE;
+                const NAN: Self = <Self as ScalarConsts<$for>>::NAN;
+            }
+
+            impl ScalarConsts<Std<$for>> for Wrapping<$impl> {
+                const ZERO: Self = <Self as ScalarConsts<$for>>::ZERO;
+                const ONE: Self = <Self as ScalarConsts<$for>>::ONE;
+                const NAN: Self = <Self as ScalarConsts<$for>>::NAN;
+            }
         )+
 
         #[cfg(feature = "num-traits")]
@@ -377,6 +444,17 @@ impl crate::num_traits::NumCast for Std<f64> {
     }
 }
 
+/// An archived `Std` reads as the `Std` of the archived number.
+#[cfg(feature = "rkyv")]
+impl<Num> Scalar<Std<Num>> for ArchivedStd<Num>
+where
+    Num: Axis + crate::rkyv::Archive,
+    Std<Num>: Axis,
+    crate::rkyv::Archived<Num>: Scalar<Num>,
+{
+    #[inline] fn scalar(&self) -> Std<Num> { Std(self.0.scalar()) }
+}
+
 #[cfg(feature = "serde")]
 use crate::serde::{
     Serialize,
@@ -449,15 +527,20 @@ where
     Out::new_scalar(scalar)
 }
 
+#[cfg(not(feature = "deterministic"))]
 impl Axis for Std<f32> {
     const ONE: Self = Std(1.0);
     const ZERO: Self = Std(0.0);
     const TAU: Self = Std(f32::TAU);
     const NAN: Self = Std(f32::NAN);
     const ERROR: Self = Std(f32::EPSILON);
+    const EPS_ARITH: Self = Std(4.0 * f32::EPSILON); // 4 * ERROR, a few roundings
+    const EPS_TRIG: Self = Std(8.0 * f32::EPSILON); // 8 * ERROR
     #[inline] fn is_nan( &self ) -> bool { std::primitive::f32::is_nan(self.0) }
+    #[inline] fn classify( self ) -> crate::core::num::FpCategory { std::primitive::f32::classify(self.0) }
     #[inline] fn mul_add( self, factor: Self, addend: Self ) -> Self { Std(std::primitive::f32::mul_add(self.0, factor.0, addend.0)) }
     #[inline] fn sqrt( self ) -> Self { Std(std::primitive::f32::sqrt(self.0)) }
+    #[inline] fn inv_sqrt_fast( self ) -> Self { Std(<f32 as Axis>::inv_sqrt_fast(self.0)) }
     #[inline] fn pow( self, exp: Self ) -> Self { Std(std::primitive::f32::pow(self.0, exp.0)) }
     #[inline] fn sin_cos( self ) -> (Self, Self) {
         let (sin, cos) = std::primitive::f32::sin_cos(self.0);
@@ -474,15 +557,23 @@ impl Axis for Std<f32> {
     #[inline] fn ln( self ) -> Self { Std(std::primitive::f32::ln(self.0)) }
     #[inline] fn from_u8( uint: u8 ) -> Self { Std( uint as f32) }
     #[inline(always)] fn from_f64( float: f64 ) -> Self { Std(float as f32) }
+    #[inline(always)] fn from_f32( float: f32 ) -> Self { Std(<f32 as Axis>::from_f32(float)) }
+    #[inline(always)] fn from_i32( int: i32 ) -> Self { Std(<f32 as Axis>::from_i32(int)) }
+    #[inline(always)] fn from_u32( uint: u32 ) -> Self { Std(<f32 as Axis>::from_u32(uint)) }
+    #[inline(always)] fn from_usize( uint: usize ) -> Self { Std(<f32 as Axis>::from_usize(uint)) }
 }
     
+#[cfg(not(feature = "deterministic"))]
 impl Axis for Std<f64> {
     const ONE: Self = Std(1.0);
     const ZERO: Self = Std(0.0);
     const TAU: Self = Std(f64::TAU);
     const NAN: Self = Std(f64::NAN);
     const ERROR: Self = Std(f64::EPSILON);
+    const EPS_ARITH: Self = Std(4.0 * f64::EPSILON); // 4 * ERROR, a few roundings
+    const EPS_TRIG: Self = Std(8.0 * f64::EPSILON); // 8 * ERROR
     #[inline] fn is_nan( &self ) -> bool { std::primitive::f64::is_nan(self.0) }
+    #[inline] fn classify( self ) -> crate::core::num::FpCategory { std::primitive::f64::classify(self.0) }
     #[inline] fn mul_add( self, factor: Self, addend: Self ) -> Self { Std(std::primitive::f64::mul_add(self.0, factor.0, addend.0)) }
     #[inline] fn sqrt( self ) -> Self { Std(std::primitive::f64::sqrt(self.0)) }
     #[inline] fn pow( self, exp: Self ) -> Self { Std(std::primitive::f64::pow(self.0, exp.0)) }
@@ -501,6 +592,70 @@ impl Axis for Std<f64> {
     #[inline] fn ln( self ) -> Self { Std(std::primitive::f64::ln(self.0)) }
     #[inline] fn from_u8( uint: u8 ) -> Self { Std( uint as f64) }
     #[inline(always)] fn from_f64( float: f64 ) -> Self { Std(float) }
+    #[inline(always)] fn from_f32( float: f32 ) -> Self { Std(<f64 as Axis>::from_f32(float)) }
+    #[inline(always)] fn from_i32( int: i32 ) -> Self { Std(<f64 as Axis>::from_i32(int)) }
+    #[inline(always)] fn from_u32( uint: u32 ) -> Self { Std(<f64 as Axis>::from_u32(uint)) }
+    #[inline(always)] fn from_usize( uint: usize ) -> Self { Std(<f64 as Axis>::from_usize(uint)) }
+}
+
+/// Implements [`Axis`] for `Std<$num>` by calling the methods of `$num` (which use libm).
+#[cfg(feature = "deterministic")]
+macro_rules! deterministic_std_axis {
+    ( $num:ty ) => {
+        impl Axis for Std<$num> {
+            const ONE: Self = Std(<$num as Axis>::ONE);
+            const ZERO: Self = Std(<$num as Axis>::ZERO);
+            const TAU: Self = Std(<$num as Axis>::TAU);
+            const NAN: Self = Std(<$num as Axis>::NAN);
+            const ERROR: Self = Std(<$num>::EPSILON);
+            const EPS_ARITH: Self = Std(4.0 * <$num>::EPSILON); // 4 * ERROR, a few roundings
+            const EPS_TRIG: Self = Std(8.0 * <$num>::EPSILON); // 8 * ERROR
+            #[inline] fn is_nan( &self ) -> bool { <$num as Axis>::is_nan(&self.0) }
+            #[inline] fn classify( self ) -> crate::core::num::FpCategory { <$num as Axis>::classify(self.0) }
+            #[inline] fn mul_add( self, factor: Self, addend: Self ) -> Self { Std(<$num as Axis>::mul_add(self.0, factor.0, addend.0)) }
+            #[inline] fn sqrt( self ) -> Self { Std(<$num as Axis>::sqrt(self.0)) }
+            #[inline] fn inv_sqrt_fast( self ) -> Self { Std(<$num as Axis>::inv_sqrt_fast(self.0)) }
+            #[inline] fn pow( self, exp: Self ) -> Self { Std(<$num as Axis>::pow(self.0, exp.0)) }
+            #[inline] fn sin_cos( self ) -> (Self, Self) {
+                let (sin, cos) = <$num as Axis>::sin_cos(self.0);
+                ( Std(sin), Std(cos) )
+            }
+            #[inline] fn sin( self ) -> Self { Std(<$num as Axis>::sin(self.0)) }
+            #[inline] fn asin( self ) -> Self { Std(<$num as Axis>::asin(self.0)) }
+            #[inline] fn sinh( self ) -> Self { Std(<$num as Axis>::sinh(self.0)) }
+            #[inline] fn cos( self ) -> Self { Std(<$num as Axis>::cos(self.0)) }
+            #[inline] fn acos( self ) -> Self { Std(<$num as Axis>::acos(self.0)) }
+            #[inline] fn cosh( self ) -> Self { Std(<$num as Axis>::cosh(self.0)) }
+            #[inline] fn atan2( self, other: Self ) -> Self { Std(<$num as Axis>::atan2(self.0, other.0)) }
+            #[inline] fn exp( self ) -> Self { Std(<$num as Axis>::exp(self.0)) }
+            #[inline] fn ln( self ) -> Self { Std(<$num as Axis>::ln(self.0)) }
+            #[inline] fn from_u8( uint: u8 ) -> Self { Std(<$num as Axis>::from_u8(uint)) }
+            #[inline(always)] fn from_f64( float: f64 ) -> Self { Std(<$num as Axis>::from_f64(float)) }
+            #[inline(always)] fn from_f32( float: f32 ) -> Self { Std(<$num as Axis>::from_f32(float)) }
+            #[inline(always)] fn from_i32( int: i32 ) -> Self { Std(<$num as Axis>::from_i32(int)) }
+            #[inline(always)] fn from_u32( uint: u32 ) -> Self { Std(<$num as Axis>::from_u32(uint)) }
+            #[inline(always)] fn from_usize( uint: usize ) -> Self { Std(<$num as Axis>::from_usize(uint)) }
+        }
+    };
+}
+
+#[cfg(feature = "deterministic")]
+deterministic_std_axis!(f32);
+#[cfg(feature = "deterministic")]
+deterministic_std_axis!(f64);
+
+impl<Num> crate::traits::AxisBytes for Std<Num>
+where
+    Num: crate::traits::AxisBytes,
+    Std<Num>: Axis,
+{
+    type Bytes = Num::Bytes;
+    type QuatBytes = Num::QuatBytes;
+
+    #[inline] fn to_le_bytes(self) -> Num::Bytes { self.0.to_le_bytes() }
+    #[inline] fn to_be_bytes(self) -> Num::Bytes { self.0.to_be_bytes() }
+    #[inline] fn from_le_bytes(bytes: Num::Bytes) -> Self { Std(Num::from_le_bytes(bytes)) }
+    #[inline] fn from_be_bytes(bytes: Num::Bytes) -> Self { Std(Num::from_be_bytes(bytes)) }
 }
 
 impl<Num: Axis, Q> crate::Quaternion<Std<Num>> for Std<Q>
@@ -558,6 +713,26 @@ where
     Std<Num>: Axis,
 { }
 
+impl<Num: Axis, U> crate::UnitQuaternion<Std<Num>> for Std<U>
+where
+    U: crate::UnitQuaternion<Num>,
+    Std<Num>: Axis,
+{ }
+
+impl<Num: Axis, U> crate::UnitQuaternionConsts<Std<Num>> for Std<U>
+where
+    U: crate::UnitQuaternionConsts<Num>,
+    Std<Num>: Axis,
+{
+    const IDENTITY: Self = Std(U::IDENTITY);
+    const NAN: Self = Std(U::NAN);
+
+    const UNIT_R: Self = Std(U::UNIT_R);
+    const UNIT_I: Self = Std(U::UNIT_I);
+    const UNIT_J: Self = Std(U::UNIT_J);
+    const UNIT_K: Self = Std(U::UNIT_K);
+}
+
 impl<Num: Axis, V> crate::Vector<Std<Num>> for Std<V>
 where
     V: crate::Vector<Num>,
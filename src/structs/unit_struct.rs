@@ -3,15 +3,46 @@ use crate::core::option::Option;
 use crate::{
     Axis,
     Scalar,
+    QuaternionMethods,
     UnitQuaternion,
     UnitQuaternionConstructor,
     UnitQuaternionConsts,
 };
+use crate::quat;
+use crate::unit;
+use crate::structs::Quat;
 #[cfg(feature = "std")]
 use crate::structs::Std;
 
-/// Default given struct by this crate for a unit quaternion.
+use crate::core::ops::{Mul, MulAssign, Neg, Not};
+use crate::core::iter::{Iterator, Product};
+// the `rkyv` derives use prelude names
+#[cfg(feature = "rkyv")]
+use crate::core::marker::Sized;
+
+/**
+Default given struct by this crate for a unit quaternion.
+
+Unit quaternions can be multiplied (`*`), negated (`-`) and conjugated (`!`, which is also the inverse),
+all of which give back a unit quaternion.
+
+Adding or subtracting two unit quaternions does not give a unit quaternion,
+so `+` and `-` are intentionally not implemented, use [`nlerp`](UnitQuat::nlerp)
+or [`slerp`](UnitQuat::slerp) to blend them instead
+(or [`unit::add_any`](crate::unit::add_any) if you really want the sum).
+For the same reason an iterator of unit quaternions can only be multiplied into a `UnitQuat`
+([`Product`]), summing it needs a [`Quat`] (`iter.sum::<Quat<f32>>()`).
+
+```compile_fail
+use quaternion_traits::structs::UnitQuat;
+use quaternion_traits::traits::UnitQuaternionConsts;
+
+let sum = UnitQuat::<f32>::IDENTITY + UnitQuat::<f32>::UNIT_I;
+```
+*/
 #[derive(Debug, Clone, Copy, PartialEq, Hash)]
+#[cfg_attr(feature = "rkyv", derive(crate::rkyv::Archive, crate::rkyv::Serialize, crate::rkyv::Deserialize))]
+#[cfg_attr(feature = "rkyv", rkyv(bytecheck(verify)))]
 pub struct UnitQuat<Num: Axis> {
     r: Num,
     i: Num,
@@ -23,7 +54,7 @@ impl<Num: Axis> UnitQuat<Num> {
     /// Constructs a new unit quaternion.
     #[inline]
     pub fn new(r: impl Scalar<Num>, i: impl Scalar<Num>, j: impl Scalar<Num>, k: impl Scalar<Num>) -> Option<Self> {
-        if (r.scalar() * r.scalar() + i.scalar() * i.scalar() + j.scalar() * j.scalar() + k.scalar() * k.scalar() - Num::ONE).abs() >= Num::ERROR * Num::ERROR {
+        if (r.scalar() * r.scalar() + i.scalar() * i.scalar() + j.scalar() * j.scalar() + k.scalar() * k.scalar() - Num::ONE).abs() >= Num::EPS_TRIG {
             return Option::None;
         }
 
@@ -49,6 +80,73 @@ impl<Num: Axis> UnitQuat<Num> {
     }
 }
 
+/// Used by [`unit_quat!`](crate::unit_quat) to check the components at compile time,
+/// with the tolerance [`UnitQuat::new`] has for [f32] and [f64] (their [`EPS_TRIG`](Axis::EPS_TRIG)).
+#[doc(hidden)]
+pub const fn __is_unit_literal(r: f64, i: f64, j: f64, k: f64) -> bool {
+    (r * r + i * i + j * j + k * k - 1.0).abs() < <f64 as Axis>::EPS_TRIG
+}
+
+/**
+Constructs a [`UnitQuat`] from its components (`r, i, j, k`) at compile time.
+
+The components have to be known at compile time (literals or constants)
+and are checked to be of unit length with the tolerance [`UnitQuat::new`] has for [f32] and [f64]
+([Std](crate::structs::Std) is stricter),
+so the check costs nothing at runtime and a wrong constant is a compile error
+instead of a `None` (or a silently wrong rotation).
+
+The component type can be given before a `;` (like `unit_quat!(f64; 1, 0, 0, 0)`),
+otherwise it's inferred from where the quaternion is used.
+Only primitive floats can be used, as the check is done with `as f64` casts.
+
+# Example
+```
+use quaternion_traits::unit_quat;
+use quaternion_traits::structs::UnitQuat;
+use quaternion_traits::quat::is_near_by;
+
+// 90° around the Z axis
+const TURN_Z_90: UnitQuat<f32> = unit_quat!(0.7071067, 0.0, 0.0, 0.7071068);
+
+let half_angle: f32 = core::f32::consts::FRAC_PI_4;
+assert!( is_near_by::<f32>(TURN_Z_90, [half_angle.cos(), 0.0, 0.0, half_angle.sin()], 0.000001) );
+
+let identity = unit_quat!(f64; 1, 0, 0, 0);
+assert_eq!( identity, UnitQuat::<f64>::new(1.0, 0.0, 0.0, 0.0).unwrap() );
+```
+
+Components that aren't of unit length don't compile:
+```compile_fail
+use quaternion_traits::unit_quat;
+use quaternion_traits::structs::UnitQuat;
+
+const NOT_UNIT: UnitQuat<f32> = unit_quat!(0.7, 0.0, 0.0, 0.7);
+```
+```compile_fail
+use quaternion_traits::unit_quat;
+
+let nan = unit_quat!(f32; f32::NAN, 0.0, 0.0, 0.0);
+```
+*/
+#[macro_export]
+macro_rules! unit_quat {
+    ( $r:expr, $i:expr, $j:expr, $k:expr $(,)? ) => {{
+        const {
+            ::core::assert!(
+                $crate::structs::__is_unit_literal($r as f64, $i as f64, $j as f64, $k as f64),
+                "the components given to `unit_quat!` aren't of unit length",
+            );
+        }
+        let (r, i, j, k) = ($r, $i, $j, $k);
+        // SAFETY: checked above
+        unsafe { $crate::structs::UnitQuat::new_uncehcekd(r, i, j, k) }
+    }};
+    ( $num:ty; $r:expr, $i:expr, $j:expr, $k:expr $(,)? ) => {
+        $crate::unit_quat!($r as $num, $i as $num, $j as $num, $k as $num)
+    };
+}
+
 impl<Num: Axis> crate::Quaternion<Num> for UnitQuat<Num> {
     fn r(&self) -> Num { self.r }
     fn i(&self) -> Num { self.i }
@@ -83,6 +181,134 @@ impl<Num: Axis> crate::core::default::Default for UnitQuat<Num> {
     fn default() -> Self { UnitQuat::IDENTITY }
 }
 
+impl<Num: Axis> UnitQuat<Num> {
+    /// Normalized liniar interpolation inbetween two unit quaternions.
+    /// 
+    /// Uses the shortest path inbetween the two unit quaternions.
+    /// Cheaper then [`slerp`](UnitQuat::slerp) but doesn't move at a constant speed.
+    #[inline]
+    pub fn nlerp(self, to: impl UnitQuaternion<Num>, at: impl Scalar<Num>) -> Self {
+        quat::to_unit_quat(quat::lerp::<Num, (Num, [Num; 3])>(self, to, at))
+    }
+
+    /// Spherical liniar interpolation inbetween two unit quaternions.
+    /// 
+    /// Check [the slerp function](crate::unit::slerp) for more info.
+    #[inline]
+    #[cfg(feature = "math_fns")]
+    pub fn slerp(self, to: impl UnitQuaternion<Num>, at: impl Scalar<Num>) -> Self {
+        unit::slerp(self, to, at)
+    }
+}
+
+/// Multiplies two unit quaternions, giving a unit quaternion.
+/// 
+/// Rounding errors make the product drift away from being a unit quaternion,
+/// so it gets normalized again once its squared absolute value
+/// is further than [`Num::ERROR`](Axis::ERROR) away from one.
+impl<Num: Axis> Mul for UnitQuat<Num> {
+    type Output = UnitQuat<Num>;
+    #[inline] fn mul(self, other: UnitQuat<Num>) -> Self::Output {
+        let product: (Num, [Num; 3]) = quat::mul(self, other);
+        if (quat::abs_squared::<Num, Num>(product) - Num::ONE).abs() >= Num::ERROR {
+            return quat::to_unit_quat(product);
+        }
+        UnitQuat { r: product.0, i: product.1[0], j: product.1[1], k: product.1[2] }
+    }
+}
+
+impl<Num: Axis> MulAssign for UnitQuat<Num> {
+    #[inline] fn mul_assign(&mut self, other: UnitQuat<Num>) {
+        *self = *self * other;
+    }
+}
+
+/// Multiplies all the unit quaternions of an iterator (in order), giving a unit quaternion.
+/// 
+/// Renormalizes the same way as `*` does, returns the identity if the iterator is empty.
+impl<Num: Axis, U: UnitQuaternion<Num>> Product<U> for UnitQuat<Num> {
+    fn product<I: Iterator<Item = U>>(iter: I) -> Self {
+        iter.fold(UnitQuat::IDENTITY, |product: Self, unit: U| {
+            product * UnitQuat { r: unit.r(), i: unit.i(), j: unit.j(), k: unit.k() }
+        })
+    }
+}
+
+/// Multiplying with a quaternion that isn't a unit one gives a [`Quat`].
+impl<Num: Axis, T: QuaternionMethods<Num>> Mul<Quat<Num, T>> for UnitQuat<Num> {
+    type Output = Quat<Num, T>;
+    #[inline] fn mul(self, other: Quat<Num, T>) -> Self::Output {
+        quat::mul(self, other)
+    }
+}
+
+impl<Num: Axis> Neg for UnitQuat<Num> {
+    type Output = UnitQuat<Num>;
+    #[inline] fn neg(self) -> Self::Output {
+        unit::neg(self)
+    }
+}
+
+/// Calculates the conjugate (which is also the inverse) of the unit quaternion using `!`.
+impl<Num: Axis> Not for UnitQuat<Num> {
+    type Output = UnitQuat<Num>;
+    #[inline] fn not(self) -> Self::Output {
+        unit::conj(self)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+mod rkyv_impls {
+    use crate::core::fmt;
+    use crate::core::result::Result;
+    use crate::rkyv::{Archive, Archived};
+    use crate::rkyv::bytecheck::Verify;
+    use crate::rkyv::rancor::{Fallible, Source};
+    use crate::{Axis, Quaternion, Scalar};
+    use crate::quat;
+    use super::ArchivedUnitQuat;
+
+    /// An archived unit quaternion can be read in place.
+    impl<Num> Quaternion<Num> for ArchivedUnitQuat<Num>
+    where
+        Num: Axis + Archive,
+        Archived<Num>: Scalar<Num>,
+    {
+        #[inline] fn r(&self) -> Num { self.r.scalar() }
+        #[inline] fn i(&self) -> Num { self.i.scalar() }
+        #[inline] fn j(&self) -> Num { self.j.scalar() }
+        #[inline] fn k(&self) -> Num { self.k.scalar() }
+    }
+
+    #[derive(Debug)]
+    struct NotAUnitQuaternion;
+
+    impl fmt::Display for NotAUnitQuaternion {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("the archived UnitQuat is not a unit quaternion")
+        }
+    }
+
+    impl crate::core::error::Error for NotAUnitQuaternion {}
+
+    /// Validating an archived unit quaternion does the same check as [`UnitQuat::new`](super::UnitQuat::new).
+    unsafe impl<Num, C> Verify<C> for ArchivedUnitQuat<Num>
+    where
+        Num: Axis + Archive,
+        Archived<Num>: Scalar<Num>,
+        C: Fallible + ?crate::core::marker::Sized,
+        C::Error: Source,
+    {
+        fn verify(&self, _: &mut C) -> Result<(), C::Error> {
+            if (quat::abs_squared::<Num, Num>(self) - Num::ONE).abs() < Num::EPS_TRIG {
+                Result::Ok(())
+            } else {
+                Result::Err(Source::new(NotAUnitQuaternion))
+            }
+        }
+    }
+}
+
 /// Type alias for `Unit<f32>` (uses `Std<f32>` is `std` is enabled)
 #[cfg(any(not(feature = "std"), doc))]
 pub type Unit32 = UnitQuat<f32>;
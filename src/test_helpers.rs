@@ -0,0 +1,377 @@
+/*!
+Helpers for testing code that uses quaternions.
+
+The [`assert_quat_near!`](crate::assert_quat_near) and
+[`assert_same_rotation!`](crate::assert_same_rotation) macros
+are the intended way of using this module, they call the functions here
+with the text of the compared expressions.
+
+[`for_each_test_quat`] goes through a fixed grid of quaternions,
+useful for checking that an identity holds for more than a couple of hand picked values.
+
+Nothing here needs `std`, failures are reported with [`core::panic!`](crate::core::panic).
+ */
+
+use crate::core::fmt::{Display, Formatter, Result};
+use crate::Axis;
+use crate::Quaternion;
+use crate::quat;
+use crate::structs::QuaternionFormat;
+
+type Q<N> = (N, [N; 3]);
+
+/// Shows a quaternion using [`quat::display`].
+struct Shown<Num>(Q<Num>);
+
+impl<Num: Axis + Display> Display for Shown<Num> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        quat::display(f, self.0, QuaternionFormat::DEFAULT)
+    }
+}
+
+/// Shows the component-wise difference of two quaternions.
+struct Difference<Num>(Q<Num>);
+
+impl<Num: Axis + Display> Display for Difference<Num> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let (r, [i, j, k]) = self.0;
+        f.write_str("[")?;
+        r.write_axis(f)?;
+        f.write_str(", ")?;
+        i.write_axis(f)?;
+        f.write_str(", ")?;
+        j.write_axis(f)?;
+        f.write_str(", ")?;
+        k.write_axis(f)?;
+        f.write_str("]")
+    }
+}
+
+/// Panics with a message showing both quaternions,
+/// their component-wise difference and their distance.
+#[cold]
+#[track_caller]
+fn fail<Num: Axis + Display>(
+    assertion: &str,
+    left: Q<Num>,
+    right: Q<Num>,
+    tolerance: Num,
+    left_expr: &str,
+    right_expr: &str,
+) -> ! {
+    crate::core::panic!(
+        "assertion `{left_expr} {assertion} {right_expr}` failed\n      left: {}\n     right: {}\ndifference: {}\n  distance: {}\n tolerance: {}",
+        Shown(left),
+        Shown(right),
+        Difference(quat::sub::<Num, Q<Num>>(left, right)),
+        quat::dist_euclid::<Num, Num>(left, right),
+        tolerance,
+    )
+}
+
+/// Asserts that the distance inbetween two quaternions is less then `tolerance`.
+///
+/// Same check as [`is_near_by`](quat::is_near_by).
+///
+/// # Panics
+/// If the quaternions are not near, showing both of them,
+/// their component-wise difference and their distance.
+///
+/// # Example
+/// ```
+/// use quaternion_traits::test_helpers::assert_near;
+///
+/// assert_near::<f32>([1.0, 2.0, 3.0, 4.0], [1.0, 2.0, 3.0, 4.00001], 0.0001, "a", "b");
+/// ```
+#[track_caller]
+pub fn assert_near<Num: Axis + Display>(
+    left: impl Quaternion<Num>,
+    right: impl Quaternion<Num>,
+    tolerance: Num,
+    left_expr: &str,
+    right_expr: &str,
+) {
+    let (left, right): (Q<Num>, Q<Num>) = (quat::convert_quat(left), quat::convert_quat(right));
+    if !quat::is_near_by(left, right, tolerance) {
+        fail("≈", left, right, tolerance, left_expr, right_expr)
+    }
+}
+
+/// Asserts that two quaternions represent the same rotation,
+/// meaning that `left` is near eather `right` or `-right`.
+///
+/// # Panics
+/// If neither `right` or `-right` is near `left`, showing both quaternions,
+/// the component-wise difference and distance to the closer one of `right` or `-right`.
+///
+/// # Example
+/// ```
+/// use quaternion_traits::test_helpers::assert_same_rotation;
+///
+/// assert_same_rotation::<f32>([0.0, 1.0, 0.0, 0.0], [0.0, -1.0, 0.0, 0.0], 0.0001, "a", "b");
+/// ```
+#[track_caller]
+pub fn assert_same_rotation<Num: Axis + Display>(
+    left: impl Quaternion<Num>,
+    right: impl Quaternion<Num>,
+    tolerance: Num,
+    left_expr: &str,
+    right_expr: &str,
+) {
+    let left: Q<Num> = quat::convert_quat(left);
+    let right: Q<Num> = quat::enforce_continuity(left, right);
+    if !quat::is_near_by(left, right, tolerance) {
+        fail("is the same rotation as", left, right, tolerance, left_expr, right_expr)
+    }
+}
+
+/**
+Asserts that two quaternions are near each other.
+
+Uses [`Num::EPS_ARITH`](Axis::EPS_ARITH) as the tolerance if none is given.
+On failure the message shows both quaternions (using [`quat::display`]),
+their component-wise difference and their distance.
+
+If the number type can't be inferred (eg: arrays of [f32] with the `std` feature,
+since they are quaternions of both [f32] and [`Std<f32>`](crate::structs::Std))
+put it first followed by a `;`.
+
+# Example
+```
+use quaternion_traits::assert_quat_near;
+use quaternion_traits::structs::Quat;
+use quaternion_traits::QuaternionConstructor;
+
+let a: Quat<f32> = Quat::new_quat(1.0, 2.0, 3.0, 4.0);
+let b: Quat<f32> = Quat::new_quat(1.0, 2.0, 3.0, 4.000001);
+
+assert_quat_near!(a, b);
+assert_quat_near!(a, b, 0.001);
+assert_quat_near!(f32; [1.0, 0.0, 0.0, 0.0], [1.0, 0.0, 0.0, 0.0]);
+```
+
+```should_panic
+use quaternion_traits::assert_quat_near;
+
+assert_quat_near!(f32; [1.0, 2.0, 3.0, 4.0], [1.0, 2.0, 3.0, 5.0], 0.5);
+```
+*/
+#[macro_export]
+macro_rules! assert_quat_near {
+    ( $left:expr, $right:expr $(,)? ) => {
+        $crate::test_helpers::assert_near($left, $right, $crate::traits::Axis::EPS_ARITH, ::core::stringify!($left), ::core::stringify!($right))
+    };
+    ( $left:expr, $right:expr, $tolerance:expr $(,)? ) => {
+        $crate::test_helpers::assert_near($left, $right, $crate::traits::Scalar::scalar(&$tolerance), ::core::stringify!($left), ::core::stringify!($right))
+    };
+    ( $num:ty; $left:expr, $right:expr $(,)? ) => {
+        $crate::test_helpers::assert_near::<$num>($left, $right, <$num as $crate::traits::Axis>::EPS_ARITH, ::core::stringify!($left), ::core::stringify!($right))
+    };
+    ( $num:ty; $left:expr, $right:expr, $tolerance:expr $(,)? ) => {
+        $crate::test_helpers::assert_near::<$num>($left, $right, $crate::traits::Scalar::<$num>::scalar(&$tolerance), ::core::stringify!($left), ::core::stringify!($right))
+    };
+}
+
+/**
+Asserts that two quaternions represent the same rotation
+(meaning that `left` is near eather `right` or `-right`).
+
+Takes the same arguments as [`assert_quat_near!`](crate::assert_quat_near),
+but uses [`Num::EPS_TRIG`](Axis::EPS_TRIG) as the tolerance if none is given
+(rotations usually went through trigonometric functions).
+
+# Example
+```
+use quaternion_traits::assert_same_rotation;
+
+assert_same_rotation!(f32; [0.0, 0.6, 0.0, 0.8], [0.0, -0.6, 0.0, -0.8]);
+```
+*/
+#[macro_export]
+macro_rules! assert_same_rotation {
+    ( $left:expr, $right:expr $(,)? ) => {
+        $crate::test_helpers::assert_same_rotation($left, $right, $crate::traits::Axis::EPS_TRIG, ::core::stringify!($left), ::core::stringify!($right))
+    };
+    ( $left:expr, $right:expr, $tolerance:expr $(,)? ) => {
+        $crate::test_helpers::assert_same_rotation($left, $right, $crate::traits::Scalar::scalar(&$tolerance), ::core::stringify!($left), ::core::stringify!($right))
+    };
+    ( $num:ty; $left:expr, $right:expr $(,)? ) => {
+        $crate::test_helpers::assert_same_rotation::<$num>($left, $right, <$num as $crate::traits::Axis>::EPS_TRIG, ::core::stringify!($left), ::core::stringify!($right))
+    };
+    ( $num:ty; $left:expr, $right:expr, $tolerance:expr $(,)? ) => {
+        $crate::test_helpers::assert_same_rotation::<$num>($left, $right, $crate::traits::Scalar::<$num>::scalar(&$tolerance), ::core::stringify!($left), ::core::stringify!($right))
+    };
+}
+
+/// The values each component of the quaternions of the [f32] grid of [`for_each_test_quat`] are picked from.
+/// 
+/// Zero, one, [`EPSILON`](f32::EPSILON) and the constants of [`core::f32::consts`](crate::core::f32::consts)
+/// as they are, scaled up by `1e8` and scaled down by `1e-8`.
+pub const F32S: [f32; 62] = [
+    0.0,
+    1.0,
+    f32::EPSILON,
+    crate::core::f32::consts::E,
+    crate::core::f32::consts::FRAC_1_PI,
+    crate::core::f32::consts::FRAC_1_SQRT_2,
+    crate::core::f32::consts::FRAC_2_PI,
+    crate::core::f32::consts::FRAC_2_SQRT_PI,
+    crate::core::f32::consts::FRAC_PI_2,
+    crate::core::f32::consts::FRAC_PI_3,
+    crate::core::f32::consts::FRAC_PI_4,
+    crate::core::f32::consts::FRAC_PI_6,
+    crate::core::f32::consts::FRAC_PI_8,
+    crate::core::f32::consts::LN_2,
+    crate::core::f32::consts::LN_10,
+    crate::core::f32::consts::LOG2_10,
+    crate::core::f32::consts::LOG2_E,
+    crate::core::f32::consts::LOG10_2,
+    crate::core::f32::consts::LOG10_E,
+    crate::core::f32::consts::PI,
+    crate::core::f32::consts::SQRT_2,
+    crate::core::f32::consts::TAU,
+    1e8 * f32::EPSILON,
+    1e8 * crate::core::f32::consts::E,
+    1e8 * crate::core::f32::consts::FRAC_1_PI,
+    1e8 * crate::core::f32::consts::FRAC_1_SQRT_2,
+    1e8 * crate::core::f32::consts::FRAC_2_PI,
+    1e8 * crate::core::f32::consts::FRAC_2_SQRT_PI,
+    1e8 * crate::core::f32::consts::FRAC_PI_2,
+    1e8 * crate::core::f32::consts::FRAC_PI_3,
+    1e8 * crate::core::f32::consts::FRAC_PI_4,
+    1e8 * crate::core::f32::consts::FRAC_PI_6,
+    1e8 * crate::core::f32::consts::FRAC_PI_8,
+    1e8 * crate::core::f32::consts::LN_2,
+    1e8 * crate::core::f32::consts::LN_10,
+    1e8 * crate::core::f32::consts::LOG2_10,
+    1e8 * crate::core::f32::consts::LOG2_E,
+    1e8 * crate::core::f32::consts::LOG10_2,
+    1e8 * crate::core::f32::consts::LOG10_E,
+    1e8 * crate::core::f32::consts::PI,
+    1e8 * crate::core::f32::consts::SQRT_2,
+    1e8 * crate::core::f32::consts::TAU,
+    1e-8 * f32::EPSILON, // <--- Only thing that breaks sqrt (it's square is too small), use sqrt_scaled
+    1e-8 * crate::core::f32::consts::E,
+    1e-8 * crate::core::f32::consts::FRAC_1_PI,
+    1e-8 * crate::core::f32::consts::FRAC_1_SQRT_2,
+    1e-8 * crate::core::f32::consts::FRAC_2_PI,
+    1e-8 * crate::core::f32::consts::FRAC_2_SQRT_PI,
+    1e-8 * crate::core::f32::consts::FRAC_PI_2,
+    1e-8 * crate::core::f32::consts::FRAC_PI_3,
+    1e-8 * crate::core::f32::consts::FRAC_PI_4,
+    1e-8 * crate::core::f32::consts::FRAC_PI_6,
+    1e-8 * crate::core::f32::consts::FRAC_PI_8,
+    1e-8 * crate::core::f32::consts::LN_2,
+    1e-8 * crate::core::f32::consts::LN_10,
+    1e-8 * crate::core::f32::consts::LOG2_10,
+    1e-8 * crate::core::f32::consts::LOG2_E,
+    1e-8 * crate::core::f32::consts::LOG10_2,
+    1e-8 * crate::core::f32::consts::LOG10_E,
+    1e-8 * crate::core::f32::consts::PI,
+    1e-8 * crate::core::f32::consts::SQRT_2,
+    1e-8 * crate::core::f32::consts::TAU,
+];
+
+/// The same values as [`F32S`] with the precision of an [f64], used for the [f64] grid of [`for_each_test_quat`].
+pub const F64S: [f64; 62] = [
+    0.0,
+    1.0,
+    f64::EPSILON,
+    crate::core::f64::consts::E,
+    crate::core::f64::consts::FRAC_1_PI,
+    crate::core::f64::consts::FRAC_1_SQRT_2,
+    crate::core::f64::consts::FRAC_2_PI,
+    crate::core::f64::consts::FRAC_2_SQRT_PI,
+    crate::core::f64::consts::FRAC_PI_2,
+    crate::core::f64::consts::FRAC_PI_3,
+    crate::core::f64::consts::FRAC_PI_4,
+    crate::core::f64::consts::FRAC_PI_6,
+    crate::core::f64::consts::FRAC_PI_8,
+    crate::core::f64::consts::LN_2,
+    crate::core::f64::consts::LN_10,
+    crate::core::f64::consts::LOG2_10,
+    crate::core::f64::consts::LOG2_E,
+    crate::core::f64::consts::LOG10_2,
+    crate::core::f64::consts::LOG10_E,
+    crate::core::f64::consts::PI,
+    crate::core::f64::consts::SQRT_2,
+    crate::core::f64::consts::TAU,
+    1e8 * f64::EPSILON,
+    1e8 * crate::core::f64::consts::E,
+    1e8 * crate::core::f64::consts::FRAC_1_PI,
+    1e8 * crate::core::f64::consts::FRAC_1_SQRT_2,
+    1e8 * crate::core::f64::consts::FRAC_2_PI,
+    1e8 * crate::core::f64::consts::FRAC_2_SQRT_PI,
+    1e8 * crate::core::f64::consts::FRAC_PI_2,
+    1e8 * crate::core::f64::consts::FRAC_PI_3,
+    1e8 * crate::core::f64::consts::FRAC_PI_4,
+    1e8 * crate::core::f64::consts::FRAC_PI_6,
+    1e8 * crate::core::f64::consts::FRAC_PI_8,
+    1e8 * crate::core::f64::consts::LN_2,
+    1e8 * crate::core::f64::consts::LN_10,
+    1e8 * crate::core::f64::consts::LOG2_10,
+    1e8 * crate::core::f64::consts::LOG2_E,
+    1e8 * crate::core::f64::consts::LOG10_2,
+    1e8 * crate::core::f64::consts::LOG10_E,
+    1e8 * crate::core::f64::consts::PI,
+    1e8 * crate::core::f64::consts::SQRT_2,
+    1e8 * crate::core::f64::consts::TAU,
+    1e-8 * f64::EPSILON,
+    1e-8 * crate::core::f64::consts::E,
+    1e-8 * crate::core::f64::consts::FRAC_1_PI,
+    1e-8 * crate::core::f64::consts::FRAC_1_SQRT_2,
+    1e-8 * crate::core::f64::consts::FRAC_2_PI,
+    1e-8 * crate::core::f64::consts::FRAC_2_SQRT_PI,
+    1e-8 * crate::core::f64::consts::FRAC_PI_2,
+    1e-8 * crate::core::f64::consts::FRAC_PI_3,
+    1e-8 * crate::core::f64::consts::FRAC_PI_4,
+    1e-8 * crate::core::f64::consts::FRAC_PI_6,
+    1e-8 * crate::core::f64::consts::FRAC_PI_8,
+    1e-8 * crate::core::f64::consts::LN_2,
+    1e-8 * crate::core::f64::consts::LN_10,
+    1e-8 * crate::core::f64::consts::LOG2_10,
+    1e-8 * crate::core::f64::consts::LOG2_E,
+    1e-8 * crate::core::f64::consts::LOG10_2,
+    1e-8 * crate::core::f64::consts::LOG10_E,
+    1e-8 * crate::core::f64::consts::PI,
+    1e-8 * crate::core::f64::consts::SQRT_2,
+    1e-8 * crate::core::f64::consts::TAU,
+];
+
+/// Only every `TEST_QUAT_STEP`th quaternion of the grids is used by [`for_each_test_quat`].
+const TEST_QUAT_STEP: usize = 1999;
+
+/// Calls `test` with quaternions who's components are all from [`F32S`] and then from [`F64S`]
+/// (converted with [`Axis::from_f32`] and [`Axis::from_f64`]).
+/// 
+/// Each grid has `62⁴` quaternions, every `1999`th one of them is used
+/// (`7392` from each grid, starting with the origin).
+/// 
+/// # Example
+/// ```
+/// use quaternion_traits::quat::{conj, eq};
+/// use quaternion_traits::test_helpers::for_each_test_quat;
+/// 
+/// let mut count: usize = 0;
+/// for_each_test_quat::<f32>(|quat: [f32; 4]| {
+///     assert!( eq::<f32>(conj::<f32, [f32; 4]>(conj::<f32, [f32; 4]>(quat)), quat) );
+///     count += 1;
+/// });
+/// assert_eq!( count, 2 * 7392 );
+/// ```
+pub fn for_each_test_quat<Num: Axis>(mut test: impl crate::core::ops::FnMut([Num; 4])) {
+    use crate::core::iter::Iterator;
+
+    const LEN: usize = F32S.len();
+    let components = |index: usize| -> [usize; 4] {
+        [index % LEN, index / LEN % LEN, index / (LEN * LEN) % LEN, index / (LEN * LEN * LEN)]
+    };
+    for index in (0..LEN * LEN * LEN * LEN).step_by(TEST_QUAT_STEP) {
+        test(components(index).map(|n: usize| Num::from_f32(F32S[n])));
+    }
+    for index in (0..LEN * LEN * LEN * LEN).step_by(TEST_QUAT_STEP) {
+        test(components(index).map(|n: usize| Num::from_f64(F64S[n])));
+    }
+}
@@ -1,6 +1,25 @@
 /*!
 Diverse traits for representing and constructing diverse value
 types like quaternions, vectors, scalar values and others.
+
+# Discarding outputs
+
+`()` and `[T; 0]` implement every constructor trait by throwing the values away
+(and every representation trait as all zeros), so they work as a "null sink"
+for outputs generic code doesn't need.
+
+```
+# #[cfg(feature = "math_fns")] {
+use quaternion_traits::quat::{to_vector, to_polar_form};
+
+let quat: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+
+let () = to_vector::<f32, ()>(quat);
+// only the absolute value is kept
+let (abs, (), []) = to_polar_form::<f32, f32, (), [f32; 0]>(quat);
+assert!( (abs - 30.0_f32.sqrt()).abs() < 0.0001 );
+# }
+```
  */
 
 
@@ -9,12 +28,28 @@ use crate::quat;
 use crate::core::marker::Sized;
 #[allow(unused_imports)]
 use crate::core::option::Option;
+use crate::core::result::Result;
 
 /**
 The general representation of any quaternion type.
 
 Note: The [`r`](Quaternion::r), [`i`](Quaternion::i), [`j`](Quaternion::j) and [`k`](Quaternion::k)
 methods are used as if they are cheap operations.
+
+# Implemented representations
+- `[S; 4]` and `(R, I, J, K)`: the four parts as scalars.
+- `(S, V)`: a scalar and a vector (`r` and `[i, j, k]`).
+- `(C, J, K)`: a complex number (`r + i·i`) and two scalars.
+- `[C; 2]`: two complex numbers, `r + i·i` and `j + k·i`
+  (the [Cayley–Dickson](https://en.wikipedia.org/wiki/Cayley%E2%80%93Dickson_construction) pair).
+
+Since `(S, V)` is implemented for every pair, a `(C1, C2)` tuple of two complex numbers can't be a quaternion
+(it would conflict for types that are both complex numbers and scalars or vectors),
+use `[C; 2]` for it instead (or the `Complex<Complex<T>>` of `num-complex`, with the `num-complex` feature).
+
+References, smart pointers, lock guards and cells of a quaternion are quaternions too.
+Reading a [`RefCell`] panics if it's mutably borrowed
+and reading a [`OnceCell`] panics if it's empty.
 */
 pub trait Quaternion<Num: Axis> {
     /// The real part of this quaternion.
@@ -37,6 +72,13 @@ The general representation for any scalar type.
 
 Marks that this type can turn into an [`Axis`] type.
 
+Besides the floats, it's implemented for the integer primitives and their
+[`Wrapping`](crate::core::num::Wrapping), [`Saturating`](crate::core::num::Saturating),
+[`NonZero`](crate::core::num::NonZero) and `Option<NonZero>` (where [`None`](Option::None) is zero) wrappers.
+[`Duration`](crate::core::time::Duration) is a scalar in seconds, constructing one saturates negative seconds to zero
+and long durations lose their nanoseconds on the way to a float.
+`bool` and `char` are not numbers so they intentionally don't implement it.
+
 Note: The [`scalar`](Scalar::scalar) method is used as if it's a cheap operation.
 */
 pub trait Scalar<Num: Axis> {
@@ -96,6 +138,11 @@ The general representation of any NxN rotation matrix.
 
 Only `Matrix<_, 2>`, `Matrix<_, 3>` and `Matrix<_, 4>` have impls and are used.
 
+All the impls in this crate are row-major: nested arrays and tuples are read as `matrix[row][col]`
+and flat arrays (`[T; 4]`, `[T; 9]` and `[T; 16]`) are stored row after row,
+the same way [`to_array`](Matrix::to_array) returns them.
+For column-major data wrap it in [`ColMajor`](crate::structs::ColMajor).
+
 Note: The [`get_unchecked`](Matrix::get_unchecked) method is used as if it's a cheap operation.
 */
 #[cfg(feature = "matrix")]
@@ -105,6 +152,10 @@ pub trait Matrix<T, const N: usize> {
     /// # Important
     /// This value should not panic for values of
     /// `row` and `col` that are both smaller then N.
+    ///
+    /// Every implementation in this crate panics (with the position in the message)
+    /// if `row` or `col` is greater then or equal to N, in debug and release builds alike.
+    /// The exception is [`FnMatrix`](crate::structs::FnMatrix), which leaves that to its function.
     fn get_unchecked( &self, row: usize, col: usize ) -> T;
 
     #[inline]
@@ -125,15 +176,12 @@ pub trait Matrix<T, const N: usize> {
     }
 
     /// Turns this matrix reprezentation into a NxN array.
+    /// 
+    /// By default this builds the array element by element with [`get_unchecked`](Matrix::get_unchecked),
+    /// if it panics the elements already gotten are dropped.
     fn to_array( &self ) -> [[T; N]; N] {
-        use crate::core::mem::MaybeUninit;
-        let mut matrix: [[T; N]; N] = unsafe { MaybeUninit::uninit().assume_init() };
-        for row in 0..N {
-            for col in 0..N {
-                matrix[row][col] = self.get_unchecked(row, col);
-            }
-        }
-        matrix
+        use crate::core::array::from_fn;
+        from_fn(|row| from_fn(|col| self.get_unchecked(row, col)))
     }
 }
 
@@ -201,6 +249,19 @@ pub trait QuaternionConstructor<Num: Axis>: Sized {
     #[inline]
     fn identity() -> Self { quat::identity() }
 
+    /// Constructs a quaternion with all four components equal to `value`.
+    /// 
+    /// # Example
+    /// ```
+    /// use quaternion_traits::QuaternionConstructor;
+    /// 
+    /// let quat: [f32; 4] = QuaternionConstructor::<f32>::splat(2.0);
+    /// 
+    /// assert_eq!( quat, [2.0, 2.0, 2.0, 2.0] );
+    /// ```
+    #[inline]
+    fn splat(value: impl Scalar<Num>) -> Self { quat::splat(value) }
+
     /// Constructs a quaternion with all [`Num::NAN`s](Axis::NAN).
     /// 
     /// # Example
@@ -288,12 +349,61 @@ pub trait UnitQuaternionConstructor<Num: Axis>: Sized {
     /// Constructs a new unit quaternion.
     #[inline]
     fn new_unit_quat(r: Num, i: Num, j: Num, k: Num) -> Option<Self> {
-        if (r * r + i * i + j * j + k * k - Num::ONE).abs() < Num::ERROR * Num::ERROR {
+        Self::new_unit_quat_or_err(r, i, j, k).ok()
+    }
+
+    /// Constructs a new unit quaternion, or tells by how much it isn't one.
+    /// 
+    /// Accepts the same values as [`new_unit_quat`](UnitQuaternionConstructor::new_unit_quat),
+    /// but the error carries the squared magnitude that got rejected.
+    /// 
+    /// # Example
+    /// ```
+    /// use quaternion_traits::UnitQuaternionConstructor;
+    /// use quaternion_traits::traits::NotUnitError;
+    /// 
+    /// let ok: Result<[f32; 4], NotUnitError<f32>> = UnitQuaternionConstructor::new_unit_quat_or_err(0.6, 0.0, 0.8, 0.0);
+    /// assert_eq!( ok, Ok([0.6, 0.0, 0.8, 0.0]) );
+    /// 
+    /// let err: Result<[f32; 4], NotUnitError<f32>> = UnitQuaternionConstructor::new_unit_quat_or_err(1.0, 1.0, 1.0, 0.0);
+    /// assert_eq!( err, Err(NotUnitError { abs_squared: 3.0 }) );
+    /// ```
+    #[inline]
+    fn new_unit_quat_or_err(r: Num, i: Num, j: Num, k: Num) -> Result<Self, NotUnitError<Num>> {
+        let abs_squared: Num = r * r + i * i + j * j + k * k;
+        if (abs_squared - Num::ONE).abs() < Num::EPS_TRIG {
             unsafe {
-                Option::Some(Self::new_unit_quat_unchecked(r, i, j, k))
+                Result::Ok(Self::new_unit_quat_unchecked(r, i, j, k))
             }
         } else {
-            Option::None
+            Result::Err(NotUnitError { abs_squared })
+        }
+    }
+
+    /// Constructs a new unit quaternion by normalizing the values
+    /// instead of rejecting them.
+    /// 
+    /// If the values can't be normalized (the origin, or ones with a NaN or an infinity)
+    /// the identity quaternion `(1, 0, 0, 0)` is constructed instead.
+    /// 
+    /// # Example
+    /// ```
+    /// use quaternion_traits::UnitQuaternionConstructor;
+    /// 
+    /// let normalized: [f32; 4] = UnitQuaternionConstructor::new_unit_quat_normalizing(0.0, 1.2, 0.0, -1.6);
+    /// assert_eq!( normalized, [0.0, 0.6, 0.0, -0.8] );
+    /// 
+    /// let origin: [f32; 4] = UnitQuaternionConstructor::new_unit_quat_normalizing(0.0, 0.0, 0.0, 0.0);
+    /// assert_eq!( origin, [1.0, 0.0, 0.0, 0.0] );
+    /// ```
+    #[inline]
+    fn new_unit_quat_normalizing(r: Num, i: Num, j: Num, k: Num) -> Self {
+        let [r, i, j, k] = quat::normalize::<Num, [Num; 4]>((r, [i, j, k]));
+        match Self::new_unit_quat_or_err(r, i, j, k) {
+            Result::Ok(ok) => ok,
+            Result::Err(_) => unsafe {
+                Self::new_unit_quat_unchecked(Num::ONE, Num::ZERO, Num::ZERO, Num::ZERO)
+            },
         }
     }
 
@@ -315,6 +425,16 @@ pub trait UnitQuaternionConstructor<Num: Axis>: Sized {
         UnitQuaternionConstructor::new_unit_quat(quat.r(), quat.i(), quat.j(), quat.k())
     }
 
+    /// Constructs a new unit quaternion from a normal one,
+    /// or tells by how much it isn't a unit quaternion.
+    /// 
+    /// Will have the same values.
+    /// Check [`new_unit_quat_or_err`](UnitQuaternionConstructor::new_unit_quat_or_err) for more info.
+    #[inline]
+    fn from_quat_or_err(quat: impl Quaternion<Num>) -> Result<Self, NotUnitError<Num>> {
+        UnitQuaternionConstructor::new_unit_quat_or_err(quat.r(), quat.i(), quat.j(), quat.k())
+    }
+
     /// Constructs a new unit quaternion from a normal one.
     /// 
     /// Will have the same values.
@@ -329,6 +449,43 @@ pub trait UnitQuaternionConstructor<Num: Axis>: Sized {
     }
 } 
 
+/// The error given back when a quaternion was expected to be a unit quaternion but isn't.
+/// 
+/// Given back by [`new_unit_quat_or_err`](UnitQuaternionConstructor::new_unit_quat_or_err)
+/// and [`from_quat_or_err`](UnitQuaternionConstructor::from_quat_or_err).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NotUnitError<Num> {
+    /// The squared magnitude ([`abs_squared`](crate::quat::abs_squared)) of the rejected quaternion.
+    pub abs_squared: Num,
+}
+
+impl<Num: crate::core::fmt::Display> crate::core::fmt::Display for NotUnitError<Num> {
+    fn fmt(&self, f: &mut crate::core::fmt::Formatter<'_>) -> crate::core::fmt::Result {
+        crate::core::write!(f, "not a unit quaternion (squared magnitude {})", self.abs_squared)
+    }
+}
+
+impl<Num: crate::core::fmt::Debug + crate::core::fmt::Display> crate::core::error::Error for NotUnitError<Num> {}
+
+/// The error given back when a slice doesn't have the length that was expected.
+/// 
+/// Given back by [`to_matrix_3_slice`](crate::quat::to_matrix_3_slice).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WrongLengthError {
+    /// The length that was expected.
+    pub expected: usize,
+    /// The length of the given slice.
+    pub found: usize,
+}
+
+impl crate::core::fmt::Display for WrongLengthError {
+    fn fmt(&self, f: &mut crate::core::fmt::Formatter<'_>) -> crate::core::fmt::Result {
+        crate::core::write!(f, "expected a slice of length {}, found one of length {}", self.expected, self.found)
+    }
+}
+
+impl crate::core::error::Error for WrongLengthError {}
+
 /**
 A constructor for vectors.
 
@@ -364,6 +521,21 @@ pub trait VectorConstructor<Num: Axis>: Sized {
     fn from_vector(vector: impl Vector<Num>) -> Self {
         VectorConstructor::new_vector(vector.x(), vector.y(), vector.z())
     }
+
+    /// Constructs a vector with all three components equal to `value`.
+    /// 
+    /// # Example
+    /// ```
+    /// use quaternion_traits::traits::VectorConstructor;
+    /// 
+    /// let vec: [f32; 3] = VectorConstructor::<f32>::splat(2.0);
+    /// 
+    /// assert_eq!( vec, [2.0, 2.0, 2.0] );
+    /// ```
+    #[inline]
+    fn splat(value: impl Scalar<Num>) -> Self {
+        quat::vec::splat(value)
+    }
 } 
 
 /**
@@ -401,6 +573,22 @@ pub trait ComplexConstructor<Num: Axis>: Sized {
     fn from_complex(complex: impl Complex<Num>) -> Self {
         ComplexConstructor::new_complex(complex.real(), complex.imaginary())
     }
+
+    /// Constructs a complex number with both parts equal to `value`.
+    /// 
+    /// # Example
+    /// ```
+    /// use quaternion_traits::traits::ComplexConstructor;
+    /// 
+    /// let complex: (f32, f32) = ComplexConstructor::<f32>::splat(2.0);
+    /// 
+    /// assert_eq!( complex, (2.0, 2.0) );
+    /// ```
+    #[inline]
+    fn splat(value: impl Scalar<Num>) -> Self {
+        let value: Num = value.scalar();
+        ComplexConstructor::new_complex(value, value)
+    }
 } 
 
 /**
@@ -553,6 +741,101 @@ pub trait VectorConsts<Num: Axis>: Sized + Vector<Num> {
     const UNIT_Z: Self;
 }
 
+macro_rules! float_consts {
+    ( $( $name:ident: $doc:literal ),* $(,)? ) => { $(
+        #[doc = $doc]
+        #[inline]
+        fn $name() -> Self {
+            crate::quat::from_scalar(Num::from_f64(crate::core::f64::consts::$name))
+        }
+    )* };
+}
+
+/**
+Adds the mathematical constants of [`core::f64::consts`](crate::core::f64::consts)
+as scalar quaternions, aka `from_scalar(Num::from_f64(consts::X))`.
+
+They are functions (named like in [`num_traits::FloatConst`](https://docs.rs/num-traits/latest/num_traits/float/trait.FloatConst.html))
+and not associated constants since [`Axis::from_f64`] isn't a `const fn`.
+With the `num-traits` feature [`Quat`](crate::structs::Quat) also implements `FloatConst` with these values.
+
+# Example
+```
+use quaternion_traits::traits::QuaternionFloatConsts;
+
+let pi: [f32; 4] = <[f32; 4] as QuaternionFloatConsts<f32>>::PI();
+assert_eq!( pi, [core::f32::consts::PI, 0.0, 0.0, 0.0] );
+```
+ */
+#[allow(non_snake_case)]
+pub trait QuaternionFloatConsts<Num: Axis>: Sized + QuaternionConstructor<Num> {
+    float_consts!{
+        E: "Euler's number (e) as a scalar quaternion.",
+        FRAC_1_PI: "1/π as a scalar quaternion.",
+        FRAC_1_SQRT_2: "1/sqrt(2) as a scalar quaternion.",
+        FRAC_2_PI: "2/π as a scalar quaternion.",
+        FRAC_2_SQRT_PI: "2/sqrt(π) as a scalar quaternion.",
+        FRAC_PI_2: "π/2 as a scalar quaternion.",
+        FRAC_PI_3: "π/3 as a scalar quaternion.",
+        FRAC_PI_4: "π/4 as a scalar quaternion.",
+        FRAC_PI_6: "π/6 as a scalar quaternion.",
+        FRAC_PI_8: "π/8 as a scalar quaternion.",
+        LN_10: "ln(10) as a scalar quaternion.",
+        LN_2: "ln(2) as a scalar quaternion.",
+        LOG10_E: "log<sub>10</sub>(e) as a scalar quaternion.",
+        LOG2_E: "log<sub>2</sub>(e) as a scalar quaternion.",
+        PI: "Archimedes' constant (π) as a scalar quaternion.",
+        SQRT_2: "sqrt(2) as a scalar quaternion.",
+        TAU: "The full circle constant (τ) as a scalar quaternion.",
+        LOG10_2: "log<sub>10</sub>(2) as a scalar quaternion.",
+        LOG2_10: "log<sub>2</sub>(10) as a scalar quaternion.",
+    }
+}
+
+/**
+An [`Axis`] with a fixed size little and big endian byte representation.
+
+Used by [`quat::to_le_bytes`], [`quat::from_le_bytes`] and their big endian
+counterparts for wire formats that don't depend on the platform or on `serde`.
+
+The bytes are the ones of the IEEE 754 bit pattern, so every number
+(including the payload and sign of a NaN) comes back bit for bit.
+
+Implemented for [f32], [f64] and [`Std`](crate::structs::Std) of them.
+ */
+pub trait AxisBytes: Axis {
+    /// The bytes of one number (`[u8; size_of::<Self>()]`).
+    type Bytes: crate::core::marker::Copy + crate::core::convert::AsRef<[u8]> + crate::core::convert::AsMut<[u8]> + crate::core::default::Default;
+    /// The bytes of a whole quaternion, four [`Bytes`](AxisBytes::Bytes) one after the other
+    /// (`[u8; 4 * size_of::<Self>()]`).
+    type QuatBytes: crate::core::marker::Copy + crate::core::convert::AsRef<[u8]> + crate::core::convert::AsMut<[u8]> + crate::core::default::Default;
+
+    /// The little endian bytes of the number.
+    fn to_le_bytes(self) -> Self::Bytes;
+    /// The big endian bytes of the number.
+    fn to_be_bytes(self) -> Self::Bytes;
+    /// Reads a number from its little endian bytes.
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+    /// Reads a number from its big endian bytes.
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+}
+
+macro_rules! impl_axis_bytes {
+    ( $( $num:ty: $size:literal ),* ) => { $(
+        impl AxisBytes for $num {
+            type Bytes = [u8; $size];
+            type QuatBytes = [u8; 4 * $size];
+
+            #[inline] fn to_le_bytes(self) -> [u8; $size] { <$num>::to_le_bytes(self) }
+            #[inline] fn to_be_bytes(self) -> [u8; $size] { <$num>::to_be_bytes(self) }
+            #[inline] fn from_le_bytes(bytes: [u8; $size]) -> Self { <$num>::from_le_bytes(bytes) }
+            #[inline] fn from_be_bytes(bytes: [u8; $size]) -> Self { <$num>::from_be_bytes(bytes) }
+        }
+    )* };
+}
+
+impl_axis_bytes!{ f32: 4, f64: 8 }
+
 pub use quat_methods::QuaternionMethods;
 
 // Quat impls
@@ -581,6 +864,14 @@ impl<Num: Axis, T> QuaternionConstructor<Num> for [T; 0] {
     #[inline(always)] fn from_quat(_: impl Quaternion<Num>) -> Self { [] }
 }
 
+impl<Num: Axis> UnitQuaternionConstructor<Num> for () {
+    #[inline(always)] unsafe fn new_unit_quat_unchecked(_: Num, _: Num, _: Num, _: Num) { }
+}
+
+impl<Num: Axis, T> UnitQuaternionConstructor<Num> for [T; 0] {
+    #[inline(always)] unsafe fn new_unit_quat_unchecked(_: Num, _: Num, _: Num, _: Num) -> Self { [] }
+}
+
 impl<Num: Axis, S, V> Quaternion<Num> for (S, V)
 where 
     S: Scalar<Num>,
@@ -684,6 +975,26 @@ where
     }
 }
 
+impl<Num: Axis, C> Quaternion<Num> for [C; 2]
+where C: Complex<Num>
+{
+    #[inline(always)] fn r(&self) -> Num { self[0].real() }
+    #[inline(always)] fn i(&self) -> Num { self[0].imaginary() }
+    #[inline(always)] fn j(&self) -> Num { self[1].real() }
+    #[inline(always)] fn k(&self) -> Num { self[1].imaginary() }
+}
+
+impl<Num: Axis, C> QuaternionConstructor<Num> for [C; 2]
+where C: ComplexConstructor<Num>
+{
+    #[inline] fn new_quat(r: Num, i: Num, j: Num, k: Num) -> [C; 2] {
+        [
+            ComplexConstructor::new_complex(r, i),
+            ComplexConstructor::new_complex(j, k),
+        ]
+    }
+}
+
 impl<Num: Axis, T> Quaternion<Num> for &T
 where T: Quaternion<Num>
 {
@@ -714,6 +1025,13 @@ where
     const UNIT_J: Self = (R::ZERO, I::ZERO, J::ONE, K::ZERO);
     const UNIT_K: Self = (R::ZERO, I::ZERO, J::ZERO, K::ONE);
 }
+impl<Num: Axis, R, I, J, K> QuaternionFloatConsts<Num> for (R, I, J, K)
+where 
+    R: ScalarConstructor<Num>,
+    I: ScalarConstructor<Num>,
+    J: ScalarConstructor<Num>,
+    K: ScalarConstructor<Num>,
+{}
 
 impl<Num: Axis, S> QuaternionMethods<Num> for [S; 4]
 where S: Scalar<Num> + ScalarConstructor<Num>
@@ -728,6 +1046,9 @@ where S: ScalarConsts<Num>
     const UNIT_J: Self = [S::ZERO, S::ZERO, S::ONE, S::ZERO];
     const UNIT_K: Self = [S::ZERO, S::ZERO, S::ZERO, S::ONE];
 }
+impl<Num: Axis, S> QuaternionFloatConsts<Num> for [S; 4]
+where S: ScalarConstructor<Num>
+{}
 
 impl<Num: Axis, S, V> QuaternionMethods<Num> for (S, V)
 where 
@@ -778,6 +1099,11 @@ where
     const UNIT_J: Self = (S::ZERO, V::UNIT_Y);
     const UNIT_K: Self = (S::ZERO, V::UNIT_Z);
 }
+impl<Num: Axis, S, V> QuaternionFloatConsts<Num> for (S, V)
+where 
+    S: ScalarConstructor<Num>,
+    V: VectorConstructor<Num>,
+{}
 
 impl<Num: Axis, C, J, K> QuaternionMethods<Num> for (C, J, K)
 where 
@@ -814,6 +1140,47 @@ where
     const UNIT_J: Self = (C::ORIGIN, J::ONE, K::ZERO);
     const UNIT_K: Self = (C::ORIGIN, J::ZERO, K::ONE);
 }
+impl<Num: Axis, C, J, K> QuaternionFloatConsts<Num> for (C, J, K)
+where 
+    C: ComplexConstructor<Num>,
+    J: ScalarConstructor<Num>,
+    K: ScalarConstructor<Num>,
+{}
+
+impl<Num: Axis, C> QuaternionMethods<Num> for [C; 2]
+where C: Complex<Num> + ComplexConstructor<Num>
+{
+    #[inline]
+    fn complex_part(self) -> Self {
+        let [complex, _] = self;
+        [complex, C::new_complex(Num::ZERO, Num::ZERO)]
+    }
+
+    #[inline]
+    fn from_complex(complex: impl Complex<Num>) -> Self {
+        [C::from_complex(complex), C::new_complex(Num::ZERO, Num::ZERO)]
+    }
+
+    #[inline]
+    // There might be edgecases where `from_complex(c)` is not `new_complex(c.real(), c.imaginary())`
+    fn to_complex<Out: ComplexConstructor<Num>>(self) -> Out {
+        let [complex, _] = self;
+        ComplexConstructor::from_complex(complex)
+    }
+}
+impl<Num: Axis, C> QuaternionConsts<Num> for [C; 2]
+where C: ComplexConsts<Num>
+{
+    const ORIGIN: Self = [C::ORIGIN, C::ORIGIN];
+    const IDENTITY: Self = [C::IDENTITY, C::ORIGIN];
+    const NAN: Self = [C::NAN, C::NAN];
+    const UNIT_I: Self = [C::UNIT_IMAGINARY, C::ORIGIN];
+    const UNIT_J: Self = [C::ORIGIN, C::IDENTITY];
+    const UNIT_K: Self = [C::ORIGIN, C::UNIT_IMAGINARY];
+}
+impl<Num: Axis, C> QuaternionFloatConsts<Num> for [C; 2]
+where C: ComplexConstructor<Num>
+{}
 
 impl<Num: Axis, Q> Quaternion<Num> for (Q, )
 where Q: Quaternion<Num>
@@ -834,6 +1201,18 @@ where Q: QuaternionConstructor<Num>
     }
 }
 
+impl<Num: Axis, Q> QuaternionConsts<Num> for (Q, )
+where Q: QuaternionConsts<Num>
+{
+    const ORIGIN: Self = (Q::ORIGIN, );
+    const IDENTITY: Self = (Q::IDENTITY, );
+    const NAN: Self = (Q::NAN, );
+    const UNIT_R: Self = (Q::UNIT_R, );
+    const UNIT_I: Self = (Q::UNIT_I, );
+    const UNIT_J: Self = (Q::UNIT_J, );
+    const UNIT_K: Self = (Q::UNIT_K, );
+}
+
 impl<Num: Axis, Q> Quaternion<Num> for [Q; 1]
 where Q: Quaternion<Num>
 {
@@ -853,6 +1232,18 @@ where Q: QuaternionConstructor<Num>
     }
 }
 
+impl<Num: Axis, Q> QuaternionConsts<Num> for [Q; 1]
+where Q: QuaternionConsts<Num>
+{
+    const ORIGIN: Self = [Q::ORIGIN];
+    const IDENTITY: Self = [Q::IDENTITY];
+    const NAN: Self = [Q::NAN];
+    const UNIT_R: Self = [Q::UNIT_R];
+    const UNIT_I: Self = [Q::UNIT_I];
+    const UNIT_J: Self = [Q::UNIT_J];
+    const UNIT_K: Self = [Q::UNIT_K];
+}
+
 // Unit Quaternion impls
 
 impl<Num: Axis, U> UnitQuaternion<Num> for &U
@@ -881,6 +1272,17 @@ where Q: UnitQuaternionConstructor<Num>
     }
 }
 
+impl<Num: Axis, Q> UnitQuaternionConsts<Num> for (Q, )
+where Q: UnitQuaternionConsts<Num>
+{
+    const IDENTITY: Self = (Q::IDENTITY, );
+    const NAN: Self = (Q::NAN, );
+    const UNIT_R: Self = (Q::UNIT_R, );
+    const UNIT_I: Self = (Q::UNIT_I, );
+    const UNIT_J: Self = (Q::UNIT_J, );
+    const UNIT_K: Self = (Q::UNIT_K, );
+}
+
 impl<Num: Axis, Q> UnitQuaternion<Num> for [Q; 1]
 where Q: UnitQuaternion<Num>
 { }
@@ -895,6 +1297,17 @@ where Q: UnitQuaternionConstructor<Num>
     }
 }
 
+impl<Num: Axis, Q> UnitQuaternionConsts<Num> for [Q; 1]
+where Q: UnitQuaternionConsts<Num>
+{
+    const IDENTITY: Self = [Q::IDENTITY];
+    const NAN: Self = [Q::NAN];
+    const UNIT_R: Self = [Q::UNIT_R];
+    const UNIT_I: Self = [Q::UNIT_I];
+    const UNIT_J: Self = [Q::UNIT_J];
+    const UNIT_K: Self = [Q::UNIT_K];
+}
+
 // Scalar impls
 
 impl<Num: Axis> Scalar<Num> for () {
@@ -905,6 +1318,16 @@ impl<Num: Axis, T> Scalar<Num> for [T; 0] {
     #[inline(always)] fn scalar(&self) -> Num { Num::ZERO }
 }
 
+impl<Num: Axis> ScalarConstructor<Num> for () {
+    #[inline(always)] fn new_scalar(_: Num) { }
+    #[inline(always)] fn from_scalar(_: impl Scalar<Num>) { }
+}
+
+impl<Num: Axis, T> ScalarConstructor<Num> for [T; 0] {
+    #[inline(always)] fn new_scalar(_: Num) -> Self { [] }
+    #[inline(always)] fn from_scalar(_: impl Scalar<Num>) -> Self { [] }
+}
+
 impl<Num: Axis> Scalar<Num> for Num {
     #[inline(always)] fn scalar(&self) -> Num { *self }
 }
@@ -973,6 +1396,16 @@ impl<Num: Axis, T> Complex<Num> for [T; 0] {
     #[inline(always)] fn imaginary(&self) -> Num { Num::ZERO }
 }
 
+impl<Num: Axis> ComplexConstructor<Num> for () {
+    #[inline(always)] fn new_complex(_: Num, _: Num) { }
+    #[inline(always)] fn from_complex(_: impl Complex<Num>) { }
+}
+
+impl<Num: Axis, T> ComplexConstructor<Num> for [T; 0] {
+    #[inline(always)] fn new_complex(_: Num, _: Num) -> Self { [] }
+    #[inline(always)] fn from_complex(_: impl Complex<Num>) -> Self { [] }
+}
+
 impl<Num: Axis, R, I> Complex<Num> for (R, I)
 where 
     R: Scalar<Num>,
@@ -1059,6 +1492,16 @@ where C: ComplexConstructor<Num>
     }
 }
 
+impl<Num: Axis, C> ComplexConsts<Num> for (C, )
+where C: ComplexConsts<Num>
+{
+    const ORIGIN: Self = (C::ORIGIN, );
+    const IDENTITY: Self = (C::IDENTITY, );
+    const NAN: Self = (C::NAN, );
+    const UNIT_REAL: Self = (C::UNIT_REAL, );
+    const UNIT_IMAGINARY: Self = (C::UNIT_IMAGINARY, );
+}
+
 impl<Num: Axis, C> Complex<Num> for [C; 1]
 where C: Complex<Num>
 {
@@ -1076,6 +1519,16 @@ where C: ComplexConstructor<Num>
     }
 }
 
+impl<Num: Axis, C> ComplexConsts<Num> for [C; 1]
+where C: ComplexConsts<Num>
+{
+    const ORIGIN: Self = [C::ORIGIN];
+    const IDENTITY: Self = [C::IDENTITY];
+    const NAN: Self = [C::NAN];
+    const UNIT_REAL: Self = [C::UNIT_REAL];
+    const UNIT_IMAGINARY: Self = [C::UNIT_IMAGINARY];
+}
+
 // Vector impls
 
 impl<Num: Axis> Vector<Num> for () {
@@ -1090,6 +1543,16 @@ impl<Num: Axis, T> Vector<Num> for [T; 0] {
     #[inline(always)] fn z(&self) -> Num { Num::ZERO }
 }
 
+impl<Num: Axis> VectorConstructor<Num> for () {
+    #[inline(always)] fn new_vector(_: Num, _: Num, _: Num) { }
+    #[inline(always)] fn from_vector(_: impl Vector<Num>) { }
+}
+
+impl<Num: Axis, T> VectorConstructor<Num> for [T; 0] {
+    #[inline(always)] fn new_vector(_: Num, _: Num, _: Num) -> Self { [] }
+    #[inline(always)] fn from_vector(_: impl Vector<Num>) -> Self { [] }
+}
+
 impl<Num: Axis, X, Y, Z> Vector<Num> for (X, Y, Z)
 where
     X: Scalar<Num>,
@@ -1187,6 +1650,16 @@ where V: VectorConstructor<Num>
     }
 }
 
+impl<Num: Axis, V> VectorConsts<Num> for (V, )
+where V: VectorConsts<Num>
+{
+    const ORIGIN: Self = (V::ORIGIN, );
+    const NAN: Self = (V::NAN, );
+    const UNIT_X: Self = (V::UNIT_X, );
+    const UNIT_Y: Self = (V::UNIT_Y, );
+    const UNIT_Z: Self = (V::UNIT_Z, );
+}
+
 impl<Num: Axis, V> Vector<Num> for [V; 1]
 where V: Vector<Num>
 {
@@ -1205,6 +1678,16 @@ where V: VectorConstructor<Num>
     }
 }
 
+impl<Num: Axis, V> VectorConsts<Num> for [V; 1]
+where V: VectorConsts<Num>
+{
+    const ORIGIN: Self = [V::ORIGIN];
+    const NAN: Self = [V::NAN];
+    const UNIT_X: Self = [V::UNIT_X];
+    const UNIT_Y: Self = [V::UNIT_Y];
+    const UNIT_Z: Self = [V::UNIT_Z];
+}
+
 // Rotation impls
 
 #[cfg(feature = "rotation")]
@@ -1221,6 +1704,18 @@ impl<Num: Axis, T> Rotation<Num> for [T; 0] {
     #[inline(always)] fn yaw(&self) -> Num { Num::ZERO }
 }
 
+#[cfg(feature = "rotation")]
+impl<Num: Axis> RotationConstructor<Num> for () {
+    #[inline(always)] fn new_rotation(_: Num, _: Num, _: Num) { }
+    #[inline(always)] fn from_rotation(_: impl Rotation<Num>) { }
+}
+
+#[cfg(feature = "rotation")]
+impl<Num: Axis, T> RotationConstructor<Num> for [T; 0] {
+    #[inline(always)] fn new_rotation(_: Num, _: Num, _: Num) -> Self { [] }
+    #[inline(always)] fn from_rotation(_: impl Rotation<Num>) -> Self { [] }
+}
+
 #[cfg(feature = "rotation")]
 impl<Num: Axis, R> Rotation<Num> for (R, )
 where R: Rotation<Num>
@@ -1330,6 +1825,9 @@ impl<T: crate::core::clone::Clone, const N: usize> Matrix<T, N> for [[T; N]; N]
 {
     #[inline]
     fn get_unchecked( &self, row: usize, col: usize ) -> T {
+        if row >= N || col >= N {
+            crate::core::panic!("Out of index operation! Got ({row}, {col}), accepting at most ({}, {})", N.saturating_sub(1), N.saturating_sub(1))
+        }
         self[row][col].clone()
     }
 }
@@ -1341,6 +1839,13 @@ impl<T: crate::core::clone::Clone, const N: usize> MatrixConstructor<T, N> for [
     fn new_matrix(matrix: [[T; N]; N]) -> Self { matrix }
 }
 
+// `[T; 0]` would overlap with `[[T; 0]; 0]` above (which already discards every `0x0` matrix)
+#[cfg(feature = "matrix")]
+impl<T, const N: usize> MatrixConstructor<T, N> for () {
+    #[inline(always)] fn new_matrix(_: [[T; N]; N]) { }
+    #[inline(always)] fn from_matrix(_: impl Matrix<T, N>) { }
+}
+
 #[cfg(feature = "matrix")]
 mod matrix;
 
@@ -1392,6 +1897,16 @@ use crate::core::cell::{
     Ref,
     RefMut,
     LazyCell,
+    Cell,
+    RefCell,
+    OnceCell,
+};
+
+#[cfg(feature = "std")]
+use crate::std::sync::{
+    MutexGuard,
+    RwLockReadGuard,
+    RwLockWriteGuard,
 };
 
 macro_rules! ref_impls {
@@ -1455,11 +1970,100 @@ ref_impls!{Ref<'_, T>}
 ref_impls!{RefMut<'_, T>}
 ref_impls!{ManuallyDrop<T>}
 ref_impls!{&mut T}
+#[cfg(feature = "std")] ref_impls!{MutexGuard<'_, T>}
+#[cfg(feature = "std")] ref_impls!{RwLockReadGuard<'_, T>}
+#[cfg(feature = "std")] ref_impls!{RwLockWriteGuard<'_, T>}
+
+/// Reads a copy of the value of the [Cell].
+#[inline]
+fn read_cell<T: crate::core::marker::Copy, R>(cell: &Cell<T>, read: impl crate::core::ops::FnOnce(&T) -> R) -> R {
+    read(&cell.get())
+}
+
+/// Borrows the value of the [RefCell].
+/// 
+/// # Panics
+/// If the [RefCell] is currently mutably borrowed.
+#[inline]
+fn read_ref_cell<T, R>(cell: &RefCell<T>, read: impl crate::core::ops::FnOnce(&T) -> R) -> R {
+    read(&*cell.borrow())
+}
+
+/// Reads the value of the [OnceCell].
+/// 
+/// # Panics
+/// If the [OnceCell] has not been initialized yet.
+#[inline]
+fn read_once_cell<T, R>(cell: &OnceCell<T>, read: impl crate::core::ops::FnOnce(&T) -> R) -> R {
+    match cell.get() {
+        Option::Some(value) => read(value),
+        Option::None => crate::core::panic!("Empty OnceCell! It has to be initialized to read it as a quaternion"),
+    }
+}
+
+macro_rules! read_impls {
+    ( $ty:ty $(: $( $trait:ident )::+ )? => $read:ident ) => {
+        impl<Num: Axis, T> Quaternion<Num> for $ty
+        where T: Quaternion<Num> $( + $( $trait )::+ )?
+        {
+            fn r(&self) -> Num { $read(self, |inner: &T| inner.r()) }
+            fn i(&self) -> Num { $read(self, |inner: &T| inner.i()) }
+            fn j(&self) -> Num { $read(self, |inner: &T| inner.j()) }
+            fn k(&self) -> Num { $read(self, |inner: &T| inner.k()) }
+        }
+
+        impl<Num: Axis, T> UnitQuaternion<Num> for $ty
+        where T: UnitQuaternion<Num> $( + $( $trait )::+ )?
+        { }
+
+        impl<Num: Axis, T> Vector<Num> for $ty
+        where T: Vector<Num> $( + $( $trait )::+ )?
+        {
+            fn x(&self) -> Num { $read(self, |inner: &T| inner.x()) }
+            fn y(&self) -> Num { $read(self, |inner: &T| inner.y()) }
+            fn z(&self) -> Num { $read(self, |inner: &T| inner.z()) }
+        }
+
+        impl<Num: Axis, T> Complex<Num> for $ty
+        where T: Complex<Num> $( + $( $trait )::+ )?
+        {
+            fn real(&self) -> Num { $read(self, |inner: &T| inner.real()) }
+            fn imaginary(&self) -> Num { $read(self, |inner: &T| inner.imaginary()) }
+        }
+
+        #[cfg(feature = "rotation")]
+        impl<Num: Axis, T> Rotation<Num> for $ty
+        where T: Rotation<Num> $( + $( $trait )::+ )?
+        {
+            fn roll(&self) -> Num { $read(self, |inner: &T| inner.roll()) }
+            fn pitch(&self) -> Num { $read(self, |inner: &T| inner.pitch()) }
+            fn yaw(&self) -> Num { $read(self, |inner: &T| inner.yaw()) }
+        }
+
+        #[cfg(feature = "matrix")]
+        impl<Elem, const N: usize, T> Matrix<Elem, N> for $ty
+        where T: Matrix<Elem, N> $( + $( $trait )::+ )?
+        {
+            #[inline] fn get_unchecked( &self, row: usize, col: usize ) -> Elem { $read(self, |inner: &T| inner.get_unchecked(row, col)) }
+
+            #[inline] fn get( &self, row: usize, col: usize ) -> Option<Elem> { $read(self, |inner: &T| inner.get(row, col)) }
+
+            #[inline] fn to_array( &self ) -> [[Elem; N]; N] { $read(self, |inner: &T| inner.to_array()) }
+        }
+    };
+}
+
+read_impls!{Cell<T>: crate::core::marker::Copy => read_cell}
+read_impls!{RefCell<T> => read_ref_cell}
+read_impls!{OnceCell<T> => read_once_cell}
 
 // Other impls
 
 mod axis;
 
+#[cfg(feature = "compact_float")]
+mod compact_float;
+
 mod quat_methods;
 
 mod core_impls;
@@ -1,9 +1,13 @@
 
+#[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
 use crate::libm;
+#[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+use crate::std;
 use crate::core::{
     ops::{Add, Sub, Mul, Div, Rem, Neg},
     cmp::{PartialEq, PartialOrd},
-    marker::{Copy, Sized}
+    marker::{Copy, Sized},
+    num::FpCategory,
 };
 
 /**
@@ -28,6 +32,8 @@ If you want to implement this trait for a custom type `T` make sure the followin
 
 `T::ZERO < T::ERROR < T::ONE`
 
+`T::ZERO < T::EPS_ARITH <= T::EPS_TRIG < T::ONE`
+
 For any `a: T` (optionaly for `T::NAN`) -> `a == a`
 
 For any `a: T` -> `a + T::ZERO == a`
@@ -45,6 +51,11 @@ For any `a: T, b: T` -> `a * b == b * a`
 For any `a: T, b: T` -> `a / b == a * (T::ONE / b)`
 
 For any `a: T, b: T` -> `(a + b) - b == a`
+
+The rest of the contract (the constants, the trigonometric identities,
+[`from_u8`](Axis::from_u8) being exact, ...) is checked by
+`check_axis_conformance` in the `axis_tests` module (feature `test_harness`),
+run it on your type in your own tests.
 */
 pub trait Axis: Sized
               + Add<Output = Self>
@@ -68,7 +79,25 @@ pub trait Axis: Sized
     /// The representation of a `Not a Number` value.
     const NAN: Self;
     /// Used as the aporximative precision error for flaoting point arithmatic.
+    /// 
+    /// It's the general precision step (used for things like picking a numerically stable branch),
+    /// comparisons of results use [`EPS_ARITH`](Axis::EPS_ARITH) or [`EPS_TRIG`](Axis::EPS_TRIG).
     const ERROR: Self;
+    /// The tolerance for results of a few arithmetic operations
+    /// (additions, multiplications, ...), used by [`is_near`](crate::quat::is_near)
+    /// and the other comparisons of plain values.
+    /// 
+    /// Defaults to [`ERROR`](Axis::ERROR).
+    const EPS_ARITH: Self = Self::ERROR;
+    /// The tolerance for results that went through trigonometric functions
+    /// (or square roots), used by the unit quaternion checks
+    /// (like [`is_normalized`](crate::quat::is_normalized) and [`UnitQuat::new`](crate::structs::UnitQuat::new))
+    /// and by `assert_same_rotation!`.
+    /// 
+    /// The types of this crate use `8 * ERROR`,
+    /// but since that can't be calculated in a trait default this defaults to [`ERROR`](Axis::ERROR),
+    /// so set it if your type should be as forgiving.
+    const EPS_TRIG: Self = Self::ERROR;
     // /// The representation of the ∞ value.
     // const INF: Self;
     // /// The representation of the -∞ value.
@@ -77,11 +106,32 @@ pub trait Axis: Sized
     /// Checks to see if `self` is NAN. (`x == Self::NAN` is not guaranteed to work)
     fn is_nan(&self) -> bool;
 
+    /// Gets what kind of number `self` is (zero, subnormal, normal, infinite or NaN).
+    /// 
+    /// By default this only tells apart [`Nan`](FpCategory::Nan), [`Zero`](FpCategory::Zero)
+    /// and [`Normal`](FpCategory::Normal), which is right for fixed point and integer like types,
+    /// floating point types should override it.
+    #[inline]
+    fn classify(self) -> FpCategory {
+        if self.is_nan() { FpCategory::Nan }
+        else if self == Self::ZERO { FpCategory::Zero }
+        else { FpCategory::Normal }
+    }
+
     /// Calculates `(self * factor) + addend`.
     fn mul_add(self, factor: Self, addend: Self) -> Self;
 
     /// Calculates the square root of `self`.
     fn sqrt(self) -> Self;
+    /// Calculates `ONE / sqrt(self)` for a positive `self`, trading some precision for speed.
+    /// 
+    /// By default this is just `ONE / self.sqrt()`, [f32] (and [`Std<f32>`](crate::structs::Std))
+    /// use the bit trick with two newton iterations instead, which stays within a
+    /// relative error of `1e-6` (so it's not bit-identical to `ONE / sqrt(x)`).
+    /// 
+    /// The result for zero, negative, infinite or NaN values is unspecified.
+    #[inline]
+    fn inv_sqrt_fast(self) -> Self { Self::ONE / self.sqrt() }
     /// Calculates the `self` raised to the `exp` power.
     fn pow(self, exp: Self) -> Self;
     /// Calculates the sine and cosine of `self` at once.
@@ -98,7 +148,7 @@ pub trait Axis: Sized
     }
     /// Calculates the cosine of `self`.
     #[inline]
-    fn cos(self) -> Self { self.sin_cos().0 }
+    fn cos(self) -> Self { self.sin_cos().1 }
     /// Calculates the arccosine of `self`.
     fn acos(self) -> Self;
     /// Calculates the arccosine of `self`.
@@ -134,7 +184,49 @@ pub trait Axis: Sized
     }
     
     /// Turns a [`f64`] into `Self`
+    /// 
+    /// Values that can't be represented exactly should be rounded to the nearest
+    /// representable value, with ties going to the even one (like `as` does for floats).
     fn from_f64( float: f64 ) -> Self;
+
+    /// Turns a [`f32`] into `Self`, rounding like [`from_f64`](Axis::from_f64).
+    /// 
+    /// By default this is `from_f64(float as f64)`, which rounds only once
+    /// since every [`f32`] is exactly representable as an [`f64`].
+    #[inline]
+    fn from_f32( float: f32 ) -> Self {
+        Self::from_f64(float as f64)
+    }
+
+    /// Turns an [`i32`] into `Self`, rounding like [`from_f64`](Axis::from_f64).
+    /// 
+    /// By default this is `from_f64(int as f64)`, which rounds only once
+    /// since every [`i32`] is exactly representable as an [`f64`].
+    #[inline]
+    fn from_i32( int: i32 ) -> Self {
+        Self::from_f64(int as f64)
+    }
+
+    /// Turns a [`u32`] into `Self`, rounding like [`from_f64`](Axis::from_f64).
+    /// 
+    /// By default this is `from_f64(uint as f64)`, which rounds only once
+    /// since every [`u32`] is exactly representable as an [`f64`].
+    #[inline]
+    fn from_u32( uint: u32 ) -> Self {
+        Self::from_f64(uint as f64)
+    }
+
+    /// Turns a [`usize`] into `Self`, rounding like [`from_f64`](Axis::from_f64).
+    /// 
+    /// By default this is `from_f64(uint as f64)`.
+    /// Values above `2^53` are rounded when turned into an [`f64`] and then
+    /// again by `from_f64`, and rounding twice can land one step away from
+    /// the nearest value of `Self`, so types that can hold such large values exactly
+    /// (or that are less precise then [`f64`]) should override this.
+    #[inline]
+    fn from_usize( uint: usize ) -> Self {
+        Self::from_f64(uint as f64)
+    }
     
     // #[deprecated(note = "Use `from_f64` instead.")]
     /// Turns a [`u8`] into `Self` (Note: this could be decapricated)
@@ -145,6 +237,22 @@ pub trait Axis: Sized
         }
         out
     }
+
+    #[inline]
+    #[cfg(feature = "display")]
+    /// Writes this number to a formatter/string.
+    /// 
+    /// This is what [`quat::display`](crate::quat::display) uses for every number
+    /// it shows, by default this just uses the [`Display`](crate::core::fmt::Display) implementation.
+    /// 
+    /// With the `compact_float` feature [f32] and [f64] use a small
+    /// formatter of this crate instead of the one in `core`.
+    fn write_axis( self, target: &mut impl crate::core::fmt::Write ) -> crate::core::fmt::Result
+    where 
+        Self: crate::core::fmt::Display,
+    {
+        crate::core::write!(target, "{}", self)
+    }
 }
 
 impl Axis for f32 {
@@ -152,55 +260,137 @@ impl Axis for f32 {
     const ZERO: Self = 0.0;
     const TAU: Self = crate::core::f32::consts::TAU;
     const NAN: Self = f32::NAN;
-    const ERROR: Self = 0.00001525878; // 2 ^ -16
+    const ERROR: Self = 1.0 / 65536.0; // 2 ^ -16
+    const EPS_TRIG: Self = 8.0 / 65536.0; // 2 ^ -13 = 8 * ERROR
 
     #[inline]
     fn is_nan( &self ) -> bool { f32::is_nan(*self) }
 
+    #[inline]
+    fn classify( self ) -> FpCategory { f32::classify(self) }
+
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn mul_add( self, factor: Self, addend: Self ) -> Self { self * factor + addend }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn mul_add( self, factor: Self, addend: Self ) -> Self { std::primitive::f32::mul_add(self, factor, addend) }
 
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn sqrt( self ) -> Self { libm::sqrtf(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn sqrt( self ) -> Self { std::primitive::f32::sqrt(self) }
+
+    #[inline]
+    fn inv_sqrt_fast( self ) -> Self {
+        // the constants of "Fast inverse square root method with modified newton iteration" (Moroz et al.)
+        let mut inv: f32 = f32::from_bits(0x5F1F_FFF9_u32.wrapping_sub(self.to_bits() >> 1));
+        inv *= 0.703_952_3 * (2.389_244_6 - self * inv * inv);
+        inv * (1.5 - 0.5 * self * inv * inv)
+    }
 
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn pow( self, exp: Self ) -> Self { libm::powf(self, exp) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn pow( self, exp: Self ) -> Self { std::primitive::f32::powf(self, exp) }
     
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn sin_cos( self ) -> (Self, Self) { libm::sincosf(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn sin_cos( self ) -> (Self, Self) { std::primitive::f32::sin_cos(self) }
 
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn sin( self ) -> Self { libm::sinf(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn sin( self ) -> Self { std::primitive::f32::sin(self) }
 
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn asin( self ) -> Self { libm::asinf(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn asin( self ) -> Self { std::primitive::f32::asin(self) }
 
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn sinh( self ) -> Self { libm::sinhf(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn sinh( self ) -> Self { std::primitive::f32::sinh(self) }
 
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn cos( self ) -> Self { libm::cosf(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn cos( self ) -> Self { std::primitive::f32::cos(self) }
 
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn acos( self ) -> Self { libm::acosf(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn acos( self ) -> Self { std::primitive::f32::acos(self) }
 
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn cosh( self ) -> Self { libm::coshf(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn cosh( self ) -> Self { std::primitive::f32::cosh(self) }
 
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn exp( self ) -> Self { libm::expf(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn exp( self ) -> Self { std::primitive::f32::exp(self) }
 
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn ln( self ) -> Self { libm::logf(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn ln( self ) -> Self { std::primitive::f32::ln(self) }
     
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn atan2( self, bottom: Self ) -> Self { libm::atan2f(self, bottom) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn atan2( self, bottom: Self ) -> Self { std::primitive::f32::atan2(self, bottom) }
 
     #[inline(always)]
     fn from_u8( uint: u8 ) -> Self { uint as Self }
 
     #[inline(always)]
     fn from_f64( float: f64 ) -> Self { float as Self }
+
+    #[inline(always)]
+    fn from_f32( float: f32 ) -> Self { float }
+
+    #[inline(always)]
+    fn from_i32( int: i32 ) -> Self { int as Self }
+
+    #[inline(always)]
+    fn from_u32( uint: u32 ) -> Self { uint as Self }
+
+    #[inline(always)]
+    fn from_usize( uint: usize ) -> Self { uint as Self }
+
+    #[inline]
+    #[cfg(feature = "compact_float")]
+    fn write_axis( self, target: &mut impl crate::core::fmt::Write ) -> crate::core::fmt::Result {
+        super::compact_float::write_f32(target, self)
+    }
 }
 
 impl Axis for f64 {
@@ -208,53 +398,127 @@ impl Axis for f64 {
     const ZERO: Self = 0.0;
     const TAU: Self = crate::core::f64::consts::TAU;
     const NAN: Self = f64::NAN;
-    const ERROR: Self = 0.00001525878; // 2 ^ -16
+    const ERROR: Self = 1.0 / 65536.0; // 2 ^ -16
+    const EPS_TRIG: Self = 8.0 / 65536.0; // 2 ^ -13 = 8 * ERROR
 
     #[inline]
     fn is_nan( &self ) -> bool { f64::is_nan(*self) }
 
+    #[inline]
+    fn classify( self ) -> FpCategory { f64::classify(self) }
+
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn mul_add( self, factor: Self, addend: Self ) -> Self { self * factor + addend }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn mul_add( self, factor: Self, addend: Self ) -> Self { std::primitive::f64::mul_add(self, factor, addend) }
 
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn sqrt( self ) -> Self { libm::sqrt(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn sqrt( self ) -> Self { std::primitive::f64::sqrt(self) }
 
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn pow( self, exp: Self ) -> Self { libm::pow(self, exp) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn pow( self, exp: Self ) -> Self { std::primitive::f64::powf(self, exp) }
     
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn sin_cos( self ) -> (Self, Self) { libm::sincos(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn sin_cos( self ) -> (Self, Self) { std::primitive::f64::sin_cos(self) }
     
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn sin( self ) -> Self { libm::sin(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn sin( self ) -> Self { std::primitive::f64::sin(self) }
 
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn asin( self ) -> Self { libm::asin(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn asin( self ) -> Self { std::primitive::f64::asin(self) }
     
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn sinh( self ) -> Self { libm::sinh(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn sinh( self ) -> Self { std::primitive::f64::sinh(self) }
     
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn cos( self ) -> Self { libm::cos(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn cos( self ) -> Self { std::primitive::f64::cos(self) }
     
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn acos( self ) -> Self { libm::acos(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn acos( self ) -> Self { std::primitive::f64::acos(self) }
     
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn cosh( self ) -> Self { libm::cosh(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn cosh( self ) -> Self { std::primitive::f64::cosh(self) }
     
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn exp( self ) -> Self { libm::exp(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn exp( self ) -> Self { std::primitive::f64::exp(self) }
     
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn ln( self ) -> Self { libm::log(self) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn ln( self ) -> Self { std::primitive::f64::ln(self) }
     
     #[inline(always)]
+    #[cfg(any(not(feature = "std_axis_for_primitives"), feature = "deterministic"))]
     fn atan2( self, bottom: Self ) -> Self { libm::atan2(self, bottom) }
+    #[inline(always)]
+    #[cfg(all(feature = "std_axis_for_primitives", not(feature = "deterministic")))]
+    fn atan2( self, bottom: Self ) -> Self { std::primitive::f64::atan2(self, bottom) }
 
     #[inline(always)]
     fn from_u8( uint: u8 ) -> Self { uint as Self }
 
     #[inline(always)]
     fn from_f64( float: f64 ) -> Self { float }
+
+    #[inline(always)]
+    fn from_f32( float: f32 ) -> Self { float as Self }
+
+    #[inline(always)]
+    fn from_i32( int: i32 ) -> Self { int as Self }
+
+    #[inline(always)]
+    fn from_u32( uint: u32 ) -> Self { uint as Self }
+
+    #[inline(always)]
+    fn from_usize( uint: usize ) -> Self { uint as Self }
+
+    #[inline]
+    #[cfg(feature = "compact_float")]
+    fn write_axis( self, target: &mut impl crate::core::fmt::Write ) -> crate::core::fmt::Result {
+        super::compact_float::write_f64(target, self)
+    }
 }
@@ -0,0 +1,131 @@
+//! A small float formatter used by [`Axis::write_axis`](super::Axis::write_axis) for
+//! [f32] and [f64] when the `compact_float` feature is enabled.
+//!
+//! It looks for the shortest amount of significant digits that parses back
+//! into the same number (checked using double-double arithmetic, so in rare cases it can be off by one ULP)
+//! and writes them in the same plain decimal notation as [`Display`](crate::core::fmt::Display),
+//! without going through the (quite large) float formatting of `core`.
+
+use crate::core::fmt::{Write, Result};
+use crate::core::ops::Fn;
+use crate::libm;
+
+/// Used to write multiple zeros at once.
+const ZEROS: &str = "0000000000000000";
+
+#[inline]
+pub(crate) fn write_f32(target: &mut impl Write, num: f32) -> Result {
+    write_float(target, num as f64, 9, |candidate: f64| candidate as f32 == num)
+}
+
+#[inline]
+pub(crate) fn write_f64(target: &mut impl Write, num: f64) -> Result {
+    write_float(target, num, 17, |candidate: f64| candidate == num)
+}
+
+fn write_float(target: &mut impl Write, num: f64, max_digits: i32, round_trips: impl Fn(f64) -> bool) -> Result {
+    if num.is_nan() {
+        return target.write_str("NaN");
+    }
+    if num.is_sign_negative() {
+        target.write_char('-')?;
+    }
+    let num: f64 = libm::fabs(num);
+    if num == f64::INFINITY {
+        return target.write_str("inf");
+    }
+    if num == 0.0 {
+        return target.write_char('0');
+    }
+
+    // num ~= mantissa / 10^scale
+    let exp10: i32 = libm::floor(libm::log10(num)) as i32;
+    let mut mantissa: u64 = 0;
+    let mut scale: i32 = 0;
+    for digits in 1..=max_digits {
+        scale = digits - 1 - exp10;
+        let (hi, lo) = scale_by((num, 0.0), scale);
+        let whole: f64 = libm::floor(hi);
+        mantissa = (whole as i64 + libm::round((hi - whole) + lo) as i64) as u64;
+        let mantissa_hi: f64 = mantissa as f64;
+        let mantissa_lo: f64 = (mantissa as i64 - mantissa_hi as i64) as f64;
+        if round_trips(scale_by((mantissa_hi, mantissa_lo), -scale).0) {
+            break;
+        }
+    }
+    while mantissa.is_multiple_of(10) && mantissa != 0 {
+        mantissa /= 10;
+        scale -= 1;
+    }
+
+    let mut buffer: [u8; 20] = [b'0'; 20];
+    let mut start: usize = buffer.len();
+    while mantissa != 0 || start == buffer.len() {
+        start -= 1;
+        buffer[start] = b'0' + (mantissa % 10) as u8;
+        mantissa /= 10;
+    }
+    // Only ASCII digits were written.
+    let digits: &str = match crate::core::str::from_utf8(&buffer[start..]) {
+        crate::core::result::Result::Ok(digits) => digits,
+        crate::core::result::Result::Err(_) => return crate::core::result::Result::Err(crate::core::fmt::Error),
+    };
+    let len: i32 = digits.len() as i32;
+
+    if scale <= 0 {
+        target.write_str(digits)?;
+        write_zeros(target, -scale)
+    } else if len > scale {
+        let (whole, fraction) = digits.split_at((len - scale) as usize);
+        target.write_str(whole)?;
+        target.write_char('.')?;
+        target.write_str(fraction)
+    } else {
+        target.write_str("0.")?;
+        write_zeros(target, scale - len)?;
+        target.write_str(digits)
+    }
+}
+
+#[inline]
+fn write_zeros(target: &mut impl Write, mut count: i32) -> Result {
+    while count > 0 {
+        let chunk: i32 = if count > ZEROS.len() as i32 { ZEROS.len() as i32 } else { count };
+        target.write_str(&ZEROS[..chunk as usize])?;
+        count -= chunk;
+    }
+    crate::core::result::Result::Ok(())
+}
+
+/// Gets `num * 10^exp` (using double-double arithmetic),
+/// splitting the power up so it doesn't overflow for subnormal numbers.
+#[inline]
+fn scale_by(num: (f64, f64), exp: i32) -> (f64, f64) {
+    let half: i32 = exp / 2;
+    mul(mul(num, pow10(half)), pow10(exp - half))
+}
+
+/// Gets `10^exp` as a double-double.
+fn pow10(exp: i32) -> (f64, f64) {
+    // 0.1 isn't exact in binary, the second half is the rest of it.
+    let mut base: (f64, f64) = if exp < 0 { (0.1, -5.551115123125783e-18) } else { (10.0, 0.0) };
+    let mut exp: u32 = exp.unsigned_abs();
+    let mut out: (f64, f64) = (1.0, 0.0);
+    while exp != 0 {
+        if exp & 1 == 1 {
+            out = mul(out, base);
+        }
+        base = mul(base, base);
+        exp >>= 1;
+    }
+    out
+}
+
+/// Multiplies two double-doubles.
+#[inline]
+fn mul((left_hi, left_lo): (f64, f64), (right_hi, right_lo): (f64, f64)) -> (f64, f64) {
+    let hi: f64 = left_hi * right_hi;
+    let lo: f64 = libm::fma(left_hi, right_hi, -hi) + (left_hi * right_lo + left_lo * right_hi);
+    let sum: f64 = hi + lo;
+    (sum, lo - (sum - hi))
+}
@@ -46,6 +46,19 @@ macro_rules! impl_scalar_T_for_primitive {
             #[inline] fn scalar(&self) -> $float { self.0 as $float }
         }
 
+        impl Scalar<$float> for crate::core::option::Option<NonZero<$ty>> {
+            #[inline] fn scalar(&self) -> $float {
+                match self {
+                    crate::core::option::Option::Some(num) => num.get() as $float,
+                    crate::core::option::Option::None => 0.0,
+                }
+            }
+        }
+
+        impl Scalar<$float> for &crate::core::option::Option<NonZero<$ty>> {
+            #[inline] fn scalar(&self) -> $float { (**self).scalar() }
+        }
+
         impl ScalarConstructor<$float> for $ty {
             #[inline] fn new_scalar(axis: $float) -> $ty { axis as $ty }
         }
@@ -61,6 +74,27 @@ macro_rules! impl_scalar_T_for_primitive {
         impl ScalarConstructor<$float> for Saturating<$ty> {
             #[inline] fn new_scalar(axis: $float) -> Self { Saturating(axis as $ty) }
         }
+
+        // `NonZero<T>` has no zero so it has no constants (or constructor),
+        // the NaN constants are what constructing from NaN gives (`NaN as T` is `0`).
+
+        impl ScalarConsts<$float> for crate::core::option::Option<NonZero<$ty>> {
+            const ZERO: Self = crate::core::option::Option::None;
+            const ONE: Self = NonZero::new(1);
+            const NAN: Self = crate::core::option::Option::None;
+        }
+
+        impl ScalarConsts<$float> for Wrapping<$ty> {
+            const ZERO: Self = Wrapping(0);
+            const ONE: Self = Wrapping(1);
+            const NAN: Self = Wrapping(0);
+        }
+
+        impl ScalarConsts<$float> for Saturating<$ty> {
+            const ZERO: Self = Saturating(0);
+            const ONE: Self = Saturating(1);
+            const NAN: Self = Saturating(0);
+        }
     };
     ( $( $ty:ty ),+ $(,)? ) => {
         $(
@@ -109,3 +143,70 @@ impl Scalar<f32> for &f32 {
 impl Scalar<f64> for &f64 {
     #[inline] fn scalar(&self) -> f64 { **self as f64 }
 }
+
+// `Duration` is a scalar in seconds.
+//
+// It's stored as whole seconds and nanoseconds, so going through `f64` loses the nanoseconds
+// for durations longer than about 104 days (`2^53` nanoseconds) and through `f32`
+// for anything longer than about 16 milliseconds (`2^24` nanoseconds).
+// Negative and NaN seconds saturate to `Duration::ZERO` and too many seconds to `Duration::MAX`.
+
+impl Scalar<f64> for crate::core::time::Duration {
+    #[inline] fn scalar(&self) -> f64 { self.as_secs_f64() }
+}
+
+impl Scalar<f32> for crate::core::time::Duration {
+    #[inline] fn scalar(&self) -> f32 { self.as_secs_f32() }
+}
+
+impl Scalar<f64> for &crate::core::time::Duration {
+    #[inline] fn scalar(&self) -> f64 { self.as_secs_f64() }
+}
+
+impl Scalar<f32> for &crate::core::time::Duration {
+    #[inline] fn scalar(&self) -> f32 { self.as_secs_f32() }
+}
+
+impl ScalarConstructor<f64> for crate::core::time::Duration {
+    #[inline] fn new_scalar(axis: f64) -> Self {
+        // also catches NaN
+        if axis > 0.0 {
+            crate::core::time::Duration::try_from_secs_f64(axis).unwrap_or(crate::core::time::Duration::MAX)
+        } else {
+            crate::core::time::Duration::ZERO
+        }
+    }
+}
+
+impl ScalarConstructor<f32> for crate::core::time::Duration {
+    #[inline] fn new_scalar(axis: f32) -> Self {
+        // also catches NaN
+        if axis > 0.0 {
+            crate::core::time::Duration::try_from_secs_f32(axis).unwrap_or(crate::core::time::Duration::MAX)
+        } else {
+            crate::core::time::Duration::ZERO
+        }
+    }
+}
+
+/// A [`Duration`](crate::core::time::Duration) can't be NaN, so [`NAN`](ScalarConsts::NAN)
+/// is [`Duration::ZERO`](crate::core::time::Duration::ZERO),
+/// the same duration [`new_scalar`](ScalarConstructor::new_scalar) gives for NaN seconds.
+/// Reading it back gives `0.0` seconds, not NaN.
+impl ScalarConsts<f64> for crate::core::time::Duration {
+    const ZERO: Self = crate::core::time::Duration::ZERO;
+    const ONE: Self = crate::core::time::Duration::from_secs(1);
+    /// [`Duration::ZERO`](crate::core::time::Duration::ZERO), there is no NaN duration.
+    const NAN: Self = crate::core::time::Duration::ZERO;
+}
+
+/// A [`Duration`](crate::core::time::Duration) can't be NaN, so [`NAN`](ScalarConsts::NAN)
+/// is [`Duration::ZERO`](crate::core::time::Duration::ZERO),
+/// the same duration [`new_scalar`](ScalarConstructor::new_scalar) gives for NaN seconds.
+/// Reading it back gives `0.0` seconds, not NaN.
+impl ScalarConsts<f32> for crate::core::time::Duration {
+    const ZERO: Self = crate::core::time::Duration::ZERO;
+    const ONE: Self = crate::core::time::Duration::from_secs(1);
+    /// [`Duration::ZERO`](crate::core::time::Duration::ZERO), there is no NaN duration.
+    const NAN: Self = crate::core::time::Duration::ZERO;
+}
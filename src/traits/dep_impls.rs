@@ -244,3 +244,222 @@ mod num_bigint_impl {
     }
 
 }
+
+#[cfg(feature = "bevy_math")]
+mod bevy_math_impl {
+    //! `bevy_math` stores quaternions as `(x, y, z, w)`,
+    //! which are read here as `(i, j, k, r)`.
+    //! 
+    //! Its matrices are column-major, so they are transposed
+    //! to follow the row-major [`Matrix`](crate::Matrix) convention.
+    use crate::bevy_math::{
+        Quat,
+        DQuat,
+        Vec3,
+        Vec3A,
+        DVec3,
+    };
+
+    macro_rules! impl_quat {
+        ($quat:ty, $num:ty) => {
+            impl crate::Quaternion<$num> for $quat {
+                #[inline] fn r(&self) -> $num { self.w }
+                #[inline] fn i(&self) -> $num { self.x }
+                #[inline] fn j(&self) -> $num { self.y }
+                #[inline] fn k(&self) -> $num { self.z }
+            }
+
+            impl crate::QuaternionConstructor<$num> for $quat {
+                #[inline] fn new_quat(r: $num, i: $num, j: $num, k: $num) -> Self {
+                    <$quat>::from_xyzw(i, j, k, r)
+                }
+            }
+
+            impl crate::QuaternionConsts<$num> for $quat {
+                const ORIGIN: Self = <$quat>::from_xyzw(0.0, 0.0, 0.0, 0.0);
+                const IDENTITY: Self = <$quat>::IDENTITY;
+                const NAN: Self = <$quat>::NAN;
+
+                const UNIT_I: Self = <$quat>::from_xyzw(1.0, 0.0, 0.0, 0.0);
+                const UNIT_J: Self = <$quat>::from_xyzw(0.0, 1.0, 0.0, 0.0);
+                const UNIT_K: Self = <$quat>::from_xyzw(0.0, 0.0, 1.0, 0.0);
+            }
+
+            // bevy only uses its quaternions as rotations
+            impl crate::UnitQuaternion<$num> for $quat { }
+        };
+    }
+
+    impl_quat!(Quat, f32);
+    impl_quat!(DQuat, f64);
+
+    macro_rules! impl_vector {
+        ($vec:ty, $num:ty) => {
+            impl crate::Vector<$num> for $vec {
+                #[inline] fn x(&self) -> $num { self.x }
+                #[inline] fn y(&self) -> $num { self.y }
+                #[inline] fn z(&self) -> $num { self.z }
+            }
+
+            impl crate::VectorConstructor<$num> for $vec {
+                #[inline] fn new_vector(x: $num, y: $num, z: $num) -> Self {
+                    <$vec>::new(x, y, z)
+                }
+            }
+
+            impl crate::VectorConsts<$num> for $vec {
+                const ORIGIN: Self = <$vec>::ZERO;
+                const NAN: Self = <$vec>::NAN;
+                const UNIT_X: Self = <$vec>::X;
+                const UNIT_Y: Self = <$vec>::Y;
+                const UNIT_Z: Self = <$vec>::Z;
+            }
+        };
+    }
+
+    impl_vector!(Vec3, f32);
+    impl_vector!(Vec3A, f32);
+    impl_vector!(DVec3, f64);
+
+    #[cfg(feature = "matrix")]
+    macro_rules! impl_matrix {
+        ($mat:ty, $num:ty, $n:literal) => {
+            impl crate::Matrix<$num, $n> for $mat {
+                #[inline]
+                fn get_unchecked( &self, row: usize, col: usize ) -> $num {
+                    if row >= $n || col >= $n {
+                        crate::core::panic!("Out of index operation! Got ({row}, {col}), accepting at most ({}, {})", $n - 1, $n - 1)
+                    }
+                    self.col(col)[row]
+                }
+
+                #[inline]
+                fn to_array( &self ) -> [[$num; $n]; $n] {
+                    let cols = self.to_cols_array_2d();
+                    crate::core::array::from_fn(|row| crate::core::array::from_fn(|col| cols[col][row]))
+                }
+            }
+
+            impl crate::MatrixConstructor<$num, $n> for $mat {
+                #[inline]
+                fn new_matrix(matrix: [[$num; $n]; $n]) -> Self {
+                    <$mat>::from_cols_array_2d(&crate::core::array::from_fn(|col| crate::core::array::from_fn(|row| matrix[row][col])))
+                }
+            }
+        };
+    }
+
+    #[cfg(feature = "matrix")]
+    mod matrix_impls {
+        use crate::bevy_math::{
+            Mat3,
+            Mat4,
+            DMat3,
+            DMat4,
+        };
+
+        impl_matrix!(Mat3, f32, 3);
+        impl_matrix!(Mat4, f32, 4);
+        impl_matrix!(DMat3, f64, 3);
+        impl_matrix!(DMat4, f64, 4);
+    }
+}
+
+#[cfg(feature = "rkyv")]
+mod rkyv_impl {
+    use crate::rkyv::rend::{
+        f32_le,
+        f32_be,
+        f64_le,
+        f64_be,
+    };
+    use crate::Scalar;
+
+    // archived floats are quaternion parts, so archived arrays and tuples
+    // (and the archived structs of this crate) can be read without deserializing
+    macro_rules! impl_archived_float {
+        ( $( $archived:ty: $num:ty ),+ ) => { $(
+            impl Scalar<$num> for $archived {
+                #[inline] fn scalar(&self) -> $num { self.to_native() }
+            }
+
+            impl Scalar<$num> for &$archived {
+                #[inline] fn scalar(&self) -> $num { self.to_native() }
+            }
+        )+ };
+    }
+
+    impl_archived_float!{
+        f32_le: f32,
+        f32_be: f32,
+        f64_le: f64,
+        f64_be: f64
+    }
+}
+
+#[cfg(feature = "ndarray")]
+mod ndarray_impl {
+    //! One dimensional arrays (owned ones, views, ...) are read as `[r, i, j, k]` quaternions
+    //! and as `[x, y, z]` vectors.
+    //! 
+    //! Their length is only known at runtime, so reading a part panics
+    //! if the array doesn't have exactly 4 (or 3) elements.
+    //! The missing parts aren't filled with zeros,
+    //! a row of the wrong shape is almost always a bug that should be seen.
+    use crate::{
+        Axis,
+        Quaternion,
+        QuaternionConstructor,
+        Vector,
+        VectorConstructor,
+    };
+    use crate::ndarray::{
+        arr1,
+        Array1,
+        ArrayBase,
+        Data,
+        Ix1,
+    };
+
+    /// Reads `array[index]`, panicking if the array isn't `len` long.
+    #[inline]
+    #[track_caller]
+    fn part<Num, S>(array: &ArrayBase<S, Ix1>, len: usize, index: usize) -> Num
+    where 
+        Num: Axis,
+        S: Data<Elem = Num>,
+    {
+        if array.len() != len {
+            crate::core::panic!(
+                "Wrong array length! Got {} elements, but {} are needed to read it as a {}",
+                array.len(), len, if len == 4 { "quaternion" } else { "vector" },
+            );
+        }
+        array[index]
+    }
+
+    impl<Num: Axis, S: Data<Elem = Num>> Quaternion<Num> for ArrayBase<S, Ix1> {
+        #[inline] fn r(&self) -> Num { part(self, 4, 0) }
+        #[inline] fn i(&self) -> Num { part(self, 4, 1) }
+        #[inline] fn j(&self) -> Num { part(self, 4, 2) }
+        #[inline] fn k(&self) -> Num { part(self, 4, 3) }
+    }
+
+    impl<Num: Axis> QuaternionConstructor<Num> for Array1<Num> {
+        #[inline] fn new_quat(r: Num, i: Num, j: Num, k: Num) -> Self {
+            arr1(&[r, i, j, k])
+        }
+    }
+
+    impl<Num: Axis, S: Data<Elem = Num>> Vector<Num> for ArrayBase<S, Ix1> {
+        #[inline] fn x(&self) -> Num { part(self, 3, 0) }
+        #[inline] fn y(&self) -> Num { part(self, 3, 1) }
+        #[inline] fn z(&self) -> Num { part(self, 3, 2) }
+    }
+
+    impl<Num: Axis> VectorConstructor<Num> for Array1<Num> {
+        #[inline] fn new_vector(x: Num, y: Num, z: Num) -> Self {
+            arr1(&[x, y, z])
+        }
+    }
+}
@@ -110,9 +110,7 @@ impl<T: Copy> Matrix<T, 2> for [(T, T); 2] {
 impl<T: Copy> Matrix<T, 2> for [T; 4] {
     #[inline]
     fn get_unchecked( &self, row: usize, col: usize ) -> T {
-        #[cfg(not(debug_assertions))]
-        return self[row * 2 + col];
-        #[cfg(debug_assertions)]
+        // the flat index of an out of bounds position can be in bounds (eg: (0, 2) is (1, 0)), so this is always checked
         match (row, col) {
             (..2, ..2) => self[row * 2 + col],
             _ => crate::core::panic!("Out of index operation! Got ({row}, {col}), accepting at most (1, 1)")
@@ -328,9 +326,6 @@ impl<T: Copy> Matrix<T, 3> for [(T, T, T); 3] {
 impl<T: Copy> Matrix<T, 3> for [T; 9] {
     #[inline]
     fn get_unchecked( &self, row: usize, col: usize ) -> T {
-        #[cfg(not(debug_assertions))]
-        return self[row * 3 + col];
-        #[cfg(debug_assertions)]
         match (row, col) {
             (..3, ..3) => self[row * 3 + col],
             _ => crate::core::panic!("Out of index operation! Got ({row}, {col}), accepting at most (2, 2)")
@@ -624,9 +619,6 @@ impl<T: Copy> Matrix<T, 4> for [(T, T, T, T); 4] {
 impl<T: Copy> Matrix<T, 4> for [T; 16] {
     #[inline]
     fn get_unchecked( &self, row: usize, col: usize ) -> T {
-        #[cfg(not(debug_assertions))]
-        return self[row * 4 + col];
-        #[cfg(debug_assertions)]
         match (row, col) {
             (..4, ..4) => self[row * 4 + col],
             _ => crate::core::panic!("Out of index operation! Got ({row}, {col}), accepting at most (3, 3)")
@@ -9,6 +9,11 @@ If possible it's planned to have the same* documentation for both instances of e
 in the future.
 
 \* With examples that match their respective function.
+
+Some methods also have a `_ref` version (like [`abs_ref`](QuaternionMethods::abs_ref)
+or [`conj_ref`](QuaternionMethods::conj_ref)) that takes `&self` (and `&other`)
+instead, for quaternions that aren't [`Copy`](crate::core::marker::Copy)
+or that need to be used again after the call.
  */
 pub trait QuaternionMethods<Num: Axis>: Quaternion<Num> + QuaternionConstructor<Num> + Sized {
     /// Adds two quaternions togheder.
@@ -19,6 +24,18 @@ pub trait QuaternionMethods<Num: Axis>: Quaternion<Num> + QuaternionConstructor<
     /// 
     /// Check [the sub function](crate::quat::sub) in the root for more info.
     #[inline] fn sub(self, other: impl Quaternion<Num>) -> Self { quat::sub(self, other) }
+    /// Subtracts a quaternion from a scalar value.
+    /// 
+    /// Check [the sub_from_scalar function](crate::quat::sub_from_scalar) in the root for more info.
+    #[cfg(feature = "qol_fns")] #[inline] fn sub_from_scalar(self, scalar: impl Scalar<Num>) -> Self { quat::sub_from_scalar(scalar, self) }
+    /// Subtracts a quaternion from a complex number.
+    /// 
+    /// Check [the sub_from_complex function](crate::quat::sub_from_complex) in the root for more info.
+    #[cfg(feature = "qol_fns")] #[inline] fn sub_from_complex(self, complex: impl Complex<Num>) -> Self { quat::sub_from_complex(complex, self) }
+    /// Subtracts a quaternion from a vector.
+    /// 
+    /// Check [the sub_from_vector function](crate::quat::sub_from_vector) in the root for more info.
+    #[cfg(feature = "qol_fns")] #[inline] fn sub_from_vector(self, vector: impl Vector<Num>) -> Self { quat::sub_from_vector(vector, self) }
     /// Muliplies a quaternion to another one.
     /// 
     /// Check [the mul function](crate::quat::mul) in the root for more info.
@@ -35,10 +52,30 @@ pub trait QuaternionMethods<Num: Axis>: Quaternion<Num> + QuaternionConstructor<
     /// 
     /// Check [the div_reversed function](crate::quat::div_reversed) in the root for more info.
     #[inline] fn div_reversed(self, other: impl Quaternion<Num>) -> Self { quat::div_reversed(self, other) }
-    /// Divides a quaternion from another one in reversed order.
+    /// Solves `self * x = other` for `x`.
+    /// 
+    /// Check [the solve_left function](crate::quat::solve_left) in the root for more info.
+    #[inline] fn solve_left(self, other: impl Quaternion<Num>) -> Self { quat::solve_left(self, other) }
+    /// Solves `x * self = other` for `x`.
+    /// 
+    /// Check [the solve_right function](crate::quat::solve_right) in the root for more info.
+    #[inline] fn solve_right(self, other: impl Quaternion<Num>) -> Self { quat::solve_right(self, other) }
+    /// Calculates the remainder of dividing a quaternion by a modulus.
     /// 
     /// Check [the rem function](crate::quat::rem) in the root for more info.
-    #[cfg(feature = "unstable")] #[inline] fn rem(self, modulus: impl Quaternion<Num>) -> Self { quat::rem(self, modulus) }
+    #[inline] fn rem(self, modulus: impl Quaternion<Num>) -> Self { quat::rem(self, modulus) }
+    /// Calculates the remainder of dividing a quaternion by a modulus, if the modulus has a finite inverse.
+    /// 
+    /// Check [the rem_checked function](crate::quat::rem_checked) in the root for more info.
+    #[inline] fn rem_checked(self, modulus: impl Quaternion<Num>) -> Option<Self> { quat::rem_checked(self, modulus) }
+    /// Calculates the remainder of dividing a quaternion by a modulus with the quotient rounded to the nearest Hurwitz quaternion.
+    /// 
+    /// Check [the rem_euclid function](crate::quat::rem_euclid) in the root for more info.
+    #[cfg(feature = "math_fns")] #[inline] fn rem_euclid(self, modulus: impl Quaternion<Num>) -> Self { quat::rem_euclid(self, modulus) }
+    /// Divides a quaternion by a modulus and gives back the quotient and the remainder.
+    /// 
+    /// Check [the div_rem function](crate::quat::div_rem) in the root for more info.
+    #[inline] fn div_rem(self, modulus: impl Quaternion<Num>) -> (Self, Self) { quat::div_rem(self, modulus) }
     /// Multiplies two quaternions and then adds the addend.
     /// 
     /// Check [the mul_add function](crate::quat::mul_add) in the root for more info.
@@ -99,6 +136,18 @@ pub trait QuaternionMethods<Num: Axis>: Quaternion<Num> + QuaternionConstructor<
     /// 
     /// Check [the dot function](crate::quat::dot) in the root for more info.
     #[inline] fn dot(self, other: impl Quaternion<Num>) -> Num { quat::dot(self, other) }
+    /// Gets eather this quaternion or it's negative, whichever has a non-negative dot product with the other.
+    /// 
+    /// Check [the enforce_continuity function](crate::quat::enforce_continuity) in the root for more info.
+    #[inline] fn aligned_with(self, other: impl Quaternion<Num>) -> Self { quat::enforce_continuity(other, self) }
+    /// Gets the absolute value of the dot product of two quaternions.
+    /// 
+    /// Check [the abs_dot function](crate::quat::abs_dot) in the root for more info.
+    #[inline] fn abs_dot(self, other: impl Quaternion<Num>) -> Num { quat::abs_dot(self, other) }
+    /// Gets eather this quaternion or it's negative, whichever has a non-negative dot product with the reference.
+    /// 
+    /// Check [the align_to function](crate::quat::align_to) in the root for more info.
+    #[inline] fn align_to(self, reference: impl Quaternion<Num>) -> Self { quat::align_to(self, reference) }
     /// Normalizes a quaternion.
     /// 
     /// Check [the normalize function](crate::quat::normalize) in the root for more info.
@@ -123,6 +172,14 @@ pub trait QuaternionMethods<Num: Axis>: Quaternion<Num> + QuaternionConstructor<
     /// 
     /// Check [the is_near_by function](crate::quat::is_near_by) in the root for more info.
     #[inline] fn is_near_by(self, other: impl Quaternion<Num>, error: impl Scalar<Num>) -> bool { quat::is_near_by(self, other, error) }
+    /// Checks if a quaternion is a Lipschitz quaternion (all integer components).
+    /// 
+    /// Check [the is_lipschitz function](crate::quat::is_lipschitz) in the root for more info.
+    #[cfg(feature = "math_fns")] #[inline] #[allow(clippy::wrong_self_convention)] fn is_lipschitz(self, error: impl Scalar<Num>) -> bool { quat::is_lipschitz(self, error) }
+    /// Checks if a quaternion is a Hurwitz quaternion (all integer or all half-integer components).
+    /// 
+    /// Check [the is_hurwitz function](crate::quat::is_hurwitz) in the root for more info.
+    #[cfg(feature = "math_fns")] #[inline] #[allow(clippy::wrong_self_convention)] fn is_hurwitz(self, error: impl Scalar<Num>) -> bool { quat::is_hurwitz(self, error) }
     /// Checks if the ratio inbetween the absolute values of each quaternion
     /// are near [Num::ONE](Axis::ONE) by a margin of [Num::ERROR](Axis::ERROR).
     /// 
@@ -137,16 +194,44 @@ pub trait QuaternionMethods<Num: Axis>: Quaternion<Num> + QuaternionConstructor<
     /// 
     /// Check [the dist_euclid function](crate::quat::dist_euclid) in the root for more info.
     #[inline] fn dist_euclid(self, other: impl Quaternion<Num>) -> Num { quat::dist_euclid(self, other) }
+    /// Gets the squared distance inbetween the coordonates of two quaternions.
+    /// 
+    /// Check [the dist_euclid_squared function](crate::quat::dist_euclid_squared) in the root for more info.
+    #[inline] fn dist_euclid_squared(self, other: impl Quaternion<Num>) -> Num { quat::dist_euclid_squared(self, other) }
     /// Calculates the cosine distance between two quaternions.
     #[inline] fn dist_cosine(self, other: impl Quaternion<Num>) -> Num { quat::dist_cosine(self, other) }
     /// Gets the square root of a quaternion.
     /// 
     /// Check [the sqrt function](crate::quat::sqrt) in the root for more info.
     #[cfg(feature = "math_fns")] #[inline] fn sqrt(self) -> Self { quat::sqrt(self) }
+    /// Calculates the square root of a quaternion without underflowing for tiny inputs.
+    /// 
+    /// Check [the sqrt_scaled function](crate::quat::sqrt_scaled) in the root for more info.
+    #[cfg(feature = "math_fns")] #[inline] fn sqrt_scaled(self) -> Self { quat::sqrt_scaled(self) }
+    /// Refines a guess of the square root of a quaternion.
+    /// 
+    /// Check [the sqrt_newton function](crate::quat::sqrt_newton) in the root for more info.
+    #[cfg(feature = "math_fns")] #[inline] fn sqrt_newton(self, initial_guess: impl Quaternion<Num>) -> Self { quat::sqrt_newton(self, initial_guess) }
+    /// Rounds a quaternion to the nearest Lipschitz quaternion (all integer components).
+    /// 
+    /// Check [the round_lipschitz function](crate::quat::round_lipschitz) in the root for more info.
+    #[cfg(feature = "math_fns")] #[inline] fn round_lipschitz(self) -> Self { quat::round_lipschitz(self) }
+    /// Rounds a quaternion to the nearest Hurwitz quaternion (all integer or all half-integer components).
+    /// 
+    /// Check [the round_hurwitz function](crate::quat::round_hurwitz) in the root for more info.
+    #[cfg(feature = "math_fns")] #[inline] fn round_hurwitz(self) -> Self { quat::round_hurwitz(self) }
     /// Gets the square of a quaternion.
     /// 
     /// Check [the square function](crate::quat::square) in the root for more info.
     #[cfg(any(feature = "qol_fns",feature = "math_fns"))]#[inline] fn square(self) -> Self { quat::square(self) }
+    /// Calculates the square of a quaternion and then adds the addend.
+    /// 
+    /// Check [the square_add function](crate::quat::square_add) in the root for more info.
+    #[cfg(any(feature = "qol_fns",feature = "math_fns"))]#[inline] fn square_add(self, addend: impl Quaternion<Num>) -> Self { quat::square_add(self, addend) }
+    /// Calculates the symmetrized product `(self·other + other·self) / 2`.
+    /// 
+    /// Check [the sym_mul function](crate::quat::sym_mul) in the root for more info.
+    #[cfg(any(feature = "qol_fns",feature = "math_fns"))]#[inline] fn sym_mul(self, other: impl Quaternion<Num>) -> Self { quat::sym_mul(self, other) }
     /// Raises a quaternion to an integer power.
     /// 
     /// Check [the pow_i function](crate::quat::pow_i) in the root for more info.
@@ -245,6 +330,18 @@ pub trait QuaternionMethods<Num: Axis>: Quaternion<Num> + QuaternionConstructor<
     /// 
     /// Check [the to_rotation function](crate::quat::to_rotation) in the root for more info.
     #[cfg(feature = "rotation")] #[inline] fn to_rotation<R: RotationConstructor<Num>>(self) -> R { quat::to_rotation(self) }
+    /// Applies a rotation around the x (`i`) axis on top of the rotation of a quaternion.
+    /// 
+    /// Check [the rotate_x function](crate::quat::rotate_x) in the root for more info.
+    #[cfg(feature = "rotation")] #[inline] fn rotate_x(self, angle: impl Scalar<Num>) -> Self { quat::rotate_x(self, angle) }
+    /// Applies a rotation around the y (`j`) axis on top of the rotation of a quaternion.
+    /// 
+    /// Check [the rotate_y function](crate::quat::rotate_y) in the root for more info.
+    #[cfg(feature = "rotation")] #[inline] fn rotate_y(self, angle: impl Scalar<Num>) -> Self { quat::rotate_y(self, angle) }
+    /// Applies a rotation around the z (`k`) axis on top of the rotation of a quaternion.
+    /// 
+    /// Check [the rotate_z function](crate::quat::rotate_z) in the root for more info.
+    #[cfg(feature = "rotation")] #[inline] fn rotate_z(self, angle: impl Scalar<Num>) -> Self { quat::rotate_z(self, angle) }
     /// Gets the polar form of a quaternion.
     /// 
     /// Check [the to_polar_form function](crate::quat::to_polar_form) in the root for more info.
@@ -293,8 +390,117 @@ pub trait QuaternionMethods<Num: Axis>: Quaternion<Num> + QuaternionConstructor<
     /// 
     /// Check [the from_matrix_3 function](crate::quat::from_matrix_3) in the root for more info.
     #[cfg(feature = "matrix")] #[inline] fn from_matrix_3<Elem: Scalar<Num>>(matrix: impl Matrix<Elem, 3>) -> Self { quat::from_matrix_3(matrix) }
+    /// Constructs a unit quaternion representation from a rotation that might not be orthonormal.
+    /// 
+    /// Check [the from_matrix_3_orthonormalized function](crate::quat::from_matrix_3_orthonormalized) in the root for more info.
+    #[cfg(feature = "matrix")] #[inline] fn from_matrix_3_orthonormalized<Elem: Scalar<Num>>(matrix: impl Matrix<Elem, 3>) -> Self { quat::from_matrix_3_orthonormalized(matrix) }
     /// Constructs a unit quaternion representation from a rotation.
     /// 
     /// Check [the from_matrix_4 function](crate::quat::from_matrix_4) in the root for more info.
     #[cfg(feature = "matrix")] #[inline] fn from_matrix_4<Elem: Scalar<Num>>(matrix: impl Matrix<Elem, 4>) -> Self { quat::from_matrix_4::<Num, Elem, Self>(matrix) }
+    // Non-consuming methods
+    // 
+    // Same as the methods above but they only read `self` (and `other`) through a reference,
+    // so the quaternions don't have to be `Copy` or cloned to be used again.
+
+    /// Adds two quaternions togheder without consuming them.
+    /// 
+    /// Check [the add function](crate::quat::add) in the root for more info.
+    #[inline] fn add_ref(&self, other: &impl Quaternion<Num>) -> Self { quat::add(self, other) }
+    /// Subtracts a quaternion from another one without consuming them.
+    /// 
+    /// Check [the sub function](crate::quat::sub) in the root for more info.
+    #[inline] fn sub_ref(&self, other: &impl Quaternion<Num>) -> Self { quat::sub(self, other) }
+    /// Muliplies a quaternion to another one without consuming them.
+    /// 
+    /// Check [the mul function](crate::quat::mul) in the root for more info.
+    #[inline] fn mul_ref(&self, other: &impl Quaternion<Num>) -> Self { quat::mul(self, other) }
+    /// Divides a quaternion from another one without consuming them.
+    /// 
+    /// Check [the div function](crate::quat::div) in the root for more info.
+    #[inline] fn div_ref(&self, other: &impl Quaternion<Num>) -> Self { quat::div(self, other) }
+    /// Scales a quaternion without consuming it.
+    /// 
+    /// Check [the scale function](crate::quat::scale) in the root for more info.
+    #[inline] fn scale_ref(&self, other: impl Scalar<Num>) -> Self { quat::scale(self, other) }
+    /// Scales a quaternion by the inverse of the scalar without consuming it.
+    /// 
+    /// Check [the unscale function](crate::quat::unscale) in the root for more info.
+    #[inline] fn unscale_ref(&self, other: impl Scalar<Num>) -> Self { quat::unscale(self, other) }
+    /// Checks if a quaternion is a scalar value without consuming it.
+    /// 
+    /// Check [the is_scalar function](crate::quat::is_scalar) in the root for more info.
+    #[inline] fn is_scalar_ref(&self) -> bool { quat::is_scalar(self) }
+    /// Checks if a quaternion is a complex value without consuming it.
+    /// 
+    /// Check [the is_complex function](crate::quat::is_complex) in the root for more info.
+    #[inline] fn is_complex_ref(&self) -> bool { quat::is_complex(self) }
+    /// Checks if a quaternion is a vector value without consuming it.
+    /// 
+    /// Check [the is_vector function](crate::quat::is_vector) in the root for more info.
+    #[inline] fn is_vector_ref(&self) -> bool { quat::is_vector(self) }
+    /// Checks if two quaternion represent the same value without consuming them.
+    /// 
+    /// Check [the eq function](crate::quat::eq) in the root for more info.
+    #[inline] fn eq_ref(&self, other: &impl Quaternion<Num>) -> bool { quat::eq(self, other) }
+    /// Gets the absolute value of a quaternion without consuming it.
+    /// 
+    /// Check [the abs function](crate::quat::abs) in the root for more info.
+    #[inline] fn abs_ref(&self) -> Num { quat::abs(self) }
+    /// Gets the squared absolute value of a quaternion without consuming it.
+    /// 
+    /// Check [the abs_squared function](crate::quat::abs_squared) in the root for more info.
+    #[inline] fn abs_squared_ref(&self) -> Num { quat::abs_squared(self) }
+    /// Gets the angle from a quaternion's polar form without consuming it.
+    /// 
+    /// Check [the angle function](crate::quat::angle) in the root for more info.
+    #[inline] fn angle_ref(&self) -> Num { quat::angle(self) }
+    /// Gets the dot product of two quaternions without consuming them.
+    /// 
+    /// Check [the dot function](crate::quat::dot) in the root for more info.
+    #[inline] fn dot_ref(&self, other: &impl Quaternion<Num>) -> Num { quat::dot(self, other) }
+    /// Normalizes a quaternion without consuming it.
+    /// 
+    /// Check [the normalize function](crate::quat::normalize) in the root for more info.
+    #[inline] fn norm_ref(&self) -> Self { quat::normalize(self) }
+    /// Gets the conjugate of a quaternion without consuming it.
+    /// 
+    /// Check [the conj function](crate::quat::conj) in the root for more info.
+    #[inline] fn conj_ref(&self) -> Self { quat::conj(self) }
+    /// Gets the negative of a quaternion without consuming it.
+    /// 
+    /// Check [the neg function](crate::quat::neg) in the root for more info.
+    #[inline] fn neg_ref(&self) -> Self { quat::neg(self) }
+    /// Gets the inverse of a quaternion without consuming it.
+    /// 
+    /// Check [the inv function](crate::quat::inv) in the root for more info.
+    #[inline] fn inv_ref(&self) -> Self { quat::inv(self) }
+    /// Checks if the distance inbetween two quaternions is less then [`Num::ERROR`](Axis::ERROR) without consuming them.
+    /// 
+    /// Check [the is_near function](crate::quat::is_near) in the root for more info.
+    #[inline] fn is_near_ref(&self, other: &impl Quaternion<Num>) -> bool { quat::is_near(self, other) }
+    /// Checks if the distance inbetween two quaternions is less then error without consuming them.
+    /// 
+    /// Check [the is_near_by function](crate::quat::is_near_by) in the root for more info.
+    #[inline] fn is_near_by_ref(&self, other: &impl Quaternion<Num>, error: impl Scalar<Num>) -> bool { quat::is_near_by(self, other, error) }
+    /// Gets the distance inbetween the coordonates of two quaternions without consuming them.
+    /// 
+    /// Check [the dist_euclid function](crate::quat::dist_euclid) in the root for more info.
+    #[inline] fn dist_euclid_ref(&self, other: &impl Quaternion<Num>) -> Num { quat::dist_euclid(self, other) }
+    /// Gets the squared distance inbetween the coordonates of two quaternions without consuming them.
+    /// 
+    /// Check [the dist_euclid_squared function](crate::quat::dist_euclid_squared) in the root for more info.
+    #[inline] fn dist_euclid_squared_ref(&self, other: &impl Quaternion<Num>) -> Num { quat::dist_euclid_squared(self, other) }
+    /// Squares a quaternion without consuming it.
+    /// 
+    /// Check [the square function](crate::quat::square) in the root for more info.
+    #[cfg(any(feature = "qol_fns",feature = "math_fns"))] #[inline] fn square_ref(&self) -> Self { quat::square(self) }
+    /// Gets the exponential of a quaternion without consuming it.
+    /// 
+    /// Check [the exp function](crate::quat::exp) in the root for more info.
+    #[cfg(any(feature = "math_fns", feature = "trigonometry"))] #[inline] fn exp_ref(&self) -> Self { quat::exp(self) }
+    /// Gets the natural logarithm of a quaternion without consuming it.
+    /// 
+    /// Check [the ln function](crate::quat::ln) in the root for more info.
+    #[cfg(any(feature = "math_fns", feature = "trigonometry"))] #[inline] fn ln_ref(&self) -> Self { quat::ln(self) }
 }
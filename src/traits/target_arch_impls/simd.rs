@@ -165,10 +165,15 @@ where
     const TAU: Self = Simd::from_array([Num::TAU]);
     const NAN: Self = Simd::from_array([Num::NAN]);
     const ERROR: Self = Simd::from_array([Num::ERROR]);
+    const EPS_ARITH: Self = Simd::from_array([Num::EPS_ARITH]);
+    const EPS_TRIG: Self = Simd::from_array([Num::EPS_TRIG]);
 
     #[inline]
     fn is_nan( &self ) -> bool { self[0].is_nan() }
 
+    #[inline]
+    fn classify( self ) -> crate::core::num::FpCategory { self[0].classify() }
+
     #[inline]
     fn mul_add( self, factor: Self, addend: Self ) -> Self { self * factor + addend }
 
@@ -216,6 +221,18 @@ where
 
     #[inline]
     fn from_f64( float: f64 ) -> Self { Simd::from_array([Num::from_f64(float)]) }
+
+    #[inline]
+    fn from_f32( float: f32 ) -> Self { Simd::from_array([Num::from_f32(float)]) }
+
+    #[inline]
+    fn from_i32( int: i32 ) -> Self { Simd::from_array([Num::from_i32(int)]) }
+
+    #[inline]
+    fn from_u32( uint: u32 ) -> Self { Simd::from_array([Num::from_u32(uint)]) }
+
+    #[inline]
+    fn from_usize( uint: usize ) -> Self { Simd::from_array([Num::from_usize(uint)]) }
 }
 
 impl<Num> Scalar<Num> for Simd<Num, 1>
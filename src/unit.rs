@@ -54,7 +54,7 @@ use crate::{
 #[inline(always)]
 // short hard for the unsafe unchecked call.
 fn new_unit<Num: Axis, Out: UnitQuaternionConstructor<Num>>(r: Num, i: Num, j: Num, k: Num) -> Out {
-    crate::core::debug_assert!( r * r + i * i + j * j + k * k - Num::ONE < Num::ERROR * Num::ERROR );
+    crate::core::debug_assert!( (r * r + i * i + j * j + k * k - Num::ONE).abs() < Num::EPS_TRIG );
     unsafe { Out::new_unit_quat_unchecked(r, i, j, k) }
 }
 
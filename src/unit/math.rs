@@ -2,7 +2,7 @@
 use super::*;
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Adds two unit quaternions.
 /// 
 /// # Safety
@@ -21,21 +21,22 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Adds two unit quaternions.
 pub unsafe fn add_checked<Num, Out>(left: impl UnitQuaternion<Num>, right: impl UnitQuaternion<Num>) -> Option<Out>
 where
     Num: Axis,
     Out: UnitQuaternionConstructor<Num>,
 {
-    let unit: U<Num> = new_unit(
+    // the sum is only a candidate, so it skips the unit check of `new_unit`
+    let unit: U<Num> = unsafe { U::new_unit_quat_unchecked(
         left.r() + right.r(),
         left.i() + right.i(),
         left.j() + right.j(),
         left.k() + right.k(),
-    );
+    ) };
     
-    if (dot::<Num, Num>(unit, unit) - Num::ONE).abs() < Num::ERROR * Num::ERROR {
+    if (dot::<Num, Num>(unit, unit) - Num::ONE).abs() < Num::EPS_TRIG {
         return Option::Some(Out::from_unit_quat(unit))
     }
 
@@ -43,7 +44,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Adds two unit quaternions.
 pub fn add_any<Num, Out>(left: impl UnitQuaternion<Num>, right: impl UnitQuaternion<Num>) -> Out
 where
@@ -59,7 +60,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Multiplies two unit quaternions.
 pub fn mul<Num, Out>(left: impl UnitQuaternion<Num>, right: impl UnitQuaternion<Num>) -> Out
 where
@@ -75,7 +76,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Multiplies two unit quaternions in reversed order.
 pub fn mul_reversed<Num, Out>(left: impl UnitQuaternion<Num>, right: impl UnitQuaternion<Num>) -> Out
 where
@@ -86,7 +87,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Divides a quaternion by another quaternion.
 pub fn div<Num, Out>(left: impl UnitQuaternion<Num>, right: impl UnitQuaternion<Num>) -> Out
 where
@@ -97,7 +98,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Gets the negative of this unit quaternion.
 pub fn neg<Num, Out>(quaternion: impl UnitQuaternion<Num>) -> Out
 where
@@ -113,7 +114,7 @@ where
 }
 
 #[inline]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Gets the conjugate of this unit quaternion.
 pub fn conj<Num, Out>(quaternion: impl UnitQuaternion<Num>) -> Out
 where
@@ -146,7 +147,7 @@ where
 
 #[inline]
 #[cfg(feature = "math_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 /// Spherical liniar interpolation for unit quaternions.
 /// 
 /// Uses the shortest path inbetween the two unit
@@ -167,13 +168,8 @@ where
         <U<Num>>::from_unit_quat(to)
     };
 
-    if dot > Num::ONE - Num::ERROR { // for ERROR = 0.0005 => Aprox. Err < 0.017%
-        return unsafe {
-            add_unchecked(
-                scale::<Num, U<Num>>(add_unchecked::<Num, U<Num>>(to, &from), at),
-                from
-            )
-        };
+    if dot > crate::consts::slerp_lerp_threshold::<Num>() { // for ERROR = 0.0005 => Aprox. Err < 0.017%
+        return quat::to_unit_quat(quat::lerp::<Num, (Num, [Num; 3])>(from, to, at));
     }
 
     let angle = dot.acos();
@@ -197,18 +193,20 @@ where
 /// # Safety
 /// Eather the output is a unit quaternion or it's
 /// used in an operation that returns a unit quaternion.
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub unsafe fn scale<Num, Out>(quaternion: impl UnitQuaternion<Num>, scalar: impl Scalar<Num>) -> Out
 where 
     Num: Axis,
     Out: UnitQuaternionConstructor<Num>,
 {
-    new_unit(
+    // the result can be a part of a unit quaternion (see the safety section),
+    // so it skips the unit check of `new_unit`
+    unsafe { Out::new_unit_quat_unchecked(
         quaternion.r() * scalar.scalar(),
         quaternion.i() * scalar.scalar(),
         quaternion.j() * scalar.scalar(),
         quaternion.k() * scalar.scalar(),
-    )
+    ) }
 }
 
 /// Calculates the dot product of two unit quaternions.
@@ -222,7 +220,7 @@ where
 
 /// Calculates the naturla logarithm of a unit quaternion.
 #[cfg(feature = "math_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn ln<Num, Out>(quaternion: impl UnitQuaternion<Num>) -> Out
 where 
     Num: Axis,
@@ -243,7 +241,7 @@ where
 }
 
 #[cfg(any(feature = "math_fns", feature = "trigonometry"))]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 #[inline]
 /// Raises the number e to a unit quaternion power.
 /// 
@@ -279,7 +277,7 @@ where
 /// 
 /// Faster then [`quat::sqrt`] if you know you have a unit quaternion.
 #[cfg(feature = "math_fns")]
-#[cfg_attr(all(test, panic = "abort"), no_panic::no_panic)]
+#[cfg_attr(feature = "panic_free_check", crate::no_panic::no_panic)]
 pub fn sqrt<Num, Out>(quaternion: impl UnitQuaternion<Num>) -> Out
 where 
     Num: Axis,
@@ -296,7 +294,7 @@ where
     }
 
     let r: Num = quaternion.r(); // alias
-    let frac_1_sqrt_2 = Num::from_f64(crate::core::f64::consts::FRAC_1_SQRT_2);
+    let frac_1_sqrt_2 = crate::consts::frac_1_sqrt_2::<Num>();
 
     if r.abs() < Num::ERROR {
         return unsafe {
@@ -0,0 +1,210 @@
+/*!
+Algebraic identities every [`Axis`] type and quaternion backing has to follow.
+
+Each identity is checked on every quaternion of
+`test_helpers::for_each_test_quat` (the `F32S` grid and the `F64S` grid),
+or of a smaller grid of [`for_each_test_quat`] without the `test_helpers` feature,
+and the tolerances are relative (scaled by the size of the expected value when it's above one).
+
+A new number type or backing is added with one line in the [`identities!`] invocation at the bottom.
+ */
+#![allow(unused_imports)]
+
+use core::fmt::Debug;
+use quaternion_traits::*;
+use quaternion_traits::traits::{Axis, Scalar};
+#[cfg(feature = "test_helpers")]
+use quaternion_traits::test_helpers::for_each_test_quat;
+
+type Q<N> = (N, [N; 3]);
+
+/// Calls `test` with every quaternion who's components are all from `VALUES`
+/// (the grid used when `test_helpers` isn't there to give the bigger one).
+#[cfg(not(feature = "test_helpers"))]
+fn for_each_test_quat<Num: Axis>(mut test: impl FnMut([Num; 4])) {
+    const VALUES: [f64; 9] = [0.0, 1.0, -1.0, 0.5, -2.5, core::f64::consts::PI, 1e-4, -1e-8, 1e4];
+
+    for r in VALUES { for i in VALUES { for j in VALUES { for k in VALUES {
+        test([r, i, j, k].map(Num::from_f64));
+    } } } }
+}
+
+/// Shows a quaternion for a failure message.
+fn show<Num: Axis + Debug>(quaternion: impl Quaternion<Num>) -> String {
+    format!("{:?}", quat::convert_quat::<Num, [Num; 4]>(quaternion))
+}
+
+/// The tolerance scaled by the size of `expected` when it's above one.
+fn scaled<Num: Axis>(tolerance: Num, expected: impl Quaternion<Num>) -> Num {
+    tolerance * quat::abs::<Num, Num>(expected).max(Num::ONE)
+}
+
+/// Asserts that `left` is within the (scaled) `tolerance` of `right`.
+#[track_caller]
+fn near<Num: Axis + Debug>(left: impl Quaternion<Num>, right: impl Quaternion<Num>, tolerance: Num, identity: &str, input: &str) {
+    let tolerance: Num = scaled(tolerance, &right);
+    let (left, right): ([Num; 4], [Num; 4]) = (quat::convert_quat(left), quat::convert_quat(right));
+    assert!(
+        quat::is_near_by(left, right, tolerance),
+        "{identity} failed for {input}\n      left: {left:?}\n  expected: {right:?}\n tolerance: {tolerance:?}",
+    );
+}
+
+/// Same as [`near`], but `-right` is accepted too.
+#[track_caller]
+#[cfg(any(feature = "matrix", feature = "rotation"))]
+fn same_rotation<Num: Axis + Debug>(left: impl Quaternion<Num>, right: impl Quaternion<Num>, tolerance: Num, identity: &str, input: &str) {
+    let left: [Num; 4] = quat::convert_quat(left);
+    near(left, quat::enforce_continuity::<Num, [Num; 4]>(left, right), tolerance, identity, input);
+}
+
+/// Checks every identity on every test quaternion using `Backing` for the inputs and results.
+#[cfg_attr(not(feature = "rotation"), allow(unused_variables))]
+fn check_identities<Num, Backing>(tolerance: f64, rotation_tolerance: f64)
+where
+    Num: Axis + Debug,
+    Backing: QuaternionConstructor<Num> + Quaternion<Num> + Copy,
+{
+    let tolerance: Num = Num::from_f64(tolerance);
+    let other: Backing = Backing::new_quat(Num::from_f64(0.5), Num::from_f64(-1.0), Num::from_f64(2.0), Num::from_f64(0.25));
+
+    for_each_test_quat::<Num>(|array: [Num; 4]| {
+        let q: Backing = Backing::from_quat(array);
+        let input: &str = &show(q);
+        let is_origin: bool = quat::eq(q, ());
+
+        // exact ones
+        assert!( quat::eq(quat::conj::<Num, Backing>(quat::conj::<Num, Backing>(q)), q), "conj(conj(q)) == q for {input}" );
+        assert!( quat::eq(quat::neg::<Num, Backing>(quat::neg::<Num, Backing>(q)), q), "neg(neg(q)) == q for {input}" );
+        assert!( quat::eq(quat::add::<Num, Backing>(q, quat::neg::<Num, Backing>(q)), ()), "q + neg(q) == 0 for {input}" );
+        assert!( quat::eq(quat::mul::<Num, Backing>(q, quat::identity::<Num, Backing>()), q), "q * 1 == q for {input}" );
+        assert!( quat::dot::<Num, Num>(q, q) == quat::abs_squared::<Num, Num>(q), "dot(q, q) == abs_squared(q) for {input}" );
+
+        // products
+        near(
+            quat::from_scalar::<Num, Backing>(quat::abs::<Num, Num>(quat::mul::<Num, Backing>(q, other))),
+            quat::from_scalar::<Num, Backing>(quat::abs::<Num, Num>(q) * quat::abs::<Num, Num>(other)),
+            tolerance, "abs(q * p)", input,
+        );
+        near(
+            quat::conj::<Num, Backing>(quat::mul::<Num, Backing>(q, other)),
+            quat::mul::<Num, Backing>(quat::conj::<Num, Backing>(other), quat::conj::<Num, Backing>(q)),
+            tolerance, "conj(q * p)", input,
+        );
+        near(quat::div::<Num, Backing>(quat::mul::<Num, Backing>(q, other), other), q, tolerance, "(q * p) / p", input);
+
+        if !is_origin {
+            near(quat::mul::<Num, Backing>(q, quat::inv::<Num, Backing>(q)), quat::identity::<Num, Backing>(), tolerance, "q * inv(q)", input);
+            near(quat::mul::<Num, Backing>(quat::inv::<Num, Backing>(q), q), quat::identity::<Num, Backing>(), tolerance, "inv(q) * q", input);
+            near(quat::inv::<Num, Backing>(quat::inv::<Num, Backing>(q)), q, tolerance, "inv(inv(q))", input);
+            near(
+                quat::from_scalar::<Num, Backing>(quat::abs::<Num, Num>(quat::normalize::<Num, Backing>(q))),
+                quat::identity::<Num, Backing>(),
+                tolerance, "abs(normalize(q))", input,
+            );
+        }
+
+        // exp, ln and sqrt
+        #[cfg(any(feature = "math_fns", feature = "trigonometry"))]
+        {
+            let pi: Num = Num::TAU / Num::from_f64(2.0);
+            if !is_origin {
+                near(quat::exp::<Num, Backing>(quat::ln::<Num, Backing>(q)), q, tolerance, "exp(ln(q))", input);
+            }
+            // `ln` gives back the angle in `[0, π]` so only those get back the same vector part,
+            // and `exp` overflows (or underflows) for big real parts
+            if quat::abs::<Num, Num>(quat::vector_part::<Num, Backing>(q)) < pi - Num::from_f64(0.01)
+                && q.r().abs() < Num::from_f64(80.0)
+            {
+                near(quat::ln::<Num, Backing>(quat::exp::<Num, Backing>(q)), q, tolerance, "ln(exp(q))", input);
+            }
+        }
+        // `sqrt` of the origin is NaN
+        #[cfg(feature = "math_fns")]
+        if !is_origin {
+            let root: Backing = quat::sqrt(q);
+            near(quat::mul::<Num, Backing>(root, root), q, tolerance, "sqrt(q)²", input);
+        }
+
+        // matrices
+        #[cfg(feature = "matrix")]
+        {
+            // the 4x4 matrix is the one of multiplying by `q` on the left
+            let matrix: [[Num; 4]; 4] = quat::to_matrix_4::<Num, Num, [[Num; 4]; 4]>(q);
+            let column: [Num; 4] = quat::convert_quat(other);
+            let product: [Num; 4] = matrix.map(|row: [Num; 4]| {
+                row[0] * column[0] + row[1] * column[1] + row[2] * column[2] + row[3] * column[3]
+            });
+            near(product, quat::mul::<Num, Backing>(q, other), tolerance, "to_matrix_4(q) * p", input);
+
+            let matrix: [[(Num, Num); 2]; 2] = quat::to_matrix_2::<Num, (Num, Num), [[(Num, Num); 2]; 2]>(q);
+            let back: Option<Backing> = quat::from_matrix_2::<Num, (Num, Num), Backing>(matrix);
+            assert!( back.is_some(), "from_matrix_2(to_matrix_2(q)) is Some for {input}" );
+            near(back.unwrap(), q, tolerance, "from_matrix_2(to_matrix_2(q))", input);
+
+            if !is_origin {
+                let unit: Backing = quat::normalize(q);
+                let matrix: [[Num; 3]; 3] = quat::to_matrix_3::<Num, Num, [[Num; 3]; 3]>(unit);
+                same_rotation(quat::from_matrix_3::<Num, Num, Backing>(matrix), unit, tolerance, "from_matrix_3(to_matrix_3(q))", input);
+            }
+        }
+
+        // rotations
+        #[cfg(feature = "rotation")]
+        if !is_origin {
+            let rotation_tolerance: Num = Num::from_f64(rotation_tolerance);
+            let unit: Backing = quat::normalize(q);
+            let (angles, lock): ([Num; 3], quat::GimbalLock) = quat::to_rotation_ext::<Num, [Num; 3]>(q, quat::AngleRange::Signed);
+            // just outside of the snapped poles the roll and yaw are ill conditioned,
+            // (how close the snapping goes depends on `Num::ERROR`) so those are skipped
+            let near_pole: bool = lock == quat::GimbalLock::None
+                && angles[1].abs() > Num::from_f64(core::f64::consts::FRAC_PI_2 - 0.05);
+            if !near_pole {
+                same_rotation(quat::from_rotation::<Num, Backing>(angles), unit, rotation_tolerance, "from_rotation(to_rotation(q))", input);
+            }
+
+            let point: [Num; 3] = [Num::from_f64(1.0), Num::from_f64(-2.0), Num::from_f64(0.5)];
+            let rotated: [Num; 3] = quat::point_rotation::<Num, [Num; 3]>(q, point);
+            let back: [Num; 3] = quat::point_rotation::<Num, [Num; 3]>(quat::inv::<Num, Backing>(q), rotated);
+            near(
+                quat::from_vector::<Num, Backing>(back),
+                quat::from_vector::<Num, Backing>(point),
+                tolerance, "point_rotation(inv(q), point_rotation(q, p))", input,
+            );
+        }
+    });
+}
+
+/// Makes a test for each `name: Num, Backing, tolerance, rotation tolerance;` line.
+///
+/// The rotation tolerance is bigger since [`quat::to_rotation`] snaps to the poles
+/// when the pitch is within [`Axis::ERROR`] of them.
+macro_rules! identities {
+    ( $( $(#[$attr:meta])* $name:ident: $num:ty, $backing:ty, $tolerance:expr, $rotation_tolerance:expr; )* ) => { $(
+        #[test]
+        $(#[$attr])*
+        fn $name() {
+            check_identities::<$num, $backing>($tolerance, $rotation_tolerance);
+        }
+    )* };
+}
+
+identities!{
+    f32_array: f32, [f32; 4], 0.0002, 0.005;
+    f32_tuple: f32, Q<f32>, 0.0002, 0.005;
+    f32_four_tuple: f32, (f32, f32, f32, f32), 0.0002, 0.005;
+    f32_quat: f32, structs::Quat<f32, [f32; 4]>, 0.0002, 0.005;
+
+    f64_array: f64, [f64; 4], 0.000_000_001, 0.005;
+    f64_tuple: f64, Q<f64>, 0.000_000_001, 0.005;
+    f64_four_tuple: f64, (f64, f64, f64, f64), 0.000_000_001, 0.005;
+    f64_quat: f64, structs::Quat<f64, [f64; 4]>, 0.000_000_001, 0.005;
+
+    #[cfg(feature = "std")] std_f32_array: structs::Std<f32>, [structs::Std<f32>; 4], 0.0002, 0.005;
+    #[cfg(feature = "std")] std_f32_tuple: structs::Std<f32>, Q<structs::Std<f32>>, 0.0002, 0.005;
+    #[cfg(feature = "std")] std_f32_quat: structs::Std<f32>, structs::Quat<structs::Std<f32>, [structs::Std<f32>; 4]>, 0.0002, 0.005;
+
+    #[cfg(feature = "std")] std_f64_array: structs::Std<f64>, [structs::Std<f64>; 4], 0.000_000_001, 0.005;
+    #[cfg(feature = "std")] std_f64_tuple: structs::Std<f64>, Q<structs::Std<f64>>, 0.000_000_001, 0.005;
+    #[cfg(feature = "std")] std_f64_quat: structs::Std<f64>, structs::Quat<structs::Std<f64>, [structs::Std<f64>; 4]>, 0.000_000_001, 0.005;
+}
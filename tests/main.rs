@@ -3,6 +3,10 @@
 #![allow(dead_code)]
 
 use quaternion_traits::*;
+#[cfg(feature = "test_helpers")]
+use quaternion_traits::{assert_quat_near, assert_same_rotation};
+#[cfg(feature = "test_helpers")]
+use quaternion_traits::test_helpers::F32S;
 use core::assert;
 use core::assert_eq;
 
@@ -11,6 +15,8 @@ type U<N> = structs::UnitQuat<N>;
 
 /// made my own for the sake of testing in both release and dev mode
 #[cfg(feature = "std")]
+// the timing tests all need other features (most of them `test_helpers`)
+#[allow(unused_macros)]
 macro_rules! timer {
     ( run $code:block $(,)? ) => {
         {
@@ -74,73 +80,9 @@ macro_rules! timer {
 #[cfg(feature = "std")]
 use std::println;
 
-const F32S: [f32; 61] = [
-    0.0,
-    1.0,
-    f32::EPSILON,
-    core::f32::consts::E,
-    core::f32::consts::FRAC_1_PI,
-    core::f32::consts::FRAC_1_SQRT_2,
-    core::f32::consts::FRAC_2_PI,
-    core::f32::consts::FRAC_2_SQRT_PI,
-    core::f32::consts::FRAC_PI_2,
-    core::f32::consts::FRAC_PI_3,
-    core::f32::consts::FRAC_PI_4,
-    core::f32::consts::FRAC_PI_6,
-    core::f32::consts::FRAC_PI_8,
-    core::f32::consts::LN_2,
-    core::f32::consts::LN_10,
-    core::f32::consts::LOG2_10,
-    core::f32::consts::LOG2_E,
-    core::f32::consts::LOG10_2,
-    core::f32::consts::LOG10_E,
-    core::f32::consts::PI,
-    core::f32::consts::SQRT_2,
-    core::f32::consts::TAU,
-    1e8 * f32::EPSILON,
-    1e8 * core::f32::consts::E,
-    1e8 * core::f32::consts::FRAC_1_PI,
-    1e8 * core::f32::consts::FRAC_1_SQRT_2,
-    1e8 * core::f32::consts::FRAC_2_PI,
-    1e8 * core::f32::consts::FRAC_2_SQRT_PI,
-    1e8 * core::f32::consts::FRAC_PI_2,
-    1e8 * core::f32::consts::FRAC_PI_3,
-    1e8 * core::f32::consts::FRAC_PI_4,
-    1e8 * core::f32::consts::FRAC_PI_6,
-    1e8 * core::f32::consts::FRAC_PI_8,
-    1e8 * core::f32::consts::LN_2,
-    1e8 * core::f32::consts::LN_10,
-    1e8 * core::f32::consts::LOG2_10,
-    1e8 * core::f32::consts::LOG2_E,
-    1e8 * core::f32::consts::LOG10_2,
-    1e8 * core::f32::consts::LOG10_E,
-    1e8 * core::f32::consts::PI,
-    1e8 * core::f32::consts::SQRT_2,
-    1e8 * core::f32::consts::TAU,
-    // 1e-8 * f32::EPSILON, // <--- Only thing that breaks sqrt (it's square is too small)
-    1e-8 * core::f32::consts::E,
-    1e-8 * core::f32::consts::FRAC_1_PI,
-    1e-8 * core::f32::consts::FRAC_1_SQRT_2,
-    1e-8 * core::f32::consts::FRAC_2_PI,
-    1e-8 * core::f32::consts::FRAC_2_SQRT_PI,
-    1e-8 * core::f32::consts::FRAC_PI_2,
-    1e-8 * core::f32::consts::FRAC_PI_3,
-    1e-8 * core::f32::consts::FRAC_PI_4,
-    1e-8 * core::f32::consts::FRAC_PI_6,
-    1e-8 * core::f32::consts::FRAC_PI_8,
-    1e-8 * core::f32::consts::LN_2,
-    1e-8 * core::f32::consts::LN_10,
-    1e-8 * core::f32::consts::LOG2_10,
-    1e-8 * core::f32::consts::LOG2_E,
-    1e-8 * core::f32::consts::LOG10_2,
-    1e-8 * core::f32::consts::LOG10_E,
-    1e-8 * core::f32::consts::PI,
-    1e-8 * core::f32::consts::SQRT_2,
-    1e-8 * core::f32::consts::TAU,
-];
-
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg(feature = "test_helpers")]
 struct F32_Quats {
     r: usize,
     i: usize,
@@ -148,6 +90,7 @@ struct F32_Quats {
     k: usize,
 }
 
+#[cfg(feature = "test_helpers")]
 impl F32_Quats {
     const fn new() -> Self {
         F32_Quats { r: 0, i: 0, j: 0, k: 0 }
@@ -155,21 +98,25 @@ impl F32_Quats {
 }
 
 #[inline(always)]
+#[cfg(feature = "test_helpers")]
 fn f32_quats() -> F32_Quats {
     F32_Quats::new()
 }
 
+#[cfg(feature = "test_helpers")]
 impl core::iter::ExactSizeIterator for F32_Quats {}
+#[cfg(feature = "test_helpers")]
 impl core::iter::FusedIterator for F32_Quats {}
+#[cfg(feature = "test_helpers")]
 impl core::iter::Iterator for F32_Quats {
     type Item = [f32; 4];
 
     fn next(&mut self) -> core::option::Option<[f32; 4]> {
         use core::option::Option;
-        if self.k == 60 { return Option::None }
-        if self.j == 60 { self.j = 0; self.k += 1 }
-        if self.i == 60 { self.i = 0; self.j += 1 }
-        if self.r == 60 { self.r = 0; self.i += 1 }
+        if self.r == F32S.len() { self.r = 0; self.i += 1 }
+        if self.i == F32S.len() { self.i = 0; self.j += 1 }
+        if self.j == F32S.len() { self.j = 0; self.k += 1 }
+        if self.k == F32S.len() { return Option::None }
         let out = [
             F32S[self.r],
             F32S[self.i],
@@ -183,11 +130,14 @@ impl core::iter::Iterator for F32_Quats {
 
 #[test]
 #[ignore]
+#[cfg(all(feature = "math_fns", feature = "test_helpers"))]
 fn sqrt_accuracy() {
     let mut mul_result: [f32; 4];
     let mut sqrt_result: [f32; 4];
     for quat in F32_Quats::new() {
         if quat == quat::origin::<f32, [f32; 4]>() { continue }
+        // it's square underflows, `sqrt_paths_accuracy` covers it with `sqrt_scaled`
+        if quat.contains(&(1e-8 * f32::EPSILON)) { continue }
         mul_result = quat::mul::<f32, [f32; 4]>(&quat, &quat);
         sqrt_result = quat::sqrt::<f32, [f32; 4]>(&mul_result);
         let error: f32 = {
@@ -202,9 +152,112 @@ fn sqrt_accuracy() {
     }
 }
 
+#[test]
+#[cfg(all(feature = "math_fns", feature = "test_helpers"))]
+fn sqrt_paths_accuracy() {
+    // how far a root of `squared` is from its principal root (computed in `f64`, which rounds away
+    // far less than `f32` does, so the result is against the exact root of the rounded `q * q`)
+    fn error(squared: [f32; 4], sqrt: [f32; 4]) -> f32 {
+        let exact: [f64; 4] = quat::sqrt_scaled::<f64, [f64; 4]>(squared.map(f64::from));
+        (quat::dist_euclid::<f64, f64>(exact, sqrt.map(f64::from)) / quat::abs::<f64, f64>(exact)) as f32
+    }
+
+    let (mut sqrt_max, mut scaled_max, mut newton_max) = (0.0_f32, 0.0_f32, 0.0_f32);
+    // `sqrt_scaled`'s error on the quaternions `sqrt` also works for
+    let mut shared_max: f32 = 0.0;
+    for quat in f32_quats().step_by(97) {
+        // keeps `q * q` away from the negative real line, where the root's axis is ill defined
+        if 2.0 * quat[0] * quat[0] <= quat::abs_squared::<f32, f32>(quat) { continue }
+        let squared: [f32; 4] = quat::mul::<f32, [f32; 4]>(quat, quat);
+        let scaled: [f32; 4] = quat::sqrt_scaled::<f32, [f32; 4]>(squared);
+        let newton: [f32; 4] = quat::sqrt_newton::<f32, [f32; 4]>(squared, scaled);
+
+        let scaled_error: f32 = error(squared, scaled);
+        let newton_error: f32 = error(squared, newton);
+        assert!( scaled_error < 0.0001, "{quat:?}\n{scaled:?}\nerror: {scaled_error:?}" );
+        assert!( newton_error < 0.0001, "{quat:?}\n{newton:?}\nerror: {newton_error:?}" );
+        scaled_max = scaled_max.max(scaled_error);
+        newton_max = newton_max.max(newton_error);
+
+        // `sqrt` is only expected to work while the squared components of `q * q` don't underflow
+        if squared.iter().all(|num| *num == 0.0 || num * num >= f32::MIN_POSITIVE) {
+            let sqrt_error: f32 = error(squared, quat::sqrt::<f32, [f32; 4]>(squared));
+            assert!( sqrt_error < 0.0001, "{quat:?}\nerror: {sqrt_error:?}" );
+            sqrt_max = sqrt_max.max(sqrt_error);
+            shared_max = shared_max.max(scaled_error);
+        }
+    }
+    assert!( newton_max <= scaled_max, "newton: {newton_max:?}, scaled: {scaled_max:?}" );
+    assert!( shared_max <= 2.0 * sqrt_max, "scaled: {shared_max:?}, sqrt: {sqrt_max:?}" );
+
+    let tiny: [f32; 4] = [1e-8 * f32::EPSILON; 4];
+    let squared: [f32; 4] = quat::mul::<f32, [f32; 4]>(tiny, tiny);
+    // the length of the vector part underflows, so normalizing it blows up
+    assert_eq!( error(squared, quat::sqrt::<f32, [f32; 4]>(squared)), f32::INFINITY );
+    assert!( error(squared, quat::sqrt_scaled::<f32, [f32; 4]>(squared)) < 0.0001 );
+}
+
+#[test]
+#[cfg(feature = "math_fns")]
+fn sqrt_keeps_small_parts() {
+    // `abs - r` cancels to zero here, the small part has to come from the vector part
+    let roots: [[f32; 4]; 2] = [
+        quat::sqrt::<f32, [f32; 4]>([1.0, 2e-4, 0.0, 0.0]),
+        quat::sqrt_scaled::<f32, [f32; 4]>([1.0, 2e-4, 0.0, 0.0]),
+    ];
+    for root in roots {
+        assert_eq!( root[0], 1.0 );
+        assert!( (root[1] - 1e-4).abs() <= 1e-4 * f32::EPSILON, "{root:?}" );
+    }
+    // and `abs + r` when the real part is negative
+    let roots: [[f32; 4]; 2] = [
+        quat::sqrt::<f32, [f32; 4]>([-1.0, 0.0, 2e-4, 0.0]),
+        quat::sqrt_scaled::<f32, [f32; 4]>([-1.0, 0.0, 2e-4, 0.0]),
+    ];
+    for root in roots {
+        assert!( (root[0] - 1e-4).abs() <= 1e-4 * f32::EPSILON, "{root:?}" );
+        assert_eq!( root[2], 1.0 );
+    }
+}
+
+#[test]
+#[cfg(any(feature = "math_fns", feature = "trigonometry"))]
+fn exp_and_ln_of_reals() {
+    assert_eq!( quat::exp::<f64, [f64; 4]>([2.0, 0.0, 0.0, 0.0]), [2.0_f64.exp(), 0.0, 0.0, 0.0] );
+    assert_eq!( quat::exp::<f64, [f64; 4]>([0.0; 4]), [1.0, 0.0, 0.0, 0.0] );
+    assert_eq!( quat::ln::<f64, [f64; 4]>([2.0, 0.0, 0.0, 0.0]), [2.0_f64.ln(), 0.0, 0.0, 0.0] );
+    // negative reals get the angle on the `i` axis, like complex numbers
+    assert_eq!( quat::ln::<f64, [f64; 4]>([-2.0, 0.0, 0.0, 0.0]), [2.0_f64.ln(), core::f64::consts::PI, 0.0, 0.0] );
+}
+
+#[test]
+#[cfg(any(feature = "math_fns", feature = "trigonometry"))]
+fn ln_keeps_tiny_vector_parts() {
+    // `1 / |q|` rounds to one here, so the angle can't come from `acos(r / |q|)`
+    for tiny in [1e-9_f64, -3e-10, 1e-14] {
+        let log: [f64; 4] = quat::ln::<f64, [f64; 4]>([1.0, tiny, 0.0, tiny]);
+        assert!( ((log[1] - tiny) / tiny).abs() < 1e-12 && ((log[3] - tiny) / tiny).abs() < 1e-12, "{log:?}" );
+    }
+}
+
+#[test]
+#[cfg(feature = "math_fns")]
+fn sqrt_scaled_rescales_exactly() {
+    let quat: [f64; 4] = [1.5, -2.0, 0.75, 3.0];
+    let root: [f64; 4] = quat::sqrt_scaled::<f64, [f64; 4]>(quat);
+    for exponent in [-500, -100, -1, 1, 100, 500] {
+        let scaled: [f64; 4] = quat.map(|num: f64| num * 2.0_f64.powi(2 * exponent));
+        let expected: [f64; 4] = root.map(|num: f64| num * 2.0_f64.powi(exponent));
+        assert_eq!( quat::sqrt_scaled::<f64, [f64; 4]>(scaled), expected, "2^{}", 2 * exponent );
+    }
+    // the smallest subnormals still get a root instead of NaN or zero
+    let tiny: [f64; 4] = quat::sqrt_scaled::<f64, [f64; 4]>([f64::from_bits(1), 0.0, f64::from_bits(3), 0.0]);
+    assert!( tiny.iter().all(|num: &f64| num.is_finite()) && tiny[0] > 0.0, "{tiny:?}" );
+}
+
 #[test]
 #[ignore]
-#[cfg(feature = "std")]
+#[cfg(all(feature = "std", feature = "test_helpers"))]
 #[allow(unexpected_cfgs)]
 fn timing_pow_f_vs_sqrt() {
     let pow_f_average = timer! {
@@ -239,4 +292,4701 @@ fn timing_pow_f_vs_sqrt() {
     assert!( sqrt_average < pow_f_average );
 }
 
+#[test]
+#[cfg(all(feature = "math_fns", feature = "test_helpers"))]
+fn pow_f_matches_square_and_inv() {
+    for quat in f32_quats().step_by(997) {
+        if quat == quat::origin::<f32, [f32; 4]>() { continue }
+        let abs: f32 = quat::abs::<f32, f32>(quat);
+
+        let squared: [f32; 4] = quat::pow_f::<f32, [f32; 4]>(quat, 2.0);
+        let error: f32 = quat::dist_euclid::<f32, f32>(squared, quat::square::<f32, [f32; 4]>(quat)) / (abs * abs);
+        assert!( error < 0.0001, "{quat:?}^2 = {squared:?}\nerror: {error:?}" );
+
+        let inverse: [f32; 4] = quat::pow_f::<f32, [f32; 4]>(quat, -1.0);
+        let error: f32 = quat::dist_euclid::<f32, f32>(inverse, quat::inv::<f32, [f32; 4]>(quat)) * abs;
+        assert!( error < 0.0001, "{quat:?}^-1 = {inverse:?}\nerror: {error:?}" );
+
+        assert_eq!( quat::pow_f::<f32, [f32; 4]>(quat, 0.0), quat::identity::<f32, [f32; 4]>() );
+    }
+
+    assert_eq!( quat::pow_f::<f32, [f32; 4]>([0.0; 4], 2.0), [0.0; 4] );
+    assert!( quat::is_nan::<f32>(quat::pow_f::<f32, [f32; 4]>([0.0; 4], 0.0)) );
+    assert!( quat::is_nan::<f32>(quat::pow_f::<f32, [f32; 4]>([0.0; 4], -1.0)) );
+    assert_eq!( quat::pow_f::<f32, [f32; 4]>([4.0, 0.0, 0.0, 0.0], 0.5), [2.0, 0.0, 0.0, 0.0] );
+    assert_eq!( quat::pow_f::<f32, [f32; 4]>([-3.0, 0.0, 0.0, 0.0], 2.0), [9.0, 0.0, 0.0, 0.0] );
+    assert!( quat::is_near::<f32>(
+        quat::pow_f::<f32, [f32; 4]>([-4.0, 0.0, 0.0, 0.0], 0.5),
+        [0.0, 2.0, 0.0, 0.0],
+    ) );
+}
+
+#[test]
+#[cfg(all(any(feature = "qol_fns", feature = "math_fns"), feature = "test_helpers"))]
+fn square_add_and_sym_mul_match_composed() {
+    let addend: [f32; 4] = [0.5, -1.5, 2.5, -3.5];
+    let other: [f32; 4] = [8.7, -6.5, 4.3, -2.1];
+    for quat in f32_quats().step_by(997) {
+        let composed: [f32; 4] = quat::add::<f32, [f32; 4]>(quat::square::<f32, [f32; 4]>(quat), addend);
+        let fused: [f32; 4] = quat::square_add::<f32, [f32; 4]>(quat, addend);
+        let error: f32 = quat::dist_euclid::<f32, f32>(fused, composed)
+            / quat::abs::<f32, f32>(composed).max(1.0);
+        assert!( error < 0.0001, "{quat:?}\n{fused:?}\n{composed:?}" );
+
+        let composed: [f32; 4] = quat::scale::<f32, [f32; 4]>(
+            quat::add::<f32, [f32; 4]>(
+                quat::mul::<f32, [f32; 4]>(other, quat),
+                quat::mul::<f32, [f32; 4]>(quat, other),
+            ),
+            0.5,
+        );
+        let symmetric: [f32; 4] = quat::sym_mul::<f32, [f32; 4]>(other, quat);
+        let error: f32 = quat::dist_euclid::<f32, f32>(symmetric, composed)
+            / (quat::abs::<f32, f32>(quat) * quat::abs::<f32, f32>(other)).max(1.0);
+        assert!( error < 0.0001, "{quat:?}\n{symmetric:?}\n{composed:?}" );
+        assert_eq!( symmetric, quat::sym_mul::<f32, [f32; 4]>(quat, other) );
+    }
+}
+
+#[test]
+fn near_and_close_by_error_edge_cases() {
+    let a: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+    let b: [f32; 4] = [1.0, 2.0, 3.0, 4.5];
+
+    assert!( quat::is_near_by::<f32>(a, b, 1.0) );
+    assert!( !quat::is_near_by::<f32>(a, b, -1.0) );
+    assert!( !quat::is_near_by::<f32>(a, a, -1.0) );
+    assert!( !quat::is_near_by::<f32>(a, a, 0.0) );
+    assert!( !quat::is_near_by::<f32>(a, a, f32::NAN) );
+
+    assert!( quat::is_close_by::<f32>(a, b, 1.0) );
+    assert!( !quat::is_close_by::<f32>(a, b, -1.0) );
+    assert!( !quat::is_close_by::<f32>(a, a, -1.0) );
+    assert!( !quat::is_close_by::<f32>([0.0; 4], [0.0; 4], -1.0) );
+    assert!( !quat::is_close_by::<f32>(a, a, f32::NAN) );
+}
+
+#[test]
+#[cfg(feature = "test_helpers")]
+fn dist_euclid_squared_matches_dist_euclid() {
+    let other: [f32; 4] = [8.7, -6.5, 4.3, -2.1];
+    for quat in f32_quats().step_by(997) {
+        let squared: f32 = quat::dist_euclid_squared::<f32, f32>(quat, other);
+        let dist: f32 = quat::dist_euclid::<f32, f32>(quat, other);
+        assert!( (squared - dist * dist).abs() <= squared * 0.0001, "{quat:?}\n{squared:?}\n{dist:?}" );
+    }
+}
+
+#[test]
+fn solve_left_and_right_defining_equations() {
+    // a fixed xorshift sequence so the quaternions are spread out but the test is reproducible
+    let mut state: u32 = 0x9E37_79B9;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        (state as f32 / u32::MAX as f32) * 20.0 - 10.0
+    };
+
+    for _ in 0..1000 {
+        let a: [f32; 4] = [next(), next(), next(), next()];
+        let b: [f32; 4] = [next(), next(), next(), next()];
+        let error: f32 = <f32 as traits::Axis>::ERROR * quat::abs::<f32, f32>(b);
+
+        let left: [f32; 4] = quat::solve_left::<f32, [f32; 4]>(a, b);
+        let right: [f32; 4] = quat::solve_right::<f32, [f32; 4]>(a, b);
+        assert!( quat::is_near_by::<f32>(quat::mul::<f32, [f32; 4]>(a, left), b, error), "{a:?} {b:?}" );
+        assert!( quat::is_near_by::<f32>(quat::mul::<f32, [f32; 4]>(right, a), b, error), "{a:?} {b:?}" );
+
+        // `div(b, a) = b * inv(a)` and `div_reversed(a, b) = inv(a) * b`
+        assert_eq!( quat::div::<f32, [f32; 4]>(b, a), right );
+        assert_eq!( quat::div_reversed::<f32, [f32; 4]>(a, b), left );
+
+        assert_eq!( quat::solve_left_checked::<f32, [f32; 4]>(a, b), Some(left) );
+        assert_eq!( quat::solve_right_checked::<f32, [f32; 4]>(a, b), Some(right) );
+    }
+
+    let b: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+    for singular in [[0.0; 4], [-0.0; 4], [f32::NAN, 1.0, 0.0, 0.0], [f32::INFINITY, 0.0, 0.0, 0.0], [1e-30, 0.0, 0.0, 0.0], [1e30, 0.0, 0.0, 0.0]] {
+        assert_eq!( quat::solve_left_checked::<f32, [f32; 4]>(singular, b), None, "{singular:?}" );
+        assert_eq!( quat::solve_right_checked::<f32, [f32; 4]>(singular, b), None, "{singular:?}" );
+    }
+    assert!( quat::is_nan::<f32>(quat::solve_left::<f32, [f32; 4]>([0.0; 4], b)) );
+    assert!( quat::is_nan::<f32>(quat::solve_right::<f32, [f32; 4]>([0.0; 4], b)) );
+}
+
+#[test]
+#[cfg(all(feature = "alloc", feature = "test_helpers"))]
+fn convert_num_slice_rounding_and_exactness() {
+    let f32s: Vec<f32> = F32S.into_iter()
+        .chain(F32S.map(|num| -num))
+        .chain([f32::MAX, f32::MIN_POSITIVE, 1e-45, f32::INFINITY, f32::NEG_INFINITY])
+        .collect();
+    let narrow: Vec<[f32; 4]> = f32s.chunks_exact(4).map(|chunk| [chunk[0], chunk[1], chunk[2], chunk[3]]).collect();
+
+    // f32 -> f64 is exact, so going back gives the same bits
+    let wide: Vec<[f64; 4]> = quat::convert_num_vec::<f32, f64>(&narrow);
+    let mut back: Vec<[f32; 4]> = vec![[0.0; 4]; narrow.len()];
+    quat::convert_num_slice::<f64, f32>(&wide, &mut back);
+    for ((narrow, wide), back) in narrow.iter().zip(&wide).zip(&back) {
+        for axis in 0..4 {
+            assert_eq!( wide[axis], narrow[axis] as f64 );
+            assert_eq!( back[axis].to_bits(), narrow[axis].to_bits() );
+        }
+    }
+
+    // f64 -> f32 rounds to nearest, same as `as`
+    let wide: Vec<[f64; 4]> = (0..1000_u64)
+        .map(|n| core::array::from_fn(|axis| {
+            let bits: u64 = (n * 4 + axis as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+            let num: f64 = f64::from_bits(bits);
+            if num.is_nan() { 0.1 } else { num }
+        }))
+        .chain([[0.1, 1.0 + f64::EPSILON, 1e300, -1e-300], [f32::MAX as f64 * 1.5, 16_777_217.0, -0.0, 1.0 / 3.0]])
+        .collect();
+    let narrow: Vec<[f32; 4]> = quat::convert_num_vec::<f64, f32>(&wide);
+    let mut from_slice: Vec<[f32; 4]> = vec![[0.0; 4]; wide.len()];
+    quat::convert_num_slice::<f64, f32>(&wide, &mut from_slice);
+    assert_eq!( narrow, from_slice );
+    for (wide, narrow) in wide.iter().zip(&narrow) {
+        for axis in 0..4 {
+            assert_eq!( narrow[axis].to_bits(), (wide[axis] as f32).to_bits(), "{wide:?}" );
+        }
+        assert_eq!( *narrow, quat::convert_num::<f64, f32, [f32; 4]>(*wide) );
+    }
+
+    assert!( quat::convert_num_vec::<f64, f32>(&[]).is_empty() );
+    quat::convert_num_slice::<f64, f32>(&[], &mut []);
+}
+
+#[test]
+#[should_panic(expected = "Got 2 source and 3 destination quaternions")]
+fn convert_num_slice_length_mismatch() {
+    let mut dst: [[f32; 4]; 3] = [[0.0; 4]; 3];
+    quat::convert_num_slice::<f64, f32>(&[[1.0; 4]; 2], &mut dst);
+}
+
+#[test]
+fn complex_pair_representation() {
+    use traits::{QuaternionConsts, QuaternionMethods};
+
+    let quats: [[f32; 4]; 4] = [[1.0, 2.0, 3.0, 4.0], [-0.5, 0.25, 8.0, -2.0], [0.0, 1.0, 0.0, 0.0], [3.0, -1.0, -4.0, 1.5]];
+    for left in quats {
+        for right in quats {
+            let expected: [f32; 4] = quat::mul::<f32, [f32; 4]>(left, right);
+
+            let left_pair: [[f32; 2]; 2] = quat::convert_quat::<f32, [[f32; 2]; 2]>(left);
+            let right_pair: [(f32, f32); 2] = quat::convert_quat::<f32, [(f32, f32); 2]>(right);
+            assert_eq!( left_pair, [[left[0], left[1]], [left[2], left[3]]] );
+
+            let product: [[f32; 2]; 2] = quat::mul::<f32, [[f32; 2]; 2]>(left_pair, right_pair);
+            assert_eq!( product, [[expected[0], expected[1]], [expected[2], expected[3]]] );
+            assert_eq!( QuaternionMethods::<f32>::mul(left_pair, right_pair), product );
+        }
+    }
+
+    type Pair = [[f32; 2]; 2];
+    let [identity, unit_i, unit_j, unit_k]: [Pair; 4] = [
+        QuaternionConsts::<f32>::IDENTITY,
+        QuaternionConsts::<f32>::UNIT_I,
+        QuaternionConsts::<f32>::UNIT_J,
+        QuaternionConsts::<f32>::UNIT_K,
+    ];
+    assert_eq!( quat::convert_quat::<f32, [f32; 4]>(identity), [1.0, 0.0, 0.0, 0.0] );
+    assert_eq!( quat::convert_quat::<f32, [f32; 4]>(unit_i), [0.0, 1.0, 0.0, 0.0] );
+    assert_eq!( quat::convert_quat::<f32, [f32; 4]>(unit_j), [0.0, 0.0, 1.0, 0.0] );
+    assert_eq!( quat::convert_quat::<f32, [f32; 4]>(unit_k), [0.0, 0.0, 0.0, 1.0] );
+    assert_eq!( quat::mul::<f32, Pair>(unit_i, unit_j), unit_k );
+    assert_eq!( QuaternionMethods::<f32>::complex_part([[1.0_f32, 2.0], [3.0, 4.0]]), [[1.0, 2.0], [0.0, 0.0]] );
+    assert_eq!( QuaternionMethods::<f32>::to_complex::<[f32; 2]>([[1.0_f32, 2.0], [3.0, 4.0]]), [1.0, 2.0] );
+}
+
+#[test]
+fn integer_wrapper_scalars() {
+    use core::num::{NonZero, Saturating, Wrapping};
+    use traits::{Axis, Scalar, ScalarConstructor, ScalarConsts};
+
+    fn check<Num, Out>(make: fn(u8) -> Out)
+    where
+        Num: Axis + core::fmt::Debug,
+        Out: Scalar<Num> + ScalarConstructor<Num> + ScalarConsts<Num> + PartialEq + core::fmt::Debug,
+    {
+        let num = |float: f64| Num::from_f64(float);
+        let quat: [Num; 4] = [num(1.0), num(2.0), num(3.0), num(4.0)];
+        assert_eq!( quat::abs_squared::<Num, Out>(quat), make(30) );
+        // the casts truncate: 1.11 -> 1 and 0.75 -> 0
+        assert_eq!( quat::abs_squared::<Num, Out>([num(0.5), num(0.5), num(0.5), num(0.6)]), make(1) );
+        assert_eq!( quat::abs_squared::<Num, Out>([num(0.5), num(0.5), num(0.5), num(0.0)]), make(0) );
+        assert_eq!( quat::dot::<Num, Out>(quat, [num(1.0), num(0.0), num(2.0), num(0.0)]), make(7) );
+
+        assert_eq!( Out::ZERO, make(0) );
+        assert_eq!( Out::ONE, make(1) );
+        assert_eq!( Out::NAN, Out::new_scalar(Num::NAN) );
+        assert_eq!( Out::ZERO.scalar(), Num::ZERO );
+        assert_eq!( Out::ONE.scalar(), Num::ONE );
+        assert_eq!( (&make(30)).scalar(), num(30.0) );
+    }
+
+    macro_rules! check_all {
+        ( $num:ty : $( $int:ty ),+ ) => { $(
+            check::<$num, Wrapping<$int>>(|int| Wrapping(int as $int));
+            check::<$num, Saturating<$int>>(|int| Saturating(int as $int));
+            check::<$num, Option<NonZero<$int>>>(|int| NonZero::new(int as $int));
+        )+ };
+    }
+
+    check_all!( f32: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize );
+    check_all!( f64: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize );
+    #[cfg(feature = "std")]
+    check_all!( structs::Std<f32>: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize );
+    #[cfg(feature = "std")]
+    check_all!( structs::Std<f64>: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize );
+
+    // plain `NonZero` is only a scalar, it can't be constructed or have a zero
+    assert_eq!( Scalar::<f32>::scalar(&NonZero::new(5_u16).unwrap()), 5.0 );
+    // constructing saturates the same way `as` does
+    assert_eq!( <Saturating<u8> as ScalarConstructor<f32>>::new_scalar(300.0), Saturating(255) );
+    assert_eq!( <Option<NonZero<u8>> as ScalarConstructor<f32>>::new_scalar(-1.0), None );
+}
+
+#[test]
+fn sum_and_product_of_references() {
+    use structs::{Quat, UnitQuat};
+
+    let quats: Vec<[f32; 4]> = vec![[1.0, 2.0, 3.0, 4.0], [3.0, -2.0, 1.0, -4.0], [1.0, 1.3, 2.2, 3.1], [0.5, 0.0, -1.0, 0.25]];
+    let summed: [f32; 4] = quat::sum::<f32, [f32; 4]>(quats.clone());
+    let multiplied: [f32; 4] = quat::product::<f32, [f32; 4]>(quats.clone());
+
+    // `&[f32; 4]` is a quaternion as well, so nothing needs cloning
+    assert_eq!( quat::sum::<f32, [f32; 4]>(quats.iter()), summed );
+    assert_eq!( quat::product::<f32, [f32; 4]>(quats.iter()), multiplied );
+    assert_eq!( quat::sum::<f32, [f32; 4]>(&quats), summed );
+    assert_eq!( quats.iter().sum::<Quat<f32, [f32; 4]>>(), summed );
+    assert_eq!( quats.iter().product::<Quat<f32, [f32; 4]>>(), multiplied );
+    assert_eq!( quats.iter().copied().sum::<Quat<f32, [f32; 4]>>(), summed );
+
+    let empty: [[f32; 4]; 0] = [];
+    assert_eq!( quat::sum::<f32, [f32; 4]>(empty.iter()), [0.0; 4] );
+    assert_eq!( quat::product::<f32, [f32; 4]>(empty.iter()), [1.0, 0.0, 0.0, 0.0] );
+
+    let units: Vec<UnitQuat<f32>> = quats.iter().map(|quat| quat::to_unit_quat::<f32, UnitQuat<f32>>(quat)).collect();
+    let unit_product: UnitQuat<f32> = units.iter().product();
+    assert!( quat::is_near::<f32>(unit_product, quat::normalize::<f32, [f32; 4]>(multiplied)) );
+    assert_eq!( units.iter().copied().product::<UnitQuat<f32>>(), unit_product );
+    assert_eq!( empty_units().product::<UnitQuat<f32>>(), UnitQuat::default() );
+    assert!( quat::is_near::<f32>(units.iter().sum::<Quat<f32>>(), quat::sum::<f32, [f32; 4]>(&units)) );
+
+    fn empty_units() -> impl Iterator<Item = UnitQuat<f32>> { core::iter::empty() }
+}
+
 // mod exp;
+
+#[test]
+#[cfg(all(feature = "compact_float", feature = "alloc", feature = "test_helpers"))]
+fn compact_float_round_trip() {
+    extern crate alloc;
+    use alloc::string::String;
+
+    fn ulps_f32(a: f32, b: f32) -> u32 { if a == b { 0 } else { (a.to_bits() as i32).abs_diff(b.to_bits() as i32) } }
+    fn ulps_f64(a: f64, b: f64) -> u64 { if a == b { 0 } else { (a.to_bits() as i64).abs_diff(b.to_bits() as i64) } }
+
+    let f32s = F32S.into_iter()
+        .chain(F32S.map(|num| -num))
+        .chain([f32::MAX, f32::MIN_POSITIVE, 1e-45, 1e30, 123456.79, 0.1, 0.3])
+        // a spread out sample of every finite f32
+        .chain((0..0x7F80_0000_u32).step_by(7_654_321).map(f32::from_bits));
+
+    for num in f32s {
+        let mut string = String::new();
+        quat::default_display::<f32>(&mut string, [num, 0.0, 0.0, 0.0]).unwrap();
+        let parsed: [f32; 4] = quat::from_str::<f32, _>(&string).unwrap();
+        assert!( ulps_f32(num, parsed[0]) <= 1, "{num:?} -> {string:?} -> {:?}", parsed[0] );
+    }
+
+    let f64s = F32S.map(|num| num as f64).into_iter()
+        .chain([f64::MAX, f64::MIN_POSITIVE, 5e-324, 1e300, 0.1, 0.3, 1.0 / 3.0])
+        .chain((0..0x7FF0_0000_0000_0000_u64).step_by(32_876_543_210_987_654).map(f64::from_bits));
+
+    for num in f64s {
+        let mut string = String::new();
+        quat::default_display::<f64>(&mut string, [num, 0.0, 0.0, 0.0]).unwrap();
+        let parsed: [f64; 4] = quat::from_str::<f64, _>(&string).unwrap();
+        assert!( ulps_f64(num, parsed[0]) <= 1, "{num:?} -> {string:?} -> {:?}", parsed[0] );
+    }
+
+    // Shows the same output as `Display` for simple numbers
+    assert_eq!( quat::to_default_string::<f32>([1.0, -2.5, 0.125, 4.0]).unwrap(), "1 - 2.5i + 0.125j + 4k" );
+    assert_eq!( quat::to_default_string::<f64>([0.1, 0.0, 0.0, -1e-7]).unwrap(), "0.1 - 0.0000001k" );
+}
+
+#[test]
+#[cfg(all(feature = "std_axis_for_primitives", feature = "test_helpers"))]
+fn std_axis_for_primitives_matches_std() {
+    use structs::Std;
+
+    fn same_bits(left: [f32; 4], right: [Std<f32>; 4]) -> bool {
+        left.into_iter().zip(right).all(|(left, Std(right))| {
+            left.to_bits() == right.to_bits() || (left.is_nan() && right.is_nan())
+        })
+    }
+
+    for a in F32S {
+        for b in F32S {
+            let primitive: [f32; 4] = [a, b, -b, 0.5 * a];
+            let std: [Std<f32>; 4] = primitive.map(Std);
+
+            assert!(
+                same_bits(quat::exp::<f32, [f32; 4]>(primitive), quat::exp::<Std<f32>, [Std<f32>; 4]>(std)),
+                "exp {primitive:?}",
+            );
+            assert!(
+                same_bits(quat::sqrt::<f32, [f32; 4]>(primitive), quat::sqrt::<Std<f32>, [Std<f32>; 4]>(std)),
+                "sqrt {primitive:?}",
+            );
+        }
+    }
+}
+
+#[test]
+#[ignore]
+#[cfg(all(feature = "std", feature = "test_helpers"))]
+fn timing_primitive_axis_vs_std() {
+    use structs::Std;
+
+    let primitive_average = timer! {
+        run {
+            for quat in f32_quats() {
+                core::hint::black_box(quat::exp::<f32, [f32; 4]>(core::hint::black_box(quat)));
+            }
+        },
+        repeat 5,
+    };
+
+    println!();
+
+    let std_average = timer! {
+        run {
+            for quat in f32_quats() {
+                core::hint::black_box(quat::exp::<Std<f32>, [Std<f32>; 4]>(core::hint::black_box(quat.map(Std))));
+            }
+        },
+        repeat 5,
+    };
+
+    println!("  exp: f32 {primitive_average:?}, Std<f32> {std_average:?}");
+    println!("
+    release, without `std_axis_for_primitives`:
+        exp(q) with f32      takes ~`727`ms to run on all F32_Quats
+        exp(q) with Std<f32> takes ~`590`ms to run on all F32_Quats
+
+    release, with `std_axis_for_primitives`:
+        exp(q) with f32      takes ~`586`ms to run on all F32_Quats
+        exp(q) with Std<f32> takes ~`578`ms to run on all F32_Quats
+    ");
+    #[cfg(not(feature = "std_axis_for_primitives"))]
+    assert!( std_average < primitive_average );
+}
+
+#[test]
+#[cfg(feature = "test_helpers")]
+fn batch_matches_scalar() {
+    let mut quats = f32_quats().step_by(997);
+    while let (Some(a), Some(b), Some(c), Some(d)) = (quats.next(), quats.next(), quats.next(), quats.next()) {
+        let left: [[f32; 4]; 4] = [a, b, c, d];
+        let right: [[f32; 4]; 4] = [d, c, b, a];
+
+        let mul4 = quat::mul4(&left, &right);
+        let dot4 = quat::dot4(&left, &right);
+        let normalize4 = quat::normalize4(&left);
+        for n in 0..4 {
+            assert_eq!( mul4[n], quat::mul::<f32, [f32; 4]>(left[n], right[n]) );
+            assert_eq!( dot4[n], quat::dot::<f32, f32>(left[n], right[n]) );
+            assert_eq!( normalize4[n], quat::normalize::<f32, [f32; 4]>(left[n]) );
+        }
+    }
+
+    // subnormal axes, and a normal one whose square underflows
+    let tiny: [[f32; 4]; 4] = [
+        [1e-38, 0.0, 0.0, 0.0],
+        [0.0, -3e-40, 4e-40, 0.0],
+        [1e-20, 1e-40, 0.0, -1e-45],
+        [0.0, 0.0, 0.0, -1e-45],
+    ];
+    let normalize4 = quat::normalize4(&tiny);
+    for n in 0..4 {
+        assert_eq!( normalize4[n], quat::normalize::<f32, [f32; 4]>(tiny[n]) );
+    }
+    assert_eq!( normalize4[0], [1.0, 0.0, 0.0, 0.0] );
+    assert_quat_near!( f32; normalize4[1], [0.0, -0.6, 0.8, 0.0] );
+    assert_eq!( normalize4[3], [0.0, 0.0, 0.0, -1.0] );
+}
+
+#[test]
+#[ignore]
+#[cfg(all(feature = "std", feature = "test_helpers"))]
+fn timing_mul_vs_mul4() {
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    let quats: Vec<[[f32; 4]; 4]> = f32_quats()
+        .take(4096)
+        .collect::<Vec<[f32; 4]>>()
+        .chunks_exact(4)
+        .map(|chunk| [chunk[0], chunk[1], chunk[2], chunk[3]])
+        .collect();
+
+    let mul_average = timer! {
+        run {
+            for pair in core::hint::black_box(&quats).windows(2) {
+                for n in 0..4 {
+                    core::hint::black_box(quat::mul::<f32, [f32; 4]>(pair[0][n], pair[1][n]));
+                }
+            }
+        },
+        silent, repeat 1000,
+    };
+
+    println!();
+
+    let mul4_average = timer! {
+        run {
+            for pair in core::hint::black_box(&quats).windows(2) {
+                core::hint::black_box(quat::mul4(&pair[0], &pair[1]));
+            }
+        },
+        silent, repeat 1000,
+    };
+
+    // the default x86_64 target only has 128 bit SSE2 and doesn't reach 2x,
+    // with avx2 (eg: `RUSTFLAGS="-C target-cpu=native"`) it's between 2x and 3x
+    println!("
+    release (x86_64, default target cpu), ~`1.2`-`1.5`x:
+        mul(q, p) on each pair takes ~`16.7`µs for 1023 batches
+        mul4(q, p)             takes ~`11.4`µs for 1023 batches
+
+    release (x86_64, `-C target-cpu=native` with avx2), ~`2.3`-`3`x:
+        mul(q, p) on each pair takes ~`14.8`µs for 1023 batches
+        mul4(q, p)             takes ~`5.7`µs for 1023 batches
+    ");
+    if cfg!(target_feature = "avx2") {
+        assert!( 2 * mul4_average <= mul_average, "mul4 {mul4_average:?}, mul {mul_average:?}" );
+    } else {
+        assert!( mul4_average < mul_average, "mul4 {mul4_average:?}, mul {mul_average:?}" );
+    }
+}
+
+#[test]
+fn cell_containers_as_quaternions() {
+    use core::cell::{Cell, RefCell, OnceCell};
+
+    let a: [f32; 4] = [1.0, -2.0, 0.5, 3.0];
+    let b: [f32; 4] = [-0.25, 4.0, 1.5, -1.0];
+    let abs: f32 = quat::abs::<f32, f32>(a);
+    let mul: [f32; 4] = quat::mul::<f32, [f32; 4]>(a, b);
+
+    assert_eq!( quat::abs::<f32, f32>(Cell::new(a)), abs );
+    assert_eq!( quat::mul::<f32, [f32; 4]>(Cell::new(a), Cell::new(b)), mul );
+
+    assert_eq!( quat::abs::<f32, f32>(RefCell::new(a)), abs );
+    assert_eq!( quat::mul::<f32, [f32; 4]>(RefCell::new(a), RefCell::new(b)), mul );
+
+    let once_a: OnceCell<[f32; 4]> = OnceCell::new();
+    let once_b: OnceCell<[f32; 4]> = OnceCell::new();
+    once_a.set(a).unwrap();
+    once_b.set(b).unwrap();
+    assert_eq!( quat::abs::<f32, f32>(&once_a), abs );
+    assert_eq!( quat::mul::<f32, [f32; 4]>(&once_a, &once_b), mul );
+}
+
+#[test]
+#[should_panic(expected = "Empty OnceCell! It has to be initialized to read it as a quaternion")]
+fn empty_once_cell() {
+    quat::abs::<f32, f32>(core::cell::OnceCell::<[f32; 4]>::new());
+}
+
+#[test]
+#[should_panic(expected = "already mutably borrowed")]
+fn mutably_borrowed_ref_cell() {
+    let cell: core::cell::RefCell<[f32; 4]> = core::cell::RefCell::new([1.0, 0.0, 0.0, 0.0]);
+    let _borrow = cell.borrow_mut();
+    quat::abs::<f32, f32>(&cell);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn lock_guards_as_quaternions() {
+    use std::sync::{Mutex, RwLock};
+
+    let a: [f32; 4] = [1.0, -2.0, 0.5, 3.0];
+    let b: [f32; 4] = [-0.25, 4.0, 1.5, -1.0];
+    let abs: f32 = quat::abs::<f32, f32>(a);
+    let mul: [f32; 4] = quat::mul::<f32, [f32; 4]>(a, b);
+
+    let mutex_a = Mutex::new(a);
+    let mutex_b = Mutex::new(b);
+    assert_eq!( quat::abs::<f32, f32>(mutex_a.lock().unwrap()), abs );
+    assert_eq!( quat::mul::<f32, [f32; 4]>(mutex_a.lock().unwrap(), mutex_b.lock().unwrap()), mul );
+
+    let lock_a = RwLock::new(a);
+    let lock_b = RwLock::new(b);
+    assert_eq!( quat::abs::<f32, f32>(lock_a.read().unwrap()), abs );
+    assert_eq!( quat::mul::<f32, [f32; 4]>(lock_a.read().unwrap(), lock_b.read().unwrap()), mul );
+    assert_eq!( quat::abs::<f32, f32>(lock_a.write().unwrap()), abs );
+}
+
+#[test]
+#[cfg(all(feature = "matrix", feature = "rotation"))]
+fn matrix_major_adapters() {
+    use structs::{ColMajor, RowMajor};
+    use traits::{Matrix, MatrixConstructor};
+
+    let rotation: [f32; 4] = quat::from_axis_angle::<f32, [f32; 4]>([1.0_f32, -2.0, 0.5], 1.3_f32);
+    let mut rows: [[f32; 4]; 4] = [[0.0; 4]; 4];
+    rows[3][3] = 1.0;
+    for col in 0..3 {
+        let mut basis: [f32; 3] = [0.0; 3];
+        basis[col] = 1.0;
+        let rotated: [f32; 3] = quat::point_rotation::<f32, [f32; 3]>(rotation, basis);
+        for row in 0..3 {
+            rows[row][col] = rotated[row];
+        }
+    }
+
+    let row_major: RowMajor<[f32; 16]> = MatrixConstructor::new_matrix(rows);
+    let col_major: ColMajor<[f32; 16]> = MatrixConstructor::new_matrix(rows);
+    for row in 0..4 {
+        for col in 0..4 {
+            assert_eq!( row_major.0[row * 4 + col], rows[row][col] );
+            assert_eq!( col_major.0[col * 4 + row], rows[row][col] );
+        }
+    }
+    assert_eq!( col_major.to_array(), rows );
+
+    let from_row: [f32; 4] = quat::from_matrix_4::<f32, f32, [f32; 4]>(row_major);
+    let from_col: [f32; 4] = quat::from_matrix_4::<f32, f32, [f32; 4]>(col_major);
+    assert_eq!( from_row, from_col );
+    // `rows` has the rotated basis vectors as columns, the transpose of the DCM
+    // the matrix functions use, so it reads back as the inverse rotation
+    let inverse: [f32; 4] = quat::conj::<f32, [f32; 4]>(rotation);
+    assert!( quat::is_near_by::<f32>(from_row, inverse, 0.00001), "{from_row:?} != {inverse:?}" );
+
+    // reading a buffer with the wrong convention reads the transposed matrix,
+    // which is the inverse rotation
+    let from_transposed: [f32; 4] = quat::from_matrix_4::<f32, f32, [f32; 4]>(ColMajor(row_major.0));
+    assert!( quat::is_near_by::<f32>(from_transposed, quat::conj::<f32, [f32; 4]>(from_row), 0.00001) );
+}
+
+#[test]
+#[cfg(all(feature = "matrix", feature = "std"))]
+fn matrix_to_array_drops_every_element_once() {
+    use std::string::{String, ToString};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use traits::Matrix;
+
+    static CREATED: AtomicUsize = AtomicUsize::new(0);
+    static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Debug, PartialEq)]
+    struct Counted(String);
+    impl Counted {
+        fn new(text: String) -> Self {
+            CREATED.fetch_add(1, Ordering::SeqCst);
+            Counted(text)
+        }
+    }
+    impl Clone for Counted {
+        fn clone(&self) -> Self { Counted::new(self.0.clone()) }
+    }
+    impl Drop for Counted {
+        fn drop(&mut self) { DROPPED.fetch_add(1, Ordering::SeqCst); }
+    }
+
+    /// Uses the default `to_array`, but panics when reaching `stop`.
+    struct PanicsAt([[Counted; 3]; 3], (usize, usize));
+    impl Matrix<Counted, 3> for PanicsAt {
+        fn get_unchecked( &self, row: usize, col: usize ) -> Counted {
+            if (row, col) == self.1 { panic!("reached ({row}, {col})") }
+            self.0[row][col].clone()
+        }
+    }
+
+    let live = || CREATED.load(Ordering::SeqCst) - DROPPED.load(Ordering::SeqCst);
+    let make = || -> [[Counted; 3]; 3] {
+        core::array::from_fn(|row| core::array::from_fn(|col| Counted::new((row * 3 + col).to_string())))
+    };
+
+    {
+        let matrix: [[Counted; 3]; 3] = make();
+        let array: [[Counted; 3]; 3] = matrix.to_array();
+        assert_eq!( array, matrix );
+        assert_eq!( live(), 18 );
+    }
+    assert_eq!( live(), 0 );
+
+    for stop in [(0, 0), (0, 2), (1, 1), (2, 2)] {
+        let matrix: PanicsAt = PanicsAt(make(), stop);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| matrix.to_array()));
+        assert!( result.is_err() );
+        // only the original elements are left, the ones gotten before the panic were dropped once each
+        assert_eq!( live(), 9 );
+        drop(matrix);
+        assert_eq!( live(), 0 );
+    }
+    assert_eq!( DROPPED.load(Ordering::SeqCst), CREATED.load(Ordering::SeqCst) );
+}
+
+#[test]
+#[cfg(feature = "rotation")]
+fn similarity_compose_and_inverse() {
+    use structs::Similarity;
+
+    let near = |left: [f32; 3], right: [f32; 3]| {
+        let size: f32 = right[0].abs().max(right[1].abs()).max(right[2].abs()).max(1.0);
+        (0..3).all(|axis| (left[axis] - right[axis]).abs() < size * 0.0001)
+    };
+
+    let transforms: [Similarity<f32>; 4] = [
+        Similarity::IDENTITY,
+        Similarity::new(quat::from_axis_angle::<f32, [f32; 4]>([0.3, -0.5, 0.8], 1.2), 2.5, [1.0, -2.0, 3.0]),
+        Similarity::new([4.0_f32, -1.0, 0.5, 2.0], 0.125, [-7.0, 0.25, 0.0]),
+        Similarity::new(quat::from_axis_angle::<f32, [f32; 4]>([0.0, 1.0, 0.0], -2.9), -3.0, [0.0, 0.0, -10.0]),
+    ];
+    let points: [[f32; 3]; 4] = [[0.0; 3], [1.0, 2.0, 3.0], [-0.5, 8.0, 0.125], [100.0, -40.0, 7.0]];
+
+    for first in transforms {
+        for point in points {
+            // matches the function
+            let transformed: [f32; 3] = first.transform_point(point);
+            let check: [f32; 3] = quat::similarity_transform::<f32, [f32; 3]>(point, first.rotation(), first.scale(), first.translation());
+            assert!( near(transformed, check), "{first:?} {point:?}" );
+
+            // the inverse round trips both ways
+            let back: [f32; 3] = first.inverse().transform_point(transformed);
+            assert!( near(back, point), "{first:?} {point:?} {back:?}" );
+            let back: [f32; 3] = first.transform_point(first.inverse().transform_point::<[f32; 3]>(point));
+            assert!( near(back, point), "{first:?} {point:?} {back:?}" );
+
+            for second in transforms {
+                let composed: [f32; 3] = first.compose(second).transform_point(point);
+                let one_by_one: [f32; 3] = first.transform_point(second.transform_point::<[f32; 3]>(point));
+                assert!( near(composed, one_by_one), "{first:?} {second:?} {point:?}" );
+            }
+        }
+        let identity: Similarity<f32> = first.compose(first.inverse());
+        assert!( (identity.scale() - 1.0).abs() < 0.0001 );
+        assert!( near(identity.translation(), [0.0; 3]) );
+        assert!( quat::is_near_by::<f32>(identity.rotation(), [1.0, 0.0, 0.0, 0.0], 0.0001) );
+    }
+}
+
+#[test]
+#[cfg(all(feature = "alloc", feature = "rotation"))]
+fn angular_velocity_track_recovers_constant_spin() {
+    let omega: [f32; 3] = [0.6, -1.2, 2.4];
+    let speed: f32 = (omega[0] * omega[0] + omega[1] * omega[1] + omega[2] * omega[2]).sqrt();
+    let axis: [f32; 3] = omega.map(|part| part / speed);
+    let start: [f32; 4] = quat::from_axis_angle::<f32, [f32; 4]>([1.0, 1.0, 0.0], 0.7);
+    let near = |velocity: [f32; 3], tolerance: f32| (0..3).all(|at| (velocity[at] - omega[at]).abs() < tolerance);
+
+    // uneven timestamps, a sign flip and a sample that isn't normalized
+    let mut samples: Vec<(f32, [f32; 4])> = (0..40)
+        .map(|n| n as f32 * 0.05 + if n % 3 == 0 { 0.01 } else { 0.0 })
+        .map(|time| (time, quat::mul::<f32, [f32; 4]>(quat::from_axis_angle::<f32, [f32; 4]>(axis, speed * time), start)))
+        .collect();
+    samples[17].1 = quat::neg::<f32, [f32; 4]>(samples[17].1);
+    samples[25].1 = quat::scale::<f32, [f32; 4]>(samples[25].1, 3.0);
+
+    let mut velocities: Vec<[f32; 3]> = vec![[9.0; 3]; 3];
+    quat::angular_velocity_track::<f32>(&samples, 1, &mut velocities);
+    assert_eq!( velocities.len(), samples.len() );
+    for (at, velocity) in velocities.iter().enumerate() {
+        assert!( near(*velocity, 0.001), "{at}: {velocity:?}" );
+    }
+
+    quat::angular_velocity_track::<f32>(&samples, 5, &mut velocities);
+    assert_eq!( velocities.len(), samples.len() );
+    for (at, velocity) in velocities.iter().enumerate() {
+        assert!( near(*velocity, 0.001), "{at}: {velocity:?}" );
+    }
+
+    // smoothing evens out jitter around the constant spin
+    for (n, sample) in samples.iter_mut().enumerate() {
+        let jitter: f32 = if n % 2 == 0 { 0.002 } else { -0.002 };
+        sample.1 = quat::mul::<f32, [f32; 4]>(quat::from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], jitter), sample.1);
+    }
+    quat::angular_velocity_track::<f32>(&samples, 1, &mut velocities);
+    let raw_error: f32 = velocities[5..35].iter().map(|velocity| (velocity[2] - omega[2]).abs()).fold(0.0, f32::max);
+    quat::angular_velocity_track::<f32>(&samples, 4, &mut velocities);
+    let smoothed_error: f32 = velocities[5..35].iter().map(|velocity| (velocity[2] - omega[2]).abs()).fold(0.0, f32::max);
+    assert!( smoothed_error < raw_error * 0.5, "{smoothed_error} {raw_error}" );
+
+    quat::angular_velocity_track::<f32>(&samples[..1], 3, &mut velocities);
+    assert_eq!( velocities, [[0.0; 3]] );
+    quat::angular_velocity_track::<f32>(&[], 3, &mut velocities);
+    assert!( velocities.is_empty() );
+}
+
+#[test]
+#[cfg(all(feature = "alloc", feature = "rotation"))]
+fn quat_spline_sampling() {
+    use structs::{QuatSpline, SplineEnds};
+
+    let axis: [f32; 3] = [0.3, -0.5, 0.8];
+    let keys: [(f32, [f32; 4]); 5] = [
+        (0.0, quat::from_axis_angle::<f32, [f32; 4]>(axis, 0.0)),
+        (0.5, quat::from_axis_angle::<f32, [f32; 4]>([1.0, 0.0, 0.0], 0.7)),
+        (1.25, quat::from_axis_angle::<f32, [f32; 4]>(axis, 2.0)),
+        (2.0, quat::from_axis_angle::<f32, [f32; 4]>([0.0, 1.0, 0.0], -1.0)),
+        (3.0, quat::from_axis_angle::<f32, [f32; 4]>(axis, 0.1)),
+    ];
+    let spline: QuatSpline<f32> = QuatSpline::from_keys(SplineEnds::Clamp, keys.iter().rev().copied());
+
+    // sampling at the keys gives back the keys
+    for (time, key) in keys {
+        assert!( quat::is_near_by::<f32>(spline.sample(time), key, 0.00001) );
+    }
+    assert_eq!( spline.sample(-1.0), spline.sample(0.0) );
+    assert_eq!( spline.sample(4.0), spline.sample(3.0) );
+    assert_eq!( spline.velocity(4.0), [0.0; 3] );
+
+    // sign flipped copies of the same rotation stay at that rotation
+    let rotation: [f32; 4] = quat::from_axis_angle::<f32, [f32; 4]>(axis, 1.0);
+    let flipped: QuatSpline<f32> = QuatSpline::from_keys(SplineEnds::Clamp, [
+        (0.0, rotation),
+        (1.0, quat::neg::<f32, [f32; 4]>(rotation)),
+        (2.0, rotation),
+    ]);
+    for step in 0..=20 {
+        let time: f32 = step as f32 * 0.1;
+        assert!( quat::is_near_by::<f32>(flipped.sample(time), rotation, 0.00001) );
+        assert!( quat::abs::<f32, f32>(quat::from_vector::<f32, [f32; 4]>(flipped.velocity(time))) < 0.0001 );
+    }
+}
+
+#[test]
+#[cfg(all(feature = "alloc", feature = "rotation"))]
+fn quat_spline_loop_wraps_smoothly() {
+    use structs::{QuatSpline, SplineEnds};
+
+    let same_rotation = |left: [f32; 4], right: [f32; 4]| quat::dot::<f32, f32>(left, right).abs() > 1.0 - 0.00001;
+    let near_vector = |left: [f32; 3], right: [f32; 3]| {
+        (left[0] - right[0]).abs() < 0.005 && (left[1] - right[1]).abs() < 0.005 && (left[2] - right[2]).abs() < 0.005
+    };
+
+    // a full turn around z with some wobble, the last key is a full turn (the same rotation as the first one)
+    let mut spline: QuatSpline<f32> = QuatSpline::new(SplineEnds::Loop);
+    for step in 0..=6 {
+        let angle: f32 = step as f32 * core::f32::consts::TAU / 6.0;
+        let spin: [f32; 4] = quat::from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], angle);
+        let wobble: [f32; 4] = quat::from_axis_angle::<f32, [f32; 4]>([1.0, 0.0, 0.0], 0.3 * (2.0 * angle).sin());
+        spline.insert(step as f32, quat::mul::<f32, [f32; 4]>(spin, wobble));
+    }
+
+    assert!( same_rotation(spline.sample(6.0), spline.sample(0.0)) );
+    assert!( same_rotation(spline.sample(7.5), spline.sample(1.5)) );
+    assert!( same_rotation(spline.sample(-0.5), spline.sample(5.5)) );
+
+    // no jump and no kink across the end of the loop
+    let before: [f32; 4] = spline.sample(6.0 - 0.001);
+    let after: [f32; 4] = spline.sample(6.0 + 0.001);
+    assert!( quat::dot::<f32, f32>(before, after).abs() > 0.99999 );
+    assert!( near_vector(spline.velocity(6.0 - 0.001), spline.velocity(0.001)) );
+    assert!( near_vector(spline.velocity(6.0 + 0.001), spline.velocity(0.001)) );
+    assert!( near_vector(spline.velocity(6.0), spline.velocity(0.0)) );
+}
+
+/// `mul` and `rotate_vector` of the [quaternion](https://crates.io/crates/quaternion) crate,
+/// copied from version `2.0.0` (`src/lib.rs`) with `T` fixed to `f32`
+/// and its `vecmath` helpers inlined.
+/// 
+/// Vendored instead of a dev-dependency so the tests only need the crate's own dependencies
+/// (`quaternion` pulls in `vecmath` and `piston-float`) and the expected values stay
+/// pinned to that release. Re-copy them when checking against a newer one.
+mod quaternion_crate {
+    pub type Quaternion = (f32, [f32; 3]);
+
+    fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+        [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+    }
+
+    fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+        a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+    }
+
+    fn scale(a: [f32; 3], b: f32) -> [f32; 3] {
+        [a[0] * b, a[1] * b, a[2] * b]
+    }
+
+    fn add(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+        [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+    }
+
+    pub fn mul(a: Quaternion, b: Quaternion) -> Quaternion {
+        (
+            a.0 * b.0 - dot(a.1, b.1),
+            [
+                a.0 * b.1[0] + a.1[0] * b.0 + a.1[1] * b.1[2] - a.1[2] * b.1[1],
+                a.0 * b.1[1] + a.1[1] * b.0 + a.1[2] * b.1[0] - a.1[0] * b.1[2],
+                a.0 * b.1[2] + a.1[2] * b.0 + a.1[0] * b.1[1] - a.1[1] * b.1[0],
+            ],
+        )
+    }
+
+    pub fn rotate_vector(q: Quaternion, v: [f32; 3]) -> [f32; 3] {
+        let t = scale(cross(q.1, v), 2.0);
+        add(add(v, scale(t, q.0)), cross(q.1, t))
+    }
+}
+
+#[test]
+#[cfg(feature = "rotation")]
+fn quaternion_crate_compatibility() {
+    use structs::Quat;
+
+    let quats: [(f32, [f32; 3]); 3] = [
+        (1.0, [2.0, -3.0, 0.5]),
+        (0.25, [-1.0, 0.0, 4.0]),
+        (-2.0, [0.1, 0.2, -0.3]),
+    ];
+    let vector: [f32; 3] = [0.3, -1.2, 2.5];
+
+    for left in quats {
+        let quat: Quat<f32> = Quat::from(left);
+        assert_eq!( quat.to_wxyz_tuple(), left );
+        assert_eq!( <(f32, [f32; 3])>::from(quat), left );
+
+        let unit: (f32, [f32; 3]) = quat::normalize::<f32, (f32, [f32; 3])>(left);
+        // argument order: `quaternion::rotate_vector(q, v)` is `quat::rotate_vector(v, q)`
+        assert_eq!(
+            quat::rotate_vector::<f32, [f32; 3]>(vector, unit),
+            quaternion_crate::rotate_vector(unit, vector),
+        );
+        // same rotation, but the operations are done in a different order
+        let point_rotated: [f32; 3] = quat::point_rotation_unchecked::<f32, [f32; 3]>(unit, vector);
+        let rotated: [f32; 3] = quaternion_crate::rotate_vector(unit, vector);
+        for n in 0..3 {
+            assert!( (point_rotated[n] - rotated[n]).abs() < 0.00001 );
+        }
+
+        // the same product, up to rounding (the terms are added in a different order)
+        for right in quats {
+            assert!( quat::is_near_by::<f32>(
+                quat::mul::<f32, (f32, [f32; 3])>(left, right),
+                quaternion_crate::mul(left, right),
+                0.00001,
+            ) );
+        }
+    }
+}
+
+#[test]
+fn unit_debug_checks() {
+    use structs::UnitQuat;
+
+    let unit: UnitQuat<f32> = UnitQuat::new(0.5, 0.5, -0.5, 0.5).unwrap();
+    // the sum (and the scaled parts of `slerp`) aren't unit quaternions,
+    // they don't trip the debug check of the unit module
+    assert_eq!( unsafe { unit::add_checked::<f32, UnitQuat<f32>>(unit, unit) }, None );
+    let half: UnitQuat<f32> = unsafe { unit::scale::<f32, UnitQuat<f32>>(unit, 0.5) };
+    assert_eq!( quat::abs::<f32, f32>(half), 0.5 );
+}
+
+#[test]
+fn lerp_takes_the_shortest_path() {
+    let from: [f64; 4] = [1.0, 0.0, 0.0, 0.0];
+    let to: [f64; 4] = [-0.5, 0.5, -0.5, 0.5];
+
+    // heads to `-to` (the same rotation) instead of through the origin
+    assert_eq!( quat::lerp::<f64, [f64; 4]>(from, to, 0.0), from );
+    assert_eq!( quat::lerp::<f64, [f64; 4]>(from, to, 1.0), quat::neg::<f64, [f64; 4]>(to) );
+    assert_eq!( quat::lerp::<f64, [f64; 4]>(from, to, 0.5), [0.75, -0.25, 0.25, -0.25] );
+}
+
+#[test]
+#[cfg(feature = "math_fns")]
+fn slerp_of_nearly_equal_quaternions() {
+    let from: [f64; 4] = quat::normalize::<f64, [f64; 4]>([1.0, 2.0, -3.0, 0.5]);
+    let to: [f64; 4] = quat::normalize::<f64, [f64; 4]>([1.0, 2.0, -3.0, 0.5001]);
+    // close enough for the linear fallback, from both sides
+    assert!( quat::dot::<f64, f64>(from, to) > consts::slerp_lerp_threshold::<f64>() );
+    let halfway: [f64; 4] = quat::normalize::<f64, [f64; 4]>(quat::add::<f64, [f64; 4]>(from, to));
+    // `-to` is the same rotation, so it ends at `to` as well
+    for other in [to, quat::neg::<f64, [f64; 4]>(to)] {
+        assert!( quat::is_near_by::<f64>(quat::slerp_unchecked::<f64, [f64; 4]>(from, other, 0.0), from, 1e-15) );
+        assert!( quat::is_near_by::<f64>(quat::slerp_unchecked::<f64, [f64; 4]>(from, other, 0.5), halfway, 1e-15) );
+        assert!( quat::is_near_by::<f64>(quat::slerp_unchecked::<f64, [f64; 4]>(from, other, 1.0), to, 1e-15) );
+    }
+
+    // and the same for unit quaternions, which stay unit quaternions
+    use structs::UnitQuat;
+    let (from, to): (UnitQuat<f64>, UnitQuat<f64>) = (UnitQuat::new_normalized(from[0], from[1], from[2], from[3]), UnitQuat::new_normalized(to[0], to[1], to[2], to[3]));
+    for other in [to, -to] {
+        for (at, expected) in [(0.0, quat::from_unit_quat::<f64, [f64; 4]>(from)), (0.5, halfway), (1.0, quat::from_unit_quat::<f64, [f64; 4]>(to))] {
+            let blended: UnitQuat<f64> = unit::slerp::<f64, UnitQuat<f64>>(from, other, at);
+            assert!( quat::is_near_by::<f64>(blended, expected, 1e-15), "{at}" );
+            assert!( (quat::abs::<f64, f64>(blended) - 1.0).abs() < 1e-15 );
+        }
+    }
+}
+
+#[test]
+fn unit_quat_operators() {
+    use structs::{Quat, UnitQuat};
+
+    let same = |left: UnitQuat<f32>, right: [f32; 4]| quat::is_near_by::<f32>(left, right, <f32 as traits::Axis>::ERROR);
+
+    let is_unit = |quat: UnitQuat<f32>| (quat::abs::<f32, f32>(quat) - 1.0).abs() < <f32 as traits::Axis>::ERROR;
+
+    let quats: [UnitQuat<f32>; 4] = [
+        UnitQuat::new_normalized(1.0, 2.0, -3.0, 0.5),
+        UnitQuat::new_normalized(-0.25, 1.0, 0.0, 4.0),
+        UnitQuat::new_normalized(2.0, 0.1, 0.2, -0.3),
+        UnitQuat::new_normalized(0.0, 0.0, 1.0, 0.0),
+    ];
+
+    for left in quats {
+        // closure, even after a lot of multiplications
+        let mut product: UnitQuat<f32> = left;
+        for _ in 0..10_000 {
+            for right in quats {
+                product *= right;
+            }
+        }
+        assert!( is_unit(product) );
+
+        assert!( same(left * !left, [1.0, 0.0, 0.0, 0.0]) );
+        assert!( same(!left * left, [1.0, 0.0, 0.0, 0.0]) );
+        assert!( same(-left, quat::neg::<f32, [f32; 4]>(left)) );
+
+        for right in quats {
+            let unit: UnitQuat<f32> = left * right;
+            assert!( same(unit, quat::mul::<f32, [f32; 4]>(left, right)) );
+
+            let quat: Quat<f32> = Quat::new_quat(1.0, 2.0, 3.0, 4.0);
+            let left_mixed: Quat<f32> = left * quat;
+            let right_mixed: Quat<f32> = quat * right;
+            assert_eq!( left_mixed, quat::mul::<f32, [f32; 4]>(left, quat) );
+            assert_eq!( right_mixed, quat::mul::<f32, [f32; 4]>(quat, right) );
+        }
+    }
+
+    // blending
+    let from: UnitQuat<f32> = quats[0];
+    for to in [quats[1], -quats[1], from, -from] {
+        for step in 0..=10 {
+            let at: f32 = step as f32 * 0.1;
+            assert!( is_unit(from.nlerp(to, at)) );
+            #[cfg(feature = "math_fns")]
+            assert!( is_unit(from.slerp(to, at)) );
+        }
+        #[cfg(feature = "math_fns")]
+        assert!( quat::dot::<f32, f32>(from.slerp(to, 1.0), to).abs() > 1.0 - <f32 as traits::Axis>::ERROR );
+        assert!( quat::dot::<f32, f32>(from.nlerp(to, 1.0), to).abs() > 1.0 - <f32 as traits::Axis>::ERROR );
+    }
+    #[cfg(feature = "math_fns")]
+    assert!( same(from.slerp(-from, 0.5), quat::from_unit_quat::<f32, [f32; 4]>(from)) );
+}
+
+#[test]
+#[cfg(all(feature = "test_helpers", feature = "std"))]
+fn assert_helpers_messages() {
+    extern crate alloc;
+    use alloc::string::String;
+    use structs::Quat;
+
+    fn message(run: fn()) -> String {
+        let payload = std::panic::catch_unwind(run).unwrap_err();
+        match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(_) => panic!("the panic payload should be a formatted message"),
+        }
+    }
+
+    let a: Quat<f32> = Quat::new_quat(1.0, 2.0, 3.0, 4.0);
+    assert_quat_near!(a, a);
+    assert_quat_near!(a, Quat::<f32>::new_quat(1.0, 2.0, 3.0, 4.1), 0.2);
+    assert_same_rotation!(a, -a);
+    assert_same_rotation!(f32; [0.0, 1.0, 0.0, 0.0], [0.0, -1.0, 0.0, 0.0], 0.001);
+
+    let near = message(|| {
+        let left: Quat<f32> = Quat::new_quat(1.0, 2.0, 3.0, 4.0);
+        assert_quat_near!(left, [1.0_f32, 2.0, 3.0, 5.0]);
+    });
+    assert!( near.contains("left ≈ [1.0_f32, 2.0, 3.0, 5.0]"), "{near}" );
+    assert!( near.contains("left: 1 + 2i + 3j + 4k"), "{near}" );
+    assert!( near.contains("right: 1 + 2i + 3j + 5k"), "{near}" );
+    assert!( near.contains("difference: [0, 0, 0, -1]"), "{near}" );
+    assert!( near.contains("distance: 1"), "{near}" );
+
+    let rotation = message(|| assert_same_rotation!(f32; [1.0, 0.0, 0.0, 0.0], [0.0, 0.0, 0.0, -1.0], 0.5));
+    assert!( rotation.contains("is the same rotation as"), "{rotation}" );
+    assert!( rotation.contains("tolerance: 0.5"), "{rotation}" );
+}
+
+#[test]
+#[cfg(feature = "rotation")]
+fn from_rotation_composes_yaw_pitch_roll() {
+    for [roll, pitch, yaw] in [[0.3_f64, -0.7, 1.1], [-2.5, 1.2, 0.4], [1.0, 0.0, -3.0], [0.1, 0.2, 0.3]] {
+        let composed: [f64; 4] = quat::mul::<f64, [f64; 4]>(
+            quat::mul::<f64, [f64; 4]>(
+                quat::from_axis_angle::<f64, [f64; 4]>([0.0, 0.0, 1.0], yaw),
+                quat::from_axis_angle::<f64, [f64; 4]>([0.0, 1.0, 0.0], pitch),
+            ),
+            quat::from_axis_angle::<f64, [f64; 4]>([1.0, 0.0, 0.0], roll),
+        );
+        let quat: [f64; 4] = quat::from_rotation::<f64, [f64; 4]>([roll, pitch, yaw]);
+        assert!( quat::is_near_by::<f64>(quat, composed, 1e-12), "{quat:?} != {composed:?}" );
+        assert!( (quat::abs::<f64, f64>(quat) - 1.0).abs() < 1e-12 );
+    }
+}
+
+#[test]
+#[cfg(feature = "rotation")]
+fn compose_axis_angles_matches_from_rotation() {
+    // the roll is done first, so it's the first link of the chain
+    for [roll, pitch, yaw] in [[0.3_f64, -0.7, 1.1], [-2.5, 1.2, 0.4], [1.0, 0.0, -3.0], [0.1, 0.2, 0.3]] {
+        let chain: [f64; 4] = quat::compose_axis_angles::<f64, [f64; 4]>([
+            ([1.0, 0.0, 0.0], roll),
+            ([0.0, 1.0, 0.0], pitch),
+            ([0.0, 0.0, 1.0], yaw),
+        ]);
+        let quat: [f64; 4] = quat::from_rotation::<f64, [f64; 4]>([roll, pitch, yaw]);
+        assert!( quat::is_near_by::<f64>(chain, quat, 1e-12), "{chain:?} != {quat:?}" );
+    }
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "test_helpers"))]
+fn from_rotation_undoes_to_rotation() {
+    for rotation in [[0.3_f32, -0.7, 1.1], [-2.5, 1.2, 0.4], [1.0, 0.0, -3.0], [0.1, 0.2, 0.3]] {
+        let quat: [f32; 4] = quat::from_rotation::<f32, [f32; 4]>(rotation);
+        let angles: [f32; 3] = quat::to_rotation::<f32, [f32; 3]>(quat);
+        for axis in 0..3 {
+            assert!( (angles[axis] - rotation[axis]).abs() < 0.0001, "{rotation:?} became {angles:?}" );
+        }
+        assert_same_rotation!(f32; quat::from_rotation::<f32, [f32; 4]>(angles), quat, 0.0001);
+    }
+}
+
+#[test]
+#[cfg(all(feature = "bevy_math", feature = "rotation", feature = "matrix", feature = "test_helpers"))]
+fn bevy_math_interop() {
+    use bevy_math::{Quat, DQuat, Vec3, Vec3A, Mat3, Mat4, EulerRot};
+
+    // a `Transform`'s rotation is a plain `Quat`
+    let rotation: Quat = Quat::from_euler(EulerRot::XYZ, 0.3, -0.7, 1.1);
+    assert_eq!( quat::convert_quat::<f32, [f32; 4]>(rotation), [rotation.w, rotation.x, rotation.y, rotation.z] );
+
+    let angles: [f32; 3] = quat::to_rotation::<f32, [f32; 3]>(rotation);
+    let (yaw, pitch, roll) = rotation.to_euler(EulerRot::ZYX);
+    assert!( (angles[0] - roll).abs() < 0.0001 && (angles[1] - pitch).abs() < 0.0001 && (angles[2] - yaw).abs() < 0.0001 );
+    let back: Quat = quat::from_rotation::<f32, Quat>(angles);
+    assert_same_rotation!(f32; back, rotation, 0.0001);
+
+    let double: DQuat = quat::convert_num::<f32, f64, DQuat>(rotation);
+    assert_same_rotation!(f64; double, rotation.as_dquat(), 0.000001);
+
+    // rotating vectors agrees with bevy
+    let point: Vec3 = Vec3::new(1.0, -2.0, 0.5);
+    let rotated: Vec3 = quat::point_rotation::<f32, Vec3>(rotation, point);
+    assert!( rotated.abs_diff_eq(rotation * point, 0.0001) );
+    let rotated: Vec3A = quat::point_rotation::<f32, Vec3A>(rotation, Vec3A::from(point));
+    assert!( rotated.abs_diff_eq(rotation * Vec3A::from(point), 0.0001) );
+
+    // slerp agrees with bevy
+    let to: Quat = Quat::from_axis_angle(Vec3::new(0.0, 0.6, 0.8), 2.0);
+    for step in 0..=10 {
+        let at: f32 = step as f32 * 0.1;
+        assert_same_rotation!(f32; quat::slerp_unchecked::<f32, Quat>(rotation, to, at), rotation.slerp(to, at), 0.0001);
+    }
+
+    // matrices are read row-major
+    let mat3: Mat3 = Mat3::from_quat(rotation);
+    assert_eq!( traits::Matrix::to_array(&mat3), mat3.transpose().to_cols_array_2d() );
+    assert_eq!( traits::Matrix::<f32, 3>::get_unchecked(&mat3, 0, 2), mat3.z_axis.x );
+    assert_eq!( <Mat3 as traits::MatrixConstructor<f32, 3>>::new_matrix(traits::Matrix::to_array(&mat3)), mat3 );
+    // `from_matrix_3` reads a DCM, the transpose of bevy's rotation matrix
+    assert_same_rotation!(f32; quat::from_matrix_3::<f32, f32, Quat>(mat3.transpose()), rotation, 0.0001);
+    let mat4: Mat4 = quat::to_matrix_4::<f32, f32, Mat4>(rotation);
+    assert_eq!( traits::Matrix::<f32, 4>::to_array(&mat4)[0], [rotation.w, -rotation.x, -rotation.y, -rotation.z] );
+    assert_eq!( mat4.x_axis.to_array(), [rotation.w, rotation.x, rotation.y, rotation.z] );
+}
+
+#[test]
+#[cfg(feature = "test_harness")]
+fn axis_conformance() {
+    use axis_tests::check_axis_conformance;
+
+    check_axis_conformance::<f32>(0.0001);
+    check_axis_conformance::<f64>(0.000_000_001);
+    #[cfg(feature = "std")]
+    {
+        use structs::Std;
+        check_axis_conformance::<Std<f32>>(Std(0.0001));
+        check_axis_conformance::<Std<f64>>(Std(0.000_000_001));
+    }
+}
+
+#[test]
+fn default_axis_sin_and_cos() {
+    use core::ops::{Add, Sub, Mul, Div, Rem, Neg};
+    use traits::Axis;
+
+    // only has the required methods, so `sin` and `cos` are the defaults taken from `sin_cos`
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    struct Plain(f64);
+
+    macro_rules! op {
+        ( $trait:ident, $fn:ident, $op:tt ) => {
+            impl $trait for Plain {
+                type Output = Plain;
+                fn $fn(self, other: Plain) -> Plain { Plain(self.0 $op other.0) }
+            }
+        };
+    }
+    op!(Add, add, +);
+    op!(Sub, sub, -);
+    op!(Mul, mul, *);
+    op!(Div, div, /);
+    op!(Rem, rem, %);
+
+    impl Neg for Plain {
+        type Output = Plain;
+        fn neg(self) -> Plain { Plain(-self.0) }
+    }
+
+    impl Axis for Plain {
+        const ONE: Self = Plain(1.0);
+        const ZERO: Self = Plain(0.0);
+        const TAU: Self = Plain(<f64 as Axis>::TAU);
+        const NAN: Self = Plain(f64::NAN);
+        const ERROR: Self = Plain(<f64 as Axis>::ERROR);
+        fn is_nan(&self) -> bool { self.0.is_nan() }
+        fn mul_add(self, factor: Self, addend: Self) -> Self { Plain(self.0.mul_add(factor.0, addend.0)) }
+        fn sqrt(self) -> Self { Plain(self.0.sqrt()) }
+        fn pow(self, exp: Self) -> Self { Plain(self.0.pow(exp.0)) }
+        fn sin_cos(self) -> (Self, Self) { let (sin, cos) = self.0.sin_cos(); (Plain(sin), Plain(cos)) }
+        fn asin(self) -> Self { Plain(self.0.asin()) }
+        fn acos(self) -> Self { Plain(self.0.acos()) }
+        fn atan2(self, bottom: Self) -> Self { Plain(self.0.atan2(bottom.0)) }
+        fn exp(self) -> Self { Plain(self.0.exp()) }
+        fn ln(self) -> Self { Plain(self.0.ln()) }
+        fn from_f64(float: f64) -> Self { Plain(float) }
+    }
+
+    for angle in [0.0, 0.5, 1.0, -2.0, 3.0] {
+        let (sin, cos): (f64, f64) = angle.sin_cos();
+        assert_eq!( Plain(angle).sin(), Plain(sin) );
+        assert_eq!( Plain(angle).cos(), Plain(cos) );
+    }
+}
+
+// the consts of the single element wrappers, checked at compile time
+const _: ([f32; 3], ) = <([f32; 3], ) as traits::VectorConsts<f32>>::UNIT_X;
+const _: [[f32; 3]; 1] = <[[f32; 3]; 1] as traits::VectorConsts<f32>>::UNIT_Z;
+const _: ([f32; 2], ) = <([f32; 2], ) as traits::ComplexConsts<f32>>::UNIT_IMAGINARY;
+const _: [(f32, f32); 1] = <[(f32, f32); 1] as traits::ComplexConsts<f32>>::IDENTITY;
+const _: ([f32; 4], ) = <([f32; 4], ) as traits::QuaternionConsts<f32>>::UNIT_J;
+const _: [Q<f32>; 1] = <[Q<f32>; 1] as traits::QuaternionConsts<f32>>::ORIGIN;
+const _: (U<f32>, ) = <(U<f32>, ) as traits::UnitQuaternionConsts<f32>>::UNIT_K;
+const _: [U<f32>; 1] = <[U<f32>; 1] as traits::UnitQuaternionConsts<f32>>::IDENTITY;
+#[cfg(feature = "std")]
+const _: structs::Std<U<f32>> = <structs::Std<U<f32>> as traits::UnitQuaternionConsts<structs::Std<f32>>>::UNIT_I;
+
+#[test]
+fn single_element_wrapper_consts() {
+    use traits::{VectorConsts, ComplexConsts, QuaternionConsts, UnitQuaternionConsts};
+
+    assert_eq!( <([f32; 3], ) as VectorConsts<f32>>::UNIT_Y, ([0.0, 1.0, 0.0], ) );
+    assert_eq!( <[[f32; 3]; 1] as VectorConsts<f32>>::ORIGIN, [[0.0; 3]] );
+    assert_eq!( <([f32; 2], ) as ComplexConsts<f32>>::UNIT_IMAGINARY, ([0.0, 1.0], ) );
+    assert_eq!( <[[f32; 2]; 1] as ComplexConsts<f32>>::UNIT_REAL, [[1.0, 0.0]] );
+    assert_eq!( <([f32; 4], ) as QuaternionConsts<f32>>::UNIT_K, ([0.0, 0.0, 0.0, 1.0], ) );
+    assert_eq!( <[[f32; 4]; 1] as QuaternionConsts<f32>>::UNIT_R, [[1.0, 0.0, 0.0, 0.0]] );
+    assert_eq!( quat::convert_quat::<f32, [f32; 4]>(<(U<f32>, ) as UnitQuaternionConsts<f32>>::UNIT_J), [0.0, 0.0, 1.0, 0.0] );
+    assert_eq!( quat::convert_quat::<f32, [f32; 4]>(<[U<f32>; 1] as UnitQuaternionConsts<f32>>::UNIT_I), [0.0, 1.0, 0.0, 0.0] );
+    assert!( quat::is_nan::<f32>(<[U<f32>; 1] as UnitQuaternionConsts<f32>>::NAN) );
+}
+
+#[test]
+#[cfg(all(feature = "math_fns", feature = "rotation"))]
+fn slerp_extrapolate_follows_the_great_circle() {
+    let from: [f32; 4] = quat::from_axis_angle::<f32, [f32; 4]>([1.0, 2.0, -0.5], 0.4);
+    let close: [f32; 4] = quat::from_axis_angle::<f32, [f32; 4]>([-0.3, 1.0, 0.2], 1.2);
+    // more than a quarter turn away on the sphere so `slerp_unchecked` would flip it
+    let far: [f32; 4] = quat::neg::<f32, [f32; 4]>(close);
+
+    for to in [close, far] {
+        let delta: [f32; 4] = quat::mul::<f32, [f32; 4]>(to, quat::conj::<f32, [f32; 4]>(from));
+        let twice: [f32; 4] = quat::slerp_extrapolate::<f32, [f32; 4]>(from, to, 2.0);
+        assert!( quat::is_near_by::<f32>(twice, quat::mul::<f32, [f32; 4]>(delta, to), 0.0001) );
+
+        let reflected: [f32; 4] = quat::slerp_extrapolate::<f32, [f32; 4]>(from, to, -1.0);
+        let through_from: [f32; 4] = quat::mul::<f32, [f32; 4]>(quat::mul::<f32, [f32; 4]>(from, quat::conj::<f32, [f32; 4]>(to)), from);
+        assert!( quat::is_near_by::<f32>(reflected, through_from, 0.0001) );
+
+        // no jumps: small steps of `at` move the result by small amounts
+        let mut previous: [f32; 4] = from;
+        for step in -40..=40 {
+            let at: f32 = step as f32 * 0.1;
+            let current: [f32; 4] = quat::slerp_extrapolate::<f32, [f32; 4]>(from, to, at);
+            assert!( (quat::abs::<f32, f32>(current) - 1.0).abs() < 0.0001 );
+            if step > -40 {
+                assert!( quat::dist_euclid::<f32, f32>(previous, current) < 0.35 );
+            }
+            previous = current;
+        }
+    }
+
+    // the same and opposite quaternions
+    assert!( quat::is_near::<f32>(quat::slerp_extrapolate::<f32, [f32; 4]>(from, from, 5.0), from) );
+    let half: [f32; 4] = quat::slerp_extrapolate::<f32, [f32; 4]>(from, quat::neg::<f32, [f32; 4]>(from), 0.5);
+    assert!( quat::dot::<f32, f32>(half, from).abs() < 0.0001 );
+    assert!( (quat::abs::<f32, f32>(half) - 1.0).abs() < 0.0001 );
+}
+
+#[test]
+fn normalize_fast_relative_error() {
+    use traits::Axis;
+
+    // every bit pattern of [1, 4) covers all the mantissas and both exponent parities
+    let mut x: f32 = 1.0;
+    while x < 4.0 {
+        let error: f64 = (x.inv_sqrt_fast() as f64 * (x as f64).sqrt() - 1.0).abs();
+        assert!( error < 0.000001, "inv_sqrt_fast({x}) is off by {error}" );
+        x = f32::from_bits(x.to_bits() + 1);
+    }
+
+    let directions: [[f32; 4]; 5] = [
+        [1.0, 0.0, 0.0, 0.0],
+        [0.5, -0.5, 0.5, -0.5],
+        [1.0, 2.0, 3.0, 4.0],
+        [-0.3, 0.0, 7.1, 0.02],
+        [0.0, 0.0, -1.0, 1e-3],
+    ];
+    for exponent in -20..=20 {
+        let magnitude: f32 = 10.0_f32.powi(exponent);
+        for direction in directions {
+            let quat: [f32; 4] = direction.map(|x| x * magnitude);
+            let fast: [f32; 4] = quat::normalize_fast::<f32, [f32; 4]>(quat);
+            let exact: [f64; 4] = quat::normalize::<f64, [f64; 4]>(quat.map(|x| x as f64));
+            for axis in 0..4 {
+                let error: f64 = (fast[axis] as f64 - exact[axis]).abs();
+                assert!( error < 0.000001, "normalize_fast({quat:?}) = {fast:?} is off by {error} from {exact:?}" );
+            }
+        }
+    }
+
+    assert_eq!( quat::normalize_fast::<f32, [f32; 4]>([0.0; 4]), [0.0; 4] );
+    assert_eq!( quat::normalize_fast::<f64, [f64; 4]>([0.0, 3.0, 0.0, 4.0]), quat::normalize::<f64, [f64; 4]>([0.0, 3.0, 0.0, 4.0]) );
+}
+
+#[test]
+#[ignore]
+#[cfg(all(feature = "std", feature = "test_helpers"))]
+fn timing_normalize_vs_normalize_fast() {
+    let normalize_average = timer! {
+        run {
+            for quat in f32_quats() {
+                core::hint::black_box(quat::normalize::<f32, [f32; 4]>(core::hint::black_box(quat)));
+            }
+        },
+        repeat 5,
+    };
+
+    let normalize_fast_average = timer! {
+        run {
+            for quat in f32_quats() {
+                core::hint::black_box(quat::normalize_fast::<f32, [f32; 4]>(core::hint::black_box(quat)));
+            }
+        },
+        repeat 5,
+    };
+
+    println!("
+    release (x86_64, hardware sqrt and division):
+        normalize(q)      takes ~`320`ms to run on all F32_Quats
+        normalize_fast(q) takes ~`410`ms to run on all F32_Quats
+    ");
+    ::std::dbg!(normalize_fast_average.as_secs_f64() / normalize_average.as_secs_f64());
+}
+
+#[test]
+#[cfg(all(feature = "math_fns", feature = "rotation", feature = "test_helpers"))]
+fn blend_barycentric_karcher_mean() {
+    let from: [f32; 4] = quat::from_axis_angle::<f32, [f32; 4]>([0.2, 1.0, -0.4], 0.3);
+    let to: [f32; 4] = quat::from_axis_angle::<f32, [f32; 4]>([-1.0, 0.5, 0.7], 2.1);
+    for step in 0..=10 {
+        let at: f32 = step as f32 * 0.1;
+        let blended: [f32; 4] = quat::blend_barycentric::<f32, [f32; 4]>(&[from, to], &[1.0 - at, at]).unwrap();
+        assert_same_rotation!(f32; blended, quat::slerp_unchecked::<f32, [f32; 4]>(from, to, at), 0.0001);
+        // the sign of a quaternion doesn't matter
+        let flipped: [f32; 4] = quat::blend_barycentric::<f32, [f32; 4]>(&[from, quat::neg::<f32, [f32; 4]>(to)], &[1.0 - at, at]).unwrap();
+        assert_same_rotation!(f32; flipped, blended, 0.0001);
+    }
+
+    // three rotations spread evenly around a central one
+    let center: [f32; 4] = quat::from_axis_angle::<f32, [f32; 4]>([1.0, 1.0, 0.0], 0.8);
+    let around: [[f32; 4]; 3] = [0.0_f32, 1.0, 2.0].map(|n| {
+        let angle: f32 = n * core::f32::consts::TAU / 3.0;
+        let offset: [f32; 4] = quat::from_axis_angle::<f32, [f32; 4]>([angle.cos(), angle.sin(), 0.0], 1.0);
+        quat::mul::<f32, [f32; 4]>(center, offset)
+    });
+    let third: f32 = 1.0 / 3.0;
+    let blended: [f32; 4] = quat::blend_barycentric::<f32, [f32; 4]>(&around, &[third; 3]).unwrap();
+    assert_same_rotation!(f32; blended, center, 0.0001);
+
+    assert!( quat::blend_barycentric::<f32, [f32; 4]>(&[] as &[[f32; 4]], &[] as &[f32]).is_none() );
+    assert!( quat::blend_barycentric::<f32, [f32; 4]>(&around, &[0.5_f32, 0.5]).is_none() );
+    assert!( quat::blend_barycentric::<f32, [f32; 4]>(&around, &[0.5_f32, 0.5, 0.5]).is_none() );
+    assert!( quat::blend_barycentric::<f32, [f32; 4]>(&around, &[f32::NAN; 3]).is_none() );
+}
+
+#[test]
+#[cfg(feature = "rotation")]
+fn pairwise_distances_and_nearest() {
+    let a: [f32; 4] = quat::from_axis_angle::<f32, [f32; 4]>([1.0, 0.0, 0.0], 0.4);
+    let b: [f32; 4] = quat::from_axis_angle::<f32, [f32; 4]>([0.0, 1.0, 0.0], 1.1);
+    let c: [f32; 4] = quat::from_axis_angle::<f32, [f32; 4]>([0.3, -0.2, 1.0], 2.9);
+    let set: [[f32; 4]; 5] = [a, b, c, quat::neg::<f32, [f32; 4]>(b), quat::scale::<f32, [f32; 4]>(a, 3.0)];
+
+    let mut distances: [f32; 10] = [f32::NAN; 10];
+    quat::pairwise_angular_distances::<f32>(&set, &mut distances);
+    let n: usize = set.len();
+    for first in 0..n {
+        for second in first + 1..n {
+            let distance: f32 = distances[first * n - first * (first + 1) / 2 + (second - first - 1)];
+            let relative: [f32; 4] = quat::mul::<f32, [f32; 4]>(quat::conj::<f32, [f32; 4]>(set[first]), set[second]);
+            let expected: f32 = 2.0 * quat::normalize::<f32, [f32; 4]>(relative)[0].abs().min(1.0).acos();
+            assert!( (distance - expected).abs() < 0.001, "({first}, {second}): {distance} != {expected}" );
+            assert!( (0.0..=core::f32::consts::PI).contains(&distance) );
+        }
+    }
+    // sign flipped and scaled duplicates are at distance zero
+    assert!( distances[5] < 0.0001 ); // (1, 3)
+    assert!( distances[3] < 0.0001 ); // (0, 4)
+
+    // a set of one or none has no pairs
+    quat::pairwise_angular_distances::<f32>(&[a], &mut []);
+    quat::pairwise_angular_distances::<f32>(&[], &mut []);
+
+    assert_eq!( quat::nearest::<f32>(quat::neg::<f32, [f32; 4]>(c), &set).map(|(index, _)| index), Some(2) );
+    let (index, distance) = quat::nearest::<f32>(b, &set).unwrap();
+    assert_eq!( index, 1 );
+    assert!( distance < 0.0001 );
+    assert_eq!( quat::nearest::<f32>(a, &[]), None );
+}
+
+#[test]
+#[should_panic(expected = "Mismatched slice lengths in pairwise_angular_distances")]
+#[cfg(feature = "rotation")]
+fn pairwise_distances_length_mismatch() {
+    let mut distances: [f32; 2] = [0.0; 2];
+    quat::pairwise_angular_distances::<f32>(&[[1.0, 0.0, 0.0, 0.0]; 3], &mut distances);
+}
+
+#[test]
+fn quaternion_float_consts_are_scalar_quaternions() {
+    use core::f64::consts;
+    use traits::QuaternionFloatConsts;
+
+    fn check<T: QuaternionFloatConsts<f64> + Quaternion<f64>>() {
+        let values: [(T, f64); 19] = [
+            (T::E(), consts::E),
+            (T::FRAC_1_PI(), consts::FRAC_1_PI),
+            (T::FRAC_1_SQRT_2(), consts::FRAC_1_SQRT_2),
+            (T::FRAC_2_PI(), consts::FRAC_2_PI),
+            (T::FRAC_2_SQRT_PI(), consts::FRAC_2_SQRT_PI),
+            (T::FRAC_PI_2(), consts::FRAC_PI_2),
+            (T::FRAC_PI_3(), consts::FRAC_PI_3),
+            (T::FRAC_PI_4(), consts::FRAC_PI_4),
+            (T::FRAC_PI_6(), consts::FRAC_PI_6),
+            (T::FRAC_PI_8(), consts::FRAC_PI_8),
+            (T::LN_10(), consts::LN_10),
+            (T::LN_2(), consts::LN_2),
+            (T::LOG10_E(), consts::LOG10_E),
+            (T::LOG2_E(), consts::LOG2_E),
+            (T::PI(), consts::PI),
+            (T::SQRT_2(), consts::SQRT_2),
+            (T::TAU(), consts::TAU),
+            (T::LOG10_2(), consts::LOG10_2),
+            (T::LOG2_10(), consts::LOG2_10),
+        ];
+        for (quat, expected) in values {
+            assert_eq!( [quat.r(), quat.i(), quat.j(), quat.k()], [expected, 0.0, 0.0, 0.0] );
+        }
+    }
+
+    check::<[f64; 4]>();
+    check::<(f64, f64, f64, f64)>();
+    check::<Q<f64>>();
+    check::<((f64, f64), f64, f64)>();
+    check::<[(f64, f64); 2]>();
+    check::<structs::Quat<f64, [f64; 4]>>();
+
+    let pi: [f32; 4] = <[f32; 4] as QuaternionFloatConsts<f32>>::PI();
+    assert_eq!( pi, [core::f64::consts::PI as f32, 0.0, 0.0, 0.0] );
+}
+
+/// A quaternion that can't be copied or cloned.
+#[derive(Debug, PartialEq)]
+struct NotCopy([f32; 4]);
+
+impl Quaternion<f32> for NotCopy {
+    fn r(&self) -> f32 { self.0[0] }
+    fn i(&self) -> f32 { self.0[1] }
+    fn j(&self) -> f32 { self.0[2] }
+    fn k(&self) -> f32 { self.0[3] }
+}
+
+impl QuaternionConstructor<f32> for NotCopy {
+    fn new_quat(r: f32, i: f32, j: f32, k: f32) -> Self { NotCopy([r, i, j, k]) }
+}
+
+impl QuaternionMethods<f32> for NotCopy {}
+
+#[test]
+fn ref_methods_dont_consume() {
+    let a: NotCopy = NotCopy([1.0, 2.0, -3.0, 0.5]);
+    let b: NotCopy = NotCopy([0.0, -1.0, 4.0, 2.0]);
+    let (x, y): ([f32; 4], [f32; 4]) = (a.0, b.0);
+
+    assert_eq!( a.add_ref(&b), NotCopy(quat::add::<f32, [f32; 4]>(x, y)) );
+    assert_eq!( a.sub_ref(&b), NotCopy(quat::sub::<f32, [f32; 4]>(x, y)) );
+    assert_eq!( a.mul_ref(&b), NotCopy(quat::mul::<f32, [f32; 4]>(x, y)) );
+    assert_eq!( a.div_ref(&b), NotCopy(quat::div::<f32, [f32; 4]>(x, y)) );
+    assert_eq!( a.scale_ref(2.0), NotCopy(quat::scale::<f32, [f32; 4]>(x, 2.0)) );
+    assert_eq!( a.unscale_ref(2.0), NotCopy(quat::unscale::<f32, [f32; 4]>(x, 2.0)) );
+    assert_eq!( a.conj_ref(), NotCopy(quat::conj::<f32, [f32; 4]>(x)) );
+    assert_eq!( a.neg_ref(), NotCopy(quat::neg::<f32, [f32; 4]>(x)) );
+    assert_eq!( a.inv_ref(), NotCopy(quat::inv::<f32, [f32; 4]>(x)) );
+    assert_eq!( a.norm_ref(), NotCopy(quat::normalize::<f32, [f32; 4]>(x)) );
+    assert_eq!( a.abs_ref(), quat::abs::<f32, f32>(x) );
+    assert_eq!( a.abs_squared_ref(), quat::abs_squared::<f32, f32>(x) );
+    assert_eq!( a.angle_ref(), quat::angle::<f32, f32>(x) );
+    assert_eq!( a.dot_ref(&b), quat::dot::<f32, f32>(x, y) );
+    assert_eq!( a.dist_euclid_ref(&b), quat::dist_euclid::<f32, f32>(x, y) );
+    assert_eq!( a.dist_euclid_squared_ref(&b), quat::dist_euclid_squared::<f32, f32>(x, y) );
+    assert!( a.eq_ref(&NotCopy(x)) && !a.eq_ref(&b) );
+    assert!( a.is_near_ref(&NotCopy(x)) && !a.is_near_by_ref(&b, 0.1) );
+    assert!( !a.is_scalar_ref() && !a.is_complex_ref() && !a.is_vector_ref() );
+    #[cfg(any(feature = "qol_fns", feature = "math_fns"))]
+    assert_eq!( a.square_ref(), NotCopy(quat::square::<f32, [f32; 4]>(x)) );
+    #[cfg(any(feature = "math_fns", feature = "trigonometry"))]
+    {
+        assert_eq!( a.exp_ref(), NotCopy(quat::exp::<f32, [f32; 4]>(x)) );
+        assert_eq!( a.ln_ref(), NotCopy(quat::ln::<f32, [f32; 4]>(x)) );
+    }
+
+    // both are still usable (and movable) after all the calls above
+    assert_eq!( QuaternionMethods::add(a, b), NotCopy(quat::add::<f32, [f32; 4]>(x, y)) );
+}
+
+#[test]
+#[cfg(feature = "math_fns")]
+fn hurwitz_units_from_associates() {
+    // the 24 Hurwitz units are the 8 Lipschitz units and the 16 `(±1 ± i ± j ± k) / 2`,
+    // multiplying by a Lipschitz unit keeps the parity of the minus signs
+    // so the half-integer ones are the associates of two quaternions
+    let mut units: Vec<[f32; 4]> = Vec::new();
+    for quat in [[1.0, 0.0, 0.0, 0.0], [0.5, 0.5, 0.5, 0.5], [0.5, 0.5, 0.5, -0.5]] {
+        for associate in quat::associates::<f32, [f32; 4]>(quat) {
+            assert_eq!( quat::four_square_norm::<f32, f32>(associate), 1.0 );
+            assert!( quat::is_hurwitz::<f32>(associate, 0.0001) );
+            if !units.contains(&associate) {
+                units.push(associate);
+            }
+        }
+    }
+    assert_eq!( units.len(), 24 );
+    assert_eq!( units.iter().filter(|unit| quat::is_unit_lipschitz::<f32>(unit)).count(), 8 );
+
+    // closed under multiplication
+    for a in &units {
+        for b in &units {
+            let product: [f32; 4] = quat::mul::<f32, [f32; 4]>(a, b);
+            assert!( units.contains(&product), "{a:?} * {b:?} = {product:?} is not a Hurwitz unit" );
+        }
+    }
+
+    // associates keep the norm
+    let quat: [f32; 4] = [3.0, -1.0, 4.0, 1.0];
+    for associate in quat::associates::<f32, [f32; 4]>(quat) {
+        assert_eq!( quat::four_square_norm::<f32, u32>(associate), 27 );
+        assert!( !quat::is_unit_lipschitz::<f32>(associate) );
+    }
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "test_helpers"))]
+fn to_rotation_ext_gimbal_lock() {
+    use core::f32::consts::{FRAC_PI_2, PI, TAU};
+    use quat::{AngleRange, GimbalLock};
+
+    let (roll, yaw): (f32, f32) = (0.7, -0.4);
+    // at the north pole only `roll - yaw` matters, at the south one only `roll + yaw`
+    for (side, lock, locked_roll) in [(1.0_f32, GimbalLock::North, roll - yaw), (-1.0, GimbalLock::South, roll + yaw)] {
+        // exactly at the pole and just inside the locked region
+        for offset in [0.0_f32, 0.001, -0.001] {
+            let quat: [f32; 4] = quat::from_rotation::<f32, [f32; 4]>([roll, side * (FRAC_PI_2 - offset), yaw]);
+            let (angles, flag) = quat::to_rotation_ext::<f32, [f32; 3]>(quat, AngleRange::Signed);
+            assert_eq!( flag, lock, "{offset}" );
+            assert_eq!( (angles[1], angles[2]), (side * FRAC_PI_2, 0.0) );
+            assert!( (angles[0] - locked_roll).abs() < 0.002, "{offset}: {angles:?}" );
+            assert_same_rotation!(f32; quat::from_rotation::<f32, [f32; 4]>(angles), quat, 0.002);
+        }
+
+        // just outside of it the angles are given back as they were
+        let quat: [f32; 4] = quat::from_rotation::<f32, [f32; 4]>([roll, side * (FRAC_PI_2 - 0.02), yaw]);
+        let (angles, flag) = quat::to_rotation_ext::<f32, [f32; 3]>(quat, AngleRange::Signed);
+        assert_eq!( flag, GimbalLock::None );
+        assert!( (angles[0] - roll).abs() < 0.001 && (angles[2] - yaw).abs() < 0.001, "{angles:?}" );
+        assert!( (angles[0] - side * angles[2] - locked_roll).abs() < 0.001 );
+
+        // past the pole the pitch comes back down and roll and yaw flip around
+        let quat: [f32; 4] = quat::from_rotation::<f32, [f32; 4]>([roll, side * (FRAC_PI_2 + 0.02), yaw]);
+        let (angles, flag) = quat::to_rotation_ext::<f32, [f32; 3]>(quat, AngleRange::Signed);
+        assert_eq!( flag, GimbalLock::None );
+        assert!( (angles[1] - side * (FRAC_PI_2 - 0.02)).abs() < 0.001, "{angles:?}" );
+        assert!( (angles[0] - (roll - PI)).abs() < 0.001 && (angles[2] - (yaw + PI)).abs() < 0.001, "{angles:?}" );
+        assert_same_rotation!(f32; quat::from_rotation::<f32, [f32; 4]>(angles), quat, 0.0001);
+    }
+
+    // the ranges
+    for rotation in [[-3.0_f32, 0.4, -0.1], [2.9, -1.2, 3.1], [-FRAC_PI_2, FRAC_PI_2, 0.0], [-0.5, -FRAC_PI_2, -0.6]] {
+        let quat: [f32; 4] = quat::from_rotation::<f32, [f32; 4]>(rotation);
+        let (signed, signed_lock) = quat::to_rotation_ext::<f32, [f32; 3]>(quat, AngleRange::Signed);
+        let (unsigned, unsigned_lock) = quat::to_rotation_ext::<f32, [f32; 3]>(quat, AngleRange::Unsigned);
+        assert_eq!( signed_lock, unsigned_lock );
+        assert_eq!( signed, quat::to_rotation::<f32, [f32; 3]>(quat) );
+        assert_eq!( signed[1], unsigned[1] );
+        for axis in [0, 2] {
+            assert!( -PI < signed[axis] && signed[axis] <= PI, "{signed:?}" );
+            assert!( (0.0..TAU).contains(&unsigned[axis]), "{unsigned:?}" );
+            let difference: f32 = (unsigned[axis] - signed[axis]).rem_euclid(TAU);
+            assert!( difference < 0.0001 || TAU - difference < 0.0001 );
+        }
+        assert_same_rotation!(f32; quat::from_rotation::<f32, [f32; 4]>(unsigned), quat, 0.0001);
+    }
+}
+
+#[test]
+fn rem_methods_and_operator() {
+    let array: [f32; 4] = [7.0, -5.0, 2.5, 9.0];
+    let tuple: Q<f32> = (7.0, [-5.0, 2.5, 9.0]);
+    let modulus: [f32; 4] = [1.0, 2.0, -1.0, 3.0];
+
+    let expected: [f32; 4] = quat::rem::<f32, [f32; 4]>(array, modulus);
+    assert_eq!( QuaternionMethods::<f32>::rem(array, modulus), expected );
+    assert!( quat::eq::<f32>(QuaternionMethods::<f32>::rem(tuple, modulus), expected) );
+    assert_eq!( QuaternionMethods::<f32>::rem_checked(array, modulus), Some(expected) );
+    assert_eq!( QuaternionMethods::<f32>::rem_checked(tuple, [0.0_f32; 4]), None );
+    assert_eq!( QuaternionMethods::<f32>::rem_checked(array, (f32::NAN, [0.0, 0.0, 0.0])), None );
+
+    let (quotient, remainder) = QuaternionMethods::<f32>::div_rem(array, modulus);
+    assert_eq!( remainder, expected );
+    assert_eq!( quat::add::<f32, [f32; 4]>(quat::mul::<f32, [f32; 4]>(modulus, quotient), remainder), array );
+    let (quotient, remainder) = QuaternionMethods::<f32>::div_rem(tuple, modulus);
+    assert!( quat::eq::<f32>(quat::add::<f32, Q<f32>>(quat::mul::<f32, Q<f32>>(modulus, quotient), remainder), tuple) );
+
+    // the quotient is truncated
+    for component in [quotient.0, quotient.1[0], quotient.1[1], quotient.1[2]] {
+        assert_eq!( component % 1.0, 0.0 );
+    }
+
+    // a real modulus works on each component on it's own
+    let real: [f32; 4] = [2.0, 0.0, 0.0, 0.0];
+    assert_eq!( QuaternionMethods::<f32>::rem(array, real), [7.0 % 2.0, -5.0 % 2.0, 2.5 % 2.0, 9.0 % 2.0] );
+
+    #[cfg(feature = "math_fns")]
+    for modulus in [[1.0, 2.0, -1.0, 3.0], [0.5, 0.5, 0.5, 0.5], [3.0, 0.0, -4.0, 0.0]] {
+        let euclid: [f32; 4] = QuaternionMethods::<f32>::rem_euclid(array, modulus);
+        assert!( quat::abs_squared::<f32, f32>(euclid) <= quat::abs_squared::<f32, f32>(modulus) / 2.0 + 0.0001 );
+        let tuple_euclid: Q<f32> = QuaternionMethods::<f32>::rem_euclid(tuple, modulus);
+        assert!( quat::eq::<f32>(tuple_euclid, euclid) );
+    }
+
+    // the operator on `Quat` is the same as the function
+    let mut quat: structs::Quat<f32, [f32; 4]> = structs::Quat::new(array);
+    assert_eq!( (quat % modulus).quat, expected );
+    assert_eq!( (&quat % modulus).quat, expected );
+    assert_eq!( QuaternionMethods::<f32>::rem(quat, modulus).quat, expected );
+    quat %= modulus;
+    assert_eq!( quat.quat, expected );
+}
+
+#[test]
+#[cfg(feature = "rotation")]
+fn to_axis_angle_wrapped_across_pi() {
+    use core::f32::consts::{FRAC_PI_2, PI, TAU};
+    use quat::AngleWrap;
+
+    let axis: [f32; 3] = [0.6, 0.0, 0.8];
+
+    // only one of the vector's components is non zero
+    let (single, angle): ([f32; 3], f32) = quat::to_axis_angle::<f32, [f32; 3], f32>([1.0, 1.0, 0.0, 0.0]);
+    assert_eq!( single, [1.0, 0.0, 0.0] );
+    assert!( (angle - FRAC_PI_2).abs() < 0.0001 );
+
+    let mut previous: f32 = 0.0;
+    for step in 1..62 {
+        let turned: f32 = step as f32 * 0.1;
+        // not normalized, the angle doesn't depend on the length
+        let quat: [f32; 4] = quat::scale::<f32, [f32; 4]>(quat::from_axis_angle::<f32, [f32; 4]>(axis, turned), 3.0);
+
+        let (signed_axis, signed): ([f32; 3], f32) = quat::to_axis_angle_wrapped::<f32, [f32; 3], f32>(quat, AngleWrap::SignedPi);
+        let (_, unsigned): ([f32; 3], f32) = quat::to_axis_angle_wrapped::<f32, [f32; 3], f32>(quat, AngleWrap::ZeroTau);
+        let (_, unwrapped): ([f32; 3], f32) = quat::to_axis_angle_wrapped::<f32, [f32; 3], f32>(quat, AngleWrap::Unwrapped(previous));
+
+        assert!( quat::is_near_by::<f32>((0.0, signed_axis), (0.0, axis), 0.0001), "{signed_axis:?}" );
+        assert_eq!( (signed_axis, signed), quat::to_axis_angle::<f32, [f32; 3], f32>(quat) );
+        assert!( -PI < signed && signed <= PI, "{signed}" );
+        assert!( (0.0..TAU).contains(&unsigned), "{unsigned}" );
+        assert!( (unsigned - turned).abs() < 0.0001, "{turned}: {unsigned}" );
+
+        // keeps going past `π` instead of jumping to `-π`
+        assert!( unwrapped > previous, "{turned}: {unwrapped} after {previous}" );
+        assert!( (unwrapped - turned).abs() < 0.0001, "{turned}: {unwrapped}" );
+        previous = unwrapped;
+    }
+
+    // a whole turn later
+    let quat: [f32; 4] = quat::from_axis_angle::<f32, [f32; 4]>(axis, 1.0);
+    let (_, unwrapped): ([f32; 3], f32) = quat::to_axis_angle_wrapped::<f32, [f32; 3], f32>(quat, AngleWrap::Unwrapped(TAU + 0.5));
+    assert!( (unwrapped - (TAU + 1.0)).abs() < 0.0001 );
+    let (_, unwrapped): ([f32; 3], f32) = quat::to_axis_angle_wrapped::<f32, [f32; 3], f32>(quat, AngleWrap::Unwrapped(-TAU));
+    assert!( (unwrapped - (1.0 - TAU)).abs() < 0.0001 );
+}
+
+#[test]
+fn duration_as_scalar() {
+    use core::time::Duration;
+    use traits::{Scalar, ScalarConstructor, ScalarConsts};
+
+    // nanoseconds survive through `f64` while they fit in its 53 bits
+    let short: Duration = Duration::new(1, 234_567_891);
+    assert_eq!( <Duration as Scalar<f64>>::scalar(&short), 1.234567891 );
+    assert_eq!( <Duration as ScalarConstructor<f64>>::new_scalar(1.234567891), short );
+    let nano: Duration = Duration::from_nanos(1);
+    assert_eq!( <Duration as ScalarConstructor<f64>>::new_scalar(Scalar::<f64>::scalar(&nano)), nano );
+    assert_eq!( <Duration as ScalarConstructor<f32>>::new_scalar(Scalar::<f32>::scalar(&nano)), nano );
+
+    // multiple years only keep about a microsecond through `f64` and minutes through `f32`
+    let years: Duration = Duration::new(5 * 365 * 24 * 60 * 60, 123_456_789);
+    let back: Duration = <Duration as ScalarConstructor<f64>>::new_scalar(Scalar::<f64>::scalar(&years));
+    assert!( back.abs_diff(years) < Duration::from_micros(1), "{back:?}" );
+    let back: Duration = <Duration as ScalarConstructor<f32>>::new_scalar(Scalar::<f32>::scalar(&years));
+    assert!( back.abs_diff(years) < Duration::from_secs(10), "{back:?}" );
+
+    // saturating
+    assert_eq!( <Duration as ScalarConstructor<f64>>::new_scalar(-1.0), Duration::ZERO );
+    assert_eq!( <Duration as ScalarConstructor<f32>>::new_scalar(-0.0), Duration::ZERO );
+    assert_eq!( <Duration as ScalarConstructor<f64>>::new_scalar(f64::NAN), Duration::ZERO );
+    assert_eq!( <Duration as ScalarConstructor<f64>>::new_scalar(f64::INFINITY), Duration::MAX );
+    assert_eq!( <Duration as ScalarConstructor<f32>>::new_scalar(1e30), Duration::MAX );
+    // there is no NaN duration, the NaN constant is the one NaN seconds give
+    assert_eq!( <Duration as ScalarConsts<f64>>::NAN, Duration::ZERO );
+    assert_eq!( Scalar::<f32>::scalar(&<Duration as ScalarConsts<f32>>::NAN), 0.0 );
+
+    // usable straight in the quaternion functions
+    let angular_velocity: [f64; 4] = [0.0, 0.5, -1.0, 2.0];
+    let step: [f64; 4] = quat::scale::<f64, [f64; 4]>(angular_velocity, Duration::from_millis(250));
+    assert_eq!( step, [0.0, 0.125, -0.25, 0.5] );
+    let step: [f32; 4] = quat::scale::<f32, [f32; 4]>([0.0, 0.5, -1.0, 2.0], &Duration::from_millis(250));
+    assert_eq!( step, [0.0, 0.125, -0.25, 0.5] );
+
+    #[cfg(feature = "std")]
+    {
+        use structs::Std;
+        assert_eq!( Scalar::<Std<f64>>::scalar(&short), Std(1.234567891) );
+        assert_eq!( <Duration as ScalarConstructor<Std<f32>>>::new_scalar(Std(-2.0)), Duration::ZERO );
+        assert_eq!( <Duration as ScalarConstructor<Std<f64>>>::new_scalar(Std(0.25)), Duration::from_millis(250) );
+    }
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "test_helpers"))]
+fn radians_and_degrees() {
+    use core::f32::consts::{PI, FRAC_PI_2};
+    use structs::{Radians, Degrees};
+
+    let same = |left: [f32; 4], right: [f32; 4]| quat::is_near_by::<f32>(left, right, 0.00001);
+
+    // conversions and arithmetic
+    assert_eq!( Radians::from(Degrees(180.0_f32)), Radians(PI) );
+    assert_eq!( Degrees::from(Radians(FRAC_PI_2)), Degrees(90.0_f32) );
+    assert_eq!( Degrees(90.0_f32) + Degrees(90.0), Degrees(180.0) );
+    assert_eq!( Radians(PI) - Radians(FRAC_PI_2), Radians(FRAC_PI_2) );
+    assert_eq!( -Degrees(45.0_f32) * 2.0, Degrees(-90.0) );
+    assert_eq!( Degrees(270.0_f32) / Degrees(90.0), 3.0 );
+    assert_eq!( traits::Scalar::<f32>::scalar(&Degrees(180.0_f32)), PI );
+    assert_eq!( <Degrees<f32> as traits::ScalarConstructor<f32>>::new_scalar(PI), Degrees(180.0) );
+
+    // 180º is π radians in every function that takes an angle
+    let axis: [f32; 3] = [0.0, 0.6, 0.8];
+    let quat: [f32; 4] = [0.5, 0.5, -0.5, 0.5];
+    let expected: [f32; 4] = quat::from_axis_angle::<f32, [f32; 4]>(axis, PI);
+    for angle in [Degrees(180.0), Degrees::from(Radians(PI))] {
+        assert!( same(quat::from_axis_angle::<f32, [f32; 4]>(axis, angle), expected) );
+        assert!( same(quat::from_axis_angle::<f32, [f32; 4]>(axis, &angle), expected) );
+        assert!( same(quat::from_axis_angle_typed::<f32, [f32; 4]>(axis, angle), expected) );
+        assert!( same(quat::from_axis_angle_checked::<f32, [f32; 4]>(axis, angle).unwrap(), expected) );
+        assert!( same(quat::from_axis_angle_unchecked::<f32, [f32; 4]>(axis, angle), expected) );
+        assert!( same(quat::rotate_x::<f32, [f32; 4]>(quat, angle), quat::rotate_x::<f32, [f32; 4]>(quat, PI)) );
+        assert!( same(quat::rotate_y::<f32, [f32; 4]>(quat, angle), quat::rotate_y::<f32, [f32; 4]>(quat, PI)) );
+        assert!( same(quat::rotate_z::<f32, [f32; 4]>(quat, angle), quat::rotate_z::<f32, [f32; 4]>(quat, PI)) );
+        assert!( same(
+            quat::from_polar_form::<f32, [f32; 4]>(2.0, angle, axis).unwrap(),
+            quat::from_polar_form::<f32, [f32; 4]>(2.0, PI, axis).unwrap(),
+        ) );
+    }
+    assert!( same(quat::from_axis_angle_typed::<f32, [f32; 4]>(axis, Radians(PI)), expected) );
+}
+
+#[test]
+#[ignore]
+#[cfg(all(feature = "std", feature = "rotation", feature = "test_helpers"))]
+fn timing_rotation_strategies() {
+    extern crate alloc;
+    use alloc::vec::Vec;
+    use quat::rotation_strategies::{rotate_via_sandwich, rotate_via_matrix, rotation_matrix};
+
+    let quats: Vec<[f32; 4]> = f32_quats()
+        .step_by(4099)
+        .map(|quat| quat::normalize::<f32, [f32; 4]>(quat))
+        .collect();
+    let vectors: Vec<[f32; 3]> = f32_quats()
+        .step_by(1009)
+        .take(1000)
+        .map(|[_, x, y, z]| [x, y, z])
+        .collect();
+
+    // each quaternion rotates the first `count` vectors
+    for count in [1, 2, 3, 4, 8, 1000] {
+        let sandwich_average = timer! {
+            run {
+                for &quat in core::hint::black_box(&quats) {
+                    for &vector in &vectors[..count] {
+                        core::hint::black_box(rotate_via_sandwich::<f32, [f32; 3]>(quat, vector));
+                    }
+                }
+            },
+            silent, repeat 100,
+        };
+
+        let matrix_average = timer! {
+            run {
+                for &quat in core::hint::black_box(&quats) {
+                    let matrix: [[f32; 3]; 3] = rotation_matrix::<f32>(quat);
+                    for &vector in &vectors[..count] {
+                        core::hint::black_box(rotate_via_matrix::<f32, [f32; 3]>(&matrix, vector));
+                    }
+                }
+            },
+            silent, repeat 100,
+        };
+
+        println!("{count} vectors: matrix / sandwich = {}", matrix_average.as_secs_f64() / sandwich_average.as_secs_f64());
+        if count >= quat::rotation_strategies::MATRIX_CROSSOVER {
+            assert!( matrix_average < sandwich_average, "{count}" );
+        }
+    }
+
+    println!("
+    release (x86_64), matrix time / sandwich time:
+        1 vector     ~`2.4`
+        2 vectors    ~`1.2`
+        3 vectors    ~`1.0`
+        4 vectors    ~`0.32`
+        8 vectors    ~`0.36`
+        1000 vectors ~`0.45`
+    ");
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "test_helpers"))]
+fn rotation_strategies_agree() {
+    use quat::rotation_strategies::{rotate_via_sandwich, rotate_via_matrix, rotation_matrix, rotate_many, MATRIX_CROSSOVER};
+
+    let quats: [[f32; 4]; 5] = [
+        [1.0, 0.0, 0.0, 0.0],
+        quat::from_axis_angle::<f32, [f32; 4]>([0.0, 0.0, 1.0], core::f32::consts::FRAC_PI_2),
+        quat::from_axis_angle::<f32, [f32; 4]>([1.0, 2.0, -0.5], 1.2),
+        quat::from_axis_angle::<f32, [f32; 4]>([-3.0, 0.1, 0.7], 2.9),
+        quat::normalize::<f32, [f32; 4]>([0.5, 0.5, -0.5, 0.5]),
+    ];
+
+    for quat in quats {
+        let matrix: [[f32; 3]; 3] = rotation_matrix::<f32>(quat);
+        for x in F32S { for y in F32S { for z in F32S {
+            let vector: [f32; 3] = [x, y, z];
+            let via_sandwich: [f32; 3] = rotate_via_sandwich::<f32, [f32; 3]>(quat, vector);
+            let via_matrix: [f32; 3] = rotate_via_matrix::<f32, [f32; 3]>(&matrix, vector);
+            // within a few ULPs of the length of the vector (the worst one is ~`3.8`)
+            let ulp: f32 = f32::EPSILON * (x * x + y * y + z * z).sqrt();
+            for n in 0..3 {
+                assert!( (via_sandwich[n] - via_matrix[n]).abs() <= 5.0 * ulp, "{quat:?} {vector:?}: {via_sandwich:?} {via_matrix:?}" );
+            }
+        } } }
+    }
+
+    // `rotate_many` picks the strategy by the size hint
+    let quat: [f32; 4] = [2.0, 1.0, -1.0, 0.5];
+    let unit: [f32; 4] = quat::normalize::<f32, [f32; 4]>(quat);
+    let vectors: [[f32; 3]; 6] = [F32S[3], F32S[8], F32S[19], F32S[24], F32S[40], F32S[50]].map(|n| [n, -2.0 * n, 1.0]);
+    for count in 0..=vectors.len() {
+        let rotated: Vec<[f32; 3]> = rotate_many::<f32, [f32; 3]>(quat, &vectors[..count]).collect();
+        assert_eq!( rotated.len(), count );
+        for (rotated, vector) in rotated.into_iter().zip(vectors) {
+            if count < MATRIX_CROSSOVER {
+                assert_eq!( rotated, rotate_via_sandwich::<f32, [f32; 3]>(unit, vector) );
+            } else {
+                assert_eq!( rotated, rotate_via_matrix::<f32, [f32; 3]>(&rotation_matrix::<f32>(unit), vector) );
+            }
+        }
+    }
+    // no size hint, so the sandwich
+    let filtered: Vec<[f32; 3]> = rotate_many::<f32, [f32; 3]>(quat, vectors.iter().filter(|_| true)).collect();
+    assert_eq!( filtered, vectors.map(|vector| rotate_via_sandwich::<f32, [f32; 3]>(unit, vector)) );
+}
+
+/// The rows of the 3x3 matrix of `quat`, the basis vectors it rotates to.
+#[cfg(all(feature = "rotation", feature = "matrix"))]
+fn rotated_basis(quat: [f64; 4]) -> [[f64; 3]; 3] {
+    [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]].map(|basis: [f64; 3]| quat::point_rotation::<f64, [f64; 3]>(quat, basis))
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "matrix"))]
+fn from_matrix_3_every_branch() {
+    // the real part, `i`, `j` and `k` are each the largest one once
+    for (axis, angle) in [([1.0, 2.0, 3.0], 0.5), ([1.0, 0.2, -0.3], 3.0), ([0.1, -1.0, 0.4], 3.0), ([0.3, 0.2, 1.0], -3.0)] {
+        let quat: [f64; 4] = quat::from_axis_angle::<f64, [f64; 4]>(axis, angle);
+        let back: [f64; 4] = quat::from_matrix_3::<f64, f64, [f64; 4]>(rotated_basis(quat));
+        assert!( (quat::dot::<f64, f64>(back, quat).abs() - 1.0).abs() < 1e-12, "{back:?} isn't ±{quat:?}" );
+    }
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "matrix"))]
+fn to_matrix_3_rotates_the_basis() {
+    for (axis, angle) in [([1.0, 2.0, 3.0], 0.5), ([1.0, 0.2, -0.3], 3.0), ([0.0, 1.0, 0.0], 1.0)] {
+        let quat: [f64; 4] = quat::from_axis_angle::<f64, [f64; 4]>(axis, angle);
+        let matrix: [[f64; 3]; 3] = quat::to_matrix_3::<f64, f64, [[f64; 3]; 3]>(quat);
+        let expected: [[f64; 3]; 3] = rotated_basis(quat);
+        for (row, expected) in matrix.into_iter().zip(expected) {
+            for (got, expected) in row.into_iter().zip(expected) {
+                assert!( (got - expected).abs() < 1e-12, "{matrix:?} != {:?}", rotated_basis(quat) );
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "matrix", feature = "test_helpers"))]
+fn euler_matrix_passthroughs() {
+    let rotations: [[f32; 3]; 6] = [
+        [0.0, 0.0, 0.0],
+        [0.3, -0.8, 2.0],
+        [-3.0, 1.2, -0.1],
+        [core::f32::consts::PI, 0.0, core::f32::consts::FRAC_PI_2],
+        [0.7, core::f32::consts::FRAC_PI_2, -0.4],
+        [F32S[4], -F32S[10], F32S[13]],
+    ];
+    for rotation in rotations {
+        let matrix: [[f32; 3]; 3] = quat::rotation_to_matrix_3::<f32, f32, [[f32; 3]; 3]>(rotation);
+        assert_eq!( matrix, quat::to_matrix_3::<f32, f32, [[f32; 3]; 3]>(quat::from_rotation::<f32, [f32; 4]>(rotation)) );
+        // other element and output types too
+        let matrix_f64: [[f64; 3]; 3] = quat::rotation_to_matrix_3::<f32, f64, [[f64; 3]; 3]>(rotation);
+        assert_eq!( matrix_f64, matrix.map(|row| row.map(f64::from)) );
+
+        let back: [f32; 3] = quat::matrix_3_to_rotation::<f32, f32, [f32; 3]>(matrix);
+        assert_eq!( back, quat::to_rotation::<f32, [f32; 3]>(quat::from_matrix_3::<f32, f32, [f32; 4]>(matrix)) );
+        assert_same_rotation!(f32; quat::from_rotation::<f32, [f32; 4]>(back), quat::from_rotation::<f32, [f32; 4]>(rotation), 0.001);
+    }
+}
+
+#[test]
+#[cfg(feature = "rotation")]
+fn integrate_gyro_constant_rate() {
+    let start: [f64; 4] = quat::from_axis_angle::<f64, [f64; 4]>([0.3, -1.0, 0.2], 0.9);
+    let rate: [f64; 3] = [0.4, 1.5, -0.7];
+    let speed: f64 = (rate[0] * rate[0] + rate[1] * rate[1] + rate[2] * rate[2]).sqrt();
+    let steps: u32 = 10_000;
+    let dt: f64 = 0.001;
+
+    let (mut body, mut world): ([f64; 4], [f64; 4]) = (start, start);
+    let (mut body_f32, mut world_f32): ([f32; 4], [f32; 4]) = (start.map(|n| n as f32), start.map(|n| n as f32));
+    for _ in 0..steps {
+        body = quat::integrate_gyro::<f64, [f64; 4]>(body, rate, dt);
+        world = quat::integrate_gyro_world::<f64, [f64; 4]>(world, rate, dt);
+        body_f32 = quat::integrate_gyro::<f32, [f32; 4]>(body_f32, rate.map(|n| n as f32), dt as f32);
+        world_f32 = quat::integrate_gyro_world::<f32, [f32; 4]>(world_f32, rate.map(|n| n as f32), core::time::Duration::from_millis(1));
+    }
+
+    let turn: [f64; 4] = quat::from_axis_angle::<f64, [f64; 4]>(rate, speed * dt * steps as f64);
+    let expected_body: [f64; 4] = quat::mul::<f64, [f64; 4]>(start, turn);
+    let expected_world: [f64; 4] = quat::mul::<f64, [f64; 4]>(turn, start);
+
+    // the documented bound, about `steps` times epsilon
+    let bound_f64: f64 = steps as f64 * f64::EPSILON;
+    let bound_f32: f32 = steps as f32 * f32::EPSILON;
+    assert!( quat::dist_euclid::<f64, f64>(body, expected_body) < bound_f64 );
+    assert!( quat::dist_euclid::<f64, f64>(world, expected_world) < bound_f64 );
+    assert!( quat::dist_euclid::<f32, f32>(body_f32, expected_body.map(|n| n as f32)) < bound_f32 );
+    assert!( quat::dist_euclid::<f32, f32>(world_f32, expected_world.map(|n| n as f32)) < bound_f32 );
+    // and the two frames really are different
+    assert!( quat::dist_euclid::<f64, f64>(body, world) > 0.1 );
+
+    // not rotating gives back the input as is, even if it's not normalized
+    let not_unit: [f32; 4] = [2.0, 0.0, 1.0, 0.0];
+    assert_eq!( quat::integrate_gyro::<f32, [f32; 4]>(not_unit, [0.0; 3], 0.01), not_unit );
+    assert_eq!( quat::integrate_gyro::<f32, [f32; 4]>(not_unit, [1.0, 2.0, 3.0], 0.0), not_unit );
+    assert_eq!( quat::integrate_gyro_world::<f32, [f32; 4]>(not_unit, [0.0; 3], core::time::Duration::ZERO), not_unit );
+    // rotating does normalize it
+    let rotated: [f32; 4] = quat::integrate_gyro::<f32, [f32; 4]>(not_unit, [1.0, 0.0, 0.0], 0.01);
+    assert!( (quat::abs::<f32, f32>(rotated) - 1.0).abs() < 0.000001 );
+}
+
+#[test]
+#[cfg(feature = "test_helpers")]
+fn normalize_unchecked_parity() {
+    for quat in f32_quats().step_by(101) {
+        if quat == [0.0; 4] {
+            continue;
+        }
+        let checked: [f32; 4] = quat::normalize::<f32, [f32; 4]>(quat);
+        let unchecked: [f32; 4] = quat::normalize_unchecked::<f32, [f32; 4]>(quat);
+        assert_eq!( checked, unchecked, "{quat:?}" );
+    }
+
+    // the origin doesn't panic, it's just NaN
+    assert_eq!( quat::normalize::<f32, [f32; 4]>([0.0; 4]), [0.0; 4] );
+    assert!( quat::is_nan::<f32>(quat::normalize_unchecked::<f32, [f32; 4]>([0.0_f32; 4])) );
+    assert!( quat::is_nan::<f64>(quat::normalize_unchecked::<f64, (f64, [f64; 3])>(())) );
+    #[cfg(feature = "rotation")]
+    {
+        assert!( quat::to_rotation::<f32, [f32; 3]>([0.0_f32; 4]).iter().all(|angle| angle.is_nan()) );
+        assert!( quat::to_roll::<f32, f32>([0.0_f32; 4]).is_nan() );
+    }
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "test_helpers"))]
+fn roll_pitch_yaw_match_to_rotation() {
+    use core::f32::consts::{FRAC_PI_2, PI};
+    use structs::UnitQuat;
+
+    let angles: [f32; 7] = [-PI, -2.0, -0.5, 0.0, 0.3, 1.7, PI];
+    let pitches: [f32; 9] = [-FRAC_PI_2, -FRAC_PI_2 + 0.001, -FRAC_PI_2 + 0.02, -0.4, 0.0, 0.9, FRAC_PI_2 - 0.02, FRAC_PI_2 - 0.001, FRAC_PI_2];
+
+    let rotations = angles.into_iter().flat_map(|roll| pitches.into_iter().flat_map(move |pitch| {
+        angles.into_iter().map(move |yaw| quat::from_rotation::<f32, [f32; 4]>([roll, pitch, yaw]))
+    }));
+    // not normalized ones too
+    let quats = rotations.chain(f32_quats().step_by(977).filter(|quat| *quat != [0.0; 4]));
+
+    let mut locked: usize = 0;
+    for quat in quats {
+        let rotation: [f32; 3] = quat::to_rotation::<f32, [f32; 3]>(quat);
+        let (roll, pitch, yaw) = quat::to_roll_pitch_yaw::<f32>(quat);
+        assert_eq!( [roll, pitch, yaw], rotation, "{quat:?}" );
+        assert_eq!( quat::to_roll::<f32, f32>(quat), rotation[0], "{quat:?}" );
+        assert_eq!( quat::to_pitch::<f32, f32>(quat), rotation[1], "{quat:?}" );
+        assert_eq!( quat::to_yaw::<f32, f32>(quat), rotation[2], "{quat:?}" );
+        if rotation[1].abs() == FRAC_PI_2 {
+            locked += 1;
+        }
+
+        // the unit ones skip the normalization, so they match `to_rotation` of the same unit quaternion
+        let unit: UnitQuat<f32> = UnitQuat::new_normalized(quat[0], quat[1], quat[2], quat[3]);
+        let unit_rotation: [f32; 3] = quat::to_rotation::<f32, [f32; 3]>(unit);
+        let (roll, pitch, yaw) = quat::to_roll_pitch_yaw_unit::<f32>(unit);
+        let unit_angles: [f32; 3] = [quat::to_roll_unit::<f32, f32>(unit), quat::to_pitch_unit::<f32, f32>(unit), quat::to_yaw_unit::<f32, f32>(unit)];
+        assert_eq!( [roll, pitch, yaw], unit_angles );
+        for n in 0..3 {
+            // `±π` can come out as either one
+            let difference: f32 = (unit_angles[n] - unit_rotation[n]).abs();
+            assert!( difference < 0.0001 || (difference - 2.0 * PI).abs() < 0.0001, "{quat:?}: {unit_angles:?} {unit_rotation:?}" );
+        }
+    }
+    // the poles were actually hit
+    assert!( locked >= 2 * angles.len() * angles.len() );
+}
+
+#[test]
+fn features_match_the_build() {
+    use quaternion_traits::features;
+
+    assert_eq!( features::STD, cfg!(feature = "std") );
+    assert_eq!( features::DETERMINISTIC, cfg!(feature = "deterministic") );
+    assert_eq!( features::ALLOC, cfg!(feature = "alloc") );
+    assert_eq!( features::QOL_FNS, cfg!(feature = "qol_fns") );
+    assert_eq!( features::MATH_FNS, cfg!(feature = "math_fns") );
+    assert_eq!( features::TRIGONOMETRY, cfg!(feature = "trigonometry") );
+    assert_eq!( features::ROTATION, cfg!(feature = "rotation") );
+    assert_eq!( features::MATRIX, cfg!(feature = "matrix") );
+    assert_eq!( features::DISPLAY, cfg!(feature = "display") );
+    assert_eq!( features::UNSTABLE, cfg!(feature = "unstable") );
+
+    assert_eq!( features::FEATURES.contains(&"test_helpers"), cfg!(feature = "test_helpers") );
+    assert_eq!( features::FEATURES.contains(&"test_harness"), cfg!(feature = "test_harness") );
+    assert!( !features::FEATURES.contains(&"full") );
+
+    for (name, enabled) in [
+        ("std", features::STD), ("alloc", features::ALLOC), ("qol_fns", features::QOL_FNS),
+        ("math_fns", features::MATH_FNS), ("trigonometry", features::TRIGONOMETRY),
+        ("rotation", features::ROTATION), ("matrix", features::MATRIX),
+        ("display", features::DISPLAY), ("unstable", features::UNSTABLE),
+    ] {
+        assert_eq!( features::FEATURES.contains(&name), enabled, "{name}" );
+    }
+    assert_eq!( features::summary(), features::FEATURES.join(", ") );
+}
+
+#[test]
+#[cfg(feature = "test_helpers")]
+fn quat_struct_comparisons_and_borrows() {
+    use core::borrow::{Borrow, BorrowMut};
+    use core::cmp::Ordering;
+    use structs::Quat;
+
+    let mut q: Quat<f32, [f32; 4]> = Quat::new([1.0, 2.0, 3.0, 4.0]);
+
+    // both directions
+    assert!( q == [1.0, 2.0, 3.0, 4.0] );
+    assert!( [1.0_f32, 2.0, 3.0, 4.0] == q );
+    assert!( (1.0_f32, [2.0_f32, 3.0, 4.0]) == q );
+    assert!( (1.0_f32, 2.0_f32, 3.0_f32, 4.0_f32) == q );
+    assert!( [1.0_f32, 2.0, 3.0, 5.0] != q );
+    assert!( q != [1.0, 2.0, 3.0, 5.0] );
+
+    // borrows
+    let as_ref: &[f32; 4] = q.as_ref();
+    assert_eq!( as_ref, &[1.0, 2.0, 3.0, 4.0] );
+    let borrowed: &[f32; 4] = q.borrow();
+    assert_eq!( borrowed, &[1.0, 2.0, 3.0, 4.0] );
+    let as_mut: &mut [f32; 4] = q.as_mut();
+    as_mut[0] = -1.0;
+    let borrowed_mut: &mut [f32; 4] = q.borrow_mut();
+    borrowed_mut[3] = -4.0;
+    assert!( q == [-1.0, 2.0, 3.0, -4.0] );
+
+    // real first, then i, j and k
+    assert_eq!( Quat::<f32, [f32; 4]>::new([0.0, 9.0, 9.0, 9.0]).partial_cmp(&[1.0, 0.0, 0.0, 0.0]), Some(Ordering::Less) );
+    assert_eq!( Quat::<f32, [f32; 4]>::new([1.0, 0.0, 0.0, 2.0]).partial_cmp(&[1.0, 0.0, 0.0, 1.0]), Some(Ordering::Greater) );
+    assert_eq!( Quat::<f32, [f32; 4]>::new([1.0, 2.0, 3.0, 4.0]).partial_cmp(&(1.0, [2.0, 3.0, 4.0])), Some(Ordering::Equal) );
+    assert_eq!( Quat::<f32, [f32; 4]>::new([1.0, f32::NAN, 0.0, 0.0]).partial_cmp(&[1.0, 0.0, 0.0, 0.0]), None );
+
+    // every 1009th one, sorting all of them takes too long
+    let mut sorted: Vec<Quat<f32, [f32; 4]>> = f32_quats().step_by(1009)
+        .filter(|q: &[f32; 4]| !q.iter().any(|n: &f32| n.is_nan()))
+        .map(Quat::new)
+        .collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    for pair in sorted.windows(2) {
+        let (a, b): ([f32; 4], [f32; 4]) = (pair[0].get(), pair[1].get());
+        assert!( a.partial_cmp(&b) != Some(Ordering::Greater), "{a:?} <= {b:?}" );
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn quat_struct_btree_map() {
+    extern crate alloc;
+    use alloc::collections::BTreeMap;
+    use alloc::vec::Vec;
+    use core::cmp::Ordering;
+    use structs::Quat;
+
+    // `Quat` of floats isn't `Ord` (NaN isn't equal to itself),
+    // a key without NaNs can take its order from `PartialOrd`
+    #[derive(Debug, PartialEq)]
+    struct Key(Quat<f32, [f32; 4]>);
+    impl Eq for Key {}
+    impl PartialOrd for Key {
+        fn partial_cmp(&self, other: &Key) -> Option<Ordering> { Some(self.cmp(other)) }
+    }
+    impl Ord for Key {
+        fn cmp(&self, other: &Key) -> Ordering { self.0.partial_cmp(&other.0).unwrap() }
+    }
+
+    let key = |r: f32, i: f32, j: f32, k: f32| Key(Quat::new([r, i, j, k]));
+
+    let mut map: BTreeMap<Key, &str> = BTreeMap::new();
+    map.insert(key(1.0, 0.0, 0.0, 0.0), "identity");
+    map.insert(key(0.0, 0.0, 0.0, 1.0), "k");
+    map.insert(key(0.0, 1.0, 0.0, 0.0), "i");
+    map.insert(key(0.0, 0.0, 1.0, 0.0), "j");
+    assert_eq!( map.insert(key(1.0, 0.0, 0.0, 0.0), "one"), Some("identity") );
+
+    // real first, then i, j and k
+    assert_eq!( map.values().copied().collect::<Vec<&str>>(), ["k", "j", "i", "one"] );
+    assert_eq!( map.get(&key(0.0, 0.0, 1.0, 0.0)), Some(&"j") );
+    assert_eq!( map.get(&key(0.0, 0.0, 0.0, -1.0)), None );
+    let keys: Vec<&Key> = map.keys().collect();
+    for pair in keys.windows(2) {
+        assert_eq!( pair[0].0.partial_cmp(&pair[1].0), Some(Ordering::Less) );
+    }
+    assert_eq!( Quat::<f32, [f32; 4]>::new([f32::NAN, 0.0, 0.0, 0.0]).partial_cmp(&[0.0_f32; 4]), None );
+}
+
+#[test]
+#[cfg(all(feature = "math_fns", feature = "test_helpers"))]
+fn roots_of_neg_one_on_the_sphere() {
+    for polar in F32S.iter().copied().filter(|n: &f32| n.abs() < 100.0) {
+        for azimuth in [0.0, 0.3, -1.2, 2.5, core::f32::consts::PI] {
+            let root: [f32; 4] = quat::unit_vector_from_angles::<f32, [f32; 4]>(polar, azimuth);
+            assert!( quat::is_root_of_neg_one::<f32>(root), "{root:?} from {polar} {azimuth}" );
+            assert!( quat::is_near::<f32>(quat::square::<f32, [f32; 4]>(root), [-1.0, 0.0, 0.0, 0.0]), "{root:?}² = -1" );
+        }
+    }
+    assert!( !quat::is_root_of_neg_one::<f32>([0.0, 0.0, 0.0, 0.0]) );
+    assert!( !quat::is_root_of_neg_one::<f32>([0.1, 1.0, 0.0, 0.0]) );
+
+    // the closest two of `n` points are about `3.09 / sqrt(n)` apart
+    for total in [10, 100, 400] {
+        let points: Vec<[f64; 3]> = (0..total).map(|index| quat::fibonacci_sphere_point::<f64>(index, total)).collect();
+        let mut closest: f64 = f64::INFINITY;
+        for (n, a) in points.iter().enumerate() {
+            assert!( quat::is_root_of_neg_one::<f64>((0.0, *a)) );
+            assert!( quat::is_near::<f64>(quat::square::<f64, [f64; 4]>((0.0, *a)), [-1.0, 0.0, 0.0, 0.0]) );
+            for b in &points[n + 1..] {
+                closest = closest.min(quat::dist_euclid::<f64, f64>((0.0, *a), (0.0, *b)));
+            }
+        }
+        let scaled: f64 = closest * (total as f64).sqrt();
+        assert!( (3.0..3.2).contains(&scaled), "{total} points, closest {closest}" );
+    }
+    assert!( quat::fibonacci_sphere_point::<f32>(5, 5).iter().any(|n: &f32| n.is_nan()) );
+    assert!( quat::fibonacci_sphere_point::<f32>(0, 0).iter().any(|n: &f32| n.is_nan()) );
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "matrix", feature = "test_helpers"))]
+fn frame_orthogonality_and_columns() {
+    let basis: [[f64; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    for q in f32_quats().step_by(997).filter(|q: &[f32; 4]| !quat::is_nan::<f32>(q) && !quat::eq::<f32>(q, ())) {
+        let unit: [f64; 4] = quat::normalize::<f64, [f64; 4]>(q.map(f64::from));
+        let error: f64 = quat::frame_orthogonality_error::<f64, f64>(unit);
+        assert!( error < <f64 as traits::Axis>::ERROR, "{error} for {unit:?}" );
+
+        // `s² - 1` of de-normalization gives an error proportional to it
+        // (the factor depends on the rotation, but not on how far it's scaled)
+        let small: f64 = quat::frame_orthogonality_error::<f64, f64>(quat::scale::<f64, [f64; 4]>(unit, (1.0_f64 + 1e-4).sqrt()));
+        let big: f64 = quat::frame_orthogonality_error::<f64, f64>(quat::scale::<f64, [f64; 4]>(unit, (1.0_f64 + 1e-2).sqrt()));
+        // near the identity both are only rounding errors
+        if small > 1e-9 {
+            assert!( (big / small / 100.0 - 1.0).abs() < 0.05, "{small} -> {big} for {unit:?}" );
+        }
+
+        let [x, y, z]: [[f64; 3]; 3] = basis.map(|axis: [f64; 3]| quat::rotate_vector::<f64, [f64; 3]>(axis, unit));
+        let back: [f64; 4] = quat::frame_from_columns::<f64, [f64; 4]>(x, y, z).unwrap();
+        assert_same_rotation!(f64; back, unit, 1e-9);
+    }
+
+    assert_eq!( quat::frame_orthogonality_error::<f32, f32>([1.0, 0.0, 0.0, 0.0]), 0.0 );
+    assert!( quat::frame_orthogonality_error::<f32, f32>([f32::NAN, 0.0, 0.0, 0.0]).is_nan() );
+
+    let [x, y, z]: [[f32; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    assert_eq!( quat::frame_from_columns::<f32, [f32; 4]>(x, [0.0; 3], z), None );
+    assert_eq!( quat::frame_from_columns::<f32, [f32; 4]>(x, [2.0, 0.0, 0.0], z), None );
+    assert_eq!( quat::frame_from_columns::<f32, [f32; 4]>(x, y, [0.0, 0.0, -1.0]), None );
+    // slightly off columns still give a unit quaternion
+    let drifted: [f32; 4] = quat::frame_from_columns::<f32, [f32; 4]>([1.001, 0.002, 0.0], [-0.001, 0.998, 0.001], z).unwrap();
+    assert!( (quat::abs::<f32, f32>(drifted) - 1.0).abs() < 0.00001 );
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "test_helpers"))]
+fn tolerances_at_their_boundaries() {
+    use traits::Axis;
+
+    fn check<Num: Axis + core::fmt::Debug>() {
+        let half: Num = Num::from_f64(0.5);
+        assert!( Num::ZERO < Num::EPS_ARITH && Num::EPS_ARITH <= Num::EPS_TRIG && Num::EPS_TRIG < Num::ONE );
+
+        // `is_near` uses `EPS_ARITH` on the distance
+        let inside: Num = Num::EPS_ARITH * half;
+        let outside: Num = Num::EPS_ARITH * Num::from_f64(1.5);
+        assert!( quat::is_near::<Num>([Num::ONE, Num::ZERO, Num::ZERO, Num::ZERO], [Num::ONE + inside, Num::ZERO, Num::ZERO, Num::ZERO]) );
+        assert!( !quat::is_near::<Num>([Num::ONE, Num::ZERO, Num::ZERO, Num::ZERO], [Num::ONE + outside, Num::ZERO, Num::ZERO, Num::ZERO]) );
+
+        // the unit checks use `EPS_TRIG` on the squared length
+        let inside: Num = (Num::ONE + Num::EPS_TRIG * half).sqrt();
+        let outside: Num = (Num::ONE + Num::EPS_TRIG * Num::from_f64(1.5)).sqrt();
+        assert!( quat::is_normalized::<Num>([inside, Num::ZERO, Num::ZERO, Num::ZERO]) );
+        assert!( !quat::is_normalized::<Num>([outside, Num::ZERO, Num::ZERO, Num::ZERO]) );
+        assert!( structs::UnitQuat::<Num>::new(Num::ZERO, inside, Num::ZERO, Num::ZERO).is_some() );
+        assert!( structs::UnitQuat::<Num>::new(Num::ZERO, outside, Num::ZERO, Num::ZERO).is_none() );
+        assert!( quat::to_unit_quat_checked::<Num, structs::UnitQuat<Num>>([Num::ZERO, Num::ZERO, inside, Num::ZERO]).is_some() );
+        assert!( quat::to_unit_quat_checked::<Num, structs::UnitQuat<Num>>([Num::ZERO, Num::ZERO, outside, Num::ZERO]).is_none() );
+        assert!( quat::from_axis_angle_checked::<Num, [Num; 4]>([inside, Num::ZERO, Num::ZERO], Num::ONE).is_some() );
+        assert!( quat::from_axis_angle_checked::<Num, [Num; 4]>([outside, Num::ZERO, Num::ZERO], Num::ONE).is_none() );
+        // too short axes are rejected too
+        let short: Num = (Num::ONE - Num::EPS_TRIG * Num::from_f64(1.5)).sqrt();
+        assert!( quat::from_axis_angle_checked::<Num, [Num; 4]>([short, Num::ZERO, Num::ZERO], Num::ONE).is_none() );
+
+        // normalized quaternions always pass (this failed for `f32` with `ERROR²`)
+        for q in f32_quats().step_by(101).filter(|q: &[f32; 4]| !quat::is_nan::<f32>(q) && !quat::eq::<f32>(q, ())) {
+            let unit: [Num; 4] = quat::normalize::<Num, [Num; 4]>(q.map(|n: f32| Num::from_f64(n as f64)));
+            assert!( quat::is_normalized::<Num>(unit), "{unit:?}" );
+            assert!( quat::to_unit_quat_checked::<Num, structs::UnitQuat<Num>>(unit).is_some(), "{unit:?}" );
+        }
+    }
+    check::<f32>();
+    check::<f64>();
+    #[cfg(feature = "std")]
+    check::<structs::Std<f32>>();
+
+    assert_eq!( <f32 as Axis>::EPS_ARITH, <f32 as Axis>::ERROR );
+    assert_eq!( <f32 as Axis>::EPS_TRIG, 8.0 * <f32 as Axis>::ERROR );
+    assert_eq!( <f64 as Axis>::EPS_TRIG, 8.0 * <f64 as Axis>::ERROR );
+}
+
+#[test]
+#[cfg(feature = "rkyv")]
+fn rkyv_archives() {
+    use quaternion_traits::traits::Scalar;
+    use quaternion_traits::structs::{Quat, Std, UnitQuat, ArchivedQuat, ArchivedStd, ArchivedUnitQuat};
+    use rkyv::rancor::Error;
+
+    // round trips
+    let quat: Quat<f32, [f32; 4]> = Quat::new_quat(1.0, -2.5, 0.25, 8.0);
+    let bytes = rkyv::to_bytes::<Error>(&quat).unwrap();
+    let back: Quat<f32, [f32; 4]> = rkyv::from_bytes::<Quat<f32, [f32; 4]>, Error>(&bytes).unwrap();
+    assert_eq!( back, quat );
+
+    let std32: Std<f32> = Std(0.5);
+    let bytes = rkyv::to_bytes::<Error>(&std32).unwrap();
+    assert_eq!( rkyv::from_bytes::<Std<f32>, Error>(&bytes).unwrap(), std32 );
+    let archived: &ArchivedStd<f32> = rkyv::access::<ArchivedStd<f32>, Error>(&bytes).unwrap();
+    assert_eq!( Scalar::<Std<f32>>::scalar(archived), std32 );
+
+    let std64: Std<f64> = Std(-0.125);
+    let bytes = rkyv::to_bytes::<Error>(&std64).unwrap();
+    assert_eq!( rkyv::from_bytes::<Std<f64>, Error>(&bytes).unwrap(), std64 );
+
+    let unit: UnitQuat<f32> = UnitQuat::new(0.5, 0.5, -0.5, 0.5).unwrap();
+    let bytes = rkyv::to_bytes::<Error>(&unit).unwrap();
+    assert_eq!( rkyv::from_bytes::<UnitQuat<f32>, Error>(&bytes).unwrap(), unit );
+
+    // zero-copy reads, the archived quaternion is just four floats
+    assert_eq!( core::mem::size_of::<ArchivedQuat<f32, [f32; 4]>>(), 16 );
+    let bytes = rkyv::to_bytes::<Error>(&quat).unwrap();
+    let archived: &ArchivedQuat<f32, [f32; 4]> = rkyv::access::<ArchivedQuat<f32, [f32; 4]>, Error>(&bytes).unwrap();
+    assert_eq!( archived.quat[1].to_native(), -2.5 );
+    assert_eq!( Quaternion::<f32>::r(archived), 1.0 );
+    assert_eq!( Quaternion::<f32>::k(archived), 8.0 );
+    assert!( quat::eq::<f32>(archived, quat) );
+    let product: [f32; 4] = quat::mul::<f32, [f32; 4]>(archived, quat);
+    assert_eq!( product, quat::mul::<f32, [f32; 4]>(quat, quat) );
+
+    let bytes = rkyv::to_bytes::<Error>(&unit).unwrap();
+    let archived: &ArchivedUnitQuat<f32> = rkyv::access::<ArchivedUnitQuat<f32>, Error>(&bytes).unwrap();
+    assert!( quat::eq::<f32>(archived, unit) );
+
+    // a corrupted unit quaternion doesn't pass validation
+    let mut corrupted = bytes.clone();
+    let len: usize = corrupted.len();
+    corrupted[len - 16..len - 12].copy_from_slice(&2.0f32.to_le_bytes());
+    assert!( rkyv::access::<ArchivedUnitQuat<f32>, Error>(&corrupted).is_err() );
+    assert!( rkyv::from_bytes::<UnitQuat<f32>, Error>(&corrupted).is_err() );
+    // the same bytes are fine as a plain quaternion
+    assert!( rkyv::access::<ArchivedQuat<f32, [f32; 4]>, Error>(&corrupted).is_ok() );
+}
+
+#[test]
+#[cfg(all(feature = "trigonometry", feature = "test_helpers"))]
+fn trigonometry_identities() {
+    use quaternion_traits::traits::Axis;
+
+    let near = |left: [f64; 4], right: [f64; 4], tolerance: f64, identity: &str, q: [f64; 4]| {
+        let distance: f64 = quat::dist_euclid::<f64, f64>(left, right);
+        assert!( distance <= tolerance, "{identity} for {q:?}: {left:?} != {right:?} ({distance} > {tolerance})" );
+    };
+
+    // `cosh(7)²` is about `3e5` so the rounding stays well below the tolerances
+    for q in f32_quats().step_by(7) {
+        let q: [f64; 4] = q.map(|n: f32| n as f64);
+        let len: f64 = quat::abs::<f64, f64>(quat::vector_part::<f64, [f64; 4]>(q));
+        if len > 7.0 { continue }
+        let size: f64 = len.cosh() * len.cosh();
+
+        let (sin, cos): ([f64; 4], [f64; 4]) = quat::sin_cos::<f64, [f64; 4]>(q);
+        assert_eq!( sin, quat::sin::<f64, [f64; 4]>(q), "sin_cos(q).0 == sin(q) for {q:?}" );
+        assert_eq!( cos, quat::cos::<f64, [f64; 4]>(q), "sin_cos(q).1 == cos(q) for {q:?}" );
+
+        let one: [f64; 4] = quat::add::<f64, [f64; 4]>(quat::square::<f64, [f64; 4]>(sin), quat::square::<f64, [f64; 4]>(cos));
+        near(one, [1.0, 0.0, 0.0, 0.0], 1e-9 * size, "sin² + cos² == 1", q);
+
+        let sinh: [f64; 4] = quat::sinh::<f64, [f64; 4]>(q);
+        let cosh: [f64; 4] = quat::cosh::<f64, [f64; 4]>(q);
+        if q[0] < 7.0 {
+            let one: [f64; 4] = quat::sub::<f64, [f64; 4]>(quat::square::<f64, [f64; 4]>(cosh), quat::square::<f64, [f64; 4]>(sinh));
+            near(one, [1.0, 0.0, 0.0, 0.0], 1e-9 * q[0].cosh() * q[0].cosh(), "cosh² - sinh² == 1", q);
+        }
+
+        // the quotients lose accuracy close to the poles
+        let cos_abs: f64 = quat::abs::<f64, f64>(cos);
+        if cos_abs > 1e-3 {
+            let tolerance: f64 = 1e-12 * size / (cos_abs * cos_abs);
+            let inv_cos: [f64; 4] = quat::inv::<f64, [f64; 4]>(cos);
+            near(quat::tan::<f64, [f64; 4]>(q), quat::mul::<f64, [f64; 4]>(sin, inv_cos), tolerance, "tan == sin * inv(cos)", q);
+            near(quat::sec::<f64, [f64; 4]>(q), inv_cos, tolerance, "sec == inv(cos)", q);
+        }
+        let sin_abs: f64 = quat::abs::<f64, f64>(sin);
+        if sin_abs > 1e-3 {
+            let tolerance: f64 = 1e-12 * size / (sin_abs * sin_abs);
+            let inv_sin: [f64; 4] = quat::inv::<f64, [f64; 4]>(sin);
+            near(quat::cot::<f64, [f64; 4]>(q), quat::mul::<f64, [f64; 4]>(cos, inv_sin), tolerance, "cot == cos * inv(sin)", q);
+            near(quat::csc::<f64, [f64; 4]>(q), inv_sin, tolerance, "csc == inv(sin)", q);
+        }
+    }
+
+    // the origin and the real axis are exact
+    assert_eq!( quat::sec::<f32, [f32; 4]>(()), [1.0, 0.0, 0.0, 0.0] );
+    assert_eq!( quat::sech::<f32, [f32; 4]>(()), [1.0, 0.0, 0.0, 0.0] );
+    assert_eq!( quat::cos::<f32, [f32; 4]>(()), [1.0, 0.0, 0.0, 0.0] );
+    assert_eq!( quat::sin::<f32, [f32; 4]>(()), [0.0, 0.0, 0.0, 0.0] );
+    assert_eq!( quat::tan::<f32, [f32; 4]>(()), [0.0, 0.0, 0.0, 0.0] );
+    assert_eq!( quat::csc::<f32, [f32; 4]>(()), [f32::INFINITY, 0.0, 0.0, 0.0] );
+    for x in F32S.into_iter().flat_map(|x: f32| [x, -x]) {
+        let real: [f32; 4] = [x, 0.0, 0.0, 0.0];
+        let (sin, cos): (f32, f32) = Axis::sin_cos(x);
+        assert_eq!( quat::sin::<f32, [f32; 4]>(real), [sin, 0.0, 0.0, 0.0], "sin({x})" );
+        assert_eq!( quat::cos::<f32, [f32; 4]>(real), [cos, 0.0, 0.0, 0.0], "cos({x})" );
+        assert_eq!( quat::sec::<f32, [f32; 4]>(real), [1.0 / cos, 0.0, 0.0, 0.0], "sec({x})" );
+        assert_eq!( quat::csc::<f32, [f32; 4]>(real), [1.0 / sin, 0.0, 0.0, 0.0], "csc({x})" );
+        assert_eq!( quat::tan::<f32, [f32; 4]>(real), [sin / cos, 0.0, 0.0, 0.0], "tan({x})" );
+        assert_eq!( quat::sinh::<f32, [f32; 4]>(real), [Axis::sinh(x), 0.0, 0.0, 0.0], "sinh({x})" );
+        assert_eq!( quat::cosh::<f32, [f32; 4]>(real), [Axis::cosh(x), 0.0, 0.0, 0.0], "cosh({x})" );
+    }
+}
+
+#[test]
+#[cfg(all(feature = "matrix", feature = "rotation"))]
+fn same_rotation_matrix_tolerates_element_noise() {
+    let tenth_degree: f64 = 0.1_f64.to_radians();
+    let rotations: [[f64; 4]; 7] = [
+        quat::identity::<f64, [f64; 4]>(),
+        quat::from_axis_angle::<f64, [f64; 4]>([1.0, 2.0, 3.0], 1.0),
+        quat::from_axis_angle::<f64, [f64; 4]>([0.0, 0.0, 1.0], core::f64::consts::PI),
+        quat::from_axis_angle::<f64, [f64; 4]>([1.0, -1.0, 0.5], 3.1),
+        // gimbal lock (pitch at ±90°)
+        quat::from_rotation::<f64, [f64; 4]>([0.3, core::f64::consts::FRAC_PI_2, -0.7]),
+        quat::from_rotation::<f64, [f64; 4]>([1.2, -core::f64::consts::FRAC_PI_2, 0.4]),
+        quat::from_axis_angle::<f64, [f64; 4]>([0.0, 1.0, 0.0], -2.5),
+    ];
+    // a fixed pattern of element noise up to `1e-3`
+    let noise: [[f64; 3]; 3] = [[1e-3, -0.7e-3, 0.4e-3], [-1e-3, 0.2e-3, 0.9e-3], [0.5e-3, -0.8e-3, -0.6e-3]];
+
+    for rotation in rotations {
+        let matrix: [[f64; 3]; 3] = quat::to_matrix_3::<f64, f64, [[f64; 3]; 3]>(rotation);
+        let mut noisy: [[f64; 3]; 3] = matrix;
+        for row in 0..3 {
+            for column in 0..3 {
+                noisy[row][column] += noise[row][column];
+            }
+        }
+        let angle: f64 = quat::rotation_angle_between_matrices::<f64, f64, f64>(matrix, noisy);
+        assert!( angle < tenth_degree, "{angle} for {rotation:?}" );
+        assert!( quat::same_rotation_matrix::<f64, f64>(matrix, noisy, tenth_degree) );
+        assert!( quat::same_rotation_matrix::<f64, f64>(matrix, matrix, 1e-12) );
+        // the same rotation from the other sign
+        let flipped: [[f64; 3]; 3] = quat::to_matrix_3::<f64, f64, [[f64; 3]; 3]>(quat::neg::<f64, [f64; 4]>(rotation));
+        assert!( quat::same_rotation_matrix::<f64, f64>(noisy, flipped, tenth_degree) );
+
+        // one degree further is a different rotation
+        let turned: [f64; 4] = quat::mul::<f64, [f64; 4]>(rotation, quat::from_axis_angle::<f64, [f64; 4]>([0.0, 1.0, 1.0], 1.0_f64.to_radians()));
+        let other: [[f64; 3]; 3] = quat::to_matrix_3::<f64, f64, [[f64; 3]; 3]>(turned);
+        let angle: f64 = quat::rotation_angle_between_matrices::<f64, f64, f64>(matrix, other);
+        assert!( (angle - 1.0_f64.to_radians()).abs() < 1e-9, "{angle} for {rotation:?}" );
+        assert!( !quat::same_rotation_matrix::<f64, f64>(matrix, other, tenth_degree) );
+        assert!( !quat::same_rotation_matrix::<f64, f64>(noisy, other, tenth_degree) );
+    }
+
+    let nan: [[f64; 3]; 3] = [[f64::NAN; 3]; 3];
+    assert!( !quat::same_rotation_matrix::<f64, f64>(nan, nan, core::f64::consts::PI) );
+}
+
+#[test]
+#[cfg(feature = "test_helpers")]
+fn byte_wire_format() {
+    use quaternion_traits::traits::AxisBytes;
+
+    // golden bytes, these can't change without breaking the wire format
+    let quat: [f32; 4] = [1.0, -2.0, 0.5, 0.0];
+    let le: [u8; 16] = [
+        0x00, 0x00, 0x80, 0x3F,
+        0x00, 0x00, 0x00, 0xC0,
+        0x00, 0x00, 0x00, 0x3F,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+    assert_eq!( quat::to_le_bytes::<f32>(quat), le );
+    assert_eq!( quat::from_le_bytes::<f32, [f32; 4]>(le), quat );
+    let be: [u8; 16] = [
+        0x3F, 0x80, 0x00, 0x00,
+        0xC0, 0x00, 0x00, 0x00,
+        0x3F, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+    assert_eq!( quat::to_be_bytes::<f32>(quat), be );
+    assert_eq!( quat::from_be_bytes::<f32, [f32; 4]>(be), quat );
+
+    let wide: [u8; 32] = quat::to_le_bytes::<f64>([1.0, -2.0, 0.5, 0.0]);
+    assert_eq!( wide[..8], [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x3F] );
+    assert_eq!( wide[8..16], [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0] );
+    assert_eq!( wide[16..24], [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0x3F] );
+    assert_eq!( wide[24..], [0; 8] );
+
+    // big endian is little endian with every number's bytes swapped
+    let swap = |bytes: &[u8], size: usize| -> Vec<u8> {
+        bytes.chunks(size).flat_map(|number: &[u8]| number.iter().rev().copied()).collect()
+    };
+    let nan: f32 = f32::from_bits(0x7FC0_1234);
+    for q in f32_quats().step_by(997).chain([[nan, -nan, f32::INFINITY, -0.0]]) {
+        let le: [u8; 16] = quat::to_le_bytes::<f32>(q);
+        let be: [u8; 16] = quat::to_be_bytes::<f32>(q);
+        assert_eq!( swap(&le, 4), be );
+        let back: [f32; 4] = quat::from_le_bytes::<f32, [f32; 4]>(le);
+        assert_eq!( back.map(f32::to_bits), q.map(f32::to_bits) );
+        let back: [f32; 4] = quat::from_be_bytes::<f32, [f32; 4]>(be);
+        assert_eq!( back.map(f32::to_bits), q.map(f32::to_bits) );
+
+        let wide: [f64; 4] = q.map(|n: f32| n as f64);
+        let le: [u8; 32] = quat::to_le_bytes::<f64>(wide);
+        let be: [u8; 32] = quat::to_be_bytes::<f64>(wide);
+        assert_eq!( swap(&le, 8), be );
+        let back: [f64; 4] = quat::from_be_bytes::<f64, [f64; 4]>(be);
+        assert_eq!( back.map(f64::to_bits), wide.map(f64::to_bits) );
+    }
+    // NaN payloads and signs are kept
+    let payload: f64 = f64::from_bits(0xFFF8_0000_DEAD_BEEF);
+    let back: [f64; 4] = quat::from_le_bytes::<f64, [f64; 4]>(quat::to_le_bytes::<f64>([payload; 4]));
+    assert_eq!( back.map(f64::to_bits), [0xFFF8_0000_DEAD_BEEF; 4] );
+
+    #[cfg(feature = "std")]
+    {
+        let std_quat: [structs::Std<f32>; 4] = quat.map(structs::Std);
+        assert_eq!( quat::to_le_bytes::<structs::Std<f32>>(std_quat), le );
+        assert_eq!( quat::from_be_bytes::<structs::Std<f32>, [structs::Std<f32>; 4]>(be), std_quat );
+        assert_eq!( <structs::Std<f64> as AxisBytes>::to_be_bytes(structs::Std(1.0)), 1.0_f64.to_be_bytes() );
+    }
+}
+
+#[test]
+fn subnormal_classification_and_abs() {
+    use core::num::FpCategory;
+    use quaternion_traits::traits::Axis;
+
+    let tiny: f32 = f32::from_bits(1);
+    assert_eq!( Axis::classify(tiny), FpCategory::Subnormal );
+    assert_eq!( Axis::classify(f32::MIN_POSITIVE), FpCategory::Normal );
+    assert_eq!( Axis::classify(-0.0_f64), FpCategory::Zero );
+    assert_eq!( Axis::classify(f64::NEG_INFINITY), FpCategory::Infinite );
+    assert_eq!( Axis::classify(f64::NAN), FpCategory::Nan );
+    #[cfg(feature = "std")]
+    assert_eq!( Axis::classify(structs::Std(1e-310_f64)), FpCategory::Subnormal );
+
+    assert_eq!(
+        quat::classify::<f32>([tiny, 1.0, -0.0, f32::NAN]),
+        [FpCategory::Subnormal, FpCategory::Normal, FpCategory::Zero, FpCategory::Nan],
+    );
+    assert_eq!( quat::count_subnormals::<f32>([tiny, -tiny, 1e-39, 1e-37]), 3 );
+    assert_eq!( quat::count_subnormals::<f32>([1.0, 2.0, 0.0, f32::INFINITY]), 0 );
+
+    // these all used to underflow to zero
+    let cases: [([f32; 4], f32); 4] = [
+        ([3e-40, 0.0, 4e-40, 0.0], 5e-40),
+        ([0.0, -tiny, 0.0, 0.0], tiny),
+        ([1e-39, 1e-39, 1e-39, 1e-39], 2e-39),
+        ([1e-20, 1e-40, 0.0, 0.0], 1e-20),
+    ];
+    for (quaternion, expected) in cases {
+        let abs: f32 = quat::abs::<f32, f32>(quaternion);
+        assert!( (abs - expected).abs() <= expected * 1e-5 + tiny, "abs({quaternion:?}) = {abs}, expected {expected}" );
+        #[cfg(feature = "math_fns")]
+        let abs_small: f32 = quat::abs_small::<f32, f32>(quaternion);
+        #[cfg(feature = "math_fns")]
+        assert!( (abs_small - expected).abs() <= expected * 1e-5 + tiny, "abs_small({quaternion:?}) = {abs_small}, expected {expected}" );
+    }
+    let abs: f64 = quat::abs::<f64, f64>([3e-320, 4e-320, 0.0, 0.0]);
+    assert!( (abs - 5e-320).abs() <= 1e-322 );
+
+    // the rescaled path keeps the special values
+    assert_eq!( quat::abs::<f32, f32>([f32::INFINITY, tiny, 0.0, 0.0]), f32::INFINITY );
+    assert!( quat::abs::<f32, f32>([f32::NAN, tiny, 0.0, 0.0]).is_nan() );
+    assert_eq!( quat::abs::<f32, f32>([0.0; 4]), 0.0 );
+}
+
+/// The composed versions of `div`, `lerp` and `slerp_unchecked` from before they were fused.
+mod composed {
+    use super::*;
+    use quaternion_traits::traits::Axis;
+
+    pub fn div(left: [f32; 4], right: [f32; 4]) -> [f32; 4] {
+        quat::mul::<f32, [f32; 4]>(left, quat::inv::<f32, Q<f32>>(right))
+    }
+
+    pub fn lerp(from: [f32; 4], to: [f32; 4], at: f32) -> [f32; 4] {
+        quat::add::<f32, [f32; 4]>(
+            if quat::dot::<f32, f32>(from, to) < 0.0 {
+                quat::scale::<f32, Q<f32>>(quat::add::<f32, Q<f32>>(to, from), -at)
+            } else {
+                quat::scale::<f32, Q<f32>>(quat::sub::<f32, Q<f32>>(to, from), at)
+            },
+            from,
+        )
+    }
+
+    pub fn slerp_unchecked(from: [f32; 4], to: [f32; 4], at: f32) -> [f32; 4] {
+        let mut dot: f32 = quat::dot::<f32, f32>(from, to);
+        let to: Q<f32> = if dot < 0.0 {
+            dot = -dot;
+            quat::neg::<f32, Q<f32>>(to)
+        } else {
+            quat::convert_quat::<f32, Q<f32>>(to)
+        };
+        if dot > 1.0 - <f32 as Axis>::ERROR {
+            return quat::normalize_unchecked::<f32, [f32; 4]>(quat::add::<f32, Q<f32>>(
+                quat::scale::<f32, Q<f32>>(quat::sub::<f32, Q<f32>>(to, from), at),
+                from,
+            ));
+        }
+        let angle: f32 = Axis::acos(dot);
+        let transition_angle: f32 = at * angle;
+        let sin_1: f32 = Axis::sin(angle - transition_angle);
+        let sin_2: f32 = Axis::sin(transition_angle);
+        let coeficient: f32 = 1.0 / Axis::sqrt(1.0 - dot * dot);
+        quat::add::<f32, [f32; 4]>(
+            quat::scale::<f32, Q<f32>>(from, coeficient * sin_1),
+            quat::scale::<f32, Q<f32>>(to, coeficient * sin_2),
+        )
+    }
+}
+
+/// If the two numbers are within one ULP of each other (or both NaN).
+fn within_ulp(left: f32, right: f32) -> bool {
+    if left.is_nan() || right.is_nan() {
+        return left.is_nan() && right.is_nan();
+    }
+    if left == right {
+        return true;
+    }
+    left.signum() == right.signum() && left.to_bits().abs_diff(right.to_bits()) <= 1
+}
+
+#[test]
+#[cfg(all(feature = "math_fns", feature = "test_helpers"))]
+fn fused_compositions_match_composed() {
+    let check = |fused: [f32; 4], composed: [f32; 4], name: &str, inputs: &dyn core::fmt::Debug| {
+        for n in 0..4 {
+            assert!( within_ulp(fused[n], composed[n]), "{name} for {inputs:?}: {fused:?} != {composed:?}" );
+        }
+    };
+    let others: [[f32; 4]; 3] = [[0.5, -1.0, 2.0, 0.25], [0.0; 4], [1e-8, 3.0, -1e8, 0.0]];
+
+    for q in f32_quats().step_by(37) {
+        for other in others {
+            check(quat::div::<f32, [f32; 4]>(q, other), composed::div(q, other), "div", &(q, other));
+            check(quat::div::<f32, [f32; 4]>(other, q), composed::div(other, q), "div", &(other, q));
+            for at in [0.0, 0.3, 1.0, -2.0] {
+                check(quat::lerp::<f32, [f32; 4]>(q, other, at), composed::lerp(q, other, at), "lerp", &(q, other, at));
+            }
+        }
+    }
+
+    let units: Vec<[f32; 4]> = f32_quats()
+        .step_by(4099)
+        .filter(|q: &[f32; 4]| !quat::eq::<f32>(q, ()))
+        .map(|q: [f32; 4]| quat::normalize::<f32, [f32; 4]>(q))
+        .collect();
+    for pair in units.windows(2) {
+        for at in [0.0, 0.25, 0.5, 1.0] {
+            check(
+                quat::slerp_unchecked::<f32, [f32; 4]>(pair[0], pair[1], at),
+                composed::slerp_unchecked(pair[0], pair[1], at),
+                "slerp_unchecked", &(pair[0], pair[1], at),
+            );
+        }
+        // the nearly equal path
+        check(
+            quat::slerp_unchecked::<f32, [f32; 4]>(pair[0], pair[0], 0.5),
+            composed::slerp_unchecked(pair[0], pair[0], 0.5),
+            "slerp_unchecked", &(pair[0], 0.5),
+        );
+    }
+}
+
+#[test]
+#[ignore]
+#[cfg(all(feature = "std", feature = "math_fns", feature = "test_helpers"))]
+fn timing_fused_compositions() {
+    use std::hint::black_box;
+
+    let quats: Vec<[f32; 4]> = f32_quats()
+        .step_by(61)
+        .filter(|q: &[f32; 4]| !quat::eq::<f32>(q, ()))
+        .map(|q: [f32; 4]| quat::normalize::<f32, [f32; 4]>(q))
+        .take(65536)
+        .collect();
+
+    macro_rules! compare {
+        ( $name:literal: $fused:expr, $composed:expr ) => {{
+            let fused = timer! {
+                run { for pair in black_box(&quats).windows(2) { black_box($fused(pair[0], pair[1])); } },
+                silent, repeat 20, silent,
+            };
+            let composed = timer! {
+                run { for pair in black_box(&quats).windows(2) { black_box($composed(pair[0], pair[1])); } },
+                silent, repeat 20, silent,
+            };
+            println!("{:>16}: fused {fused:?}, composed {composed:?}", $name);
+        }};
+    }
+
+    compare!("div": quat::div::<f32, [f32; 4]>, composed::div);
+    compare!("lerp": |a, b| quat::lerp::<f32, [f32; 4]>(a, b, 0.3), |a, b| composed::lerp(a, b, 0.3));
+    compare!("slerp_unchecked": |a, b| quat::slerp_unchecked::<f32, [f32; 4]>(a, b, 0.3), |a, b| composed::slerp_unchecked(a, b, 0.3));
+
+    println!("
+    release (x86_64, default target cpu), 65535 pairs:
+                div: fused ~`530`µs, composed ~`570`µs
+               lerp: fused ~`140`µs, composed ~`150`µs
+    slerp_unchecked: fused ~`1.10`ms, composed ~`1.10`ms (the `acos` and `sin`s take most of the time)
+    ");
+}
+
+#[test]
+fn unit_type_as_null_sink() {
+    let q: [f32; 4] = [1.0, 2.0, -3.0, 0.5];
+
+    let () = quat::convert_quat::<f32, ()>(q);
+    let () = quat::convert_num::<f32, f64, ()>(q);
+    let () = quat::from_le_bytes::<f32, ()>([0; 16]);
+    let () = quat::to_unit_quat::<f32, ()>(q);
+    assert_eq!( quat::to_unit_quat_checked::<f32, ()>(quat::normalize::<f32, [f32; 4]>(q)), Some(()) );
+    assert_eq!( quat::to_unit_quat_checked::<f32, ()>(q), None );
+    let () = quat::to_vector::<f32, ()>(q);
+    let () = quat::to_complex::<f32, ()>(q);
+    let () = quat::to_scalar::<f32, ()>(q);
+    let [] = quat::to_vector::<f32, [f32; 0]>(q);
+    let [] = quat::to_complex::<f32, [u8; 0]>(q);
+    let [] = quat::to_scalar::<f32, [f64; 0]>(q);
+    #[cfg(feature = "math_fns")]
+    let ((), (), ()) = quat::to_polar_form::<f32, (), (), ()>(q);
+    let () = quat::abs::<f32, ()>(q);
+    let () = quat::from_vector::<f32, ()>([1.0, 2.0, 3.0]);
+
+    #[cfg(feature = "rotation")]
+    {
+        let () = quat::to_rotation::<f32, ()>(q);
+        let [] = quat::to_rotation::<f32, [f32; 0]>(q);
+        let ((), _) = quat::to_rotation_ext::<f32, ()>(q, quat::AngleRange::Unsigned);
+        let ((), ()) = quat::to_axis_angle::<f32, (), ()>(q);
+        let () = quat::to_roll::<f32, ()>(q);
+        let () = quat::to_pitch::<f32, ()>(q);
+        let () = quat::to_yaw::<f32, ()>(q);
+        let () = quat::from_rotation::<f32, ()>([0.1, 0.2, 0.3]);
+        let () = quat::point_rotation::<f32, ()>(q, [1.0, 0.0, 0.0]);
+    }
+    #[cfg(feature = "matrix")]
+    {
+        let () = quat::to_matrix_2::<f32, (f32, f32), ()>(q);
+        let () = quat::to_matrix_3::<f32, f32, ()>(q);
+        let () = quat::to_matrix_4::<f32, f32, ()>(q);
+        let () = quat::orthonormalize_matrix_3::<f32, f32, ()>([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+    }
+    #[cfg(all(feature = "matrix", feature = "rotation"))]
+    {
+        let () = quat::rotation_to_matrix_3::<f32, f32, ()>([0.1, 0.2, 0.3]);
+        let () = quat::matrix_3_to_rotation::<f32, f32, ()>([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+    }
+
+    // the sinks read back as zeros
+    assert_eq!( quat::from_vector::<f32, [f32; 4]>(()), [0.0; 4] );
+    assert_eq!( quat::from_complex::<f32, [f32; 4]>([0_u8; 0]), [0.0; 4] );
+}
+
+#[test]
+#[cfg(feature = "qol_fns")]
+fn sub_from_reconstructs_minuend() {
+    use quaternion_traits::QuaternionMethods;
+
+    // dyadic values keep every sum and difference exact
+    const VALUES: [f32; 7] = [0.0, 1.0, -2.5, 0.125, 7.75, -64.0, 1024.5];
+
+    for r in VALUES { for i in VALUES { for j in VALUES { for k in VALUES {
+        let q: [f32; 4] = [r, i, j, k];
+        for a in VALUES { for b in VALUES {
+            assert_eq!(
+                quat::add::<f32, [f32; 4]>(quat::sub_from_scalar::<f32, [f32; 4]>(a, q), q),
+                [a, 0.0, 0.0, 0.0],
+            );
+            assert_eq!(
+                quat::add::<f32, [f32; 4]>(quat::sub_from_complex::<f32, [f32; 4]>((a, b), q), q),
+                [a, b, 0.0, 0.0],
+            );
+            assert_eq!(
+                quat::add::<f32, [f32; 4]>(quat::sub_from_vector::<f32, [f32; 4]>([a, b, a], q), q),
+                [0.0, a, b, a],
+            );
+            assert_eq!(
+                QuaternionMethods::<f32>::sub_from_scalar(q, a),
+                quat::neg::<f32, [f32; 4]>(quat::sub_scalar::<f32, [f32; 4]>(q, a)),
+            );
+            assert_eq!(
+                QuaternionMethods::<f32>::sub_from_complex(q, (a, b)),
+                quat::neg::<f32, [f32; 4]>(quat::sub_complex::<f32, [f32; 4]>(q, (a, b))),
+            );
+            assert_eq!(
+                QuaternionMethods::<f32>::sub_from_vector(q, [b, a, b]),
+                quat::neg::<f32, [f32; 4]>(quat::sub_vector::<f32, [f32; 4]>(q, [b, a, b])),
+            );
+        } }
+    } } } }
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "matrix"))]
+fn adjoint_operators_and_bch() {
+    fn apply(matrix: [[f64; 3]; 3], vector: [f64; 3]) -> [f64; 3] {
+        [0, 1, 2].map(|row| matrix[row][0] * vector[0] + matrix[row][1] * vector[1] + matrix[row][2] * vector[2])
+    }
+    fn exp_map(vector: [f64; 3]) -> [f64; 4] {
+        quat::exp::<f64, [f64; 4]>([0.0, vector[0] * 0.5, vector[1] * 0.5, vector[2] * 0.5])
+    }
+    fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+        [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+    }
+    let vectors: [[f64; 3]; 5] = [
+        [1.0, 0.0, 0.0], [0.3, -0.8, 2.0], [-1.5, 0.25, 0.5], [0.0, 0.0, -3.0], [0.7, 0.7, -0.1],
+    ];
+
+    for v in vectors {
+        for w in vectors {
+            assert_eq!( apply(quat::ad::<f64, f64, [[f64; 3]; 3]>(v), w), cross(v, w) );
+
+            let q: [f64; 4] = quat::from_axis_angle::<f64, [f64; 4]>(v, w[1]);
+            let by_matrix: [f64; 3] = apply(quat::Ad::<f64, f64, [[f64; 3]; 3]>(q), w);
+            let rotated: [f64; 3] = quat::rotate_vector::<f64, [f64; 3]>(w, q);
+            for n in 0..3 {
+                assert!( (by_matrix[n] - rotated[n]).abs() < 1e-12, "{q:?} {w:?}: {by_matrix:?} != {rotated:?}" );
+            }
+
+            // the error of the second order approximation shrinks with the cube of the size
+            // (dropping the bracket term would leave a square sized error)
+            for scale in [1e-1, 1e-2, 1e-3] {
+                let small_v: [f64; 3] = v.map(|x| x * scale);
+                let small_w: [f64; 3] = w.map(|x| x * scale);
+                let composed: [f64; 4] = exp_map(quat::baker_campbell_hausdorff_2::<f64, [f64; 3]>(small_v, small_w));
+                let product: [f64; 4] = quat::mul::<f64, [f64; 4]>(exp_map(small_v), exp_map(small_w));
+                let error: f64 = quat::abs::<f64, f64>(quat::sub::<f64, [f64; 4]>(composed, product));
+                assert!( error <= 2.0 * scale * scale * scale * 27.0 + 1e-15, "{v:?} {w:?} at {scale}: {error}" );
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "test_helpers")]
+fn quats_batch_matches_single_quaternions() {
+    use quaternion_traits::structs::Quats;
+
+    let quats: Vec<[f32; 4]> = f32_quats().step_by(9973).collect();
+    for chunk in quats.chunks_exact(16).collect::<Vec<_>>().windows(2) {
+        let left: Quats<f32, 16> = Quats::from(<[[f32; 4]; 16]>::try_from(chunk[0]).unwrap());
+        let right: Quats<f32, 16> = Quats::from(<[[f32; 4]; 16]>::try_from(chunk[1]).unwrap());
+
+        let added = left.add(right);
+        let multiplied = left.mul(right);
+        let scaled = left.scale(-0.375);
+        let normalized = left.normalize();
+        let conjugated = left.map(quat::conj::<f32, (f32, [f32; 3])>);
+        for n in 0..16 {
+            assert_eq!( added[n], quat::add::<f32, [f32; 4]>(left[n], right[n]) );
+            assert_eq!( multiplied[n], quat::mul::<f32, [f32; 4]>(left[n], right[n]) );
+            assert_eq!( scaled[n], quat::scale::<f32, [f32; 4]>(left[n], -0.375) );
+            assert_eq!( normalized[n].map(f32::to_bits), quat::normalize::<f32, [f32; 4]>(left[n]).map(f32::to_bits) );
+            assert_eq!( conjugated[n], quat::conj::<f32, [f32; 4]>(left[n]) );
+        }
+        assert_eq!( left + right, added );
+        assert_eq!( left * right, multiplied );
+
+        let iterated: Vec<[f32; 4]> = left.into_iter().collect();
+        assert_eq!( iterated, chunk[0] );
+        assert_eq!( <[[f32; 4]; 16]>::from(left).as_slice(), chunk[0] );
+    }
+
+    // the origin and subnormal quaternions take the slow path of normalize
+    let edge: Quats<f32, 4> = Quats::from([[0.0; 4], [1e-40, 0.0, 0.0, 0.0], [3e-39, -4e-39, 0.0, 0.0], [1.0, 1e-40, 0.0, 0.0]]);
+    for (n, q) in edge.normalize().into_iter().enumerate() {
+        assert_eq!( q.map(f32::to_bits), quat::normalize::<f32, [f32; 4]>(edge[n]).map(f32::to_bits) );
+    }
+
+    // compensated sum: the small parts survive the large ones cancelling
+    let mut batch: Quats<f64, 64> = Quats::from([[0.1, -0.2, 0.3, 1.0]; 64]);
+    batch[0] = [1e17, 0.0, 0.0, 0.0];
+    batch[63] = [-1e17, 0.0, 0.0, 0.0];
+    let sum: [f64; 4] = batch.reduce_sum();
+    let expected: [f64; 4] = [62.0 * 0.1, 62.0 * -0.2, 62.0 * 0.3, 62.0];
+    for axis in 0..4 {
+        assert!( (sum[axis] - expected[axis]).abs() < 1e-12, "{sum:?} != {expected:?}" );
+    }
+
+    // balanced product: same result as the sequential product for exact values,
+    // and the identity for an empty batch
+    let rotations: Quats<f64, 7> = Quats::from([
+        [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0], [0.5, 0.5, 0.5, 0.5], [0.0, 0.0, 0.0, 1.0],
+        [0.5, -0.5, 0.5, -0.5], [1.0, 0.0, 0.0, 0.0], [0.0, 0.0, -1.0, 0.0],
+    ]);
+    assert_eq!( rotations.reduce_product::<[f64; 4]>(), quat::product::<f64, [f64; 4]>(rotations) );
+    assert_eq!( Quats::<f32, 0>::from([]).reduce_product::<[f32; 4]>(), [1.0, 0.0, 0.0, 0.0] );
+    assert_eq!( Quats::<f32, 0>::from([]).reduce_sum::<[f32; 4]>(), [0.0; 4] );
+
+    let unit: [f64; 4] = quat::normalize::<f64, [f64; 4]>([0.9, 0.1, -0.2, 0.3]);
+    let many: Quats<f64, 64> = Quats::from([unit; 64]);
+    let balanced: [f64; 4] = many.reduce_product();
+    let sequential: [f64; 4] = quat::product::<f64, [f64; 4]>(many);
+    assert!( quat::is_near_by::<f64>(balanced, sequential, 1e-12) );
+}
+
+#[test]
+#[ignore = "timing test, run with --release --ignored --nocapture"]
+#[cfg(all(feature = "std", feature = "test_helpers"))]
+fn timing_quats_batch() {
+    use std::hint::black_box;
+    use quaternion_traits::structs::Quats;
+
+    let quats: Vec<[f32; 4]> = f32_quats().step_by(61).take(65536).collect();
+
+    macro_rules! compare {
+        ( $n:literal ) => {{
+            let batches: Vec<Quats<f32, $n>> = quats.chunks_exact($n)
+                .map(|chunk| Quats::from(<[[f32; 4]; $n]>::try_from(chunk).unwrap()))
+                .collect();
+            let batched = timer! {
+                run { for pair in black_box(&batches).windows(2) { black_box(pair[0].mul(pair[1]).normalize()); } },
+                silent, repeat 20, silent,
+            };
+            let single = timer! {
+                run { for pair in black_box(&batches).windows(2) { for n in 0..$n {
+                    black_box(quat::normalize::<f32, [f32; 4]>(quat::mul::<f32, [f32; 4]>(pair[0][n], pair[1][n])));
+                } } },
+                silent, repeat 20, silent,
+            };
+            println!("{:>4}: batched {batched:?}, one by one {single:?}", $n);
+        }};
+    }
+
+    compare!(8);
+    compare!(64);
+
+    println!("
+    release (x86_64, default target cpu), mul then normalize over 65536 quaternions:
+       8: batched ~`430`-`680`µs, one by one ~`560`-`760`µs
+      64: batched ~`700`-`820`µs, one by one ~`650`-`730`µs (the 1 KiB batches get copied by value)
+    ");
+}
+
+#[test]
+#[cfg(feature = "rotation")]
+fn rotate_vector_direction() {
+    // a quarter turn around Z takes X to Y (before 3.0.0 it gave `2 * X - Y`, which isn't a rotation)
+    let quarter: [f64; 4] = [core::f64::consts::FRAC_1_SQRT_2, 0.0, 0.0, core::f64::consts::FRAC_1_SQRT_2];
+    let rotated: [f64; 3] = quat::rotate_vector::<f64, [f64; 3]>([1.0, 0.0, 0.0], quarter);
+    for (got, expected) in rotated.into_iter().zip([0.0, 1.0, 0.0]) {
+        assert!( (got - expected).abs() < 1e-15, "{rotated:?}" );
+    }
+
+    // the same rotation as `point_rotation`, and the conjugate undoes it
+    let unit: [f64; 4] = quat::from_axis_angle::<f64, [f64; 4]>([1.0, -2.0, 0.5], 2.0);
+    let vector: [f64; 3] = [0.3, -1.2, 2.5];
+    let rotated: [f64; 3] = quat::rotate_vector::<f64, [f64; 3]>(vector, unit);
+    let point_rotated: [f64; 3] = quat::point_rotation::<f64, [f64; 3]>(unit, vector);
+    let back: [f64; 3] = quat::rotate_vector::<f64, [f64; 3]>(rotated, quat::conj::<f64, [f64; 4]>(unit));
+    for n in 0..3 {
+        assert!( (rotated[n] - point_rotated[n]).abs() < 1e-12, "{rotated:?} != {point_rotated:?}" );
+        assert!( (back[n] - vector[n]).abs() < 1e-12, "{back:?} != {vector:?}" );
+    }
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "test_helpers"))]
+fn rotate_vector_scaled_is_exact() {
+    // hand computed `q * v * conj(q)` for integer quaternions
+    assert_eq!( quat::rotate_vector_scaled::<f64, [f64; 3]>([1.0, 2.0, 3.0], [1.0, 1.0, 0.0, 0.0]), ([2.0, -6.0, 4.0], 2.0) );
+    assert_eq!( quat::rotate_vector_scaled::<f64, [f64; 3]>([9.0, 0.0, 0.0], [1.0, 2.0, 2.0, 0.0]), ([9.0, 72.0, -36.0], 9.0) );
+    assert_eq!( quat::rotate_vector_scaled::<f64, [f64; 3]>([0.0; 3], [0.0; 4]), ([0.0; 3], 0.0) );
+
+    // rational vectors with the fractions reduced by hand
+    assert_eq!( quat::rotate_vector_exact::<f64, [f64; 3]>([9.0, 0.0, 0.0], [1.0, 2.0, 2.0, 0.0]), [1.0, 8.0, -4.0] );
+    assert_eq!( quat::rotate_vector_exact::<f64, [f64; 3]>([0.5, 0.25, -1.5], [0.0, 0.0, 0.0, 2.0]), [-0.5, -0.25, -1.5] );
+    assert_eq!( quat::rotate_vector_exact::<f64, [f64; 3]>([0.5, 2.0, -3.0], [3.0, 3.0, 3.0, 3.0]), [-3.0, 0.5, 2.0] );
+    assert_eq!( quat::rotate_vector_exact::<f64, [f64; 3]>([0.75, -0.5, 0.25], [1.0, 0.0, 1.0, 0.0]), [0.25, -0.5, -0.75] );
+    assert!( quat::rotate_vector_exact::<f64, [f64; 3]>([1.0, 2.0, 3.0], [0.0; 4]).iter().all(|x| x.is_nan()) );
+
+    // same rotation as rotate_vector with the quaternion normalized
+    for q in f32_quats().step_by(7919).filter(|q| !quat::eq::<f32>(q, ())) {
+        let q: [f64; 4] = q.map(f64::from);
+        let vector: [f64; 3] = [0.3, -1.25, 2.0];
+        let exact: [f64; 3] = quat::rotate_vector_exact::<f64, [f64; 3]>(vector, q);
+        let normalized: [f64; 3] = quat::rotate_vector::<f64, [f64; 3]>(vector, quat::normalize::<f64, [f64; 4]>(q));
+        for axis in 0..3 {
+            assert!( (exact[axis] - normalized[axis]).abs() < 1e-9, "{q:?}: {exact:?} != {normalized:?}" );
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "test_helpers")]
+fn unit_quat_construction_errors() {
+    use quaternion_traits::UnitQuaternionConstructor;
+    use quaternion_traits::traits::NotUnitError;
+    use quaternion_traits::structs::{Quat, UnitQuat};
+
+    let err: Result<[f64; 4], NotUnitError<f64>> = UnitQuaternionConstructor::new_unit_quat_or_err(2.0, 0.0, 0.0, 0.0);
+    assert_eq!( err, Err(NotUnitError { abs_squared: 4.0 }) );
+    let err: Result<UnitQuat<f64>, NotUnitError<f64>> = UnitQuaternionConstructor::from_quat_or_err([1.0, -1.0, 0.5, 0.0]);
+    assert_eq!( err, Err(NotUnitError { abs_squared: 2.25 }) );
+    assert_eq!( format!("{}", NotUnitError { abs_squared: 2.25 }), "not a unit quaternion (squared magnitude 2.25)" );
+    let err: Result<Quat<f32, [f32; 4]>, NotUnitError<f32>> = UnitQuaternionConstructor::new_unit_quat_or_err(0.0, 0.0, 0.0, 0.0);
+    assert_eq!( err.unwrap_err().abs_squared, 0.0 );
+
+    // the same values as new_unit_quat get accepted
+    for q in f32_quats().step_by(997) {
+        let [r, i, j, k] = quat::normalize::<f32, [f32; 4]>(q);
+        for [r, i, j, k] in [[r, i, j, k], q] {
+            assert_eq!(
+                <[f32; 4]>::new_unit_quat_or_err(r, i, j, k).ok(),
+                <[f32; 4]>::new_unit_quat(r, i, j, k),
+            );
+        }
+    }
+
+    // magnitude 2 is scaled down to magnitude 1
+    let normalized: [f64; 4] = UnitQuaternionConstructor::new_unit_quat_normalizing(1.0, 1.0, -1.0, 1.0);
+    assert_eq!( normalized, [0.5, 0.5, -0.5, 0.5] );
+    let normalized: UnitQuat<f64> = UnitQuaternionConstructor::new_unit_quat_normalizing(0.0, 0.0, 2.0, 0.0);
+    assert_eq!( quat::convert_quat::<f64, [f64; 4]>(normalized), [0.0, 0.0, 1.0, 0.0] );
+
+    // values that can't be normalized give the identity
+    for [r, i, j, k] in [[0.0; 4], [f64::NAN, 0.0, 0.0, 0.0], [f64::INFINITY, 1.0, 0.0, 0.0]] {
+        assert_eq!( <[f64; 4]>::new_unit_quat_normalizing(r, i, j, k), [1.0, 0.0, 0.0, 0.0] );
+    }
+}
+
+#[test]
+#[cfg(all(feature = "math_fns", feature = "test_helpers"))]
+fn tunable_term_and_iteration_counts() {
+    // reference values of ln(gamma(x))
+    let references: [(f64, f64); 4] = [
+        (0.5, 0.572_364_942_924_700_1), // ln(√π)
+        (1.0, 0.0),
+        (3.0, core::f64::consts::LN_2),
+        (5.0, 3.178_053_830_347_945_6), // ln(24)
+    ];
+    for (x, expected) in references {
+        let errors: [f64; 4] = [
+            quat::lngamma_n::<f64, [f64; 4], 10>([x, 0.0, 0.0, 0.0]),
+            quat::lngamma_n::<f64, [f64; 4], 100>([x, 0.0, 0.0, 0.0]),
+            quat::lngamma_n::<f64, [f64; 4], 2000>([x, 0.0, 0.0, 0.0]),
+            quat::lngamma_n::<f64, [f64; 4], 60000>([x, 0.0, 0.0, 0.0]),
+        ].map(|value| (value[0] - expected).abs());
+        for pair in errors.windows(2) {
+            assert!( pair[1] < pair[0], "ln(gamma({x})): {errors:?}" );
+        }
+        assert!( errors[3] < x * x / 60000.0, "ln(gamma({x})): {errors:?}" );
+    }
+
+    for q in f32_quats().step_by(199_999).filter(|q| !quat::eq::<f32>(q, ())) {
+        let q: [f64; 4] = q.map(|x| f64::from(x) * 1e-3);
+        assert_eq!( quat::lngamma::<f64, [f64; 4]>(q), quat::lngamma_n::<f64, [f64; 4], 2000>(q) );
+    }
+
+    // the blend converges further with more iterations, and stops early when it's done
+    let spread: [[f64; 4]; 3] = [
+        quat::from_axis_angle::<f64, [f64; 4]>([1.0, 0.0, 0.0], 2.5),
+        quat::from_axis_angle::<f64, [f64; 4]>([0.0, 1.0, 0.0], 2.5),
+        quat::from_axis_angle::<f64, [f64; 4]>([0.0, 0.0, 1.0], 2.5),
+    ];
+    let weights: [f64; 3] = [0.5, 0.3, 0.2];
+    let converged: [f64; 4] = quat::blend_barycentric_n::<f64, [f64; 4], 1000>(&spread, &weights).unwrap();
+    let distances: [f64; 4] = [
+        quat::blend_barycentric_n::<f64, [f64; 4], 0>(&spread, &weights).unwrap(),
+        quat::blend_barycentric_n::<f64, [f64; 4], 1>(&spread, &weights).unwrap(),
+        quat::blend_barycentric_n::<f64, [f64; 4], 3>(&spread, &weights).unwrap(),
+        quat::blend_barycentric_n::<f64, [f64; 4], 10>(&spread, &weights).unwrap(),
+    ].map(|blended| quat::abs::<f64, f64>(quat::sub::<f64, [f64; 4]>(blended, converged)));
+    for pair in distances.windows(2) {
+        assert!( pair[1] < pair[0], "{distances:?}" );
+    }
+    assert_eq!(
+        quat::blend_barycentric::<f64, [f64; 4]>(&spread, &weights),
+        quat::blend_barycentric_n::<f64, [f64; 4], 16>(&spread, &weights),
+    );
+}
+
+#[test]
+#[cfg(feature = "rotation")]
+fn reflections() {
+    let directions: [[f64; 3]; 6] = [
+        [1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.3, -0.8, 2.0], [-1.5, 0.25, 0.5], [0.7, 0.7, -0.1], [-0.2, 0.1, -3.0],
+    ];
+    let near = |a: [f64; 3], b: [f64; 3]| (0..3).all(|n| (a[n] - b[n]).abs() < 1e-12);
+
+    for normal in directions {
+        let reflection: [f64; 4] = quat::reflection_across_plane::<f64, [f64; 4]>(normal);
+        for vector in directions {
+            let reflected: [f64; 3] = quat::reflect_vector::<f64, [f64; 3]>(vector, normal);
+            let sandwich: [f64; 3] = quat::to_vector::<f64, [f64; 3]>(quat::mul::<f64, [f64; 4]>(
+                quat::mul::<f64, [f64; 4]>(reflection, [0.0, vector[0], vector[1], vector[2]]),
+                reflection,
+            ));
+            assert!( near(reflected, sandwich), "{vector:?} across {normal:?}: {reflected:?} != {sandwich:?}" );
+            // reflecting twice gives back the original vector
+            assert!( near(quat::reflect_vector::<f64, [f64; 3]>(reflected, normal), vector) );
+        }
+    }
+
+    for first in directions {
+        for second in directions {
+            let rotation: [f64; 4] = quat::rotation_from_two_reflections::<f64, [f64; 4]>(first, second);
+            for vector in directions {
+                let twice: [f64; 3] = quat::reflect_vector::<f64, [f64; 3]>(quat::reflect_vector::<f64, [f64; 3]>(vector, first), second);
+                assert!( near(quat::rotate_vector::<f64, [f64; 3]>(vector, rotation), twice) );
+            }
+
+            // the rotation takes the first normal to the first normal mirrored around the second one,
+            // which is twice the angle inbetween them
+            let first_unit: [f64; 3] = quat::normalize::<f64, [f64; 4]>([0.0, first[0], first[1], first[2]])[1..].try_into().unwrap();
+            let target: [f64; 3] = quat::reflect_vector::<f64, [f64; 3]>(first_unit, second).map(|x| -x);
+            if (first_unit[0] * target[0] + first_unit[1] * target[1] + first_unit[2] * target[2]) < -0.5 {
+                continue; // (nearly) a half turn, the axis from rotation_from_to isn't unique
+            }
+            let expected: [f64; 4] = quat::rotation_from_to::<f64, [f64; 4]>(first_unit, target);
+            let sign: f64 = if quat::dot::<f64, f64>(rotation, expected) < 0.0 { -1.0 } else { 1.0 };
+            assert!(
+                quat::is_near_by::<f64>(rotation, quat::scale::<f64, [f64; 4]>(expected, sign), 1e-9),
+                "{first:?} {second:?}: {rotation:?} != {expected:?}",
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg(all(feature = "matrix", feature = "test_helpers"))]
+fn matrix_adapters() {
+    use quaternion_traits::structs::{FnMatrix, MatrixView};
+    use quaternion_traits::traits::Matrix;
+
+    for q in f32_quats().step_by(4999).filter(|q| !quat::eq::<f32>(q, ())) {
+        let q: [f32; 4] = quat::normalize::<f32, [f32; 4]>(q);
+        let table: [[f32; 3]; 3] = quat::to_matrix_3::<f32, f32, [[f32; 3]; 3]>(q);
+        let big: [[f32; 4]; 4] = quat::to_matrix_4::<f32, f32, [[f32; 4]; 4]>(q);
+
+        let procedural = FnMatrix::new(|row, col| table[row][col]);
+        assert_eq!( Matrix::<f32, 3>::to_array(&procedural), table );
+        assert_eq!( quat::from_matrix_3::<f32, f32, [f32; 4]>(procedural), quat::from_matrix_3::<f32, f32, [f32; 4]>(table) );
+
+        // a 3x3 block of a 3x5 buffer, starting at the second column
+        let mut flat: [f32; 15] = [f32::NAN; 15];
+        for row in 0..3 {
+            flat[row * 5 + 1..row * 5 + 4].copy_from_slice(&table[row]);
+        }
+        let view: MatrixView<f32, 3> = MatrixView::new(&flat[1..], 5).unwrap();
+        assert_eq!( view.to_array(), table );
+        assert_eq!( quat::from_matrix_3::<f32, f32, [f32; 4]>(view), quat::from_matrix_3::<f32, f32, [f32; 4]>(table) );
+
+        let flat_big: Vec<f32> = big.iter().flatten().copied().collect();
+        let view: MatrixView<f32, 4> = MatrixView::contiguous(&flat_big).unwrap();
+        assert_eq!( quat::from_matrix_4::<f32, f32, [f32; 4]>(view), quat::from_matrix_4::<f32, f32, [f32; 4]>(big) );
+    }
+
+    // out of bounds reads don't reach the function or the slice
+    let calls = core::cell::Cell::new(0_u32);
+    let counting = FnMatrix::new(|row, col| { calls.set(calls.get() + 1); (row * 10 + col) as f32 });
+    assert_eq!( Matrix::<f32, 2>::get(&counting, 1, 1), Some(11.0) );
+    assert_eq!( Matrix::<f32, 2>::get(&counting, 2, 0), None );
+    assert_eq!( Matrix::<f32, 2>::get(&counting, 0, 2), None );
+    assert_eq!( Matrix::<f32, 2>::get(&counting, usize::MAX, usize::MAX), None );
+    assert_eq!( calls.get(), 1 );
+
+    let flat: [f32; 8] = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+    let view: MatrixView<f32, 2> = MatrixView::new(&flat, 3).unwrap();
+    assert_eq!( view.get(1, 1), Some(4.0) );
+    assert_eq!( view.get(0, 2), None ); // inside the slice, but not the matrix
+    assert_eq!( view.get(2, 0), None );
+    assert_eq!( view.row(1), Some(&flat[3..5]) );
+    assert_eq!( view.row(2), None );
+    assert_eq!( format!("{view:?}"), "[[0.0, 1.0], [3.0, 4.0]]" );
+    assert_eq!( format!("{counting:?}"), "FnMatrix(..)" );
+
+    // construction checks
+    assert!( MatrixView::<f32, 2>::new(&flat, 1).is_none() ); // overlapping rows
+    assert!( MatrixView::<f32, 2>::new(&flat, 6).is_some() );
+    assert!( MatrixView::<f32, 2>::new(&flat, 7).is_none() ); // second row runs past the end
+    assert!( MatrixView::<f32, 2>::new(&flat, usize::MAX).is_none() );
+    assert!( MatrixView::<f32, 3>::contiguous(&flat).is_none() );
+    assert!( MatrixView::<f32, 0>::new(&[], 0).is_some() );
+}
+
+#[test]
+#[cfg(feature = "rotation")]
+fn rotation_rate_conversions() {
+    let rates: [[f64; 3]; 5] = [
+        [0.0, 0.0, 1.0], [0.3, -0.8, 2.0], [-1.5, 0.25, 0.5], [0.7, 0.7, -0.1], [2.0, -1.0, 0.5],
+    ];
+    for rate in rates {
+        // tiny steps: the small angle approximation holds to first order
+        for dt in [1e-3, 1e-5] {
+            let rotation: [f64; 3] = quat::rate_to_rotation::<f64, [f64; 3]>(rate, dt);
+            let approximate: [f64; 3] = rotation.map(|angle| angle / dt);
+            let exact: [f64; 3] = quat::rotation_to_rate::<f64, [f64; 3]>(rotation, dt);
+            for axis in 0..3 {
+                assert!( (exact[axis] - rate[axis]).abs() < 1e-9, "{rate:?} at {dt}: {exact:?}" );
+                assert!( (approximate[axis] - rate[axis]).abs() < 10.0 * dt, "{rate:?} at {dt}: {approximate:?}" );
+            }
+        }
+
+        // large steps: exact composition, where the small angle shortcut is visibly off
+        let dt: f64 = 0.75;
+        let rotation: [f64; 3] = quat::rate_to_rotation::<f64, [f64; 3]>(rate, dt);
+        let exact: [f64; 3] = quat::rotation_to_rate::<f64, [f64; 3]>(rotation, dt);
+        for axis in 0..3 {
+            assert!( (exact[axis] - rate[axis]).abs() < 1e-9, "{rate:?}: {exact:?}" );
+        }
+        let speed: f64 = rate.iter().map(|x| x * x).sum::<f64>().sqrt();
+        let composed: [f64; 4] = quat::from_axis_angle::<f64, [f64; 4]>(rate, speed * dt);
+        let from_euler: [f64; 4] = quat::from_rotation::<f64, [f64; 4]>(rotation);
+        assert!( quat::is_near_by::<f64>(composed, from_euler, 1e-9) || quat::is_near_by::<f64>(composed, quat::neg::<f64, [f64; 4]>(from_euler), 1e-9) );
+        let integrated: [f64; 4] = quat::integrate_gyro::<f64, [f64; 4]>([1.0, 0.0, 0.0, 0.0], exact, dt);
+        assert!( quat::is_near_by::<f64>(integrated, composed, 1e-9) );
+        let shortcut: [f64; 3] = rotation.map(|angle| angle / dt);
+        if rate[0] != 0.0 || rate[1] != 0.0 {
+            assert!( (0..3).any(|axis| (shortcut[axis] - rate[axis]).abs() > 1e-3), "{rate:?}: {shortcut:?}" );
+        }
+    }
+
+    assert_eq!( quat::rate_to_rotation::<f64, [f64; 3]>([0.0; 3], 1.0), [0.0; 3] );
+    assert_eq!( quat::rate_to_rotation::<f64, [f64; 3]>([1.0, 2.0, 3.0], 0.0), [0.0; 3] );
+    assert_eq!( quat::rotation_to_rate::<f64, [f64; 3]>([0.0; 3], 0.5), [0.0; 3] );
+}
+
+#[test]
+#[cfg(all(feature = "trigonometry", feature = "test_helpers"))]
+fn fused_sin_cos_and_sinh_cosh() {
+    for q in f32_quats().step_by(3) {
+        let (sin, cos): ([f32; 4], [f32; 4]) = quat::sin_cos::<f32, [f32; 4]>(q);
+        let (sinh, cosh): ([f32; 4], [f32; 4]) = quat::sinh_cosh::<f32, [f32; 4]>(q);
+        // bit for bit, so NaNs and signed zeros match too
+        let same = |left: [f32; 4], right: [f32; 4]| left.map(f32::to_bits) == right.map(f32::to_bits);
+        assert!( same(sin, quat::sin::<f32, [f32; 4]>(q)), "sin_cos(q).0 == sin(q) for {q:?}" );
+        assert!( same(cos, quat::cos::<f32, [f32; 4]>(q)), "sin_cos(q).1 == cos(q) for {q:?}" );
+        assert!( same(sinh, quat::sinh::<f32, [f32; 4]>(q)), "sinh_cosh(q).0 == sinh(q) for {q:?}" );
+        assert!( same(cosh, quat::cosh::<f32, [f32; 4]>(q)), "sinh_cosh(q).1 == cosh(q) for {q:?}" );
+    }
+
+    // different output types
+    let (sin, cos): ((f32, [f32; 3]), [f64; 0]) = quat::sin_cos_split::<f32, (f32, [f32; 3]), [f64; 0]>(());
+    assert_eq!( (sin, cos), ((0.0, [0.0; 3]), []) );
+    let (sinh, cosh): ((), [f32; 4]) = quat::sinh_cosh_split::<f32, (), [f32; 4]>(());
+    assert_eq!( (sinh, cosh), ((), [1.0, 0.0, 0.0, 0.0]) );
+}
+
+#[test]
+#[ignore = "timing test, run with --release --ignored --nocapture"]
+#[cfg(all(feature = "std", feature = "trigonometry", feature = "test_helpers"))]
+fn timing_fused_sin_cos() {
+    use std::hint::black_box;
+
+    let quats: Vec<[f32; 4]> = f32_quats().step_by(61).take(65536).collect();
+
+    let fused = timer! {
+        run { for &q in black_box(&quats) { black_box(quat::sin_cos::<f32, [f32; 4]>(q)); } },
+        silent, repeat 20, silent,
+    };
+    let separate = timer! {
+        run { for &q in black_box(&quats) { black_box((quat::sin::<f32, [f32; 4]>(q), quat::cos::<f32, [f32; 4]>(q))); } },
+        silent, repeat 20, silent,
+    };
+    println!("     sin_cos: fused {fused:?}, separate {separate:?}");
+
+    let fused = timer! {
+        run { for &q in black_box(&quats) { black_box(quat::sinh_cosh::<f32, [f32; 4]>(q)); } },
+        silent, repeat 20, silent,
+    };
+    let separate = timer! {
+        run { for &q in black_box(&quats) { black_box((quat::sinh::<f32, [f32; 4]>(q), quat::cosh::<f32, [f32; 4]>(q))); } },
+        silent, repeat 20, silent,
+    };
+    println!("  sinh_cosh: fused {fused:?}, separate {separate:?}");
+
+    println!("
+    release (x86_64, default target cpu), 65536 quaternions:
+       sin_cos: fused ~`1.5`ms, separate ~`2.8`ms
+     sinh_cosh: fused ~`1.8`ms, separate ~`3.2`ms
+    ");
+}
+
+#[test]
+#[cfg(feature = "test_helpers")]
+fn unit_quat_literals() {
+    use quaternion_traits::unit_quat;
+    use structs::UnitQuat;
+    use traits::UnitQuaternionConsts;
+    use core::f32::consts::FRAC_1_SQRT_2;
+
+    const TURN_Z_90: UnitQuat<f32> = unit_quat!(FRAC_1_SQRT_2, 0.0, 0.0, FRAC_1_SQRT_2);
+    const HALF_TURN_X: UnitQuat<f64> = unit_quat!(f64; 0, 1, 0, 0);
+    const IDENTITY: UnitQuat<f32> = unit_quat!(1.0, 0.0, 0.0, 0.0,);
+
+    assert_eq!( Some(TURN_Z_90), UnitQuat::<f32>::new(FRAC_1_SQRT_2, 0.0, 0.0, FRAC_1_SQRT_2) );
+    assert_eq!( HALF_TURN_X, UnitQuat::<f64>::UNIT_I );
+    assert_eq!( IDENTITY, UnitQuat::<f32>::IDENTITY );
+    quaternion_traits::assert_quat_near!( f32; TURN_Z_90 * TURN_Z_90 * IDENTITY, UnitQuat::<f32>::UNIT_K );
+
+    // same tolerance as `UnitQuat::new`, `0.7071²` is `0.49999041`
+    #[allow(clippy::approx_constant)]
+    const ROUGH: f32 = 0.7071;
+    let rough: UnitQuat<f32> = unit_quat!(ROUGH, ROUGH, 0.0, 0.0);
+    assert_eq!( Some(rough), UnitQuat::<f32>::new(ROUGH, ROUGH, 0.0, 0.0) );
+}
+
+#[test]
+#[cfg(all(feature = "math_fns", feature = "test_helpers"))]
+fn hemisphere_alignment() {
+    use traits::QuaternionMethods;
+
+    let quats: Vec<[f64; 4]> = f32_quats()
+        .step_by(97)
+        .filter(|q: &[f32; 4]| !quat::eq::<f32>(q, ()))
+        .map(|q: [f32; 4]| quat::normalize::<f64, [f64; 4]>(q.map(|n: f32| n as f64)))
+        .collect();
+
+    for pair in quats.windows(2) {
+        let [a, b] = [pair[0], pair[1]];
+        let neg_b: [f64; 4] = quat::neg::<f64, [f64; 4]>(b);
+
+        assert_eq!( quat::abs_dot::<f64, f64>(a, b), quat::abs_dot::<f64, f64>(a, neg_b) );
+        assert_eq!( quat::abs_dot::<f64, f64>(a, b), quat::dot::<f64, f64>(a, b).abs() );
+        assert!( quat::dot::<f64, f64>(a, quat::align_to::<f64, [f64; 4]>(b, a)) >= 0.0 );
+        assert_eq!( quat::align_to::<f64, [f64; 4]>(b, a), quat::align_to::<f64, [f64; 4]>(neg_b, a) );
+        assert_eq!( QuaternionMethods::<f64>::align_to(b, a), quat::enforce_continuity::<f64, [f64; 4]>(a, b) );
+        assert_eq!( QuaternionMethods::<f64>::abs_dot(neg_b, a), quat::abs_dot::<f64, f64>(a, b) );
+
+        // `slerp_extrapolate` never flips `to`, after aligning it takes the shortest path for eather sign of `b`
+        for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            let shortest: [f64; 4] = quat::slerp_unchecked::<f64, [f64; 4]>(a, b, t);
+            for to in [b, neg_b] {
+                let aligned: [f64; 4] = quat::slerp_extrapolate::<f64, [f64; 4]>(a, quat::align_to::<f64, [f64; 4]>(to, a), t);
+                assert!( quat::is_near_by::<f64>(aligned, shortest, 1e-9), "{a:?} {to:?} at {t}: {aligned:?} != {shortest:?}" );
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg(all(feature = "matrix", feature = "test_helpers"))]
+fn matrix_slices() {
+    use traits::WrongLengthError;
+
+    // 90° around the Z axis, asymmetric so a transposed read would give the opposite rotation
+    let turn: [f64; 4] = quat::from_axis_angle::<f64, [f64; 4]>([0.0, 0.0, 1.0], core::f64::consts::FRAC_PI_2);
+    let rows: [[f64; 3]; 3] = quat::to_matrix_3::<f64, f64, [[f64; 3]; 3]>(turn);
+    let flat: Vec<f64> = rows.iter().flatten().copied().collect();
+    assert!( (flat[1] - 1.0).abs() < 1e-15 && (flat[3] + 1.0).abs() < 1e-15, "{flat:?}" );
+
+    let from_slice: [f64; 4] = quat::from_matrix_3_slice::<f64, [f64; 4]>(&flat).unwrap();
+    assert_eq!( from_slice, quat::from_matrix_3::<f64, f64, [f64; 4]>(rows) );
+    assert!( quat::is_near_by::<f64>(from_slice, turn, 1e-15) );
+    let transposed: Vec<f64> = (0..9).map(|n| flat[(n % 3) * 3 + n / 3]).collect();
+    assert!( quat::is_near_by::<f64>(quat::from_matrix_3_slice::<f64, [f64; 4]>(&transposed).unwrap(), quat::conj::<f64, [f64; 4]>(turn), 1e-15) );
+
+    let mut out: Vec<f64> = vec![f64::NAN; 9];
+    assert_eq!( quat::to_matrix_3_slice::<f64>(turn, &mut out), Ok(()) );
+    assert_eq!( out, flat );
+
+    let flat_4: Vec<f32> = quat::to_matrix_3::<f32, f32, [[f32; 3]; 3]>([0.5, 0.5, -0.5, 0.5])
+        .iter()
+        .flat_map(|row: &[f32; 3]| [row[0], row[1], row[2], 9.0])
+        .chain([1.0, 2.0, 3.0, 1.0])
+        .collect();
+    assert!( quat::is_near_by::<f32>(quat::from_matrix_4_slice::<f32, [f32; 4]>(&flat_4).unwrap(), [0.5, 0.5, -0.5, 0.5], 1e-6) );
+
+    // round trips
+    for q in f32_quats().step_by(89).filter(|q: &[f32; 4]| !quat::eq::<f32>(q, ())) {
+        let q: [f32; 4] = quat::normalize::<f32, [f32; 4]>(q);
+        let mut data: [f32; 9] = [0.0; 9];
+        quat::to_matrix_3_slice::<f32>(q, &mut data).unwrap();
+        let back: [f32; 4] = quat::from_matrix_3_slice::<f32, [f32; 4]>(&data).unwrap();
+        assert!( quat::is_near_by::<f32>(back, q, 1e-5) || quat::is_near_by::<f32>(back, quat::neg::<f32, [f32; 4]>(q), 1e-5), "{q:?} -> {back:?}" );
+    }
+
+    // wrong lengths are rejected
+    for len in [0, 3, 8, 10, 16] {
+        let data: Vec<f64> = vec![0.0; len];
+        assert_eq!( quat::from_matrix_3_slice::<f64, [f64; 4]>(&data), None );
+    }
+    for len in [0, 9, 12, 15, 17] {
+        let data: Vec<f64> = vec![0.0; len];
+        assert_eq!( quat::from_matrix_4_slice::<f64, [f64; 4]>(&data), None );
+    }
+    let mut long: [f64; 16] = [7.0; 16];
+    assert_eq!( quat::to_matrix_3_slice::<f64>(turn, &mut long), Err(WrongLengthError { expected: 9, found: 16 }) );
+    assert_eq!( long, [7.0; 16] );
+    assert_eq!( quat::to_matrix_3_slice::<f64>(turn, &mut []), Err(WrongLengthError { expected: 9, found: 0 }) );
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "test_helpers"))]
+fn rotation_keys() {
+    for q in f32_quats().step_by(13) {
+        let key: u64 = quat::rotation_key::<f32>(q, 1000);
+        assert_eq!( key, quat::rotation_key::<f32>(quat::neg::<f32, [f32; 4]>(q), 1000), "{q:?}" );
+        assert_eq!( quat::rotation_key_default::<f32>(q), quat::rotation_key::<f32>(q, quat::DEFAULT_ROTATION_KEY_CELLS) );
+        assert!( key >> 60 < 4 && (key >> 40) & 0xFFFFF < 1000 && (key >> 20) & 0xFFFFF < 1000 && key & 0xFFFFF < 1000, "{q:?}: {key:x}" );
+    }
+
+    // a quaternion in the middle of the cells `(600, 450, 520)`, with `r` as the largest component
+    let cells: u32 = 1000;
+    let width: f64 = 2.0_f64.sqrt() / cells as f64;
+    let [i, j, k] = [600, 450, 520].map(|cell: u32| (cell as f64 + 0.5) * width - core::f64::consts::FRAC_1_SQRT_2);
+    let center: [f64; 4] = [(1.0 - i * i - j * j - k * k).sqrt(), i, j, k];
+    let key: u64 = quat::rotation_key::<f64>(center, cells);
+    assert_eq!( key, (600 << 40) | (450 << 20) | 520 );
+
+    let axes: [[f64; 3]; 4] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [0.6, -0.48, 0.64]];
+    for axis in axes {
+        // a rotation by `angle` moves the quaternion by `angle / 2`
+        let nudge = |cells_moved: f64| quat::mul::<f64, [f64; 4]>(center, quat::from_axis_angle::<f64, [f64; 4]>(axis, 2.0 * cells_moved * width));
+        for small in [0.01, 0.1, 0.25, 0.45] {
+            assert_eq!( quat::rotation_key::<f64>(nudge(small), cells), key, "{axis:?} by {small} cells" );
+            assert_eq!( quat::rotation_key::<f64>(nudge(-small), cells), key, "{axis:?} by -{small} cells" );
+        }
+        for large in [3.0, 5.0, 20.0] {
+            assert_ne!( quat::rotation_key::<f64>(nudge(large), cells), key, "{axis:?} by {large} cells" );
+        }
+    }
+
+    // the scale of the quaternion doesn't matter
+    assert_eq!( quat::rotation_key::<f64>(quat::scale::<f64, [f64; 4]>(center, 3.5), cells), key );
+    // a single cell can't tell anything apart
+    assert_eq!( quat::rotation_key::<f32>([0.5, -0.5, 0.5, 0.5], 0), quat::rotation_key::<f32>([0.5, 0.5, 0.5, -0.5], 1) );
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "std"))]
+fn rotation_keys_of_any_axis() {
+    use structs::Std;
+
+    for angle in [0.0_f32, 0.3, 1.0, 2.5, -3.0] {
+        let q: [f32; 4] = quat::from_axis_angle::<f32, [f32; 4]>([0.6, -0.48, 0.64], angle);
+        let wrapped: [Std<f32>; 4] = q.map(Std);
+        assert_eq!( quat::rotation_key::<Std<f32>>(wrapped, 1000), quat::rotation_key::<f32>(q, 1000), "{q:?}" );
+        assert_eq!( quat::rotation_key_default::<Std<f32>>(wrapped), quat::rotation_key_default::<f32>(q), "{q:?}" );
+    }
+    // NaN and values past the last cell end up in the first and last cell
+    assert_eq!( quat::rotation_key::<f32>([f32::NAN; 4], 1000) & 0xFFFFF, 0 );
+    assert_eq!( quat::rotation_key::<f32>([1.0, 0.0, 0.0, 1.0], 1000) & 0xFFFFF, 999 );
+}
+
+#[test]
+#[cfg(feature = "rotation")]
+fn cube_rotations() {
+    use structs::CubeRotation;
+
+    let quats: Vec<[f64; 4]> = CubeRotation::ALL.iter().map(|rotation: &CubeRotation| rotation.to_quat::<f64, [f64; 4]>()).collect();
+
+    for (index, rotation) in CubeRotation::ALL.into_iter().enumerate() {
+        assert_eq!( rotation.index() as usize, index );
+        assert_eq!( CubeRotation::new(index as u8), Some(rotation) );
+
+        // round trips, also through the negative and through f32
+        assert!( quat::is_normalized::<f64>(quats[index]) );
+        assert_eq!( CubeRotation::from_quat::<f64>(quats[index]), Some(rotation) );
+        assert_eq!( CubeRotation::from_quat::<f64>(quat::neg::<f64, [f64; 4]>(quats[index])), Some(rotation) );
+        assert_eq!( CubeRotation::from_quat::<f32>(rotation.to_quat::<f32, [f32; 4]>()), Some(rotation) );
+        let noisy: [f64; 4] = quat::mul::<f64, [f64; 4]>(quats[index], quat::from_axis_angle::<f64, [f64; 4]>([0.3, 0.4, 1.2], 1e-6));
+        assert_eq!( CubeRotation::from_quat::<f64>(noisy), Some(rotation) );
+        assert_eq!( CubeRotation::from_quat_by::<f64>(noisy, 1e-9), None );
+
+        // every rotation is a different one
+        for other in &quats[..index] {
+            assert!( quat::abs_dot::<f64, f64>(quats[index], other) < 0.75 );
+        }
+
+        assert_eq!( rotation * rotation.inverse(), CubeRotation::IDENTITY );
+        assert_eq!( rotation.inverse() * rotation, CubeRotation::IDENTITY );
+        assert_eq!( rotation * CubeRotation::IDENTITY, rotation );
+        let inverse: [f64; 4] = rotation.inverse().to_quat::<f64, [f64; 4]>();
+        assert!( quat::is_near_by::<f64>(inverse, quat::align_to::<f64, [f64; 4]>(quat::conj::<f64, [f64; 4]>(quats[index]), inverse), 1e-15) );
+    }
+
+    // the table is closed and matches quaternion multiplication, every row is a permutation
+    for a in CubeRotation::ALL {
+        let mut seen: [bool; 24] = [false; 24];
+        for b in CubeRotation::ALL {
+            let composed: CubeRotation = a * b;
+            assert!( composed.index() < 24 );
+            assert_eq!( a.compose(b), composed );
+            seen[composed.index() as usize] = true;
+            let product: [f64; 4] = quat::mul::<f64, [f64; 4]>(a, b);
+            assert!( quat::is_near_by::<f64>(quat::align_to::<f64, [f64; 4]>(product, composed), composed.to_quat::<f64, [f64; 4]>(), 1e-15), "{a:?} * {b:?}" );
+        }
+        assert!( seen.iter().all(|&seen: &bool| seen), "{a:?}" );
+    }
+
+    // associativity
+    for a in CubeRotation::ALL.into_iter().step_by(5) {
+        for b in CubeRotation::ALL {
+            for c in CubeRotation::ALL {
+                assert_eq!( (a * b) * c, a * (b * c) );
+            }
+        }
+    }
+
+    assert_eq!( CubeRotation::new(24), None );
+    assert_eq!( CubeRotation::from_quat::<f64>([0.0; 4]), None );
+    assert_eq!( CubeRotation::from_quat::<f64>([2.0, 0.0, 0.0, 0.0]), None );
+    assert_eq!( CubeRotation::from_quat::<f64>(quat::from_axis_angle::<f64, [f64; 4]>([1.0, 1.0, 0.0], 1.0)), None );
+}
+
+#[test]
+fn parallel_and_perpendicular_vectors() {
+    use core::f64::consts::{PI, FRAC_PI_2};
+
+    let directions: [[f64; 3]; 4] = [[1.0, 0.0, 0.0], [1.0, 2.0, 3.0], [-0.3, 0.8, 0.1], [5.0, -5.0, 1e-3]];
+    for a in directions {
+        // any vector perpendicular to `a`
+        let side: [f64; 3] = if a[0].abs() < 0.9 { [0.0, a[2], -a[1]] } else { [a[1], -a[0], 0.0] };
+        for magnitude in [1e-20, 1e-10, 1.0, 1e10, 1e20] {
+            for other_magnitude in [1e-20, 1.0, 1e20] {
+                let scaled = |v: [f64; 3], by: f64| v.map(|n: f64| n * by);
+                let same: [f64; 3] = scaled(a, magnitude);
+                let opposite: [f64; 3] = scaled(a, -other_magnitude);
+                let perpendicular: [f64; 3] = scaled(side, other_magnitude);
+
+                assert!( quat::are_parallel::<f64>(same, opposite), "{a:?} at {magnitude}, {other_magnitude}" );
+                assert!( !quat::are_perpendicular::<f64>(same, opposite) );
+                assert!( quat::are_perpendicular::<f64>(same, perpendicular), "{a:?} at {magnitude}, {other_magnitude}" );
+                assert!( !quat::are_parallel::<f64>(same, perpendicular) );
+                assert!( (quat::angle_between_vectors::<f64, f64>(same, opposite) - PI).abs() < 1e-12 );
+                assert!( (quat::angle_between_vectors::<f64, f64>(same, perpendicular) - FRAC_PI_2).abs() < 1e-12 );
+                assert!( quat::angle_between_vectors::<f64, f64>(same, scaled(a, other_magnitude)) < 1e-12 );
+
+                // the tolerance is relative, it only depends on the angle
+                for (tilt, parallel) in [(1e-9, true), (1e-7, true), (1e-3, false), (0.1, false)] {
+                    let tilted: [f64; 3] = core::array::from_fn(|n| (a[n] + side[n] * tilt) * other_magnitude);
+                    assert_eq!( quat::are_parallel::<f64>(same, tilted), parallel, "{a:?} tilted by {tilt} at {magnitude}, {other_magnitude}" );
+                    let angle: f64 = quat::angle_between_vectors::<f64, f64>(same, tilted);
+                    let expected: f64 = (tilt * (side.iter().map(|n| n * n).sum::<f64>() / a.iter().map(|n| n * n).sum::<f64>()).sqrt()).atan();
+                    assert!( (angle - expected).abs() <= 1e-14 + 1e-9 * expected, "{angle} != {expected}" );
+                }
+            }
+        }
+    }
+
+    // f32 doesn't overflow or underflow either
+    assert!( quat::are_parallel::<f32>([1e20, 2e20, 3e20], [-1e-20, -2e-20, -3e-20]) );
+    assert!( quat::are_perpendicular::<f32>([1e20, 0.0, 0.0], [0.0, 1e-20, 1e-20]) );
+    assert!( (quat::angle_between_vectors::<f32, f32>([1e20, 0.0, 0.0], [0.0, 1e-20, 0.0]) - core::f32::consts::FRAC_PI_2).abs() < 1e-6 );
+
+    // exact cases
+    assert!( quat::are_parallel::<f32>([0.0, 0.0, 2.0], [0.0, 0.0, -7.0]) );
+    assert!( quat::are_perpendicular::<f32>([0.0, 0.0, 2.0], [3.0, 0.0, 0.0]) );
+    assert_eq!( quat::angle_between_vectors::<f32, f32>([0.0, 0.0, 2.0], [0.0, 0.0, 7.0]), 0.0 );
+    assert_eq!( quat::angle_between_vectors::<f32, f32>([0.0, 0.0, 2.0], [0.0, 0.0, -7.0]), core::f32::consts::PI );
+    assert_eq!( quat::angle_between_vectors::<f32, f32>([0.0, 0.0, 2.0], [0.0, 1.0, 0.0]), core::f32::consts::FRAC_PI_2 );
+    // the zero vector
+    assert!( quat::are_parallel::<f32>([0.0; 3], [1.0, 2.0, 3.0]) );
+    assert!( quat::are_perpendicular::<f32>([1.0, 2.0, 3.0], [0.0; 3]) );
+    assert!( quat::angle_between_vectors::<f32, f32>([0.0; 3], [1.0, 2.0, 3.0]).is_nan() );
+
+    // the quaternion versions only look at the vector parts
+    assert!( quat::vectors_parallel::<f64>([100.0, 1.0, 2.0, 3.0], [-3.0, -1e-20, -2e-20, -3e-20]) );
+    assert!( quat::vectors_perpendicular::<f64>([1.0, 1.0, 0.0, 0.0], [1.0, 0.0, 1e20, 0.0]) );
+    assert_eq!( quat::vector_angle_between::<f64, f64>([1.0, 1.0, 0.0, 0.0], [1.0, 0.0, 1.0, 0.0]), FRAC_PI_2 );
+}
+
+#[test]
+fn axis_integer_and_f32_constructors() {
+    use traits::Axis;
+
+    // round to nearest, ties to even
+    assert_eq!( <f32 as Axis>::from_u32(16_777_217), 16_777_216.0 );
+    assert_eq!( <f32 as Axis>::from_u32(16_777_219), 16_777_220.0 );
+    assert_eq!( <f32 as Axis>::from_i32(-16_777_219), -16_777_220.0 );
+    assert_eq!( <f32 as Axis>::from_i32(i32::MIN), -2_147_483_648.0 );
+    assert_eq!( <f32 as Axis>::from_u32(u32::MAX), 4_294_967_296.0 );
+    assert_eq!( <f64 as Axis>::from_usize((1 << 53) + 1), 9_007_199_254_740_992.0 );
+    assert_eq!( <f64 as Axis>::from_usize((1 << 53) + 3), 9_007_199_254_740_996.0 );
+    assert_eq!( <f32 as Axis>::from_f64(1.0 + f32::EPSILON as f64 / 2.0), 1.0 );
+    assert_eq!( <f32 as Axis>::from_f64(1.0 + f32::EPSILON as f64 * 1.5), 1.0 + 2.0 * f32::EPSILON );
+
+    // `usize` straight to `f32` rounds once, through `f64` it would round twice:
+    // `2^60 + 2^36 + 1` is just above the halfway point inbetween two `f32`s,
+    // but going through `f64` first rounds it down onto the halfway point, which then ties to even
+    #[cfg(target_pointer_width = "64")]
+    {
+        let n: usize = (1 << 60) + (1 << 36) + 1;
+        assert_eq!( <f32 as Axis>::from_usize(n), ((1_u64 << 60) + (1 << 37)) as f32 );
+        assert_eq!( <f32 as Axis>::from_f64(n as f64), (1_u64 << 60) as f32 );
+    }
+
+    // every f32 is exact
+    for x in [0.1_f32, -1.0e-40, f32::MAX, f32::MIN_POSITIVE, core::f32::consts::PI] {
+        assert_eq!( <f64 as Axis>::from_f32(x), x as f64 );
+        assert_eq!( <f32 as Axis>::from_f32(x).to_bits(), x.to_bits() );
+    }
+    assert!( <f64 as Axis>::from_f32(f32::NAN).is_nan() );
+
+    #[cfg(feature = "std")]
+    {
+        use structs::Std;
+        assert_eq!( <Std<f32> as Axis>::from_u32(16_777_217), Std(16_777_216.0) );
+        assert_eq!( <Std<f64> as Axis>::from_i32(-7), Std(-7.0) );
+        assert_eq!( <Std<f64> as Axis>::from_f32(0.1), Std(0.1_f32 as f64) );
+        assert_eq!( <Std<f32> as Axis>::from_usize(1000), Std(1000.0) );
+    }
+}
+
+#[test]
+fn blas_vector_space_ops() {
+    use quat::blas;
+
+    let x: [f64; 4] = [1.5, -2.0, 0.25, 3.0];
+    let y: (f64, [f64; 3]) = (0.5, [1.0, -1.0, 2.0]);
+
+    let expected: [f64; 4] = quat::add::<f64, [f64; 4]>(quat::scale::<f64, [f64; 4]>(x, -3.0), y);
+    assert_eq!( blas::axpy::<f64, [f64; 4]>(-3.0, x, y), expected );
+    assert_eq!( blas::axpy::<f64, [f64; 4]>(0.0, x, y), quat::convert_quat::<f64, [f64; 4]>(y) );
+
+    // `x` and `y` can be the same quaternion, and the result can go back into it
+    let mut z: [f64; 4] = x;
+    z = blas::axpy::<f64, [f64; 4]>(2.0, &z, &z);
+    assert_eq!( z, quat::scale::<f64, [f64; 4]>(x, 3.0) );
+    assert_eq!( blas::axpy::<f64, [f64; 4]>(-1.0, &x, &x), [0.0; 4] );
+
+    assert_eq!( blas::dot::<f64, f64>(x, y), quat::dot::<f64, f64>(x, y) );
+    assert_eq!( blas::norm2::<f64, f64>(x), quat::abs::<f64, f64>(x) );
+    assert!( (blas::norm2::<f64, f64>(x) * blas::norm2::<f64, f64>(x) - blas::dot::<f64, f64>(x, x)).abs() < 1e-12 );
+
+    let mut copy: (f32, f32, f32, f32) = (0.0, 0.0, 0.0, 0.0);
+    blas::copy_into::<f32, (f32, f32, f32, f32)>([1.0_f32, 2.0, 3.0, 4.0], &mut copy);
+    assert_eq!( copy, (1.0, 2.0, 3.0, 4.0) );
+
+    let mut a: [f64; 4] = x;
+    let mut b: (f64, [f64; 3]) = y;
+    blas::swap::<f64, _, _>(&mut a, &mut b);
+    assert_eq!( (a, b), ([0.5, 1.0, -1.0, 2.0], (1.5, [-2.0, 0.25, 3.0])) );
+    blas::swap::<f64, _, _>(&mut a, &mut b);
+    assert_eq!( (a, b), (x, y) );
+}
+
+/// A fixed xorshift sequence of `count` quaternions, with components in `-10..10`.
+fn xorshift_quats(count: usize) -> Vec<[f32; 4]> {
+    let mut state: u32 = 0x9E37_79B9;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        (state as f32 / u32::MAX as f32) * 20.0 - 10.0
+    };
+    (0..count).map(|_| [next(), next(), next(), next()]).collect()
+}
+
+#[test]
+#[cfg(all(feature = "math_fns", feature = "rotation"))]
+fn slice_functions() {
+    let mut quats: Vec<[f32; 4]> = xorshift_quats(2000);
+    quats.extend([[0.0; 4], [-0.0, 0.0, 0.0, 0.0], [1.0, 0.0, 0.0, 0.0], [0.0, 0.0, -3.5, 0.0], [f32::MAX, 1.0, 0.0, 0.0]]);
+    let others: Vec<[f32; 4]> = quats.iter().rev().copied().collect();
+
+    let mut normalized: Vec<[f32; 4]> = quats.clone();
+    quat::normalize_slice::<f32>(&mut normalized);
+    for (quat, normalized) in quats.iter().zip(&normalized) {
+        assert_eq!( quat::normalize::<f32, [f32; 4]>(quat).map(f32::to_bits), normalized.map(f32::to_bits) );
+    }
+
+    let mut products: Vec<[f32; 4]> = vec![[0.0; 4]; quats.len()];
+    quat::mul_slices::<f32>(&quats, &others, &mut products);
+    for n in 0..quats.len() {
+        assert_eq!( quat::mul::<f32, [f32; 4]>(quats[n], others[n]).map(f32::to_bits), products[n].map(f32::to_bits) );
+    }
+
+    let units: Vec<[f32; 4]> = normalized.iter().filter(|q| **q != [0.0; 4]).copied().collect();
+    let other_units: Vec<[f32; 4]> = units.iter().rev().copied().collect();
+    let mut interpolated: Vec<[f32; 4]> = vec![[0.0; 4]; units.len()];
+    quat::slerp_slices::<f32>(&units, &other_units, 0.3, &mut interpolated);
+    for n in 0..units.len() {
+        assert_eq!( quat::slerp_unchecked::<f32, [f32; 4]>(units[n], other_units[n], 0.3).map(f32::to_bits), interpolated[n].map(f32::to_bits) );
+    }
+
+    let points: Vec<[f32; 3]> = quats.iter().map(|q| [q[1], q[2], q[3]]).collect();
+    for rotation in xorshift_quats(16) {
+        let mut rotated: Vec<[f32; 3]> = points.clone();
+        quat::rotate_points::<f32>(rotation, &mut rotated);
+        for (point, rotated) in points.iter().zip(&rotated) {
+            assert_eq!( quat::point_rotation::<f32, [f32; 3]>(rotation, point).map(f32::to_bits), rotated.map(f32::to_bits) );
+        }
+    }
+
+    quat::normalize_slice::<f32>(&mut []);
+    quat::mul_slices::<f32>(&[], &[], &mut []);
+}
+
+#[test]
+#[should_panic(expected = "Got 2 and 2 input quaternions, but the output holds 3")]
+fn slice_functions_length_mismatch() {
+    let mut out: [[f32; 4]; 3] = [[0.0; 4]; 3];
+    quat::mul_slices::<f32>(&[[1.0; 4]; 2], &[[1.0; 4]; 2], &mut out);
+}
+
+#[test]
+#[cfg(all(feature = "rayon", feature = "rotation"))]
+fn parallel_slice_functions() {
+    // lengths around the chunk size, so both the sequential fallback and the split get checked
+    for len in [0, 1, 1000, quat::par::MIN_CHUNK_LEN, quat::par::MIN_CHUNK_LEN + 1, 5 * quat::par::MIN_CHUNK_LEN + 17] {
+        let quats: Vec<[f32; 4]> = xorshift_quats(2 * len);
+        let (left, right) = quats.split_at(len);
+
+        let mut sequential: Vec<[f32; 4]> = left.to_vec();
+        let mut parallel: Vec<[f32; 4]> = left.to_vec();
+        quat::normalize_slice::<f32>(&mut sequential);
+        quat::par::normalize_slice::<f32>(&mut parallel);
+        assert_eq!( parallel, sequential );
+
+        let units: Vec<[f32; 4]> = sequential;
+        let mut other_units: Vec<[f32; 4]> = right.to_vec();
+        quat::normalize_slice::<f32>(&mut other_units);
+
+        let mut sequential: Vec<[f32; 4]> = vec![[0.0; 4]; len];
+        let mut parallel: Vec<[f32; 4]> = vec![[0.0; 4]; len];
+        quat::mul_slices::<f32>(left, right, &mut sequential);
+        quat::par::mul_slices::<f32>(left, right, &mut parallel);
+        assert_eq!( parallel, sequential );
+
+        quat::slerp_slices::<f32>(&units, &other_units, 0.7, &mut sequential);
+        quat::par::slerp_slices::<f32>(&units, &other_units, 0.7, &mut parallel);
+        assert_eq!( parallel, sequential );
+
+        let points: Vec<[f32; 3]> = right.iter().map(|q| [q[1], q[2], q[3]]).collect();
+        let mut sequential: Vec<[f32; 3]> = points.clone();
+        let mut parallel: Vec<[f32; 3]> = points;
+        quat::rotate_points::<f32>([0.5, -1.0, 2.0, 0.25], &mut sequential);
+        quat::par::rotate_points::<f32>([0.5, -1.0, 2.0, 0.25], &mut parallel);
+        assert_eq!( parallel, sequential );
+    }
+}
+
+#[test]
+#[cfg(all(feature = "rayon", feature = "rotation"))]
+fn parallel_slice_functions_on_thread_pools() {
+    // the same results on pools of any size, including ones with more threads than chunks
+    let len: usize = 9 * quat::par::MIN_CHUNK_LEN + 5;
+    let quats: Vec<[f32; 4]> = xorshift_quats(2 * len);
+    let (left, right) = quats.split_at(len);
+    let points: Vec<[f32; 3]> = right.iter().map(|q| [q[1], q[2], q[3]]).collect();
+
+    let mut units: Vec<[f32; 4]> = left.to_vec();
+    let mut other_units: Vec<[f32; 4]> = right.to_vec();
+    quat::normalize_slice::<f32>(&mut units);
+    quat::normalize_slice::<f32>(&mut other_units);
+    let mut products: Vec<[f32; 4]> = vec![[0.0; 4]; len];
+    let mut slerped: Vec<[f32; 4]> = vec![[0.0; 4]; len];
+    let mut rotated: Vec<[f32; 3]> = points.clone();
+    quat::mul_slices::<f32>(left, right, &mut products);
+    quat::slerp_slices::<f32>(&units, &other_units, 0.3, &mut slerped);
+    quat::rotate_points::<f32>([0.5, -1.0, 2.0, 0.25], &mut rotated);
+
+    for threads in [1, 2, 4, 8] {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+        pool.install(|| {
+            assert_eq!( rayon::current_num_threads(), threads );
+
+            let mut parallel: Vec<[f32; 4]> = left.to_vec();
+            quat::par::normalize_slice::<f32>(&mut parallel);
+            assert_eq!( parallel, units );
+
+            quat::par::mul_slices::<f32>(left, right, &mut parallel);
+            assert_eq!( parallel, products );
+
+            quat::par::slerp_slices::<f32>(&units, &other_units, 0.3, &mut parallel);
+            assert_eq!( parallel, slerped );
+
+            let mut parallel: Vec<[f32; 3]> = points.clone();
+            quat::par::rotate_points::<f32>([0.5, -1.0, 2.0, 0.25], &mut parallel);
+            assert_eq!( parallel, rotated );
+        });
+    }
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+#[should_panic(expected = "Mismatched slice lengths in mul_slices")]
+fn parallel_slice_functions_length_mismatch() {
+    let left: Vec<[f32; 4]> = xorshift_quats(3 * quat::par::MIN_CHUNK_LEN);
+    let mut out: Vec<[f32; 4]> = vec![[0.0; 4]; left.len() - 1];
+    quat::par::mul_slices::<f32>(&left, &left, &mut out);
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+#[ignore = "timing test, run with --release --ignored --nocapture --features rayon"]
+fn timing_parallel_slice_functions() {
+    use std::hint::black_box;
+
+    let cores: usize = std::thread::available_parallelism().map_or(1, |cores| cores.get());
+    let quats: Vec<[f32; 4]> = xorshift_quats(1 << 21);
+    let (left, right) = quats.split_at(1 << 20);
+    let mut out: Vec<[f32; 4]> = vec![[0.0; 4]; left.len()];
+
+    let sequential = timer! {
+        run { quat::mul_slices::<f32>(black_box(left), black_box(right), black_box(&mut out)) },
+        silent, repeat 20, silent,
+    };
+    println!("  mul_slices: sequential {sequential:?}");
+    let sequential = timer! {
+        run { let mut quats = left.to_vec(); quat::normalize_slice::<f32>(black_box(&mut quats)) },
+        silent, repeat 20, silent,
+    };
+    println!("  normalize_slice: sequential {sequential:?}");
+
+    // pools of a fixed size, so the numbers don't depend on `RAYON_NUM_THREADS`
+    for threads in [1, 2, 4, 8] {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+        let parallel = pool.install(|| timer! {
+            run { quat::par::mul_slices::<f32>(black_box(left), black_box(right), black_box(&mut out)) },
+            silent, repeat 20, silent,
+        });
+        println!("  mul_slices: {threads} threads {parallel:?}");
+        let parallel = pool.install(|| timer! {
+            run { let mut quats = left.to_vec(); quat::par::normalize_slice::<f32>(black_box(&mut quats)) },
+            silent, repeat 20, silent,
+        });
+        println!("  normalize_slice: {threads} threads {parallel:?}");
+    }
+
+    if cores < 4 {
+        println!("  only {cores} cores available, pools bigger than that only show the overhead of the split");
+    }
+
+    // recorded on a single core, so they show the overhead, not the scaling
+    println!("
+    release (x86_64, default target cpu, 1 core), 2^20 quaternions:
+           mul_slices: sequential ~`4`ms, 1 thread ~`4`ms, 4 threads ~`4`ms
+      normalize_slice: sequential ~`9`ms, 1 thread ~`9`ms, 4 threads ~`9`ms
+    ");
+}
+
+#[test]
+#[cfg(feature = "math_fns")]
+fn eased_slerp() {
+    use quat::ease;
+
+    let easings: [fn(f64) -> f64; 5] = [ease::smoothstep, ease::smootherstep, ease::ease_in_out_cubic, |at| at, |at| at * at];
+    let quats: Vec<[f64; 4]> = xorshift_quats(64).into_iter()
+        .map(|q| quat::normalize::<f64, [f64; 4]>(q.map(f64::from)))
+        .collect();
+
+    for pair in quats.chunks(2) {
+        let (from, to) = (pair[0], pair[1]);
+        let shortest_to: [f64; 4] = if quat::dot::<f64, f64>(from, to) < 0.0 { quat::neg::<f64, [f64; 4]>(to) } else { to };
+
+        for ease in easings {
+            assert_eq!( ease(0.0), 0.0 );
+            assert_eq!( ease(1.0), 1.0 );
+
+            // exact endpoints, and `at` gets clamped before easing
+            for at in [0.0, -0.5, -f64::INFINITY] {
+                assert_eq!( quat::slerp_eased::<f64, [f64; 4]>(from, to, at, ease), from );
+            }
+            for at in [1.0, 1.5, f64::INFINITY] {
+                assert_eq!( quat::slerp_eased::<f64, [f64; 4]>(from, to, at, ease), shortest_to );
+            }
+
+            for at in [0.1, 0.5, 0.9] {
+                assert_eq!(
+                    quat::slerp_eased::<f64, [f64; 4]>(from, to, at, ease),
+                    quat::slerp_unchecked::<f64, [f64; 4]>(from, to, ease(at)),
+                );
+            }
+        }
+
+        // the speed at the ends by finite differences (the chord length is the arc length for small steps)
+        let speed = |ease: fn(f64) -> f64, at: f64, step: f64| -> f64 {
+            let here: [f64; 4] = quat::slerp_eased::<f64, [f64; 4]>(from, to, at, ease);
+            let there: [f64; 4] = quat::slerp_eased::<f64, [f64; 4]>(from, to, at + step, ease);
+            quat::dist_euclid::<f64, f64>(here, there) / step.abs()
+        };
+        let linear: f64 = speed(|at| at, 0.0, 1e-4);
+        if linear < 1e-3 { continue }
+        for (ease, name) in [(ease::smoothstep as fn(f64) -> f64, "smoothstep"), (ease::smootherstep, "smootherstep"), (ease::ease_in_out_cubic, "ease_in_out_cubic")] {
+            assert!( speed(ease, 0.0, 1e-4) < linear * 1e-3, "{name} {from:?} {to:?}" );
+            assert!( speed(ease, 1.0, -1e-4) < linear * 1e-3, "{name} {from:?} {to:?}" );
+        }
+        assert!( (speed(|at| at, 1.0, -1e-4) - linear).abs() < linear * 1e-3 );
+    }
+}
+
+/// A long chain of `mul`, `exp`, `slerp_unchecked` and `to_rotation`,
+/// giving the final quaternion and its roll, pitch and yaw.
+#[cfg(feature = "rotation")]
+fn deterministic_chain<Num: traits::Axis>() -> [Num; 7] {
+    let num = |float: f64| Num::from_f64(float);
+
+    let step: [Num; 4] = quat::exp::<Num, [Num; 4]>([num(0.0), num(0.01), num(0.02), num(-0.015)]);
+    let target: [Num; 4] = quat::exp::<Num, [Num; 4]>([num(0.0), num(0.3), num(-0.2), num(0.1)]);
+    let mut q: [Num; 4] = quat::normalize::<Num, [Num; 4]>([num(0.9), num(0.1), num(-0.3), num(0.2)]);
+    for _ in 0..500 {
+        q = quat::mul::<Num, [Num; 4]>(q, step);
+        q = quat::slerp_unchecked::<Num, [Num; 4]>(q, target, num(0.05));
+        q = quat::normalize::<Num, [Num; 4]>(q);
+    }
+    let [roll, pitch, yaw]: [Num; 3] = quat::to_rotation::<Num, [Num; 3]>(q);
+    [q[0], q[1], q[2], q[3], roll, pitch, yaw]
+}
+
+/// The bits of [`deterministic_chain`] with the libm methods, the same on every platform.
+const CHAIN_F32: [u32; 7] = [0x3f5b2569, 0x3ee29354, 0x3e88187a, 0xbcd9e4d4, 0x3f8145ea, 0x3eff897f, 0x3e5f0631];
+const CHAIN_F64: [u64; 7] = [
+    0x3feb64ad6f83029a, 0x3fdc52688dcd5ca0, 0x3fd1030f1df64961, 0xbf9b3ca24138cd6a,
+    0x3ff028bbf2752693, 0x3fdff13063321136, 0x3fcbe0c198988581,
+];
+
+#[test]
+#[cfg(all(feature = "rotation", any(not(feature = "std_axis_for_primitives"), feature = "deterministic")))]
+fn deterministic_golden_bits() {
+    assert_eq!( deterministic_chain::<f32>().map(f32::to_bits), CHAIN_F32 );
+    assert_eq!( deterministic_chain::<f64>().map(f64::to_bits), CHAIN_F64 );
+
+    #[cfg(feature = "deterministic")]
+    {
+        use structs::Std;
+        assert_eq!( deterministic_chain::<Std<f32>>().map(|Std(axis)| axis.to_bits()), CHAIN_F32 );
+        assert_eq!( deterministic_chain::<Std<f64>>().map(|Std(axis)| axis.to_bits()), CHAIN_F64 );
+    }
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "test_helpers"))]
+fn axis_angle_interpolation() {
+    let keys: [([f64; 3], f64); 5] = [
+        ([0.0, 0.0, 1.0], 0.5),
+        ([1.0, 2.0, -0.5], 2.0),
+        ([0.0, -3.0, 0.0], -1.25),
+        ([-1.0, 1.0, 1.0], 3.0),
+        ([0.5, 0.0, 0.0], 0.1),
+    ];
+    let unit = |axis: [f64; 3]| {
+        let length: f64 = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
+        axis.map(|x| x / length)
+    };
+
+    for (axis_a, angle_a) in keys {
+        for (axis_b, angle_b) in keys {
+            let from: [f64; 4] = quat::from_axis_angle::<f64, [f64; 4]>(axis_a, angle_a);
+            let to: [f64; 4] = quat::from_axis_angle::<f64, [f64; 4]>(axis_b, angle_b);
+
+            for at in [0.0, 0.2, 0.5, 0.9, 1.0] {
+                // converting to quaternions outside of the function
+                let slerped: [f64; 4] = quat::slerp_unchecked::<f64, [f64; 4]>(from, to, at);
+                let nlerped: [f64; 4] = quat::normalize::<f64, [f64; 4]>(quat::lerp::<f64, [f64; 4]>(from, to, at));
+
+                let (axis, angle) = quat::slerp_axis_angle::<f64>(axis_a, angle_a, axis_b, angle_b, at);
+                assert!( (-core::f64::consts::PI..=core::f64::consts::PI).contains(&angle), "{angle}" );
+                assert!( (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2] - 1.0).abs() < 1e-12 );
+                assert!( axis.iter().zip(unit(axis_a)).map(|(x, y)| x * y).sum::<f64>() >= -1e-12 );
+                assert_same_rotation!(f64; quat::from_axis_angle::<f64, [f64; 4]>(axis, angle), slerped, 1e-9);
+
+                let (axis, angle) = quat::nlerp_axis_angle::<f64>(axis_a, angle_a, axis_b, angle_b, at);
+                assert_same_rotation!(f64; quat::from_axis_angle::<f64, [f64; 4]>(axis, angle), nlerped, 1e-9);
+            }
+        }
+    }
+
+    // an identity endpoint gives the axis of the other one instead of NaN or a zero axis
+    for identity_axis in [[0.0; 3], [0.0, 1.0, 0.0]] {
+        for at in [0.0, 0.3, 1.0] {
+            let (axis, angle) = quat::slerp_axis_angle::<f64>(identity_axis, 0.0, [0.0, 0.0, 2.0], 1.0, at);
+            assert!( axis[0] == 0.0 && axis[1] == 0.0 && (axis[2] - 1.0).abs() < 1e-12, "{axis:?}" );
+            assert!( (angle - at).abs() < 1e-12 );
+
+            let (axis, angle) = quat::nlerp_axis_angle::<f64>([0.0, 0.0, -2.0], -1.0, identity_axis, 0.0, at);
+            assert!( axis[0] == 0.0 && axis[1] == 0.0 && (axis[2] + 1.0).abs() < 1e-12, "{axis:?}" );
+            // the keyframe is a rotation by `-1` around `-z`, nlerp doesn't move at a constant speed
+            assert!( (-1.0 - 1e-12..=0.0).contains(&angle), "{angle}" );
+        }
+    }
+    let (axis, angle) = quat::slerp_axis_angle::<f64>([0.0; 3], 1.0, [0.0; 3], 2.0, 0.5);
+    assert_eq!( (axis, angle), ([0.0; 3], 0.0) );
+    let (axis, angle) = quat::slerp_axis_angle::<f64>([0.0, 0.0, 1.0], 0.0, [0.0; 3], 0.0, 0.5);
+    assert_eq!( (axis, angle), ([0.0, 0.0, 1.0], 0.0) );
+}
+
+#[test]
+#[cfg(feature = "test_helpers")]
+fn real_numbers_as_quaternions() {
+    use structs::Real;
+    use traits::{QuaternionConsts, QuaternionMethods};
+
+    let q: [f64; 4] = [1.5, -2.0, 0.25, 3.0];
+    let real: Real<f64> = Real::from(2.0);
+    assert_eq!( real, Real::new(2.0) );
+    assert_eq!( real.get(), 2.0 );
+
+    assert_eq!( quat::add::<f64, [f64; 4]>(q, real), quat::add::<f64, [f64; 4]>(q, (2.0, [0.0; 3])) );
+    assert_eq!( quat::sub::<f64, [f64; 4]>(real, q), [0.5, 2.0, -0.25, -3.0] );
+    assert_eq!( quat::mul::<f64, [f64; 4]>(q, real), quat::scale::<f64, [f64; 4]>(q, 2.0) );
+    assert_eq!( quat::mul::<f64, [f64; 4]>(real, q), quat::mul::<f64, [f64; 4]>(q, real) );
+    assert_eq!( quat::div::<f64, [f64; 4]>(q, real), quat::scale::<f64, [f64; 4]>(q, 0.5) );
+    assert_eq!( quat::dot::<f64, f64>(q, real), 3.0 );
+    assert_eq!( quat::abs::<f64, f64>(Real(-3.0)), 3.0 );
+    #[cfg(feature = "math_fns")]
+    assert_quat_near!( f64; quat::exp::<f64, [f64; 4]>(Real(1.0)), [core::f64::consts::E, 0.0, 0.0, 0.0], 1e-12 );
+
+    // constructing a `Real` only keeps the real part
+    assert_eq!( quat::convert_quat::<f64, Real<f64>>(q), Real(1.5) );
+    assert_eq!( quat::mul::<f64, Real<f64>>(q, q), Real(1.5 * 1.5 - 4.0 - 0.0625 - 9.0) );
+    assert_eq!( real.add(q), Real(3.5) );
+    assert_eq!( real.mul(Real(4.0)), Real(8.0) );
+    assert_eq!( <Real<f64> as QuaternionConsts<f64>>::UNIT_I, <Real<f64> as QuaternionConsts<f64>>::ORIGIN );
+    assert_eq!( <Real<f64> as QuaternionConsts<f64>>::IDENTITY, Real(1.0) );
+    assert!( <Real<f64> as QuaternionConsts<f64>>::NAN.0.is_nan() );
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "test_helpers"))]
+fn wrapped_and_checked_rotations() {
+    use core::f64::consts::PI;
+    let error: f64 = <f64 as traits::Axis>::ERROR;
+
+    for rotation in [[0.1, 0.0, 0.0], [0.1, 0.3, -0.2], [0.1, -2.5, 1.0]] {
+        let small: [f64; 4] = quat::from_rotation::<f64, [f64; 4]>(rotation);
+        let mut spun: [f64; 3] = rotation;
+        spun[0] += 1000.0 * PI;
+        let wrapped: [f64; 4] = quat::from_rotation_wrapped::<f64, [f64; 4]>(spun);
+        assert!( quat::is_near_by::<f64>(wrapped, small, error), "{wrapped:?} {small:?}" );
+        // the plain one is the same rotation, `sin_cos` of `f64` reduces large arguments well
+        assert_same_rotation!( f64; quat::from_rotation::<f64, [f64; 4]>(spun), small, error );
+
+        // an odd number of turns flips the sign of the plain quaternion, the wrapped one takes them away
+        spun[0] = rotation[0] + 3.0 * 2.0 * PI;
+        assert_quat_near!( f64; quat::from_rotation_wrapped::<f64, [f64; 4]>(spun), small, error );
+        assert_quat_near!( f64; quat::from_rotation::<f64, [f64; 4]>(spun), quat::neg::<f64, [f64; 4]>(small), error );
+    }
+
+    let rotation: [f32; 3] = [0.5, 1.0, -2.0];
+    assert_eq!( quat::from_rotation_checked::<f32, [f32; 4]>(rotation), Some(quat::from_rotation::<f32, [f32; 4]>(rotation)) );
+    for bad in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+        for index in 0..3 {
+            let mut rotation: [f32; 3] = rotation;
+            rotation[index] = bad;
+            assert_eq!( quat::from_rotation_checked::<f32, [f32; 4]>(rotation), None );
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn ndarray_rows() {
+    use ndarray::{array, Array1, Array2, ArrayView1};
+
+    let mut quats: Array2<f64> = array![
+        [1.0, 2.0, 3.0, 4.0],
+        [0.0, 0.0, 0.0, 0.5],
+        [-3.0, 0.0, 4.0, 0.0],
+    ];
+    let original: Vec<[f64; 4]> = quat::from_array2_rows::<f64>(&quats).collect();
+    assert_eq!( original[1], [0.0, 0.0, 0.0, 0.5] );
+
+    // normalize every row through the views and write the results back
+    for mut row in quats.rows_mut() {
+        let normalized: Array1<f64> = quat::normalize::<f64, Array1<f64>>(row.view());
+        row.assign(&normalized);
+    }
+    for (row, original) in quats.rows().into_iter().zip(&original) {
+        assert!( quat::is_normalized::<f64>(row) );
+        assert_eq!( quat::convert_quat::<f64, [f64; 4]>(row), quat::normalize::<f64, [f64; 4]>(original) );
+    }
+
+    // owned arrays are quaternions and vectors too
+    let q: Array1<f64> = quat::mul::<f64, Array1<f64>>(array![1.0, 2.0, 3.0, 4.0], &quats.row(2));
+    assert_quat_near!( f64; &q, quat::mul::<f64, [f64; 4]>([1.0, 2.0, 3.0, 4.0], original[2]).map(|x| x / 5.0), 1e-12 );
+    let view: ArrayView1<f64> = q.view();
+    assert_eq!( quat::convert_quat::<f64, [f64; 4]>(view), quat::convert_quat::<f64, [f64; 4]>(&q) );
+    #[cfg(feature = "rotation")]
+    {
+        let point: Array1<f64> = quat::point_rotation::<f64, Array1<f64>>(quats.row(0), array![1.0, 0.0, 0.0]);
+        assert_eq!( point.len(), 3 );
+        assert_eq!( [point[0], point[1], point[2]], quat::point_rotation::<f64, [f64; 3]>(quat::convert_quat::<f64, [f64; 4]>(quats.row(0)), [1.0, 0.0, 0.0]) );
+    }
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+#[should_panic(expected = "Wrong array length! Got 3 elements, but 4 are needed to read it as a quaternion")]
+fn ndarray_wrong_length() {
+    quat::abs::<f64, f64>(ndarray::array![1.0, 2.0, 3.0]);
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+#[should_panic(expected = "Wrong number of columns in from_array2_rows! Got 3, but quaternions need 4")]
+fn ndarray_wrong_columns() {
+    let _ = quat::from_array2_rows::<f64>(&ndarray::Array2::zeros((2, 3)));
+}
+
+#[test]
+fn numeric_contracts() {
+    use quaternion_traits::contracts::{self, ContractViolation};
+
+    assert_eq!( contracts::check_all::<f32>(), Ok(()) );
+    assert_eq!( contracts::check_all::<f64>(), Ok(()) );
+    #[cfg(feature = "std")]
+    assert_eq!( contracts::check_all::<structs::Std<f64>>(), Ok(()) );
+
+    // plain arithmetic of f64 holds far tighter than the default tolerances
+    assert_eq!( contracts::mul_is_associative_within::<f64>(1e-15), Ok(()) );
+    assert_eq!( contracts::conj_reverses_mul_within::<f64>(1e-15), Ok(()) );
+    assert_eq!( contracts::inv_is_mul_inverse_within::<f64>(1e-15), Ok(()) );
+    #[cfg(feature = "matrix")]
+    assert_eq!( contracts::matrix_roundtrip_within::<f64>(1e-15), Ok(()) );
+
+    let violation: ContractViolation<f64> = contracts::normalize_is_unit_within::<f64>(-1.0).unwrap_err();
+    assert_eq!( violation.contract, "normalize_is_unit_within" );
+    assert_eq!( violation.sample, 0 );
+    assert_eq!( violation.tolerance, -1.0 );
+    assert!( violation.error >= 0.0 && violation.error < 1e-15 );
+    assert_eq!(
+        violation.to_string(),
+        format!("contract normalize_is_unit_within broken on sample 0 (error {}, tolerance -1)", violation.error),
+    );
+}
+
+#[test]
+#[cfg(feature = "display")]
+fn inline_strings() {
+    use core::fmt::Write;
+    use structs::{BufferTooSmall, InlineString, QuaternionFormat as QF};
+
+    let quats: [[f32; 4]; 5] = [
+        [0.0; 4],
+        [1.0, 2.0, 3.0, 4.0],
+        [-1.0, 0.0, -0.5, 1.0],
+        [0.0, 1.0, -2.0, 0.0],
+        [0.25, -1.0, 1.0, -1.0],
+    ];
+    let formats: [QF; 2] = [QF::DEFAULT, QF { remove_spacing: true, show_1s: true, ..QF::DEFAULT }];
+    for quat in quats {
+        for format in formats {
+            let inline: InlineString<32> = quat::to_inline_string::<f32, 32>(quat, format).unwrap();
+            #[cfg(feature = "alloc")]
+            assert_eq!( inline, quat::to_string::<f32>(quat, format).unwrap().as_str() );
+            assert_eq!( inline.capacity(), 32 );
+        }
+    }
+
+    // `8` bytes is too short for all of `1 + 2i + 3j + 4k`
+    assert_eq!(
+        quat::to_inline_string::<f32, 8>([1.0, 2.0, 3.0, 4.0], QF::DEFAULT),
+        Err(BufferTooSmall { capacity: 8, needed: 16 }),
+    );
+    assert_eq!( quat::to_inline_string::<f32, 8>([0.0, 1.0, -2.0, 0.0], QF::DEFAULT).unwrap(), "i - 2j" );
+    assert_eq!( quat::to_inline_string::<f32, 1>([0.0; 4], QF::DEFAULT).unwrap(), "0" );
+
+    // `Display` goes through to the `str`, padding included
+    let inline: InlineString<32> = quat::to_inline_string::<f32, 32>([-1.0, 0.0, -0.5, 1.0], QF::DEFAULT).unwrap();
+    assert_eq!( format!("{inline}"), "-1 - 0.5j + k" );
+    assert_eq!( format!("[{inline:>15}]"), "[  -1 - 0.5j + k]" );
+    assert_eq!( format!("{inline:?}"), "\"-1 - 0.5j + k\"" );
+
+    // strings that don't fit are rejected whole, so no character is ever cut
+    let mut string: InlineString<3> = InlineString::new();
+    string.push_str("ab").unwrap();
+    assert_eq!( string.push_str("é"), Err(BufferTooSmall { capacity: 3, needed: 4 }) );
+    assert!( write!(string, "{}", 'é').is_err() );
+    assert_eq!( string, "ab" );
+    string.clear();
+    write!(string, "é").unwrap();
+    assert_eq!( &*string, "é" );
+    let mut longer: InlineString<8> = InlineString::default();
+    longer.push_str("é").unwrap();
+    assert_eq!( string, longer );
+}
+
+#[test]
+fn vector_component_utilities() {
+    use quat::vec;
+
+    let v: [f64; 3] = [-1.5, 4.0, 2.0];
+    assert_eq!( vec::with_x::<f64, [f64; 3]>(v, 0.5), [0.5, 4.0, 2.0] );
+    assert_eq!( vec::with_y::<f64, (f64, f64, f64)>(v, 0.5), (-1.5, 0.5, 2.0) );
+    assert_eq!( vec::with_z::<f64, [f64; 3]>(&v, 0.5), [-1.5, 4.0, 0.5] );
+    assert_eq!( vec::abs_components::<f64, [f64; 3]>(v), [1.5, 4.0, 2.0] );
+    assert_eq!( vec::max_component::<f64>(v), (4.0, 1) );
+    assert_eq!( vec::min_component::<f64>(v), (-1.5, 0) );
+    assert_eq!( vec::max_component::<f64>([1.0, 0.0, 1.0]), (1.0, 0) );
+    assert_eq!( vec::min_component::<f64>([1.0, 0.0, 0.0]), (0.0, 1) );
+
+    let onto: [f64; 3] = [1.0, 1.0, 0.0];
+    let along: [f64; 3] = vec::project_onto::<f64, [f64; 3]>(v, onto);
+    let across: [f64; 3] = vec::reject_from::<f64, [f64; 3]>(v, onto);
+    assert_eq!( along, [1.25, 1.25, 0.0] );
+    assert_eq!( across, [-2.75, 2.75, 2.0] );
+    assert!( vec::are_parallel::<f64>(along, onto) );
+    assert!( vec::are_perpendicular::<f64>(across, onto) );
+    assert!( !vec::are_parallel::<f64>(across, onto) );
+    assert!( !vec::are_perpendicular::<f64>(along, onto) );
+    assert_eq!( vec::angle_between::<f64, f64>(across, onto), quat::angle_between_vectors::<f64, f64>(across, onto) );
+    assert_eq!( vec::angle_between::<f64, f64>(along, onto), 0.0 );
+    assert_eq!( vec::project_onto::<f64, [f64; 3]>(v, [0.0; 3]), [0.0; 3] );
+    assert_eq!( vec::reject_from::<f64, [f64; 3]>(v, [0.0; 3]), v );
+}
+
+#[test]
+fn orthonormal_bases() {
+    use quat::vec;
+
+    let dot = |a: [f64; 3], b: [f64; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+    let cross = |a: [f64; 3], b: [f64; 3]| [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]];
+
+    let mut directions: Vec<[f64; 3]> = Vec::new();
+    // every octant
+    for signs in 0..8 {
+        let sign = |bit: usize| if signs & (1 << bit) == 0 { 1.0 } else { -1.0 };
+        directions.push([0.3 * sign(0), 2.0 * sign(1), 0.7 * sign(2)]);
+        // near zero components, around the poles of the construction
+        directions.push([1e-9 * sign(0), 1e-12 * sign(1), sign(2)]);
+        directions.push([1e-30 * sign(0), 1e-30 * sign(1), 1e-20 * sign(2)]);
+        directions.push([sign(0), 1e-15 * sign(1), 1e-300 * sign(2)]);
+    }
+    // the axes (and signed zeros)
+    for axis in 0..3 {
+        for sign in [1.0, -1.0] {
+            let mut direction: [f64; 3] = [0.0; 3];
+            direction[axis] = sign * 3.0;
+            directions.push(direction);
+            directions.push(direction.map(|n: f64| if n == 0.0 { -0.0 } else { n }));
+        }
+    }
+
+    for direction in directions {
+        let length: f64 = dot(direction, direction).sqrt();
+        let unit: [f64; 3] = direction.map(|n| n / length);
+        let (first, second) = vec::orthonormal_basis::<f64>(direction);
+        for (a, b, expected) in [(first, first, 1.0), (second, second, 1.0), (first, second, 0.0), (first, unit, 0.0), (second, unit, 0.0)] {
+            assert!( (dot(a, b) - expected).abs() < 1e-15, "{direction:?}: {first:?} {second:?}" );
+        }
+        let handed: [f64; 3] = cross(first, second);
+        assert!( (0..3).all(|n| (handed[n] - unit[n]).abs() < 1e-15), "{direction:?}: {handed:?}" );
+    }
+    assert_eq!( vec::orthonormal_basis::<f64>([0.0; 3]), ([1.0, 0.0, 0.0], [0.0, 1.0, 0.0]) );
+}
+
+#[test]
+#[cfg(feature = "rotation")]
+fn rotation_between_opposite_vectors() {
+    // the half turn axis used to be `[0, 0, 0]` for these, giving NaN
+    for from in [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [0.0, -2.0, 0.0], [0.0, 1.0, 1.0], [1.0, 2.0, 3.0]] {
+        let to: [f64; 3] = from.map(|n: f64| -n);
+        let rotation: [f64; 4] = quat::rotation_from_to::<f64, [f64; 4]>(from, to);
+        assert!( quat::is_normalized::<f64>(rotation), "{from:?}: {rotation:?}" );
+        let rotated: [f64; 3] = quat::point_rotation::<f64, [f64; 3]>(rotation, from);
+        assert!( (0..3).all(|n| (rotated[n] - to[n]).abs() < 1e-12), "{from:?}: {rotated:?}" );
+    }
+}
+
+#[test]
+fn consts_match_references() {
+    use quaternion_traits::consts;
+
+    // decimal expansions far past the precision of f64, parsing rounds them to the nearest f64
+    let reference = |digits: &str| digits.parse::<f64>().unwrap();
+    assert_eq!( consts::EULER_MASCHERONI, reference("0.57721566490153286060651209008240243104215933593992359880576723") );
+    assert_eq!( consts::PI, reference("3.14159265358979323846264338327950288419716939937510582097494459") );
+    assert_eq!( consts::FRAC_PI_2, reference("1.57079632679489661923132169163975144209858469968755291048747230") );
+    assert_eq!( consts::FRAC_1_SQRT_2, reference("0.70710678118654752440084436210484903928483593768847403658833987") );
+    assert_eq!( consts::HALF + consts::HALF, 1.0 );
+    assert_eq!( consts::SLERP_LERP_THRESHOLD, 1.0 - <f64 as traits::Axis>::ERROR );
+
+    // γ = H(n) - ln(n) - 1/2n + 1/12n² - 1/120n⁴ + ..., summed from the small terms up
+    let n: f64 = 1000.0;
+    let harmonic: f64 = (1..=1000).rev().map(|k| 1.0 / k as f64).sum();
+    let gamma: f64 = harmonic - n.ln() - 1.0 / (2.0 * n) + 1.0 / (12.0 * n * n) - 1.0 / (120.0 * n.powi(4));
+    assert!( (gamma - consts::EULER_MASCHERONI).abs() < 1e-14, "{gamma}" );
+
+    // the adapters round the f64 constants to `Num`
+    assert_eq!( consts::euler_mascheroni::<f32>(), consts::EULER_MASCHERONI as f32 );
+    assert_eq!( consts::pi::<f32>(), core::f32::consts::PI );
+    assert_eq!( consts::frac_pi_2::<f32>(), core::f32::consts::FRAC_PI_2 );
+    assert_eq!( consts::frac_1_sqrt_2::<f32>(), core::f32::consts::FRAC_1_SQRT_2 );
+    assert_eq!( consts::half::<f64>(), 0.5 );
+    assert_eq!( consts::slerp_lerp_threshold::<f32>() as f64, consts::SLERP_LERP_THRESHOLD );
+    assert_eq!( consts::slerp_lerp_threshold::<f64>(), consts::SLERP_LERP_THRESHOLD );
+    // `Std` keeps the machine epsilon as its `ERROR`
+    #[cfg(feature = "std")]
+    assert_eq!( consts::slerp_lerp_threshold::<structs::Std<f64>>(), structs::Std(1.0 - f64::EPSILON) );
+}
+
+#[test]
+#[cfg(feature = "matrix")]
+fn matrix_2_forms() {
+    use quat::{Matrix2, Matrix2Form};
+
+    const FORMS: [Matrix2Form; 3] = [Matrix2Form::Standard, Matrix2Form::Conjugate, Matrix2Form::PauliBasis];
+
+    // small integers keep the determinant exact
+    let exact: [[f64; 4]; 4] = [[1.0, 2.0, 3.0, 4.0], [-5.0, 0.0, 7.0, -1.0], [0.0, 0.0, 0.0, 0.0], [0.5, -0.25, 8.0, 3.0]];
+    for q in exact {
+        for form in FORMS {
+            let matrix: Matrix2<f64> = quat::to_matrix_2_with::<f64>(q, form);
+            assert_eq!( matrix.determinant(), (quat::abs_squared::<f64, f64>(q), 0.0), "{form:?} of {q:?}" );
+            assert_eq!( quat::from_matrix_2_with::<f64, [f64; 4]>(matrix, form), Some(q), "{form:?} of {q:?}" );
+        }
+    }
+
+    for q in xorshift_quats(64) {
+        for form in FORMS {
+            let matrix: Matrix2<f32> = quat::to_matrix_2_with::<f32>(q, form);
+            let (real, imaginary) = matrix.determinant();
+            let abs_squared: f32 = quat::abs_squared::<f32, f32>(q);
+            assert!( (real - abs_squared).abs() <= abs_squared * 1e-6, "{form:?} of {q:?}" );
+            assert_eq!( imaginary, 0.0, "{form:?} of {q:?}" );
+            assert_eq!( quat::from_matrix_2_with::<f32, [f32; 4]>(matrix, form), Some(q), "{form:?} of {q:?}" );
+        }
+    }
+
+    // the forms are the same matrix up to conjugation
+    let q: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+    let standard = quat::to_matrix_2_with::<f32>(q, Matrix2Form::Standard);
+    assert_eq!( standard, Matrix2::Complex(quat::to_matrix_2::<f32, (f32, f32), _>(q)) );
+    assert_eq!( quat::to_matrix_2_with::<f32>(q, Matrix2Form::PauliBasis).to_complex(), standard.to_complex() );
+    let conjugate = quat::to_matrix_2_with::<f32>(q, Matrix2Form::Conjugate).to_complex();
+    for (row, standard_row) in conjugate.iter().zip(standard.to_complex()) {
+        for (&(re, im), (standard_re, standard_im)) in row.iter().zip(standard_row) {
+            assert_eq!( (re, im), (standard_re, -standard_im) );
+        }
+    }
+
+    // a matrix that doesn't fit the form
+    let pauli = quat::to_matrix_2_with::<f32>(q, Matrix2Form::PauliBasis);
+    assert_eq!( quat::from_matrix_2_with::<f32, [f32; 4]>(pauli, Matrix2Form::Conjugate), None );
+    assert_eq!( quat::from_matrix_2_with::<f32, [f32; 4]>(standard, Matrix2Form::PauliBasis), None );
+    let broken = Matrix2::Complex([[(1.0, 2.0), (3.0, 4.0)], [(5.0, 6.0), (7.0, 8.0)]]);
+    assert_eq!( quat::from_matrix_2_with::<f32, [f32; 4]>(broken, Matrix2Form::Standard), None );
+    assert_eq!( quat::from_matrix_2_with::<f32, [f32; 4]>(broken, Matrix2Form::Conjugate), None );
+}
+
+#[test]
+#[cfg(feature = "rotation")]
+fn angle_between_accuracy() {
+    // the formulation `angle_between` used before, for comparison
+    fn acos_angle(from: [f32; 4], to: [f32; 4]) -> f32 {
+        (quat::dot::<f32, f32>(from, to) / (quat::abs_squared::<f32, f32>(from) * quat::abs_squared::<f32, f32>(to)).sqrt()).acos()
+    }
+    // the exact angle between the (already rounded) f32 inputs, in f64
+    fn reference(from: [f32; 4], to: [f32; 4]) -> f64 {
+        let from: [f64; 4] = from.map(f64::from);
+        let to: [f64; 4] = to.map(f64::from);
+        let from: [f64; 4] = quat::unscale::<f64, [f64; 4]>(from, quat::abs::<f64, f64>(from));
+        let to: [f64; 4] = quat::unscale::<f64, [f64; 4]>(to, quat::abs::<f64, f64>(to));
+        2.0 * quat::abs::<f64, f64>(quat::sub::<f64, [f64; 4]>(from, to)).atan2(quat::abs::<f64, f64>(quat::add::<f64, [f64; 4]>(from, to)))
+    }
+
+    // two perpendicular unit quaternions spanning the plane the angles are in
+    let start: [f64; 4] = [0.5, 0.5, 0.5, 0.5];
+    let side: [f64; 4] = [0.5, -0.5, 0.5, -0.5];
+    let from: [f32; 4] = start.map(|x| x as f32);
+
+    let count: usize = 200;
+    let (low, high): (f64, f64) = (1e-9, core::f64::consts::PI - 1e-9);
+    let mut endpoint_errors: [(f64, f64); 2] = [(0.0, 0.0); 2];
+    let mut max_error: f64 = 0.0;
+    for step in 0..=count {
+        // logarithmically spaced from both ends, so both endpoints get as close
+        let offset: f64 = 1e-9 * (core::f64::consts::FRAC_PI_2 / 1e-9).powf(step as f64 / count as f64);
+        for angle in [low.max(offset), high.min(core::f64::consts::PI - offset)] {
+            let to: [f32; 4] = core::array::from_fn(|n| (start[n] * angle.cos() + side[n] * angle.sin()) as f32);
+            let exact: f64 = reference(from, to);
+            let new: f64 = (quat::angle_between::<f32, f32>(from, to) as f64 - exact).abs();
+            let old: f64 = (acos_angle(from, to) as f64 - exact).abs();
+            max_error = max_error.max(new);
+            let end: &mut (f64, f64) = &mut endpoint_errors[(exact > core::f64::consts::FRAC_PI_2) as usize];
+            if exact.min(core::f64::consts::PI - exact) < 1e-3 {
+                end.0 = end.0.max(new);
+                end.1 = end.1.max(old);
+            }
+        }
+    }
+
+    // f32 has about 7 digits, the atan2 formulation keeps them over the whole range
+    assert!( max_error < 1e-6, "{max_error}" );
+    for (new, old) in endpoint_errors {
+        assert!( new * 1000.0 < old, "new {new}, old {old}" );
+    }
+}
+
+#[test]
+fn splat_constructors() {
+    use quaternion_traits::traits::{ComplexConstructor, QuaternionConstructor, VectorConstructor};
+
+    assert_eq!( quat::splat::<f32, [f32; 4]>(1.5), [1.5; 4] );
+    assert_eq!( quat::splat::<f32, (f32, [f32; 3])>(-2_i8), (-2.0, [-2.0; 3]) );
+    assert_eq!( quat::vec::splat::<f32, [f32; 3]>(1.5), [1.5; 3] );
+    assert_eq!( quat::vec::splat::<f32, (f32, f64, i32)>(3.0), (3.0, 3.0, 3) );
+
+    // every constructor gets the default methods
+    assert_eq!( <[f64; 4] as QuaternionConstructor<f64>>::splat(0.25), [0.25; 4] );
+    assert_eq!( <structs::Real<f64> as QuaternionConstructor<f64>>::splat(0.25), structs::Real(0.25) );
+    assert_eq!( <[f64; 3] as VectorConstructor<f64>>::splat(0.25), [0.25; 3] );
+    assert_eq!( <(f32, f32) as ComplexConstructor<f32>>::splat(4.0), (4.0, 4.0) );
+    assert_eq!( <[u8; 2] as ComplexConstructor<f32>>::splat(4.0), [4, 4] );
+
+    // a uniform bound for a componentwise check
+    let q: [f32; 4] = [0.1, -0.2, 0.05, 0.0];
+    let bound: [f32; 4] = quat::splat::<f32, _>(0.25);
+    assert!( q.iter().zip(bound).all(|(x, b)| x.abs() <= b) );
+}
+
+#[test]
+#[cfg(all(feature = "matrix", feature = "std"))]
+fn matrix_out_of_bounds_panics() {
+    extern crate alloc;
+    use alloc::string::String;
+    use structs::{ColMajor, MatrixView, RowMajor};
+    use traits::{Matrix, MatrixConstructor};
+
+    // the same in debug and release builds: a descriptive panic, never another element
+    fn check<M: Matrix<f32, N>, const N: usize>(matrix: M, transposed: bool) {
+        let name: &str = core::any::type_name::<M>();
+        for (row, col) in [(N, 0), (0, N), (N - 1, N), (N, N), (usize::MAX, 0)] {
+            assert_eq!( matrix.get(row, col), None, "{name} at ({row}, {col})" );
+            let payload = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| matrix.get_unchecked(row, col)))
+                .expect_err(name);
+            let message: String = *payload.downcast::<String>().expect(name);
+            let (row, col) = if transposed { (col, row) } else { (row, col) };
+            assert!( message.starts_with(&std::format!("Out of index operation! Got ({row}, {col})")), "{name}: {message}" );
+        }
+        assert_eq!( matrix.get_unchecked(N - 1, N - 1), (N * N) as f32, "{name}" );
+    }
+    fn build<M: MatrixConstructor<f32, N>, const N: usize>() -> M {
+        M::new_matrix(core::array::from_fn(|row| core::array::from_fn(|col| (row * N + col + 1) as f32)))
+    }
+
+    check::<((f32, f32), (f32, f32)), 2>(build(), false);
+    check::<([f32; 2], [f32; 2]), 2>(build(), false);
+    check::<[(f32, f32); 2], 2>(build(), false);
+    check::<[f32; 4], 2>(build(), false);
+    check::<[[f32; 2]; 2], 2>(build(), false);
+    check::<((f32, f32, f32), (f32, f32, f32), (f32, f32, f32)), 3>(build(), false);
+    check::<([f32; 3], [f32; 3], [f32; 3]), 3>(build(), false);
+    check::<[(f32, f32, f32); 3], 3>(build(), false);
+    check::<[f32; 9], 3>(build(), false);
+    check::<[[f32; 3]; 3], 3>(build(), false);
+    check::<((f32, f32, f32, f32), (f32, f32, f32, f32), (f32, f32, f32, f32), (f32, f32, f32, f32)), 4>(build(), false);
+    check::<([f32; 4], [f32; 4], [f32; 4], [f32; 4]), 4>(build(), false);
+    check::<[(f32, f32, f32, f32); 4], 4>(build(), false);
+    check::<[f32; 16], 4>(build(), false);
+    check::<[[f32; 4]; 4], 4>(build(), false);
+    check::<RowMajor<[f32; 16]>, 4>(build(), false);
+    check::<(RowMajor<[f32; 9]>, ), 3>((build(), ), false);
+    check::<&[f32; 4], 2>(&build(), false);
+
+    // the transposed matrix ends with the same element, but reports the position of the inner matrix
+    check::<ColMajor<[f32; 16]>, 4>(build(), true);
+
+    // a 2x2 view into a 3 wide matrix, where (0, 2) would otherwise read the padding
+    let data: [f32; 6] = [1.0, 2.0, 0.0, 3.0, 4.0, 0.0];
+    check::<MatrixView<f32, 2>, 2>(MatrixView::new(&data, 3).unwrap(), false);
+
+    // empty matrices have no valid position, but still get the message (and no overflow)
+    let empty: [[f32; 0]; 0] = [];
+    let view: MatrixView<f32, 0> = MatrixView::new(&[], 0).unwrap();
+    for payload in [
+        std::panic::catch_unwind(|| Matrix::<f32, 0>::get_unchecked(&empty, 0, 0)).unwrap_err(),
+        std::panic::catch_unwind(|| view.get_unchecked(0, 0)).unwrap_err(),
+    ] {
+        let message: String = *payload.downcast::<String>().unwrap();
+        assert!( message.starts_with("Out of index operation! Got (0, 0)"), "{message}" );
+    }
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "test_helpers"))]
+fn orbit_camera_clamps_pitch() {
+    let expected = |yaw: f64, pitch: f64| quat::mul::<f64, [f64; 4]>(
+        quat::from_axis_angle::<f64, [f64; 4]>([0.0, 0.0, 1.0], yaw),
+        quat::from_axis_angle::<f64, [f64; 4]>([0.0, 1.0, 0.0], pitch),
+    );
+    let limits: (f64, f64) = (-0.8, 1.2);
+
+    // up past the highest pitch, then down past the lowest, turning all the time
+    let mut camera: [f64; 4] = quat::identity::<f64, [f64; 4]>();
+    let (mut yaw, mut pitch): (f64, f64) = (0.0, 0.0);
+    for step in 0..400 {
+        let pitch_delta: f64 = if step < 200 { 0.02 } else { -0.03 };
+        camera = quat::orbit::<f64, [f64; 4]>(camera, 0.05, pitch_delta, limits);
+        yaw += 0.05;
+        pitch = (pitch + pitch_delta).clamp(limits.0, limits.1);
+
+        let camera_pitch: f64 = quat::to_pitch::<f64, f64>(camera);
+        assert!( camera_pitch >= limits.0 - 1e-9 && camera_pitch <= limits.1 + 1e-9, "step {step}: {camera_pitch}" );
+        assert!( quat::to_roll::<f64, f64>(camera).abs() < 1e-9, "step {step}" );
+        assert_same_rotation!( f64; camera, expected(yaw, pitch), 1e-9 );
+    }
+    assert_eq!( pitch, limits.0 );
+    assert!( yaw > core::f64::consts::TAU * 3.0 );
+
+    // a camera outside the limits is moved onto the nearest one
+    let outside: [f64; 4] = expected(0.3, 1.5);
+    assert_same_rotation!( f64; quat::orbit::<f64, [f64; 4]>(outside, 0.0, 0.0, limits), expected(0.3, 1.2), 1e-9 );
+
+    // without limits the camera goes over the top
+    let mut free: [f64; 4] = quat::identity::<f64, [f64; 4]>();
+    for _ in 0..100 {
+        free = quat::turntable::<f64, [f64; 4]>(free, 0.05, 0.02);
+    }
+    assert_same_rotation!( f64; free, expected(5.0, 2.0), 1e-9 );
+    assert!( quat::to_pitch::<f64, f64>(free) < 1.2 );
+}
+
+#[test]
+#[cfg(feature = "test_helpers")]
+fn widened_accumulation() {
+    // tiny quaternions with varied components, many of them
+    let tiny: Vec<[f32; 4]> = xorshift_quats(1000).into_iter().map(|q| q.map(|x| x * 1e-3)).collect();
+    let many = || tiny.iter().cycle().take(200_000);
+
+    // the exact f32 inputs added up in f64 and rounded once (widening is lossless)
+    let mut reference: [f64; 4] = [0.0; 4];
+    for q in many() {
+        for (sum, x) in reference.iter_mut().zip(q) {
+            *sum += *x as f64;
+        }
+    }
+    for q in &tiny {
+        assert_eq!( quat::narrow::<[f32; 4]>(quat::widen::<[f64; 4]>(q)), *q );
+    }
+
+    let plain: [f32; 4] = quat::sum::<f32, [f32; 4]>(many());
+    let widened: [f32; 4] = quat::sum_widened::<[f32; 4]>(many());
+    let error = |sum: [f32; 4]| quat::dist_euclid::<f64, f64>(quat::widen::<[f64; 4]>(sum), reference);
+    let rounded: [f32; 4] = quat::narrow(reference);
+    assert_eq!( widened, rounded );
+    assert!( error(widened) * 100.0 < error(plain), "widened {}, plain {}", error(widened), error(plain) );
+
+    // the mean agrees with the f32 one on a well conditioned set (rotations clustered around the identity)
+    let rotations: Vec<[f32; 4]> = xorshift_quats(32).into_iter()
+        .map(|q| quat::normalize::<f32, [f32; 4]>(quat::add::<f32, [f32; 4]>(q, [8.0, 0.0, 0.0, 0.0])))
+        .collect();
+    let widened: [f32; 4] = quat::mean_markley_widened::<[f32; 4]>(&rotations, core::iter::repeat(1.0_f32));
+    let plain: [f32; 4] = quat::mean_markley::<f32, [f32; 4]>(&rotations, core::iter::repeat(1.0_f32));
+    assert_quat_near!( f64; quat::widen::<[f64; 4]>(widened), quat::widen::<[f64; 4]>(plain), 1e-5 );
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "test_helpers"))]
+fn homogeneous_points() {
+    use structs::Homogeneous;
+    use traits::Vector;
+
+    let rotation: [f64; 4] = quat::from_axis_angle::<f64, [f64; 4]>([1.0, -2.0, 0.5], 0.7);
+    let point: [f64; 3] = [1.5, -3.0, 0.25];
+    let rotated: [f64; 3] = quat::point_rotation::<f64, [f64; 3]>(rotation, point);
+
+    // any w stands for the same point (powers of two keep the division exact)
+    for w in [1.0, 2.0, 0.5, -4.0] {
+        let homogeneous: Homogeneous<f64> = Homogeneous([point[0] * w, point[1] * w, point[2] * w, w]);
+        assert_eq!( [homogeneous.x(), homogeneous.y(), homogeneous.z()], point );
+        assert_eq!( quat::point_rotation::<f64, [f64; 3]>(rotation, homogeneous), rotated );
+        assert_eq!( quat::rotate_vector::<f64, [f64; 3]>(homogeneous, rotation), quat::rotate_vector::<f64, [f64; 3]>(point, rotation) );
+
+        // rotating the coordinates keeps w, and still stands for the rotated point
+        let moved: Homogeneous<f64> = quat::rotate_homogeneous::<f64>(rotation, homogeneous);
+        assert_eq!( moved.w(), w );
+        assert_quat_near!( f64; (0.0, [moved.x(), moved.y(), moved.z()]), (0.0, rotated), 1e-12 );
+    }
+
+    // constructing gives w = 1
+    assert_eq!( quat::point_rotation::<f64, Homogeneous<f64>>(rotation, point), Homogeneous([rotated[0], rotated[1], rotated[2], 1.0]) );
+    // and so does the default, which is the origin
+    let origin: Homogeneous<f64> = Homogeneous::default();
+    assert_eq!( origin, Homogeneous([0.0, 0.0, 0.0, 1.0]) );
+    assert_eq!( [origin.x(), origin.y(), origin.z()], [0.0; 3] );
+
+    // directions can't be read as points, but rotate like vectors
+    let direction: Homogeneous<f64> = Homogeneous([1.0, 0.0, -2.0, 0.0]);
+    assert_eq!( direction.x(), f64::INFINITY );
+    assert!( direction.y().is_nan() );
+    assert_eq!( direction.z(), f64::NEG_INFINITY );
+    let turned: Homogeneous<f64> = quat::rotate_homogeneous::<f64>(rotation, direction);
+    let expected: [f64; 3] = quat::point_rotation::<f64, [f64; 3]>(rotation, [1.0, 0.0, -2.0]);
+    assert_eq!( turned, Homogeneous([expected[0], expected[1], expected[2], 0.0]) );
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "alloc"))]
+fn rotation_set_dedup_and_sort() {
+    let turn = |axis: [f64; 3], angle: f64| quat::from_axis_angle::<f64, [f64; 4]>(axis, angle);
+    let a: [f64; 4] = turn([0.0, 0.0, 1.0], 1.0);
+    let b: [f64; 4] = turn([1.0, 0.0, 0.0], 2.0);
+    let c: [f64; 4] = turn([0.0, 1.0, 1.0], -0.5);
+    let tolerance: f64 = 1e-3;
+
+    // duplicates that only differ by sign
+    let mut set: Vec<[f64; 4]> = vec![a, quat::neg::<f64, [f64; 4]>(a), b, quat::neg::<f64, [f64; 4]>(b), quat::neg::<f64, [f64; 4]>(b), c];
+    assert_eq!( quat::dedup_rotations::<f64>(&mut set, tolerance), 3 );
+    assert_eq!( set.len(), 3 );
+    for rotation in [a, b, c] {
+        let (_, distance) = quat::nearest::<f64>(rotation, &set).unwrap();
+        assert!( distance < 1e-12 );
+    }
+    // the signs are canonical: the largest component is positive
+    for q in &set {
+        let largest: f64 = q.iter().copied().fold(0.0, |max: f64, x| if x.abs() > max.abs() { x } else { max });
+        assert!( largest > 0.0, "{q:?}" );
+    }
+
+    // near duplicates, inside the tolerance (removed) and outside it (kept)
+    let inside: [f64; 4] = turn([0.0, 0.0, 1.0], 1.0 + tolerance * 0.01);
+    let outside: [f64; 4] = turn([0.0, 0.0, 1.0], 1.0 + tolerance * 2.0);
+    let mut set: Vec<[f64; 4]> = vec![a, inside, outside];
+    assert_eq!( quat::dedup_rotations::<f64>(&mut set, tolerance), 1 );
+    // `a` and `inside` have the same key, so the one with the smaller real part is kept
+    // (across a cell border the order, and even finding them, isn't promised)
+    assert_eq!( quat::rotation_key_default::<f64>(a), quat::rotation_key_default::<f64>(inside) );
+    assert!( inside[0] < a[0] );
+    assert!( set.contains(&inside) && set.contains(&outside) );
+
+    // exact duplicates are found even with a different rotation of the same key inbetween
+    let between: [f64; 4] = turn([0.0, 0.0, 1.0], 1.0 + 1e-4);
+    assert_eq!( quat::rotation_key_default::<f64>(a), quat::rotation_key_default::<f64>(between) );
+    let mut set: Vec<[f64; 4]> = vec![a, between, a];
+    assert_eq!( quat::dedup_rotations::<f64>(&mut set, 1e-5), 1 );
+    assert!( set.contains(&a) && set.contains(&between) );
+
+    // NaNs and the origin are never duplicates of anything
+    let mut set: Vec<[f64; 4]> = vec![[0.0; 4], [0.0; 4], [f64::NAN; 4], a];
+    assert_eq!( quat::dedup_rotations::<f64>(&mut set, tolerance), 0 );
+    let mut empty: Vec<[f64; 4]> = Vec::new();
+    assert_eq!( quat::dedup_rotations::<f64>(&mut empty, tolerance), 0 );
+
+    // sorting by angle from a reference, signs don't matter and ties keep their order
+    let reference: [f64; 4] = turn([0.0, 0.0, 1.0], 0.2);
+    let mut set: [[f64; 4]; 6] = [
+        turn([0.0, 0.0, 1.0], 2.2),
+        [f64::NAN; 4],
+        quat::neg::<f64, [f64; 4]>(turn([0.0, 0.0, 1.0], 0.5)),
+        turn([0.0, 0.0, 1.0], -0.3),
+        turn([0.0, 0.0, 1.0], 0.5),
+        reference,
+    ];
+    let tie: [f64; 4] = set[2];
+    quat::sort_by_angle_from::<f64>(&mut set, reference);
+    assert_eq!( set[0], reference );
+    assert_eq!( set[1], tie );
+    assert_eq!( set[2], turn([0.0, 0.0, 1.0], 0.5) );
+    assert_eq!( set[3], turn([0.0, 0.0, 1.0], -0.3) );
+    assert_eq!( set[4], turn([0.0, 0.0, 1.0], 2.2) );
+    assert!( set[5][0].is_nan() );
+}
+
+#[test]
+#[cfg(feature = "rotation")]
+fn kabsch_recovers_rotations() {
+    let rotation_distance = |a: [f64; 4], b: [f64; 4]| {
+        let d: f64 = quat::dot::<f64, f64>(a, b).abs().min(1.0);
+        2.0 * (1.0 - d * d).sqrt().atan2(d)
+    };
+    let randoms: Vec<[f64; 4]> = xorshift_quats(200).into_iter().map(|q| q.map(f64::from)).collect();
+    let points: Vec<[f64; 3]> = randoms.iter().map(|&[_, x, y, z]| [x * 3.0, y - 0.5, z * 2.0]).collect();
+
+    // exactly rotated vectors
+    for rotation in randoms.iter().take(20) {
+        let rotation: [f64; 4] = quat::normalize::<f64, [f64; 4]>(*rotation);
+        let pairs = points.iter().take(5).map(|&point| (point, quat::point_rotation::<f64, [f64; 3]>(rotation, point)));
+        let found: [f64; 4] = quat::kabsch::<f64, [f64; 4]>(pairs).unwrap();
+        assert!( found[0] >= 0.0 );
+        assert!( (quat::abs::<f64, f64>(found) - 1.0).abs() < <f64 as traits::Axis>::ERROR );
+        assert!( rotation_distance(found, rotation) < <f64 as traits::Axis>::ERROR, "{rotation:?} {found:?}" );
+    }
+
+    // noisy vectors: the error shrinks with the noise
+    let rotation: [f64; 4] = quat::from_axis_angle::<f64, [f64; 4]>([0.3, -1.0, 0.2], 2.5);
+    for noise in [1e-2, 1e-4, 1e-6] {
+        let pairs = points.iter().zip(randoms.iter().rev()).map(|(&point, &[_, x, y, z])| {
+            let [rx, ry, rz]: [f64; 3] = quat::point_rotation::<f64, [f64; 3]>(rotation, point);
+            (point, [rx + x * noise, ry + y * noise, rz + z * noise])
+        });
+        let found: [f64; 4] = quat::kabsch::<f64, [f64; 4]>(pairs).unwrap();
+        assert!( rotation_distance(found, rotation) < noise, "noise {noise}: {}", rotation_distance(found, rotation) );
+    }
+
+    // degenerate inputs
+    let line: [([f64; 3], [f64; 3]); 3] = [([1.0, 2.0, 3.0], [3.0, 2.0, 1.0]), ([-2.0, -4.0, -6.0], [-6.0, -4.0, -2.0]), ([0.5, 1.0, 1.5], [1.5, 1.0, 0.5])];
+    assert_eq!( quat::kabsch::<f64, [f64; 4]>(line), None );
+    assert_eq!( quat::kabsch::<f64, [f64; 4]>([([1.0, 0.0, 0.0], [0.0, 1.0, 0.0])]), None );
+    assert_eq!( quat::kabsch::<f64, [f64; 4]>([([0.0; 3], [0.0; 3]); 0]), None );
+    // zero vectors don't count as pairs
+    let with_zeros: [([f64; 3], [f64; 3]); 3] = [([1.0, 0.0, 0.0], [0.0, 1.0, 0.0]), ([0.0; 3], [0.0, 0.0, 1.0]), ([0.0, 1.0, 0.0], [0.0; 3])];
+    assert_eq!( quat::kabsch::<f64, [f64; 4]>(with_zeros), None );
+    let quarter_turn: [f64; 4] = quat::kabsch::<f64, [f64; 4]>([([1.0, 0.0, 0.0], [0.0, 1.0, 0.0]), ([0.0; 3], [1.0; 3]), ([0.0, 0.0, 1.0], [0.0, 0.0, 1.0])]).unwrap();
+    assert!( rotation_distance(quarter_turn, quat::from_axis_angle::<f64, [f64; 4]>([0.0, 0.0, 1.0], core::f64::consts::FRAC_PI_2)) < 1e-12 );
+}
+
+#[test]
+#[cfg(all(feature = "rotation", feature = "test_helpers"))]
+fn half_and_double_angles() {
+    // identity halves into itself exactly
+    assert_eq!( quat::half::<f64, [f64; 4]>([1.0, 0.0, 0.0, 0.0]), [1.0, 0.0, 0.0, 0.0] );
+    assert_eq!( quat::half::<f64, [f64; 4]>([3.0, 0.0, 0.0, 0.0]), [1.0, 0.0, 0.0, 0.0] );
+    assert_eq!( quat::double::<f64, [f64; 4]>([1.0, 0.0, 0.0, 0.0]), [1.0, 0.0, 0.0, 0.0] );
+    assert_eq!( quat::half::<f64, [f64; 4]>([0.0; 4]), [0.0; 4] );
+
+    let mut inputs: Vec<[f64; 4]> = xorshift_quats(100).into_iter().map(|q| q.map(f64::from)).collect();
+    inputs.extend([
+        // half turns (zero real part)
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, -2.0, 0.0],
+        [0.0, 0.6, 0.0, 0.8],
+        // full turns and almost full turns
+        [-1.0, 0.0, 0.0, 0.0],
+        [-2.0, 0.0, 0.0, 0.0],
+        [-1.0, 1e-9, 0.0, 0.0],
+        [-1.0, 0.0, -1e-150, 1e-150],
+    ]);
+    for q in inputs {
+        let unit: [f64; 4] = quat::normalize::<f64, [f64; 4]>(q);
+        let halved: [f64; 4] = quat::half::<f64, [f64; 4]>(q);
+        assert!( (quat::abs::<f64, f64>(halved) - 1.0).abs() < 1e-15, "{q:?}" );
+        assert!( halved[0] >= 0.0, "{q:?}" );
+
+        // double undoes half, on the normalized quaternion (not just the same rotation)
+        assert_quat_near!( f64; quat::double::<f64, [f64; 4]>(halved), unit, 1e-14 );
+        // and half of a half turn is a quarter turn around the same axis
+        // (angle taken from the quaternion itself, so a negative real part means more than half a turn)
+        let length = (unit[1] * unit[1] + unit[2] * unit[2] + unit[3] * unit[3]).sqrt();
+        let angle = 2.0 * length.atan2(unit[0]);
+        let axis = [unit[1] / length, unit[2] / length, unit[3] / length];
+        if length > 1e-6 {
+            assert_same_rotation!( f64; halved, quat::from_axis_angle::<f64, [f64; 4]>(axis, angle / 2.0), 1e-12 );
+        }
+        // the same as squaring for unit quaternions
+        assert_quat_near!( f64; quat::double::<f64, [f64; 4]>(unit), quat::mul::<f64, [f64; 4]>(unit, unit), 1e-15 );
+    }
+
+    // a full turn has no axis, the x axis is picked
+    assert_eq!( quat::half::<f64, [f64; 4]>([-1.0, 0.0, 0.0, 0.0]), [0.0, 1.0, 0.0, 0.0] );
+    assert_eq!( quat::double::<f64, [f64; 4]>([0.0, 1.0, 0.0, 0.0]), [-1.0, 0.0, 0.0, 0.0] );
+    // near it the half stays accurate
+    let almost: [f64; 4] = quat::half::<f64, [f64; 4]>([-1.0, 1e-9, 0.0, 0.0]);
+    assert!( (almost[0] - 5e-10).abs() < 1e-24 && (almost[1] - 1.0).abs() < 1e-15, "{almost:?}" );
+    // and keeps its own axis even when the square of the vector part underflows
+    assert_eq!( quat::half::<f64, [f64; 4]>([-1.0, 0.0, 1e-170, 0.0]), [0.0, 0.0, 1.0, 0.0] );
+    assert_eq!( quat::half::<f64, [f64; 4]>([-1.0, 0.0, 0.0, -1e-320]), [0.0, 0.0, 0.0, -1.0] );
+    // a tiny quaternion halves like any other
+    assert_quat_near!( f64; quat::half::<f64, [f64; 4]>([0.0, 1e-170, 0.0, 0.0]), [core::f64::consts::FRAC_1_SQRT_2, core::f64::consts::FRAC_1_SQRT_2, 0.0, 0.0] );
+}
@@ -0,0 +1,358 @@
+/*!
+Links every function of `quat` and `unit` marked with `no_panic`.
+
+Only does something with the `panic_free_check` feature, it has to be run with the `panic-free-check` profile
+(the optimizer has to prove that the functions don't panic, which needs to see through the calls into `libm`)
+and on it's own (the other tests instantiate the functions with types whose methods are allowed to panic, eg: `RefCell`):
+
+```text
+cargo test --profile panic-free-check --features panic_free_check --test panic_free
+```
+
+If one of the functions can panic this fails to link with
+`ERROR[no-panic]: detected panic in function <name>` somewhere in the output.
+
+Every input goes through [black_box] so that the compiler
+has to prove the function doesn't panic for any input and not just for the ones used here.
+*/
+#![cfg(feature = "panic_free_check")]
+#![allow(unused_must_use)]
+
+use quaternion_traits::*;
+use core::hint::black_box;
+
+type Q = [f32; 4];
+type U = structs::UnitQuat<f32>;
+type V = [f32; 3];
+type C = (f32, f32);
+
+#[inline(never)]
+fn q() -> Q { black_box([1.2, 3.4, 5.6, 7.8]) }
+#[inline(never)]
+fn u() -> U { black_box(quat::to_unit_quat::<f32, U>([1.2, 3.4, 5.6, 7.8])) }
+#[inline(never)]
+fn v() -> V { black_box([1.0, 2.0, 3.0]) }
+#[inline(never)]
+fn c() -> C { black_box((1.0, 2.0)) }
+#[inline(never)]
+fn s() -> f32 { black_box(0.5) }
+
+#[test]
+fn inputless_and_meta() {
+    black_box(quat::origin::<f32, Q>());
+    black_box(quat::identity::<f32, Q>());
+    black_box(quat::nan::<f32, Q>());
+    black_box(quat::unit_r::<f32, Q>());
+    black_box(quat::unit_i::<f32, Q>());
+    black_box(quat::unit_j::<f32, Q>());
+    black_box(quat::unit_k::<f32, Q>());
+
+    black_box(quat::new_quat::<f32, Q>(s(), s(), s(), s()));
+    black_box(quat::vector_part::<f32, Q>(q()));
+    black_box(quat::complex_part::<f32, Q>(q()));
+    black_box(quat::scalar_part::<f32, Q>(q()));
+}
+
+#[test]
+fn math() {
+    black_box(quat::add::<f32, Q>(q(), q()));
+    black_box(quat::add_scalar::<f32, Q>(q(), s()));
+    black_box(quat::add_complex::<f32, Q>(q(), c()));
+    black_box(quat::add_vector::<f32, Q>(q(), v()));
+    black_box(quat::sub::<f32, Q>(q(), q()));
+    black_box(quat::sub_scalar::<f32, Q>(q(), s()));
+    black_box(quat::sub_complex::<f32, Q>(q(), c()));
+    black_box(quat::sub_vector::<f32, Q>(q(), v()));
+    black_box(quat::sub_from_scalar::<f32, Q>(s(), q()));
+    black_box(quat::sub_from_complex::<f32, Q>(c(), q()));
+    black_box(quat::sub_from_vector::<f32, Q>(v(), q()));
+    black_box(quat::mul::<f32, Q>(q(), q()));
+    black_box(quat::mul_complex::<f32, Q>(q(), c()));
+    black_box(quat::mul_vector::<f32, Q>(q(), v()));
+    black_box(quat::mul_reversed::<f32, Q>(q(), q()));
+    black_box(quat::mul_complex_reversed::<f32, Q>(q(), c()));
+    black_box(quat::mul_vector_reversed::<f32, Q>(q(), v()));
+    black_box(quat::div::<f32, Q>(q(), q()));
+    black_box(quat::div_reversed::<f32, Q>(q(), q()));
+    black_box(quat::solve_left::<f32, Q>(q(), q()));
+    black_box(quat::solve_right::<f32, Q>(q(), q()));
+    black_box(quat::solve_left_checked::<f32, Q>(q(), q()));
+    black_box(quat::solve_right_checked::<f32, Q>(q(), q()));
+    black_box(quat::div_rem::<f32, Q, Q>(q(), q()));
+    black_box(quat::rem::<f32, Q>(q(), q()));
+    black_box(quat::rem_checked::<f32, Q>(q(), q()));
+    black_box(quat::rem_euclid::<f32, Q>(q(), q()));
+    black_box(quat::round_lipschitz::<f32, Q>(q()));
+    black_box(quat::round_hurwitz::<f32, Q>(q()));
+    black_box(quat::four_square_norm::<f32, f32>(q()));
+    black_box(quat::associates::<f32, Q>(q()));
+    black_box(quat::mul_add::<f32, Q>(q(), q(), q()));
+    black_box(quat::mul_reversed_add::<f32, Q>(q(), q(), q()));
+    black_box(quat::neg::<f32, Q>(q()));
+    black_box(quat::conj::<f32, Q>(q()));
+    black_box(quat::scale::<f32, Q>(q(), s()));
+    black_box(quat::unscale::<f32, Q>(q(), s()));
+    black_box(quat::lerp::<f32, Q>(q(), q(), s()));
+    black_box(quat::slerp_checked::<f32, Q>(q(), q(), s()));
+    black_box(quat::slerp_unchecked::<f32, Q>(q(), q(), s()));
+    black_box(quat::slerp_extrapolate::<f32, Q>(q(), q(), s()));
+    black_box(quat::dist_euclid::<f32, f32>(q(), q()));
+    black_box(quat::dist_euclid_squared::<f32, f32>(q(), q()));
+    black_box(quat::dist_cosine::<f32, f32>(q(), q()));
+    black_box(quat::dist_geodesic::<f32, f32>(q(), q()));
+    black_box(quat::angle_between::<f32, f32>(q(), q()));
+    black_box(quat::angle_between_cos::<f32, f32>(q(), q()));
+    black_box(quat::normalize::<f32, Q>(q()));
+    black_box(quat::normalize_unchecked::<f32, Q>(q()));
+    black_box(quat::normalize_fast::<f32, Q>(q()));
+    black_box(quat::abs::<f32, f32>(q()));
+    black_box(quat::abs_small::<f32, f32>(q()));
+    black_box(quat::abs_squared::<f32, f32>(q()));
+    black_box(quat::angle::<f32, f32>(q()));
+    black_box(quat::angle_cos::<f32, f32>(q()));
+    black_box(quat::inv::<f32, Q>(q()));
+    black_box(quat::ln::<f32, Q>(q()));
+    black_box(quat::exp::<f32, Q>(q()));
+    black_box(quat::sqrt::<f32, Q>(q()));
+    black_box(quat::sqrt_scaled::<f32, Q>(q()));
+    black_box(quat::sqrt_newton::<f32, Q>(q(), q()));
+    black_box(quat::unit_vector_from_angles::<f32, Q>(s(), s()));
+    black_box(quat::fibonacci_sphere_point::<f32>(black_box(3), black_box(10)));
+    black_box(quat::square::<f32, Q>(q()));
+    black_box(quat::square_add::<f32, Q>(q(), q()));
+    black_box(quat::sym_mul::<f32, Q>(q(), q()));
+    black_box(quat::pow_i::<f32, Q>(q(), black_box(-3)));
+    black_box(quat::pow_u::<f32, Q>(q(), black_box(3)));
+    black_box(quat::pow_f::<f32, Q>(q(), s()));
+    black_box(quat::lngamma::<f32, Q>(q()));
+    black_box(quat::lngamma_n::<f32, Q, 100>(q()));
+    black_box(quat::gamma::<f32, Q>(q()));
+    black_box(quat::dot::<f32, f32>(q(), q()));
+    black_box(quat::enforce_continuity::<f32, Q>(q(), q()));
+    black_box(quat::abs_dot::<f32, f32>(q(), q()));
+    black_box(quat::align_to::<f32, Q>(q(), q()));
+    black_box(quat::slerp_eased::<f32, Q>(q(), q(), s(), quat::ease::smoothstep));
+    black_box(quat::ease::smoothstep::<f32>(s()));
+    black_box(quat::ease::smootherstep::<f32>(s()));
+    black_box(quat::ease::ease_in_out_cubic::<f32>(s()));
+    black_box(quat::blas::axpy::<f32, Q>(s(), q(), q()));
+    black_box(quat::blas::norm2::<f32, f32>(q()));
+    black_box(quat::blas::copy_into::<f32, Q>(q(), black_box(&mut q())));
+    black_box(quat::blas::swap::<f32, Q, (f32, V)>(black_box(&mut q()), black_box(&mut (s(), v()))));
+    black_box(quat::are_parallel::<f32>(v(), v()));
+    black_box(quat::are_perpendicular::<f32>(v(), v()));
+    black_box(quat::angle_between_vectors::<f32, f32>(v(), v()));
+    black_box(quat::vectors_parallel::<f32>(q(), q()));
+    black_box(quat::vectors_perpendicular::<f32>(q(), q()));
+    black_box(quat::vector_angle_between::<f32, f32>(q(), q()));
+    black_box(quat::hadamard::<f32, Q>(q(), q()));
+
+    #[cfg(feature = "unstable")] black_box(quat::log::<f32, Q>(q(), q()));
+    #[cfg(feature = "unstable")] black_box(quat::pow_q::<f32, Q>(q(), q()));
+
+    black_box(quat::mul4(&[q(); 4], &[q(); 4]));
+    black_box(quat::dot4(&[q(); 4], &[q(); 4]));
+    black_box(quat::normalize4(&[q(); 4]));
+    black_box(quat::normalize_slice::<f32>(black_box(&mut [q(); 8])));
+    black_box(quat::rotate_points::<f32>(q(), black_box(&mut [v(); 8])));
+}
+
+#[test]
+fn iterators() {
+    black_box(quat::sum::<f32, Q>([q(); 4]));
+    black_box(quat::sum_widened::<Q>([q(); 4]));
+    black_box(quat::product::<f32, Q>([q(); 4]));
+    black_box(quat::continuous::<f32, Q>([q(); 4]).count());
+    black_box(quat::mean_markley::<f32, Q>([q(); 4], [s(); 4]));
+    black_box(quat::compose_axis_angles::<f32, Q>([(v(), s()); 4]));
+    black_box(quat::kabsch::<f32, Q>([(v(), v()); 4]));
+}
+
+#[test]
+fn relational() {
+    black_box(quat::eq::<f32>(q(), q()));
+    black_box(quat::is_scalar::<f32>(q()));
+    black_box(quat::is_complex::<f32>(q()));
+    black_box(quat::is_vector::<f32>(q()));
+    black_box(quat::is_nan::<f32>(q()));
+    black_box(quat::classify::<f32>(q()));
+    black_box(quat::count_subnormals::<f32>(q()));
+    black_box(quat::is_all_nan::<f32>(q()));
+    black_box(quat::is_on_axis_plane::<f32>(q()));
+    black_box(quat::is_near::<f32>(q(), q()));
+    black_box(quat::is_near_by::<f32>(q(), q(), s()));
+    black_box(quat::is_lipschitz::<f32>(q(), s()));
+    black_box(quat::is_hurwitz::<f32>(q(), s()));
+    black_box(quat::is_unit_lipschitz::<f32>(q()));
+    black_box(quat::is_root_of_neg_one::<f32>(q()));
+    black_box(quat::is_close::<f32>(q(), q()));
+    black_box(quat::is_close_by::<f32>(q(), q(), s()));
+    black_box(quat::is_normalized::<f32>(q()));
+    black_box(quat::are_mul_commutative::<f32>(q(), q()));
+    black_box(quat::are_nearly_mul_commutative::<f32>(q(), q()));
+}
+
+#[test]
+fn conversions() {
+    black_box(quat::convert_quat::<f32, Q>(q()));
+    black_box(quat::convert_num::<f32, f64, [f64; 4]>(q()));
+    black_box(quat::from_unit_quat::<f32, Q>(u()));
+    black_box(quat::to_le_bytes::<f32>(q()));
+    black_box(quat::to_be_bytes::<f32>(q()));
+    black_box(quat::from_le_bytes::<f32, Q>(black_box([0; 16])));
+    black_box(quat::from_be_bytes::<f32, Q>(black_box([0; 16])));
+    black_box(quat::to_le_bytes::<f64>(black_box([0.0_f64; 4])));
+    black_box(quat::from_be_bytes::<f64, [f64; 4]>(black_box([0; 32])));
+    black_box(quat::from_vector::<f32, Q>(v()));
+    black_box(quat::from_complex::<f32, Q>(c()));
+    black_box(quat::from_scalar::<f32, Q>(s()));
+    black_box(quat::widen::<[f64; 4]>(q()));
+    black_box(quat::narrow::<Q>(black_box([0.0_f64; 4])));
+    black_box(quat::splat::<f32, Q>(s()));
+    black_box(quat::from_rotation::<f32, Q>(v()));
+    black_box(quat::from_rotation_checked::<f32, Q>(v()));
+    black_box(quat::from_rotation_wrapped::<f32, Q>(v()));
+    black_box(quat::from_polar_form::<f32, Q>(s(), s(), v()));
+    black_box(quat::from_matrix_2::<f32, C, Q>(black_box([[c(); 2]; 2])));
+    black_box(quat::from_matrix_2_unchecked::<f32, C, Q>(black_box([[c(); 2]; 2])));
+    black_box(quat::from_matrix_2_with::<f32, Q>(black_box(quat::Matrix2::Pauli(q())), black_box(quat::Matrix2Form::PauliBasis)));
+    black_box(quat::from_matrix_3::<f32, f32, Q>(black_box([v(); 3])));
+    black_box(quat::from_matrix_4::<f32, f32, Q>(black_box([q(); 4])));
+    black_box(quat::from_matrix_3_slice::<f32, Q>(black_box(&[s(); 9][..])));
+    black_box(quat::from_matrix_4_slice::<f32, Q>(black_box(&[s(); 16][..])));
+    black_box(quat::orthonormalize_matrix_3::<f32, f32, [V; 3]>(black_box([v(); 3])));
+    black_box(quat::from_matrix_3_orthonormalized::<f32, f32, Q>(black_box([v(); 3])));
+    black_box(quat::frame_from_columns::<f32, Q>(v(), v(), v()));
+    black_box(quat::to_unit_quat::<f32, U>(q()));
+    black_box(quat::to_unit_quat_checked::<f32, U>(q()));
+    black_box(quat::to_vector::<f32, V>(q()));
+    black_box(quat::to_complex::<f32, C>(q()));
+    black_box(quat::to_scalar::<f32, f32>(q()));
+    black_box(quat::to_rotation::<f32, V>(q()));
+    black_box(quat::to_rotation_ext::<f32, V>(q(), quat::AngleRange::Unsigned));
+    black_box(quat::to_matrix_2::<f32, C, [[C; 2]; 2]>(q()));
+    black_box(quat::to_matrix_2_with::<f32>(q(), black_box(quat::Matrix2Form::PauliBasis)));
+    black_box(quat::to_matrix_2_with::<f32>(q(), black_box(quat::Matrix2Form::Conjugate)).determinant());
+    black_box(quat::to_matrix_3::<f32, f32, [V; 3]>(q()));
+    black_box(quat::to_matrix_3_slice::<f32>(q(), black_box(&mut [s(); 9][..])));
+    black_box(quat::rotation_to_matrix_3::<f32, f32, [V; 3]>(v()));
+    black_box(quat::matrix_3_to_rotation::<f32, f32, V>(black_box([v(); 3])));
+    black_box(quat::to_matrix_4::<f32, f32, [Q; 4]>(q()));
+    black_box(quat::to_polar_form::<f32, f32, f32, V>(q()));
+    black_box(quat::to_roll::<f32, f32>(q()));
+    black_box(quat::to_pitch::<f32, f32>(q()));
+    black_box(quat::to_yaw::<f32, f32>(q()));
+    black_box(quat::to_roll_unit::<f32, f32>(u()));
+    black_box(quat::to_pitch_unit::<f32, f32>(u()));
+    black_box(quat::to_yaw_unit::<f32, f32>(u()));
+    black_box(quat::to_roll_pitch_yaw::<f32>(q()));
+    black_box(quat::to_roll_pitch_yaw_unit::<f32>(u()));
+    black_box(quat::from_roll::<f32, Q>(s()));
+    black_box(quat::from_pitch::<f32, Q>(s()));
+    black_box(quat::from_yaw::<f32, Q>(s()));
+}
+
+#[test]
+fn rotation() {
+    black_box(quat::rotate_vector::<f32, V>(v(), q()));
+    black_box(quat::rotate_vector_scaled::<f32, V>(v(), q()));
+    black_box(quat::rotate_vector_exact::<f32, V>(v(), q()));
+    black_box(quat::reflection_across_plane::<f32, Q>(v()));
+    black_box(quat::reflect_vector::<f32, V>(v(), v()));
+    black_box(quat::rotation_from_two_reflections::<f32, Q>(v(), v()));
+    black_box(quat::rotation_from_to::<f32, Q>(v(), v()));
+    black_box(quat::vec::splat::<f32, V>(s()));
+    black_box(quat::vec::with_x::<f32, V>(v(), s()));
+    black_box(quat::vec::with_y::<f32, V>(v(), s()));
+    black_box(quat::vec::with_z::<f32, V>(v(), s()));
+    black_box(quat::vec::abs_components::<f32, V>(v()));
+    black_box(quat::vec::max_component::<f32>(v()));
+    black_box(quat::vec::min_component::<f32>(v()));
+    black_box(quat::vec::project_onto::<f32, V>(v(), v()));
+    black_box(quat::vec::reject_from::<f32, V>(v(), v()));
+    black_box(quat::vec::orthonormal_basis::<f32>(v()));
+    black_box(quat::from_axis_angle_unchecked::<f32, Q>(v(), s()));
+    black_box(quat::from_axis_angle_checked::<f32, Q>(v(), s()));
+    black_box(quat::from_axis_angle::<f32, Q>(v(), s()));
+    black_box(quat::from_axis_angle_typed::<f32, Q>(v(), structs::Degrees(s())));
+    black_box(quat::rotate_x::<f32, Q>(q(), s()));
+    black_box(quat::rotate_y::<f32, Q>(q(), s()));
+    black_box(quat::rotate_z::<f32, Q>(q(), s()));
+    black_box(quat::double::<f32, Q>(q()));
+    black_box(quat::half::<f32, Q>(q()));
+    black_box(quat::turntable::<f32, Q>(q(), s(), s()));
+    black_box(quat::orbit::<f32, Q>(q(), s(), s(), (s(), s())));
+    black_box(quat::integrate_gyro::<f32, Q>(q(), v(), s()));
+    black_box(quat::integrate_gyro_world::<f32, Q>(q(), v(), s()));
+    black_box(quat::rotation_to_rate::<f32, V>(v(), s()));
+    black_box(quat::rate_to_rotation::<f32, V>(v(), s()));
+    black_box(quat::rotation_key::<f32>(q(), black_box(1000)));
+    black_box(quat::rotation_key_default::<f32>(q()));
+    black_box(quat::frame_orthogonality_error::<f32, f32>(q()));
+    black_box(quat::rotation_angle_between_matrices::<f32, f32, f32>(black_box([v(); 3]), black_box([v(); 3])));
+    black_box(quat::same_rotation_matrix::<f32, f32>(black_box([v(); 3]), black_box([v(); 3]), s()));
+    black_box(quat::ad::<f32, f32, [V; 3]>(v()));
+    black_box(quat::Ad::<f32, f32, [V; 3]>(q()));
+    black_box(quat::baker_campbell_hausdorff_2::<f32, V>(v(), v()));
+    black_box(quat::to_axis_angle::<f32, V, f32>(q()));
+    black_box(quat::to_axis_angle_wrapped::<f32, V, f32>(q(), quat::AngleWrap::Unwrapped(s())));
+    black_box(quat::slerp_axis_angle::<f32>(v(), s(), v(), s(), s()));
+    black_box(quat::nlerp_axis_angle::<f32>(v(), s(), v(), s(), s()));
+    black_box(quat::point_rotation::<f32, V>(q(), v()));
+    black_box(quat::rotate_homogeneous::<f32>(q(), black_box(structs::Homogeneous(q()))));
+    black_box(quat::point_rotation_checked::<f32, V>(q(), v()));
+    black_box(quat::point_rotation_unchecked::<f32, V>(q(), v()));
+    black_box(quat::rotation_strategies::rotate_via_sandwich::<f32, V>(q(), v()));
+    black_box(quat::rotation_strategies::rotation_matrix::<f32>(q()));
+    black_box(quat::rotation_strategies::rotate_via_matrix::<f32, V>(&[v(); 3], v()));
+    black_box(quat::frame_rotation::<f32, V>(q(), v()));
+    black_box(quat::frame_rotation_checked::<f32, V>(q(), v()));
+    black_box(quat::frame_rotation_unchecked::<f32, V>(q(), v()));
+    black_box(quat::similarity_transform::<f32, V>(v(), q(), s(), v()));
+}
+
+#[test]
+fn trigonometry() {
+    black_box(quat::sin::<f32, Q>(q()));
+    black_box(quat::sinh::<f32, Q>(q()));
+    black_box(quat::sec::<f32, Q>(q()));
+    black_box(quat::sech::<f32, Q>(q()));
+    black_box(quat::cos::<f32, Q>(q()));
+    black_box(quat::cosh::<f32, Q>(q()));
+    black_box(quat::csc::<f32, Q>(q()));
+    black_box(quat::csch::<f32, Q>(q()));
+    black_box(quat::sin_cos::<f32, Q>(q()));
+    black_box(quat::sin_cos_split::<f32, Q, Q>(q()));
+    black_box(quat::sinh_cosh::<f32, Q>(q()));
+    black_box(quat::sinh_cosh_split::<f32, Q, Q>(q()));
+    black_box(quat::tan::<f32, Q>(q()));
+    black_box(quat::tanh::<f32, Q>(q()));
+    black_box(quat::cot::<f32, Q>(q()));
+    black_box(quat::coth::<f32, Q>(q()));
+    black_box(quat::asin::<f32, Q>(q()));
+    black_box(quat::acos::<f32, Q>(q()));
+    black_box(quat::atan::<f32, Q>(q()));
+    black_box(quat::acot::<f32, Q>(q()));
+    black_box(quat::asec::<f32, Q>(q()));
+    black_box(quat::acsc::<f32, Q>(q()));
+    black_box(quat::asinh::<f32, Q>(q()));
+    black_box(quat::acosh::<f32, Q>(q()));
+    black_box(quat::atanh::<f32, Q>(q()));
+    black_box(quat::acoth::<f32, Q>(q()));
+    black_box(quat::acsch::<f32, Q>(q()));
+    black_box(quat::asech::<f32, Q>(q()));
+}
+
+#[test]
+fn unit() {
+    black_box(unit::add_any::<f32, Q>(u(), u()));
+    black_box(unit::mul::<f32, U>(u(), u()));
+    black_box(unit::mul_reversed::<f32, U>(u(), u()));
+    black_box(unit::div::<f32, U>(u(), u()));
+    black_box(unit::neg::<f32, U>(u()));
+    black_box(unit::conj::<f32, U>(u()));
+    black_box(unit::slerp::<f32, U>(u(), u(), s()));
+    black_box(unit::ln::<f32, Q>(u()));
+    black_box(unit::exp::<f32, U>(u()));
+    black_box(unit::sqrt::<f32, U>(u()));
+}